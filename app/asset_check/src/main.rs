@@ -0,0 +1,105 @@
+use std::{path::PathBuf, process};
+
+use asset_checker::AssetChecker;
+use asset_fs::{AssetPackWriter, ASSET_PACK_EXTENSION};
+use asset_schema_gen::AssetSchemaGen;
+use structopt::StructOpt;
+
+/// Options to check assets.
+#[derive(StructOpt, Debug)]
+#[structopt(name = "Will Asset Check", rename_all = "snake_case")]
+pub struct Opt {
+    /// Path to the assets directory.
+    #[structopt(default_value = "assets")]
+    assets_dir: PathBuf,
+    /// Command to run instead of checking assets.
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+/// Commands other than checking assets.
+#[derive(StructOpt, Debug)]
+#[structopt(rename_all = "snake_case")]
+pub enum Command {
+    /// Packs a namespace directory into a single asset pack file.
+    ///
+    /// Place the pack under `assets/download` to load it as a namespace.
+    Pack {
+        /// Path to the namespace directory.
+        namespace_dir: PathBuf,
+        /// Path to write the pack to, defaults to `<namespace_dir>.pack`.
+        #[structopt(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Writes JSON Schemas for asset definition files.
+    ///
+    /// Editors use these to offer completion and validation when editing definitions.
+    Schema {
+        /// Directory to write the schemas to.
+        #[structopt(default_value = "schemas")]
+        output_dir: PathBuf,
+    },
+}
+
+fn main() {
+    let opt = Opt::from_args();
+
+    match opt.command {
+        Some(Command::Pack {
+            namespace_dir,
+            output,
+        }) => pack(namespace_dir, output),
+        Some(Command::Schema { output_dir }) => schema(output_dir),
+        None => check(opt.assets_dir),
+    }
+}
+
+fn check(assets_dir: PathBuf) {
+    let asset_check_errors = AssetChecker::check(&assets_dir);
+    asset_check_errors
+        .iter()
+        .for_each(|asset_check_error| eprintln!("{}", asset_check_error));
+
+    if asset_check_errors.is_empty() {
+        println!("No errors found in `{}`.", assets_dir.display());
+    } else {
+        eprintln!();
+        eprintln!(
+            "Found {} error(s) in `{}`.",
+            asset_check_errors.len(),
+            assets_dir.display()
+        );
+        process::exit(1);
+    }
+}
+
+fn pack(namespace_dir: PathBuf, output: Option<PathBuf>) {
+    let pack_path = output.unwrap_or_else(|| namespace_dir.with_extension(ASSET_PACK_EXTENSION));
+
+    match AssetPackWriter::write(&namespace_dir, &pack_path) {
+        Ok(entries) => println!(
+            "Packed {} file(s) from `{}` into `{}`.",
+            entries.len(),
+            namespace_dir.display(),
+            pack_path.display()
+        ),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn schema(output_dir: PathBuf) {
+    match AssetSchemaGen::write(&output_dir) {
+        Ok(schema_paths) => println!(
+            "Wrote {} schema(s) to `{}`.",
+            schema_paths.len(),
+            output_dir.display()
+        ),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -36,11 +36,12 @@ use application_robot::RobotState;
 #[cfg(not(feature = "wasm"))]
 use application_ui::FontConfigLoader;
 use application_ui::{ApplicationUiBundle, FontConfig};
+use asset_model::{config::AssetType, loaded::OverrideNamespaces};
 use asset_play::{AssetPlayBundle, ItemIdEventSystem};
 use asset_selection_stdio::AssetSelectionStdioBundle;
 use asset_selection_ui_play::{
-    ApwPreviewSpawnSystemCharacter, ApwPreviewSpawnSystemMap, AssetSelectionSfxSystem,
-    AswPortraitUpdateSystem,
+    ApwNamespaceInfoSystem, ApwPreviewSpawnSystemCharacter, ApwPreviewSpawnSystemMap,
+    AssetSelectionSfxSystem, AswPortraitUpdateSystem,
 };
 use asset_ui_play::AssetSelectionHighlightUpdateSystem;
 use audio_loading::AudioLoadingBundle;
@@ -50,6 +51,7 @@ use character_loading::CharacterLoadingBundle;
 use collision_audio_loading::CollisionAudioLoadingBundle;
 use collision_loading::CollisionLoadingBundle;
 use energy_loading::EnergyLoadingBundle;
+use frame_event_loading::FrameEventLoadingBundle;
 use frame_rate::strategy::frame_rate_limit_config;
 use game_input::{
     ControllerInputUpdateSystem, GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
@@ -66,8 +68,10 @@ use game_mode_selection_ui::GameModeSelectionSfxSystem;
 use game_play::GamePlayBundle;
 use game_play_stdio::GamePlayStdioBundle;
 use input_reaction_loading::InputReactionLoadingBundle;
+use item_loading::ItemLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
-use loading::{LoadingBundle, LoadingState};
+use loading::{AssetPartLoadingCoordinatorSystem, AssetReloadSystem, LoadingBundle, LoadingState};
+use loading_model::{loaded::DeferredAssetTypes, play::AssetMemoryBudget};
 #[cfg(not(feature = "wasm"))]
 use log::debug;
 use map_loading::MapLoadingBundle;
@@ -84,7 +88,9 @@ use network_session_play::{
     SessionInputResourcesSyncSystem, SessionInputResourcesSyncSystemDesc,
     SessionMessageResponseSystem, SessionMessageResponseSystemDesc, SessionStatusNotifierSystem,
 };
+use object_type::ObjectType;
 use parent_play::ChildEntityDeleteSystem;
+use pause_menu_stdio::PauseMenuStdioBundle;
 use sequence_loading::SequenceLoadingBundle;
 use serde::{Deserialize, Serialize};
 use session_host_play::{
@@ -106,7 +112,7 @@ use session_lobby_ui_play::{
     SessionDeviceWidgetUpdateSystem,
 };
 use spawn_loading::SpawnLoadingBundle;
-use sprite_loading::SpriteLoadingBundle;
+use sprite_loading::{SpriteAtlasSystem, SpriteLoadingBundle};
 use state_play::{
     StateCameraResetSystem, StateIdEventSystem, StateItemSpawnSystem,
     StateItemUiInputAugmentSystem, StateItemUiInputAugmentSystemDesc,
@@ -117,7 +123,9 @@ use stdio_input::StdioInputBundle;
 use stdio_spi::MapperSystem;
 use structopt::StructOpt;
 use structopt_toml::StructOptToml;
+use survival_loading::SurvivalLoadingBundle;
 use tracker::PrevTrackerSystem;
+use training_stdio::TrainingStdioBundle;
 use ui_audio_loading::UiAudioLoadingBundle;
 use ui_loading::UiLoadingBundle;
 use ui_play::{
@@ -154,6 +162,22 @@ pub struct WillConfig {
     /// Logger configuration file.
     #[structopt(long)]
     logger_config: Option<PathBuf>,
+    /// Memory budget for loaded assets in MiB, beyond which unused characters and maps are
+    /// unloaded.
+    #[structopt(long)]
+    memory_budget: Option<u64>,
+    /// Namespaces whose `namespace.yaml` overrides replace assets in other namespaces.
+    #[serde(default)]
+    #[structopt(long)]
+    override_namespaces: Vec<String>,
+    /// Load all assets before the main menu, instead of characters and maps when selected.
+    #[serde(default)]
+    #[structopt(long)]
+    preload: bool,
+    /// Reload object definitions when their files change.
+    #[serde(default)]
+    #[structopt(long)]
+    reload: bool,
     /// Address of the session server.
     ///
     /// Currently must be an `IpAddr`, in the future we may accept hostnames.
@@ -164,6 +188,10 @@ pub struct WillConfig {
     #[serde(default = "WillConfig::session_server_port_default")]
     #[structopt(long, default_value = "1234")]
     session_server_port: u16,
+    /// Pack the sprite sheets of the selected assets into texture atlases when a game loads.
+    #[serde(default)]
+    #[structopt(long)]
+    sprite_atlas: bool,
 }
 
 impl WillConfig {
@@ -479,7 +507,24 @@ where
             .with_bundle(SequenceLoadingBundle::new())?
             .with_bundle(AudioLoadingBundle::new())?
             .with_bundle(KinematicLoadingBundle::new())?
-            .with_bundle(LoadingBundle::new(assets_dir.clone()))?
+            .with_bundle(LoadingBundle::new(assets_dir.clone()))?;
+
+        if will_config.reload {
+            game_data = game_data.with(
+                AssetReloadSystem::default(),
+                any::type_name::<AssetReloadSystem>(),
+                &[any::type_name::<AssetPartLoadingCoordinatorSystem>()],
+            );
+        }
+        if will_config.sprite_atlas {
+            game_data = game_data.with(
+                SpriteAtlasSystem::default(),
+                any::type_name::<SpriteAtlasSystem>(),
+                &[],
+            );
+        }
+
+        game_data = game_data
             .with_system_desc(
                 InputToGameInputSystemDesc::default(),
                 any::type_name::<InputToGameInputSystem>(),
@@ -512,18 +557,23 @@ where
             .with_bundle(GamePlayStdioBundle::new())?
             .with_bundle(GameModeSelectionStdioBundle::new())?
             .with_bundle(NetworkModeSelectionStdioBundle::new())?
+            .with_bundle(PauseMenuStdioBundle::new())?
             .with_bundle(SessionHostStdioBundle::new())?
             .with_bundle(SessionJoinStdioBundle::new())?
+            .with_bundle(TrainingStdioBundle::new())?
             .with_bundle(CollisionLoadingBundle::new())?
             .with_bundle(SpawnLoadingBundle::new())?
+            .with_bundle(FrameEventLoadingBundle::new())?
             .with_bundle(BackgroundLoadingBundle::new())?
             .with_bundle(UiLoadingBundle::new())?
             .with_bundle(MapLoadingBundle::new())?
             .with_bundle(CharacterLoadingBundle::new())?
             .with_bundle(EnergyLoadingBundle::new())?
+            .with_bundle(ItemLoadingBundle::new())?
             .with_bundle(InputReactionLoadingBundle::new())?
             .with_bundle(CollisionAudioLoadingBundle::new(assets_dir.clone()))?
             .with_bundle(UiAudioLoadingBundle::new(assets_dir.clone()))?
+            .with_bundle(SurvivalLoadingBundle::new(assets_dir.clone()))?
             .with(CameraOrthoSystem::default(), "camera_ortho", &[])
             .with(
                 UiActiveWidgetUpdateSystem::new(),
@@ -696,6 +746,11 @@ where
                 any::type_name::<ApwPreviewSpawnSystemMap>(),
                 &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
             )
+            .with(
+                ApwNamespaceInfoSystem::new(),
+                any::type_name::<ApwNamespaceInfoSystem>(),
+                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+            )
             .with(
                 ChildEntityDeleteSystem::new(),
                 any::type_name::<ChildEntityDeleteSystem>(),
@@ -731,10 +786,27 @@ where
             );
     }
 
+    // Characters and maps are only needed once selected, so they are loaded on demand.
+    let deferred_asset_types = if will_config.preload {
+        DeferredAssetTypes::default()
+    } else {
+        DeferredAssetTypes::new(
+            [AssetType::Object(ObjectType::Character), AssetType::Map]
+                .iter()
+                .copied()
+                .collect(),
+        )
+    };
+
     let app = CoreApplication::<_, AppEvent, AppEventReader>::build(assets_dir, state)?
         .with_resource(session_server_config)
         .with_resource(player_controllers)
         .with_resource(player_input_configs)
+        .with_resource(OverrideNamespaces::new(will_config.override_namespaces))
+        .with_resource(deferred_asset_types)
+        .with_resource(AssetMemoryBudget::new(
+            will_config.memory_budget.map(|mib| mib * 1024 * 1024),
+        ))
         .with_frame_limit_config(frame_rate_limit_config(will_config.frame_rate))
         .build(game_data)?;
 
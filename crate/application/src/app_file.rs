@@ -5,11 +5,14 @@ use std::{
 };
 
 use amethyst::{utils::application_root_dir, Error};
+use asset_fs::AssetFs;
 use serde::Deserialize;
 
-use crate::{FindContext, Format, IoUtils};
+use crate::{FindContext, Format};
 
 /// Functions to discover and interact with application files.
+///
+/// Files may be within asset packs, e.g. `assets/download/my_mod.pack/namespace.yaml`.
 #[derive(Debug)]
 pub struct AppFile(
     // Prevent instantiation.
@@ -84,7 +87,7 @@ impl AppFile {
 
             #[cfg(not(target_arch = "wasm32"))]
             {
-                if resource_path.exists() {
+                if AssetFs::exists(&resource_path) {
                     return Ok(resource_path);
                 }
             }
@@ -177,7 +180,7 @@ impl AppFile {
         for<'de> T: Deserialize<'de>,
         P: AsRef<Path> + AsRef<ffi::OsStr>,
     {
-        let bytes = IoUtils::read_file(file_path.as_ref())?;
+        let bytes = AssetFs::read(file_path.as_ref())?;
 
         Self::load_bytes(&bytes, format)
     }
@@ -197,6 +200,7 @@ impl AppFile {
         let data = match format {
             Format::Ron => ron::de::from_bytes(&bytes)?,
             Format::Yaml => serde_yaml::from_slice(&bytes)?,
+            Format::Json => serde_json::from_slice(&bytes)?,
         };
 
         Ok(data)
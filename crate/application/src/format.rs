@@ -1,3 +1,5 @@
+use std::path::Path;
+
 /// Format of the resource to load.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Format {
@@ -5,4 +7,31 @@ pub enum Format {
     Ron,
     /// [YAML Ain't Markup Language](https://yaml.org/).
     Yaml,
+    /// [JavaScript Object Notation](https://www.json.org/).
+    Json,
+}
+
+impl Format {
+    /// Returns the file extension for this format, without the leading `.`.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ron => "ron",
+            Format::Yaml => "yaml",
+            Format::Json => "json",
+        }
+    }
+
+    /// Returns the format of a file based on its extension, if it is a known format.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the file.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "ron" => Some(Format::Ron),
+            "yaml" | "yml" => Some(Format::Yaml),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
 }
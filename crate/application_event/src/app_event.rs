@@ -13,11 +13,13 @@ use game_input_model::play::ControlInputEvent;
 use game_mode_selection_model::GameModeSelectionEvent;
 use game_play_model::GamePlayEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
+use pause_menu_model::PauseMenuEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
 use stdio_command_model::StdioCommandEvent;
 use strum_macros::{Display, EnumDiscriminants, EnumIter, EnumString};
+use training_model::TrainingEvent;
 
 /// Type encompassing all state event types.
 #[derive(Clone, Debug, Display, EnumDiscriminants, EventReader, From, PartialEq)]
@@ -42,6 +44,8 @@ pub enum AppEvent {
     GamePlay(GamePlayEvent),
     /// `network_mode_selection` events.
     NetworkModeSelection(NetworkModeSelectionEvent),
+    /// `pause_menu` events.
+    PauseMenu(PauseMenuEvent),
     /// `session_host` events.
     SessionHost(SessionHostEvent),
     /// `session_join` events.
@@ -50,6 +54,8 @@ pub enum AppEvent {
     SessionLobby(SessionLobbyEvent),
     /// `stdio_command` events.
     StdioCommand(StdioCommandEvent),
+    /// `training` events.
+    Training(TrainingEvent),
     /// Events sent by the winit window.
     Window(Event<'static, ()>),
 }
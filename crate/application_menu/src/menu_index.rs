@@ -1,13 +1,17 @@
+use asset_schema::AssetSchema;
 use game_mode_selection_model::GameModeIndex;
 use network_mode_selection_model::NetworkModeIndex;
+use pause_menu_model::PauseMenuIndex;
 use serde::{Deserialize, Serialize};
 
 /// Sum type of all menu index types.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(deny_unknown_fields, untagged)]
 pub enum MenuIndex {
     /// Game mode menu indicies.
     GameMode(GameModeIndex),
     /// Network mode menu indicies.
     NetworkMode(NetworkModeIndex),
+    /// Pause menu indicies.
+    PauseMenu(PauseMenuIndex),
 }
@@ -23,9 +23,11 @@ use character_selection_model::{CharacterSelections, CharacterSelectionsStatus};
 use collision_audio_loading::CollisionAudioLoadingBundle;
 use collision_loading::CollisionLoadingBundle;
 use energy_loading::EnergyLoadingBundle;
+use frame_event_loading::FrameEventLoadingBundle;
 use game_input_model::config::ControlBindings;
 use game_loading::GameLoadingState;
 use input_reaction_loading::InputReactionLoadingBundle;
+use item_loading::ItemLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 use map_loading::MapLoadingBundle;
@@ -116,11 +118,13 @@ impl AutexousiousApplication {
             .with_bundle(LoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(CollisionLoadingBundle::new())
             .with_bundle(SpawnLoadingBundle::new())
+            .with_bundle(FrameEventLoadingBundle::new())
             .with_bundle(BackgroundLoadingBundle::new())
             .with_bundle(UiLoadingBundle::new())
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(ItemLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
@@ -201,11 +205,13 @@ impl AutexousiousApplication {
             .with_bundle(LoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(CollisionLoadingBundle::new())
             .with_bundle(SpawnLoadingBundle::new())
+            .with_bundle(FrameEventLoadingBundle::new())
             .with_bundle(BackgroundLoadingBundle::new())
             .with_bundle(UiLoadingBundle::new())
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(ItemLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
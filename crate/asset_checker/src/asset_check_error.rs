@@ -0,0 +1,169 @@
+use std::{error, fmt, path::PathBuf};
+
+use asset_model::config::AssetSlug;
+use derive_new::new;
+
+/// Error found in an asset definition file.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct AssetCheckError {
+    /// Path to the definition file.
+    pub path: PathBuf,
+    /// Line in the file that the error is at, starting from 1.
+    pub line: Option<usize>,
+    /// What is wrong with the definition.
+    pub kind: AssetCheckErrorKind,
+}
+
+/// Kinds of errors in asset definition files.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssetCheckErrorKind {
+    /// The file could not be read.
+    Io {
+        /// Message of the underlying `io::Error`.
+        message: String,
+    },
+    /// The file could not be deserialized.
+    Deserialize {
+        /// Message of the underlying deserialization error.
+        message: String,
+    },
+    /// The definition it `extends` could not be merged.
+    Extends {
+        /// Message of the underlying merge error.
+        message: String,
+    },
+    /// A sequence refers to a sequence name that does not exist.
+    SequenceNotFound {
+        /// Sequence that contains the reference.
+        sequence: String,
+        /// Name of the sequence that does not exist.
+        sequence_name: String,
+    },
+    /// A spawn refers to an object slug that is not in the asset index.
+    SpawnObjectNotFound {
+        /// Sequence that contains the spawn.
+        sequence: String,
+        /// Slug of the object to spawn.
+        asset_slug: AssetSlug,
+    },
+    /// A frame refers to a sprite sheet that does not exist.
+    SpriteSheetNotFound {
+        /// Sequence that contains the frame.
+        sequence: String,
+        /// Index of the frame within the sequence.
+        frame_index: usize,
+        /// Index of the sprite sheet.
+        sheet: usize,
+        /// Number of sprite sheets defined.
+        sheet_count: usize,
+    },
+    /// A frame refers to a sprite index past the sprite sheet's bounds.
+    SpriteIndexOutOfBounds {
+        /// Sequence that contains the frame.
+        sequence: String,
+        /// Index of the frame within the sequence.
+        frame_index: usize,
+        /// Index of the sprite sheet.
+        sheet: usize,
+        /// Index of the sprite.
+        index: usize,
+        /// Number of sprites in the sprite sheet.
+        sprite_count: usize,
+    },
+    /// A frame refers to a sound file that does not exist.
+    SoundNotFound {
+        /// Sequence that contains the frame.
+        sequence: String,
+        /// Index of the frame within the sequence.
+        frame_index: usize,
+        /// Path to the sound file.
+        sound: PathBuf,
+    },
+    /// A sprite sheet is not used by any frame.
+    SpriteSheetUnused {
+        /// Index of the sprite sheet.
+        sheet: usize,
+        /// Path to the sprite sheet image.
+        sheet_path: String,
+    },
+}
+
+impl fmt::Display for AssetCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "{}:{}: {}", self.path.display(), line, self.kind)
+        } else {
+            write!(f, "{}: {}", self.path.display(), self.kind)
+        }
+    }
+}
+
+impl fmt::Display for AssetCheckErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetCheckErrorKind::Io { message } => write!(f, "Failed to read file: {}", message),
+            AssetCheckErrorKind::Deserialize { message } => {
+                write!(f, "Failed to deserialize file: {}", message)
+            }
+            AssetCheckErrorKind::Extends { message } => {
+                write!(f, "Failed to extend definition: {}", message)
+            }
+            AssetCheckErrorKind::SequenceNotFound {
+                sequence,
+                sequence_name,
+            } => write!(
+                f,
+                "Sequence `{}` refers to sequence `{}` which does not exist.",
+                sequence, sequence_name
+            ),
+            AssetCheckErrorKind::SpawnObjectNotFound {
+                sequence,
+                asset_slug,
+            } => write!(
+                f,
+                "Sequence `{}` spawns object `{}` which does not exist.",
+                sequence, asset_slug
+            ),
+            AssetCheckErrorKind::SpriteSheetNotFound {
+                sequence,
+                frame_index,
+                sheet,
+                sheet_count,
+            } => write!(
+                f,
+                "Sequence `{}` frame {} uses sprite sheet {}, but there are only {} sheets.",
+                sequence, frame_index, sheet, sheet_count
+            ),
+            AssetCheckErrorKind::SpriteIndexOutOfBounds {
+                sequence,
+                frame_index,
+                sheet,
+                index,
+                sprite_count,
+            } => write!(
+                f,
+                "Sequence `{}` frame {} uses sprite {} of sheet {}, \
+                 but the sheet only has {} sprites.",
+                sequence, frame_index, index, sheet, sprite_count
+            ),
+            AssetCheckErrorKind::SoundNotFound {
+                sequence,
+                frame_index,
+                sound,
+            } => write!(
+                f,
+                "Sequence `{}` frame {} plays sound `{}` which does not exist.",
+                sequence,
+                frame_index,
+                sound.display()
+            ),
+            AssetCheckErrorKind::SpriteSheetUnused { sheet, sheet_path } => write!(
+                f,
+                "Sprite sheet {} (`{}`) is not used by any frame.",
+                sheet, sheet_path
+            ),
+        }
+    }
+}
+
+impl error::Error for AssetCheckError {}
@@ -0,0 +1,309 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use asset_loading::{
+    AssetDiscovery, DefinitionFile, ObjectDefinitionMergeError, ObjectDefinitionMerger,
+};
+use asset_model::config::{AssetSlug, AssetType};
+use background_model::config::BackgroundDefinition;
+use character_model::config::{CharacterDefinition, CharacterSequence};
+use energy_model::config::EnergyDefinition;
+use item_model::config::ItemDefinition;
+use map_model::config::MapDefinition;
+use object_model::config::{GameObjectSequence, ObjectDefinition};
+use object_type::ObjectType;
+use sequence_model::config::SequenceNameString;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sprite_model::config::SpritesDefinition;
+use ui_model::config::UiDefinition;
+
+use crate::{
+    AssetCheckError, AssetCheckErrorKind, DefinitionReader, InputReactionsChecker, ObjectChecker,
+    SpritesChecker,
+};
+
+/// Stem of the object definition file.
+const OBJECT_STEM: &str = "object";
+/// Stem of the sprites definition file.
+const SPRITES_STEM: &str = "sprites";
+/// Stem of the map definition file.
+const MAP_STEM: &str = "map";
+/// Stem of the background definition file.
+const BACKGROUND_STEM: &str = "background";
+/// Stem of the UI definition file.
+const UI_STEM: &str = "ui";
+
+/// Slugs and paths of indexed assets.
+#[derive(Clone, Copy, Debug)]
+struct AssetRefs<'f> {
+    /// Slugs of all assets.
+    asset_slugs: &'f HashSet<AssetSlug>,
+    /// Directory of each asset.
+    asset_paths: &'f HashMap<AssetSlug, PathBuf>,
+}
+
+/// Checks all asset definitions in an assets directory.
+#[derive(Debug)]
+pub struct AssetChecker;
+
+impl AssetChecker {
+    /// Returns all errors found in the asset definitions, ordered by asset path.
+    ///
+    /// # Parameters
+    ///
+    /// * `assets_dir`: Path to the assets directory to check.
+    pub fn check(assets_dir: &Path) -> Vec<AssetCheckError> {
+        let asset_index = AssetDiscovery::asset_index(assets_dir);
+        let asset_paths = asset_index
+            .values()
+            .flat_map(|asset_records| asset_records.iter())
+            .map(|asset_record| (asset_record.asset_slug.clone(), asset_record.path.clone()))
+            .collect::<HashMap<AssetSlug, PathBuf>>();
+        let asset_slugs = asset_paths.keys().cloned().collect::<HashSet<AssetSlug>>();
+        let asset_refs = AssetRefs {
+            asset_slugs: &asset_slugs,
+            asset_paths: &asset_paths,
+        };
+
+        let mut asset_records = asset_index
+            .iter()
+            .flat_map(|(asset_type, asset_records)| {
+                asset_records
+                    .iter()
+                    .map(move |asset_record| (*asset_type, asset_record))
+            })
+            .collect::<Vec<_>>();
+        asset_records.sort_by(|(_, asset_record_1), (_, asset_record_2)| {
+            asset_record_1.path.cmp(&asset_record_2.path)
+        });
+
+        asset_records
+            .into_iter()
+            .flat_map(|(asset_type, asset_record)| {
+                Self::check_asset(assets_dir, asset_refs, asset_type, &asset_record.path)
+            })
+            .collect::<Vec<AssetCheckError>>()
+    }
+
+    fn check_asset(
+        assets_dir: &Path,
+        asset_refs: AssetRefs<'_>,
+        asset_type: AssetType,
+        asset_path: &Path,
+    ) -> Vec<AssetCheckError> {
+        match asset_type {
+            AssetType::Object(ObjectType::Character) => {
+                Self::check_object::<CharacterDefinition, _, _, _>(
+                    assets_dir,
+                    asset_refs,
+                    asset_path,
+                    |character_definition| &character_definition.object_definition,
+                    |character_sequence: &CharacterSequence| {
+                        let frames = &character_sequence.object_sequence.sequence.frames;
+                        character_sequence
+                            .input_reactions
+                            .iter()
+                            .chain(frames.iter().map(|frame| &frame.input_reactions))
+                            .flat_map(InputReactionsChecker::sequence_names)
+                            .collect::<Vec<_>>()
+                    },
+                )
+            }
+            AssetType::Object(ObjectType::Energy) => {
+                Self::check_object::<EnergyDefinition, _, _, _>(
+                    assets_dir,
+                    asset_refs,
+                    asset_path,
+                    |energy_definition| &energy_definition.object_definition,
+                    |_| Vec::new(),
+                )
+            }
+            AssetType::Object(ObjectType::Item) => Self::check_object::<ItemDefinition, _, _, _>(
+                assets_dir,
+                asset_refs,
+                asset_path,
+                |item_definition| &item_definition.object_definition,
+                |_| Vec::new(),
+            ),
+            AssetType::Object(ObjectType::TestObject) => Vec::new(),
+            AssetType::Map => Self::check_map(asset_path),
+            AssetType::Ui => Self::check_ui(asset_path),
+        }
+    }
+
+    fn check_object<D, ObjSeq, FnObjDef, FnSeqNames>(
+        assets_dir: &Path,
+        asset_refs: AssetRefs<'_>,
+        asset_path: &Path,
+        fn_object_definition: FnObjDef,
+        fn_sequence_names: FnSeqNames,
+    ) -> Vec<AssetCheckError>
+    where
+        D: DeserializeOwned,
+        ObjSeq: GameObjectSequence,
+        ObjSeq::SequenceName: for<'de> Deserialize<'de> + Serialize,
+        FnObjDef: Fn(&D) -> &ObjectDefinition<ObjSeq>,
+        FnSeqNames: Fn(&ObjSeq) -> Vec<&SequenceNameString<ObjSeq::SequenceName>>,
+    {
+        let (mut sprites_checker, mut asset_check_errors) = Self::sprites_checker(asset_path);
+
+        let definition_path = DefinitionFile::find_or_default(asset_path, OBJECT_STEM).path;
+        let definition_merged = ObjectDefinitionMerger::resolve(&definition_path, |asset_slug| {
+            asset_refs.asset_paths.get(asset_slug).cloned()
+        });
+        let definition = match definition_merged {
+            Ok(Some(definition_merged)) => {
+                DefinitionReader::read_merged::<D>(&definition_path, definition_merged)
+            }
+            Ok(None) => DefinitionReader::read::<D>(&definition_path),
+            // Let the reader report errors in the definition file itself.
+            Err(ObjectDefinitionMergeError::Read { ref path, .. }) if path == &definition_path => {
+                DefinitionReader::read::<D>(&definition_path)
+            }
+            Err(e) => Err(AssetCheckError::new(
+                definition_path.clone(),
+                None,
+                AssetCheckErrorKind::Extends {
+                    message: e.to_string(),
+                },
+            )),
+        };
+        match definition {
+            Ok((definition, contents)) => {
+                asset_check_errors.extend(ObjectChecker::check(
+                    assets_dir,
+                    asset_refs.asset_slugs,
+                    &definition_path,
+                    &contents,
+                    fn_object_definition(&definition),
+                    sprites_checker.as_mut(),
+                    fn_sequence_names,
+                ));
+
+                if let Some(sprites_checker) = sprites_checker {
+                    asset_check_errors.extend(sprites_checker.unused_sheets());
+                }
+            }
+            Err(asset_check_error) => asset_check_errors.push(asset_check_error),
+        }
+
+        asset_check_errors
+    }
+
+    fn check_map(asset_path: &Path) -> Vec<AssetCheckError> {
+        let (mut sprites_checker, mut asset_check_errors) = Self::sprites_checker(asset_path);
+
+        let definition_path = DefinitionFile::find_or_default(asset_path, MAP_STEM).path;
+        match DefinitionReader::read::<MapDefinition>(&definition_path) {
+            Ok((map_definition, contents)) => {
+                asset_check_errors.extend(Self::check_background(
+                    &definition_path,
+                    &contents,
+                    &map_definition.background,
+                    sprites_checker.as_mut(),
+                ));
+
+                if let Some(sprites_checker) = sprites_checker {
+                    asset_check_errors.extend(sprites_checker.unused_sheets());
+                }
+            }
+            Err(asset_check_error) => asset_check_errors.push(asset_check_error),
+        }
+
+        asset_check_errors
+    }
+
+    /// UI sprite sheets may be used by the UI definition, so unused sheets are not reported.
+    fn check_ui(asset_path: &Path) -> Vec<AssetCheckError> {
+        let (mut sprites_checker, mut asset_check_errors) = Self::sprites_checker(asset_path);
+
+        if let Some(DefinitionFile {
+            path: background_definition_path,
+            ..
+        }) = DefinitionFile::find(asset_path, BACKGROUND_STEM)
+        {
+            match DefinitionReader::read::<BackgroundDefinition>(&background_definition_path) {
+                Ok((background_definition, contents)) => {
+                    asset_check_errors.extend(Self::check_background(
+                        &background_definition_path,
+                        &contents,
+                        &background_definition,
+                        sprites_checker.as_mut(),
+                    ));
+                }
+                Err(asset_check_error) => asset_check_errors.push(asset_check_error),
+            }
+        }
+
+        if let Some(DefinitionFile {
+            path: ui_definition_path,
+            ..
+        }) = DefinitionFile::find(asset_path, UI_STEM)
+        {
+            if let Err(asset_check_error) =
+                DefinitionReader::read::<UiDefinition>(&ui_definition_path)
+            {
+                asset_check_errors.push(asset_check_error);
+            }
+        }
+
+        asset_check_errors
+    }
+
+    fn check_background(
+        definition_path: &Path,
+        contents: &str,
+        background_definition: &BackgroundDefinition,
+        mut sprites_checker: Option<&mut SpritesChecker>,
+    ) -> Vec<AssetCheckError> {
+        let mut asset_check_errors = Vec::new();
+
+        if let Some(sprites_checker) = sprites_checker.as_mut() {
+            background_definition
+                .layers
+                .iter()
+                .for_each(|(layer_name, sprite_item)| {
+                    let line = DefinitionReader::key_line(contents, layer_name);
+                    sprite_item.sequence.frames.iter().enumerate().for_each(
+                        |(frame_index, sprite_frame)| {
+                            if let Some(asset_check_error) = sprites_checker.check_sprite_ref(
+                                definition_path,
+                                line,
+                                layer_name,
+                                frame_index,
+                                sprite_frame.sprite,
+                            ) {
+                                asset_check_errors.push(asset_check_error);
+                            }
+                        },
+                    );
+                });
+        }
+
+        asset_check_errors
+    }
+
+    /// Returns the `SpritesChecker` for an asset.
+    ///
+    /// If `sprites.yaml` fails to deserialize, sprite references are not checked, as every
+    /// reference would otherwise be reported.
+    fn sprites_checker(asset_path: &Path) -> (Option<SpritesChecker>, Vec<AssetCheckError>) {
+        if let Some(DefinitionFile {
+            path: sprites_path, ..
+        }) = DefinitionFile::find(asset_path, SPRITES_STEM)
+        {
+            match DefinitionReader::read::<SpritesDefinition>(&sprites_path) {
+                Ok(sprites_definition) => (
+                    Some(SpritesChecker::new(sprites_path, Some(sprites_definition))),
+                    Vec::new(),
+                ),
+                Err(asset_check_error) => (None, vec![asset_check_error]),
+            }
+        } else {
+            let sprites_path = DefinitionFile::find_or_default(asset_path, SPRITES_STEM).path;
+            (Some(SpritesChecker::new(sprites_path, None)), Vec::new())
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use application::Format;
+use asset_fs::AssetFs;
+use serde::de::DeserializeOwned;
+use serde_yaml::Value;
+
+use crate::{AssetCheckError, AssetCheckErrorKind};
+
+/// Reads definition files, recording where they fail to deserialize.
+#[derive(Debug)]
+pub struct DefinitionReader;
+
+impl DefinitionReader {
+    /// Returns the deserialized definition and the file contents.
+    ///
+    /// The definition is deserialized according to the file extension, defaulting to YAML.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the definition file.
+    pub fn read<D>(path: &Path) -> Result<(D, String), AssetCheckError>
+    where
+        D: DeserializeOwned,
+    {
+        let contents = Self::read_to_string(path)?;
+
+        let format = Format::from_path(path).unwrap_or(Format::Yaml);
+        Self::deserialize::<D>(&contents, format)
+            .map(|definition| (definition, contents.clone()))
+            .map_err(|(line, message)| {
+                AssetCheckError::new(
+                    path.to_path_buf(),
+                    line,
+                    AssetCheckErrorKind::Deserialize { message },
+                )
+            })
+    }
+
+    /// Returns the deserialized merged definition and the file contents.
+    ///
+    /// The contents are of the definition file itself, so lines are only found for keys that are
+    /// not inherited.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the definition file.
+    /// * `definition_merged`: Definition merged over the definitions it extends.
+    pub fn read_merged<D>(
+        path: &Path,
+        definition_merged: Value,
+    ) -> Result<(D, String), AssetCheckError>
+    where
+        D: DeserializeOwned,
+    {
+        let contents = Self::read_to_string(path)?;
+
+        serde_yaml::from_value::<D>(definition_merged)
+            .map(|definition| (definition, contents))
+            .map_err(|e| {
+                AssetCheckError::new(
+                    path.to_path_buf(),
+                    None,
+                    AssetCheckErrorKind::Deserialize {
+                        message: e.to_string(),
+                    },
+                )
+            })
+    }
+
+    /// Returns the line of the first nested mapping key with the given name, starting from 1.
+    ///
+    /// This is used to point errors at the sequence or layer they are found in. Keys may be
+    /// quoted, as they are in JSON and RON maps.
+    ///
+    /// # Parameters
+    ///
+    /// * `contents`: Contents of the definition file.
+    /// * `key`: Name of the key to find.
+    pub fn key_line(contents: &str, key: &str) -> Option<usize> {
+        let key_prefix = format!("{}:", key);
+        let key_prefix_quoted = format!("\"{}\":", key);
+        contents
+            .lines()
+            .position(|line| {
+                let line_trimmed = line.trim_start();
+                line_trimmed.len() < line.len()
+                    && (line_trimmed.starts_with(&key_prefix)
+                        || line_trimmed.starts_with(&key_prefix_quoted))
+            })
+            .map(|index| index + 1)
+    }
+
+    /// Returns the deserialized definition, or the error line and message.
+    fn deserialize<D>(contents: &str, format: Format) -> Result<D, (Option<usize>, String)>
+    where
+        D: DeserializeOwned,
+    {
+        match format {
+            Format::Yaml => serde_yaml::from_str::<D>(contents).map_err(|e| {
+                let line = e.location().map(|location| location.line());
+                (line, e.to_string())
+            }),
+            Format::Ron => ron::de::from_str::<D>(contents).map_err(|e| {
+                let line = if let ron::de::Error::Parser(_, position) = &e {
+                    Some(position.line)
+                } else {
+                    None
+                };
+                (line, e.to_string())
+            }),
+            Format::Json => {
+                serde_json::from_str::<D>(contents).map_err(|e| (Some(e.line()), e.to_string()))
+            }
+        }
+    }
+
+    /// Returns the contents of the file, which may be within an asset pack.
+    fn read_to_string(path: &Path) -> Result<String, AssetCheckError> {
+        AssetFs::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| String::from_utf8(bytes).map_err(|e| e.to_string()))
+            .map_err(|message| {
+                AssetCheckError::new(
+                    path.to_path_buf(),
+                    None,
+                    AssetCheckErrorKind::Io { message },
+                )
+            })
+    }
+}
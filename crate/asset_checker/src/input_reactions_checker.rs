@@ -0,0 +1,100 @@
+use input_reaction_model::config::{
+    ButtonInputReactionN, InputReaction, InputReactionSingle, InputReactions,
+};
+use sequence_model::config::{SequenceName, SequenceNameString};
+
+/// Collects the sequence names that input reactions transition to.
+#[derive(Debug)]
+pub struct InputReactionsChecker;
+
+impl InputReactionsChecker {
+    /// Returns the sequence names referenced by the input reactions.
+    ///
+    /// # Parameters
+    ///
+    /// * `input_reactions`: Input reactions to collect sequence names from.
+    pub fn sequence_names<SeqName, IRR>(
+        input_reactions: &InputReactions<SeqName, IRR>,
+    ) -> Vec<&SequenceNameString<SeqName>>
+    where
+        SeqName: SequenceName,
+        IRR: Default,
+    {
+        let InputReactions {
+            press_defend,
+            press_jump,
+            press_attack,
+            press_special,
+            hold_defend,
+            hold_jump,
+            hold_attack,
+            hold_special,
+            release_defend,
+            release_jump,
+            release_attack,
+            release_special,
+            press_x,
+            hold_x,
+            release_x,
+            press_z,
+            hold_z,
+            release_z,
+            fallback,
+            press_button,
+        } = input_reactions;
+
+        let button_input_reactions = press_button
+            .iter()
+            .flat_map(|button_input_reaction_n| match button_input_reaction_n {
+                ButtonInputReactionN::One(button_input_reaction) => vec![button_input_reaction],
+                ButtonInputReactionN::Many(button_input_reactions) => {
+                    button_input_reactions.iter().collect::<Vec<_>>()
+                }
+            })
+            .map(|button_input_reaction| &button_input_reaction.reaction);
+
+        vec![
+            press_defend,
+            press_jump,
+            press_attack,
+            press_special,
+            hold_defend,
+            hold_jump,
+            hold_attack,
+            hold_special,
+            release_defend,
+            release_jump,
+            release_attack,
+            release_special,
+            press_x,
+            hold_x,
+            release_x,
+            press_z,
+            hold_z,
+            release_z,
+            fallback,
+        ]
+        .into_iter()
+        .filter_map(Option::as_ref)
+        .chain(button_input_reactions)
+        .flat_map(Self::input_reaction_sequence_names)
+        .collect::<Vec<_>>()
+    }
+
+    fn input_reaction_sequence_names<SeqName, IRR>(
+        input_reaction: &InputReaction<SeqName, IRR>,
+    ) -> Vec<&SequenceNameString<SeqName>>
+    where
+        SeqName: SequenceName,
+        IRR: Default,
+    {
+        match input_reaction {
+            InputReaction::SequenceNameString(sequence_name_string) => vec![sequence_name_string],
+            InputReaction::Single(InputReactionSingle { next, .. }) => vec![next],
+            InputReaction::Multiple(input_reaction_singles) => input_reaction_singles
+                .iter()
+                .map(|input_reaction_single| &input_reaction_single.next)
+                .collect::<Vec<_>>(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Checks asset definitions for errors without running the game.
+//!
+//! Every definition in the assets directory is deserialized, and references between definitions
+//! are validated. All errors are collected instead of stopping at the first.
+//!
+//! The entry point to using this crate is `AssetChecker::check`.
+
+pub use crate::{
+    asset_check_error::{AssetCheckError, AssetCheckErrorKind},
+    asset_checker::AssetChecker,
+    definition_reader::DefinitionReader,
+    input_reactions_checker::InputReactionsChecker,
+    object_checker::ObjectChecker,
+    sprites_checker::SpritesChecker,
+};
+
+mod asset_check_error;
+mod asset_checker;
+mod definition_reader;
+mod input_reactions_checker;
+mod object_checker;
+mod sprites_checker;
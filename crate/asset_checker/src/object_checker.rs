@@ -0,0 +1,140 @@
+use std::{collections::HashSet, path::Path};
+
+use asset_fs::AssetFs;
+use asset_model::config::AssetSlug;
+use object_model::config::{GameObjectFrame, GameObjectSequence, ObjectDefinition};
+use sequence_model::config::{SequenceEndTransition, SequenceNameString};
+use serde::{Deserialize, Serialize};
+
+use crate::{AssetCheckError, AssetCheckErrorKind, DefinitionReader, SpritesChecker};
+
+/// Checks references in an object definition.
+#[derive(Debug)]
+pub struct ObjectChecker;
+
+impl ObjectChecker {
+    /// Returns errors for references in the object definition that do not resolve.
+    ///
+    /// # Parameters
+    ///
+    /// * `assets_dir`: Path to the assets directory, which sound paths are relative to.
+    /// * `asset_slugs`: Slugs of all assets in the asset index.
+    /// * `definition_path`: Path to the `object.yaml` file.
+    /// * `contents`: Contents of the `object.yaml` file.
+    /// * `object_definition`: The deserialized object definition.
+    /// * `sprites_checker`: Checks sprite references against the object's sprite sheets, if they
+    ///   could be read.
+    /// * `fn_sequence_names`: Returns additional sequence names referenced by a sequence.
+    pub fn check<ObjSeq, FnSeqNames>(
+        assets_dir: &Path,
+        asset_slugs: &HashSet<AssetSlug>,
+        definition_path: &Path,
+        contents: &str,
+        object_definition: &ObjectDefinition<ObjSeq>,
+        mut sprites_checker: Option<&mut SpritesChecker>,
+        fn_sequence_names: FnSeqNames,
+    ) -> Vec<AssetCheckError>
+    where
+        ObjSeq: GameObjectSequence,
+        ObjSeq::SequenceName: for<'de> Deserialize<'de> + Serialize,
+        FnSeqNames: Fn(&ObjSeq) -> Vec<&SequenceNameString<ObjSeq::SequenceName>>,
+    {
+        let sequences = &object_definition.sequences;
+        let mut asset_check_errors = Vec::new();
+
+        sequences.iter().for_each(|(sequence_name, sequence)| {
+            let sequence_label = sequence_name.to_string();
+            let line = DefinitionReader::key_line(contents, &sequence_label);
+            let object_sequence = sequence.object_sequence();
+
+            // Sequence names referenced by this sequence that don't exist, without duplicates.
+            let mut sequence_names_missing = Vec::new();
+            let next = &object_sequence.sequence.next;
+            let sequence_name_next = if let SequenceEndTransition::SequenceName(next) = next {
+                Some(next)
+            } else {
+                None
+            };
+            sequence_name_next
+                .into_iter()
+                .chain(fn_sequence_names(sequence))
+                .filter(|sequence_name_ref| !sequences.contains_key(*sequence_name_ref))
+                .for_each(|sequence_name_ref| {
+                    if !sequence_names_missing.contains(&sequence_name_ref) {
+                        sequence_names_missing.push(sequence_name_ref);
+                    }
+                });
+            asset_check_errors.extend(sequence_names_missing.into_iter().map(
+                |sequence_name_ref| {
+                    AssetCheckError::new(
+                        definition_path.to_path_buf(),
+                        line,
+                        AssetCheckErrorKind::SequenceNotFound {
+                            sequence: sequence_label.clone(),
+                            sequence_name: sequence_name_ref.to_string(),
+                        },
+                    )
+                },
+            ));
+
+            let mut spawn_slugs_missing = Vec::new();
+            object_sequence
+                .sequence
+                .frames
+                .iter()
+                .enumerate()
+                .for_each(|(frame_index, frame)| {
+                    let object_frame = frame.object_frame();
+
+                    let asset_check_error = sprites_checker.as_mut().and_then(|sprites_checker| {
+                        sprites_checker.check_sprite_ref(
+                            definition_path,
+                            line,
+                            &sequence_label,
+                            frame_index,
+                            object_frame.sprite,
+                        )
+                    });
+                    if let Some(asset_check_error) = asset_check_error {
+                        asset_check_errors.push(asset_check_error);
+                    }
+
+                    if let Some(sound) = object_frame.sound.as_ref() {
+                        if !AssetFs::exists(&assets_dir.join(sound)) {
+                            asset_check_errors.push(AssetCheckError::new(
+                                definition_path.to_path_buf(),
+                                line,
+                                AssetCheckErrorKind::SoundNotFound {
+                                    sequence: sequence_label.clone(),
+                                    frame_index,
+                                    sound: sound.clone(),
+                                },
+                            ));
+                        }
+                    }
+
+                    object_frame
+                        .spawns
+                        .iter()
+                        .filter(|spawn| !asset_slugs.contains(&spawn.object))
+                        .for_each(|spawn| {
+                            if !spawn_slugs_missing.contains(&&spawn.object) {
+                                spawn_slugs_missing.push(&spawn.object);
+                            }
+                        });
+                });
+            asset_check_errors.extend(spawn_slugs_missing.into_iter().map(|asset_slug| {
+                AssetCheckError::new(
+                    definition_path.to_path_buf(),
+                    line,
+                    AssetCheckErrorKind::SpawnObjectNotFound {
+                        sequence: sequence_label.clone(),
+                        asset_slug: asset_slug.clone(),
+                    },
+                )
+            }));
+        });
+
+        asset_check_errors
+    }
+}
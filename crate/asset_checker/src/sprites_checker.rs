@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use sprite_model::config::{SpriteRef, SpritesDefinition};
+
+use crate::{AssetCheckError, AssetCheckErrorKind};
+
+/// Checks that sprite references are within the bounds of an asset's sprite sheets.
+///
+/// Sprite sheets that are referenced are tracked, so that unused sheets can be reported.
+#[derive(Debug)]
+pub struct SpritesChecker {
+    /// Path to the `sprites.yaml` file.
+    sprites_path: PathBuf,
+    /// The deserialized `sprites.yaml` and its contents, if it exists.
+    sprites_definition: Option<(SpritesDefinition, String)>,
+    /// Whether each sprite sheet is referenced by a frame.
+    sheets_used: Vec<bool>,
+}
+
+impl SpritesChecker {
+    /// Returns a new `SpritesChecker`.
+    ///
+    /// # Parameters
+    ///
+    /// * `sprites_path`: Path to the `sprites.yaml` file.
+    /// * `sprites_definition`: The deserialized `sprites.yaml` and its contents, if it exists.
+    pub fn new(
+        sprites_path: PathBuf,
+        sprites_definition: Option<(SpritesDefinition, String)>,
+    ) -> Self {
+        let sheet_count = sprites_definition
+            .as_ref()
+            .map(|(sprites_definition, _)| sprites_definition.sheets.len())
+            .unwrap_or(0);
+
+        SpritesChecker {
+            sprites_path,
+            sprites_definition,
+            sheets_used: vec![false; sheet_count],
+        }
+    }
+
+    /// Returns an error if the sprite reference is out of bounds.
+    ///
+    /// # Parameters
+    ///
+    /// * `definition_path`: Path to the file that contains the sprite reference.
+    /// * `line`: Line of the sequence that contains the sprite reference.
+    /// * `sequence`: Name of the sequence that contains the sprite reference.
+    /// * `frame_index`: Index of the frame that contains the sprite reference.
+    /// * `sprite_ref`: The sprite reference.
+    pub fn check_sprite_ref(
+        &mut self,
+        definition_path: &Path,
+        line: Option<usize>,
+        sequence: &str,
+        frame_index: usize,
+        sprite_ref: SpriteRef,
+    ) -> Option<AssetCheckError> {
+        let SpriteRef { sheet, index } = sprite_ref;
+        let sheets = self
+            .sprites_definition
+            .as_ref()
+            .map(|(sprites_definition, _)| sprites_definition.sheets.as_slice())
+            .unwrap_or(&[]);
+
+        let kind = if let Some(sprite_sheet_definition) = sheets.get(sheet) {
+            self.sheets_used[sheet] = true;
+
+            let sprite_count =
+                (sprite_sheet_definition.row_count * sprite_sheet_definition.column_count) as usize;
+            if index < sprite_count {
+                None
+            } else {
+                Some(AssetCheckErrorKind::SpriteIndexOutOfBounds {
+                    sequence: sequence.to_string(),
+                    frame_index,
+                    sheet,
+                    index,
+                    sprite_count,
+                })
+            }
+        } else {
+            Some(AssetCheckErrorKind::SpriteSheetNotFound {
+                sequence: sequence.to_string(),
+                frame_index,
+                sheet,
+                sheet_count: sheets.len(),
+            })
+        };
+
+        kind.map(|kind| AssetCheckError::new(definition_path.to_path_buf(), line, kind))
+    }
+
+    /// Returns errors for each sprite sheet that is not referenced by a frame.
+    pub fn unused_sheets(&self) -> Vec<AssetCheckError> {
+        if let Some((sprites_definition, contents)) = self.sprites_definition.as_ref() {
+            sprites_definition
+                .sheets
+                .iter()
+                .zip(self.sheets_used.iter())
+                .enumerate()
+                .filter(|(_, (_, sheet_used))| !**sheet_used)
+                .map(|(sheet, (sprite_sheet_definition, _))| {
+                    let sheet_path = sprite_sheet_definition.path.clone();
+                    let line = contents
+                        .lines()
+                        .position(|line| line.contains(sheet_path.as_str()))
+                        .map(|index| index + 1);
+
+                    AssetCheckError::new(
+                        self.sprites_path.clone(),
+                        line,
+                        AssetCheckErrorKind::SpriteSheetUnused { sheet, sheet_path },
+                    )
+                })
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        }
+    }
+}
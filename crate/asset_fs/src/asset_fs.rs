@@ -0,0 +1,303 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs,
+    path::Component,
+    sync::{Arc, Mutex},
+};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use lazy_static::lazy_static;
+#[cfg(not(target_arch = "wasm32"))]
+use log::error;
+#[cfg(target_arch = "wasm32")]
+use wasm_support_fs::{DirAccess, PathAccessExt};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{AssetPack, ASSET_PACK_EXTENSION};
+
+// Cache of opened asset packs, along with the modification time of the pack when it was opened.
+#[cfg(not(target_arch = "wasm32"))]
+lazy_static! {
+    static ref ASSET_PACK_CACHE: Arc<Mutex<HashMap<PathBuf, (SystemTime, Arc<AssetPack>)>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+}
+
+/// Accesses asset files regardless of whether they are in a directory or an asset pack.
+///
+/// Any path segment that is an `*.pack` file is treated as a directory whose contents are the
+/// files in the pack. In WASM, paths are looked up on the application server, and asset packs are
+/// not supported.
+#[derive(Debug)]
+pub struct AssetFs;
+
+impl AssetFs {
+    /// Returns whether a file or directory exists at the given path.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to check.
+    pub fn exists(path: &Path) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match Self::pack_entry(path) {
+                Some((asset_pack, entry_path)) => {
+                    asset_pack.is_file(&entry_path) || asset_pack.is_dir(&entry_path)
+                }
+                None => path.exists(),
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            path.exists_on_server()
+        }
+    }
+
+    /// Returns whether a directory exists at the given path.
+    ///
+    /// An asset pack is itself a directory.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to check.
+    pub fn is_dir(path: &Path) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match Self::pack_entry(path) {
+                Some((asset_pack, entry_path)) => asset_pack.is_dir(&entry_path),
+                None => path.is_dir(),
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            path.exists_on_server()
+        }
+    }
+
+    /// Returns the contents of the file at the given path.
+    ///
+    /// Files are not read synchronously in WASM, so this always returns an error there.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the file to read.
+    pub fn read(path: &Path) -> io::Result<Vec<u8>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match Self::pack_entry(path) {
+                Some((asset_pack, entry_path)) => asset_pack
+                    .read(&entry_path)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e)),
+                None => fs::read(path),
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "Synchronously reading `{}` is not supported in WASM.",
+                    path.display()
+                ),
+            ))
+        }
+    }
+
+    /// Returns the size of the file at the given path in bytes.
+    ///
+    /// File sizes are not known in WASM, so this always returns an error there.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the file.
+    pub fn size(path: &Path) -> io::Result<u64> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match Self::pack_entry(path) {
+                Some((asset_pack, entry_path)) => asset_pack
+                    .size(&entry_path)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e)),
+                None => fs::metadata(path).map(|metadata| metadata.len()),
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "Reading the size of `{}` is not supported in WASM.",
+                    path.display()
+                ),
+            ))
+        }
+    }
+
+    /// Returns when the file at the given path was last modified.
+    ///
+    /// Files in an asset pack share the modification time of the pack.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the file.
+    pub fn modified(path: &Path) -> io::Result<SystemTime> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match Self::pack_entry(path) {
+                Some((asset_pack, _entry_path)) => {
+                    fs::metadata(asset_pack.path()).and_then(|metadata| metadata.modified())
+                }
+                None => fs::metadata(path).and_then(|metadata| metadata.modified()),
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = path;
+            Ok(SystemTime::UNIX_EPOCH)
+        }
+    }
+
+    /// Returns the child directories of the given directory.
+    ///
+    /// Symlinks are traversed, and included in the listing if their target is a directory.
+    ///
+    /// # Parameters
+    ///
+    /// * `dir`: Path of the directory to list.
+    pub fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match Self::pack_entry(dir) {
+                Some((asset_pack, entry_path)) => asset_pack
+                    .child_dirs(&entry_path)
+                    .into_iter()
+                    .map(|child_dir| dir.join(child_dir))
+                    .collect::<Vec<PathBuf>>(),
+                None => Self::read_dir_paths(dir)
+                    .into_iter()
+                    .filter(|path| path.is_dir())
+                    .collect::<Vec<PathBuf>>(),
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            DirAccess::child_dirs(dir)
+        }
+    }
+
+    /// Returns the asset packs in the given directory.
+    ///
+    /// Asset packs cannot be listed in WASM, so this always returns an empty list there.
+    ///
+    /// # Parameters
+    ///
+    /// * `dir`: Path of the directory to list.
+    pub fn child_packs(dir: &Path) -> Vec<PathBuf> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut child_packs = Self::read_dir_paths(dir)
+                .into_iter()
+                .filter(|path| {
+                    path.extension() == Some(OsStr::new(ASSET_PACK_EXTENSION)) && path.is_file()
+                })
+                .collect::<Vec<PathBuf>>();
+            child_packs.sort();
+            child_packs
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = dir;
+            Vec::new()
+        }
+    }
+
+    /// Returns the asset pack containing the path, and the path of the entry within the pack.
+    ///
+    /// Returns `None` if the path is not within an asset pack, or the pack fails to be read.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn pack_entry(path: &Path) -> Option<(Arc<AssetPack>, String)> {
+        let pack_path = path.ancestors().find(|ancestor| {
+            ancestor.extension() == Some(OsStr::new(ASSET_PACK_EXTENSION)) && ancestor.is_file()
+        })?;
+        let entry_path = path
+            .strip_prefix(pack_path)
+            .ok()?
+            .components()
+            .map(|component| match component {
+                Component::Normal(segment) => segment.to_str(),
+                _ => None,
+            })
+            .collect::<Option<Vec<&str>>>()?
+            .join("/");
+
+        Self::pack(pack_path).map(|asset_pack| (asset_pack, entry_path))
+    }
+
+    /// Returns the asset pack at the given path, opening it if it is not cached or has changed.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn pack(pack_path: &Path) -> Option<Arc<AssetPack>> {
+        let modified = fs::metadata(pack_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()?;
+        let mut asset_pack_cache = match ASSET_PACK_CACHE.lock() {
+            Ok(asset_pack_cache) => asset_pack_cache,
+            Err(e) => {
+                error!("Failed to lock `ASSET_PACK_CACHE`: {}", e);
+                return None;
+            }
+        };
+
+        match asset_pack_cache.get(pack_path) {
+            Some((cached_modified, asset_pack)) if *cached_modified == modified => {
+                Some(Arc::clone(asset_pack))
+            }
+            _ => match AssetPack::open(pack_path) {
+                Ok(asset_pack) => {
+                    let asset_pack = Arc::new(asset_pack);
+                    asset_pack_cache
+                        .insert(pack_path.to_path_buf(), (modified, Arc::clone(&asset_pack)));
+                    Some(asset_pack)
+                }
+                Err(e) => {
+                    error!("Failed to open asset pack: {}", e);
+                    None
+                }
+            },
+        }
+    }
+
+    /// Returns the paths of the entries in the directory.
+    ///
+    /// Returns an empty list if the directory does not exist. Other errors are logged.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_dir_paths(dir: &Path) -> Vec<PathBuf> {
+        match fs::read_dir(dir) {
+            Ok(read_dir) => read_dir
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .collect::<Vec<PathBuf>>(),
+            Err(io_err) => {
+                if io_err.kind() != io::ErrorKind::NotFound {
+                    error!(
+                        "Failed to read directory: `{}`. Error: `{}`.",
+                        dir.display(),
+                        &io_err
+                    );
+                }
+                Vec::new()
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    fs::File,
+    io::{self, BufReader, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use crate::{AssetPackEntry, AssetPackError};
+
+/// File extension of asset packs.
+pub const ASSET_PACK_EXTENSION: &str = "pack";
+/// Bytes at the start of every asset pack.
+pub(crate) const ASSET_PACK_MAGIC: &[u8; 4] = b"WPAK";
+/// Version of the asset pack format.
+pub(crate) const ASSET_PACK_VERSION: u32 = 1;
+/// Number of bytes of the magic bytes, format version, and entry count.
+const HEADER_LEN: u64 = 4 + 4 + 4;
+/// Number of bytes of an index entry, excluding its path.
+const ENTRY_LEN_MIN: u64 = 4 + 8 + 8 + 4;
+
+/// Namespace directory packed into a single file.
+///
+/// The pack is laid out as follows, with all integers in little endian:
+///
+/// * Magic bytes: `WPAK`.
+/// * Format version: `u32`.
+/// * Entry count: `u32`.
+/// * Per entry: path length `u32`, UTF-8 path, data offset `u64`, data length `u64`, CRC32 `u32`.
+/// * File data.
+///
+/// Only the index is read when the pack is opened -- file data is read on demand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetPack {
+    /// Path to the pack file.
+    path: PathBuf,
+    /// Entries in the pack, keyed by their path.
+    entries: BTreeMap<String, AssetPackEntry>,
+}
+
+impl AssetPack {
+    /// Reads the index of the asset pack at the given path.
+    ///
+    /// Lengths and offsets in the index are checked against the size of the file, so a corrupted
+    /// or malicious pack returns an error instead of allocating or reading beyond the file.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the pack file.
+    pub fn open(path: &Path) -> Result<Self, AssetPackError> {
+        let io_error = |error| AssetPackError::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+        let file = File::open(path).map_err(io_error)?;
+        let pack_len = file.metadata().map_err(io_error)?.len();
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic).map_err(io_error)?;
+        if magic != *ASSET_PACK_MAGIC {
+            return Err(AssetPackError::MagicInvalid {
+                path: path.to_path_buf(),
+            });
+        }
+
+        let version = read_u32(&mut reader).map_err(io_error)?;
+        if version != ASSET_PACK_VERSION {
+            return Err(AssetPackError::VersionUnsupported {
+                path: path.to_path_buf(),
+                version,
+            });
+        }
+
+        let index_truncated = || AssetPackError::IndexTruncated {
+            path: path.to_path_buf(),
+        };
+        let entry_count = read_u32(&mut reader).map_err(io_error)?;
+        let mut index_remaining = pack_len.saturating_sub(HEADER_LEN);
+        if u64::from(entry_count) * ENTRY_LEN_MIN > index_remaining {
+            return Err(index_truncated());
+        }
+
+        let entries = (0..entry_count)
+            .map(|_| {
+                let path_len = read_u32(&mut reader).map_err(io_error)?;
+                index_remaining = index_remaining
+                    .checked_sub(ENTRY_LEN_MIN + u64::from(path_len))
+                    .ok_or_else(index_truncated)?;
+                let mut path_bytes = vec![0u8; path_len as usize];
+                reader.read_exact(&mut path_bytes).map_err(io_error)?;
+                let entry_path =
+                    String::from_utf8(path_bytes).map_err(|_| AssetPackError::PathInvalid {
+                        path: path.to_path_buf(),
+                    })?;
+                let offset = read_u64(&mut reader).map_err(io_error)?;
+                let length = read_u64(&mut reader).map_err(io_error)?;
+                let crc32 = read_u32(&mut reader).map_err(io_error)?;
+                let is_in_bounds = offset
+                    .checked_add(length)
+                    .map(|end| end <= pack_len)
+                    .unwrap_or(false);
+                if !is_in_bounds {
+                    return Err(AssetPackError::EntryOutOfBounds {
+                        path: path.to_path_buf(),
+                        entry_path,
+                        offset,
+                        length,
+                        pack_len,
+                    });
+                }
+
+                Ok((
+                    entry_path.clone(),
+                    AssetPackEntry::new(entry_path, offset, length, crc32),
+                ))
+            })
+            .collect::<Result<BTreeMap<_, _>, AssetPackError>>()?;
+
+        Ok(AssetPack {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// Returns the path to the pack file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the entries in the pack, ordered by path.
+    pub fn entries(&self) -> impl Iterator<Item = &AssetPackEntry> {
+        self.entries.values()
+    }
+
+    /// Returns whether the pack contains a file at the given path.
+    ///
+    /// # Parameters
+    ///
+    /// * `entry_path`: Path of the file within the pack, separated by `/`.
+    pub fn is_file(&self, entry_path: &str) -> bool {
+        self.entries.contains_key(entry_path)
+    }
+
+    /// Returns whether the pack contains files under the given directory path.
+    ///
+    /// The empty path is the pack's root directory.
+    ///
+    /// # Parameters
+    ///
+    /// * `entry_path`: Path of the directory within the pack, separated by `/`.
+    pub fn is_dir(&self, entry_path: &str) -> bool {
+        entry_path.is_empty() || self.dir_entries(entry_path).next().is_some()
+    }
+
+    /// Returns the names of the child directories of the given directory path.
+    ///
+    /// # Parameters
+    ///
+    /// * `entry_path`: Path of the directory within the pack, separated by `/`.
+    pub fn child_dirs(&self, entry_path: &str) -> Vec<String> {
+        let prefix_len = Self::dir_prefix(entry_path).len();
+        let mut child_dirs = self
+            .dir_entries(entry_path)
+            .filter_map(|entry| {
+                let relative_path = &entry.path[prefix_len..];
+                relative_path
+                    .find('/')
+                    .map(|separator_index| relative_path[..separator_index].to_string())
+            })
+            .collect::<Vec<String>>();
+        child_dirs.dedup();
+        child_dirs
+    }
+
+    /// Returns the size of the file at the given path in bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `entry_path`: Path of the file within the pack, separated by `/`.
+    pub fn size(&self, entry_path: &str) -> Result<u64, AssetPackError> {
+        self.entries
+            .get(entry_path)
+            .map(|entry| entry.length)
+            .ok_or_else(|| AssetPackError::EntryNotFound {
+                path: self.path.clone(),
+                entry_path: entry_path.to_string(),
+            })
+    }
+
+    /// Returns the data of the file at the given path.
+    ///
+    /// The data is verified against the checksum recorded in the pack.
+    ///
+    /// # Parameters
+    ///
+    /// * `entry_path`: Path of the file within the pack, separated by `/`.
+    pub fn read(&self, entry_path: &str) -> Result<Vec<u8>, AssetPackError> {
+        let entry = self
+            .entries
+            .get(entry_path)
+            .ok_or_else(|| AssetPackError::EntryNotFound {
+                path: self.path.clone(),
+                entry_path: entry_path.to_string(),
+            })?;
+
+        let io_error = |error| AssetPackError::Io {
+            path: self.path.clone(),
+            error,
+        };
+        let length = usize::try_from(entry.length)
+            .map_err(|e| io_error(io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        let mut file = File::open(&self.path).map_err(io_error)?;
+        file.seek(SeekFrom::Start(entry.offset)).map_err(io_error)?;
+        let mut data = vec![0u8; length];
+        file.read_exact(&mut data).map_err(io_error)?;
+
+        let crc32 = crc32fast::hash(&data);
+        if crc32 == entry.crc32 {
+            Ok(data)
+        } else {
+            Err(AssetPackError::ChecksumMismatch {
+                path: self.path.clone(),
+                entry_path: entry_path.to_string(),
+                expected: entry.crc32,
+                actual: crc32,
+            })
+        }
+    }
+
+    /// Returns the entries under the given directory path.
+    fn dir_entries<'s>(&'s self, entry_path: &str) -> impl Iterator<Item = &'s AssetPackEntry> {
+        let prefix = Self::dir_prefix(entry_path);
+        self.entries
+            .range(prefix.clone()..)
+            .take_while(move |(path, _entry)| path.starts_with(&prefix))
+            .map(|(_path, entry)| entry)
+    }
+
+    /// Returns the prefix of entry paths within the given directory path.
+    fn dir_prefix(entry_path: &str) -> String {
+        if entry_path.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", entry_path)
+        }
+    }
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
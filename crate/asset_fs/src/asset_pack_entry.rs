@@ -0,0 +1,14 @@
+use derive_new::new;
+
+/// Location of a file within an asset pack.
+#[derive(Clone, Debug, PartialEq, Eq, new)]
+pub struct AssetPackEntry {
+    /// Path of the file relative to the namespace directory, separated by `/`.
+    pub path: String,
+    /// Byte offset of the file's data from the start of the pack.
+    pub offset: u64,
+    /// Number of bytes of the file's data.
+    pub length: u64,
+    /// CRC32 checksum of the file's data.
+    pub crc32: u32,
+}
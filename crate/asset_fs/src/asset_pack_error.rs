@@ -0,0 +1,127 @@
+use std::{error, fmt, io, path::PathBuf};
+
+/// Errors when reading or writing an asset pack.
+#[derive(Debug)]
+pub enum AssetPackError {
+    /// Failed to read or write a file.
+    Io {
+        /// Path of the file.
+        path: PathBuf,
+        /// The underlying error.
+        error: io::Error,
+    },
+    /// The file does not begin with the asset pack magic bytes.
+    MagicInvalid {
+        /// Path of the pack.
+        path: PathBuf,
+    },
+    /// The pack was written with an unsupported format version.
+    VersionUnsupported {
+        /// Path of the pack.
+        path: PathBuf,
+        /// Format version recorded in the pack.
+        version: u32,
+    },
+    /// The pack index declares more data than the pack contains.
+    IndexTruncated {
+        /// Path of the pack.
+        path: PathBuf,
+    },
+    /// An entry's data lies beyond the end of the pack.
+    EntryOutOfBounds {
+        /// Path of the pack.
+        path: PathBuf,
+        /// Path of the file within the pack.
+        entry_path: String,
+        /// Offset of the data recorded in the pack index.
+        offset: u64,
+        /// Length of the data recorded in the pack index.
+        length: u64,
+        /// Length of the pack file.
+        pack_len: u64,
+    },
+    /// A file path is not valid unicode.
+    PathInvalid {
+        /// Path of the pack, or the file being packed.
+        path: PathBuf,
+    },
+    /// The pack does not contain the requested file.
+    EntryNotFound {
+        /// Path of the pack.
+        path: PathBuf,
+        /// Path of the file within the pack.
+        entry_path: String,
+    },
+    /// The file's data does not match its recorded checksum.
+    ChecksumMismatch {
+        /// Path of the pack.
+        path: PathBuf,
+        /// Path of the file within the pack.
+        entry_path: String,
+        /// Checksum recorded in the pack index.
+        expected: u32,
+        /// Checksum of the data read.
+        actual: u32,
+    },
+}
+
+impl fmt::Display for AssetPackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => {
+                write!(f, "Failed to access `{}`: {}", path.display(), error)
+            }
+            Self::MagicInvalid { path } => {
+                write!(f, "`{}` is not an asset pack.", path.display())
+            }
+            Self::VersionUnsupported { path, version } => write!(
+                f,
+                "`{}` uses unsupported asset pack version `{}`.",
+                path.display(),
+                version
+            ),
+            Self::IndexTruncated { path } => write!(
+                f,
+                "Index of `{}` is longer than the asset pack.",
+                path.display()
+            ),
+            Self::EntryOutOfBounds {
+                path,
+                entry_path,
+                offset,
+                length,
+                pack_len,
+            } => write!(
+                f,
+                "Data of `{}` at offset `{}` with length `{}` is beyond the end of `{}`, \
+                 which is `{}` bytes long.",
+                entry_path,
+                offset,
+                length,
+                path.display(),
+                pack_len
+            ),
+            Self::PathInvalid { path } => {
+                write!(f, "Path `{}` is not valid unicode.", path.display())
+            }
+            Self::EntryNotFound { path, entry_path } => {
+                write!(f, "`{}` does not contain `{}`.", path.display(), entry_path)
+            }
+            Self::ChecksumMismatch {
+                path,
+                entry_path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Checksum of `{}` in `{}` is `{:08x}`, expected `{:08x}`.",
+                entry_path,
+                path.display(),
+                actual,
+                expected
+            ),
+        }
+    }
+}
+
+impl error::Error for AssetPackError {}
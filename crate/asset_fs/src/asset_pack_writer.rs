@@ -0,0 +1,142 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use log::debug;
+
+use crate::{
+    asset_pack::{ASSET_PACK_MAGIC, ASSET_PACK_VERSION},
+    AssetPackEntry, AssetPackError,
+};
+
+/// Packs a namespace directory into a single file.
+#[derive(Debug)]
+pub struct AssetPackWriter;
+
+impl AssetPackWriter {
+    /// Writes the files in the namespace directory into an asset pack, and returns its entries.
+    ///
+    /// Files are read into memory before being written, so care must be taken to ensure the
+    /// namespace is not large. The pack file itself is skipped if it is written within the
+    /// namespace directory.
+    ///
+    /// # Parameters
+    ///
+    /// * `namespace_dir`: Directory of the namespace to pack.
+    /// * `pack_path`: Path to write the pack file to.
+    pub fn write(
+        namespace_dir: &Path,
+        pack_path: &Path,
+    ) -> Result<Vec<AssetPackEntry>, AssetPackError> {
+        let mut file_paths = Vec::new();
+        Self::file_paths(namespace_dir, &mut file_paths)?;
+
+        let pack_path_canonical = pack_path.canonicalize().ok();
+        let files = file_paths
+            .into_iter()
+            .filter(|file_path| file_path.canonicalize().ok() != pack_path_canonical)
+            .map(|file_path| {
+                let entry_path = Self::entry_path(namespace_dir, &file_path)?;
+                let data = fs::read(&file_path).map_err(|error| AssetPackError::Io {
+                    path: file_path.clone(),
+                    error,
+                })?;
+                Ok((entry_path, data))
+            })
+            .collect::<Result<Vec<(String, Vec<u8>)>, AssetPackError>>()?;
+
+        let index_len = files.iter().fold(12u64, |index_len, (entry_path, _data)| {
+            index_len + 24 + entry_path.len() as u64
+        });
+        let (entries, _) = files.iter().fold(
+            (Vec::with_capacity(files.len()), index_len),
+            |(mut entries, offset), (entry_path, data)| {
+                let length = data.len() as u64;
+                let crc32 = crc32fast::hash(data);
+                entries.push(AssetPackEntry::new(
+                    entry_path.clone(),
+                    offset,
+                    length,
+                    crc32,
+                ));
+                (entries, offset + length)
+            },
+        );
+
+        let io_error = |error| AssetPackError::Io {
+            path: pack_path.to_path_buf(),
+            error,
+        };
+        let mut writer = BufWriter::new(File::create(pack_path).map_err(io_error)?);
+        writer.write_all(ASSET_PACK_MAGIC).map_err(io_error)?;
+        writer
+            .write_all(&ASSET_PACK_VERSION.to_le_bytes())
+            .map_err(io_error)?;
+        writer
+            .write_all(&(entries.len() as u32).to_le_bytes())
+            .map_err(io_error)?;
+        entries
+            .iter()
+            .try_for_each(|entry| {
+                writer.write_all(&(entry.path.len() as u32).to_le_bytes())?;
+                writer.write_all(entry.path.as_bytes())?;
+                writer.write_all(&entry.offset.to_le_bytes())?;
+                writer.write_all(&entry.length.to_le_bytes())?;
+                writer.write_all(&entry.crc32.to_le_bytes())
+            })
+            .map_err(io_error)?;
+        files
+            .iter()
+            .try_for_each(|(_entry_path, data)| writer.write_all(data))
+            .map_err(io_error)?;
+        writer.flush().map_err(io_error)?;
+
+        debug!(
+            "Packed {} files from `{}` into `{}`.",
+            entries.len(),
+            namespace_dir.display(),
+            pack_path.display()
+        );
+
+        Ok(entries)
+    }
+
+    /// Collects the paths of files within the directory, sorted by path.
+    fn file_paths(dir: &Path, file_paths: &mut Vec<PathBuf>) -> Result<(), AssetPackError> {
+        let io_error = |error| AssetPackError::Io {
+            path: dir.to_path_buf(),
+            error,
+        };
+        let mut child_paths = fs::read_dir(dir)
+            .map_err(io_error)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<PathBuf>, _>>()
+            .map_err(io_error)?;
+        child_paths.sort();
+
+        child_paths.into_iter().try_for_each(|child_path| {
+            if child_path.is_dir() {
+                Self::file_paths(&child_path, file_paths)
+            } else {
+                file_paths.push(child_path);
+                Ok(())
+            }
+        })
+    }
+
+    /// Returns the `/` separated path of the file relative to the namespace directory.
+    fn entry_path(namespace_dir: &Path, file_path: &Path) -> Result<String, AssetPackError> {
+        let path_invalid = || AssetPackError::PathInvalid {
+            path: file_path.to_path_buf(),
+        };
+        file_path
+            .strip_prefix(namespace_dir)
+            .map_err(|_| path_invalid())?
+            .components()
+            .map(|component| component.as_os_str().to_str().ok_or_else(path_invalid))
+            .collect::<Result<Vec<&str>, AssetPackError>>()
+            .map(|segments| segments.join("/"))
+    }
+}
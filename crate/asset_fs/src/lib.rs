@@ -0,0 +1,23 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Reads asset files from directories, packed archives, or the application server.
+//!
+//! Namespaces may be distributed as a single `*.pack` file instead of a directory tree. Paths
+//! inside a pack are addressed as if the pack were a directory, e.g.
+//! `assets/download/my_mod.pack/object/character/fighter/object.yaml`.
+//!
+//! In WASM, paths are looked up on the application server using `wasm_support_fs`.
+
+pub use crate::{
+    asset_fs::AssetFs,
+    asset_pack::{AssetPack, ASSET_PACK_EXTENSION},
+    asset_pack_entry::AssetPackEntry,
+    asset_pack_error::AssetPackError,
+    asset_pack_writer::AssetPackWriter,
+};
+
+mod asset_fs;
+mod asset_pack;
+mod asset_pack_entry;
+mod asset_pack_error;
+mod asset_pack_writer;
@@ -1,10 +1,13 @@
 use std::{convert::TryFrom, mem, path::Path};
 
 use asset_model::config::{AssetIndex, AssetType, AssetTypeVariant};
+use log::error;
 use object_type::ObjectType;
 use strum::IntoEnumIterator;
 
-use crate::{AssetIndexer, NamespaceDiscoverer};
+use crate::{
+    AssetIndexer, NamespaceDirectory, NamespaceDiscoverer, NamespaceResolution, NamespaceResolver,
+};
 
 /// Discovers assets across multiple namespaces.
 #[derive(Debug)]
@@ -13,43 +16,73 @@ pub struct AssetDiscovery;
 impl AssetDiscovery {
     /// Returns the asset index of the `assets` directory.
     ///
+    /// Namespaces that are refused during resolution are logged and not indexed.
+    ///
     /// # Parameters
     ///
     /// * `assets_dir`: Path to the assets directory to index.
     pub fn asset_index(assets_dir: &Path) -> AssetIndex {
-        let namespace_directories = NamespaceDiscoverer::discover(assets_dir);
-        let mut asset_index_combined = namespace_directories.iter().map(AssetIndexer::index).fold(
-            AssetIndex::default(),
-            |mut asset_index_combined, mut asset_index| {
-                AssetTypeVariant::iter().for_each(|asset_type_variant| {
-                    if let AssetTypeVariant::Object = asset_type_variant {
-                        ObjectType::iter().for_each(|object_type| {
+        let NamespaceResolution {
+            namespace_directories,
+            errors,
+        } = Self::namespaces(assets_dir);
+        errors.iter().for_each(|e| error!("{}", e));
+
+        Self::asset_index_namespaces(&namespace_directories)
+    }
+
+    /// Returns the namespaces in the `assets` directory, resolved into load order.
+    ///
+    /// # Parameters
+    ///
+    /// * `assets_dir`: Path to the assets directory.
+    pub fn namespaces(assets_dir: &Path) -> NamespaceResolution {
+        NamespaceResolver::resolve(NamespaceDiscoverer::discover(assets_dir))
+    }
+
+    /// Returns the asset index of the given namespaces.
+    ///
+    /// Records are ordered by namespace load order, then by slug within each namespace.
+    ///
+    /// # Parameters
+    ///
+    /// * `namespace_directories`: Namespace directories in load order.
+    pub fn asset_index_namespaces(namespace_directories: &[NamespaceDirectory]) -> AssetIndex {
+        namespace_directories
+            .iter()
+            .map(|namespace_directory| {
+                let mut asset_index = AssetIndexer::index(namespace_directory);
+                asset_index.values_mut().for_each(|asset_records| {
+                    asset_records.sort_unstable_by(|r1, r2| r1.asset_slug.cmp(&r2.asset_slug))
+                });
+                asset_index
+            })
+            .fold(
+                AssetIndex::default(),
+                |mut asset_index_combined, mut asset_index| {
+                    AssetTypeVariant::iter().for_each(|asset_type_variant| {
+                        if let AssetTypeVariant::Object = asset_type_variant {
+                            ObjectType::iter().for_each(|object_type| {
+                                Self::asset_index_merge(
+                                    &mut asset_index_combined,
+                                    &mut asset_index,
+                                    AssetType::Object(object_type),
+                                );
+                            });
+                        } else {
                             Self::asset_index_merge(
                                 &mut asset_index_combined,
                                 &mut asset_index,
-                                AssetType::Object(object_type),
-                            );
-                        });
-                    } else {
-                        Self::asset_index_merge(
-                            &mut asset_index_combined,
-                            &mut asset_index,
-                            AssetType::try_from(asset_type_variant).unwrap_or_else(|e| {
-                                panic!("Expected `AssetType::try_from({:?})` to succeed.", e)
-                            }),
-                        )
-                    }
-                });
-
-                asset_index_combined
-            },
-        );
-
-        asset_index_combined.values_mut().for_each(|asset_records| {
-            asset_records.sort_unstable_by(|r1, r2| r1.asset_slug.cmp(&r2.asset_slug))
-        });
+                                AssetType::try_from(asset_type_variant).unwrap_or_else(|e| {
+                                    panic!("Expected `AssetType::try_from({:?})` to succeed.", e)
+                                }),
+                            )
+                        }
+                    });
 
-        asset_index_combined
+                    asset_index_combined
+                },
+            )
     }
 
     fn asset_index_merge(
@@ -0,0 +1,35 @@
+use std::{path::PathBuf, time::UNIX_EPOCH};
+
+use amethyst::{
+    assets::Source,
+    error::{format_err, ResultExt},
+    Error,
+};
+use asset_fs::AssetFs;
+use derive_new::new;
+
+/// Asset `Source` that reads files from directories and asset packs.
+///
+/// This replaces the `Loader`'s default directory source, so that assets within packed
+/// namespaces are loaded in the same way as assets in namespace directories.
+#[derive(Debug, new)]
+pub struct AssetFsSource {
+    /// Directory that relative asset paths are resolved against.
+    base_dir: PathBuf,
+}
+
+impl Source for AssetFsSource {
+    fn modified(&self, path: &str) -> Result<u64, Error> {
+        let path = self.base_dir.join(path);
+        AssetFs::modified(&path)
+            .with_context(|_| format_err!("Failed to read metadata of `{}`", path.display()))?
+            .duration_since(UNIX_EPOCH)
+            .with_context(|_| format_err!("Modification time of `{}` is invalid", path.display()))
+            .map(|duration| duration.as_secs())
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let path = self.base_dir.join(path);
+        AssetFs::read(&path).with_context(|_| format_err!("Failed to read `{}`", path.display()))
+    }
+}
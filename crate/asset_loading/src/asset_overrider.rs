@@ -0,0 +1,94 @@
+use std::mem;
+
+use asset_model::{
+    config::{AssetIndex, AssetSlug},
+    loaded::OverriddenAssetPaths,
+};
+use log::error;
+
+use crate::NamespaceDirectory;
+
+/// Replaces assets with assets from override namespaces.
+#[derive(Debug)]
+pub struct AssetOverrider;
+
+impl AssetOverrider {
+    /// Replaces the paths of overridden assets with the overriding asset's path.
+    ///
+    /// Each asset listed in an override namespace's `overrides` is replaced by the asset with the
+    /// same name in the override namespace. The overriding asset's own record is removed, so that
+    /// it is only accessible through the slug it overrides.
+    ///
+    /// Returns the original paths of the overridden assets.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_index`: Asset index to apply overrides to.
+    /// * `namespace_directories`: Namespace directories that are loaded.
+    /// * `override_namespaces`: Namespaces whose overrides are enabled.
+    pub fn apply(
+        asset_index: &mut AssetIndex,
+        namespace_directories: &[NamespaceDirectory],
+        override_namespaces: &[String],
+    ) -> OverriddenAssetPaths {
+        let mut overridden_asset_paths = OverriddenAssetPaths::default();
+
+        namespace_directories
+            .iter()
+            .filter(|namespace_directory| {
+                override_namespaces.contains(&namespace_directory.namespace)
+            })
+            .filter_map(|namespace_directory| {
+                namespace_directory
+                    .manifest
+                    .as_ref()
+                    .map(|manifest| (&namespace_directory.namespace, &manifest.overrides))
+            })
+            .flat_map(|(namespace, overrides)| {
+                overrides
+                    .iter()
+                    .map(move |asset_slug| (namespace, asset_slug))
+            })
+            .for_each(|(namespace, asset_slug)| {
+                let asset_slug_override = AssetSlug {
+                    namespace: namespace.clone(),
+                    name: asset_slug.name.clone(),
+                };
+                if asset_slug_override == *asset_slug {
+                    error!("Namespace `{}` cannot override its own asset.", namespace);
+                    return;
+                }
+
+                let overridden = asset_index.values_mut().find_map(|asset_records| {
+                    let index_override = asset_records
+                        .iter()
+                        .position(|asset_record| asset_record.asset_slug == asset_slug_override)?;
+                    let index_target = asset_records
+                        .iter()
+                        .position(|asset_record| &asset_record.asset_slug == asset_slug)?;
+
+                    let path_override = asset_records.remove(index_override).path;
+                    let index_target = if index_override < index_target {
+                        index_target - 1
+                    } else {
+                        index_target
+                    };
+                    let asset_record_target = &mut asset_records[index_target];
+
+                    Some(mem::replace(&mut asset_record_target.path, path_override))
+                });
+
+                if let Some(path_original) = overridden {
+                    overridden_asset_paths.insert(asset_slug.clone(), path_original);
+                } else {
+                    error!(
+                        "Namespace `{}` overrides `{}`, but there is no `{}` asset of the same \
+                         type to replace it with.",
+                        namespace, asset_slug, asset_slug_override
+                    );
+                }
+            });
+
+        overridden_asset_paths
+    }
+}
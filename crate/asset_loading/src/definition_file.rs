@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+
+use application::Format;
+use asset_fs::AssetFs;
+use log::warn;
+
+/// Formats that definition files may be written in, in order of precedence.
+pub const DEFINITION_FORMATS: &[Format] = &[Format::Yaml, Format::Ron, Format::Json];
+
+/// Definition file within an asset directory, and the format it is written in.
+///
+/// Definitions may be written in any of the `DEFINITION_FORMATS`, e.g. `object.yaml`,
+/// `object.ron`, or `object.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DefinitionFile {
+    /// Path to the definition file.
+    pub path: PathBuf,
+    /// Format of the definition file.
+    pub format: Format,
+}
+
+impl DefinitionFile {
+    /// Returns the definition file with the given stem, if it exists in any supported format.
+    ///
+    /// If the definition exists in multiple formats, the first in `DEFINITION_FORMATS` is used.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_dir`: Directory of the asset.
+    /// * `stem`: File name of the definition without its extension, e.g. `"object"`.
+    pub fn find(asset_dir: &Path, stem: &str) -> Option<DefinitionFile> {
+        let mut definition_files = DEFINITION_FORMATS
+            .iter()
+            .map(|format| Self::with_format(asset_dir, stem, *format))
+            .filter(|definition_file| AssetFs::exists(&definition_file.path));
+
+        let definition_file = definition_files.next();
+        if let Some(definition_file) = definition_file.as_ref() {
+            definition_files.for_each(|definition_file_ignored| {
+                warn!(
+                    "Ignoring `{}` as `{}` exists.",
+                    definition_file_ignored.path.display(),
+                    definition_file.path.display()
+                )
+            });
+        }
+
+        definition_file
+    }
+
+    /// Returns the definition file with the given stem, defaulting to YAML if none exist.
+    ///
+    /// This is used when the definition is required, so that errors refer to a file path.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_dir`: Directory of the asset.
+    /// * `stem`: File name of the definition without its extension, e.g. `"object"`.
+    pub fn find_or_default(asset_dir: &Path, stem: &str) -> DefinitionFile {
+        Self::find(asset_dir, stem)
+            .unwrap_or_else(|| Self::with_format(asset_dir, stem, Format::Yaml))
+    }
+
+    /// Returns the paths of the definition file with the given stem in every supported format.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_dir`: Directory of the asset.
+    /// * `stem`: File name of the definition without its extension, e.g. `"object"`.
+    pub fn candidate_paths(asset_dir: &Path, stem: &str) -> Vec<PathBuf> {
+        DEFINITION_FORMATS
+            .iter()
+            .map(|format| Self::with_format(asset_dir, stem, *format).path)
+            .collect::<Vec<PathBuf>>()
+    }
+
+    fn with_format(asset_dir: &Path, stem: &str, format: Format) -> DefinitionFile {
+        let path = asset_dir.join(format!("{}.{}", stem, format.extension()));
+
+        DefinitionFile { path, format }
+    }
+}
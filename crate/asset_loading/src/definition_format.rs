@@ -0,0 +1,51 @@
+use amethyst::{
+    assets::Format as AmethystFormat,
+    error::{format_err, ResultExt},
+    Error,
+};
+use application::{AppFile, Format};
+use derive_new::new;
+use serde::Deserialize;
+#[cfg(target_arch = "wasm32")]
+use serde_yaml::Value;
+
+#[cfg(target_arch = "wasm32")]
+use crate::EXTENDS_KEY;
+
+/// Format for loading definition files, deserialized according to the file's format.
+///
+/// In WASM, definitions that `extends` another definition fail to load with an error, as the
+/// definitions they extend cannot be read synchronously to be merged.
+#[derive(Clone, Copy, Debug, new)]
+pub struct DefinitionFormat {
+    /// Format of the definition file.
+    pub format: Format,
+}
+
+impl<D> AmethystFormat<D> for DefinitionFormat
+where
+    D: for<'a> Deserialize<'a> + Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        stringify!(DefinitionFormat)
+    }
+
+    fn import_simple(&self, bytes: Vec<u8>) -> Result<D, Error> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let extends = AppFile::load_bytes::<Value>(&bytes, self.format)
+                .ok()
+                .and_then(|definition| definition.get(EXTENDS_KEY).cloned());
+            if let Some(extends) = extends {
+                return Err(format_err!(
+                    "Definition extends `{}`, but `{}` is not supported in WASM.",
+                    extends.as_str().unwrap_or_default(),
+                    EXTENDS_KEY
+                ));
+            }
+        }
+
+        AppFile::load_bytes::<D>(&bytes, self.format)
+            .with_context(|_| format_err!("Failed to deserialize {:?} file", self.format))
+    }
+}
@@ -4,9 +4,8 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use asset_fs::AssetFs;
 use log::{error, warn};
-#[cfg(target_arch = "wasm32")]
-use wasm_support_fs::DirAccess;
 
 /// Functions to make directory traversal code more ergonomic.
 #[derive(Debug)]
@@ -16,25 +15,13 @@ impl DirTraverse {
     /// Returns the child directories of the specified directory.
     ///
     /// This will traverse symlinks, and if the target path is a directory, will include it in the
-    /// listing.
+    /// listing. If the directory is within an asset pack, the pack's directories are listed.
     ///
     /// # Parameters
     ///
     /// * `dir`: Path of the directory to list.
     pub fn child_directories(dir: &Path) -> Vec<PathBuf> {
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            Self::entries(dir).map_or_else(Vec::new, |entries| {
-                entries
-                    .filter_map(|entry| Self::entry_to_dir_path_buf(&entry))
-                    .collect::<Vec<_>>()
-            })
-        }
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            DirAccess::child_dirs(dir)
-        }
+        AssetFs::child_dirs(dir)
     }
 
     /// Returns the entries of the specified directory.
@@ -9,24 +9,43 @@
 
 pub use crate::{
     asset_discovery::AssetDiscovery,
+    asset_fs_source::AssetFsSource,
     asset_indexer::AssetIndexer,
     asset_indexing_utils::AssetIndexingUtils,
+    asset_overrider::AssetOverrider,
+    definition_file::{DefinitionFile, DEFINITION_FORMATS},
+    definition_format::DefinitionFormat,
     dir_traverse::DirTraverse,
     flat_indexer::FlatIndexer,
     namespace_directory::NamespaceDirectory,
     namespace_discoverer::{
         NamespaceDiscoverer, ASSETS_DEFAULT_DIR, ASSETS_DOWNLOAD_DIR, ASSETS_TEST_DIR,
+        NAMESPACE_MANIFEST_FILE,
     },
+    namespace_resolution::NamespaceResolution,
+    namespace_resolution_error::NamespaceResolutionError,
+    namespace_resolver::NamespaceResolver,
+    object_definition_merge_error::ObjectDefinitionMergeError,
+    object_definition_merger::{ObjectDefinitionMerger, EXTENDS_KEY, SEQUENCE_MERGE_KEY},
     object_indexer::ObjectIndexer,
     yaml_format::YamlFormat,
 };
 
 mod asset_discovery;
+mod asset_fs_source;
 mod asset_indexer;
 mod asset_indexing_utils;
+mod asset_overrider;
+mod definition_file;
+mod definition_format;
 mod dir_traverse;
 mod flat_indexer;
 mod namespace_directory;
 mod namespace_discoverer;
+mod namespace_resolution;
+mod namespace_resolution_error;
+mod namespace_resolver;
+mod object_definition_merge_error;
+mod object_definition_merger;
 mod object_indexer;
 mod yaml_format;
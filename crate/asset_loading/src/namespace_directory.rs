@@ -1,12 +1,16 @@
 use std::path::PathBuf;
 
+use asset_model::config::NamespaceManifest;
 use derive_new::new;
 
 /// Namespace and its directory path.
-#[derive(Clone, Debug, PartialEq, PartialOrd, new)]
+#[derive(Clone, Debug, PartialEq, new)]
 pub struct NamespaceDirectory {
     /// Namespace, e.g. "test", "default", "user1".
     pub namespace: String,
     /// Path of the directory.
     pub path: PathBuf,
+    /// Manifest read from the directory's `namespace.yaml`, if any.
+    #[new(default)]
+    pub manifest: Option<NamespaceManifest>,
 }
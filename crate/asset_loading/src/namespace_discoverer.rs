@@ -1,9 +1,9 @@
 use std::path::Path;
 
 use application::IoUtils;
+use asset_fs::AssetFs;
+use asset_model::config::NamespaceManifest;
 use log::error;
-#[cfg(target_arch = "wasm32")]
-use wasm_support_fs::PathAccessExt;
 
 use crate::{DirTraverse, NamespaceDirectory};
 
@@ -13,6 +13,8 @@ pub const ASSETS_TEST_DIR: &str = "test";
 pub const ASSETS_DEFAULT_DIR: &str = "default";
 /// Directory under `assets` with downloaded application configuration.
 pub const ASSETS_DOWNLOAD_DIR: &str = "download";
+/// File within a namespace directory with the namespace's metadata.
+pub const NAMESPACE_MANIFEST_FILE: &str = "namespace.yaml";
 
 /// Discovers namespaces in the assets directory.
 #[derive(Debug)]
@@ -26,6 +28,13 @@ impl NamespaceDiscoverer {
     /// * "test"
     /// * "default"
     /// * "download/*"
+    /// * "download/*.pack"
+    ///
+    /// Namespaces packed into an asset pack are named after the pack's file stem, e.g.
+    /// `download/my_mod.pack` is the `my_mod` namespace.
+    ///
+    /// Each namespace's `namespace.yaml` is read if it exists. Namespaces whose manifest fails to
+    /// be read are not returned.
     ///
     /// # Parameters
     ///
@@ -34,8 +43,14 @@ impl NamespaceDiscoverer {
         let dir_download = assets_dir.join(ASSETS_DOWNLOAD_DIR);
         let namespaces_downloaded = DirTraverse::child_directories(&dir_download)
             .into_iter()
+            .chain(AssetFs::child_packs(&dir_download))
             .filter_map(|directory| {
-                let basename = IoUtils::basename(&directory);
+                // Asset packs are named after their file stem.
+                let basename = if directory.is_file() {
+                    IoUtils::basename(&directory.with_extension(""))
+                } else {
+                    IoUtils::basename(&directory)
+                };
                 match basename {
                     Ok(namespace) => Some((namespace, directory)),
                     // kcov-ignore-start
@@ -54,19 +69,50 @@ impl NamespaceDiscoverer {
                 let path = assets_dir.join(&namespace);
                 (namespace, path)
             })
-            .filter(|(_namespace, dir)| {
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    dir.is_dir()
-                }
-
-                #[cfg(target_arch = "wasm32")]
-                {
-                    dir.exists_on_server()
+            .filter(|(_namespace, dir)| AssetFs::is_dir(dir))
+            .chain(namespaces_downloaded)
+            .filter_map(|(namespace, path)| {
+                let manifest = Self::manifest(&path);
+                match manifest {
+                    Ok(manifest) => Some(NamespaceDirectory {
+                        namespace,
+                        path,
+                        manifest,
+                    }),
+                    Err(e) => {
+                        error!("Not loading namespace `{}`: {}", namespace, e);
+                        None
+                    }
                 }
             })
-            .chain(namespaces_downloaded)
-            .map(|(namespace, path)| NamespaceDirectory { namespace, path })
             .collect::<Vec<_>>()
     }
+
+    /// Returns the namespace manifest in the directory, if any.
+    ///
+    /// Manifests are not read in WASM, as files are not accessible synchronously.
+    fn manifest(namespace_dir: &Path) -> Result<Option<NamespaceManifest>, String> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let manifest_path = namespace_dir.join(NAMESPACE_MANIFEST_FILE);
+            if AssetFs::exists(&manifest_path) {
+                AssetFs::read(&manifest_path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|bytes| {
+                        serde_yaml::from_slice::<NamespaceManifest>(&bytes)
+                            .map_err(|e| e.to_string())
+                    })
+                    .map(Some)
+                    .map_err(|e| format!("Failed to read `{}`: {}", manifest_path.display(), e))
+            } else {
+                Ok(None)
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = namespace_dir;
+            Ok(None)
+        }
+    }
 }
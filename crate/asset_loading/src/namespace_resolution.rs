@@ -0,0 +1,12 @@
+use derive_new::new;
+
+use crate::{NamespaceDirectory, NamespaceResolutionError};
+
+/// Namespaces to load and those that were refused.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct NamespaceResolution {
+    /// Namespace directories to load, in load order.
+    pub namespace_directories: Vec<NamespaceDirectory>,
+    /// Reasons namespaces were refused.
+    pub errors: Vec<NamespaceResolutionError>,
+}
@@ -0,0 +1,100 @@
+use std::{error, fmt};
+
+use semver::{Version, VersionReq};
+
+/// Reasons a namespace is refused during namespace resolution.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NamespaceResolutionError {
+    /// A namespace depends on a namespace that was not discovered.
+    DependencyMissing {
+        /// Namespace that declares the dependency.
+        namespace: String,
+        /// Namespace that is depended on.
+        dependency: String,
+        /// Version range required by the namespace.
+        version_req: VersionReq,
+    },
+    /// A dependency's version does not satisfy the required version range.
+    DependencyVersionMismatch {
+        /// Namespace that declares the dependency.
+        namespace: String,
+        /// Namespace that is depended on.
+        dependency: String,
+        /// Version range required by the namespace.
+        version_req: VersionReq,
+        /// Version of the dependency, `None` if it has no manifest.
+        version: Option<Version>,
+    },
+    /// A dependency was itself refused.
+    DependencyRefused {
+        /// Namespace that declares the dependency.
+        namespace: String,
+        /// Namespace that is depended on.
+        dependency: String,
+    },
+    /// Namespaces depend on each other in a cycle.
+    DependencyCycle {
+        /// Namespace within the cycle.
+        namespace: String,
+    },
+}
+
+impl NamespaceResolutionError {
+    /// Returns the namespace that is refused.
+    pub fn namespace(&self) -> &str {
+        match self {
+            Self::DependencyMissing { namespace, .. }
+            | Self::DependencyVersionMismatch { namespace, .. }
+            | Self::DependencyRefused { namespace, .. }
+            | Self::DependencyCycle { namespace } => namespace,
+        }
+    }
+}
+
+impl fmt::Display for NamespaceResolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DependencyMissing {
+                namespace,
+                dependency,
+                version_req,
+            } => write!(
+                f,
+                "Namespace `{}` requires `{} {}`, but `{}` was not found.",
+                namespace, dependency, version_req, dependency
+            ),
+            Self::DependencyVersionMismatch {
+                namespace,
+                dependency,
+                version_req,
+                version,
+            } => match version {
+                Some(version) => write!(
+                    f,
+                    "Namespace `{}` requires `{} {}`, but `{}` is version `{}`.",
+                    namespace, dependency, version_req, dependency, version
+                ),
+                None => write!(
+                    f,
+                    "Namespace `{}` requires `{} {}`, but `{}` has no `namespace.yaml` version.",
+                    namespace, dependency, version_req, dependency
+                ),
+            },
+            Self::DependencyRefused {
+                namespace,
+                dependency,
+            } => write!(
+                f,
+                "Namespace `{}` requires `{}`, which could not be loaded.",
+                namespace, dependency
+            ),
+            Self::DependencyCycle { namespace } => write!(
+                f,
+                "Namespace `{}` is part of a dependency cycle.",
+                namespace
+            ),
+        }
+    }
+}
+
+impl error::Error for NamespaceResolutionError {}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use crate::{NamespaceDirectory, NamespaceResolution, NamespaceResolutionError};
+
+/// Version requirement that namespaces without a manifest satisfy.
+const VERSION_REQ_ANY: &str = "*";
+
+/// Determines which namespaces can be loaded, and the order to load them in.
+#[derive(Debug)]
+pub struct NamespaceResolver;
+
+impl NamespaceResolver {
+    /// Returns the namespaces whose dependencies are met, ordered after their dependencies.
+    ///
+    /// Namespaces that have no dependencies between them keep their discovery order. Namespaces
+    /// without a manifest have no version, and so only satisfy the `"*"` version range.
+    ///
+    /// # Parameters
+    ///
+    /// * `namespace_directories`: Namespace directories in discovery order.
+    pub fn resolve(namespace_directories: Vec<NamespaceDirectory>) -> NamespaceResolution {
+        let mut errors = Vec::new();
+        let mut refused = HashSet::<String>::new();
+
+        // Refusing a namespace may cause its dependents to be refused, so repeat until stable.
+        loop {
+            let error = namespace_directories
+                .iter()
+                .filter(|namespace_directory| !refused.contains(&namespace_directory.namespace))
+                .find_map(|namespace_directory| {
+                    Self::dependency_error(&namespace_directories, &refused, namespace_directory)
+                });
+
+            if let Some(error) = error {
+                refused.insert(error.namespace().to_string());
+                errors.push(error);
+            } else {
+                break;
+            }
+        }
+
+        let mut remaining = namespace_directories
+            .into_iter()
+            .filter(|namespace_directory| !refused.contains(&namespace_directory.namespace))
+            .collect::<Vec<_>>();
+        let mut loaded = HashSet::<String>::new();
+        let mut namespace_directories_ordered = Vec::with_capacity(remaining.len());
+        while let Some(index) = remaining.iter().position(|namespace_directory| {
+            Self::dependencies(namespace_directory).all(|dependency| loaded.contains(dependency))
+        }) {
+            let namespace_directory = remaining.remove(index);
+            loaded.insert(namespace_directory.namespace.clone());
+            namespace_directories_ordered.push(namespace_directory);
+        }
+
+        // Anything left over depends on a cycle, or is part of one.
+        remaining.iter().for_each(|namespace_directory| {
+            let namespace = namespace_directory.namespace.clone();
+            let error = if Self::is_in_cycle(&remaining, namespace_directory) {
+                NamespaceResolutionError::DependencyCycle { namespace }
+            } else {
+                let dependency = Self::dependencies(namespace_directory)
+                    .find(|dependency| !loaded.contains(*dependency))
+                    .cloned()
+                    .unwrap_or_default();
+                NamespaceResolutionError::DependencyRefused {
+                    namespace,
+                    dependency,
+                }
+            };
+            errors.push(error);
+        });
+
+        NamespaceResolution::new(namespace_directories_ordered, errors)
+    }
+
+    /// Returns the first unmet dependency of a namespace, if any.
+    fn dependency_error(
+        namespace_directories: &[NamespaceDirectory],
+        refused: &HashSet<String>,
+        namespace_directory: &NamespaceDirectory,
+    ) -> Option<NamespaceResolutionError> {
+        let manifest = namespace_directory.manifest.as_ref()?;
+        let namespace = &namespace_directory.namespace;
+
+        manifest
+            .dependencies
+            .iter()
+            .find_map(|(dependency, version_req)| {
+                let dependency_directory = namespace_directories
+                    .iter()
+                    .find(|namespace_directory| &namespace_directory.namespace == dependency);
+
+                if let Some(dependency_directory) = dependency_directory {
+                    if refused.contains(dependency) {
+                        return Some(NamespaceResolutionError::DependencyRefused {
+                            namespace: namespace.clone(),
+                            dependency: dependency.clone(),
+                        });
+                    }
+
+                    let version = dependency_directory
+                        .manifest
+                        .as_ref()
+                        .map(|manifest| manifest.version.clone());
+                    let version_matches = match version.as_ref() {
+                        Some(version) => version_req.matches(version),
+                        // `"*"` may parse as a wildcard predicate rather than `VersionReq::any()`.
+                        None => version_req.to_string() == VERSION_REQ_ANY,
+                    };
+
+                    if version_matches {
+                        None
+                    } else {
+                        Some(NamespaceResolutionError::DependencyVersionMismatch {
+                            namespace: namespace.clone(),
+                            dependency: dependency.clone(),
+                            version_req: version_req.clone(),
+                            version,
+                        })
+                    }
+                } else {
+                    Some(NamespaceResolutionError::DependencyMissing {
+                        namespace: namespace.clone(),
+                        dependency: dependency.clone(),
+                        version_req: version_req.clone(),
+                    })
+                }
+            })
+    }
+
+    /// Returns whether a namespace transitively depends on itself.
+    fn is_in_cycle(
+        namespace_directories: &[NamespaceDirectory],
+        namespace_directory: &NamespaceDirectory,
+    ) -> bool {
+        let mut visited = HashSet::<&String>::new();
+        let mut to_visit = Self::dependencies(namespace_directory).collect::<Vec<_>>();
+        while let Some(dependency) = to_visit.pop() {
+            if *dependency == namespace_directory.namespace {
+                return true;
+            }
+            if visited.insert(dependency) {
+                namespace_directories
+                    .iter()
+                    .filter(|namespace_directory| &namespace_directory.namespace == dependency)
+                    .for_each(|dependency_directory| {
+                        to_visit.extend(Self::dependencies(dependency_directory))
+                    });
+            }
+        }
+
+        false
+    }
+
+    fn dependencies<'f>(
+        namespace_directory: &'f NamespaceDirectory,
+    ) -> impl Iterator<Item = &'f String> + 'f {
+        namespace_directory
+            .manifest
+            .iter()
+            .flat_map(|manifest| manifest.dependencies.keys())
+    }
+}
@@ -0,0 +1,67 @@
+use std::{error, fmt, path::PathBuf};
+
+use asset_model::config::AssetSlug;
+
+/// Errors when merging an object definition with the definition it extends.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectDefinitionMergeError {
+    /// Failed to read or parse a definition file.
+    Read {
+        /// Path to the definition file.
+        path: PathBuf,
+        /// Underlying error message.
+        message: String,
+    },
+    /// The `extends` value is not a valid asset slug.
+    ExtendsInvalid {
+        /// Path to the definition file.
+        path: PathBuf,
+        /// Underlying error message.
+        message: String,
+    },
+    /// The asset to extend does not exist.
+    ParentNotFound {
+        /// Path to the definition file.
+        path: PathBuf,
+        /// Slug of the asset to extend.
+        asset_slug: AssetSlug,
+    },
+    /// Definitions extend each other in a cycle.
+    ExtendsCycle {
+        /// Path to the definition file.
+        path: PathBuf,
+        /// Slug of the asset to extend.
+        asset_slug: AssetSlug,
+    },
+}
+
+impl fmt::Display for ObjectDefinitionMergeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Read { path, message } => {
+                write!(f, "Failed to read `{}`: {}", path.display(), message)
+            }
+            Self::ExtendsInvalid { path, message } => write!(
+                f,
+                "`{}` has an invalid `extends` value: {}",
+                path.display(),
+                message
+            ),
+            Self::ParentNotFound { path, asset_slug } => write!(
+                f,
+                "`{}` extends `{}`, which does not exist.",
+                path.display(),
+                asset_slug
+            ),
+            Self::ExtendsCycle { path, asset_slug } => write!(
+                f,
+                "`{}` extends `{}`, which leads back to `{}`.",
+                path.display(),
+                asset_slug,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl error::Error for ObjectDefinitionMergeError {}
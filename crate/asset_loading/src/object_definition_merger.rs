@@ -0,0 +1,319 @@
+use std::path::{Path, PathBuf};
+
+use application::{AppFile, Format};
+use asset_fs::AssetFs;
+use asset_model::config::AssetSlug;
+use serde_yaml::{Mapping, Value};
+
+use crate::{DefinitionFile, ObjectDefinitionMergeError};
+
+/// Key in an object definition that names the asset it extends, e.g. `extends: default/will`.
+pub const EXTENDS_KEY: &str = "extends";
+/// Key in a sequence that merges its fields over the parent's sequence, e.g. `merge: true`.
+pub const SEQUENCE_MERGE_KEY: &str = "merge";
+/// File stem of object definitions within an object asset directory.
+const OBJECT_DEFINITION_STEM: &str = "object";
+/// Key of the sequences in an object definition.
+const SEQUENCES_KEY: &str = "sequences";
+/// File stem of sprites definitions within an asset directory.
+const SPRITES_DEFINITION_STEM: &str = "sprites";
+
+/// Merges object definitions over the definitions they extend.
+///
+/// Top level fields in the extending definition replace the parent's. Sequences replace the
+/// parent's sequence of the same name, unless they specify `merge: true`, in which case their
+/// fields are merged over the parent sequence's fields.
+///
+/// Definitions are merged as YAML values, so a definition may extend one written in a different
+/// format.
+///
+/// An extending asset without its own sprites definition uses the sprites definition and images
+/// of the nearest asset it extends that has one.
+#[derive(Debug)]
+pub struct ObjectDefinitionMerger;
+
+impl ObjectDefinitionMerger {
+    /// Returns the object definition merged over the definitions it extends.
+    ///
+    /// Returns `Ok(None)` if the definition does not extend another.
+    ///
+    /// # Parameters
+    ///
+    /// * `definition_path`: Path to the `object.yaml`, `object.ron`, or `object.json` file.
+    /// * `fn_asset_dir`: Returns the directory of the asset with the given slug.
+    pub fn resolve<F>(
+        definition_path: &Path,
+        fn_asset_dir: F,
+    ) -> Result<Option<Value>, ObjectDefinitionMergeError>
+    where
+        F: Fn(&AssetSlug) -> Option<PathBuf>,
+    {
+        let definition = Self::read(definition_path)?;
+        if Self::extends(definition_path, &definition)?.is_none() {
+            return Ok(None);
+        }
+
+        let mut definition_paths_visited = vec![definition_path.to_path_buf()];
+        Self::resolve_value(
+            definition_path,
+            definition,
+            &fn_asset_dir,
+            &mut definition_paths_visited,
+        )
+        .map(Some)
+    }
+
+    /// Returns the directories of the assets that the definition extends, nearest first.
+    ///
+    /// # Parameters
+    ///
+    /// * `definition_path`: Path to the `object.yaml`, `object.ron`, or `object.json` file.
+    /// * `fn_asset_dir`: Returns the directory of the asset with the given slug.
+    pub fn ancestor_dirs<F>(
+        definition_path: &Path,
+        fn_asset_dir: F,
+    ) -> Result<Vec<PathBuf>, ObjectDefinitionMergeError>
+    where
+        F: Fn(&AssetSlug) -> Option<PathBuf>,
+    {
+        let mut ancestor_dirs = Vec::new();
+        let mut definition_paths_visited = vec![definition_path.to_path_buf()];
+        let mut definition_path = definition_path.to_path_buf();
+        loop {
+            let definition = Self::read(&definition_path)?;
+            if let Some(asset_slug) = Self::extends(&definition_path, &definition)? {
+                let (parent_dir, parent_path) = Self::parent(
+                    &definition_path,
+                    asset_slug,
+                    &fn_asset_dir,
+                    &mut definition_paths_visited,
+                )?;
+                ancestor_dirs.push(parent_dir);
+                definition_path = parent_path;
+            } else {
+                break Ok(ancestor_dirs);
+            }
+        }
+    }
+
+    /// Returns the directory to load an object's sprites definition and images from.
+    ///
+    /// This is the asset's own directory if it has a sprites definition, otherwise the directory
+    /// of the nearest asset it extends that has one. Returns `Ok(None)` if none of them have a
+    /// sprites definition.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_dir`: Directory of the object asset.
+    /// * `fn_asset_dir`: Returns the directory of the asset with the given slug.
+    pub fn sprites_dir<F>(
+        asset_dir: &Path,
+        fn_asset_dir: F,
+    ) -> Result<Option<PathBuf>, ObjectDefinitionMergeError>
+    where
+        F: Fn(&AssetSlug) -> Option<PathBuf>,
+    {
+        let has_sprites_definition =
+            |dir: &Path| DefinitionFile::find(dir, SPRITES_DEFINITION_STEM).is_some();
+        if has_sprites_definition(asset_dir) {
+            return Ok(Some(asset_dir.to_path_buf()));
+        }
+
+        let definition_path =
+            DefinitionFile::find_or_default(asset_dir, OBJECT_DEFINITION_STEM).path;
+        Self::ancestor_dirs(&definition_path, fn_asset_dir).map(|ancestor_dirs| {
+            ancestor_dirs
+                .into_iter()
+                .find(|ancestor_dir| has_sprites_definition(ancestor_dir))
+        })
+    }
+
+    /// Returns the child definition merged over the parent definition.
+    ///
+    /// # Parameters
+    ///
+    /// * `parent`: Definition that is extended.
+    /// * `child`: Definition that extends the parent.
+    pub fn merge(parent: Value, child: Value) -> Value {
+        match (parent, child) {
+            (Value::Mapping(mut parent), Value::Mapping(mut child)) => {
+                parent.remove(&Value::from(EXTENDS_KEY));
+                child.remove(&Value::from(EXTENDS_KEY));
+
+                let sequences_key = Value::from(SEQUENCES_KEY);
+                let sequences_child = child.remove(&sequences_key);
+                Self::merge_mapping_shallow(&mut parent, child);
+
+                if let Some(sequences_child) = sequences_child {
+                    let sequences = match (parent.remove(&sequences_key), sequences_child) {
+                        (
+                            Some(Value::Mapping(sequences_parent)),
+                            Value::Mapping(sequences_child),
+                        ) => {
+                            Value::Mapping(Self::merge_sequences(sequences_parent, sequences_child))
+                        }
+                        (_, sequences_child) => sequences_child,
+                    };
+                    parent.insert(sequences_key, sequences);
+                }
+
+                Value::Mapping(parent)
+            }
+            (_, child) => child,
+        }
+    }
+
+    fn resolve_value<F>(
+        definition_path: &Path,
+        definition: Value,
+        fn_asset_dir: &F,
+        definition_paths_visited: &mut Vec<PathBuf>,
+    ) -> Result<Value, ObjectDefinitionMergeError>
+    where
+        F: Fn(&AssetSlug) -> Option<PathBuf>,
+    {
+        if let Some(asset_slug) = Self::extends(definition_path, &definition)? {
+            let (_parent_dir, parent_path) = Self::parent(
+                definition_path,
+                asset_slug,
+                fn_asset_dir,
+                definition_paths_visited,
+            )?;
+
+            let parent = Self::read(&parent_path)?;
+            let parent =
+                Self::resolve_value(&parent_path, parent, fn_asset_dir, definition_paths_visited)?;
+
+            Ok(Self::merge(parent, definition))
+        } else {
+            Ok(definition)
+        }
+    }
+
+    /// Returns the directory and definition path of the asset that a definition extends.
+    fn parent<F>(
+        definition_path: &Path,
+        asset_slug: AssetSlug,
+        fn_asset_dir: &F,
+        definition_paths_visited: &mut Vec<PathBuf>,
+    ) -> Result<(PathBuf, PathBuf), ObjectDefinitionMergeError>
+    where
+        F: Fn(&AssetSlug) -> Option<PathBuf>,
+    {
+        let parent_dir = fn_asset_dir(&asset_slug).ok_or_else(|| {
+            ObjectDefinitionMergeError::ParentNotFound {
+                path: definition_path.to_path_buf(),
+                asset_slug: asset_slug.clone(),
+            }
+        })?;
+        let parent_path = DefinitionFile::find_or_default(&parent_dir, OBJECT_DEFINITION_STEM).path;
+        if definition_paths_visited.contains(&parent_path) {
+            return Err(ObjectDefinitionMergeError::ExtendsCycle {
+                path: definition_path.to_path_buf(),
+                asset_slug,
+            });
+        }
+        definition_paths_visited.push(parent_path.clone());
+
+        Ok((parent_dir, parent_path))
+    }
+
+    fn read(definition_path: &Path) -> Result<Value, ObjectDefinitionMergeError> {
+        let format = Format::from_path(definition_path).unwrap_or(Format::Yaml);
+        AssetFs::read(definition_path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| {
+                AppFile::load_bytes::<Value>(&bytes, format).map_err(|e| e.to_string())
+            })
+            .map_err(|message| ObjectDefinitionMergeError::Read {
+                path: definition_path.to_path_buf(),
+                message,
+            })
+    }
+
+    fn extends(
+        definition_path: &Path,
+        definition: &Value,
+    ) -> Result<Option<AssetSlug>, ObjectDefinitionMergeError> {
+        let extends = if let Value::Mapping(definition) = definition {
+            definition.get(&Value::from(EXTENDS_KEY))
+        } else {
+            None
+        };
+
+        match extends {
+            Some(Value::String(asset_slug)) => {
+                asset_slug.parse::<AssetSlug>().map(Some).map_err(|e| {
+                    ObjectDefinitionMergeError::ExtendsInvalid {
+                        path: definition_path.to_path_buf(),
+                        message: e.to_string(),
+                    }
+                })
+            }
+            Some(extends) => Err(ObjectDefinitionMergeError::ExtendsInvalid {
+                path: definition_path.to_path_buf(),
+                message: format!(
+                    "Expected an asset slug such as `default/will`: {:?}",
+                    extends
+                ),
+            }),
+            None => Ok(None),
+        }
+    }
+
+    fn merge_sequences(mut sequences_parent: Mapping, sequences_child: Mapping) -> Mapping {
+        sequences_child
+            .into_iter()
+            .for_each(|(sequence_name, mut sequence_child)| {
+                let merge = if let Value::Mapping(sequence_child) = &mut sequence_child {
+                    sequence_child.remove(&Value::from(SEQUENCE_MERGE_KEY))
+                        == Some(Value::Bool(true))
+                } else {
+                    false
+                };
+
+                match sequences_parent.get_mut(&sequence_name) {
+                    Some(sequence_parent) => {
+                        *sequence_parent = if merge {
+                            Self::merge_deep(sequence_parent.clone(), sequence_child)
+                        } else {
+                            sequence_child
+                        };
+                    }
+                    None => {
+                        sequences_parent.insert(sequence_name, sequence_child);
+                    }
+                }
+            });
+
+        sequences_parent
+    }
+
+    /// Replaces the parent's values with the child's, keeping the parent's key order.
+    fn merge_mapping_shallow(parent: &mut Mapping, child: Mapping) {
+        child.into_iter().for_each(|(key, value)| {
+            if let Some(value_parent) = parent.get_mut(&key) {
+                *value_parent = value;
+            } else {
+                parent.insert(key, value);
+            }
+        });
+    }
+
+    /// Merges the child's mappings into the parent's, replacing all other values.
+    fn merge_deep(parent: Value, child: Value) -> Value {
+        match (parent, child) {
+            (Value::Mapping(mut parent), Value::Mapping(child)) => {
+                child.into_iter().for_each(|(key, value)| {
+                    if let Some(value_parent) = parent.get_mut(&key) {
+                        *value_parent = Self::merge_deep(value_parent.clone(), value);
+                    } else {
+                        parent.insert(key, value);
+                    }
+                });
+                Value::Mapping(parent)
+            }
+            (_, child) => child,
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub use self::{
     asset_slug_visitor::AssetSlugVisitor,
     asset_type::{AssetType, AssetTypeVariant},
     index::{AssetIndex, AssetRecord},
+    namespace_manifest::NamespaceManifest,
 };
 
 mod asset_slug;
@@ -15,3 +16,4 @@ mod asset_slug_segment;
 mod asset_slug_visitor;
 pub mod asset_type;
 mod index;
+mod namespace_manifest;
@@ -1,7 +1,11 @@
 use std::{char, fmt, str::FromStr};
 
+use asset_schema::{
+    serde_json::{json, Value},
+    AssetSchema, SchemaGen,
+};
 use derive_builder::Builder;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::config::{AssetSlugBuildError, AssetSlugSegment, AssetSlugVisitor};
 
@@ -61,6 +65,25 @@ impl AssetSlug {
     {
         deserializer.deserialize_str(AssetSlugVisitor)
     }
+
+    /// Serializes `AssetSlug`s as a sequence of strings, such as `[default/fireball]`.
+    pub fn serialize_str_seq<S>(asset_slugs: &[AssetSlug], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(asset_slugs.iter().map(AssetSlug::to_string))
+    }
+
+    /// Deserializes `AssetSlug`s from a sequence of strings, such as `[default/fireball]`.
+    pub fn deserialize_str_seq<'de, D>(deserializer: D) -> Result<Vec<AssetSlug>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|asset_slug_str| AssetSlug::from_str(asset_slug_str).map_err(de::Error::custom))
+            .collect::<Result<Vec<AssetSlug>, D::Error>>()
+    }
 }
 
 impl AssetSlugBuilder {
@@ -107,6 +130,20 @@ impl AssetSlugBuilder {
     }
 }
 
+impl AssetSchema for AssetSlug {
+    fn schema_name() -> Option<String> {
+        Some(String::from("AssetSlug"))
+    }
+
+    fn schema(_schema_gen: &mut SchemaGen) -> Value {
+        json!({
+            "type": "string",
+            "pattern": r"^[^/\s\d][^/\s]*/[^/\s\d][^/\s]*$",
+            "description": "Namespaced reference to an asset, such as `default/fireball`.",
+        })
+    }
+}
+
 impl fmt::Display for AssetSlug {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}/{}", self.namespace, self.name)
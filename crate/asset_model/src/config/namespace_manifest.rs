@@ -0,0 +1,50 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use indexmap::IndexMap;
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+use crate::config::AssetSlug;
+
+/// Metadata of a namespace, read from its `namespace.yaml`.
+///
+/// Namespaces without a manifest have no version, so they may only be depended on with the `"*"`
+/// version requirement.
+///
+/// # Examples
+///
+/// ```yaml
+/// name: "Will Remix"
+/// version: "1.0.0"
+/// authors: ["azriel91"]
+/// dependencies:
+///   default: "*"
+/// overrides:
+///   - "default/will"
+/// ```
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct NamespaceManifest {
+    /// Display name of the namespace.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Version of the namespace.
+    #[asset_schema(with = "String")]
+    pub version: Version,
+    /// Authors of the namespace.
+    #[serde(default)]
+    pub authors: Vec<String>,
+    /// Namespaces that this namespace depends on, and the versions it is compatible with.
+    #[serde(default)]
+    #[asset_schema(with = "IndexMap<String, String>")]
+    pub dependencies: IndexMap<String, VersionReq>,
+    /// Assets in other namespaces that are replaced by this namespace's asset of the same name.
+    ///
+    /// These only take effect when the namespace is enabled as an override namespace.
+    #[serde(
+        default,
+        serialize_with = "AssetSlug::serialize_str_seq",
+        deserialize_with = "AssetSlug::deserialize_str_seq"
+    )]
+    pub overrides: Vec<AssetSlug>,
+}
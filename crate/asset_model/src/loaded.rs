@@ -3,7 +3,8 @@
 pub use self::{
     asset_id::AssetId, asset_id_mappings::AssetIdMappings, asset_item_ids::AssetItemIds,
     asset_type_mappings::AssetTypeMappings, item_id::ItemId, item_ids::ItemIds,
-    slug_and_handle::SlugAndHandle,
+    namespace_manifests::NamespaceManifests, overridden_asset_paths::OverriddenAssetPaths,
+    override_namespaces::OverrideNamespaces, slug_and_handle::SlugAndHandle,
 };
 
 mod asset_id;
@@ -12,4 +13,7 @@ mod asset_item_ids;
 mod asset_type_mappings;
 mod item_id;
 mod item_ids;
+mod namespace_manifests;
+mod overridden_asset_paths;
+mod override_namespaces;
 mod slug_and_handle;
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::NamespaceManifest;
+
+/// Manifests of loaded namespaces, keyed by namespace.
+///
+/// Namespaces without a `namespace.yaml` are not present.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct NamespaceManifests(pub HashMap<String, NamespaceManifest>);
@@ -0,0 +1,12 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::AssetSlug;
+
+/// Original directories of assets that have been replaced by an override namespace's asset.
+///
+/// This allows an overriding asset to `extend` the asset it replaces.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct OverriddenAssetPaths(pub HashMap<AssetSlug, PathBuf>);
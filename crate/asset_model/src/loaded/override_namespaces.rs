@@ -0,0 +1,8 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Namespaces whose asset overrides are enabled.
+///
+/// Overrides declared in the `namespace.yaml` of other namespaces are ignored.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct OverrideNamespaces(pub Vec<String>);
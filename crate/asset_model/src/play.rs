@@ -1,6 +1,9 @@
 //! Contains data types used during game play.
 
-pub use self::{asset_world::AssetWorld, item_id_event::ItemIdEvent};
+pub use self::{
+    asset_reload_event::AssetReloadEvent, asset_world::AssetWorld, item_id_event::ItemIdEvent,
+};
 
+mod asset_reload_event;
 mod asset_world;
 mod item_id_event;
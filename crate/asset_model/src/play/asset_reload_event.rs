@@ -0,0 +1,11 @@
+use crate::loaded::AssetId;
+
+/// Event signalling an asset has been reloaded from disk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AssetReloadEvent {
+    /// All load stages for the asset have completed again.
+    Complete {
+        /// ID of the reloaded asset.
+        asset_id: AssetId,
+    },
+}
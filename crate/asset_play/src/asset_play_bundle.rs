@@ -19,14 +19,19 @@ use asset_ui_model::{
     play::{AssetSelectionHighlightMain, AssetSelectionStatus},
 };
 use audio_model::loaded::SourceSequenceHandles;
-use character_model::loaded::CharacterIrsHandles;
-use chase_model::play::ChaseModeStick;
+use character_model::loaded::{
+    CharacterChargeLevelSfx, CharacterIrsHandles, CharacterSequenceChargeLevelNexts,
+    CharacterSequenceEndTransitions,
+};
+use chase_model::{loaded::ChaseModes, play::ChaseModeStick};
 use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
 use derive_new::new;
+use frame_event_model::loaded::FrameEventsSequenceHandles;
 use game_input_model::play::{
     ButtonInputControlled, InputControlled, NormalInputControlled, SharedInputControlled,
 };
 use input_reaction_model::loaded::InputReactionsSequenceHandles;
+use item_model::loaded::HoldPointSequenceHandles;
 use kinematic_model::{
     config::{PositionInit, ScaleInit, VelocityInit},
     loaded::ObjectAccelerationSequenceHandles,
@@ -39,7 +44,8 @@ use sequence_model::loaded::{SequenceEndTransitions, SequenceId, WaitSequenceHan
 use session_lobby_ui_model::loaded::{SessionCodeLabel, SessionDevicesWidget};
 use spawn_model::loaded::SpawnsSequenceHandles;
 use sprite_model::loaded::{
-    ScaleSequenceHandles, SpriteRenderSequenceHandles, TintSequenceHandles,
+    InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+    SpriteRenderSequenceHandles, TintSequenceHandles,
 };
 use ui_form_model::{config::UiTextInput, loaded::UiForm};
 use ui_label_model::config::UiLabel;
@@ -80,9 +86,17 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
         asset_world.register::<BodySequenceHandles>();
         asset_world.register::<InteractionsSequenceHandles>();
         asset_world.register::<SpawnsSequenceHandles>();
+        asset_world.register::<FrameEventsSequenceHandles>();
         asset_world.register::<TintSequenceHandles>();
         asset_world.register::<ScaleSequenceHandles>();
+        asset_world.register::<SpriteOffsetSequenceHandles>();
+        asset_world.register::<InterpolationSequenceHandles>();
         asset_world.register::<CharacterIrsHandles>();
+        asset_world.register::<CharacterSequenceEndTransitions>();
+        asset_world.register::<CharacterSequenceChargeLevelNexts>();
+        asset_world.register::<CharacterChargeLevelSfx>();
+        asset_world.register::<HoldPointSequenceHandles>();
+        asset_world.register::<ChaseModes>();
         asset_world.register::<InputReactionsSequenceHandles>();
         asset_world.register::<WidgetStatusSequences>();
         asset_world.register::<UiForm>();
@@ -214,6 +228,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             any::type_name::<ItemComponentComponentAugmentSystem<SpawnsSequenceHandles>>(),
             &[],
         );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<FrameEventsSequenceHandles>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<FrameEventsSequenceHandles>>(),
+            &[],
+        );
         builder.add(
             ItemComponentComponentAugmentSystem::<TintSequenceHandles>::new(),
             any::type_name::<ItemComponentComponentAugmentSystem<TintSequenceHandles>>(),
@@ -224,11 +243,49 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             any::type_name::<ItemComponentComponentAugmentSystem<ScaleSequenceHandles>>(),
             &[],
         );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<SpriteOffsetSequenceHandles>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<SpriteOffsetSequenceHandles>>(),
+            &[],
+        );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<InterpolationSequenceHandles>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<InterpolationSequenceHandles>>(),
+            &[],
+        );
         builder.add(
             ItemComponentComponentAugmentSystem::<CharacterIrsHandles>::new(),
             any::type_name::<ItemComponentComponentAugmentSystem<CharacterIrsHandles>>(),
             &[],
         );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<CharacterSequenceEndTransitions>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<CharacterSequenceEndTransitions>>(
+            ),
+            &[],
+        );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<CharacterSequenceChargeLevelNexts>::new(),
+            any::type_name::<
+                ItemComponentComponentAugmentSystem<CharacterSequenceChargeLevelNexts>,
+            >(),
+            &[],
+        );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<CharacterChargeLevelSfx>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<CharacterChargeLevelSfx>>(),
+            &[],
+        );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<HoldPointSequenceHandles>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<HoldPointSequenceHandles>>(),
+            &[],
+        );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<ChaseModes>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<ChaseModes>>(),
+            &[],
+        );
         builder.add(
             ItemComponentComponentAugmentSystem::<InputReactionsSequenceHandles>::new(),
             any::type_name::<ItemComponentComponentAugmentSystem<InputReactionsSequenceHandles>>(),
@@ -253,7 +310,7 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             ItemComponentComponentAugmentSystem::<UiLabel>::new(),
             any::type_name::<ItemComponentComponentAugmentSystem<UiLabel>>(),
             &[
-                // Needed for `Position<f32>` value to be used for `UiTransform`.
+                // Needed for `Position<FixedPoint>` value to be used for `UiTransform`.
                 //
                 // `PositionInit` has special logic to offset the position by parent and offset, and
                 // we want to take advantage of that logic.
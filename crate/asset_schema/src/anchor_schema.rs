@@ -0,0 +1,32 @@
+use serde_json::Value;
+
+use crate::{AssetSchema, EnumSchema, SchemaGen};
+
+/// Schema of `amethyst::ui::Anchor`.
+#[derive(Debug)]
+pub struct AnchorSchema;
+
+impl AssetSchema for AnchorSchema {
+    fn schema_name() -> Option<String> {
+        Some(String::from("Anchor"))
+    }
+
+    fn schema(_schema_gen: &mut SchemaGen) -> Value {
+        [
+            "TopLeft",
+            "TopMiddle",
+            "TopRight",
+            "MiddleLeft",
+            "Middle",
+            "MiddleRight",
+            "BottomLeft",
+            "BottomMiddle",
+            "BottomRight",
+        ]
+        .iter()
+        .fold(EnumSchema::new(false), |enum_schema, name| {
+            enum_schema.unit(name, None)
+        })
+        .into_schema()
+    }
+}
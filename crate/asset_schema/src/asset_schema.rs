@@ -0,0 +1,24 @@
+use serde_json::Value;
+
+use crate::SchemaGen;
+
+/// Types that can describe their serialized form as a JSON schema.
+pub trait AssetSchema {
+    /// Returns the name to store this type's schema under in the root schema's `definitions`.
+    ///
+    /// Types without a name have their schema inlined wherever they are referenced.
+    fn schema_name() -> Option<String> {
+        None
+    }
+
+    /// Returns the JSON schema of this type.
+    ///
+    /// Implementations should use [`SchemaGen::subschema`] for the schemas of nested types.
+    ///
+    /// # Parameters
+    ///
+    /// * `schema_gen`: Generator that tracks the definitions of named types.
+    ///
+    /// [`SchemaGen::subschema`]: struct.SchemaGen.html#method.subschema
+    fn schema(schema_gen: &mut SchemaGen) -> Value;
+}
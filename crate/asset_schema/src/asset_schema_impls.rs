@@ -0,0 +1,203 @@
+//! `AssetSchema` implementations for types outside this workspace.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    marker::PhantomData,
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    path::{Path, PathBuf},
+};
+
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+use smallvec::{Array, SmallVec};
+
+use crate::{AssetSchema, SchemaGen, SchemaUtils};
+
+macro_rules! impl_schema {
+    ($schema:tt, $($ty:ty),+ $(,)?) => {
+        $(
+            impl AssetSchema for $ty {
+                fn schema(_schema_gen: &mut SchemaGen) -> Value {
+                    json!($schema)
+                }
+            }
+        )+
+    };
+}
+
+impl_schema!({ "type": "boolean" }, bool);
+impl_schema!({ "type": "integer" }, i8, i16, i32, i64, i128, isize);
+impl_schema!({ "type": "integer", "minimum": 0 }, u8, u16, u32, u64, u128, usize);
+impl_schema!({ "type": "number" }, f32, f64);
+impl_schema!({ "type": "string" }, str, String, Path, PathBuf);
+impl_schema!({ "type": "string", "minLength": 1, "maxLength": 1 }, char);
+impl_schema!({ "type": "null" }, ());
+impl_schema!(
+    { "type": "integer", "not": { "const": 0 } },
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize,
+);
+impl_schema!(
+    { "type": "integer", "minimum": 1 },
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+);
+
+impl<T> AssetSchema for PhantomData<T>
+where
+    T: ?Sized,
+{
+    fn schema(_schema_gen: &mut SchemaGen) -> Value {
+        json!({ "type": "null" })
+    }
+}
+
+impl<T> AssetSchema for Option<T>
+where
+    T: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        SchemaUtils::nullable(schema_gen.subschema::<T>())
+    }
+}
+
+impl<T> AssetSchema for Box<T>
+where
+    T: AssetSchema + ?Sized,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        schema_gen.subschema::<T>()
+    }
+}
+
+macro_rules! impl_schema_seq {
+    ($($ty:ident),+) => {
+        $(
+            impl<T> AssetSchema for $ty<T>
+            where
+                T: AssetSchema,
+            {
+                fn schema(schema_gen: &mut SchemaGen) -> Value {
+                    SchemaUtils::array(schema_gen.subschema::<T>())
+                }
+            }
+        )+
+    };
+}
+
+impl_schema_seq!(Vec, VecDeque);
+
+impl<T> AssetSchema for [T]
+where
+    T: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        SchemaUtils::array(schema_gen.subschema::<T>())
+    }
+}
+
+impl<A> AssetSchema for SmallVec<A>
+where
+    A: Array,
+    A::Item: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        SchemaUtils::array(schema_gen.subschema::<A::Item>())
+    }
+}
+
+impl<T, S> AssetSchema for HashSet<T, S>
+where
+    T: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        let mut schema = SchemaUtils::array(schema_gen.subschema::<T>());
+        schema["uniqueItems"] = json!(true);
+        schema
+    }
+}
+
+impl<T> AssetSchema for BTreeSet<T>
+where
+    T: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        let mut schema = SchemaUtils::array(schema_gen.subschema::<T>());
+        schema["uniqueItems"] = json!(true);
+        schema
+    }
+}
+
+macro_rules! impl_schema_array {
+    ($($len:literal),+) => {
+        $(
+            impl<T> AssetSchema for [T; $len]
+            where
+                T: AssetSchema,
+            {
+                fn schema(schema_gen: &mut SchemaGen) -> Value {
+                    let item = schema_gen.subschema::<T>();
+                    SchemaUtils::tuple(vec![item; $len])
+                }
+            }
+        )+
+    };
+}
+
+impl_schema_array!(1, 2, 3, 4);
+
+macro_rules! impl_schema_tuple {
+    ($(($($ty:ident),+)),+) => {
+        $(
+            impl<$($ty),+> AssetSchema for ($($ty,)+)
+            where
+                $($ty: AssetSchema),+
+            {
+                fn schema(schema_gen: &mut SchemaGen) -> Value {
+                    SchemaUtils::tuple(vec![$(schema_gen.subschema::<$ty>()),+])
+                }
+            }
+        )+
+    };
+}
+
+impl_schema_tuple!((T0), (T0, T1), (T0, T1, T2), (T0, T1, T2, T3));
+
+impl<K, V, S> AssetSchema for HashMap<K, V, S>
+where
+    K: AssetSchema,
+    V: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        SchemaUtils::map(schema_gen.subschema::<K>(), schema_gen.subschema::<V>())
+    }
+}
+
+impl<K, V> AssetSchema for BTreeMap<K, V>
+where
+    K: AssetSchema,
+    V: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        SchemaUtils::map(schema_gen.subschema::<K>(), schema_gen.subschema::<V>())
+    }
+}
+
+impl<K, V, S> AssetSchema for IndexMap<K, V, S>
+where
+    K: AssetSchema,
+    V: AssetSchema,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        SchemaUtils::map(schema_gen.subschema::<K>(), schema_gen.subschema::<V>())
+    }
+}
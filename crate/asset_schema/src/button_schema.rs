@@ -0,0 +1,56 @@
+use serde_json::{json, Value};
+
+use crate::{AssetSchema, EnumSchema, SchemaGen, SchemaUtils, VirtualKeyCodeSchema};
+
+/// Schema of `amethyst::input::Button`.
+#[derive(Debug)]
+pub struct ButtonSchema;
+
+impl AssetSchema for ButtonSchema {
+    fn schema_name() -> Option<String> {
+        Some(String::from("Button"))
+    }
+
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        let mouse_button = EnumSchema::new(false)
+            .unit("Left", None)
+            .unit("Right", None)
+            .unit("Middle", None)
+            .variant("Other", json!({ "type": "integer", "minimum": 0 }), None)
+            .into_schema();
+        let scroll_direction = EnumSchema::new(false)
+            .unit("ScrollUp", None)
+            .unit("ScrollDown", None)
+            .unit("ScrollLeft", None)
+            .unit("ScrollRight", None)
+            .into_schema();
+        let controller = SchemaUtils::tuple(vec![
+            json!({ "type": "integer", "minimum": 0 }),
+            json!({ "type": "string" }),
+        ]);
+
+        EnumSchema::new(false)
+            .variant(
+                "Key",
+                schema_gen.subschema::<VirtualKeyCodeSchema>(),
+                Some("Keyboard key."),
+            )
+            .variant(
+                "ScanCode",
+                json!({ "type": "integer", "minimum": 0 }),
+                Some("Keyboard scan code."),
+            )
+            .variant("Mouse", mouse_button, Some("Mouse button."))
+            .variant(
+                "MouseWheel",
+                scroll_direction,
+                Some("Mouse wheel direction."),
+            )
+            .variant(
+                "Controller",
+                controller,
+                Some("Controller index and button name."),
+            )
+            .into_schema()
+    }
+}
@@ -0,0 +1,83 @@
+use serde_json::{json, Value};
+
+use crate::SchemaUtils;
+
+/// Builds the JSON schema of an enum.
+///
+/// Variants are externally tagged unless the enum is `#[serde(untagged)]`:
+///
+/// * Unit variants are the variant name.
+/// * Other variants are a map with the variant name as the only key.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnumSchema {
+    /// Whether the enum is `#[serde(untagged)]`.
+    untagged: bool,
+    /// Schemas of each variant.
+    variants: Vec<Value>,
+}
+
+impl EnumSchema {
+    /// Returns a new `EnumSchema`.
+    ///
+    /// # Parameters
+    ///
+    /// * `untagged`: Whether variants are serialized without their name.
+    pub fn new(untagged: bool) -> Self {
+        EnumSchema {
+            untagged,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Adds a unit variant to the schema.
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: Serialized name of the variant.
+    /// * `description`: Description of the variant, if any.
+    pub fn unit(mut self, name: &str, description: Option<&str>) -> Self {
+        let schema = if self.untagged {
+            json!({ "type": "null" })
+        } else {
+            json!({ "const": name })
+        };
+        self.variants
+            .push(SchemaUtils::describe(schema, description));
+        self
+    }
+
+    /// Adds a newtype, tuple, or struct variant to the schema.
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: Serialized name of the variant.
+    /// * `schema`: Schema of the variant's content.
+    /// * `description`: Description of the variant, if any.
+    pub fn variant(mut self, name: &str, schema: Value, description: Option<&str>) -> Self {
+        let schema = if self.untagged {
+            schema
+        } else {
+            json!({
+                "type": "object",
+                "properties": { name: schema },
+                "required": [name],
+                "additionalProperties": false,
+            })
+        };
+        self.variants
+            .push(SchemaUtils::describe(schema, description));
+        self
+    }
+
+    /// Returns the JSON schema.
+    ///
+    /// An enum with no variants matches nothing.
+    pub fn into_schema(mut self) -> Value {
+        match self.variants.len() {
+            0 => json!({ "not": {} }),
+            1 => self.variants.remove(0),
+            _ if self.untagged => json!({ "anyOf": self.variants }),
+            _ => json!({ "oneOf": self.variants }),
+        }
+    }
+}
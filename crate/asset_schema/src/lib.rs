@@ -0,0 +1,39 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Generates JSON schemas that describe the serialized form of asset definitions.
+//!
+//! Types implement [`AssetSchema`] -- usually through `#[derive(AssetSchema)]`, which reads the
+//! type's `serde` attributes -- and the root schema is generated through [`SchemaGen`].
+//!
+//! Types that are not defined in this workspace, or whose serialized form is not described by
+//! their `serde` attributes, may be described by a separate type using
+//! `#[asset_schema(with = "Type")]` on the field.
+//!
+//! [`AssetSchema`]: trait.AssetSchema.html
+//! [`SchemaGen`]: struct.SchemaGen.html
+
+pub use asset_schema_derive::AssetSchema;
+pub use serde_json;
+
+pub use crate::{
+    anchor_schema::AnchorSchema,
+    asset_schema::AssetSchema,
+    button_schema::ButtonSchema,
+    enum_schema::EnumSchema,
+    line_mode_schema::LineModeSchema,
+    object_schema::ObjectSchema,
+    schema_gen::{SchemaGen, DEFINITIONS_PREFIX, JSON_SCHEMA_DRAFT},
+    schema_utils::SchemaUtils,
+    virtual_key_code_schema::VirtualKeyCodeSchema,
+};
+
+mod anchor_schema;
+mod asset_schema;
+mod asset_schema_impls;
+mod button_schema;
+mod enum_schema;
+mod line_mode_schema;
+mod object_schema;
+mod schema_gen;
+mod schema_utils;
+mod virtual_key_code_schema;
@@ -0,0 +1,20 @@
+use serde_json::Value;
+
+use crate::{AssetSchema, EnumSchema, SchemaGen};
+
+/// Schema of `amethyst::ui::LineMode`.
+#[derive(Debug)]
+pub struct LineModeSchema;
+
+impl AssetSchema for LineModeSchema {
+    fn schema_name() -> Option<String> {
+        Some(String::from("LineMode"))
+    }
+
+    fn schema(_schema_gen: &mut SchemaGen) -> Value {
+        EnumSchema::new(false)
+            .unit("Single", Some("Text is displayed on a single line."))
+            .unit("Wrap", Some("Text wraps onto new lines."))
+            .into_schema()
+    }
+}
@@ -0,0 +1,153 @@
+use serde_json::{json, Map, Value};
+
+use crate::SchemaUtils;
+
+/// Builds the JSON schema of a struct with named fields.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectSchema {
+    /// Whether unknown fields are rejected.
+    deny_unknown_fields: bool,
+    /// Schemas of the fields, keyed by serialized name.
+    properties: Map<String, Value>,
+    /// Names of fields that must be specified.
+    required: Vec<String>,
+    /// Schemas of flattened fields that are not objects with known properties.
+    all_of: Vec<Value>,
+    /// Schema of values of unknown fields, from a flattened map.
+    additional_properties: Option<Value>,
+    /// Schema of names of unknown fields, from a flattened map.
+    property_names: Option<Value>,
+}
+
+impl ObjectSchema {
+    /// Returns a new `ObjectSchema`.
+    ///
+    /// # Parameters
+    ///
+    /// * `deny_unknown_fields`: Whether unknown fields are rejected.
+    pub fn new(deny_unknown_fields: bool) -> Self {
+        ObjectSchema {
+            deny_unknown_fields,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a field to the schema.
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: Serialized name of the field.
+    /// * `schema`: Schema of the field.
+    /// * `description`: Description of the field, if any.
+    /// * `required`: Whether the field must be specified.
+    pub fn property(
+        mut self,
+        name: &str,
+        schema: Value,
+        description: Option<&str>,
+        required: bool,
+    ) -> Self {
+        self.properties
+            .insert(name.to_string(), SchemaUtils::describe(schema, description));
+        if required {
+            self.required.push(name.to_string());
+        }
+        self
+    }
+
+    /// Merges the schema of a `#[serde(flatten)]` field into this schema.
+    ///
+    /// Properties of objects are merged directly. Other schemas, such as enums, are added to
+    /// `allOf`, and no longer reject fields belonging to this schema.
+    ///
+    /// # Parameters
+    ///
+    /// * `schema`: Inline schema of the flattened field.
+    pub fn flatten(mut self, schema: Value) -> Self {
+        match schema {
+            Value::Object(mut schema) if schema.get("type") == Some(&json!("object")) => {
+                if let Some(Value::Object(properties)) = schema.remove("properties") {
+                    self.properties.extend(properties);
+                }
+                if let Some(Value::Array(required)) = schema.remove("required") {
+                    let required_self = &mut self.required;
+                    required
+                        .into_iter()
+                        .filter_map(|name| name.as_str().map(String::from))
+                        .for_each(|name| {
+                            if !required_self.contains(&name) {
+                                required_self.push(name);
+                            }
+                        });
+                }
+                if let Some(Value::Array(all_of)) = schema.remove("allOf") {
+                    self.all_of.extend(all_of);
+                }
+                // Flattened structs receive only their own fields, so `false` is not carried over.
+                match schema.remove("additionalProperties") {
+                    None | Some(Value::Bool(_)) => {}
+                    additional_properties => self.additional_properties = additional_properties,
+                }
+                if let Some(property_names) = schema.remove("propertyNames") {
+                    self.property_names = Some(property_names);
+                }
+            }
+            schema => self.all_of.push(Self::unknown_fields_allowed(schema)),
+        }
+        self
+    }
+
+    /// Returns the JSON schema.
+    pub fn into_schema(self) -> Value {
+        let mut schema = Map::new();
+        schema.insert(String::from("type"), json!("object"));
+        if !self.properties.is_empty() {
+            schema.insert(String::from("properties"), Value::Object(self.properties));
+        }
+        if !self.required.is_empty() {
+            schema.insert(String::from("required"), json!(self.required));
+        }
+
+        // `additionalProperties` does not see properties declared within `allOf`, so unknown
+        // fields can only be rejected when there are none.
+        match self.additional_properties {
+            Some(additional_properties) => {
+                schema.insert(String::from("additionalProperties"), additional_properties);
+            }
+            None if self.deny_unknown_fields && self.all_of.is_empty() => {
+                schema.insert(String::from("additionalProperties"), json!(false));
+            }
+            None => {}
+        }
+        if let Some(property_names) = self.property_names {
+            schema.insert(String::from("propertyNames"), property_names);
+        }
+        if !self.all_of.is_empty() {
+            schema.insert(String::from("allOf"), json!(self.all_of));
+        }
+
+        Value::Object(schema)
+    }
+
+    /// Removes `"additionalProperties": false` from the schema and its alternatives.
+    fn unknown_fields_allowed(schema: Value) -> Value {
+        match schema {
+            Value::Object(mut schema) => {
+                if schema.get("additionalProperties") == Some(&json!(false)) {
+                    schema.remove("additionalProperties");
+                }
+                ["allOf", "anyOf", "oneOf"].iter().for_each(|keyword| {
+                    if let Some(Value::Array(alternatives)) = schema.remove(*keyword) {
+                        let alternatives = alternatives
+                            .into_iter()
+                            .map(Self::unknown_fields_allowed)
+                            .collect::<Vec<Value>>();
+                        schema.insert(keyword.to_string(), Value::Array(alternatives));
+                    }
+                });
+                Value::Object(schema)
+            }
+            schema => schema,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::{any, collections::HashMap};
+
+use serde_json::{json, Map, Value};
+
+use crate::AssetSchema;
+
+/// Prefix of references to schemas in the root schema's `definitions`.
+pub const DEFINITIONS_PREFIX: &str = "#/definitions/";
+/// JSON schema draft that generated schemas conform to.
+pub const JSON_SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Generates JSON schemas, tracking the definitions of named types.
+#[derive(Clone, Debug, Default)]
+pub struct SchemaGen {
+    /// Schemas of named types, keyed by definition name.
+    definitions: Map<String, Value>,
+    /// Definition names of named types, keyed by type name.
+    definition_names: HashMap<&'static str, String>,
+}
+
+impl SchemaGen {
+    /// Returns a new `SchemaGen`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the root schema for the given type.
+    ///
+    /// The type's schema is inlined at the root, and the schemas of named types it references are
+    /// stored under `definitions`.
+    pub fn root_schema<T>(mut self) -> Value
+    where
+        T: AssetSchema + ?Sized,
+    {
+        let title = T::schema_name().unwrap_or_else(|| any::type_name::<T>().to_string());
+        let schema = self.inline_schema::<T>();
+
+        let mut root_schema = Map::new();
+        root_schema.insert(String::from("$schema"), json!(JSON_SCHEMA_DRAFT));
+        root_schema.insert(String::from("title"), json!(title));
+        match schema {
+            Value::Object(schema) => root_schema.extend(schema),
+            schema => {
+                root_schema.insert(String::from("allOf"), json!([schema]));
+            }
+        }
+        if !self.definitions.is_empty() {
+            root_schema.insert(String::from("definitions"), Value::Object(self.definitions));
+        }
+
+        Value::Object(root_schema)
+    }
+
+    /// Returns the schema to use when the given type is nested in another.
+    ///
+    /// For named types, this is a reference to the type's definition, which is generated the
+    /// first time the type is encountered. Other types are inlined.
+    pub fn subschema<T>(&mut self) -> Value
+    where
+        T: AssetSchema + ?Sized,
+    {
+        let schema_name = match T::schema_name() {
+            Some(schema_name) => schema_name,
+            None => return T::schema(self),
+        };
+
+        let type_name = any::type_name::<T>();
+        let definition_name = match self.definition_names.get(type_name) {
+            Some(definition_name) => definition_name.clone(),
+            None => {
+                let definition_name = self.definition_name_free(schema_name);
+                self.definition_names
+                    .insert(type_name, definition_name.clone());
+
+                // Reserve the definition before generating the schema, in case the type is
+                // recursive.
+                self.definitions
+                    .insert(definition_name.clone(), Value::Null);
+                let schema = T::schema(self);
+                self.definitions.insert(definition_name.clone(), schema);
+
+                definition_name
+            }
+        };
+
+        json!({ "$ref": format!("{}{}", DEFINITIONS_PREFIX, definition_name) })
+    }
+
+    /// Returns the schema of the given type, with references to definitions resolved.
+    ///
+    /// This is used when the type's fields are merged into another schema, such as for
+    /// `#[serde(flatten)]` fields.
+    pub fn inline_schema<T>(&mut self) -> Value
+    where
+        T: AssetSchema + ?Sized,
+    {
+        let mut schema = T::schema(self);
+        while let Some(definition) = self.definition(&schema) {
+            schema = definition;
+        }
+        schema
+    }
+
+    /// Returns the definition that the schema refers to, if it is only a reference.
+    fn definition(&self, schema: &Value) -> Option<Value> {
+        let schema = schema.as_object()?;
+        let reference = match (schema.get("$ref"), schema.get("allOf")) {
+            (Some(reference), _) => reference,
+            // Schemas wrapped for a description.
+            (None, Some(Value::Array(all_of))) if all_of.len() == 1 => {
+                all_of[0].as_object()?.get("$ref")?
+            }
+            _ => return None,
+        };
+        let definition_name = reference.as_str()?.strip_prefix(DEFINITIONS_PREFIX)?;
+        self.definitions
+            .get(definition_name)
+            .filter(|definition| !definition.is_null())
+            .cloned()
+    }
+
+    /// Returns a definition name that is not used by another type.
+    ///
+    /// Types with the same name in different modules are suffixed with a number.
+    fn definition_name_free(&self, schema_name: String) -> String {
+        if !self.definitions.contains_key(&schema_name) {
+            return schema_name;
+        }
+
+        (2..)
+            .map(|n| format!("{}{}", schema_name, n))
+            .find(|definition_name| !self.definitions.contains_key(definition_name))
+            .expect("Expected a free definition name to exist.")
+    }
+}
@@ -0,0 +1,68 @@
+use serde_json::{json, Value};
+
+/// Functions to build common JSON schemas.
+#[derive(Debug)]
+pub struct SchemaUtils;
+
+impl SchemaUtils {
+    /// Returns the schema with the given description.
+    ///
+    /// References are wrapped in `allOf`, as sibling keywords of `$ref` are ignored by validators.
+    ///
+    /// # Parameters
+    ///
+    /// * `schema`: Schema to describe.
+    /// * `description`: Description to attach, if any.
+    pub fn describe(schema: Value, description: Option<&str>) -> Value {
+        let description = match description {
+            Some(description) => description,
+            None => return schema,
+        };
+
+        match schema {
+            Value::Object(mut schema) if !schema.contains_key("$ref") => {
+                schema.insert(String::from("description"), json!(description));
+                Value::Object(schema)
+            }
+            schema => json!({ "allOf": [schema], "description": description }),
+        }
+    }
+
+    /// Returns the schema of a sequence of values.
+    ///
+    /// # Parameters
+    ///
+    /// * `items`: Schema of each item.
+    pub fn array(items: Value) -> Value {
+        json!({ "type": "array", "items": items })
+    }
+
+    /// Returns the schema of a fixed length sequence of values.
+    ///
+    /// # Parameters
+    ///
+    /// * `items`: Schema of the item at each position.
+    pub fn tuple(items: Vec<Value>) -> Value {
+        let len = items.len();
+        json!({ "type": "array", "items": items, "minItems": len, "maxItems": len })
+    }
+
+    /// Returns the schema of a map.
+    ///
+    /// # Parameters
+    ///
+    /// * `keys`: Schema of each key.
+    /// * `values`: Schema of each value.
+    pub fn map(keys: Value, values: Value) -> Value {
+        json!({ "type": "object", "additionalProperties": values, "propertyNames": keys })
+    }
+
+    /// Returns the schema that also accepts `null`.
+    ///
+    /// # Parameters
+    ///
+    /// * `schema`: Schema of the value when present.
+    pub fn nullable(schema: Value) -> Value {
+        json!({ "anyOf": [schema, { "type": "null" }] })
+    }
+}
@@ -0,0 +1,22 @@
+use serde_json::{json, Value};
+
+use crate::{AssetSchema, SchemaGen};
+
+/// Schema of `amethyst::input::VirtualKeyCode`.
+///
+/// Key names are not enumerated, as the list is long and depends on the windowing library.
+#[derive(Debug)]
+pub struct VirtualKeyCodeSchema;
+
+impl AssetSchema for VirtualKeyCodeSchema {
+    fn schema_name() -> Option<String> {
+        Some(String::from("VirtualKeyCode"))
+    }
+
+    fn schema(_schema_gen: &mut SchemaGen) -> Value {
+        json!({
+            "type": "string",
+            "description": "Name of a keyboard key, e.g. `A`, `Key1`, `Return`, `LShift`.",
+        })
+    }
+}
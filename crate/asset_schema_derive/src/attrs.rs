@@ -0,0 +1,75 @@
+use syn::{Attribute, Lit, Meta, NestedMeta, Type};
+
+/// Returns the type whose schema is used for a field, from `#[asset_schema(with = "..")]`.
+///
+/// # Parameters
+///
+/// * `attrs`: Attributes of the field.
+pub fn schema_with(attrs: &[Attribute]) -> syn::Result<Option<Type>> {
+    let mut with = None;
+    for meta in metas(attrs, "asset_schema")? {
+        match &meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("with") => {
+                if let Lit::Str(lit_str) = &name_value.lit {
+                    with = Some(lit_str.parse::<Type>()?);
+                }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "Unknown `asset_schema` attribute.",
+                ))
+            }
+        }
+    }
+
+    Ok(with)
+}
+
+/// Returns the doc comment, with the leading space of each line removed.
+///
+/// Leading and trailing blank lines are removed.
+pub fn description(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(name_value)) => match name_value.lit {
+                Lit::Str(lit_str) => Some(lit_str.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>();
+
+    let description = lines.join("\n").trim().to_string();
+    if description.is_empty() {
+        None
+    } else {
+        Some(description)
+    }
+}
+
+/// Returns the nested metas of attributes with the given name, e.g. `#[asset_schema(..)]`.
+fn metas(attrs: &[Attribute], attr_name: &str) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident(attr_name) {
+            continue;
+        }
+        if let Meta::List(meta_list) = attr.parse_meta()? {
+            meta_list.nested.into_iter().for_each(|nested_meta| {
+                if let NestedMeta::Meta(meta) = nested_meta {
+                    metas.push(meta);
+                }
+            });
+        }
+    }
+    Ok(metas)
+}
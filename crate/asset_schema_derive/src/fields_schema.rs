@@ -0,0 +1,120 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_derive_internals::{
+    ast::{Field, Style},
+    attr,
+};
+use syn::Type;
+
+use crate::attrs::{description, schema_with};
+
+/// Returns tokens that evaluate to the schema of a struct's or variant's fields.
+///
+/// # Parameters
+///
+/// * `style`: Whether the fields are named, unnamed, or absent.
+/// * `fields`: Fields of the struct or variant, with `serde` attributes applied.
+/// * `deny_unknown_fields`: Whether unknown fields are rejected.
+/// * `default`: Whether missing fields take their default value.
+/// * `field_types`: Collects the types whose schema are used, for trait bounds.
+pub fn fields_schema(
+    style: Style,
+    fields: &[Field],
+    deny_unknown_fields: bool,
+    default: bool,
+    field_types: &mut Vec<Type>,
+) -> syn::Result<TokenStream> {
+    match style {
+        Style::Struct => {
+            let mut calls = Vec::new();
+            for field in fields.iter() {
+                if field.attrs.skip_deserializing() {
+                    continue;
+                }
+
+                let ty = schema_type(field)?;
+                field_types.push(ty.clone());
+                if field.attrs.flatten() {
+                    calls.push(quote! {
+                        .flatten(schema_gen.inline_schema::<#ty>())
+                    });
+                } else {
+                    let name = field.attrs.name().deserialize_name();
+                    let description = option_tokens(&description(&field.original.attrs));
+                    let required =
+                        !(default || has_default(field.attrs.default()) || is_option(field.ty));
+                    calls.push(quote! {
+                        .property(
+                            #name,
+                            schema_gen.subschema::<#ty>(),
+                            #description,
+                            #required,
+                        )
+                    });
+                }
+            }
+
+            Ok(quote! {
+                asset_schema::ObjectSchema::new(#deny_unknown_fields)
+                    #(#calls)*
+                    .into_schema()
+            })
+        }
+        Style::Tuple | Style::Newtype => {
+            let mut types = Vec::new();
+            for field in fields.iter() {
+                if field.attrs.skip_deserializing() {
+                    continue;
+                }
+
+                let ty = schema_type(field)?;
+                field_types.push(ty.clone());
+                types.push(ty);
+            }
+
+            if types.len() == 1 {
+                let ty = &types[0];
+                Ok(quote!(schema_gen.subschema::<#ty>()))
+            } else {
+                Ok(quote! {
+                    asset_schema::SchemaUtils::tuple(vec![#(schema_gen.subschema::<#types>()),*])
+                })
+            }
+        }
+        Style::Unit => Ok(quote!(asset_schema::serde_json::json!({ "type": "null" }))),
+    }
+}
+
+/// Returns tokens for an `Option<&str>`.
+pub fn option_tokens(value: &Option<String>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
+/// Returns whether `#[serde(default)]` or `#[serde(default = "..")]` is specified.
+pub fn has_default(default: &attr::Default) -> bool {
+    match default {
+        attr::Default::None => false,
+        attr::Default::Default | attr::Default::Path(_) => true,
+    }
+}
+
+/// Returns the type whose schema is used for the field.
+pub fn schema_type(field: &Field) -> syn::Result<Type> {
+    Ok(schema_with(&field.original.attrs)?.unwrap_or_else(|| field.ty.clone()))
+}
+
+/// Returns whether the type is an `Option`, which `serde` allows to be missing.
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
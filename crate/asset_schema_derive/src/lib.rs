@@ -0,0 +1,231 @@
+#![recursion_limit = "128"]
+
+//! Provides the `#[derive(AssetSchema)]` macro to describe a type's serialized form as a JSON
+//! schema.
+//!
+//! The schema is derived from the type's `serde` attributes, which are parsed by
+//! `serde_derive_internals` -- the same parser `serde_derive` uses -- so names and defaults match
+//! what `serde` accepts. The following are supported:
+//!
+//! * Container: `rename_all`, `deny_unknown_fields`, `default`, `untagged`, `transparent`,
+//!   `from`, `try_from`.
+//! * Field: `rename`, `default`, `skip`, `skip_deserializing`, `flatten`.
+//! * Variant: `rename`, `skip`, `skip_deserializing`.
+//!
+//! Doc comments are used as descriptions. The schema of a field may be overridden with
+//! `#[asset_schema(with = "Type")]`, for field types that do not implement `AssetSchema`, or use
+//! `deserialize_with`.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use asset_schema::AssetSchema;
+//! use serde::{Deserialize, Serialize};
+//!
+//! /// Position of the widget.
+//! #[derive(AssetSchema, Deserialize, Serialize)]
+//! #[serde(default, deny_unknown_fields)]
+//! pub struct Widget {
+//!     /// Horizontal position.
+//!     pub x: i32,
+//!     /// Button to activate the widget.
+//!     #[asset_schema(with = "asset_schema::ButtonSchema")]
+//!     pub button: Button,
+//! }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use serde_derive_internals::{
+    ast::{Container, Data, Field, Style, Variant},
+    attr::TagType,
+    Ctxt, Derive,
+};
+use syn::{parse_macro_input, parse_quote, DeriveInput, Type};
+
+use crate::{
+    attrs::description,
+    fields_schema::{fields_schema, has_default, option_tokens, schema_type},
+    type_params::mentions_type_param,
+};
+
+mod attrs;
+mod fields_schema;
+mod type_params;
+
+#[proc_macro_derive(AssetSchema, attributes(asset_schema))]
+pub fn derive_asset_schema(item: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(item as DeriveInput);
+
+    asset_schema_impl(ast)
+        .unwrap_or_else(|errors| {
+            let compile_errors = errors.iter().map(syn::Error::to_compile_error);
+            quote!(#(#compile_errors)*)
+        })
+        .into()
+}
+
+fn asset_schema_impl(mut ast: DeriveInput) -> Result<TokenStream2, Vec<syn::Error>> {
+    let mut field_types = Vec::new();
+    let schema = {
+        let ctxt = Ctxt::new();
+        let schema = Container::from_ast(&ctxt, &ast, Derive::Deserialize)
+            .map(|container| container_schema(&container, &mut field_types));
+        ctxt.check()?;
+
+        // `from_ast` only returns `None` when it has reported an error, such as for unions.
+        match schema {
+            Some(schema) => schema.map_err(|e| vec![e])?,
+            None => return Ok(TokenStream2::new()),
+        }
+    };
+    let description = option_tokens(&description(&ast.attrs));
+
+    // Named types are stored as definitions. Generic types are inlined, as each instantiation has
+    // a different schema.
+    let schema_name_fn = if ast.generics.params.is_empty() {
+        let schema_name = ast.ident.to_string();
+        Some(quote! {
+            fn schema_name() -> Option<String> {
+                Some(String::from(#schema_name))
+            }
+        })
+    } else {
+        None
+    };
+
+    let bounded_types = field_types
+        .into_iter()
+        .filter(|ty| mentions_type_param(&ast.generics, ty))
+        .fold(Vec::<Type>::new(), |mut bounded_types, ty| {
+            if !bounded_types.contains(&ty) {
+                bounded_types.push(ty);
+            }
+            bounded_types
+        });
+    if !bounded_types.is_empty() {
+        let where_clause = ast.generics.make_where_clause();
+        bounded_types.iter().for_each(|ty| {
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: asset_schema::AssetSchema));
+        });
+    }
+
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics asset_schema::AssetSchema for #ident #ty_generics #where_clause {
+            #schema_name_fn
+
+            #[allow(unused_variables)]
+            fn schema(
+                schema_gen: &mut asset_schema::SchemaGen,
+            ) -> asset_schema::serde_json::Value {
+                asset_schema::SchemaUtils::describe(#schema, #description)
+            }
+        }
+    })
+}
+
+/// Returns tokens that evaluate to the schema of the type being derived.
+fn container_schema(
+    container: &Container,
+    field_types: &mut Vec<Type>,
+) -> syn::Result<TokenStream2> {
+    let from = container
+        .attrs
+        .type_from()
+        .or_else(|| container.attrs.type_try_from());
+    if let Some(from) = from {
+        field_types.push(from.clone());
+        return Ok(quote!(schema_gen.subschema::<#from>()));
+    }
+
+    match &container.data {
+        Data::Struct(_, fields) if container.attrs.transparent() => {
+            transparent_schema(container, fields, field_types)
+        }
+        Data::Struct(style, fields) => fields_schema(
+            *style,
+            fields,
+            container.attrs.deny_unknown_fields(),
+            has_default(container.attrs.default()),
+            field_types,
+        ),
+        Data::Enum(variants) => enum_schema(container, variants, field_types),
+    }
+}
+
+/// Returns tokens that evaluate to the schema of the single field of a transparent struct.
+fn transparent_schema(
+    container: &Container,
+    fields: &[Field],
+    field_types: &mut Vec<Type>,
+) -> syn::Result<TokenStream2> {
+    // `serde_derive_internals` checks that there is exactly one field that is not skipped.
+    let field = fields
+        .iter()
+        .find(|field| !field.attrs.skip_deserializing())
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &container.ident,
+                "`#[serde(transparent)]` requires a field that is not skipped.",
+            )
+        })?;
+
+    let ty = schema_type(field)?;
+    let schema = quote!(schema_gen.subschema::<#ty>());
+    field_types.push(ty);
+    Ok(schema)
+}
+
+/// Returns tokens that evaluate to the schema of an enum.
+fn enum_schema(
+    container: &Container,
+    variants: &[Variant],
+    field_types: &mut Vec<Type>,
+) -> syn::Result<TokenStream2> {
+    let untagged = match container.attrs.tag() {
+        TagType::External => false,
+        TagType::None => true,
+        TagType::Internal { .. } | TagType::Adjacent { .. } => {
+            return Err(syn::Error::new_spanned(
+                &container.ident,
+                "`AssetSchema` does not support internally or adjacently tagged enums.",
+            ));
+        }
+    };
+
+    let mut calls = Vec::new();
+    for variant in variants.iter() {
+        if variant.attrs.skip_deserializing() {
+            continue;
+        }
+
+        let name = variant.attrs.name().deserialize_name();
+        let description = option_tokens(&description(&variant.original.attrs));
+        if let Style::Unit = variant.style {
+            calls.push(quote!(.unit(#name, #description)));
+        } else {
+            let schema = fields_schema(
+                variant.style,
+                &variant.fields,
+                container.attrs.deny_unknown_fields(),
+                false,
+                field_types,
+            )?;
+            calls.push(quote!(.variant(#name, #schema, #description)));
+        }
+    }
+
+    Ok(quote! {
+        asset_schema::EnumSchema::new(#untagged)
+            #(#calls)*
+            .into_schema()
+    })
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use syn::{visit::Visit, Generics, Ident, Path, Type};
+
+/// Returns whether the type refers to any of the type parameters.
+///
+/// # Parameters
+///
+/// * `generics`: Generics of the type being derived.
+/// * `ty`: Type to check.
+pub fn mentions_type_param(generics: &Generics, ty: &Type) -> bool {
+    let type_params = generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .collect::<HashSet<&Ident>>();
+    if type_params.is_empty() {
+        return false;
+    }
+
+    let mut type_param_visitor = TypeParamVisitor {
+        type_params,
+        mentioned: false,
+    };
+    type_param_visitor.visit_type(ty);
+    type_param_visitor.mentioned
+}
+
+/// Visits paths within a type to find type parameters.
+struct TypeParamVisitor<'g> {
+    type_params: HashSet<&'g Ident>,
+    mentioned: bool,
+}
+
+impl<'g, 'ast> Visit<'ast> for TypeParamVisitor<'g> {
+    fn visit_path(&mut self, path: &'ast Path) {
+        if let Some(segment) = path.segments.first() {
+            if path.leading_colon.is_none() && self.type_params.contains(&segment.ident) {
+                self.mentioned = true;
+            }
+        }
+        syn::visit::visit_path(self, path);
+    }
+}
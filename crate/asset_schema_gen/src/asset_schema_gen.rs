@@ -0,0 +1,126 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use asset_loading::{EXTENDS_KEY, SEQUENCE_MERGE_KEY};
+use asset_model::config::NamespaceManifest;
+use asset_schema::{
+    serde_json::{self, json, Value},
+    AssetSchema, SchemaGen,
+};
+use background_model::config::BackgroundDefinition;
+use character_model::config::CharacterDefinition;
+use collision_audio_model::config::CollisionSfxPaths;
+use energy_model::config::EnergyDefinition;
+use item_model::config::ItemDefinition;
+use map_model::config::MapDefinition;
+use sprite_model::config::SpritesDefinition;
+use survival_model::config::SurvivalWaves;
+use ui_audio_model::config::UiSfxPaths;
+use ui_model::config::UiDefinition;
+
+/// Extension of generated schema files.
+pub const SCHEMA_EXTENSION: &str = "schema.json";
+/// Key of the sequences in an object definition.
+const SEQUENCES_KEY: &str = "sequences";
+
+/// Generates JSON Schemas for every top level asset definition type.
+#[derive(Debug)]
+pub struct AssetSchemaGen;
+
+impl AssetSchemaGen {
+    /// Returns the schema for each asset definition type, keyed by schema name.
+    ///
+    /// The name is the stem of the file the schema should be written to, and is chosen to match
+    /// the definition file it validates where possible, e.g. `sprites` for `sprites.yaml`.
+    pub fn schemas() -> Vec<(&'static str, Value)> {
+        vec![
+            ("background", Self::schema::<BackgroundDefinition>()),
+            ("character", Self::object_schema::<CharacterDefinition>()),
+            ("collision_audio", Self::schema::<CollisionSfxPaths>()),
+            ("energy", Self::object_schema::<EnergyDefinition>()),
+            ("item", Self::object_schema::<ItemDefinition>()),
+            ("map", Self::schema::<MapDefinition>()),
+            ("namespace", Self::schema::<NamespaceManifest>()),
+            ("sprites", Self::schema::<SpritesDefinition>()),
+            ("survival_waves", Self::schema::<SurvivalWaves>()),
+            ("ui", Self::schema::<UiDefinition>()),
+            ("ui_audio", Self::schema::<UiSfxPaths>()),
+        ]
+    }
+
+    /// Writes each schema to `<output_dir>/<name>.schema.json`, returning the written paths.
+    ///
+    /// # Parameters
+    ///
+    /// * `output_dir`: Directory to write the schemas to, created if it does not exist.
+    pub fn write(output_dir: &Path) -> Result<Vec<PathBuf>, io::Error> {
+        fs::create_dir_all(output_dir)?;
+
+        Self::schemas()
+            .into_iter()
+            .map(|(name, schema)| {
+                let schema_path = output_dir.join(format!("{}.{}", name, SCHEMA_EXTENSION));
+                let mut contents = serde_json::to_string_pretty(&schema)?;
+                contents.push('\n');
+                fs::write(&schema_path, contents)?;
+
+                Ok(schema_path)
+            })
+            .collect()
+    }
+
+    fn schema<T>() -> Value
+    where
+        T: AssetSchema,
+    {
+        SchemaGen::new().root_schema::<T>()
+    }
+
+    /// Returns the schema of an object definition, which may extend another asset's definition.
+    ///
+    /// Definitions that specify `extends` need not specify required fields, as they are taken
+    /// from the extended definition. Sequences that specify `merge: true` are merged over the
+    /// extended definition's sequence, so their fields are not validated.
+    fn object_schema<T>() -> Value
+    where
+        T: AssetSchema,
+    {
+        let mut schema = Self::schema::<T>();
+        if let Value::Object(schema) = &mut schema {
+            if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+                properties.insert(
+                    String::from(EXTENDS_KEY),
+                    json!({
+                        "type": "string",
+                        "description": "Slug of the asset whose definition this extends, \
+                            e.g. `default/will`.",
+                    }),
+                );
+
+                if let Some(Value::Object(sequences)) = properties.get_mut(SEQUENCES_KEY) {
+                    if let Some(sequence) = sequences.remove("additionalProperties") {
+                        let sequence_merge = json!({
+                            "type": "object",
+                            "properties": { SEQUENCE_MERGE_KEY: { "const": true } },
+                            "required": [SEQUENCE_MERGE_KEY],
+                        });
+                        sequences.insert(
+                            String::from("additionalProperties"),
+                            json!({ "anyOf": [sequence_merge, sequence] }),
+                        );
+                    }
+                }
+            }
+
+            // Required fields are only required when the definition does not extend another.
+            if let Some(required) = schema.remove("required") {
+                schema.insert(String::from("if"), json!({ "required": [EXTENDS_KEY] }));
+                schema.insert(String::from("else"), json!({ "required": required }));
+            }
+        }
+
+        schema
+    }
+}
@@ -0,0 +1,16 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Generates JSON Schemas for asset definition files.
+//!
+//! Editors that understand JSON Schema can use these to offer completion and validation for the
+//! YAML definitions, e.g. by adding the following line to the top of an `object.yaml`:
+//!
+//! ```yaml
+//! # yaml-language-server: $schema=../../../../schemas/character.schema.json
+//! ```
+//!
+//! The entry point to using this crate is `AssetSchemaGen::write`.
+
+pub use crate::asset_schema_gen::{AssetSchemaGen, SCHEMA_EXTENSION};
+
+mod asset_schema_gen;
@@ -1,3 +1,5 @@
+use std::num::NonZeroIsize;
+
 use game_input_model::config::ControllerId;
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
@@ -12,6 +14,7 @@ use structopt_derive::StructOpt;
 /// * `asset_selection switch -c 0 -s default/heat`
 /// * `asset_selection select -c 0 -s default/heat`
 /// * `asset_selection deselect -c 0`
+/// * `asset_selection variant_switch -c 0 -s 1`
 /// * `asset_selection confirm`
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -65,6 +68,17 @@ pub enum AssetSelectionEventArgs {
         #[structopt(short, long)]
         controller_id: ControllerId,
     },
+    /// Variant of the selected asset has been switched.
+    VariantSwitch {
+        /// Controller ID.
+        ///
+        /// 0 for the first player, 1 for the second player, etcetera.
+        #[structopt(short, long)]
+        controller_id: ControllerId,
+        /// Number of variants to switch by, e.g. "1", "-1".
+        #[structopt(short, long, allow_hyphen_values = true)]
+        skip: NonZeroIsize,
+    },
     /// Asset selections have been confirmed.
     Confirm,
 }
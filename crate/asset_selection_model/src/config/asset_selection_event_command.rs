@@ -1,9 +1,10 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::config::AssetSwitch;
 
 /// Parameters to instantiate a `AssetSelectionEvent`.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum AssetSelectionEventCommand {
     /// Signal to return from the current `State`.
@@ -18,6 +19,8 @@ pub enum AssetSelectionEventCommand {
     Select,
     /// Asset has been deselected.
     Deselect,
+    /// Variant of the selected asset has been switched.
+    VariantSwitch(AssetSwitch),
     /// Asset selections have been confirmed.
     Confirm,
 }
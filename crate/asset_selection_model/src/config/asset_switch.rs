@@ -1,9 +1,10 @@
 use std::num::NonZeroIsize;
 
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 /// Direction to switch asset selection.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum AssetSwitch {
     /// Switch to previous asset.
@@ -1,7 +1,7 @@
 use amethyst::ecs::Entity;
 use game_input_model::config::ControllerId;
 
-use crate::play::AssetSelection;
+use crate::{config::AssetSwitch, play::AssetSelection};
 
 /// Event signalling a change in asset selection.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -57,6 +57,17 @@ pub enum AssetSelectionEvent {
         /// ID of the controller that sent the event.
         controller_id: ControllerId,
     },
+    /// Variant of the selected asset has switched.
+    VariantSwitch {
+        /// Entity that the event originated from.
+        ///
+        /// This may be `None` if sent as a CLI command.
+        entity: Option<Entity>,
+        /// ID of the controller that sent the event.
+        controller_id: ControllerId,
+        /// Direction to switch the variant.
+        variant_switch: AssetSwitch,
+    },
     /// Confirm `AssetSelection`s.
     Confirm,
 }
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use amethyst::{ecs::Read, Error};
 use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
 use asset_selection_model::{
-    config::AssetSelectionEventArgs,
+    config::{AssetSelectionEventArgs, AssetSwitch},
     play::{AssetSelection, AssetSelectionEvent},
 };
 use game_input_model::config::ControllerId;
@@ -112,6 +112,14 @@ impl StdinMapper for AssetSelectionEventStdinMapper {
                 entity: None,
                 controller_id,
             }),
+            AssetSelectionEventArgs::VariantSwitch {
+                controller_id,
+                skip,
+            } => Ok(AssetSelectionEvent::VariantSwitch {
+                entity: None,
+                controller_id,
+                variant_switch: AssetSwitch::Skip(skip),
+            }),
             AssetSelectionEventArgs::Confirm => Ok(AssetSelectionEvent::Confirm),
         }
     }
@@ -1,6 +1,7 @@
 //! Data types used at runtime.
 
-pub use self::{apw_main::ApwMain, apw_preview::ApwPreview};
+pub use self::{apw_main::ApwMain, apw_namespace_info::ApwNamespaceInfo, apw_preview::ApwPreview};
 
 mod apw_main;
+mod apw_namespace_info;
 mod apw_preview;
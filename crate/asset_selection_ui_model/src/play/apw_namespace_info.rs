@@ -0,0 +1,8 @@
+use amethyst::ecs::{storage::NullStorage, Component};
+
+/// Marks an entity as an asset preview widget namespace information entity.
+///
+/// This displays the manifest details of the namespace that the selected asset belongs to.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+#[storage(NullStorage)]
+pub struct ApwNamespaceInfo;
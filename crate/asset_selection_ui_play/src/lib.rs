@@ -3,8 +3,9 @@
 //! Provides logic for asset selection UI used at runtime.
 
 pub use crate::system::{
-    ApwPreviewSpawnSystem, ApwPreviewSpawnSystemCharacter, ApwPreviewSpawnSystemMap,
-    AssetSelectionSfxSystem, AssetSelectionSfxSystemData, AswPortraitUpdateSystem,
+    ApwNamespaceInfoSystem, ApwNamespaceInfoSystemData, ApwPreviewSpawnSystem,
+    ApwPreviewSpawnSystemCharacter, ApwPreviewSpawnSystemMap, AssetSelectionSfxSystem,
+    AssetSelectionSfxSystemData, AswPortraitUpdateSystem,
 };
 
 mod system;
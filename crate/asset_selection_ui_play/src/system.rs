@@ -1,4 +1,5 @@
 pub use self::{
+    apw_namespace_info_system::{ApwNamespaceInfoSystem, ApwNamespaceInfoSystemData},
     apw_preview_spawn_system::{
         ApwPreviewSpawnSystem, ApwPreviewSpawnSystemCharacter, ApwPreviewSpawnSystemMap,
     },
@@ -6,6 +7,7 @@ pub use self::{
     asw_portrait_update_system::AswPortraitUpdateSystem,
 };
 
+mod apw_namespace_info_system;
 mod apw_preview_spawn_system;
 mod asset_selection_sfx_system;
 mod asw_portrait_update_system;
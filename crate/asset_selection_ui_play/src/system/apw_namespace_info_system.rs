@@ -0,0 +1,356 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadExpect, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+    ui::{Anchor, LineMode, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use asset_model::{
+    config::NamespaceManifest,
+    loaded::{AssetIdMappings, NamespaceManifests},
+};
+use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
+use asset_selection_ui_model::play::{ApwMain, ApwNamespaceInfo};
+use asset_ui_model::play::{AssetSelectionHighlightMain, AssetSelectionParent};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::ControllerId,
+    play::{InputControlled, SharedInputControlled},
+};
+use kinematic_model::config::{FixedPoint, Position};
+use log::error;
+use parent_model::play::ParentEntity;
+
+const FONT_COLOUR: [f32; 4] = [0.7, 0.7, 0.7, 1.];
+const FONT_SIZE: f32 = 14.;
+const LABEL_WIDTH: f32 = 200.;
+const LABEL_HEIGHT: f32 = 40.;
+/// Offset of the label below the asset preview widget's position.
+const LABEL_OFFSET_Y: f32 = -LABEL_HEIGHT;
+
+/// Displays the selected asset's namespace manifest details under the asset preview widget.
+#[derive(Debug, Default, new)]
+pub struct ApwNamespaceInfoSystem {
+    /// Reader ID for the `AssetSelectionEvent` channel.
+    #[new(default)]
+    asset_selection_event_rid: Option<ReaderId<AssetSelectionEvent>>,
+}
+
+/// `ApwNamespaceInfoSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ApwNamespaceInfoSystemData<'s> {
+    /// `AssetSelectionEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub asset_selection_ec: Read<'s, EventChannel<AssetSelectionEvent>>,
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `NamespaceManifests` resource.
+    #[derivative(Debug = "ignore")]
+    pub namespace_manifests: Read<'s, NamespaceManifests>,
+    /// `ApwMain` components.
+    #[derivative(Debug = "ignore")]
+    pub apw_mains: ReadStorage<'s, ApwMain>,
+    /// `AssetSelectionHighlightMain` components.
+    #[derivative(Debug = "ignore")]
+    pub ash_mains: ReadStorage<'s, AssetSelectionHighlightMain>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `SharedInputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub shared_input_controlleds: ReadStorage<'s, SharedInputControlled>,
+    /// `AssetSelection` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_selections: ReadStorage<'s, AssetSelection>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
+    /// `ApwNamespaceInfo` components.
+    #[derivative(Debug = "ignore")]
+    pub apw_namespace_infos: WriteStorage<'s, ApwNamespaceInfo>,
+    /// `AssetSelectionParent` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_selection_parents: WriteStorage<'s, AssetSelectionParent>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl ApwNamespaceInfoSystem {
+    /// Returns the text to display for a namespace.
+    pub fn info_text(namespace: &str, namespace_manifest: &NamespaceManifest) -> String {
+        let name = namespace_manifest
+            .name
+            .as_ref()
+            .map(String::as_str)
+            .unwrap_or(namespace);
+        if namespace_manifest.authors.is_empty() {
+            format!("{} v{}", name, namespace_manifest.version)
+        } else {
+            format!(
+                "{} v{}\nby {}",
+                name,
+                namespace_manifest.version,
+                namespace_manifest.authors.join(", ")
+            )
+        }
+    }
+
+    /// Finds the main asset preview widget `Entity` with the given controller ID.
+    fn find_apw_main_entity(
+        ApwNamespaceInfoSystemData {
+            entities,
+            apw_mains,
+            input_controlleds,
+            shared_input_controlleds,
+            ..
+        }: &ApwNamespaceInfoSystemData,
+        controller_id: ControllerId,
+    ) -> Option<Entity> {
+        (entities, apw_mains, input_controlleds)
+            .join()
+            .find_map(|(entity, _, input_controlled)| {
+                if input_controlled.controller_id == controller_id {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                (entities, apw_mains, shared_input_controlleds)
+                    .join()
+                    .map(|(entity, _, _)| entity)
+                    .next()
+            })
+    }
+
+    /// Finds the main asset selection cell `Entity` that the ASH entity is attached to.
+    fn find_asset_selection_highlight_entity(
+        ApwNamespaceInfoSystemData {
+            entities,
+            ash_mains,
+            input_controlleds,
+            ..
+        }: &ApwNamespaceInfoSystemData,
+        controller_id: ControllerId,
+    ) -> Option<Entity> {
+        (entities, ash_mains, input_controlleds)
+            .join()
+            .find_map(|(entity, _, input_controlled)| {
+                if input_controlled.controller_id == controller_id {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Deletes `ApwNamespaceInfo` entities for a particular ASH entity.
+    fn delete_info_entities(
+        ApwNamespaceInfoSystemData {
+            entities,
+            apw_namespace_infos,
+            asset_selection_parents,
+            ..
+        }: &ApwNamespaceInfoSystemData,
+        ash_entity: Entity,
+    ) {
+        (entities, apw_namespace_infos, asset_selection_parents)
+            .join()
+            .filter(|(_, _, asset_selection_parent)| asset_selection_parent.0 == ash_entity)
+            .for_each(|(entity, _, _)| {
+                if let Err(e) = entities.delete(entity) {
+                    error!("Failed to delete entity: {}", e);
+                }
+            });
+    }
+
+    /// Spawns an entity displaying the namespace information of the selected asset.
+    fn spawn_info_entity(
+        apw_namespace_info_system_data: &mut ApwNamespaceInfoSystemData,
+        ash_entity: Entity,
+        controller_id: ControllerId,
+        asset_selection: Option<AssetSelection>,
+    ) {
+        let apw_main_entity =
+            Self::find_apw_main_entity(apw_namespace_info_system_data, controller_id);
+
+        let ApwNamespaceInfoSystemData {
+            entities,
+            asset_id_mappings,
+            namespace_manifests,
+            asset_selections,
+            positions,
+            apw_namespace_infos,
+            asset_selection_parents,
+            parent_entities,
+            theme,
+            ui_transforms,
+            ui_texts,
+            ..
+        } = apw_namespace_info_system_data;
+
+        let asset_selection = asset_selection.or_else(|| asset_selections.get(ash_entity).copied());
+        let info_text = if let Some(AssetSelection::Id(asset_id)) = asset_selection {
+            asset_id_mappings.slug(asset_id).and_then(|asset_slug| {
+                namespace_manifests
+                    .get(&asset_slug.namespace)
+                    .map(|namespace_manifest| {
+                        Self::info_text(&asset_slug.namespace, namespace_manifest)
+                    })
+            })
+        } else {
+            None
+        };
+
+        if let Some(info_text) = info_text {
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+
+            let position = apw_main_entity
+                .and_then(|apw_main_entity| positions.get(apw_main_entity))
+                .copied()
+                .unwrap_or_default()
+                .to_f32();
+
+            let mut ui_transform = UiTransform::new(
+                format!("apw_namespace_info_{}", ash_entity.id()),
+                Anchor::BottomLeft,
+                Anchor::BottomLeft,
+                position.x,
+                position.y + LABEL_OFFSET_Y,
+                position.z + 1.,
+                LABEL_WIDTH,
+                LABEL_HEIGHT,
+            );
+            ui_transform.opaque = false;
+            ui_transform.transparent_target = true;
+
+            let mut ui_text = UiText::new(font.clone(), info_text, FONT_COLOUR, FONT_SIZE);
+            ui_text.align = Anchor::TopLeft;
+            ui_text.line_mode = LineMode::Wrap;
+
+            entities
+                .build_entity()
+                .with(ApwNamespaceInfo, apw_namespace_infos)
+                .with(
+                    AssetSelectionParent::new(ash_entity),
+                    asset_selection_parents,
+                )
+                .with(ParentEntity::new(ash_entity), parent_entities)
+                .with(ui_transform, ui_transforms)
+                .with(ui_text, ui_texts)
+                .build();
+        }
+    }
+}
+
+impl<'s> System<'s> for ApwNamespaceInfoSystem {
+    type SystemData = ApwNamespaceInfoSystemData<'s>;
+
+    fn run(&mut self, mut apw_namespace_info_system_data: Self::SystemData) {
+        let asset_selection_event_rid = self
+            .asset_selection_event_rid
+            .as_mut()
+            .expect("Expected `asset_selection_event_rid` field to be set.");
+
+        let asset_selection_events = apw_namespace_info_system_data
+            .asset_selection_ec
+            .read(asset_selection_event_rid)
+            .copied()
+            .collect::<Vec<AssetSelectionEvent>>();
+
+        asset_selection_events.into_iter().for_each(|ev| match ev {
+            AssetSelectionEvent::Return => {}
+            AssetSelectionEvent::Join {
+                entity,
+                controller_id,
+            } => {
+                let ash_entity = entity.or_else(|| {
+                    Self::find_asset_selection_highlight_entity(
+                        &apw_namespace_info_system_data,
+                        controller_id,
+                    )
+                });
+                if let Some(ash_entity) = ash_entity {
+                    Self::delete_info_entities(&apw_namespace_info_system_data, ash_entity);
+                    Self::spawn_info_entity(
+                        &mut apw_namespace_info_system_data,
+                        ash_entity,
+                        controller_id,
+                        None,
+                    );
+                }
+            }
+            AssetSelectionEvent::Leave {
+                entity,
+                controller_id,
+            } => {
+                let ash_entity = entity.or_else(|| {
+                    Self::find_asset_selection_highlight_entity(
+                        &apw_namespace_info_system_data,
+                        controller_id,
+                    )
+                });
+                if let Some(ash_entity) = ash_entity {
+                    Self::delete_info_entities(&apw_namespace_info_system_data, ash_entity);
+                }
+            }
+            AssetSelectionEvent::Switch {
+                entity,
+                controller_id,
+                asset_selection,
+            } => {
+                let ash_entity = entity.or_else(|| {
+                    Self::find_asset_selection_highlight_entity(
+                        &apw_namespace_info_system_data,
+                        controller_id,
+                    )
+                });
+                if let Some(ash_entity) = ash_entity {
+                    Self::delete_info_entities(&apw_namespace_info_system_data, ash_entity);
+                    Self::spawn_info_entity(
+                        &mut apw_namespace_info_system_data,
+                        ash_entity,
+                        controller_id,
+                        Some(asset_selection),
+                    );
+                }
+            }
+            // No update needed -- the selected asset has not changed.
+            AssetSelectionEvent::Select { .. }
+            | AssetSelectionEvent::Deselect { .. }
+            | AssetSelectionEvent::VariantSwitch { .. } => {}
+            AssetSelectionEvent::Confirm => {}
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.asset_selection_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<AssetSelectionEvent>>()
+                .register_reader(),
+        );
+    }
+}
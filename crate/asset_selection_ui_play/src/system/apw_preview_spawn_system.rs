@@ -4,11 +4,14 @@ mod map_preview_spawn;
 use std::{fmt::Debug, marker::PhantomData};
 
 use amethyst::{
-    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write, WriteStorage},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use asset_model::{config::AssetType, loaded::AssetTypeMappings};
+use asset_model::{
+    config::AssetType,
+    loaded::{AssetId, AssetTypeMappings},
+};
 use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
 use asset_selection_ui_model::play::{ApwMain, ApwPreview};
 use asset_ui_model::play::{AssetSelectionHighlightMain, AssetSelectionParent};
@@ -18,6 +21,10 @@ use game_input_model::{
     config::ControllerId,
     play::{InputControlled, SharedInputControlled},
 };
+use loading_model::{
+    loaded::{AssetLoadStage, LoadStage},
+    play::AssetLoadRequestEvent,
+};
 use log::error;
 
 use self::{character_preview_spawn::CharacterPreviewSpawn, map_preview_spawn::MapPreviewSpawn};
@@ -44,15 +51,32 @@ pub type ApwPreviewSpawnSystemCharacter = ApwPreviewSpawnSystem<CharacterPreview
 pub type ApwPreviewSpawnSystemMap = ApwPreviewSpawnSystem<MapPreviewSpawn>;
 
 /// Spawns / deletes character preview entities when character selection is switched.
+///
+/// Previews of assets that are not loaded are spawned once loading completes. Deferred assets are
+/// requested to be loaded, and loading is cancelled if the selection is switched away first.
 #[derive(Debug, Default, new)]
 pub struct ApwPreviewSpawnSystem<PS> {
     /// Reader ID for the `AssetSelectionEvent` channel.
     #[new(default)]
     asset_selection_event_rid: Option<ReaderId<AssetSelectionEvent>>,
+    /// Previews waiting for their asset to load.
+    #[new(default)]
+    previews_pending: Vec<PreviewPending>,
     /// Marker.
     marker: PhantomData<PS>,
 }
 
+/// Preview that is spawned once its asset is loaded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PreviewPending {
+    /// Asset selection highlight entity that the preview is for.
+    ash_entity: Entity,
+    /// ID of the controller that selected the asset.
+    controller_id: ControllerId,
+    /// ID of the asset to preview.
+    asset_id: AssetId,
+}
+
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct ApwPreviewSpawnSystemData<'s, PS>
@@ -78,6 +102,12 @@ where
     /// `AssetTypeMappings` resource.
     #[derivative(Debug = "ignore")]
     pub asset_type_mappings: Read<'s, AssetTypeMappings>,
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Read<'s, AssetLoadStage>,
+    /// `AssetLoadRequestEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_request_ec: Write<'s, EventChannel<AssetLoadRequestEvent>>,
     /// `ApwMain` components.
     #[derivative(Debug = "ignore")]
     pub apw_mains: ReadStorage<'s, ApwMain>,
@@ -212,17 +242,21 @@ where
     }
 
     // Spawns new entities that provide a preview for the asset preview widget.
+    //
+    // Returns the pending preview if the asset is not yet loaded.
     fn spawn_preview_entities(
         apw_preview_spawn_resources: &mut ApwPreviewSpawnResources<PS>,
         ash_entity: Entity,
         controller_id: ControllerId,
         asset_selection: Option<AssetSelection>,
-    ) {
+    ) -> Option<PreviewPending> {
         let apw_main_entity =
             Self::find_apw_main_entity(&apw_preview_spawn_resources, controller_id);
 
         let ApwPreviewSpawnResources {
             asset_type_mappings,
+            asset_load_stage,
+            asset_load_request_ec,
             apw_previews,
             asset_selection_parents,
             asset_selections,
@@ -237,6 +271,17 @@ where
                 let asset_type = asset_type_mappings.get(asset_id).copied();
                 if let Some(asset_type) = asset_type {
                     if asset_type == PS::ASSET_TYPE {
+                        if !Self::asset_loaded(asset_load_stage, asset_id) {
+                            asset_load_request_ec
+                                .single_write(AssetLoadRequestEvent::Request(asset_id));
+
+                            return Some(PreviewPending {
+                                ash_entity,
+                                controller_id,
+                                asset_id,
+                            });
+                        }
+
                         PS::spawn_preview_entities(
                             apw_previews,
                             asset_selection_parents,
@@ -249,6 +294,77 @@ where
                 }
             }
         }
+
+        None
+    }
+
+    /// Returns whether the asset is loaded.
+    ///
+    /// Assets without a load stage are not loaded through the asset index, so they are treated as
+    /// loaded.
+    fn asset_loaded(asset_load_stage: &AssetLoadStage, asset_id: AssetId) -> bool {
+        asset_load_stage
+            .get(asset_id)
+            .map(|load_stage| *load_stage == LoadStage::Complete)
+            .unwrap_or(true)
+    }
+
+    /// Spawns previews whose assets have finished loading.
+    fn spawn_previews_loaded(
+        previews_pending: &mut Vec<PreviewPending>,
+        apw_preview_spawn_resources: &mut ApwPreviewSpawnResources<PS>,
+    ) {
+        let entities = &apw_preview_spawn_resources.entities;
+        previews_pending.retain(|preview_pending| entities.is_alive(preview_pending.ash_entity));
+
+        let asset_load_stage = &apw_preview_spawn_resources.asset_load_stage;
+        let (previews_loaded, previews_still_pending): (Vec<_>, Vec<_>) =
+            previews_pending.drain(..).partition(|preview_pending| {
+                Self::asset_loaded(asset_load_stage, preview_pending.asset_id)
+            });
+        *previews_pending = previews_still_pending;
+
+        previews_loaded.into_iter().for_each(
+            |PreviewPending {
+                 ash_entity,
+                 controller_id,
+                 asset_id,
+             }| {
+                Self::spawn_preview_entities(
+                    apw_preview_spawn_resources,
+                    ash_entity,
+                    controller_id,
+                    Some(AssetSelection::Id(asset_id)),
+                );
+            },
+        );
+    }
+
+    /// Stops waiting to preview assets for an ASH entity.
+    ///
+    /// Loading is cancelled for assets that no other preview is waiting for.
+    fn cancel_previews_pending(
+        previews_pending: &mut Vec<PreviewPending>,
+        apw_preview_spawn_resources: &mut ApwPreviewSpawnResources<PS>,
+        ash_entity: Entity,
+    ) {
+        let (previews_cancelled, previews_still_pending): (Vec<_>, Vec<_>) = previews_pending
+            .drain(..)
+            .partition(|preview_pending| preview_pending.ash_entity == ash_entity);
+        *previews_pending = previews_still_pending;
+
+        let asset_load_request_ec = &mut apw_preview_spawn_resources.asset_load_request_ec;
+        previews_cancelled
+            .into_iter()
+            .filter(|preview_cancelled| {
+                !previews_pending
+                    .iter()
+                    .any(|preview_pending| preview_pending.asset_id == preview_cancelled.asset_id)
+            })
+            .for_each(|preview_cancelled| {
+                asset_load_request_ec
+                    .single_write(AssetLoadRequestEvent::Cancel(preview_cancelled.asset_id));
+            });
     }
 }
 
@@ -268,6 +384,7 @@ where
             .asset_selection_event_rid
             .as_mut()
             .expect("Expected `asset_selection_event_rid` field to be set.");
+        let previews_pending = &mut self.previews_pending;
 
         asset_selection_ec
             .read(asset_selection_event_rid)
@@ -284,12 +401,14 @@ where
                         )
                     });
                     if let Some(ash_entity) = ash_entity {
-                        Self::spawn_preview_entities(
+                        if let Some(preview_pending) = Self::spawn_preview_entities(
                             apw_preview_spawn_resources,
                             ash_entity,
                             controller_id,
                             None,
-                        );
+                        ) {
+                            previews_pending.push(preview_pending);
+                        }
                     }
                 }
                 AssetSelectionEvent::Leave {
@@ -303,6 +422,11 @@ where
                         )
                     });
                     if let Some(ash_entity) = ash_entity {
+                        Self::cancel_previews_pending(
+                            previews_pending,
+                            apw_preview_spawn_resources,
+                            ash_entity,
+                        );
                         Self::delete_preview_entities(&apw_preview_spawn_resources, ash_entity);
                     }
                 }
@@ -318,19 +442,30 @@ where
                         )
                     });
                     if let Some(ash_entity) = ash_entity {
+                        Self::cancel_previews_pending(
+                            previews_pending,
+                            apw_preview_spawn_resources,
+                            ash_entity,
+                        );
                         Self::delete_preview_entities(apw_preview_spawn_resources, ash_entity);
-                        Self::spawn_preview_entities(
+                        if let Some(preview_pending) = Self::spawn_preview_entities(
                             apw_preview_spawn_resources,
                             ash_entity,
                             controller_id,
                             Some(asset_selection),
-                        );
+                        ) {
+                            previews_pending.push(preview_pending);
+                        }
                     }
                 }
                 // No update needed -- preview entities are already correct.
-                AssetSelectionEvent::Select { .. } | AssetSelectionEvent::Deselect { .. } => {}
+                AssetSelectionEvent::Select { .. }
+                | AssetSelectionEvent::Deselect { .. }
+                | AssetSelectionEvent::VariantSwitch { .. } => {}
                 AssetSelectionEvent::Confirm => {}
             });
+
+        Self::spawn_previews_loaded(previews_pending, apw_preview_spawn_resources);
     }
 
     fn setup(&mut self, world: &mut World) {
@@ -8,7 +8,7 @@ use asset_selection_ui_model::play::ApwPreview;
 use asset_ui_model::play::AssetSelectionParent;
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 use object_model::play::Grounding;
 use object_type::ObjectType;
 use parent_model::play::ParentEntity;
@@ -56,22 +56,17 @@ impl<'s> PreviewSpawner<'s> for CharacterPreviewSpawn {
 
         if let AssetSelection::Id(asset_id) = asset_selection {
             // TODO: Take in position to spawn entity.
-            let x = 60.;
+            let x = FixedPoint::from(60);
             // Hack: Since characters have `PositionZAsY`, we shift the entity's Y position up by
             // the Z position of the asset_selection_entity.
-            let y = 30. + 12.;
-            let z = 1.;
+            let y = FixedPoint::from(30 + 12);
+            let z = FixedPoint::ONE;
             let position = Position::new(x, y, z);
 
             // TODO: Look up sequence ID for default sequence ID for the asset type.
             let sequence_id = SequenceId::new(0);
 
-            let spawn = Spawn {
-                object: asset_id,
-                position,
-                velocity: Velocity::default(),
-                sequence_id,
-            };
+            let spawn = Spawn::new(asset_id, position, Velocity::default(), sequence_id);
 
             let spawn_parent_entity = apw_main_entity.unwrap_or(ash_entity);
             let entity_spawned =
@@ -12,7 +12,7 @@ use camera_model::play::CameraZoomDimensions;
 use derivative::Derivative;
 use derive_new::new;
 use kinematic_model::{
-    config::{Position, ScaleInit},
+    config::{FixedPoint, Position, ScaleInit},
     play::{PositionInitOffset, PositionInitParent},
 };
 use log::warn;
@@ -151,7 +151,11 @@ impl<'s> PreviewSpawner<'s> for MapPreviewSpawn {
                 position_init_offsets
                     .insert(
                         map_entity,
-                        PositionInitOffset::new(Position::new(translate_x, 0., 0.)),
+                        PositionInitOffset::new(Position::new(
+                            FixedPoint::from(translate_x),
+                            FixedPoint::ZERO,
+                            FixedPoint::ZERO,
+                        )),
                     )
                     .expect("Failed to insert `PositionInitOffset` component.");
                 scale_inits
@@ -67,6 +67,7 @@ impl<'s> System<'s> for AssetSelectionSfxSystem {
                     AssetSelectionEvent::Select { .. } => UiSfxId::Select,
                     AssetSelectionEvent::Deselect { .. } => UiSfxId::Deselect,
                     AssetSelectionEvent::Leave { .. } => UiSfxId::Deselect,
+                    AssetSelectionEvent::VariantSwitch { .. } => UiSfxId::Switch,
                     AssetSelectionEvent::Confirm => UiSfxId::Confirm,
                 };
 
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
@@ -11,7 +12,7 @@ use crate::config::AssetDisplayLayout;
 /// # Type Parameters
 ///
 /// * `T`: Type to indicate the assets to display.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct AssetDisplay<T> {
     /// Position of the sheet.
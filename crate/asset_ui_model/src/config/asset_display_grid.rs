@@ -1,8 +1,9 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 use ui_model_spi::config::Dimensions;
 
 /// Lays out assets in a grid.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct AssetDisplayGrid {
     /// Number of columns per row in the grid.
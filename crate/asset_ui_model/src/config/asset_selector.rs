@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::config::{AshTemplate, AssetDisplay};
@@ -7,7 +8,7 @@ use crate::config::{AshTemplate, AssetDisplay};
 /// # Type Parameters
 ///
 /// * `T`: Type to indicate the assets to display.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct AssetSelector<T> {
     /// Displays assets.
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use indexmap::IndexMap;
@@ -8,5 +9,7 @@ use sprite_model::config::SpriteSequenceName;
 use crate::config::AswPortraitName;
 
 /// Portraits available to an `AssetSelectionWidget`.
-#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new,
+)]
 pub struct AswPortraits(pub IndexMap<AswPortraitName, SequenceNameString<SpriteSequenceName>>);
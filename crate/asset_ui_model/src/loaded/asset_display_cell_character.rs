@@ -1,5 +1,3 @@
-use std::convert::TryFrom;
-
 use amethyst::{
     ecs::{storage::VecStorage, Component, Entity, World, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -7,7 +5,7 @@ use amethyst::{
 use asset_model::{loaded::AssetId, ItemComponent};
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 use object_model::play::Grounding;
 use parent_model::play::ParentEntity;
 use sequence_model::loaded::SequenceId;
@@ -46,16 +44,14 @@ impl AssetDisplayCellCharacter {
     ) {
         // TODO: Look up sequence ID for default sequence ID for the asset type.
         let sequence_id = SequenceId::new(0);
-        let half_cell_width = i16::try_from(self.cell_size.w >> 1)
-            .expect("Failed to convert `cell_size.w` to `i16`.");
-        let half_cell_width = f32::from(half_cell_width);
+        let half_cell_width = FixedPoint::from(self.cell_size.w >> 1);
 
-        let spawn = Spawn {
-            object: self.asset_id,
-            position: Position::new(half_cell_width, 10., 0.),
-            velocity: Velocity::default(),
+        let spawn = Spawn::new(
+            self.asset_id,
+            Position::new(half_cell_width, FixedPoint::from(10), FixedPoint::ZERO),
+            Velocity::default(),
             sequence_id,
-        };
+        );
         let entity_spawned = GameObjectSpawner::spawn(spawn_game_object_resources, entity, &spawn);
 
         parent_entities
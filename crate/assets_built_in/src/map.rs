@@ -1,7 +1,7 @@
 use asset_model::config::{AssetSlug, AssetSlugBuilder};
 use background_model::config::BackgroundDefinition;
 use lazy_static::lazy_static;
-use map_model::config::{MapBounds, MapDefinition, MapHeader};
+use map_model::config::{MapBounds, MapDefinition, MapHeader, Platforms, SpawnPoints};
 
 use crate::NAMESPACE_BUILT_IN;
 
@@ -27,7 +27,12 @@ lazy_static! {
     pub static ref MAP_DEFINITION_BLANK: MapDefinition = {
         let (width, height, depth) = (800, 600, 200);
         let bounds = MapBounds::new(0, 0, 0, width as u32, height as u32 - depth, depth);
-        let header = MapHeader::new("Blank Screen".to_string(), bounds);
+        let header = MapHeader::new(
+            "Blank Screen".to_string(),
+            bounds,
+            SpawnPoints::default(),
+            Platforms::default(),
+        );
         MapDefinition::new(header, BackgroundDefinition::default())
     };
 }
@@ -22,7 +22,8 @@ pub use crate::{
     },
     ui::{
         UI_CHARACTER_SELECTION_NAME, UI_CHARACTER_SELECTION_PATH, UI_CHARACTER_SELECTION_SLUG,
-        UI_LOADING_NAME, UI_LOADING_PATH, UI_LOADING_SLUG,
+        UI_LOADING_NAME, UI_LOADING_PATH, UI_LOADING_SLUG, UI_PAUSE_MENU_NAME, UI_PAUSE_MENU_PATH,
+        UI_PAUSE_MENU_SLUG,
     },
 };
 
@@ -11,6 +11,9 @@ pub const UI_CHARACTER_SELECTION_NAME: &str = "character_selection";
 /// Name of the "loading" ui asset.
 pub const UI_LOADING_NAME: &str = "loading";
 
+/// Name of the "pause_menu" ui asset.
+pub const UI_PAUSE_MENU_NAME: &str = "pause_menu";
+
 lazy_static! {
     /// `PathBuf` to the "ui" asset directory.
     static ref UI_PATH: PathBuf =
@@ -49,4 +52,21 @@ lazy_static! {
 
     /// `PathBuf` to the "character_selection" ui asset directory.
     pub static ref UI_CHARACTER_SELECTION_PATH: PathBuf = UI_PATH.join(UI_CHARACTER_SELECTION_NAME);
+
+    /// Slug of the "pause_menu" ui asset.
+    pub static ref UI_PAUSE_MENU_SLUG: AssetSlug = {
+        AssetSlugBuilder::default()
+            .namespace(NAMESPACE_TEST.to_string())
+            .name(UI_PAUSE_MENU_NAME.to_string())
+            .build()
+            .unwrap_or_else(|e| panic!(
+                "Expected `{}/{}` asset slug to build. Error: \n\n```{}\n```\n",
+                NAMESPACE_TEST,
+                UI_PAUSE_MENU_NAME,
+                e
+            ))
+    };
+
+    /// `PathBuf` to the "pause_menu" ui asset directory.
+    pub static ref UI_PAUSE_MENU_PATH: PathBuf = UI_PATH.join(UI_PAUSE_MENU_NAME);
 }
@@ -71,7 +71,8 @@ impl<'s> System<'s> for SequenceAudioPlaySystem {
                         output.play_once(source, VOLUME);
                     }
                 }
-                SequenceUpdateEvent::SequenceEnd { .. } => {}
+                SequenceUpdateEvent::SequenceEnd { .. }
+                | SequenceUpdateEvent::SequenceReload { .. } => {}
             });
         }
     }
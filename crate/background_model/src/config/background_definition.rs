@@ -1,11 +1,12 @@
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use derive_new::new;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use sprite_model::config::SpriteItem;
 
 /// A grouping of images to draw as a background.
-#[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(Asset, AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 pub struct BackgroundDefinition {
     /// Sprite layers to draw.
     #[serde(default)]
@@ -1,14 +1,18 @@
 //! Contains data types used during game play.
 
 pub use self::{
+    camera_shake::CameraShake,
     camera_target_coordinates::CameraTargetCoordinates,
     camera_tracked::CameraTracked,
     camera_zoom_dimensions::{
         CameraZoomDimensions, CAMERA_ZOOM_DEPTH_DEFAULT, CAMERA_ZOOM_HEIGHT_DEFAULT,
         CAMERA_ZOOM_WIDTH_DEFAULT,
     },
+    screen_flash::ScreenFlash,
 };
 
+mod camera_shake;
 mod camera_target_coordinates;
 mod camera_tracked;
 mod camera_zoom_dimensions;
+mod screen_flash;
@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+
+/// Shakes the camera, decaying over its duration.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct CameraShake {
+    /// Maximum offset of the camera in pixels.
+    pub magnitude: f32,
+    /// Number of ticks the shake lasts for.
+    pub duration: u32,
+    /// Number of ticks the shake has run for.
+    #[new(default)]
+    pub ticks: u32,
+}
@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+
+/// Flashes a colour over the screen, fading out over its duration.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct ScreenFlash {
+    /// Colour of the flash when it begins, as RGBA.
+    pub color: [f32; 4],
+    /// Number of ticks the flash lasts for.
+    pub duration: u32,
+    /// Number of ticks the flash has run for.
+    #[new(default)]
+    pub ticks: u32,
+}
@@ -7,7 +7,7 @@ use amethyst::{
 };
 use camera_model::play::CameraTargetCoordinates;
 use derivative::Derivative;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 
 /// Camera entity `Component` storages.
 #[derive(Derivative, SystemData)]
@@ -22,12 +22,12 @@ pub struct CameraComponentStorages<'s> {
     /// `CameraTargetCoordinates` components.
     #[derivative(Debug = "ignore")]
     pub camera_target_coordinateses: WriteStorage<'s, CameraTargetCoordinates>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
-    /// `Velocity<f32>` components.
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
     /// `Transform` components.
     #[derivative(Debug = "ignore")]
     pub transforms: WriteStorage<'s, Transform>,
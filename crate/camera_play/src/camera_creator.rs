@@ -5,7 +5,7 @@ use amethyst::{
     utils::ortho_camera::{CameraNormalizeMode, CameraOrtho, CameraOrthoWorldCoordinates},
 };
 use camera_model::play::{CameraTargetCoordinates, CAMERA_ZOOM_DEPTH_DEFAULT};
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 
 use crate::{CameraComponentStorages, CameraCreatorResources};
 
@@ -108,7 +108,7 @@ impl CameraCreator {
         );
 
         let camera_target_coordinates = CameraTargetCoordinates(translation);
-        let position = Position::from(translation);
+        let position = Position::from_f32(translation);
         let transform = Transform::from(translation);
 
         cameras
@@ -122,10 +122,10 @@ impl CameraCreator {
             .expect("Failed to insert `CameraTargetCoordinates` component.");
         positions
             .insert(entity, position)
-            .expect("Failed to insert `Position<f32>` component.");
+            .expect("Failed to insert `Position<FixedPoint>` component.");
         velocities
             .insert(entity, Velocity::default())
-            .expect("Failed to insert `Velocity<f32>` component.");
+            .expect("Failed to insert `Velocity<FixedPoint>` component.");
         transforms
             .insert(entity, transform)
             .expect("Failed to insert `Transform` component.");
@@ -7,7 +7,7 @@ pub use crate::{
     camera_creator::CameraCreator,
     camera_creator_resources::CameraCreatorResources,
     camera_play_bundle::CameraPlayBundle,
-    system::{CameraTrackingSystem, CameraVelocitySystem},
+    system::{CameraShakeSystem, CameraTrackingSystem, CameraVelocitySystem, ScreenFlashSystem},
 };
 
 mod camera_component_storages;
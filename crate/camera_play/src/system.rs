@@ -1,6 +1,9 @@
 pub use self::{
-    camera_tracking_system::CameraTrackingSystem, camera_velocity_system::CameraVelocitySystem,
+    camera_shake_system::CameraShakeSystem, camera_tracking_system::CameraTrackingSystem,
+    camera_velocity_system::CameraVelocitySystem, screen_flash_system::ScreenFlashSystem,
 };
 
+mod camera_shake_system;
 mod camera_tracking_system;
 mod camera_velocity_system;
+mod screen_flash_system;
@@ -0,0 +1,93 @@
+use amethyst::{
+    core::Transform,
+    ecs::{Entities, Join, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use camera_model::play::CameraShake;
+use derivative::Derivative;
+use derive_new::new;
+
+/// Offsets the camera `Transform` while it is shaking.
+///
+/// The `Transform` is recalculated from the camera's `Position` every tick, so this system must
+/// run after the `ObjectTransformUpdateSystem`.
+#[derive(Debug, Default, new)]
+pub struct CameraShakeSystem;
+
+/// `CameraShakeSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CameraShakeSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CameraShake` components.
+    #[derivative(Debug = "ignore")]
+    pub camera_shakes: WriteStorage<'s, CameraShake>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+}
+
+impl CameraShakeSystem {
+    /// Returns the camera offset for the current tick of the shake.
+    ///
+    /// The offset alternates direction every tick, and decays linearly to 0.
+    pub fn offset(camera_shake: CameraShake) -> (f32, f32) {
+        let CameraShake {
+            magnitude,
+            duration,
+            ticks,
+        } = camera_shake;
+        if ticks >= duration {
+            return (0., 0.);
+        }
+
+        let magnitude = magnitude * (duration - ticks) as f32 / duration as f32;
+        let x = if ticks % 2 == 0 {
+            magnitude
+        } else {
+            -magnitude
+        };
+        let y = if ticks % 4 < 2 {
+            magnitude / 2.
+        } else {
+            -magnitude / 2.
+        };
+        (x, y)
+    }
+}
+
+impl<'s> System<'s> for CameraShakeSystem {
+    type SystemData = CameraShakeSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CameraShakeSystemData {
+            entities,
+            mut camera_shakes,
+            mut transforms,
+        }: Self::SystemData,
+    ) {
+        let shakes_ended = (&entities, &mut camera_shakes, &mut transforms)
+            .join()
+            .filter_map(|(entity, camera_shake, transform)| {
+                let (x, y) = Self::offset(*camera_shake);
+                let translation = transform.translation_mut();
+                translation.x += x;
+                translation.y += y;
+
+                camera_shake.ticks += 1;
+                if camera_shake.ticks >= camera_shake.duration {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        shakes_ended.into_iter().for_each(|entity| {
+            camera_shakes.remove(entity);
+        });
+    }
+}
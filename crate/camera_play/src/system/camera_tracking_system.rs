@@ -7,7 +7,7 @@ use amethyst::{
 use camera_model::play::{CameraTargetCoordinates, CameraTracked, CameraZoomDimensions};
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 use map_model::{
     config::MapBounds,
     loaded::{AssetMapBounds, AssetMargins, Margins},
@@ -37,9 +37,9 @@ pub struct CameraTrackingSystemData<'s> {
     /// `CameraTracked` components.
     #[derivative(Debug = "ignore")]
     pub camera_trackeds: ReadStorage<'s, CameraTracked>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: ReadStorage<'s, Mirrored>,
@@ -55,11 +55,11 @@ impl CameraTrackingSystem {
     /// Returns the mean position of `CameraTracked` entities.
     fn position_average(
         camera_trackeds: &ReadStorage<'_, CameraTracked>,
-        positions: &ReadStorage<'_, Position<f32>>,
+        positions: &ReadStorage<'_, Position<FixedPoint>>,
     ) -> Vector3<f32> {
         let positions = (camera_trackeds, positions)
             .join()
-            .map(|(_, position)| **position)
+            .map(|(_, position)| position.to_f32())
             .collect::<Vec<Vector3<f32>>>();
 
         positions.iter().sum::<Vector3<f32>>() / (positions.len() as f32)
@@ -75,26 +75,28 @@ impl CameraTrackingSystem {
         let x_centred = if camera_zoom_dimensions.width < map_bounds.width as f32 {
             focus_coordinate
                 .x
-                .max(map_margins.left + camera_zoom_dimensions.width / 2.)
-                .min(map_margins.right - camera_zoom_dimensions.width / 2.)
+                .max(map_margins.left.to_f32() + camera_zoom_dimensions.width / 2.)
+                .min(map_margins.right.to_f32() - camera_zoom_dimensions.width / 2.)
         } else {
             camera_zoom_dimensions.width / 2.
         };
-        let y_centred =
-            if camera_zoom_dimensions.height < (map_bounds.height + map_bounds.depth) as f32 {
-                // Subtract Z because Z+ is rendered downwards.
-                let yz_avg = focus_coordinate.y - focus_coordinate.z;
-                let bounded_max = map_margins.top
-                    - map_margins.back
-                    - map_bounds.depth as f32
-                    - camera_zoom_dimensions.height / 2.;
-                let bounded_min = map_margins.bottom - map_margins.front - map_bounds.depth as f32
+        let y_centred = if camera_zoom_dimensions.height
+            < (map_bounds.height + map_bounds.depth) as f32
+        {
+            // Subtract Z because Z+ is rendered downwards.
+            let yz_avg = focus_coordinate.y - focus_coordinate.z;
+            let bounded_max = map_margins.top.to_f32()
+                - map_margins.back.to_f32()
+                - map_bounds.depth as f32
+                - camera_zoom_dimensions.height / 2.;
+            let bounded_min =
+                map_margins.bottom.to_f32() - map_margins.front.to_f32() - map_bounds.depth as f32
                     + camera_zoom_dimensions.height / 2.;
 
-                yz_avg.max(bounded_min).min(bounded_max)
-            } else {
-                camera_zoom_dimensions.height / 2.
-            };
+            yz_avg.max(bounded_min).min(bounded_max)
+        } else {
+            camera_zoom_dimensions.height / 2.
+        };
         let z_centred = focus_coordinate.z + camera_zoom_dimensions.depth / 2.;
 
         CameraTargetCoordinates::new(x_centred, y_centred, z_centred)
@@ -6,10 +6,10 @@ use amethyst::{
 use camera_model::play::CameraTargetCoordinates;
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 
 /// How much to divide the target velocity by, to smoothen the acceleration.
-const SMOOTHING_FACTOR_DEFAULT: f32 = 3.;
+const SMOOTHING_FACTOR_DEFAULT: FixedPoint = FixedPoint::from_int(3);
 
 /// Updates camera velocity to smoothen camera movement between its current and target position.
 #[derive(Debug, Derivative, new)]
@@ -17,7 +17,7 @@ const SMOOTHING_FACTOR_DEFAULT: f32 = 3.;
 pub struct CameraVelocitySystem {
     /// How much to divide the target velocity by, to smoothen the acceleration.
     #[derivative(Default(value = "SMOOTHING_FACTOR_DEFAULT"))]
-    pub smoothing_factor: f32,
+    pub smoothing_factor: FixedPoint,
 }
 
 #[derive(Derivative, SystemData)]
@@ -29,12 +29,12 @@ pub struct CameraVelocitySystemData<'s> {
     /// `CameraTargetCoordinates` components.
     #[derivative(Debug = "ignore")]
     pub camera_target_coordinateses: ReadStorage<'s, CameraTargetCoordinates>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
-    /// `Velocity<f32>` components.
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
 }
 
 impl<'s> System<'s> for CameraVelocitySystem {
@@ -67,7 +67,8 @@ impl<'s> System<'s> for CameraVelocitySystem {
                     //
                     //     If our current velocity is 0, then we will increase to 33.
                     //     Next frame will be 44: (33 + 100) / 3
-                    let velocity_limit = (**camera_target_coordinates - **position) / 10.;
+                    let position_target = Position::from_f32(**camera_target_coordinates);
+                    let velocity_limit = (*position_target - **position) / FixedPoint::from_int(10);
                     (**velocity + velocity_limit) / self.smoothing_factor
                 };
             });
@@ -0,0 +1,105 @@
+use amethyst::{
+    ecs::{Entities, Join, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, Stretch, UiImage, UiTransform},
+};
+use camera_model::play::ScreenFlash;
+use derivative::Derivative;
+use derive_new::new;
+use log::error;
+
+/// Z order of the screen flash, drawn over game play entities.
+const SCREEN_FLASH_Z: f32 = 100.;
+
+/// Displays `ScreenFlash` entities over the screen, and deletes them when they have faded.
+#[derive(Debug, Default, new)]
+pub struct ScreenFlashSystem;
+
+/// `ScreenFlashSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ScreenFlashSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ScreenFlash` components.
+    #[derivative(Debug = "ignore")]
+    pub screen_flashes: WriteStorage<'s, ScreenFlash>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiImage` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_images: WriteStorage<'s, UiImage>,
+}
+
+impl ScreenFlashSystem {
+    /// Returns the colour of the flash for the current tick, fading the alpha linearly to 0.
+    pub fn color(screen_flash: ScreenFlash) -> [f32; 4] {
+        let ScreenFlash {
+            color: [r, g, b, a],
+            duration,
+            ticks,
+        } = screen_flash;
+        let alpha = if ticks >= duration {
+            0.
+        } else {
+            a * (duration - ticks) as f32 / duration as f32
+        };
+        [r, g, b, alpha]
+    }
+
+    fn ui_transform() -> UiTransform {
+        UiTransform::new(
+            String::from("screen_flash"),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.,
+            0.,
+            SCREEN_FLASH_Z,
+            0.,
+            0.,
+        )
+        .with_stretch(Stretch::XY {
+            x_margin: 0.,
+            y_margin: 0.,
+            keep_aspect_ratio: false,
+        })
+    }
+}
+
+impl<'s> System<'s> for ScreenFlashSystem {
+    type SystemData = ScreenFlashSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ScreenFlashSystemData {
+            entities,
+            mut screen_flashes,
+            mut ui_transforms,
+            mut ui_images,
+        }: Self::SystemData,
+    ) {
+        (&entities, &mut screen_flashes)
+            .join()
+            .for_each(|(entity, screen_flash)| {
+                if screen_flash.ticks >= screen_flash.duration {
+                    if let Err(e) = entities.delete(entity) {
+                        error!("Failed to delete screen flash entity: {}", e);
+                    }
+                    return;
+                }
+
+                if !ui_transforms.contains(entity) {
+                    ui_transforms
+                        .insert(entity, Self::ui_transform())
+                        .expect("Failed to insert `UiTransform` component.");
+                }
+                ui_images
+                    .insert(entity, UiImage::SolidColor(Self::color(*screen_flash)))
+                    .expect("Failed to insert `UiImage` component.");
+
+                screen_flash.ticks += 1;
+            });
+    }
+}
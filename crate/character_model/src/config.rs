@@ -1,7 +1,9 @@
 //! Contains the types that represent the configuration on disk.
 
 pub use self::{
+    character_charge_level_next::CharacterChargeLevelNext,
     character_definition::{CharacterDefinition, CharacterDefinitionHandle},
+    character_end_transition::CharacterEndTransition,
     character_frame::CharacterFrame,
     character_input_reactions::CharacterInputReactions,
     character_irr::CharacterIrr,
@@ -12,7 +14,9 @@ pub use self::{
     input_reaction_requirement_params::InputReactionRequirementParams,
 };
 
+mod character_charge_level_next;
 mod character_definition;
+mod character_end_transition;
 mod character_frame;
 mod character_input_reactions;
 mod character_irr;
@@ -0,0 +1,26 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CharacterSequenceNameString;
+
+/// Sequences to transition to instead of this sequence, based on the `ChargeLevel` reached.
+///
+/// The sequence for the highest configured tier that has been reached is used. If no configured
+/// tier has been reached, the character stays in this sequence.
+///
+/// ```yaml
+/// next_by_charge_level:
+///   level_1: "charge_attack_1"
+///   level_3: "charge_attack_3"
+/// ```
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct CharacterChargeLevelNext {
+    /// Sequence to transition to when `ChargeLevel::Level1` is reached.
+    pub level_1: Option<CharacterSequenceNameString>,
+    /// Sequence to transition to when `ChargeLevel::Level2` is reached.
+    pub level_2: Option<CharacterSequenceNameString>,
+    /// Sequence to transition to when `ChargeLevel::Level3` is reached.
+    pub level_3: Option<CharacterSequenceNameString>,
+}
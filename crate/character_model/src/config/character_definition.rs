@@ -1,13 +1,19 @@
+use std::path::PathBuf;
+
 use asset_derive::Asset;
-use charge_model::config::{ChargeDelay, ChargeLimit, ChargeRetentionMode, ChargeUseMode};
+use asset_schema::AssetSchema;
+use charge_model::config::{
+    ChargeDelay, ChargeLevels, ChargeLimit, ChargeRetentionMode, ChargeUseMode,
+};
 use derive_new::new;
 use object_model::config::ObjectDefinition;
 use serde::{Deserialize, Serialize};
+use sprite_model::config::SpriteVariant;
 
 use crate::config::CharacterSequence;
 
 /// Contains all of the sequences for a `Character`.
-#[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(Asset, AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct CharacterDefinition {
     /// Sequences of actions this object can perform.
@@ -25,4 +31,15 @@ pub struct CharacterDefinition {
     /// How charge is retained when no longer charging.
     #[serde(default)]
     pub charge_retention_mode: ChargeRetentionMode,
+    /// `ChargePoints` thresholds for each named charge tier.
+    #[serde(default)]
+    pub charge_levels: ChargeLevels,
+    /// Path to the sound to play when a higher charge level is reached.
+    ///
+    /// When not set, the UI `charge_level` sound is played.
+    #[serde(default)]
+    pub charge_level_sound: Option<PathBuf>,
+    /// Alternate appearances of the character, such as palette swaps or costumes.
+    #[serde(default)]
+    pub variants: Vec<SpriteVariant>,
 }
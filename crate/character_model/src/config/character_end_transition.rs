@@ -0,0 +1,16 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CharacterIrr, CharacterSequenceNameString};
+
+/// Sequence to transition to when a character's sequence ends, if the requirement is met.
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct CharacterEndTransition {
+    /// Sequence name to transition to.
+    pub next: CharacterSequenceNameString,
+    /// Requirement for the transition to happen.
+    #[serde(default)]
+    pub requirement: CharacterIrr,
+}
@@ -1,4 +1,6 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
+use item_model::config::HoldPoint;
 use object_model::config::{GameObjectFrame, ObjectFrame};
 use sequence_model::config::Wait;
 use serde::{Deserialize, Serialize};
@@ -6,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::CharacterInputReactions;
 
 /// Sequence frame type for characters.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct CharacterFrame {
     /// Common object behaviour specification that can change each tick.
@@ -15,6 +17,9 @@ pub struct CharacterFrame {
     /// Sequence ID to transition to when a `ControlAction` is pressed, held, or released.
     #[serde(default)]
     pub input_reactions: CharacterInputReactions,
+    /// Where a held item is positioned on this frame, and whether it is thrown.
+    #[serde(default)]
+    pub hold: HoldPoint,
 }
 
 impl AsRef<Wait> for CharacterFrame {
@@ -29,6 +34,12 @@ impl AsRef<CharacterInputReactions> for CharacterFrame {
     }
 }
 
+impl AsRef<HoldPoint> for CharacterFrame {
+    fn as_ref(&self) -> &HoldPoint {
+        &self.hold
+    }
+}
+
 impl GameObjectFrame for CharacterFrame {
     fn object_frame(&self) -> &ObjectFrame {
         &self.object_frame
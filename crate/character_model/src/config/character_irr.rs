@@ -1,4 +1,5 @@
 use amethyst::ecs::Entity;
+use asset_schema::AssetSchema;
 use charge_model::play::ChargeUseEvent;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
@@ -11,7 +12,9 @@ use crate::{
 };
 
 /// Character input reaction requirement.
-#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new,
+)]
 pub struct CharacterIrr(pub Vec<CharacterIrrPart>);
 
 impl<'s> InputReactionRequirement<'s> for CharacterIrr {
@@ -26,6 +29,9 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_modes,
             controller_inputs,
             mirroreds,
+            held_items,
+            groundings,
+            charge_levels,
             charge_use_ec,
         }: &mut Self::SystemData,
         entity: Entity,
@@ -37,6 +43,9 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_mode,
             controller_input,
             mirrored,
+            held_item,
+            grounding,
+            charge_level,
         ) = (
             health_pointses.get(entity).copied(),
             skill_pointses.get(entity).copied(),
@@ -44,6 +53,9 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_modes.get(entity).copied(),
             controller_inputs.get(entity).copied(),
             mirroreds.get(entity).copied(),
+            held_items.get(entity).copied(),
+            groundings.get(entity).copied(),
+            charge_levels.get(entity).copied(),
         );
 
         let input_reaction_requirement_params = InputReactionRequirementParams {
@@ -53,6 +65,9 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_mode,
             controller_input,
             mirrored,
+            held_item,
+            grounding,
+            charge_level,
         };
 
         let met = self.iter().all(|input_reaction_requirement| {
@@ -1,11 +1,12 @@
 use approx::{relative_eq, relative_ne};
-use charge_model::config::{ChargePoints, ChargeUseMode};
+use asset_schema::AssetSchema;
+use charge_model::config::{ChargeLevel, ChargePoints, ChargeUseMode};
 use game_input_model::{
     config::{InputDirection, InputDirectionZ},
     play::ControllerInput,
 };
 use mirrored_model::play::Mirrored;
-use object_model::play::{HealthPoints, SkillPoints};
+use object_model::play::{Grounding, HealthPoints, SkillPoints};
 use serde::{Deserialize, Serialize};
 
 use crate::config::InputReactionRequirementParams;
@@ -13,7 +14,7 @@ use crate::config::InputReactionRequirementParams;
 /// `CharacterInputReactionRequirementPart`
 ///
 /// Conditions for a character input reaction to happen.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum CharacterIrrPart {
     /// `ChargePoints` the object must spend to transition.
@@ -26,6 +27,14 @@ pub enum CharacterIrrPart {
     InputDirX(InputDirection),
     /// Whether or not there is z axis input, and the direction it is in.
     InputDirZ(InputDirectionZ),
+    /// Whether or not the object is holding an item.
+    Holding(bool),
+    /// Whether or not the object is airborne.
+    Airborne(bool),
+    /// `HealthPoints` the object must have fewer than to transition.
+    HpBelow(HealthPoints),
+    /// `ChargeLevel` the object must have reached to transition.
+    ChargeLevel(ChargeLevel),
 }
 
 impl CharacterIrrPart {
@@ -39,6 +48,9 @@ impl CharacterIrrPart {
             charge_use_mode,
             controller_input,
             mirrored,
+            held_item,
+            grounding,
+            charge_level,
         }: InputReactionRequirementParams,
     ) -> bool {
         match self {
@@ -61,6 +73,12 @@ impl CharacterIrrPart {
                     Self::input_requirement_met_z(controller_input, input_direction_z);
                 Some(requirement_met)
             }
+            Self::Holding(holding) => Some(held_item.is_some() == holding),
+            Self::Airborne(airborne) => {
+                grounding.map(|grounding| (grounding == Grounding::Airborne) == airborne)
+            }
+            Self::HpBelow(threshold) => health_points.map(|points| points < threshold),
+            Self::ChargeLevel(required) => charge_level.map(|level| level >= required),
         }
         .unwrap_or(false)
     }
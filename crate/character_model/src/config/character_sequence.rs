@@ -1,12 +1,16 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use object_model::config::{GameObjectSequence, ObjectSequence};
 use sequence_model::config::Sequence;
 use serde::{Deserialize, Serialize};
 
-use crate::config::{CharacterFrame, CharacterInputReactions, CharacterSequenceName};
+use crate::config::{
+    CharacterChargeLevelNext, CharacterEndTransition, CharacterFrame, CharacterInputReactions,
+    CharacterSequenceName,
+};
 
 /// Represents an independent action sequence of a character.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 // #[serde(deny_unknown_fields)] // See <https://github.com/serde-rs/serde/issues/1547>
 pub struct CharacterSequence {
     /// Object sequence for common object fields.
@@ -17,6 +21,18 @@ pub struct CharacterSequence {
     /// This is shared by all frames in the sequence, unless overridden.
     #[serde(default)]
     pub input_reactions: Option<CharacterInputReactions>,
+    /// Sequences to transition to when this sequence ends, in order of priority.
+    ///
+    /// The first transition whose requirement is met is used. If none are met, `next` is used.
+    #[new(default)]
+    #[serde(default)]
+    pub next_conditional: Vec<CharacterEndTransition>,
+    /// Sequences to transition to instead of this sequence, based on the `ChargeLevel` reached.
+    ///
+    /// This allows a single input reaction to pick its sequence by the charge tier.
+    #[new(default)]
+    #[serde(default)]
+    pub next_by_charge_level: CharacterChargeLevelNext,
 }
 
 impl AsRef<Sequence<CharacterSequenceName, CharacterFrame>> for CharacterSequence {
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use sequence_model::config::SequenceName;
 use serde::{Deserialize, Serialize};
@@ -5,6 +6,7 @@ use strum_macros::{Display, EnumString, IntoStaticStr};
 
 /// `Character` sequence names.
 #[derive(
+    AssetSchema,
     Clone,
     Copy,
     Debug,
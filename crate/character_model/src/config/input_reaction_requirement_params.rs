@@ -1,7 +1,11 @@
-use charge_model::{config::ChargeUseMode, play::ChargeTrackerClock};
+use charge_model::{
+    config::{ChargeLevel, ChargeUseMode},
+    play::ChargeTrackerClock,
+};
 use game_input_model::play::ControllerInput;
+use item_model::play::HeldItem;
 use mirrored_model::play::Mirrored;
-use object_model::play::{HealthPoints, SkillPoints};
+use object_model::play::{Grounding, HealthPoints, SkillPoints};
 
 /// Parameters to check if a `InputReactionRequirement` is met.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -18,4 +22,10 @@ pub struct InputReactionRequirementParams {
     pub controller_input: Option<ControllerInput>,
     /// `Mirrored` of the entity.
     pub mirrored: Option<Mirrored>,
+    /// `HeldItem` of the entity.
+    pub held_item: Option<HeldItem>,
+    /// `Grounding` of the entity.
+    pub grounding: Option<Grounding>,
+    /// `ChargeLevel` of the entity.
+    pub charge_level: Option<ChargeLevel>,
 }
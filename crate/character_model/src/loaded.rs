@@ -5,17 +5,29 @@
 
 pub use self::{
     asset_character_definition_handle::AssetCharacterDefinitionHandle,
+    character_charge_level_next::CharacterChargeLevelNext,
+    character_charge_level_sfx::CharacterChargeLevelSfx,
+    character_end_transition::CharacterEndTransition,
+    character_end_transitions::CharacterEndTransitions,
     character_hit_transitions::CharacterHitTransitions,
     character_input_reaction::CharacterInputReaction,
     character_input_reactions::{CharacterInputReactions, CharacterInputReactionsHandle},
     character_irs::{CharacterIrs, CharacterIrsHandle},
     character_irs_handles::CharacterIrsHandles,
+    character_sequence_charge_level_nexts::CharacterSequenceChargeLevelNexts,
+    character_sequence_end_transitions::CharacterSequenceEndTransitions,
 };
 
 mod asset_character_definition_handle;
+mod character_charge_level_next;
+mod character_charge_level_sfx;
+mod character_end_transition;
+mod character_end_transitions;
 mod character_hit_transitions;
 mod character_input_reaction;
 mod character_input_reactions;
 
 mod character_irs;
 mod character_irs_handles;
+mod character_sequence_charge_level_nexts;
+mod character_sequence_end_transitions;
@@ -0,0 +1,28 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use charge_model::config::ChargeLevel;
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to instead of the current sequence, based on the `ChargeLevel` reached.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct CharacterChargeLevelNext {
+    /// ID of the sequence to transition to when `ChargeLevel::Level1` is reached.
+    pub level_1: Option<SequenceId>,
+    /// ID of the sequence to transition to when `ChargeLevel::Level2` is reached.
+    pub level_2: Option<SequenceId>,
+    /// ID of the sequence to transition to when `ChargeLevel::Level3` is reached.
+    pub level_3: Option<SequenceId>,
+}
+
+impl CharacterChargeLevelNext {
+    /// Returns the sequence ID for the highest configured tier at or below the `ChargeLevel`.
+    pub fn sequence_id(self, charge_level: ChargeLevel) -> Option<SequenceId> {
+        match charge_level {
+            ChargeLevel::None => None,
+            ChargeLevel::Level1 => self.level_1,
+            ChargeLevel::Level2 => self.level_2.or(self.level_1),
+            ChargeLevel::Level3 => self.level_3.or(self.level_2).or(self.level_1),
+        }
+    }
+}
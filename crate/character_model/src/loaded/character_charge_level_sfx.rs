@@ -0,0 +1,16 @@
+use amethyst::{
+    assets::Handle,
+    audio::Source,
+    ecs::{storage::DenseVecStorage, Component},
+};
+use asset_model::ItemComponent;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Sound played when a character reaches a higher `ChargeLevel`.
+#[derive(Clone, Component, Debug, Deref, DerefMut, PartialEq, new)]
+pub struct CharacterChargeLevelSfx(pub Handle<Source>);
+
+impl<'s> ItemComponent<'s> for CharacterChargeLevelSfx {
+    type SystemData = ();
+}
@@ -0,0 +1,13 @@
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+use crate::config::CharacterIrr;
+
+/// Sequence to transition to when a character's sequence ends, if the requirement is met.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct CharacterEndTransition {
+    /// ID of the sequence to transition to.
+    pub sequence_id: SequenceId,
+    /// Requirement for the transition to happen.
+    pub requirement: CharacterIrr,
+}
@@ -0,0 +1,10 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::loaded::CharacterEndTransition;
+
+/// Conditional transitions to use when the current sequence ends, in order of priority.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct CharacterEndTransitions(pub Vec<CharacterEndTransition>);
@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::CharacterChargeLevelNext;
+
+/// Charge level transitions of each sequence.
+#[sequence_component_data(CharacterChargeLevelNext, copy)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct CharacterSequenceChargeLevelNexts;
+
+impl<'s> ItemComponent<'s> for CharacterSequenceChargeLevelNexts {
+    type SystemData = ();
+}
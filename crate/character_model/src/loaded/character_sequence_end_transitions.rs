@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::CharacterEndTransitions;
+
+/// Conditional sequence end transitions of each sequence.
+#[sequence_component_data(CharacterEndTransitions)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct CharacterSequenceEndTransitions;
+
+impl<'s> ItemComponent<'s> for CharacterSequenceEndTransitions {
+    type SystemData = ();
+}
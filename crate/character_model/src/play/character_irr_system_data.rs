@@ -4,13 +4,14 @@ use amethyst::{
     shrev::EventChannel,
 };
 use charge_model::{
-    config::ChargeUseMode,
+    config::{ChargeLevel, ChargeUseMode},
     play::{ChargeTrackerClock, ChargeUseEvent},
 };
 use derivative::Derivative;
 use game_input_model::play::ControllerInput;
+use item_model::play::HeldItem;
 use mirrored_model::play::Mirrored;
-use object_model::play::{HealthPoints, SkillPoints};
+use object_model::play::{Grounding, HealthPoints, SkillPoints};
 
 /// `SystemData` used to determine if an input reaction's requirement is met.
 #[derive(Derivative, SystemData)]
@@ -34,6 +35,15 @@ pub struct CharacterIrrSystemData<'s> {
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: ReadStorage<'s, HeldItem>,
+    /// `Grounding` components.
+    #[derivative(Debug = "ignore")]
+    pub groundings: ReadStorage<'s, Grounding>,
+    /// `ChargeLevel` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levels: ReadStorage<'s, ChargeLevel>,
     /// `ChargeUseEvent` channel.
     #[derivative(Debug = "ignore")]
     pub charge_use_ec: Write<'s, EventChannel<ChargeUseEvent>>,
@@ -4,7 +4,7 @@
 use character_model::{config::CharacterSequenceName, play::RunCounter};
 use derive_new::new;
 use game_input_model::play::ControllerInput;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 use mirrored_model::play::Mirrored;
 use object_model::play::{Grounding, HealthPoints};
 use sequence_model::play::SequenceStatus;
@@ -24,9 +24,9 @@ pub struct CharacterSequenceUpdateComponents<'c> {
     /// Whether a sequence has just begun, is ongoing, or has ended.
     pub sequence_status: SequenceStatus,
     /// Position of the character.
-    pub position: &'c Position<f32>,
+    pub position: &'c Position<FixedPoint>,
     /// Velocity of the character.
-    pub velocity: &'c Velocity<f32>,
+    pub velocity: &'c Velocity<FixedPoint>,
     /// Whether or not this object is facing left.
     pub mirrored: Mirrored,
     /// Tracks an object's attachment to the surrounding environment.
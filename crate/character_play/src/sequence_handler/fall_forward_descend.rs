@@ -1,4 +1,5 @@
 use character_model::config::CharacterSequenceName;
+use kinematic_model::config::FixedPoint;
 
 use crate::{
     sequence_handler::{common::SequenceRepeat, CharacterSequenceHandler, SwitchSequenceOnLand},
@@ -16,7 +17,7 @@ pub struct FallForwardDescend;
 
 impl CharacterSequenceHandler for FallForwardDescend {
     fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
-        if components.velocity[1] <= -10. {
+        if components.velocity[1] <= FixedPoint::from(-10) {
             FALL_FORWARD_DESCEND_BOUNCE.update(components)
         } else {
             FALL_FORWARD_DESCEND_LIE.update(components)
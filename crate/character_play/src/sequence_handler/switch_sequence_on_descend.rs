@@ -1,4 +1,5 @@
 use character_model::config::CharacterSequenceName;
+use kinematic_model::config::FixedPoint;
 use sequence_model::play::SequenceStatus;
 
 use crate::CharacterSequenceUpdateComponents;
@@ -17,7 +18,7 @@ impl SwitchSequenceOnDescend {
         components: CharacterSequenceUpdateComponents<'c>,
     ) -> Option<CharacterSequenceName> {
         // Switch to descend_sequence when Y axis velocity is no longer upwards.
-        if components.velocity[1] <= 0. {
+        if components.velocity[1] <= FixedPoint::ZERO {
             Some(self.0)
         } else if components.sequence_status == SequenceStatus::End {
             Some(components.character_sequence_name)
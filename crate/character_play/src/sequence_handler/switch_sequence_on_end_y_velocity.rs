@@ -1,5 +1,6 @@
 use character_model::config::CharacterSequenceName;
 use derive_new::new;
+use kinematic_model::config::FixedPoint;
 use sequence_model::play::SequenceStatus;
 
 use crate::CharacterSequenceUpdateComponents;
@@ -20,7 +21,7 @@ impl SwitchSequenceOnEndYVelocity {
         components: CharacterSequenceUpdateComponents<'_>,
     ) -> Option<CharacterSequenceName> {
         if components.sequence_status == SequenceStatus::End {
-            if components.velocity[1] > 0. {
+            if components.velocity[1] > FixedPoint::ZERO {
                 Some(self.upwards)
             } else {
                 Some(self.downwards)
@@ -5,17 +5,22 @@ use asset_model::loaded::AssetId;
 use character_model::{
     config::CharacterSequenceName, loaded::CharacterHitTransitions, play::RunCounter,
 };
-use charge_model::play::{ChargeRetention, ChargeTrackerClock};
+use charge_model::{
+    config::ChargeLevel,
+    play::{ChargeRetention, ChargeTrackerClock},
+};
 use game_input_model::play::ControllerInput;
+use kinematic_model::config::FixedPoint;
 use map_model::play::MapBounded;
 use object_model::{config::Mass, play::HealthPoints};
 use object_status_model::config::StunPoints;
 use sequence_model::{config::SequenceNameString, loaded::SequenceId};
+use sprite_model::loaded::SpriteSheetVariant;
 
 use crate::{CharacterComponentStorages, CharacterSpawningResources};
 
 /// Default `Character` `Mass`.
-const CHARACTER_MASS_DEFAULT: Mass = Mass(0.7);
+const CHARACTER_MASS_DEFAULT: Mass = Mass(FixedPoint::from_ratio(7, 10));
 
 /// Augments an entity with `Character` components.
 #[derive(Debug)]
@@ -29,12 +34,15 @@ impl CharacterEntityAugmenter {
     /// * `character_spawning_resources`: Resources needed to spawn the character.
     /// * `character_component_storages`: Character specific `Component` storages.
     /// * `asset_id`: Asset ID of the character.
+    /// * `variant_index`: Index of the character's appearance, where `0` is the default appearance.
     /// * `entity`: The entity to augment.
     pub fn augment<'s>(
         CharacterSpawningResources {
             asset_sequence_id_mappings_character,
             asset_character_definition_handle,
             character_definition_assets,
+            asset_sprite_sheet_handles,
+            asset_sprite_variant_sheet_handles,
         }: &CharacterSpawningResources<'s>,
         CharacterComponentStorages {
             controller_inputs,
@@ -48,9 +56,13 @@ impl CharacterEntityAugmenter {
             charge_delays,
             charge_use_modes,
             charge_retentions,
+            charge_levelses,
+            charge_levels,
             character_hit_transitionses,
+            sprite_sheet_variants,
         }: &mut CharacterComponentStorages<'s>,
         asset_id: AssetId,
+        variant_index: usize,
         entity: Entity,
     ) {
         let character_definition_handle = asset_character_definition_handle
@@ -143,8 +155,35 @@ impl CharacterEntityAugmenter {
                 ChargeRetention::from(character_definition.charge_retention_mode),
             )
             .expect("Failed to insert `ChargeUseMode` component.");
+        charge_levelses
+            .insert(entity, character_definition.charge_levels)
+            .expect("Failed to insert `ChargeLevels` component.");
+        charge_levels
+            .insert(entity, ChargeLevel::default())
+            .expect("Failed to insert `ChargeLevel` component.");
         character_hit_transitionses
             .insert(entity, character_hit_transitions)
             .expect("Failed to insert `CharacterHitTransitions` component.");
+
+        // Index `0` is the default appearance, so variants are offset by one.
+        let variant_sheet_handles = asset_sprite_variant_sheet_handles.get(asset_id).and_then(
+            |sprite_variant_sheet_handles| {
+                variant_index
+                    .checked_sub(1)
+                    .and_then(|index| sprite_variant_sheet_handles.get(index))
+            },
+        );
+        if let (Some(sprite_sheet_handles), Some(variant_sheet_handles)) = (
+            asset_sprite_sheet_handles.get(asset_id),
+            variant_sheet_handles,
+        ) {
+            let sprite_sheet_variant = SpriteSheetVariant::new(
+                sprite_sheet_handles.clone(),
+                variant_sheet_handles.clone(),
+            );
+            sprite_sheet_variants
+                .insert(entity, sprite_sheet_variant)
+                .expect("Failed to insert `SpriteSheetVariant` component.");
+        }
     }
 }
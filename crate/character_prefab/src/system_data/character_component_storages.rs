@@ -4,7 +4,7 @@ use amethyst::{
 };
 use character_model::{loaded::CharacterHitTransitions, play::RunCounter};
 use charge_model::{
-    config::{ChargeDelay, ChargeLimit, ChargeUseMode},
+    config::{ChargeDelay, ChargeLevel, ChargeLevels, ChargeLimit, ChargeUseMode},
     play::{ChargeRetention, ChargeTrackerClock},
 };
 use derivative::Derivative;
@@ -12,6 +12,7 @@ use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
 use object_model::{config::Mass, play::HealthPoints};
 use object_status_model::config::StunPoints;
+use sprite_model::loaded::SpriteSheetVariant;
 
 /// Character specific `Component` storages.
 ///
@@ -53,7 +54,16 @@ pub struct CharacterComponentStorages<'s> {
     /// `ChargeRetention` components.
     #[derivative(Debug = "ignore")]
     pub charge_retentions: WriteStorage<'s, ChargeRetention>,
+    /// `ChargeLevels` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levelses: WriteStorage<'s, ChargeLevels>,
+    /// `ChargeLevel` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levels: WriteStorage<'s, ChargeLevel>,
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: WriteStorage<'s, CharacterHitTransitions>,
+    /// `SpriteSheetVariant` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_sheet_variants: WriteStorage<'s, SpriteSheetVariant>,
 }
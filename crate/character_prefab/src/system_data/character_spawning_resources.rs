@@ -1,14 +1,18 @@
 use amethyst::{
     assets::AssetStorage,
     ecs::{Read, World},
+    renderer::sprite::SpriteSheetHandle,
     shred::{ResourceId, SystemData},
 };
+use asset_model::loaded::AssetId;
 use character_model::{
     config::{CharacterDefinition, CharacterSequenceName},
     loaded::AssetCharacterDefinitionHandle,
 };
 use derivative::Derivative;
 use sequence_model::loaded::AssetSequenceIdMappings;
+use slotmap::SecondaryMap;
+use sprite_model::loaded::AssetSpriteVariantSheetHandles;
 
 /// Resources used to spawn character entities.
 #[derive(Derivative, SystemData)]
@@ -24,4 +28,10 @@ pub struct CharacterSpawningResources<'s> {
     /// `CharacterDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub character_definition_assets: Read<'s, AssetStorage<CharacterDefinition>>,
+    /// `SecondaryMap<AssetId, Vec<SpriteSheetHandle>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprite_sheet_handles: Read<'s, SecondaryMap<AssetId, Vec<SpriteSheetHandle>>>,
+    /// `AssetSpriteVariantSheetHandles` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprite_variant_sheet_handles: Read<'s, AssetSpriteVariantSheetHandles>,
 }
@@ -6,7 +6,9 @@ pub use crate::{
     character_selection_state::{
         CharacterSelectionState, CharacterSelectionStateBuilder, CharacterSelectionStateDelegate,
     },
-    system::CharacterSelectionSystem,
+    system::{
+        CharacterSelectionSystem, CharacterVariantInputSystem, VARIANT_SWITCH_CONTROL_ACTION,
+    },
 };
 
 mod character_selection_state;
@@ -1,3 +1,7 @@
-pub use self::character_selection_system::CharacterSelectionSystem;
+pub use self::{
+    character_selection_system::CharacterSelectionSystem,
+    character_variant_input_system::{CharacterVariantInputSystem, VARIANT_SWITCH_CONTROL_ACTION},
+};
 
 mod character_selection_system;
+mod character_variant_input_system;
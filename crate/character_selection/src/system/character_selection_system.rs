@@ -1,10 +1,18 @@
 use amethyst::{
+    assets::AssetStorage,
     ecs::{Read, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use asset_model::{config::AssetType, loaded::AssetTypeMappings};
-use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
+use asset_model::{
+    config::AssetType,
+    loaded::{AssetId, AssetTypeMappings},
+};
+use asset_selection_model::{
+    config::AssetSwitch,
+    play::{AssetSelection, AssetSelectionEvent},
+};
+use character_model::{config::CharacterDefinition, loaded::AssetCharacterDefinitionHandle};
 use character_selection_model::CharacterSelections;
 use derivative::Derivative;
 use derive_new::new;
@@ -28,11 +36,34 @@ pub struct CharacterSelectionSystemData<'s> {
     /// `AssetTypeMappings` resource.
     #[derivative(Debug = "ignore")]
     pub asset_type_mappings: Read<'s, AssetTypeMappings>,
+    /// `AssetCharacterDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_character_definition_handle: Read<'s, AssetCharacterDefinitionHandle>,
+    /// `CharacterDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub character_definition_assets: Read<'s, AssetStorage<CharacterDefinition>>,
     /// `CharacterSelections` resource.
     #[derivative(Debug = "ignore")]
     pub character_selections: Write<'s, CharacterSelections>,
 }
 
+impl CharacterSelectionSystem {
+    /// Returns the number of appearances of a character, including its default appearance.
+    fn variant_count(
+        asset_character_definition_handle: &AssetCharacterDefinitionHandle,
+        character_definition_assets: &AssetStorage<CharacterDefinition>,
+        asset_id: AssetId,
+    ) -> usize {
+        asset_character_definition_handle
+            .get(asset_id)
+            .and_then(|character_definition_handle| {
+                character_definition_assets.get(character_definition_handle)
+            })
+            .map(|character_definition| character_definition.variants.len() + 1)
+            .unwrap_or(1)
+    }
+}
+
 impl<'s> System<'s> for CharacterSelectionSystem {
     type SystemData = CharacterSelectionSystemData<'s>;
 
@@ -41,6 +72,8 @@ impl<'s> System<'s> for CharacterSelectionSystem {
         CharacterSelectionSystemData {
             asset_selection_ec,
             asset_type_mappings,
+            asset_character_definition_handle,
+            character_definition_assets,
             mut character_selections,
         }: Self::SystemData,
     ) {
@@ -72,9 +105,23 @@ impl<'s> System<'s> for CharacterSelectionSystem {
 
                     let asset_type = asset_type_mappings.get(asset_id);
                     if let Some(AssetType::Object(ObjectType::Character)) = asset_type {
+                        // Players who select the same character are given different variants.
+                        let variant_count = Self::variant_count(
+                            &asset_character_definition_handle,
+                            &character_definition_assets,
+                            asset_id,
+                        );
+                        let variant_index = character_selections.variant_available(
+                            controller_id,
+                            asset_id,
+                            variant_count,
+                        );
                         character_selections
                             .selections
                             .insert(controller_id, asset_id);
+                        character_selections
+                            .variants
+                            .insert(controller_id, variant_index);
                     } else {
                         warn!(
                             "Received `AssetSelectionEvent` for {:?} which has type: {:?} in \
@@ -85,6 +132,39 @@ impl<'s> System<'s> for CharacterSelectionSystem {
                 }
                 AssetSelectionEvent::Deselect { controller_id, .. } => {
                     character_selections.selections.remove(&controller_id);
+                    character_selections.variants.remove(&controller_id);
+                }
+                AssetSelectionEvent::VariantSwitch {
+                    controller_id,
+                    variant_switch,
+                    ..
+                } => {
+                    let asset_id = character_selections.selections.get(&controller_id).copied();
+                    if let Some(asset_id) = asset_id {
+                        let variant_count = Self::variant_count(
+                            &asset_character_definition_handle,
+                            &character_definition_assets,
+                            asset_id,
+                        );
+                        let n = match variant_switch {
+                            AssetSwitch::Previous => -1,
+                            AssetSwitch::Next => 1,
+                            AssetSwitch::Skip(n) => n.get(),
+                        };
+                        if let Some(variant_index) =
+                            character_selections.variant_switched(controller_id, variant_count, n)
+                        {
+                            character_selections
+                                .variants
+                                .insert(controller_id, variant_index);
+                        }
+                    } else {
+                        warn!(
+                            "Received `AssetSelectionEvent::VariantSwitch` for controller {} \
+                            which has not selected a character.",
+                            controller_id
+                        );
+                    }
                 }
                 _ => {}
             });
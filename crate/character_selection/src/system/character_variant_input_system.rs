@@ -0,0 +1,87 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_selection_model::{config::AssetSwitch, play::AssetSelectionEvent};
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::ControlAction,
+    play::{ControlActionEventData, ControlInputEvent},
+};
+
+/// `ControlAction` that switches the variant of a selected character.
+pub const VARIANT_SWITCH_CONTROL_ACTION: ControlAction = ControlAction::Special;
+
+/// Sends `AssetSelectionEvent::VariantSwitch` events when a player presses the variant switch
+/// action after selecting a character.
+#[derive(Debug, Default, new)]
+pub struct CharacterVariantInputSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[new(default)]
+    control_input_event_rid: Option<ReaderId<ControlInputEvent>>,
+}
+
+/// `CharacterVariantInputSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterVariantInputSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `AssetSelectionEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub asset_selection_ec: Write<'s, EventChannel<AssetSelectionEvent>>,
+}
+
+impl<'s> System<'s> for CharacterVariantInputSystem {
+    type SystemData = CharacterVariantInputSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CharacterVariantInputSystemData {
+            control_input_ec,
+            character_selections,
+            mut asset_selection_ec,
+        }: Self::SystemData,
+    ) {
+        let control_input_event_rid = self
+            .control_input_event_rid
+            .as_mut()
+            .expect("Expected `control_input_event_rid` field to be set.");
+
+        let variant_switch_events = control_input_ec
+            .read(control_input_event_rid)
+            .filter_map(|ev| match ev {
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    controller_id,
+                    control_action: VARIANT_SWITCH_CONTROL_ACTION,
+                    ..
+                }) if character_selections.selections.contains_key(controller_id) => {
+                    Some(AssetSelectionEvent::VariantSwitch {
+                        entity: None,
+                        controller_id: *controller_id,
+                        variant_switch: AssetSwitch::Next,
+                    })
+                }
+                _ => None,
+            })
+            .collect::<Vec<AssetSelectionEvent>>();
+
+        asset_selection_ec.iter_write(variant_switch_events);
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.control_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ControlInputEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use asset_model::loaded::AssetId;
 use derive_new::new;
@@ -11,4 +11,88 @@ use game_input_model::config::ControllerId;
 pub struct CharacterSelections {
     /// Map of controller ID to character asset ID.
     pub selections: HashMap<ControllerId, AssetId>,
+    /// Map of controller ID to the index of the selected character's appearance.
+    ///
+    /// `0` is the character's default appearance.
+    #[new(default)]
+    pub variants: HashMap<ControllerId, usize>,
+}
+
+impl CharacterSelections {
+    /// Returns the lowest variant index of a character that no other controller has selected.
+    ///
+    /// If every variant is selected by other controllers, the default appearance is returned.
+    ///
+    /// This only depends on the current selections, so every device in a network session assigns
+    /// the same variant when it receives the same selection events.
+    ///
+    /// # Parameters
+    ///
+    /// * `controller_id`: ID of the controller selecting the character.
+    /// * `asset_id`: Asset ID of the selected character.
+    /// * `variant_count`: Number of appearances of the character, including the default.
+    pub fn variant_available(
+        &self,
+        controller_id: ControllerId,
+        asset_id: AssetId,
+        variant_count: usize,
+    ) -> usize {
+        let variants_used = self.variants_used(controller_id, asset_id);
+
+        (0..variant_count)
+            .find(|variant_index| !variants_used.contains(variant_index))
+            .unwrap_or(0)
+    }
+
+    /// Returns the variant index `n` variants away from the controller's current variant.
+    ///
+    /// Variants selected by other controllers for the same character are skipped, and the index
+    /// wraps around within the character's variants.
+    ///
+    /// Returns `None` if the controller has not selected a character.
+    ///
+    /// # Parameters
+    ///
+    /// * `controller_id`: ID of the controller switching its variant.
+    /// * `variant_count`: Number of appearances of the character, including the default.
+    /// * `n`: Number of variants to switch by, negative to switch to previous variants.
+    pub fn variant_switched(
+        &self,
+        controller_id: ControllerId,
+        variant_count: usize,
+        n: isize,
+    ) -> Option<usize> {
+        let asset_id = *self.selections.get(&controller_id)?;
+        let variant_current = self.variants.get(&controller_id).copied().unwrap_or(0);
+        let variants_used = self.variants_used(controller_id, asset_id);
+
+        let variants_selectable = (0..variant_count)
+            .filter(|variant_index| {
+                *variant_index == variant_current || !variants_used.contains(variant_index)
+            })
+            .collect::<Vec<usize>>();
+        if variants_selectable.is_empty() {
+            return None;
+        }
+
+        let position = variants_selectable
+            .iter()
+            .position(|variant_index| *variant_index == variant_current)
+            .unwrap_or(0) as isize;
+        let index = (position + n).rem_euclid(variants_selectable.len() as isize) as usize;
+
+        Some(variants_selectable[index])
+    }
+
+    /// Returns the variant indices of a character that other controllers have selected.
+    fn variants_used(&self, controller_id: ControllerId, asset_id: AssetId) -> HashSet<usize> {
+        self.selections
+            .iter()
+            .filter(|(controller_id_other, asset_id_other)| {
+                **controller_id_other != controller_id && **asset_id_other == asset_id
+            })
+            .filter_map(|(controller_id_other, _)| self.variants.get(controller_id_other))
+            .copied()
+            .collect::<HashSet<usize>>()
+    }
 }
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use asset_ui_model::config::AssetSelector;
 use object_type::Character;
 use serde::{Deserialize, Serialize};
@@ -5,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::{CswDefinition, CswTemplate};
 
 /// Configuration for initializing the character selection UI.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct CharacterSelectionUi {
     /// Positions of the asset preview widgets on screen.
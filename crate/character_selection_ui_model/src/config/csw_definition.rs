@@ -1,8 +1,9 @@
+use asset_schema::AssetSchema;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for a character selection asset preview widget.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct CswDefinition {
     /// Position of the asset preview widget on screen.
@@ -1,7 +1,8 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 /// Keys for special handling of asset preview widget layers.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum CswLayerName {
     /// Main widget entity.
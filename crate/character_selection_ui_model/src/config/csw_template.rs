@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use asset_ui_model::config::AswPortraits;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -6,7 +7,7 @@ use ui_label_model::config::UiSpriteLabel;
 use crate::config::CswLayer;
 
 /// Template for initializing each asset preview widget.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct CswTemplate {
     /// Portraits to use while character selection is not present.
@@ -1,11 +1,14 @@
 //! User defined configuration types for charges.
 
 pub use self::{
-    charge_delay::ChargeDelay, charge_limit::ChargeLimit, charge_points::ChargePoints,
+    charge_delay::ChargeDelay, charge_level::ChargeLevel, charge_levels::ChargeLevels,
+    charge_limit::ChargeLimit, charge_points::ChargePoints,
     charge_retention_mode::ChargeRetentionMode, charge_use_mode::ChargeUseMode,
 };
 
 mod charge_delay;
+mod charge_level;
+mod charge_levels;
 mod charge_limit;
 mod charge_points;
 mod charge_retention_mode;
@@ -0,0 +1,37 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use asset_schema::AssetSchema;
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// Named charge tier reached by an object.
+///
+/// Tiers are ordered, so `Level3` is greater than `Level1`.
+#[derive(
+    AssetSchema,
+    Clone,
+    Component,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+#[storage(VecStorage)]
+pub enum ChargeLevel {
+    /// No charge tier has been reached.
+    #[derivative(Default)]
+    None,
+    /// First charge tier.
+    Level1,
+    /// Second charge tier.
+    Level2,
+    /// Third charge tier.
+    Level3,
+}
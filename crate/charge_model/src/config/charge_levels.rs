@@ -0,0 +1,48 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ChargeLevel, ChargePoints};
+
+/// `ChargePoints` thresholds for each `ChargeLevel` of an object.
+///
+/// Tiers without a threshold are never reached.
+///
+/// ```yaml
+/// charge_levels:
+///   level_1: 30
+///   level_2: 60
+///   level_3: 90
+/// ```
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new,
+)]
+#[serde(default, deny_unknown_fields)]
+#[storage(DenseVecStorage)]
+pub struct ChargeLevels {
+    /// `ChargePoints` needed to reach `ChargeLevel::Level1`.
+    pub level_1: Option<ChargePoints>,
+    /// `ChargePoints` needed to reach `ChargeLevel::Level2`.
+    pub level_2: Option<ChargePoints>,
+    /// `ChargePoints` needed to reach `ChargeLevel::Level3`.
+    pub level_3: Option<ChargePoints>,
+}
+
+impl ChargeLevels {
+    /// Returns the highest `ChargeLevel` whose threshold is met by the given `ChargePoints`.
+    pub fn level(self, charge_points: ChargePoints) -> ChargeLevel {
+        let threshold_met =
+            |threshold: Option<ChargePoints>| threshold.map_or(false, |t| charge_points >= t);
+
+        if threshold_met(self.level_3) {
+            ChargeLevel::Level3
+        } else if threshold_met(self.level_2) {
+            ChargeLevel::Level2
+        } else if threshold_met(self.level_1) {
+            ChargeLevel::Level1
+        } else {
+            ChargeLevel::None
+        }
+    }
+}
@@ -1,8 +1,9 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// How charge is retained when no longer charging.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ChargeRetentionMode {
@@ -1,9 +1,12 @@
 use amethyst::ecs::{storage::VecStorage, Component};
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Charge usage subtraction variants.
-#[derive(Clone, Component, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize,
+)]
 #[derivative(Default)]
 #[serde(rename_all = "snake_case")]
 #[storage(VecStorage)]
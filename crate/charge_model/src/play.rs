@@ -2,16 +2,19 @@
 
 pub use self::{
     charge_begin_delay_clock::ChargeBeginDelayClock, charge_delay_clock::ChargeDelayClock,
-    charge_retention::ChargeRetention, charge_retention_clock::ChargeRetentionClock,
-    charge_status::ChargeStatus, charge_tracker::ChargeTracker,
+    charge_level_event::ChargeLevelEvent, charge_retention::ChargeRetention,
+    charge_retention_clock::ChargeRetentionClock, charge_status::ChargeStatus,
+    charge_tint_pulse::ChargeTintPulse, charge_tracker::ChargeTracker,
     charge_tracker_clock::ChargeTrackerClock, charge_use_event::ChargeUseEvent,
 };
 
 mod charge_begin_delay_clock;
 mod charge_delay_clock;
+mod charge_level_event;
 mod charge_retention;
 mod charge_retention_clock;
 mod charge_status;
+mod charge_tint_pulse;
 mod charge_tracker;
 mod charge_tracker_clock;
 mod charge_use_event;
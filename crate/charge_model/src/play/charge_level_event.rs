@@ -0,0 +1,13 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+
+use crate::config::ChargeLevel;
+
+/// Event indicating an object has reached a higher `ChargeLevel`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct ChargeLevelEvent {
+    /// Entity that reached the `ChargeLevel`.
+    pub entity: Entity,
+    /// `ChargeLevel` reached.
+    pub charge_level: ChargeLevel,
+}
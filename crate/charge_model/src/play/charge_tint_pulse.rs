@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+
+use crate::config::ChargeLevel;
+
+/// Tints an object in the colour of the `ChargeLevel` it reached, fading out over time.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct ChargeTintPulse {
+    /// `ChargeLevel` that was reached.
+    pub charge_level: ChargeLevel,
+    /// Number of ticks the pulse has run for.
+    #[new(default)]
+    pub ticks: u32,
+}
@@ -4,7 +4,8 @@
 
 pub use crate::system::{
     ChargeIncrementSystem, ChargeInitializeDelaySystem, ChargeInitializeDetectionSystem,
-    ChargeRetentionSystem, ChargeUsageSystem, CHARGE_DELAY_DEFAULT,
+    ChargeLevelUpdateSystem, ChargeRetentionSystem, ChargeTintPulseSystem, ChargeUsageSystem,
+    CHARGE_DELAY_DEFAULT, CHARGE_TINT_PULSE_DURATION,
 };
 
 mod system;
@@ -2,12 +2,16 @@ pub use self::{
     charge_increment_system::ChargeIncrementSystem,
     charge_initialize_delay_system::ChargeInitializeDelaySystem,
     charge_initialize_detection_system::{ChargeInitializeDetectionSystem, CHARGE_DELAY_DEFAULT},
+    charge_level_update_system::ChargeLevelUpdateSystem,
     charge_retention_system::ChargeRetentionSystem,
+    charge_tint_pulse_system::{ChargeTintPulseSystem, CHARGE_TINT_PULSE_DURATION},
     charge_usage_system::ChargeUsageSystem,
 };
 
 mod charge_increment_system;
 mod charge_initialize_delay_system;
 mod charge_initialize_detection_system;
+mod charge_level_update_system;
 mod charge_retention_system;
+mod charge_tint_pulse_system;
 mod charge_usage_system;
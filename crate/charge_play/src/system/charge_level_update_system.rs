@@ -0,0 +1,68 @@
+use amethyst::{
+    ecs::{Entities, Join, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use charge_model::{
+    config::{ChargeLevel, ChargeLevels, ChargePoints},
+    play::{ChargeLevelEvent, ChargeTrackerClock},
+};
+use derivative::Derivative;
+use derive_new::new;
+
+/// Updates `ChargeLevel` from `ChargeTrackerClock`, sending an event when a higher tier is reached.
+#[derive(Debug, Default, new)]
+pub struct ChargeLevelUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ChargeLevelUpdateSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChargeLevels` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levelses: ReadStorage<'s, ChargeLevels>,
+    /// `ChargeTrackerClock` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tracker_clocks: ReadStorage<'s, ChargeTrackerClock>,
+    /// `ChargeLevel` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levels: WriteStorage<'s, ChargeLevel>,
+    /// `ChargeLevelEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub charge_level_ec: Write<'s, EventChannel<ChargeLevelEvent>>,
+}
+
+impl<'s> System<'s> for ChargeLevelUpdateSystem {
+    type SystemData = ChargeLevelUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ChargeLevelUpdateSystemData {
+            entities,
+            charge_levelses,
+            charge_tracker_clocks,
+            mut charge_levels,
+            mut charge_level_ec,
+        }: Self::SystemData,
+    ) {
+        (&entities, &charge_levelses, &charge_tracker_clocks)
+            .join()
+            .for_each(|(entity, charge_levels_config, charge_tracker_clock)| {
+                let charge_points = ChargePoints::new((*charge_tracker_clock).value as u32);
+                let charge_level = charge_levels_config.level(charge_points);
+                let charge_level_previous = charge_levels.get(entity).copied().unwrap_or_default();
+
+                if charge_level != charge_level_previous {
+                    charge_levels
+                        .insert(entity, charge_level)
+                        .expect("Failed to insert `ChargeLevel` component.");
+
+                    if charge_level > charge_level_previous {
+                        charge_level_ec.single_write(ChargeLevelEvent::new(entity, charge_level));
+                    }
+                }
+            });
+    }
+}
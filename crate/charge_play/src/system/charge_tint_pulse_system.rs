@@ -0,0 +1,136 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use charge_model::{
+    config::ChargeLevel,
+    play::{ChargeLevelEvent, ChargeTintPulse},
+};
+use derivative::Derivative;
+use derive_new::new;
+
+/// Number of ticks a `ChargeTintPulse` lasts for.
+pub const CHARGE_TINT_PULSE_DURATION: u32 = 12;
+
+/// Tint for `ChargeLevel::None`.
+const COLOUR_DEFAULT: (f32, f32, f32, f32) = (1., 1., 1., 1.);
+/// Tint at the beginning of the pulse for `ChargeLevel::Level1`.
+const COLOUR_LEVEL_1: (f32, f32, f32, f32) = (1., 1., 0.5, 1.);
+/// Tint at the beginning of the pulse for `ChargeLevel::Level2`.
+const COLOUR_LEVEL_2: (f32, f32, f32, f32) = (1., 0.7, 0.3, 1.);
+/// Tint at the beginning of the pulse for `ChargeLevel::Level3`.
+const COLOUR_LEVEL_3: (f32, f32, f32, f32) = (1., 0.4, 0.4, 1.);
+
+/// Tracks `ChargeTintPulse`s for objects that reach a higher `ChargeLevel`.
+///
+/// The pulse is layered over the object's `Tint` with [`ChargeTintPulseSystem::pulse_blend`] when
+/// the object's `Tint` is resolved, so it fades back to the frame's `Tint` as the pulse ticks.
+#[derive(Debug, Default, new)]
+pub struct ChargeTintPulseSystem {
+    /// Reader ID for the `ChargeLevelEvent` channel.
+    #[new(default)]
+    charge_level_event_rid: Option<ReaderId<ChargeLevelEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ChargeTintPulseSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChargeLevelEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub charge_level_ec: Read<'s, EventChannel<ChargeLevelEvent>>,
+    /// `ChargeTintPulse` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tint_pulses: WriteStorage<'s, ChargeTintPulse>,
+}
+
+impl ChargeTintPulseSystem {
+    /// Returns the `Tint` at the beginning of the pulse for the given `ChargeLevel`.
+    pub fn pulse_tint(charge_level: ChargeLevel) -> Tint {
+        let colour = match charge_level {
+            ChargeLevel::None => COLOUR_DEFAULT,
+            ChargeLevel::Level1 => COLOUR_LEVEL_1,
+            ChargeLevel::Level2 => COLOUR_LEVEL_2,
+            ChargeLevel::Level3 => COLOUR_LEVEL_3,
+        };
+        Self::colour_to_tint(colour)
+    }
+
+    /// Returns the `Tint` blended from `tint` towards the pulse's `Tint`.
+    ///
+    /// The pulse's `Tint` fades out over `CHARGE_TINT_PULSE_DURATION` ticks.
+    pub fn pulse_blend(Tint(tint): Tint, charge_tint_pulse: ChargeTintPulse) -> Tint {
+        let Tint(pulse_tint) = Self::pulse_tint(charge_tint_pulse.charge_level);
+
+        let ticks = charge_tint_pulse.ticks.min(CHARGE_TINT_PULSE_DURATION);
+        let factor = 1. - ticks as f32 / CHARGE_TINT_PULSE_DURATION as f32;
+        Tint(Srgba::new(
+            Self::lerp(tint.red, pulse_tint.red, factor),
+            Self::lerp(tint.green, pulse_tint.green, factor),
+            Self::lerp(tint.blue, pulse_tint.blue, factor),
+            Self::lerp(tint.alpha, pulse_tint.alpha, factor),
+        ))
+    }
+
+    fn colour_to_tint((red, green, blue, alpha): (f32, f32, f32, f32)) -> Tint {
+        Tint(Srgba::new(red, green, blue, alpha))
+    }
+
+    fn lerp(from: f32, to: f32, factor: f32) -> f32 {
+        from + (to - from) * factor
+    }
+}
+
+impl<'s> System<'s> for ChargeTintPulseSystem {
+    type SystemData = ChargeTintPulseSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ChargeTintPulseSystemData {
+            entities,
+            charge_level_ec,
+            mut charge_tint_pulses,
+        }: Self::SystemData,
+    ) {
+        let charge_level_event_rid = self
+            .charge_level_event_rid
+            .as_mut()
+            .expect("Expected `charge_level_event_rid` field to be set.");
+
+        charge_level_ec.read(charge_level_event_rid).for_each(|ev| {
+            charge_tint_pulses
+                .insert(ev.entity, ChargeTintPulse::new(ev.charge_level))
+                .expect("Failed to insert `ChargeTintPulse` component.");
+        });
+
+        let pulses_complete = (&entities, &mut charge_tint_pulses)
+            .join()
+            .filter_map(|(entity, charge_tint_pulse)| {
+                charge_tint_pulse.ticks += 1;
+                if charge_tint_pulse.ticks >= CHARGE_TINT_PULSE_DURATION {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<Entity>>();
+
+        pulses_complete.into_iter().for_each(|entity| {
+            charge_tint_pulses.remove(entity);
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.charge_level_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ChargeLevelEvent>>()
+                .register_reader(),
+        );
+    }
+}
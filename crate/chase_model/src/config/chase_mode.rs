@@ -1,10 +1,34 @@
+use asset_schema::AssetSchema;
+use kinematic_model::config::FixedPoint;
 use serde::{Deserialize, Serialize};
 
 /// Mode that this entity chases the target object.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum ChaseMode {
     /// Sticks to the target object.
     ///
     /// The `Position` and `Transform` of this entity is copied from the target entity.
     Stick,
+    /// Steers towards the nearest object on a different `Team`.
+    Homing {
+        /// Speed to travel at, in pixels per tick.
+        speed: FixedPoint,
+        /// Maximum angle to turn each tick, in radians.
+        turn_rate: FixedPoint,
+    },
+    /// Circles the target object on the ground plane.
+    Orbit {
+        /// Distance to keep from the target object.
+        radius: FixedPoint,
+        /// Angle to travel around the target object each tick, in radians.
+        angular_speed: FixedPoint,
+    },
+    /// Trails behind the target object.
+    ///
+    /// The `Position` of this entity is the target entity's position `delay` ticks ago.
+    Follow {
+        /// Number of ticks to trail behind the target object.
+        delay: u32,
+    },
 }
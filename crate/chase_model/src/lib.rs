@@ -3,4 +3,5 @@
 //! Types used to represent chase data.
 
 pub mod config;
+pub mod loaded;
 pub mod play;
@@ -0,0 +1,6 @@
+//! Types that represent processed configuration.
+
+pub use self::{chase_mode_opt::ChaseModeOpt, chase_modes::ChaseModes};
+
+mod chase_mode_opt;
+mod chase_modes;
@@ -0,0 +1,9 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::ChaseMode;
+
+/// Newtype for an `Option<ChaseMode>`, as we need to implement `Component` on it.
+#[derive(Clone, Component, Copy, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct ChaseModeOpt(pub Option<ChaseMode>);
@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::ChaseModeOpt;
+
+/// Chase mode of each sequence.
+#[sequence_component_data(ChaseModeOpt, copy)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct ChaseModes;
+
+impl<'s> ItemComponent<'s> for ChaseModes {
+    type SystemData = ();
+}
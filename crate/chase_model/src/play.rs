@@ -1,6 +1,9 @@
 //! Contains data types used during game play.
 
-pub use self::{chase_mode_stick::ChaseModeStick, target_object::TargetObject};
+pub use self::{
+    chase_mode_stick::ChaseModeStick, follow_trail::FollowTrail, target_object::TargetObject,
+};
 
 mod chase_mode_stick;
+mod follow_trail;
 mod target_object;
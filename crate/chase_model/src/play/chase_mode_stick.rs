@@ -5,13 +5,13 @@ use amethyst::{
 use asset_model::ItemComponent;
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 
 /// Component indicating the chaser should stick to the target object.
 #[derive(Clone, Component, Copy, Debug, Default, PartialEq, new)]
 pub struct ChaseModeStick {
     /// Fixed offset from the target object.
-    pub offset: Option<Position<f32>>,
+    pub offset: Option<Position<FixedPoint>>,
 }
 
 /// `ChaseModeStickSystemData`.
@@ -0,0 +1,12 @@
+use std::collections::VecDeque;
+
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position};
+
+/// Positions of the target object that a following entity trails behind.
+#[derive(Clone, Component, Debug, Default, PartialEq, new)]
+pub struct FollowTrail {
+    /// Positions of the target object, oldest first.
+    pub positions: VecDeque<Position<FixedPoint>>,
+}
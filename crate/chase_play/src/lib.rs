@@ -2,6 +2,12 @@
 
 //! Provides logic for game objects used during game play.
 
-pub use crate::system::StickToTargetObjectSystem;
+pub use crate::system::{
+    FollowTargetObjectSystem, HomeTowardsEnemySystem, OrbitTargetObjectSystem,
+    StickToTargetObjectSystem,
+};
+
+pub(crate) use crate::target_entity_resolver::TargetEntityResolver;
 
 mod system;
+mod target_entity_resolver;
@@ -1,3 +1,11 @@
-pub use self::stick_to_target_object_system::StickToTargetObjectSystem;
+pub use self::{
+    follow_target_object_system::FollowTargetObjectSystem,
+    home_towards_enemy_system::HomeTowardsEnemySystem,
+    orbit_target_object_system::OrbitTargetObjectSystem,
+    stick_to_target_object_system::StickToTargetObjectSystem,
+};
 
+mod follow_target_object_system;
+mod home_towards_enemy_system;
+mod orbit_target_object_system;
 mod stick_to_target_object_system;
@@ -0,0 +1,95 @@
+use amethyst::{
+    ecs::{Entities, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use chase_model::{
+    config::ChaseMode,
+    loaded::ChaseModeOpt,
+    play::{FollowTrail, TargetObject},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position};
+use spawn_model::play::SpawnParent;
+
+use crate::TargetEntityResolver;
+
+/// Moves `ChaseMode::Follow` entities to where their target object was `delay` ticks ago.
+///
+/// Until the target object has been tracked for `delay` ticks, the entity stays where it is.
+#[derive(Debug, Default, new)]
+pub struct FollowTargetObjectSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct FollowTargetObjectSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChaseModeOpt` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_opts: ReadStorage<'s, ChaseModeOpt>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: ReadStorage<'s, TargetObject>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `FollowTrail` components.
+    #[derivative(Debug = "ignore")]
+    pub follow_trails: WriteStorage<'s, FollowTrail>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+}
+
+impl<'s> System<'s> for FollowTargetObjectSystem {
+    type SystemData = FollowTargetObjectSystemData<'s>;
+
+    fn run(
+        &mut self,
+        FollowTargetObjectSystemData {
+            entities,
+            chase_mode_opts,
+            target_objects,
+            spawn_parents,
+            mut follow_trails,
+            mut positions,
+        }: Self::SystemData,
+    ) {
+        (&entities, &chase_mode_opts)
+            .join()
+            .filter_map(|(entity, chase_mode_opt)| {
+                if let Some(ChaseMode::Follow { delay }) = **chase_mode_opt {
+                    Some((entity, delay))
+                } else {
+                    None
+                }
+            })
+            .for_each(|(entity, delay)| {
+                let target_position =
+                    TargetEntityResolver::resolve(&target_objects, &spawn_parents, entity)
+                        .and_then(|target_entity| positions.get(target_entity).copied());
+                let target_position = if let Some(target_position) = target_position {
+                    target_position
+                } else {
+                    return;
+                };
+
+                let follow_trail = follow_trails
+                    .entry(entity)
+                    .expect("Expected entity to be alive.")
+                    .or_insert_with(FollowTrail::default);
+                follow_trail.positions.push_back(target_position);
+
+                if follow_trail.positions.len() > delay as usize {
+                    let position_trail = follow_trail.positions.pop_front();
+                    if let (Some(position_trail), Some(position)) =
+                        (position_trail, positions.get_mut(entity))
+                    {
+                        *position = position_trail;
+                    }
+                }
+            });
+    } // kcov-ignore
+}
@@ -0,0 +1,177 @@
+use amethyst::{
+    core::math::Vector2,
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use chase_model::{config::ChaseMode, loaded::ChaseModeOpt};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position, Velocity};
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use team_model::play::Team;
+
+/// Steers `ChaseMode::Homing` entities towards the nearest object on a different `Team`.
+///
+/// Homing happens on the ground plane -- the **Y** velocity is left untouched. Directions are
+/// represented as `Vector2`s of the **X** and **Z** axes.
+#[derive(Debug, Default, new)]
+pub struct HomeTowardsEnemySystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct HomeTowardsEnemySystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChaseModeOpt` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_opts: ReadStorage<'s, ChaseModeOpt>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+}
+
+impl<'s> System<'s> for HomeTowardsEnemySystem {
+    type SystemData = HomeTowardsEnemySystemData<'s>;
+
+    fn run(
+        &mut self,
+        HomeTowardsEnemySystemData {
+            entities,
+            chase_mode_opts,
+            teams,
+            health_pointses,
+            positions,
+            mut velocities,
+            mut mirroreds,
+        }: Self::SystemData,
+    ) {
+        (
+            &entities,
+            &chase_mode_opts,
+            &positions,
+            &mut velocities,
+            (&mut mirroreds).maybe(),
+        )
+            .join()
+            .filter_map(|(entity, chase_mode_opt, position, velocity, mirrored)| {
+                if let Some(ChaseMode::Homing { speed, turn_rate }) = **chase_mode_opt {
+                    Some((entity, speed, turn_rate, position, velocity, mirrored))
+                } else {
+                    None
+                }
+            })
+            .for_each(|(entity, speed, turn_rate, position, velocity, mirrored)| {
+                let direction_current = Self::normalize(Vector2::new(velocity.x, velocity.z))
+                    .unwrap_or_else(|| match mirrored.as_deref() {
+                        Some(Mirrored(true)) => Vector2::new(-FixedPoint::ONE, FixedPoint::ZERO),
+                        _ => Vector2::new(FixedPoint::ONE, FixedPoint::ZERO),
+                    });
+
+                let team = teams.get(entity).copied();
+                let direction_target = Self::nearest_enemy(
+                    &entities,
+                    &teams,
+                    &health_pointses,
+                    &positions,
+                    entity,
+                    team,
+                    position,
+                )
+                .and_then(|position_enemy| {
+                    Self::normalize(Vector2::new(
+                        position_enemy.x - position.x,
+                        position_enemy.z - position.z,
+                    ))
+                });
+
+                let direction = if let Some(direction_target) = direction_target {
+                    Self::steer(direction_current, direction_target, turn_rate)
+                } else {
+                    direction_current
+                };
+
+                velocity.x = direction.x * speed;
+                velocity.z = direction.y * speed;
+
+                if let Some(mirrored) = mirrored {
+                    if velocity.x != FixedPoint::ZERO {
+                        mirrored.0 = velocity.x < FixedPoint::ZERO;
+                    }
+                }
+            });
+    } // kcov-ignore
+}
+
+impl HomeTowardsEnemySystem {
+    /// Returns the position of the nearest living object on a different team.
+    fn nearest_enemy<'p>(
+        entities: &Entities<'_>,
+        teams: &ReadStorage<'_, Team>,
+        health_pointses: &ReadStorage<'_, HealthPoints>,
+        positions: &'p ReadStorage<'_, Position<FixedPoint>>,
+        entity: Entity,
+        team: Option<Team>,
+        position: &Position<FixedPoint>,
+    ) -> Option<&'p Position<FixedPoint>> {
+        (entities, teams, health_pointses, positions)
+            .join()
+            .filter(|(entity_other, team_other, health_points, _)| {
+                *entity_other != entity && Some(**team_other) != team && **health_points > 0
+            })
+            .map(|(_, _, _, position_other)| position_other)
+            .min_by_key(|position_other| {
+                let x = position_other.x - position.x;
+                let z = position_other.z - position.z;
+                x * x + z * z
+            })
+    }
+
+    /// Returns the unit vector in the direction of `vector`, or `None` if it has no length.
+    fn normalize(vector: Vector2<FixedPoint>) -> Option<Vector2<FixedPoint>> {
+        let length = (vector.x * vector.x + vector.y * vector.y).sqrt();
+        if length == FixedPoint::ZERO {
+            None
+        } else {
+            Some(Vector2::new(vector.x / length, vector.y / length))
+        }
+    }
+
+    /// Returns `direction` turned towards `direction_target` by at most `turn_rate` radians.
+    fn steer(
+        direction: Vector2<FixedPoint>,
+        direction_target: Vector2<FixedPoint>,
+        turn_rate: FixedPoint,
+    ) -> Vector2<FixedPoint> {
+        let cos = turn_rate.cos();
+        let dot = direction.x * direction_target.x + direction.y * direction_target.y;
+        if dot >= cos {
+            return direction_target;
+        }
+
+        // Turn in whichever direction is closer to the target.
+        let cross = direction.x * direction_target.y - direction.y * direction_target.x;
+        let sin = if cross < FixedPoint::ZERO {
+            -turn_rate.sin()
+        } else {
+            turn_rate.sin()
+        };
+        Vector2::new(
+            direction.x * cos - direction.y * sin,
+            direction.x * sin + direction.y * cos,
+        )
+    }
+}
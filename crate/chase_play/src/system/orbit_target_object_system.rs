@@ -0,0 +1,124 @@
+use amethyst::{
+    ecs::{Entities, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use chase_model::{config::ChaseMode, loaded::ChaseModeOpt, play::TargetObject};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position};
+use spawn_model::play::SpawnParent;
+use tracker::Last;
+
+use crate::TargetEntityResolver;
+
+/// Moves `ChaseMode::Orbit` entities around their target object on the ground plane.
+///
+/// The offset from the target object is taken from the previous tick's positions, so the orbit
+/// carries the target's movement along. The **Y** offset is preserved.
+#[derive(Debug, Default, new)]
+pub struct OrbitTargetObjectSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct OrbitTargetObjectSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChaseModeOpt` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_opts: ReadStorage<'s, ChaseModeOpt>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: ReadStorage<'s, TargetObject>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `Last<Position<FixedPoint>>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions_last: ReadStorage<'s, Last<Position<FixedPoint>>>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+}
+
+impl<'s> System<'s> for OrbitTargetObjectSystem {
+    type SystemData = OrbitTargetObjectSystemData<'s>;
+
+    fn run(
+        &mut self,
+        OrbitTargetObjectSystemData {
+            entities,
+            chase_mode_opts,
+            target_objects,
+            spawn_parents,
+            positions_last,
+            mut positions,
+        }: Self::SystemData,
+    ) {
+        (&entities, &chase_mode_opts)
+            .join()
+            .filter_map(|(entity, chase_mode_opt)| {
+                if let Some(ChaseMode::Orbit {
+                    radius,
+                    angular_speed,
+                }) = **chase_mode_opt
+                {
+                    Some((entity, radius, angular_speed))
+                } else {
+                    None
+                }
+            })
+            .for_each(|(entity, radius, angular_speed)| {
+                let target_entity =
+                    TargetEntityResolver::resolve(&target_objects, &spawn_parents, entity);
+                let target_position =
+                    target_entity.and_then(|target_entity| positions.get(target_entity).copied());
+                let target_position = if let Some(target_position) = target_position {
+                    target_position
+                } else {
+                    return;
+                };
+                let target_position_last = target_entity
+                    .and_then(|target_entity| positions_last.get(target_entity))
+                    .map(|position_last| position_last.0)
+                    .unwrap_or(target_position);
+                let position_last = positions_last
+                    .get(entity)
+                    .map(|position_last| position_last.0)
+                    .or_else(|| positions.get(entity).copied());
+
+                if let (Some(position_last), Some(position)) =
+                    (position_last, positions.get_mut(entity))
+                {
+                    let offset = *position_last - *target_position_last;
+                    let (x, z) = Self::orbit_offset(offset.x, offset.z, radius, angular_speed);
+
+                    *position = target_position;
+                    position.x += x;
+                    position.y += offset.y;
+                    position.z += z;
+                }
+            });
+    } // kcov-ignore
+}
+
+impl OrbitTargetObjectSystem {
+    /// Returns the ground plane offset rotated by `angular_speed`, at `radius` from the target.
+    fn orbit_offset(
+        x: FixedPoint,
+        z: FixedPoint,
+        radius: FixedPoint,
+        angular_speed: FixedPoint,
+    ) -> (FixedPoint, FixedPoint) {
+        let (cos, sin) = (angular_speed.cos(), angular_speed.sin());
+        let (x, z) = (x * cos - z * sin, x * sin + z * cos);
+
+        let length = (x * x + z * z).sqrt();
+        if length == FixedPoint::ZERO {
+            // Start on the right of the target when there is no offset to rotate.
+            (radius, FixedPoint::ZERO)
+        } else {
+            (x * radius / length, z * radius / length)
+        }
+    }
+}
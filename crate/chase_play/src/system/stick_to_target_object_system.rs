@@ -6,7 +6,7 @@ use amethyst::{
 use chase_model::play::{ChaseModeStick, TargetObject};
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 
 /// Updates a `ChaseModeStick` entity's `Position` and `Translation` to match its `TargetObject`.
 ///
@@ -27,9 +27,9 @@ pub struct StickToTargetObjectSystemData<'s> {
     /// `ChaseModeStick` components.
     #[derivative(Debug = "ignore")]
     pub chase_mode_sticks: ReadStorage<'s, ChaseModeStick>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
     /// `Transform` components.
     #[derivative(Debug = "ignore")]
     pub transforms: WriteStorage<'s, Transform>,
@@ -72,7 +72,7 @@ impl<'s> System<'s> for StickToTargetObjectSystem {
                 if let Some(translation) = target_translation {
                     if let Some(transform) = transforms.get_mut(child_entity) {
                         let translation = if let Some(offset) = offset {
-                            translation + offset.0
+                            translation + offset.to_f32()
                         } else {
                             translation
                         };
@@ -0,0 +1,27 @@
+use amethyst::ecs::{Entity, ReadStorage};
+use chase_model::play::TargetObject;
+use spawn_model::play::SpawnParent;
+
+/// Resolves the entity that a chasing entity chases.
+#[derive(Debug)]
+pub(crate) struct TargetEntityResolver;
+
+impl TargetEntityResolver {
+    /// Returns the entity's `TargetObject` entity, falling back to its `SpawnParent`.
+    ///
+    /// Spawned objects chase the object that spawned them unless a `TargetObject` is attached.
+    pub(crate) fn resolve(
+        target_objects: &ReadStorage<'_, TargetObject>,
+        spawn_parents: &ReadStorage<'_, SpawnParent>,
+        entity: Entity,
+    ) -> Option<Entity> {
+        target_objects
+            .get(entity)
+            .map(|target_object| target_object.entity)
+            .or_else(|| {
+                spawn_parents
+                    .get(entity)
+                    .map(|spawn_parent| spawn_parent.entity)
+            })
+    }
+}
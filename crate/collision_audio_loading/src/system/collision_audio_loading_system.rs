@@ -6,6 +6,7 @@ use amethyst::{
     ecs::{Read, ReadExpect, System, World, Write},
     shred::{ResourceId, SystemData},
 };
+use asset_fs::AssetFs;
 use asset_loading::YamlFormat;
 use collision_audio_model::{
     config::{CollisionSfxId, CollisionSfxPaths},
@@ -14,6 +15,7 @@ use collision_audio_model::{
 };
 use derivative::Derivative;
 use derive_new::new;
+use loading_model::play::LoadingProgress;
 use log::{debug, error};
 #[cfg(target_arch = "wasm32")]
 use wasm_support_fs::PathAccessExt;
@@ -34,6 +36,9 @@ pub struct CollisionAudioLoadingSystem {
     #[derivative(Debug = "ignore")]
     #[new(default)]
     progress_counter: ProgressCounter,
+    /// Bytes of the audio files being loaded.
+    #[new(default)]
+    audio_bytes: u64,
 }
 
 #[derive(Derivative, SystemData)]
@@ -54,6 +59,9 @@ pub struct CollisionAudioLoadingSystemData<'s> {
     /// `CollisionAudioLoadingStatus` resource.
     #[derivative(Debug = "ignore")]
     collision_audio_loading_status: Write<'s, CollisionAudioLoadingStatus>,
+    /// `LoadingProgress` resource.
+    #[derivative(Debug = "ignore")]
+    loading_progress: Write<'s, LoadingProgress>,
 }
 
 impl<'s> System<'s> for CollisionAudioLoadingSystem {
@@ -67,6 +75,7 @@ impl<'s> System<'s> for CollisionAudioLoadingSystem {
             source_assets,
             mut collision_sfx_map,
             mut collision_audio_loading_status,
+            mut loading_progress,
         }: Self::SystemData,
     ) {
         if *collision_audio_loading_status == CollisionAudioLoadingStatus::NotStarted {
@@ -119,6 +128,12 @@ impl<'s> System<'s> for CollisionAudioLoadingSystem {
                     sfx_to_load
                         .into_iter()
                         .for_each(|(collision_sfx_id, path)| {
+                            // Sizes are not known in WASM, so audio bytes are not tracked there.
+                            let audio_bytes =
+                                AssetFs::size(&self.assets_dir.join(path)).unwrap_or(0);
+                            self.audio_bytes += audio_bytes;
+                            loading_progress.audio_bytes.total += audio_bytes;
+
                             macro_rules! load {
                                 ($audio_format:expr) => {
                                     loader.load(
@@ -168,6 +183,7 @@ impl<'s> System<'s> for CollisionAudioLoadingSystem {
                     if all_loaded {
                         debug!("Collision audio assets loaded: {:?}", &*collision_sfx_map);
                         *collision_audio_loading_status = CollisionAudioLoadingStatus::Complete;
+                        loading_progress.audio_bytes.loaded += self.audio_bytes;
                     }
                 }
             }
@@ -1,10 +1,13 @@
 use std::str::FromStr;
 
+use asset_schema::AssetSchema;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
 
 /// Logical IDs to reference audio used for collision.
-#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, Hash, PartialEq, Eq, Serialize)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Display, EnumIter, EnumString, Hash, PartialEq, Eq, Serialize,
+)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum CollisionSfxId {
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, path::PathBuf};
 
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::config::CollisionSfxId;
 
 /// Map of `CollisionSfxId` to the path of the SFX file.
-#[derive(Asset, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derive(
+    Asset, AssetSchema, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new,
+)]
 #[serde(deny_unknown_fields, transparent)]
 pub struct CollisionSfxPaths(HashMap<CollisionSfxId, PathBuf>);
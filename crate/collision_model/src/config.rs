@@ -3,6 +3,7 @@
 pub use self::{
     body::Body, hit::Hit, hit_limit::HitLimit, hit_repeat_delay::HitRepeatDelay,
     interaction::Interaction, interaction_kind::InteractionKind, interactions::Interactions,
+    pick_up::PickUp,
 };
 
 mod body;
@@ -12,3 +13,4 @@ mod hit_repeat_delay;
 mod interaction;
 mod interaction_kind;
 mod interactions;
+mod pick_up;
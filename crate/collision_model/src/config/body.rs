@@ -1,4 +1,5 @@
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -6,7 +7,19 @@ use shape_model::Volume;
 
 /// Hittable volumes of an interactable object.
 #[derive(
-    Asset, Clone, Debug, Default, Deref, DerefMut, Deserialize, Hash, PartialEq, Eq, Serialize, new,
+    Asset,
+    AssetSchema,
+    Clone,
+    Debug,
+    Default,
+    Deref,
+    DerefMut,
+    Deserialize,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+    new,
 )]
 pub struct Body(
     /// Backing vector of `Volume`s.
@@ -1,12 +1,13 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::Acceleration;
-use object_status_model::config::StunPoints;
+use object_status_model::config::{StatusEffect, StunPoints};
 use serde::{Deserialize, Serialize};
 
 use crate::config::{HitLimit, HitRepeatDelay};
 
 /// Configuration of a hit interaction.
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct Hit {
     /// Number of ticks to wait before another hit may occur.
@@ -21,4 +22,7 @@ pub struct Hit {
     pub stun: StunPoints,
     /// Acceleration to inflict on collision.
     pub acceleration: Acceleration<i32>,
+    /// Status effect to apply on collision.
+    #[new(default)]
+    pub status_effect: Option<StatusEffect>,
 }
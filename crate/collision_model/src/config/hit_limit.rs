@@ -1,5 +1,9 @@
 use std::{fmt, str::FromStr};
 
+use asset_schema::{
+    serde_json::{json, Value},
+    AssetSchema, EnumSchema, SchemaGen,
+};
 use derivative::Derivative;
 use serde::{
     de::{Error, Unexpected, Visitor},
@@ -55,6 +59,28 @@ impl<'de> Deserialize<'de> for HitLimit {
     }
 }
 
+impl AssetSchema for HitLimit {
+    fn schema_name() -> Option<String> {
+        Some(String::from("HitLimit"))
+    }
+
+    fn schema(_schema_gen: &mut SchemaGen) -> Value {
+        let limit = json!({
+            "type": "integer",
+            "minimum": 0,
+            "description": "Limit to `n` objects.",
+        });
+        let unlimited = EnumSchema::new(false)
+            .unit(HitLimit::Unlimited.into(), Some("Not limited."))
+            .into_schema();
+
+        json!({
+            "anyOf": [limit, unlimited],
+            "description": "Number of objects a `Hit` may collide with.",
+        })
+    }
+}
+
 struct HitLimitVisitor;
 
 macro_rules! impl_visit_numeric {
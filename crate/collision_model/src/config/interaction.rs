@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use shape_model::Volume;
@@ -5,7 +6,7 @@ use shape_model::Volume;
 use crate::config::InteractionKind;
 
 /// Effects of one object on another
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct Interaction {
     /// Type of collision -- hit, picking weapon, grabbing, and so on.
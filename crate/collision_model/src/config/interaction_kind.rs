@@ -1,10 +1,11 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
-use crate::config::Hit;
+use crate::config::{Hit, PickUp};
 
 /// Type of collision -- hit, picking weapon, grabbing, and so on.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum InteractionKind {
@@ -12,4 +13,6 @@ pub enum InteractionKind {
     /// other entities.
     #[derivative(Default)]
     Hit(Hit),
+    /// Collision with a holdable object causes the interacting object to pick it up.
+    PickUp(PickUp),
 }
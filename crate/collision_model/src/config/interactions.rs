@@ -1,4 +1,5 @@
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -7,7 +8,18 @@ use crate::config::Interaction;
 
 /// Effects on other objects.
 #[derive(
-    Asset, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new,
+    Asset,
+    AssetSchema,
+    Clone,
+    Debug,
+    Default,
+    Deref,
+    DerefMut,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Serialize,
+    new,
 )]
 pub struct Interactions(
     /// Backing vector of `Interaction`s.
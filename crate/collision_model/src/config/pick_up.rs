@@ -0,0 +1,10 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a pick up interaction.
+///
+/// When this interaction contacts a holdable object, the interacting object picks it up.
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct PickUp {}
@@ -3,7 +3,10 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use collision_model::play::{CollisionEvent, ContactEvent};
+use collision_model::{
+    config::InteractionKind,
+    play::{CollisionEvent, ContactEvent},
+};
 use derivative::Derivative;
 use derive_new::new;
 use spawn_model::play::SpawnParent;
@@ -56,6 +59,11 @@ impl<'s> System<'s> for ContactDetectionSystem {
                 ),
             )
             .filter(|ev| {
+                // Objects may pick up items regardless of team or spawn relationship.
+                if let InteractionKind::PickUp(_) = ev.interaction.kind {
+                    return true;
+                }
+
                 // This assumes `ev.from` is the hitting object entity. If we have a separate
                 // entity for each `Interaction`, then this assumption breaks, and we need to
                 // traverse the entity hierarchy to find the object entity.
@@ -73,10 +73,16 @@ impl<'s> System<'s> for HitDetectionSystem {
                 //
                 // 2. `HitLimit`: Make sure not more than `HitLimit` entities are hit.
 
-                let Interaction {
+                let hit_limit = if let Interaction {
                     kind: InteractionKind::Hit(Hit { hit_limit, .. }),
                     ..
-                } = ev.interaction;
+                } = ev.interaction
+                {
+                    hit_limit
+                } else {
+                    // Only `Hit` interactions produce `HitEvent`s.
+                    return false;
+                };
 
                 // If we contact multiple objects in *this* frame, when previously
                 // there was 1 contact, and the hit limit is 2, then we should only hit 1
@@ -44,36 +44,38 @@ impl<'s> System<'s> for HitRepeatTrackersAugmentSystem {
             )
             .for_each(|ev| {
                 // Only add trackers for `Hit` interactions.
-                let Interaction {
+                if let Interaction {
                     kind: InteractionKind::Hit(Hit { repeat_delay, .. }),
                     ..
-                } = ev.interaction;
+                } = ev.interaction
+                {
+                    // This assumes `ev.to` is the hit object entity. If we have a separate
+                    // entity for each `Body`, then this assumption breaks, and we need to
+                    // traverse the entity hierarchy to find the object entity.
+                    let hit_object = ev.to;
 
-                // This assumes `ev.to` is the hit object entity. If we have a separate
-                // entity for each `Body`, then this assumption breaks, and we need to
-                // traverse the entity hierarchy to find the object entity.
-                let hit_object = ev.to;
-
-                match hit_repeat_trackerses.get_mut(ev.from) {
-                    Some(hit_repeat_trackers) => {
-                        if hit_repeat_trackers
-                            .values()
-                            .all(|hit_repeat_tracker| hit_repeat_tracker.entity != hit_object)
-                        {
+                    match hit_repeat_trackerses.get_mut(ev.from) {
+                        Some(hit_repeat_trackers) => {
+                            if hit_repeat_trackers
+                                .values()
+                                .all(|hit_repeat_tracker| hit_repeat_tracker.entity != hit_object)
+                            {
+                                let hit_repeat_tracker =
+                                    Self::hit_repeat_tracker(hit_object, repeat_delay);
+                                hit_repeat_trackers.insert(hit_repeat_tracker);
+                            }
+                        }
+                        None => {
                             let hit_repeat_tracker =
                                 Self::hit_repeat_tracker(hit_object, repeat_delay);
-                            hit_repeat_trackers.insert(hit_repeat_tracker);
+                            let mut slot_map = SlotMap::new();
+                            slot_map.insert(hit_repeat_tracker);
+                            let hit_repeat_trackers = HitRepeatTrackers::new(slot_map);
+                            hit_repeat_trackerses
+                                .insert(ev.from, hit_repeat_trackers)
+                                .expect("Failed to insert `HitRepeatTrackers`.");
                         }
                     }
-                    None => {
-                        let hit_repeat_tracker = Self::hit_repeat_tracker(hit_object, repeat_delay);
-                        let mut slot_map = SlotMap::new();
-                        slot_map.insert(hit_repeat_tracker);
-                        let hit_repeat_trackers = HitRepeatTrackers::new(slot_map);
-                        hit_repeat_trackerses
-                            .insert(ev.from, hit_repeat_trackers)
-                            .expect("Failed to insert `HitRepeatTrackers`.");
-                    }
                 }
             });
     }
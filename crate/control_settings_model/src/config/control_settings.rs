@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use ui_label_model::config::UiLabel;
@@ -5,7 +6,7 @@ use ui_label_model::config::UiLabel;
 use crate::config::KeyboardSettings;
 
 /// Control Settings UI configuration.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct ControlSettings {
     /// Control settings title.
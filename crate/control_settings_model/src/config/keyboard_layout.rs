@@ -1,9 +1,12 @@
 use amethyst::winit::event::VirtualKeyCode;
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Keyboard layout variants.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, Hash, PartialEq, Eq, Serialize,
+)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum KeyboardLayout {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use amethyst::winit::event::VirtualKeyCode;
+use asset_schema::{AssetSchema, VirtualKeyCodeSchema};
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
@@ -10,7 +11,7 @@ use ui_label_model::config::UiSpriteLabel;
 use crate::config::KeyboardLayout;
 
 /// Keyboard settings.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct KeyboardSettings {
     /// Position of the keyboard on screen.
@@ -24,5 +25,6 @@ pub struct KeyboardSettings {
     /// Layout of the keyboard to render.
     pub layout: KeyboardLayout,
     /// Positions of keys for each layout.
+    #[asset_schema(with = "HashMap<KeyboardLayout, HashMap<VirtualKeyCodeSchema, UiSpriteLabel>>")]
     pub layout_positions: HashMap<KeyboardLayout, HashMap<VirtualKeyCode, UiSpriteLabel>>,
 }
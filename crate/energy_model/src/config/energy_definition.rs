@@ -1,4 +1,5 @@
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use derive_new::new;
 use object_model::config::ObjectDefinition;
 use serde::{Deserialize, Serialize};
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::EnergySequence;
 
 /// Contains all of the sequences for an `Energy`.
-#[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(Asset, AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 pub struct EnergyDefinition {
     /// Sequences of actions this object can perform.
     #[serde(flatten)]
@@ -1,10 +1,11 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use object_model::config::{GameObjectFrame, ObjectFrame};
 use sequence_model::config::Wait;
 use serde::{Deserialize, Serialize};
 
 /// Sequence frame type for energies.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct EnergyFrame {
     /// Common object behaviour specification that can change each tick.
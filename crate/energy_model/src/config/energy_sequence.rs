@@ -1,3 +1,5 @@
+use asset_schema::AssetSchema;
+use chase_model::config::ChaseMode;
 use derive_new::new;
 use object_model::config::{GameObjectSequence, ObjectSequence};
 use sequence_model::config::Sequence;
@@ -6,12 +8,15 @@ use serde::{Deserialize, Serialize};
 use crate::config::{EnergyFrame, EnergySequenceName};
 
 /// Represents an independent action sequence of an `Energy`.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 // #[serde(deny_unknown_fields)] // See <https://github.com/serde-rs/serde/issues/1547>
 pub struct EnergySequence {
     /// Object sequence for common object fields.
     #[serde(flatten)]
     pub object_sequence: ObjectSequence<EnergySequenceName, EnergyFrame>,
+    /// How the energy chases its target while in this sequence.
+    #[serde(default)]
+    pub chase: Option<ChaseMode>,
 }
 
 impl AsRef<Sequence<EnergySequenceName, EnergyFrame>> for EnergySequence {
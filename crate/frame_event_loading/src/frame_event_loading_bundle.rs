@@ -0,0 +1,29 @@
+use amethyst::{
+    assets::Processor,
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use derive_new::new;
+use frame_event_model::loaded::FrameEventsSequence;
+
+/// Adds the following systems to the dispatcher.
+///
+/// * `Processor::<FrameEventsSequence>` is added with id `"frame_events_sequence_processor"`.
+#[derive(Debug, new)]
+pub struct FrameEventLoadingBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for FrameEventLoadingBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            Processor::<FrameEventsSequence>::new(),
+            "frame_events_sequence_processor",
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::path::Path;
+
+use amethyst::{
+    assets::{AssetStorage, Loader},
+    audio::Source,
+    renderer::{palette::Srgba, resources::Tint},
+};
+use audio_loading::AudioLoader;
+use frame_event_model::{config, loaded};
+use log::error;
+use sprite_model::config::Tint as TintConfig;
+
+/// Loads `FrameEvents` from configuration.
+#[derive(Debug)]
+pub struct FrameEventsLoader;
+
+impl FrameEventsLoader {
+    /// Returns the loaded `FrameEvents`.
+    ///
+    /// # Parameters
+    ///
+    /// * `loader`: `Loader` to load sound effects with.
+    /// * `source_assets`: `Source` assets.
+    /// * `frame_events`: Frame events configuration.
+    pub fn load(
+        loader: &Loader,
+        source_assets: &AssetStorage<Source>,
+        frame_events: &config::FrameEvents,
+    ) -> loaded::FrameEvents {
+        let frame_events = frame_events
+            .iter()
+            .map(|frame_event| match frame_event {
+                config::FrameEvent::CameraShake {
+                    magnitude,
+                    duration,
+                } => loaded::FrameEvent::CameraShake {
+                    magnitude: *magnitude,
+                    duration: *duration,
+                },
+                config::FrameEvent::ScreenFlash { color, duration } => {
+                    loaded::FrameEvent::ScreenFlash {
+                        color: Self::tint(*color),
+                        duration: *duration,
+                    }
+                }
+                config::FrameEvent::SelfTint(tint) => {
+                    loaded::FrameEvent::SelfTint(Self::tint(*tint))
+                }
+                config::FrameEvent::HitTint { color, duration } => loaded::FrameEvent::HitTint {
+                    color: Self::tint(*color),
+                    duration: *duration,
+                },
+                config::FrameEvent::Sound {
+                    path,
+                    volume,
+                    pitch,
+                } => loaded::FrameEvent::Sound {
+                    source_handle: AudioLoader::load(loader, source_assets, (), path),
+                    volume: *volume,
+                    pitch: Self::pitch(path, *pitch),
+                },
+                config::FrameEvent::Detach => loaded::FrameEvent::Detach,
+                config::FrameEvent::Custom(name) => loaded::FrameEvent::Custom(name.clone()),
+            })
+            .collect::<Vec<loaded::FrameEvent>>();
+
+        loaded::FrameEvents::new(frame_events)
+    }
+
+    /// Returns the pitch to play the sound at, which is the original pitch for audio that is not
+    /// WAV, as only WAV audio can be pitch shifted.
+    fn pitch(path: &Path, pitch: f32) -> f32 {
+        let is_wav = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.eq_ignore_ascii_case("wav"))
+            .unwrap_or(false);
+        if is_wav || (pitch - 1.).abs() <= f32::EPSILON {
+            pitch
+        } else {
+            error!(
+                "Frame event sound `{}` has pitch `{}`, but only WAV audio can be pitch shifted. \
+                 Playing it at its original pitch.",
+                path.display(),
+                pitch
+            );
+            1.
+        }
+    }
+
+    fn tint(tint: TintConfig) -> Tint {
+        Tint(Srgba::new(tint.r, tint.g, tint.b, tint.a))
+    }
+}
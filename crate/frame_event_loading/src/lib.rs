@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes frame event configuration into the loaded frame event model.
+
+pub use crate::{
+    frame_event_loading_bundle::FrameEventLoadingBundle, frame_events_loader::FrameEventsLoader,
+};
+
+mod frame_event_loading_bundle;
+mod frame_events_loader;
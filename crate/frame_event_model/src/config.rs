@@ -0,0 +1,6 @@
+//! User defined configuration types for frame events.
+
+pub use self::{frame_event::FrameEvent, frame_events::FrameEvents};
+
+mod frame_event;
+mod frame_events;
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use asset_schema::AssetSchema;
+use serde::{Deserialize, Serialize};
+use sprite_model::config::Tint;
+
+/// Effect that happens when a frame begins.
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum FrameEvent {
+    /// Shakes the game camera.
+    CameraShake {
+        /// Maximum distance to displace the camera, in pixels.
+        magnitude: f32,
+        /// Number of ticks to shake the camera for.
+        duration: u32,
+    },
+    /// Covers the screen with a colour that fades out.
+    ScreenFlash {
+        /// Colour of the flash when it begins.
+        color: Tint,
+        /// Number of ticks for the flash to fade out.
+        duration: u32,
+    },
+    /// Changes the `Tint` of the object whose frame emits the event.
+    ///
+    /// To tint objects that are hit by this frame's interactions, use `HitTint`.
+    SelfTint(Tint),
+    /// Tints objects that are hit by this frame's interactions, fading out over time.
+    HitTint {
+        /// Colour of the tint when it begins.
+        color: Tint,
+        /// Number of ticks for the tint to fade out.
+        duration: u32,
+    },
+    /// Plays a sound effect.
+    Sound {
+        /// Path to the audio file.
+        path: PathBuf,
+        /// Value between 0.0 and 1.0 (inclusive) to play the sound at.
+        #[serde(default = "FrameEvent::volume_default")]
+        volume: f32,
+        /// Playback speed to play the sound at, where `1.0` is the original pitch.
+        ///
+        /// Higher values raise the pitch and shorten the sound. Pitch shifting is only supported
+        /// for WAV audio, so other formats must use the default pitch.
+        #[serde(default = "FrameEvent::pitch_default")]
+        pitch: f32,
+    },
+    /// Detaches the object from its `ParentEntity`, so it is no longer deleted with the parent.
+    Detach,
+    /// Sends a `CustomFrameEvent` with the given name.
+    Custom(String),
+}
+
+impl FrameEvent {
+    fn volume_default() -> f32 {
+        1.
+    }
+
+    fn pitch_default() -> f32 {
+        1.
+    }
+}
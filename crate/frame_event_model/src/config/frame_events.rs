@@ -0,0 +1,13 @@
+use asset_schema::AssetSchema;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::FrameEvent;
+
+/// Events that happen when a frame begins.
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new,
+)]
+#[serde(deny_unknown_fields)]
+pub struct FrameEvents(pub Vec<FrameEvent>);
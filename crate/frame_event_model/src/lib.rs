@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to represent events that happen when a frame begins.
+//!
+//! Frame events are effects that are not part of an object's own state, such as shaking the
+//! camera or notifying other systems.
+
+pub mod config;
+pub mod loaded;
+pub mod play;
@@ -0,0 +1,13 @@
+//! Types that represent processed configuration.
+
+pub use self::{
+    frame_event::FrameEvent,
+    frame_events::FrameEvents,
+    frame_events_sequence::{FrameEventsSequence, FrameEventsSequenceHandle},
+    frame_events_sequence_handles::FrameEventsSequenceHandles,
+};
+
+mod frame_event;
+mod frame_events;
+mod frame_events_sequence;
+mod frame_events_sequence_handles;
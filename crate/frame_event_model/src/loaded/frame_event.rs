@@ -0,0 +1,44 @@
+use amethyst::{assets::Handle, audio::Source, renderer::resources::Tint};
+
+/// Effect that happens when a frame begins.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameEvent {
+    /// Shakes the game camera.
+    CameraShake {
+        /// Maximum distance to displace the camera, in pixels.
+        magnitude: f32,
+        /// Number of ticks to shake the camera for.
+        duration: u32,
+    },
+    /// Covers the screen with a colour that fades out.
+    ScreenFlash {
+        /// Colour of the flash when it begins.
+        color: Tint,
+        /// Number of ticks for the flash to fade out.
+        duration: u32,
+    },
+    /// Changes the `Tint` of the object whose frame emits the event.
+    SelfTint(Tint),
+    /// Tints objects that are hit by this frame's interactions, fading out over time.
+    HitTint {
+        /// Colour of the tint when it begins.
+        color: Tint,
+        /// Number of ticks for the tint to fade out.
+        duration: u32,
+    },
+    /// Plays a sound effect.
+    Sound {
+        /// Handle to the audio `Source`.
+        source_handle: Handle<Source>,
+        /// Value between 0.0 and 1.0 (inclusive) to play the sound at.
+        volume: f32,
+        /// Playback speed to play the sound at, where `1.0` is the original pitch.
+        ///
+        /// This is always `1.0` for audio that is not WAV.
+        pitch: f32,
+    },
+    /// Detaches the object from its `ParentEntity`, so it is no longer deleted with the parent.
+    Detach,
+    /// Sends a `CustomFrameEvent` with the given name.
+    Custom(String),
+}
@@ -0,0 +1,9 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::loaded::FrameEvent;
+
+/// Events that happen when a frame begins.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct FrameEvents(pub Vec<FrameEvent>);
@@ -0,0 +1,7 @@
+use sequence_model_derive::frame_component_data;
+
+use crate::loaded::FrameEvents;
+
+/// Sequence of `FrameEvents`.
+#[frame_component_data(FrameEvents)]
+pub struct FrameEventsSequence;
@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::FrameEventsSequenceHandle;
+
+/// Sequence of `FrameEventsSequenceHandle`s.
+#[sequence_component_data(FrameEventsSequenceHandle)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct FrameEventsSequenceHandles;
+
+impl<'s> ItemComponent<'s> for FrameEventsSequenceHandles {
+    type SystemData = ();
+}
@@ -0,0 +1,6 @@
+//! Contains data types used during game play.
+
+pub use self::{custom_frame_event::CustomFrameEvent, hit_tint::HitTint};
+
+mod custom_frame_event;
+mod hit_tint;
@@ -0,0 +1,13 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+
+/// Named event sent when a frame with a `FrameEvent::Custom` begins.
+///
+/// Systems may subscribe to the `EventChannel<CustomFrameEvent>` to react to these.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct CustomFrameEvent {
+    /// Entity whose frame sent the event.
+    pub entity: Entity,
+    /// Name of the event.
+    pub name: String,
+}
@@ -0,0 +1,17 @@
+use amethyst::{
+    ecs::{storage::DenseVecStorage, Component},
+    renderer::resources::Tint,
+};
+use derive_new::new;
+
+/// Tints an object that was hit by a frame with a `HitTint` frame event, fading out over time.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct HitTint {
+    /// `Tint` at the beginning of the effect.
+    pub color: Tint,
+    /// Number of ticks for the tint to fade out.
+    pub duration: u32,
+    /// Number of ticks the tint has run for.
+    #[new(default)]
+    pub ticks: u32,
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes frame events during game play.
+
+pub use crate::{
+    system::{FrameEventSystem, FrameEventSystemData, HitTintSystem, HitTintSystemData},
+    wav_pitch::WavPitch,
+};
+
+mod system;
+mod wav_pitch;
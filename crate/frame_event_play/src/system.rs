@@ -0,0 +1,7 @@
+pub use self::{
+    frame_event_system::{FrameEventSystem, FrameEventSystemData},
+    hit_tint_system::{HitTintSystem, HitTintSystemData},
+};
+
+mod frame_event_system;
+mod hit_tint_system;
@@ -0,0 +1,189 @@
+use amethyst::{
+    assets::AssetStorage,
+    audio::{output::Output, Source},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write, WriteStorage},
+    renderer::{camera::Camera, resources::Tint},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use camera_model::play::{CameraShake, ScreenFlash};
+use derivative::Derivative;
+use derive_new::new;
+use frame_event_model::{
+    loaded::{FrameEvent, FrameEvents},
+    play::CustomFrameEvent,
+};
+use log::{error, warn};
+use parent_model::play::ParentEntity;
+use sequence_model::play::SequenceUpdateEvent;
+
+use crate::WavPitch;
+
+/// Applies `FrameEvents` at the beginning of a frame.
+#[derive(Debug, Default, new)]
+pub struct FrameEventSystem {
+    /// Reader ID for the `SequenceUpdateEvent` event channel.
+    #[new(default)]
+    sequence_update_event_rid: Option<ReaderId<SequenceUpdateEvent>>,
+}
+
+/// `FrameEventSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct FrameEventSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SequenceUpdateEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub sequence_update_ec: Read<'s, EventChannel<SequenceUpdateEvent>>,
+    /// `CustomFrameEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub custom_frame_ec: Write<'s, EventChannel<CustomFrameEvent>>,
+    /// `FrameEvents` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_eventses: ReadStorage<'s, FrameEvents>,
+    /// `Camera` components.
+    #[derivative(Debug = "ignore")]
+    pub cameras: ReadStorage<'s, Camera>,
+    /// `CameraShake` components.
+    #[derivative(Debug = "ignore")]
+    pub camera_shakes: WriteStorage<'s, CameraShake>,
+    /// `ScreenFlash` components.
+    #[derivative(Debug = "ignore")]
+    pub screen_flashes: WriteStorage<'s, ScreenFlash>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `Source` assets.
+    #[derivative(Debug = "ignore")]
+    pub source_assets: Read<'s, AssetStorage<Source>>,
+    /// `Output` resource.
+    #[derivative(Debug = "ignore")]
+    pub output: Option<Read<'s, Output>>,
+}
+
+impl FrameEventSystem {
+    fn sound_play(output: &Output, source: &Source, volume: f32, pitch: f32) {
+        // `Output` cannot change the playback speed, so we play a copy of the audio with its
+        // sample rate scaled instead.
+        if (pitch - 1.).abs() <= f32::EPSILON {
+            output.play_once(source, volume);
+        } else if let Some(bytes) = WavPitch::apply(&source.bytes, pitch) {
+            output.play_once(&Source { bytes }, volume);
+        } else {
+            warn!(
+                "Failed to play frame event sound at pitch `{}`, playing it at its original pitch.",
+                pitch
+            );
+            output.play_once(source, volume);
+        }
+    }
+
+    fn frame_event_apply(
+        FrameEventSystemData {
+            entities,
+            custom_frame_ec,
+            cameras,
+            camera_shakes,
+            screen_flashes,
+            parent_entities,
+            source_assets,
+            output,
+            ..
+        }: &mut FrameEventSystemData<'_>,
+        entity: Entity,
+        frame_event: &FrameEvent,
+    ) {
+        match frame_event {
+            FrameEvent::CameraShake {
+                magnitude,
+                duration,
+            } => {
+                let camera_entities = (&*entities, &*cameras)
+                    .join()
+                    .map(|(camera_entity, _)| camera_entity)
+                    .collect::<Vec<Entity>>();
+                camera_entities.into_iter().for_each(|camera_entity| {
+                    camera_shakes
+                        .insert(camera_entity, CameraShake::new(*magnitude, *duration))
+                        .expect("Failed to insert `CameraShake` component.");
+                });
+            }
+            FrameEvent::ScreenFlash {
+                color: Tint(color),
+                duration,
+            } => {
+                let color = [color.red, color.green, color.blue, color.alpha];
+                entities
+                    .build_entity()
+                    .with(ScreenFlash::new(color, *duration), screen_flashes)
+                    .build();
+            }
+            // The `SelfTint` is layered into the entity's `Tint` for the rest of the frame when the
+            // entity's `Tint` is resolved.
+            FrameEvent::SelfTint(_) => {}
+            // `HitTint`s are applied to hit objects by the `HitTintSystem`.
+            FrameEvent::HitTint { .. } => {}
+            FrameEvent::Sound {
+                source_handle,
+                volume,
+                pitch,
+            } => {
+                let source = source_assets.get(source_handle);
+                match (output, source) {
+                    (Some(output), Some(source)) => {
+                        Self::sound_play(output, source, *volume, *pitch)
+                    }
+                    (Some(_), None) => error!("Expected frame event sound to be loaded."),
+                    (None, _) => {}
+                }
+            }
+            FrameEvent::Detach => {
+                parent_entities.remove(entity);
+            }
+            FrameEvent::Custom(name) => {
+                custom_frame_ec.single_write(CustomFrameEvent::new(entity, name.clone()));
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for FrameEventSystem {
+    type SystemData = FrameEventSystemData<'s>;
+
+    fn run(&mut self, mut frame_event_system_data: Self::SystemData) {
+        let entities_frame_begin = frame_event_system_data
+            .sequence_update_ec
+            .read(
+                self.sequence_update_event_rid
+                    .as_mut()
+                    .expect("Expected reader ID to exist for FrameEventSystem."),
+            )
+            .filter_map(|ev| match ev {
+                SequenceUpdateEvent::SequenceBegin { entity, .. }
+                | SequenceUpdateEvent::FrameBegin { entity, .. } => Some(*entity),
+                SequenceUpdateEvent::SequenceEnd { .. }
+                | SequenceUpdateEvent::SequenceReload { .. } => None,
+            })
+            .collect::<Vec<Entity>>();
+
+        entities_frame_begin.into_iter().for_each(|entity| {
+            let frame_events = frame_event_system_data.frame_eventses.get(entity).cloned();
+            if let Some(frame_events) = frame_events {
+                frame_events.iter().for_each(|frame_event| {
+                    Self::frame_event_apply(&mut frame_event_system_data, entity, frame_event)
+                });
+            }
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.sequence_update_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<SequenceUpdateEvent>>()
+                .register_reader(),
+        );
+    }
+}
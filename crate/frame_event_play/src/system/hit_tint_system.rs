@@ -0,0 +1,133 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::play::HitEvent;
+use derivative::Derivative;
+use derive_new::new;
+use frame_event_model::{
+    loaded::{FrameEvent, FrameEvents},
+    play::HitTint,
+};
+
+/// Tracks `HitTint`s for objects hit by frames with a `HitTint` frame event.
+///
+/// The tint is layered over the object's `Tint` with [`HitTintSystem::tint_blend`] when the
+/// object's `Tint` is resolved, so it fades back to the frame's `Tint` as the tint ticks.
+#[derive(Debug, Default, new)]
+pub struct HitTintSystem {
+    /// Reader ID for the `HitEvent` channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `HitTintSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct HitTintSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `FrameEvents` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_eventses: ReadStorage<'s, FrameEvents>,
+    /// `HitTint` components.
+    #[derivative(Debug = "ignore")]
+    pub hit_tints: WriteStorage<'s, HitTint>,
+}
+
+impl HitTintSystem {
+    /// Returns the `Tint` blended from `tint` towards the `HitTint`'s colour.
+    ///
+    /// The `HitTint`'s colour fades out over its duration.
+    pub fn tint_blend(Tint(tint): Tint, hit_tint: HitTint) -> Tint {
+        let Tint(hit_colour) = hit_tint.color;
+
+        let factor = if hit_tint.duration == 0 {
+            0.
+        } else {
+            let ticks = hit_tint.ticks.min(hit_tint.duration);
+            1. - ticks as f32 / hit_tint.duration as f32
+        };
+        Tint(Srgba::new(
+            Self::lerp(tint.red, hit_colour.red, factor),
+            Self::lerp(tint.green, hit_colour.green, factor),
+            Self::lerp(tint.blue, hit_colour.blue, factor),
+            Self::lerp(tint.alpha, hit_colour.alpha, factor),
+        ))
+    }
+
+    /// Returns the `HitTint` in the entity's current frame events, if any.
+    fn hit_tint(frame_events: &FrameEvents) -> Option<HitTint> {
+        frame_events
+            .iter()
+            .filter_map(|frame_event| match frame_event {
+                FrameEvent::HitTint { color, duration } => Some(HitTint::new(*color, *duration)),
+                _ => None,
+            })
+            .last()
+    }
+
+    fn lerp(from: f32, to: f32, factor: f32) -> f32 {
+        from + (to - from) * factor
+    }
+}
+
+impl<'s> System<'s> for HitTintSystem {
+    type SystemData = HitTintSystemData<'s>;
+
+    fn run(
+        &mut self,
+        HitTintSystemData {
+            entities,
+            hit_ec,
+            frame_eventses,
+            mut hit_tints,
+        }: Self::SystemData,
+    ) {
+        let hit_event_rid = self
+            .hit_event_rid
+            .as_mut()
+            .expect("Expected `hit_event_rid` field to be set.");
+
+        let tints_complete = (&entities, &mut hit_tints)
+            .join()
+            .filter_map(|(entity, hit_tint)| {
+                hit_tint.ticks += 1;
+                if hit_tint.ticks >= hit_tint.duration {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<Entity>>();
+
+        tints_complete.into_iter().for_each(|entity| {
+            hit_tints.remove(entity);
+        });
+
+        hit_ec.read(hit_event_rid).for_each(|ev| {
+            let hit_tint = frame_eventses.get(ev.from).and_then(Self::hit_tint);
+            if let Some(hit_tint) = hit_tint {
+                hit_tints
+                    .insert(ev.to, hit_tint)
+                    .expect("Failed to insert `HitTint` component.");
+            }
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::convert::TryInto;
+
+/// Changes the pitch of WAV audio by scaling its sample rate.
+///
+/// Like changing the speed of a record, this also changes the duration of the sound.
+#[derive(Debug)]
+pub struct WavPitch;
+
+impl WavPitch {
+    /// Returns a copy of the WAV audio with its sample rate scaled by `pitch`.
+    ///
+    /// Returns `None` if the bytes are not WAV audio, or `pitch` is not positive.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes`: Bytes of the WAV file.
+    /// * `pitch`: Playback speed multiplier, where `1.0` is the original pitch.
+    pub fn apply(bytes: &[u8], pitch: f32) -> Option<Vec<u8>> {
+        if pitch.is_nan() || pitch <= 0. {
+            return None;
+        }
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return None;
+        }
+
+        let mut offset = 12;
+        while offset + 8 <= bytes.len() {
+            let chunk_id = &bytes[offset..offset + 4];
+            let chunk_len = Self::u32_read(&bytes[offset + 4..offset + 8]) as usize;
+            let data_offset = offset + 8;
+
+            if chunk_id == b"fmt " {
+                // `sample_rate: u32`, `byte_rate: u32` and `block_align: u16` are at byte 4 to 14.
+                if chunk_len < 14 || data_offset + 14 > bytes.len() {
+                    return None;
+                }
+
+                let sample_rate = Self::u32_read(&bytes[data_offset + 4..data_offset + 8]);
+                let block_align = u32::from(u16::from_le_bytes([
+                    bytes[data_offset + 12],
+                    bytes[data_offset + 13],
+                ]));
+                let sample_rate = ((sample_rate as f32 * pitch).round() as u32).max(1);
+                let byte_rate = sample_rate.saturating_mul(block_align);
+
+                let mut bytes = bytes.to_vec();
+                bytes[data_offset + 4..data_offset + 8].copy_from_slice(&sample_rate.to_le_bytes());
+                bytes[data_offset + 8..data_offset + 12].copy_from_slice(&byte_rate.to_le_bytes());
+                return Some(bytes);
+            }
+
+            // Chunks are padded to an even number of bytes.
+            offset = data_offset
+                .saturating_add(chunk_len)
+                .saturating_add(chunk_len % 2);
+        }
+
+        None
+    }
+
+    fn u32_read(bytes: &[u8]) -> u32 {
+        u32::from_le_bytes(
+            bytes
+                .try_into()
+                .expect("Expected slice to be 4 bytes long."),
+        )
+    }
+}
@@ -1,9 +1,11 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
 
 /// Control actions for characters.
 #[derive(
+    AssetSchema,
     Clone,
     Copy,
     Debug,
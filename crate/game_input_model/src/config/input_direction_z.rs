@@ -1,7 +1,8 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 /// Variants for axis input matching.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum InputDirectionZ {
     /// Z axis input is zero.
@@ -1,6 +1,11 @@
-use amethyst::ecs::{storage::DenseVecStorage, Component};
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
 use derive_new::new;
 
+use crate::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent},
+};
+
 /// Input for a character entity.
 // TODO: Map of actions and axes <https://gitlab.com/azriel91/autexousious/issues/73>.
 #[derive(Clone, Copy, Debug, Default, PartialEq, new)]
@@ -27,6 +32,65 @@ pub struct ControllerInput {
     pub special: bool,
 }
 
+impl ControllerInput {
+    /// Returns the `ControlInputEvent`s that change this input to the desired input.
+    ///
+    /// This is used to drive entities that are not controlled by a player, so that both `press_*`
+    /// and `hold_*` input reactions are triggered.
+    ///
+    /// # Parameters
+    ///
+    /// * `desired`: Input that the entity should have.
+    /// * `entity`: Entity that the events apply to.
+    /// * `controller_id`: Controller ID to send the events with.
+    pub fn change_events(
+        &self,
+        desired: ControllerInput,
+        entity: Entity,
+        controller_id: ControllerId,
+    ) -> Vec<ControlInputEvent> {
+        let axis_events = [
+            (Axis::X, self.x_axis_value, desired.x_axis_value),
+            (Axis::Z, self.z_axis_value, desired.z_axis_value),
+        ]
+        .iter()
+        .copied()
+        .filter(|(_, current, desired)| (current - desired).abs() > std::f32::EPSILON)
+        .map(|(axis, _, value)| {
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id,
+                entity,
+                axis,
+                value,
+            })
+        });
+
+        let control_action_events = [
+            (ControlAction::Defend, self.defend, desired.defend),
+            (ControlAction::Jump, self.jump, desired.jump),
+            (ControlAction::Attack, self.attack, desired.attack),
+            (ControlAction::Special, self.special, desired.special),
+        ]
+        .iter()
+        .copied()
+        .filter(|(_, current, desired)| current != desired)
+        .map(|(control_action, _, pressed)| {
+            let control_action_event_data = ControlActionEventData {
+                controller_id,
+                entity,
+                control_action,
+            };
+            if pressed {
+                ControlInputEvent::ControlActionPress(control_action_event_data)
+            } else {
+                ControlInputEvent::ControlActionRelease(control_action_event_data)
+            }
+        });
+
+        axis_events.chain(control_action_events).collect::<Vec<_>>()
+    }
+}
+
 impl Component for ControllerInput {
     type Storage = DenseVecStorage<Self>;
 }
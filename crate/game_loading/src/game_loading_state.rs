@@ -1,16 +1,41 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
-use amethyst::{core::SystemBundle, ecs::prelude::*, prelude::*};
+use amethyst::{
+    core::{Stopwatch, SystemBundle},
+    ecs::prelude::*,
+    prelude::*,
+    shrev::EventChannel,
+};
 use application_event::AppEvent;
 use application_state::AutexState;
+use asset_model::loaded::{AssetId, AssetIdMappings};
+use character_selection_model::CharacterSelections;
 use derivative::Derivative;
 use derive_new::new;
 use game_model::play::GameEntities;
+use loading_model::{
+    loaded::{AssetLoadStage, LoadStage},
+    play::AssetLoadRequestEvent,
+};
+use log::error;
+use map_selection_model::MapSelection;
+use sprite_model::play::SpriteAtlasRequestEvent;
 use state_registry::StateId;
+use survival_model::{config::SurvivalWaves, play::SurvivalStatus};
 
 use crate::{GameLoadingBundle, GameLoadingStatus};
 
+/// Time limit for the selected assets to load before returning to the previous state.
+const LOADING_TIME_LIMIT: Duration = Duration::from_secs(30);
+
 /// `State` where game play takes place.
+///
+/// Game entities are spawned once the selected characters and map are loaded. Selected assets
+/// whose loading was deferred are requested when this state begins, and their sprite sheets are
+/// requested to be packed into texture atlases once loaded.
+///
+/// If the selected assets have not loaded within the time limit, the assets that are not loaded
+/// are reported, and this state returns to the previous state.
 #[derive(Derivative, Default, new)]
 #[derivative(Debug)]
 pub struct GameLoadingState<'a, 'b, F, S>
@@ -22,6 +47,12 @@ where
     #[derivative(Debug = "ignore")]
     #[new(default)]
     dispatcher: Option<Dispatcher<'a, 'b>>,
+    /// Whether the sprite sheets of the selected assets have been requested to be packed.
+    #[new(default)]
+    sprite_atlas_requested: bool,
+    /// Tracks how long the selected assets have been loading for.
+    #[new(default)]
+    stopwatch: Stopwatch,
     /// The `State` that follows this one.
     #[derivative(Debug(bound = "F: Debug"))]
     next_state_fn: F,
@@ -59,6 +90,99 @@ where
         game_entities.map_layers.clear();
 
         world.write_resource::<GameLoadingStatus>().reset();
+        self.sprite_atlas_requested = false;
+        self.stopwatch.restart();
+    }
+
+    /// Returns the IDs of the selected characters and map.
+    ///
+    /// In survival mode, the characters of survival wave enemies are also included.
+    fn selected_asset_ids(world: &World) -> Vec<AssetId> {
+        let (
+            character_selections,
+            map_selection,
+            survival_status,
+            survival_waves,
+            asset_id_mappings,
+        ) = world.system_data::<(
+            Read<'_, CharacterSelections>,
+            Read<'_, MapSelection>,
+            Read<'_, SurvivalStatus>,
+            Read<'_, SurvivalWaves>,
+            Read<'_, AssetIdMappings>,
+        )>();
+
+        let mut asset_ids = character_selections
+            .selections
+            .values()
+            .copied()
+            .chain(map_selection.asset_id())
+            .collect::<Vec<AssetId>>();
+        if *survival_status == SurvivalStatus::Active {
+            asset_ids.extend(
+                survival_waves
+                    .waves
+                    .iter()
+                    .flat_map(|wave| wave.enemies.iter())
+                    .filter_map(|wave_enemy| asset_id_mappings.id(&wave_enemy.object).copied()),
+            );
+        }
+
+        asset_ids
+    }
+
+    /// Returns whether the given assets are loaded.
+    ///
+    /// Assets that are deferred are requested to be loaded.
+    fn assets_loaded(world: &World, asset_ids: &[AssetId]) -> bool {
+        let (asset_load_stage, mut asset_load_request_ec) = world.system_data::<(
+            Read<'_, AssetLoadStage>,
+            Write<'_, EventChannel<AssetLoadRequestEvent>>,
+        )>();
+
+        asset_ids.iter().copied().fold(true, |loaded, asset_id| {
+            match asset_load_stage.get(asset_id).copied() {
+                // Assets without a load stage are not loaded through the asset index.
+                Some(LoadStage::Complete) | None => loaded,
+                Some(LoadStage::Deferred) => {
+                    asset_load_request_ec.single_write(AssetLoadRequestEvent::Request(asset_id));
+                    false
+                }
+                Some(_) => false,
+            }
+        })
+    }
+
+    /// Logs the given assets that have not completed loading.
+    fn report_assets_not_loaded(world: &World, asset_ids: &[AssetId], elapsed: Duration) {
+        let (asset_id_mappings, asset_load_stage) =
+            world.system_data::<(Read<'_, AssetIdMappings>, Read<'_, AssetLoadStage>)>();
+
+        let assets_not_loaded = asset_ids
+            .iter()
+            .copied()
+            .filter_map(|asset_id| {
+                let load_stage = asset_load_stage.get(asset_id).copied()?;
+                if load_stage == LoadStage::Complete {
+                    None
+                } else {
+                    let asset_slug = asset_id_mappings
+                        .slug(asset_id)
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("{:?}", asset_id));
+                    Some(format!("* `{}`: `{:?}`", asset_slug, load_stage))
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        error!(
+            "Selected assets have not completed loading in {}, returning to the previous state:\n\
+             \n\
+             {}\n",
+            humantime::Duration::from(elapsed),
+            assets_not_loaded
+        );
     }
 
     /// Terminates the dispatcher.
@@ -102,6 +226,29 @@ where
         data: StateData<'_, GameData<'_, '_>>,
     ) -> Trans<GameData<'a, 'b>, AppEvent> {
         data.data.update(&data.world);
+
+        let asset_ids = Self::selected_asset_ids(&data.world);
+        if !Self::assets_loaded(&data.world, &asset_ids) {
+            let elapsed = self.stopwatch.elapsed();
+            if elapsed > LOADING_TIME_LIMIT {
+                Self::report_assets_not_loaded(&data.world, &asset_ids, elapsed);
+                return Trans::Pop;
+            }
+
+            return Trans::None;
+        }
+
+        // The channel only exists when texture atlas packing is enabled.
+        if !self.sprite_atlas_requested {
+            if let Some(mut sprite_atlas_request_ec) = data
+                .world
+                .try_fetch_mut::<EventChannel<SpriteAtlasRequestEvent>>()
+            {
+                sprite_atlas_request_ec.single_write(SpriteAtlasRequestEvent::new(asset_ids));
+            }
+            self.sprite_atlas_requested = true;
+        }
+
         self.dispatcher.as_mut().unwrap().dispatch(&data.world);
 
         if data.world.read_resource::<GameLoadingStatus>().loaded() {
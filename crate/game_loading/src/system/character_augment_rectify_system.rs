@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use amethyst::{
     assets::PrefabData,
     ecs::{
@@ -9,11 +11,18 @@ use camera_model::play::CameraTracked;
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::InputControlled;
-use game_play_hud::{CpBarPrefab, HpBarPrefab};
+use game_play_hud::{CpBarPrefab, HpBarPrefab, StatusIconPrefab};
 use game_play_model::GamePlayEntity;
-use kinematic_model::config::Position;
-use map_model::loaded::AssetMapBounds;
+use kinematic_model::config::{FixedPoint, Position};
+use map_model::{
+    config::{MapBounds, SpawnPoint},
+    loaded::{AssetMapBounds, AssetSpawnPoints},
+};
 use map_selection_model::MapSelection;
+use mirrored_model::play::Mirrored;
+use object_status_model::config::StatusEffectKind;
+use strum::IntoEnumIterator;
+use team_model::play::{Team, TeamCounter};
 
 use crate::{CharacterAugmentStatus, GameLoadingStatus};
 
@@ -37,24 +46,75 @@ pub struct CharacterAugmentRectifySystemData<'s> {
     /// `AssetMapBounds` resource.
     #[derivative(Debug = "ignore")]
     pub asset_map_bounds: Read<'s, AssetMapBounds>,
+    /// `AssetSpawnPoints` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_spawn_points: Read<'s, AssetSpawnPoints>,
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
     /// `CameraTracked` components.
     #[derivative(Debug = "ignore")]
     pub camera_trackeds: WriteStorage<'s, CameraTracked>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
     /// `LazyUpdate` resource.
     ///
-    /// This is used because both the `HpBarPrefab` and `CpBarPrefab` request `Write` access to the
-    /// same resources.
+    /// This is used because the `HpBarPrefab`, `CpBarPrefab`, and `StatusIconPrefab` request
+    /// `Write` access to the same resources.
     #[derivative(Debug = "ignore")]
     pub lazy_update: Read<'s, LazyUpdate>,
 }
 
 impl CharacterAugmentRectifySystem {
+    /// Returns the position and facing for a character spread evenly across the map.
+    ///
+    /// Characters are placed at the map's vertical and depth midpoints. Characters on the right
+    /// half of the map face left, towards the middle.
+    ///
+    /// # Parameters
+    ///
+    /// * `map_bounds`: Bounds of the selected map.
+    /// * `slot`: Index of the character in order of its controller ID.
+    /// * `count`: Number of characters being spawned.
+    fn spread_position(
+        map_bounds: MapBounds,
+        slot: usize,
+        count: usize,
+    ) -> (Position<FixedPoint>, Mirrored) {
+        let (width, height, depth) = (
+            FixedPoint::from(map_bounds.width),
+            FixedPoint::from(map_bounds.height),
+            FixedPoint::from(map_bounds.depth),
+        );
+        let two = FixedPoint::from(2);
+        let x_offset =
+            width * FixedPoint::from(slot as u32 + 1) / FixedPoint::from(count as u32 + 1);
+        let mirrored = Mirrored::new(x_offset > width / two);
+
+        (
+            Position::<FixedPoint>::new(
+                FixedPoint::from(map_bounds.x) + x_offset,
+                FixedPoint::from(map_bounds.y) + height / two,
+                FixedPoint::from(map_bounds.z) + depth / two,
+            ),
+            mirrored,
+        )
+    }
+
+    fn spawn_point_position(spawn_point: SpawnPoint) -> (Position<FixedPoint>, Mirrored) {
+        (
+            Position::<FixedPoint>::from(spawn_point.position),
+            Mirrored::new(spawn_point.mirrored),
+        )
+    }
+
     fn hp_bar_augment(world: &World, game_object_entity: Entity) {
         let (entities, mut hp_bar_prefab_system_data, mut game_play_entities) = world
             .system_data::<(
@@ -90,6 +150,31 @@ impl CharacterAugmentRectifySystem {
             .insert(cp_bar_entity, GamePlayEntity)
             .expect("Failed to insert `GamePlayEntity` component.");
     }
+
+    fn status_icons_augment(world: &World, game_object_entity: Entity) {
+        let (entities, mut status_icon_prefab_system_data, mut game_play_entities) = world
+            .system_data::<(
+                Entities<'_>,
+                <StatusIconPrefab as PrefabData<'_>>::SystemData,
+                WriteStorage<'_, GamePlayEntity>,
+            )>();
+
+        StatusEffectKind::iter().for_each(|kind| {
+            let status_icon_entity = entities.create();
+            let status_icon_prefab = StatusIconPrefab::new(game_object_entity, kind);
+            status_icon_prefab
+                .add_to_entity(
+                    status_icon_entity,
+                    &mut status_icon_prefab_system_data,
+                    &[],
+                    &[],
+                )
+                .expect("`StatusIconPrefab` failed to augment entity.");
+            game_play_entities
+                .insert(status_icon_entity, GamePlayEntity)
+                .expect("Failed to insert `GamePlayEntity` component.");
+        });
+    }
 }
 
 impl<'s> System<'s> for CharacterAugmentRectifySystem {
@@ -102,9 +187,12 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
             mut game_loading_status,
             map_selection,
             asset_map_bounds,
+            asset_spawn_points,
             input_controlleds,
+            teams,
             mut camera_trackeds,
             mut positions,
+            mut mirroreds,
             lazy_update,
         }: Self::SystemData,
     ) {
@@ -117,34 +205,57 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
             return;
         }
 
-        // Read map to determine bounds where the characters can be spawned.
-        let (width, height, depth) = {
-            asset_map_bounds
-                .get(
-                    map_selection
-                        .asset_id()
-                        .expect("Expected map selection to have an `AssetId`."),
-                )
-                .map(|bounds| {
-                    (
-                        bounds.width as f32,
-                        bounds.height as f32,
-                        bounds.depth as f32,
-                    )
-                })
-                .expect("Expected map selection to have `MapBounds`.")
-        };
-
-        // This `Position` moves the entity to the middle of a screen wide map.
-        let position = Position::<f32>::new(width / 2., height / 2., depth / 2.);
-
-        (&entities, &input_controlleds)
+        // Read map to determine where the characters can be spawned.
+        let map_asset_id = map_selection
+            .asset_id()
+            .expect("Expected map selection to have an `AssetId`.");
+        let map_bounds = asset_map_bounds
+            .get(map_asset_id)
+            .copied()
+            .expect("Expected map selection to have `MapBounds`.");
+        let spawn_points = asset_spawn_points.get(map_asset_id);
+
+        // Characters are placed in order of their controller ID, so that each player slot gets a
+        // consistent spawn point.
+        let mut character_entities = (&entities, &input_controlleds)
             .join()
-            .for_each(|(entity, _)| {
+            .map(|(entity, input_controlled)| (entity, input_controlled.controller_id))
+            .collect::<Vec<_>>();
+        character_entities.sort_by_key(|(_, controller_id)| *controller_id);
+
+        let character_count = character_entities.len();
+        let mut team_member_counts = HashMap::<TeamCounter, usize>::new();
+        character_entities
+            .into_iter()
+            .enumerate()
+            .for_each(|(slot, (entity, _))| {
+                let spawn_point = spawn_points.and_then(|spawn_points| match teams.get(entity) {
+                    Some(Team::Number(team_counter)) => {
+                        let team_member_count =
+                            team_member_counts.entry(*team_counter).or_insert(0);
+                        let team_index = *team_member_count;
+                        *team_member_count += 1;
+
+                        // When the team has run out of spawn points, fall back to the player
+                        // spawn point of the character's slot, which no other character uses.
+                        spawn_points
+                            .team(*team_counter, team_index)
+                            .or_else(|| spawn_points.player(slot))
+                    }
+                    _ => spawn_points.player(slot),
+                });
+                let (position, mirrored) = spawn_point
+                    .copied()
+                    .map(Self::spawn_point_position)
+                    .unwrap_or_else(|| Self::spread_position(map_bounds, slot, character_count));
+
                 // Set character `position` based on the map.
                 positions
                     .insert(entity, position)
-                    .expect("Failed to insert `Position<f32>` component.");
+                    .expect("Failed to insert `Position<FixedPoint>` component.");
+                mirroreds
+                    .insert(entity, mirrored)
+                    .expect("Failed to insert `Mirrored` component.");
 
                 // Track player with camera.
                 camera_trackeds
@@ -153,6 +264,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
 
                 lazy_update.exec(move |world| Self::hp_bar_augment(world, entity));
                 lazy_update.exec(move |world| Self::cp_bar_augment(world, entity));
+                lazy_update.exec(move |world| Self::status_icons_augment(world, entity));
             });
 
         game_loading_status.character_augment_status = CharacterAugmentStatus::Complete;
@@ -163,6 +275,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
 
         <HpBarPrefab as PrefabData<'_>>::SystemData::setup(world);
         <CpBarPrefab as PrefabData<'_>>::SystemData::setup(world);
+        <StatusIconPrefab as PrefabData<'_>>::SystemData::setup(world);
         <WriteStorage<'_, GamePlayEntity>>::setup(world);
     }
 }
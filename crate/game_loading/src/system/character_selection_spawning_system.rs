@@ -12,6 +12,7 @@ use derive_new::new;
 use game_input_model::play::InputControlled;
 use game_model::play::GameEntities;
 use object_type::ObjectType;
+use survival_model::play::{SurvivalStatus, SurvivalTeam};
 use team_model::play::{IndependentCounter, Team};
 
 use crate::{CharacterAugmentStatus, GameLoadingStatus};
@@ -33,6 +34,9 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `GameLoadingStatus` resource.
     #[derivative(Debug = "ignore")]
     pub game_loading_status: Write<'s, GameLoadingStatus>,
+    /// `SurvivalStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_status: Read<'s, SurvivalStatus>,
     /// `IndependentCounter` resource.
     #[derivative(Debug = "ignore")]
     pub independent_counter: Write<'s, IndependentCounter>,
@@ -74,6 +78,7 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
             entities,
             character_selections,
             mut game_loading_status,
+            survival_status,
             mut independent_counter,
             asset_item_ids,
             asset_id_mappings,
@@ -108,12 +113,19 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
                     panic!("Expected `ItemId` to exist for asset: `{}`", asset_slug)
                 });
 
+                let variant_index = character_selections
+                    .variants
+                    .get(controller_id)
+                    .copied()
+                    .unwrap_or(0);
+
                 let entity = entities.create();
 
                 CharacterEntityAugmenter::augment(
                     &character_spawning_resources,
                     &mut character_component_storages,
                     asset_id,
+                    variant_index,
                     entity,
                 );
 
@@ -126,11 +138,14 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
                 input_controlleds
                     .insert(entity, InputControlled::new(*controller_id))
                     .expect("Failed to insert `InputControlled` for character.");
+                // In survival mode, players are on the same team against the enemies.
+                let team = if *survival_status == SurvivalStatus::Active {
+                    Team::Number(SurvivalTeam::PLAYERS)
+                } else {
+                    Team::Independent(independent_counter.get_and_increment())
+                };
                 teams
-                    .insert(
-                        entity,
-                        Team::Independent(independent_counter.get_and_increment()),
-                    )
+                    .insert(entity, team)
                     .expect("Failed to insert `Team` for character.");
 
                 entity
@@ -3,10 +3,12 @@ use application_event::AppEvent;
 use application_state::{AppState, AppStateBuilder};
 use derivative::Derivative;
 use derive_new::new;
-use game_mode_selection_model::GameModeSelectionEntity;
+use game_mode_selection_model::{GameModeIndex, GameModeSelectionEntity};
 use log::debug;
 use menu_model::MenuEvent;
 use state_registry::StateId;
+use survival_model::play::SurvivalStatus;
+use training_model::play::TrainingStatus;
 
 use crate::GameModeSelectionTrans;
 
@@ -51,7 +53,7 @@ impl State<GameData<'static, 'static>, AppEvent> for GameModeSelectionStateDeleg
 
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'static, 'static>>,
+        data: StateData<'_, GameData<'static, 'static>>,
         event: AppEvent,
     ) -> Trans<GameData<'static, 'static>, AppEvent> {
         if let AppEvent::GameModeSelection(game_mode_selection_event) = event {
@@ -60,7 +62,23 @@ impl State<GameData<'static, 'static>, AppEvent> for GameModeSelectionStateDeleg
                 game_mode_selection_event
             );
             match game_mode_selection_event {
-                MenuEvent::Select(idx) => GameModeSelectionTrans::trans(idx),
+                MenuEvent::Select(idx) => {
+                    let training_status = if idx == GameModeIndex::Training {
+                        TrainingStatus::Active
+                    } else {
+                        TrainingStatus::Inactive
+                    };
+                    data.world.insert(training_status);
+
+                    let survival_status = if idx == GameModeIndex::Survival {
+                        SurvivalStatus::Active
+                    } else {
+                        SurvivalStatus::Inactive
+                    };
+                    data.world.insert(survival_status);
+
+                    GameModeSelectionTrans::trans(idx)
+                }
                 MenuEvent::Close => Trans::Pop,
             }
         } else {
@@ -4,6 +4,7 @@ use amethyst::{GameData, State, Trans};
 use application_event::AppEvent;
 use character_selection::{
     CharacterSelectionStateBuilder, CharacterSelectionStateDelegate, CharacterSelectionSystem,
+    CharacterVariantInputSystem,
 };
 use control_settings::ControlSettingsState;
 use game_loading::GameLoadingState;
@@ -24,7 +25,7 @@ impl GameModeSelectionTrans {
     /// * `game_mode_index`: The selected index.
     pub fn trans(game_mode_index: GameModeIndex) -> Trans<GameData<'static, 'static>, AppEvent> {
         match game_mode_index {
-            GameModeIndex::StartGame => {
+            GameModeIndex::StartGame | GameModeIndex::Training | GameModeIndex::Survival => {
                 let character_selection_state = Self::character_selection_state();
                 Trans::Push(character_selection_state)
             }
@@ -53,10 +54,15 @@ impl GameModeSelectionTrans {
         let state = CharacterSelectionStateBuilder::new(CharacterSelectionStateDelegate::new(
             map_selection_fn,
         ))
+        .with_system(
+            CharacterVariantInputSystem::new(),
+            any::type_name::<CharacterVariantInputSystem>(),
+            &[],
+        )
         .with_system(
             CharacterSelectionSystem::new(),
             any::type_name::<CharacterSelectionSystem>(),
-            &[],
+            &[any::type_name::<CharacterVariantInputSystem>()],
         )
         .build();
 
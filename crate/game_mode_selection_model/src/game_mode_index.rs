@@ -1,15 +1,34 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
 
 /// Game mode menu indicies.
 #[derive(
-    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+    AssetSchema,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    PartialEq,
+    Eq,
+    Serialize,
 )]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum GameModeIndex {
     /// Starts a local game.
     StartGame,
+    /// Starts a local training session.
+    ///
+    /// The character selected with the lowest controller ID is controlled by the player, and the
+    /// next selected character is the training dummy. A second character must be selected for the
+    /// session to have a dummy.
+    Training,
+    /// Starts a local survival run against waves of enemies.
+    Survival,
     /// Goes to the network mode selection menu.
     NetworkPlay,
     /// Opens control settings.
@@ -7,16 +7,22 @@ use amethyst::{
 };
 use audio_model::loaded::{SourceSequence, SourceSequenceHandles};
 use audio_play::SequenceAudioPlaySystem;
-use camera_play::{CameraTrackingSystem, CameraVelocitySystem};
+use camera_play::{
+    CameraShakeSystem, CameraTrackingSystem, CameraVelocitySystem, ScreenFlashSystem,
+};
 use character_model::{
     config::CharacterIrr,
-    loaded::{CharacterIrs, CharacterIrsHandles},
+    loaded::{CharacterIrs, CharacterIrsHandles, CharacterSequenceEndTransitions},
 };
 use charge_play::{
     ChargeIncrementSystem, ChargeInitializeDelaySystem, ChargeInitializeDetectionSystem,
-    ChargeRetentionSystem, ChargeUsageSystem,
+    ChargeLevelUpdateSystem, ChargeRetentionSystem, ChargeTintPulseSystem, ChargeUsageSystem,
+};
+use chase_model::loaded::ChaseModes;
+use chase_play::{
+    FollowTargetObjectSystem, HomeTowardsEnemySystem, OrbitTargetObjectSystem,
+    StickToTargetObjectSystem,
 };
-use chase_play::StickToTargetObjectSystem;
 use collision_audio_play::HitSfxSystem;
 use collision_model::loaded::{
     BodySequence, BodySequenceHandles, InteractionsSequence, InteractionsSequenceHandles,
@@ -26,8 +32,13 @@ use collision_play::{
     HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
 };
 use derive_new::new;
+use frame_event_model::loaded::{FrameEventsSequence, FrameEventsSequenceHandles};
+use frame_event_play::{FrameEventSystem, HitTintSystem};
 use game_input_model::play::ControllerInput;
-use game_play_hud::{CpBarUpdateSystem, HpBarUpdateSystem};
+use game_play_hud::{
+    CpBarFlashSystem, CpBarUpdateSystem, HpBarUpdateSystem, StatusIconUpdateSystem,
+};
+use game_play_model::play::SimulationStatus;
 use input_reaction_model::{
     config::BasicIrr,
     loaded::{InputReactionsSequence, InputReactionsSequenceHandles},
@@ -36,8 +47,10 @@ use input_reaction_play::{
     ButtonInputReactionsTransitionSystem, ButtonInputReactionsTransitionSystemDesc,
     InputReactionsTransitionSystem, InteractableObjectSyncSystem,
 };
+use item_model::loaded::{HoldPointSequence, HoldPointSequenceHandles};
+use item_play::{ItemHoldSystem, ItemLandSystem, ItemPickUpSystem, ItemThrowSystem};
 use kinematic_model::{
-    config::Position,
+    config::{FixedPoint, Position},
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
 };
 use map_play::{
@@ -47,27 +60,38 @@ use map_play::{
 use object_play::{
     ObjectAccelerationSystem, ObjectGravitySystem, ObjectGroundingSystem, ObjectMirroringSystem,
 };
-use object_status_play::StunPointsReductionSystem;
+use object_status_play::{
+    StatusEffectApplySystem, StatusEffectHitSystem, StatusEffectSpawnSystem,
+    StatusEffectTickSystem, StunPointsReductionSystem,
+};
 use sequence_model::loaded::{SequenceEndTransitions, WaitSequence, WaitSequenceHandles};
 use sequence_play::{
     FrameComponentUpdateSystem, SequenceComponentUpdateSystem, SequenceEndTransitionSystem,
-    SequenceStatusUpdateSystem, SequenceUpdateSystem,
+    SequenceReloadSystem, SequenceStatusUpdateSystem, SequenceUpdateSystem,
 };
 use spawn_model::loaded::{SpawnsSequence, SpawnsSequenceHandles};
 use spawn_play::{SpawnGameObjectRectifySystem, SpawnGameObjectSystem};
 use sprite_model::loaded::{
-    ScaleSequence, ScaleSequenceHandles, SpriteRenderSequence, SpriteRenderSequenceHandles,
-    TintSequence, TintSequenceHandles,
+    InterpolationSequence, InterpolationSequenceHandles, ScaleSequence, ScaleSequenceHandles,
+    SpriteOffsetSequence, SpriteOffsetSequenceHandles, SpriteRenderSequence,
+    SpriteRenderSequenceHandles, TintSequence, TintSequenceHandles,
 };
-use sprite_play::SpriteScaleUpdateSystem;
+use sprite_play::{SpriteInterpolationSystem, SpriteScaleUpdateSystem, SpriteSheetVariantSystem};
 use state_registry::StateId;
+use survival_play::{EnemyAiInputSystem, SurvivalHudDisplaySystem, SurvivalWaveSpawnSystem};
 use tracker::LastTrackerSystem;
+use training_play::{
+    FrameDataUpdateSystem, TrainingDummyInputSystem, TrainingEntitiesUpdateSystem,
+    TrainingEventSystem, TrainingHudDisplaySystem, TrainingKeyInputSystem, TrainingRefillSystem,
+};
 
 use crate::{
-    CharacterHitEffectSystem, CharacterSequenceUpdateSystem, FrameFreezeClockAugmentSystem,
-    GamePlayEndDetectionSystem, GamePlayEndTransitionDelaySystem, GamePlayEndTransitionSystem,
-    GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem, GroundingFrictionSystem,
-    ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    CharacterChargeLevelTransitionSystem, CharacterHitEffectSystem,
+    CharacterSequenceEndTransitionSystem, CharacterSequenceUpdateSystem,
+    FrameFreezeClockAugmentSystem, GamePlayEndDetectionSystem, GamePlayEndTransitionDelaySystem,
+    GamePlayEndTransitionSystem, GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem,
+    GroundingFrictionSystem, ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    TintResolutionSystem,
 };
 
 /// Adds the object type update systems to the provided dispatcher.
@@ -80,12 +104,21 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         world: &mut World,
         builder: &mut DispatcherBuilder<'a, 'b>,
     ) -> Result<(), Error> {
+        // Systems that only update game objects are paused with `SimulationStatus`. Sequence
+        // systems are shared with UI entities, so game objects are marked with `SequencePause`.
+
         // === Component augmentation === //
 
+        // Refreshes sequence components for objects whose definitions are reloaded.
+        builder.add(
+            SequenceReloadSystem::new(),
+            any::type_name::<SequenceReloadSystem>(),
+            &[],
+        ); // kcov-ignore
         builder.add(
             SequenceStatusUpdateSystem::new(),
             any::type_name::<SequenceStatusUpdateSystem>(),
-            &[],
+            &[any::type_name::<SequenceReloadSystem>()],
         ); // kcov-ignore
 
         macro_rules! sequence_component_update_system {
@@ -110,11 +143,17 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         sequence_component_update_system!(BodySequenceHandles);
         sequence_component_update_system!(InteractionsSequenceHandles);
         sequence_component_update_system!(SpawnsSequenceHandles);
+        sequence_component_update_system!(FrameEventsSequenceHandles);
         sequence_component_update_system!(SequenceEndTransitions);
         sequence_component_update_system!(TintSequenceHandles);
         sequence_component_update_system!(ScaleSequenceHandles);
+        sequence_component_update_system!(SpriteOffsetSequenceHandles);
+        sequence_component_update_system!(InterpolationSequenceHandles);
         sequence_component_update_system!(CharacterIrsHandles);
+        sequence_component_update_system!(CharacterSequenceEndTransitions);
         sequence_component_update_system!(InputReactionsSequenceHandles);
+        sequence_component_update_system!(HoldPointSequenceHandles);
+        sequence_component_update_system!(ChaseModes);
 
         // TODO: The `SequenceUpdateSystem`s depend on the following systems:
         //
@@ -152,18 +191,22 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         frame_component_update_system!(BodySequence);
         frame_component_update_system!(InteractionsSequence);
         frame_component_update_system!(SpawnsSequence);
+        frame_component_update_system!(FrameEventsSequence);
         frame_component_update_system!(TintSequence);
         frame_component_update_system!(ScaleSequence);
+        frame_component_update_system!(SpriteOffsetSequence);
+        frame_component_update_system!(InterpolationSequence);
         frame_component_update_system!(CharacterIrs);
         frame_component_update_system!(InputReactionsSequence);
+        frame_component_update_system!(HoldPointSequence);
 
         builder.add(
-            FrameFreezeClockAugmentSystem::new(),
+            FrameFreezeClockAugmentSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<FrameFreezeClockAugmentSystem>(),
             &[any::type_name::<SequenceUpdateSystem>()],
         ); // kcov-ignore
         builder.add(
-            HitRepeatTrackersAugmentSystem::new(),
+            HitRepeatTrackersAugmentSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<HitRepeatTrackersAugmentSystem>(),
             &[],
         ); // kcov-ignore
@@ -177,59 +220,135 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[any::type_name::<FrameComponentUpdateSystem<SourceSequence>>()],
         ); // kcov-ignore
 
+        // Camera shakes, screen flashes, sounds and custom events from frame updates.
+        builder.add(
+            FrameEventSystem::new(),
+            any::type_name::<FrameEventSystem>(),
+            &[any::type_name::<FrameComponentUpdateSystem<FrameEventsSequence>>()],
+        ); // kcov-ignore
+
         // Spawn objects
         builder.add(
             SpawnGameObjectSystem::new(),
             any::type_name::<SpawnGameObjectSystem>(),
             &[any::type_name::<FrameComponentUpdateSystem<SpawnsSequence>>()],
         ); // kcov-ignore
+
+        // Spawns survival waves. This only spawns enemies when `SurvivalStatus` is `Active`.
+        builder.add(
+            SurvivalWaveSpawnSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<SurvivalWaveSpawnSystem>(),
+            &[any::type_name::<SpawnGameObjectSystem>()],
+        ); // kcov-ignore
         builder.add(
             SpawnGameObjectRectifySystem::new(),
             any::type_name::<SpawnGameObjectRectifySystem>(),
-            &[any::type_name::<SpawnGameObjectSystem>()],
+            &[
+                any::type_name::<SpawnGameObjectSystem>(),
+                any::type_name::<SurvivalWaveSpawnSystem>(),
+            ],
         ); // kcov-ignore
         builder.add(
             MapSpawnOutOfBoundsDetectionSystem::new().pausable(StateId::GamePlay),
             any::type_name::<MapSpawnOutOfBoundsDetectionSystem>(),
             &[any::type_name::<SpawnGameObjectRectifySystem>()],
         ); // kcov-ignore
+
+        // Status effects applied to spawned objects.
+        builder.add(
+            StatusEffectSpawnSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<StatusEffectSpawnSystem>(),
+            &[any::type_name::<SpawnGameObjectRectifySystem>()],
+        ); // kcov-ignore
         builder.add(
             GamePlayRemovalAugmentSystem::new(),
             any::type_name::<GamePlayRemovalAugmentSystem>(),
-            &[any::type_name::<SpawnGameObjectSystem>()],
+            &[
+                any::type_name::<SpawnGameObjectSystem>(),
+                any::type_name::<SurvivalWaveSpawnSystem>(),
+            ],
         ); // kcov-ignore
 
         builder.add_barrier();
 
         // === Component value update === //
 
+        // `Scale` and `SpriteOffsetTranslation` blended towards the next frame.
+        builder.add(
+            SpriteInterpolationSystem::new(),
+            any::type_name::<SpriteInterpolationSystem>(),
+            &[],
+        ); // kcov-ignore
+
+        // `ChargeTintPulse`s started when a higher `ChargeLevel` is reached.
+        builder.add(
+            ChargeTintPulseSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<ChargeTintPulseSystem>(),
+            &[],
+        ); // kcov-ignore
+
+        // `HitTint`s started when hit by a frame with a `HitTint` frame event.
+        builder.add(
+            HitTintSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<HitTintSystem>(),
+            &[],
+        ); // kcov-ignore
+
+        // `Tint` resolved from the frame's `Tint` or `SelfTint`, charge pulse, hit tint, and status
+        // effect.
+        builder.add(
+            TintResolutionSystem::new(),
+            any::type_name::<TintResolutionSystem>(),
+            &[
+                any::type_name::<ChargeTintPulseSystem>(),
+                any::type_name::<HitTintSystem>(),
+            ],
+        ); // kcov-ignore
+
+        // `SpriteRender`s drawn from the entity's variant sprite sheets.
+        builder.add(
+            SpriteSheetVariantSystem::new(),
+            any::type_name::<SpriteSheetVariantSystem>(),
+            &[],
+        ); // kcov-ignore
+
         // transform.scale_mut().{x/y/z} = `Scale`
         builder.add(
             SpriteScaleUpdateSystem::new(),
             any::type_name::<SpriteScaleUpdateSystem>(),
-            &[],
+            &[any::type_name::<SpriteInterpolationSystem>()],
         ); // kcov-ignore
 
         // vel += `ObjectAcceleration` (from frame config).
         builder.add(
-            ObjectAccelerationSystem::new(),
+            ObjectAccelerationSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ObjectAccelerationSystem>(),
             &[],
         ); // kcov-ignore
 
+        // vel = direction to nearest enemy * speed, for homing objects.
+        builder.add(
+            HomeTowardsEnemySystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<HomeTowardsEnemySystem>(),
+            &[any::type_name::<ObjectAccelerationSystem>()],
+        ); // kcov-ignore
+
         // pos += vel
         // This must be between the `FrameFreezeClockAugmentSystem` and `SequenceUpdateSystem`s
         // since it needs to wait for the `FrameFreezeClock` to tick.
         builder.add(
-            ObjectKinematicsUpdateSystem::new(),
+            ObjectKinematicsUpdateSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ObjectKinematicsUpdateSystem>(),
-            &[any::type_name::<ObjectAccelerationSystem>()],
+            &[
+                any::type_name::<ObjectAccelerationSystem>(),
+                any::type_name::<HomeTowardsEnemySystem>(),
+            ],
         ); // kcov-ignore
 
         // `Position` correction based on margins.
         // vel += mass
         builder.add(
-            ObjectGravitySystem::new(),
+            ObjectGravitySystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ObjectGravitySystem>(),
             &[any::type_name::<ObjectKinematicsUpdateSystem>()],
         ); // kcov-ignore
@@ -246,18 +365,21 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         builder.add(
             ObjectGroundingSystem::new().pausable(StateId::GamePlay),
             any::type_name::<ObjectGroundingSystem>(),
-            &[any::type_name::<MapEnterExitDetectionSystem>()],
+            &[
+                any::type_name::<MapEnterExitDetectionSystem>(),
+                any::type_name::<KeepWithinMapBoundsSystem>(),
+            ],
         ); // kcov-ignore
 
-        // Updates `Velocity<f32>` based on grounding.
+        // Updates `Velocity<FixedPoint>` based on grounding.
         builder.add(
-            GroundingFrictionSystem::new(),
+            GroundingFrictionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<GroundingFrictionSystem>(),
             &[any::type_name::<ObjectGroundingSystem>()],
         ); // kcov-ignore
 
         builder.add(
-            MapOutOfBoundsDeletionSystem::new(),
+            MapOutOfBoundsDeletionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<MapOutOfBoundsDeletionSystem>(),
             &[
                 any::type_name::<MapEnterExitDetectionSystem>(),
@@ -265,17 +387,55 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             ],
         ); // kcov-ignore
         builder.add(
-            MapOutOfBoundsClockAugmentSystem::new(),
+            MapOutOfBoundsClockAugmentSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<MapOutOfBoundsClockAugmentSystem>(),
             &[any::type_name::<MapOutOfBoundsDeletionSystem>()],
         ); // kcov-ignore
 
+        // Releases held items with the throw velocity.
+        builder.add(
+            ItemThrowSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<ItemThrowSystem>(),
+            &[
+                any::type_name::<KeepWithinMapBoundsSystem>(),
+                any::type_name::<GroundingFrictionSystem>(),
+            ],
+        ); // kcov-ignore
+
+        // Moves held items to the holder's `HoldPoint`.
+        builder.add(
+            ItemHoldSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<ItemHoldSystem>(),
+            &[any::type_name::<ItemThrowSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            ItemLandSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<ItemLandSystem>(),
+            &[any::type_name::<ItemThrowSystem>()],
+        ); // kcov-ignore
+
+        // Chasing objects are placed relative to their target's final position for this tick.
+        builder.add(
+            OrbitTargetObjectSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<OrbitTargetObjectSystem>(),
+            &[any::type_name::<ObjectGroundingSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            FollowTargetObjectSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<FollowTargetObjectSystem>(),
+            &[any::type_name::<OrbitTargetObjectSystem>()],
+        ); // kcov-ignore
+
         builder.add(
             ObjectTransformUpdateSystem::new(),
             any::type_name::<ObjectTransformUpdateSystem>(),
             &[
                 any::type_name::<ObjectKinematicsUpdateSystem>(),
                 any::type_name::<KeepWithinMapBoundsSystem>(),
+                any::type_name::<ItemHoldSystem>(),
+                any::type_name::<OrbitTargetObjectSystem>(),
+                any::type_name::<FollowTargetObjectSystem>(),
+                any::type_name::<SpriteInterpolationSystem>(),
             ],
         ); // kcov-ignore
         builder.add(
@@ -296,20 +456,27 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
 
         // Reduces charge when not charging.
         builder.add(
-            ChargeRetentionSystem::new(),
+            ChargeRetentionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ChargeRetentionSystem>(),
             &[],
         ); // kcov-ignore
 
         // Reduces `StunPoints` each tick.
         builder.add(
-            StunPointsReductionSystem::new(),
+            StunPointsReductionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<StunPointsReductionSystem>(),
             &[],
         ); // kcov-ignore
 
+        // Ticks status effects, reducing HP for poison and burn, and removing ended effects.
         builder.add(
-            HitRepeatTrackersTickerSystem::new(),
+            StatusEffectTickSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<StatusEffectTickSystem>(),
+            &[any::type_name::<TintResolutionSystem>()],
+        ); // kcov-ignore
+
+        builder.add(
+            HitRepeatTrackersTickerSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<HitRepeatTrackersTickerSystem>(),
             &[any::type_name::<HitRepeatTrackersAugmentSystem>()],
         ); // kcov-ignore
@@ -319,7 +486,7 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // === Effect Detection === //
 
         builder.add(
-            CollisionDetectionSystem::new(),
+            CollisionDetectionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<CollisionDetectionSystem>(),
             &[
                 any::type_name::<StunPointsReductionSystem>(),
@@ -327,15 +494,20 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             ],
         ); // kcov-ignore
         builder.add(
-            ContactDetectionSystem::new(),
+            ContactDetectionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ContactDetectionSystem>(),
             &[any::type_name::<CollisionDetectionSystem>()],
         ); // kcov-ignore
         builder.add(
-            HitDetectionSystem::new(),
+            HitDetectionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<HitDetectionSystem>(),
             &[any::type_name::<ContactDetectionSystem>()],
         ); // kcov-ignore
+        builder.add(
+            ItemPickUpSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<ItemPickUpSystem>(),
+            &[any::type_name::<ContactDetectionSystem>()],
+        ); // kcov-ignore
 
         builder.add_barrier();
 
@@ -370,46 +542,65 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // `CharacterSequenceUpdater` transitions should overwrite the `SequenceEndTransition`
         // update.
         builder.add(
-            CharacterSequenceUpdateSystem::new(),
+            CharacterSequenceUpdateSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<CharacterSequenceUpdateSystem>(),
             &[any::type_name::<SequenceEndTransitionSystem>()],
         ); // kcov-ignore
+
+        // Conditional end transitions are explicitly configured, so they overwrite the
+        // `CharacterSequenceUpdater` transitions.
         builder.add(
-            InputReactionsTransitionSystem::<CharacterIrr>::new(),
-            &any::type_name::<InputReactionsTransitionSystem<CharacterIrr>>(),
+            CharacterSequenceEndTransitionSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<CharacterSequenceEndTransitionSystem>(),
             &[any::type_name::<CharacterSequenceUpdateSystem>()],
         ); // kcov-ignore
         builder.add(
-            CharacterHitEffectSystem::new(),
-            any::type_name::<CharacterHitEffectSystem>(),
+            InputReactionsTransitionSystem::<CharacterIrr>::new()
+                .pausable(SimulationStatus::Running),
+            &any::type_name::<InputReactionsTransitionSystem<CharacterIrr>>(),
+            &[any::type_name::<CharacterSequenceEndTransitionSystem>()],
+        ); // kcov-ignore
+
+        // Input reaction sequences may be swapped for the charge tier's sequence.
+        builder.add(
+            CharacterChargeLevelTransitionSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<CharacterChargeLevelTransitionSystem>(),
             &[&any::type_name::<
                 InputReactionsTransitionSystem<CharacterIrr>,
             >()],
         ); // kcov-ignore
+        builder.add(
+            CharacterHitEffectSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<CharacterHitEffectSystem>(),
+            &[any::type_name::<CharacterChargeLevelTransitionSystem>()],
+        ); // kcov-ignore
 
         // Charging
         builder.add(
-            ChargeInitializeDetectionSystem::new(),
+            ChargeInitializeDetectionSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ChargeInitializeDetectionSystem>(),
-            &[&any::type_name::<
-                InputReactionsTransitionSystem<CharacterIrr>,
-            >()],
+            &[any::type_name::<CharacterChargeLevelTransitionSystem>()],
         ); // kcov-ignore
         builder.add(
-            ChargeInitializeDelaySystem::new(),
+            ChargeInitializeDelaySystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ChargeInitializeDelaySystem>(),
             &[any::type_name::<ChargeInitializeDetectionSystem>()],
         ); // kcov-ignore
         builder.add(
-            ChargeIncrementSystem::new(),
+            ChargeIncrementSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ChargeIncrementSystem>(),
             &[any::type_name::<ChargeInitializeDelaySystem>()],
         ); // kcov-ignore
         builder.add(
-            ChargeUsageSystem::new(),
+            ChargeUsageSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<ChargeUsageSystem>(),
             &[any::type_name::<ChargeIncrementSystem>()],
         ); // kcov-ignore
+        builder.add(
+            ChargeLevelUpdateSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<ChargeLevelUpdateSystem>(),
+            &[any::type_name::<ChargeUsageSystem>()],
+        ); // kcov-ignore
 
         // Hit / Hitting effects.
         //
@@ -418,16 +609,34 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // The `HitEffectSystem` depends on the `HittingEffectSystem` to ensure the
         // `Hit` sequence is deterministic and overwrites the `Hitting` sequence.
         builder.add(
-            HittingEffectSystem::new(),
+            HittingEffectSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<HittingEffectSystem>(),
             &[],
         ); // kcov-ignore
         builder.add(
-            HitEffectSystem::new(),
+            HitEffectSystem::new().pausable(SimulationStatus::Running),
             any::type_name::<HitEffectSystem>(),
             &[any::type_name::<HittingEffectSystem>()],
         ); // kcov-ignore
 
+        // Status effects from hits, spawns, and input reactions.
+        //
+        // Invulnerability is applied after the `CharacterHitEffectSystem`, so it takes effect from
+        // the next tick.
+        builder.add(
+            StatusEffectHitSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<StatusEffectHitSystem>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            StatusEffectApplySystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<StatusEffectApplySystem>(),
+            &[
+                any::type_name::<StatusEffectHitSystem>(),
+                any::type_name::<CharacterHitEffectSystem>(),
+            ],
+        ); // kcov-ignore
+
         // Perhaps this should be straight after the `StickToTargetObjectSystem`, but we put it here
         // so that the renderer will show the HP including the damage dealt this frame, instead of
         // one frame later.
@@ -441,6 +650,88 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<CpBarUpdateSystem>(),
             &[any::type_name::<CharacterHitEffectSystem>()],
         ); // kcov-ignore
+        builder.add(
+            CpBarFlashSystem::new(),
+            any::type_name::<CpBarFlashSystem>(),
+            &[
+                any::type_name::<CpBarUpdateSystem>(),
+                any::type_name::<ChargeLevelUpdateSystem>(),
+            ],
+        ); // kcov-ignore
+        builder.add(
+            StatusIconUpdateSystem::new(),
+            any::type_name::<StatusIconUpdateSystem>(),
+            &[any::type_name::<StatusEffectApplySystem>()],
+        ); // kcov-ignore
+
+        // Training mode.
+        //
+        // These systems only affect game play when `TrainingStatus` is `Active`.
+        builder.add(
+            TrainingKeyInputSystem::new(),
+            any::type_name::<TrainingKeyInputSystem>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            TrainingEventSystem::new(),
+            any::type_name::<TrainingEventSystem>(),
+            &[any::type_name::<TrainingKeyInputSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            TrainingEntitiesUpdateSystem::new(),
+            any::type_name::<TrainingEntitiesUpdateSystem>(),
+            &[],
+        ); // kcov-ignore
+
+        // Refill after damage and charge usage so the HUD shows the refilled values.
+        builder.add(
+            TrainingRefillSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<TrainingRefillSystem>(),
+            &[
+                any::type_name::<TrainingEntitiesUpdateSystem>(),
+                any::type_name::<CharacterHitEffectSystem>(),
+                any::type_name::<ChargeUsageSystem>(),
+            ],
+        ); // kcov-ignore
+        builder.add(
+            TrainingDummyInputSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<TrainingDummyInputSystem>(),
+            &[
+                any::type_name::<TrainingEventSystem>(),
+                any::type_name::<TrainingEntitiesUpdateSystem>(),
+            ],
+        ); // kcov-ignore
+        builder.add(
+            FrameDataUpdateSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<FrameDataUpdateSystem>(),
+            &[
+                any::type_name::<TrainingEntitiesUpdateSystem>(),
+                any::type_name::<CharacterHitEffectSystem>(),
+                any::type_name::<HitEffectSystem>(),
+            ],
+        ); // kcov-ignore
+        builder.add(
+            TrainingHudDisplaySystem::new(),
+            any::type_name::<TrainingHudDisplaySystem>(),
+            &[
+                any::type_name::<TrainingEventSystem>(),
+                any::type_name::<FrameDataUpdateSystem>(),
+            ],
+        ); // kcov-ignore
+
+        // Survival mode.
+        //
+        // These systems only affect game play when `SurvivalStatus` is `Active`.
+        builder.add(
+            EnemyAiInputSystem::new().pausable(SimulationStatus::Running),
+            any::type_name::<EnemyAiInputSystem>(),
+            &[any::type_name::<CharacterHitEffectSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            SurvivalHudDisplaySystem::new(),
+            any::type_name::<SurvivalHudDisplaySystem>(),
+            &[],
+        ); // kcov-ignore
 
         builder.add_barrier();
 
@@ -483,9 +774,19 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<CameraVelocitySystem>(),
             &[any::type_name::<CameraTrackingSystem>()],
         ); // kcov-ignore
+        builder.add(
+            CameraShakeSystem::new(),
+            any::type_name::<CameraShakeSystem>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            ScreenFlashSystem::new(),
+            any::type_name::<ScreenFlashSystem>(),
+            &[],
+        ); // kcov-ignore
 
         let position_tracker_system =
-            LastTrackerSystem::<Position<f32>>::new(stringify!(Position<f32>));
+            LastTrackerSystem::<Position<FixedPoint>>::new(stringify!(Position<FixedPoint>));
         let position_tracker_system_name = position_tracker_system.system_name();
         builder.add(position_tracker_system, &position_tracker_system_name, &[]); // kcov-ignore
 
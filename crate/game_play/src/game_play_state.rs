@@ -1,16 +1,22 @@
 use amethyst::{
-    ecs::{Entity, World, WorldExt},
+    ecs::{Entity, Join, World, WorldExt},
     input::{is_key_down, VirtualKeyCode},
     GameData, State, StateData, Trans,
 };
 use application_event::AppEvent;
 use derivative::Derivative;
 use derive_new::new;
+use game_loading::GameLoadingState;
 use game_model::play::GameEntities;
-use game_play_model::{GamePlayEntity, GamePlayEvent, GamePlayStatus};
-use log::debug;
+use game_play_model::{play::SimulationStatus, GamePlayEntity, GamePlayEvent, GamePlayStatus};
+use log::{debug, warn};
+use network_session_model::play::SessionStatus;
+use pause_menu::{PauseMenuStateBuilder, PauseMenuStateDelegate};
+use sequence_model::play::SequencePause;
+use spawn_model::play::SpawnRng;
 use state_registry::StateId;
 use state_support::StateEntityUtils;
+use survival_model::play::SurvivalProgress;
 
 /// `State` where game play takes place.
 #[derive(Derivative, Default, new)]
@@ -41,33 +47,121 @@ impl GamePlayState {
 
         StateEntityUtils::clear::<GamePlayEntity>(world);
     }
+
+    /// Returns whether a network session is in play.
+    ///
+    /// Pausing is disabled during network sessions, as the session devices would otherwise go out
+    /// of sync.
+    fn is_network_session(world: &World) -> bool {
+        world
+            .try_fetch::<SessionStatus>()
+            .map(|session_status| match *session_status {
+                SessionStatus::JoinEstablished | SessionStatus::HostEstablished => true,
+                _ => false,
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the transition to the `PauseMenuState`, if game play may be paused.
+    fn pause(world: &mut World) -> Trans<GameData<'static, 'static>, AppEvent> {
+        if Self::is_network_session(world) {
+            warn!("Pausing is not supported during network sessions.");
+            return Trans::None;
+        }
+
+        let game_play_status = *world.read_resource::<GamePlayStatus>();
+        if game_play_status == GamePlayStatus::Playing {
+            debug!("Pausing `GamePlayState`.");
+            world.insert(GamePlayStatus::Paused);
+
+            let pause_menu_state =
+                PauseMenuStateBuilder::new(PauseMenuStateDelegate::new()).build();
+            Trans::Push(Box::new(pause_menu_state))
+        } else {
+            Trans::None
+        }
+    }
+
+    /// Stops game entities from being updated while another state is on top.
+    ///
+    /// Sequence update systems are shared with UI entities, so game entities are individually
+    /// marked with `SequencePause`.
+    fn pause_entities(world: &mut World) {
+        world.insert(SimulationStatus::Paused);
+
+        let entities = world.entities();
+        let game_entities = world.read_resource::<GameEntities>();
+        let game_play_entities = world.read_storage::<GamePlayEntity>();
+        let mut sequence_pauses = world.write_storage::<SequencePause>();
+
+        game_entities
+            .iter()
+            .copied()
+            .chain(
+                (&entities, &game_play_entities)
+                    .join()
+                    .map(|(entity, _)| entity),
+            )
+            .filter(|entity| entities.is_alive(*entity))
+            .for_each(|entity| {
+                sequence_pauses
+                    .insert(entity, SequencePause)
+                    .expect("Failed to insert `SequencePause` component.");
+            });
+    }
+
+    /// Allows game entities to be updated again.
+    fn resume_entities(world: &mut World) {
+        world.insert(SimulationStatus::Running);
+        world.write_storage::<SequencePause>().clear();
+    }
 }
 
-impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
+impl State<GameData<'static, 'static>, AppEvent> for GamePlayState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         data.world.insert(StateId::GamePlay);
         data.world.insert(GamePlayStatus::Playing);
+        data.world.insert(SurvivalProgress::default());
+        data.world.insert(SpawnRng::default());
     }
 
     fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
+        Self::resume_entities(&mut data.world);
         self.terminate_entities(&mut data.world);
     }
 
-    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+    fn on_pause(&mut self, data: StateData<'_, GameData<'static, 'static>>) {
+        Self::pause_entities(data.world);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'static, 'static>>) {
         data.world.insert(StateId::GamePlay);
+
+        Self::resume_entities(data.world);
+        let game_play_status = *data.world.read_resource::<GamePlayStatus>();
+        if game_play_status == GamePlayStatus::Paused {
+            data.world.insert(GamePlayStatus::Playing);
+        }
     }
 
     fn handle_event(
         &mut self,
         data: StateData<'_, GameData<'_, '_>>,
         event: AppEvent,
-    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
         match event {
             AppEvent::Window(window_event) => {
                 if is_key_down(&window_event, VirtualKeyCode::Escape) {
-                    debug!("Returning from `GamePlayState`.");
-                    data.world.insert(GamePlayStatus::None);
-                    Trans::Pop
+                    let game_play_status = *data.world.read_resource::<GamePlayStatus>();
+                    if game_play_status == GamePlayStatus::Playing
+                        && !Self::is_network_session(data.world)
+                    {
+                        Self::pause(data.world)
+                    } else {
+                        debug!("Returning from `GamePlayState`.");
+                        data.world.insert(GamePlayStatus::None);
+                        Trans::Pop
+                    }
                 } else {
                     Trans::None
                 }
@@ -80,13 +174,14 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
                         Trans::Pop
                     }
                     GamePlayEvent::Restart => {
-                        // TODO: Switch to `GameLoadingState`
-                        Trans::None
-                    }
-                    GamePlayEvent::Pause => {
-                        data.world.insert(GamePlayStatus::Paused);
-                        Trans::None
+                        debug!("Restarting `GamePlayState`.");
+                        data.world.insert(GamePlayStatus::None);
+
+                        // Game entities are spawned again from the existing selections.
+                        let game_play_fn = || Box::new(GamePlayState::new());
+                        Trans::Switch(Box::new(GameLoadingState::new(game_play_fn)))
                     }
+                    GamePlayEvent::Pause => Self::pause(data.world),
                     GamePlayEvent::Resume => {
                         data.world.insert(GamePlayStatus::Playing);
                         Trans::None
@@ -105,7 +200,7 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
     fn update(
         &mut self,
         data: StateData<'_, GameData<'_, '_>>,
-    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
         // Note: The built-in dispatcher must be run before the state specific dispatcher as the
         // `"input_system"` is registered in the main dispatcher, and is a dependency of the
         // `ControllerInputUpdateSystem`.
@@ -9,17 +9,19 @@ pub use crate::{
     game_play_bundle::GamePlayBundle,
     game_play_state::GamePlayState,
     system::{
-        CharacterHitEffectSystem, CharacterHitEffectSystemData, CharacterSequenceUpdateSystem,
-        CharacterSequenceUpdateSystemData, FrameFreezeClockAugmentSystem,
-        FrameFreezeClockAugmentSystemData, GamePlayEndDetectionSystem,
-        GamePlayEndDetectionSystemData, GamePlayEndTransitionDelaySystem,
-        GamePlayEndTransitionDelaySystemData, GamePlayEndTransitionSystem,
-        GamePlayEndTransitionSystemData, GamePlayRemovalAugmentSystem,
+        CharacterChargeLevelTransitionSystem, CharacterChargeLevelTransitionSystemData,
+        CharacterHitEffectSystem, CharacterHitEffectSystemData,
+        CharacterSequenceEndTransitionSystem, CharacterSequenceEndTransitionSystemData,
+        CharacterSequenceUpdateSystem, CharacterSequenceUpdateSystemData,
+        FrameFreezeClockAugmentSystem, FrameFreezeClockAugmentSystemData,
+        GamePlayEndDetectionSystem, GamePlayEndDetectionSystemData,
+        GamePlayEndTransitionDelaySystem, GamePlayEndTransitionDelaySystemData,
+        GamePlayEndTransitionSystem, GamePlayEndTransitionSystemData, GamePlayRemovalAugmentSystem,
         GamePlayRemovalAugmentSystemData, GamePlayStatusDisplaySystem,
         GamePlayStatusDisplaySystemData, GroundingFrictionSystem, GroundingFrictionSystemData,
         ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
-        ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
-        GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
+        ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData, TintResolutionSystem,
+        TintResolutionSystemData, GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
     },
 };
 
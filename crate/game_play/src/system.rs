@@ -1,5 +1,11 @@
 pub use self::{
+    character_charge_level_transition_system::{
+        CharacterChargeLevelTransitionSystem, CharacterChargeLevelTransitionSystemData,
+    },
     character_hit_effect_system::{CharacterHitEffectSystem, CharacterHitEffectSystemData},
+    character_sequence_end_transition_system::{
+        CharacterSequenceEndTransitionSystem, CharacterSequenceEndTransitionSystemData,
+    },
     character_sequence_update_system::{
         CharacterSequenceUpdateSystem, CharacterSequenceUpdateSystemData,
     },
@@ -25,9 +31,12 @@ pub use self::{
         ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
     },
     sequence::{FrameFreezeClockAugmentSystem, FrameFreezeClockAugmentSystemData},
+    tint_resolution_system::{TintResolutionSystem, TintResolutionSystemData},
 };
 
+mod character_charge_level_transition_system;
 mod character_hit_effect_system;
+mod character_sequence_end_transition_system;
 mod character_sequence_update_system;
 mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
@@ -38,3 +47,4 @@ mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
 mod sequence;
+mod tint_resolution_system;
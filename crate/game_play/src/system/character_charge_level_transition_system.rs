@@ -0,0 +1,106 @@
+use amethyst::{
+    ecs::{
+        storage::ComponentEvent, BitSet, Entities, Join, ReadStorage, ReaderId, System, World,
+        WriteStorage,
+    },
+    shred::{ResourceId, SystemData},
+};
+use character_model::loaded::CharacterSequenceChargeLevelNexts;
+use charge_model::config::ChargeLevel;
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Transitions characters that enter a sequence to the sequence for the `ChargeLevel` reached.
+///
+/// This allows a single input reaction to pick its target sequence by the charge tier.
+#[derive(Debug, Default, new)]
+pub struct CharacterChargeLevelTransitionSystem {
+    /// Reader ID for sequence ID changes.
+    #[new(default)]
+    sequence_id_rid: Option<ReaderId<ComponentEvent>>,
+    /// Pre-allocated bitset to track insertions and modifications to `SequenceId`s.
+    #[new(default)]
+    sequence_id_updates: BitSet,
+}
+
+/// `CharacterChargeLevelTransitionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterChargeLevelTransitionSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CharacterSequenceChargeLevelNexts` components.
+    #[derivative(Debug = "ignore")]
+    pub character_sequence_charge_level_nextses: ReadStorage<'s, CharacterSequenceChargeLevelNexts>,
+    /// `ChargeLevel` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levels: ReadStorage<'s, ChargeLevel>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for CharacterChargeLevelTransitionSystem {
+    type SystemData = CharacterChargeLevelTransitionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CharacterChargeLevelTransitionSystemData {
+            entities,
+            character_sequence_charge_level_nextses,
+            charge_levels,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        self.sequence_id_updates.clear();
+
+        sequence_ids
+            .channel()
+            .read(
+                self.sequence_id_rid
+                    .as_mut()
+                    .expect("Expected `sequence_id_rid` to be set."),
+            )
+            .for_each(|event| match event {
+                ComponentEvent::Inserted(id) | ComponentEvent::Modified(id) => {
+                    self.sequence_id_updates.add(*id);
+                }
+                ComponentEvent::Removed(_id) => {}
+            });
+
+        (
+            &entities,
+            &character_sequence_charge_level_nextses,
+            &charge_levels,
+            &self.sequence_id_updates,
+        )
+            .join()
+            .filter_map(
+                |(entity, character_sequence_charge_level_nexts, charge_level, _)| {
+                    sequence_ids
+                        .get(entity)
+                        .and_then(|sequence_id| {
+                            character_sequence_charge_level_nexts.get(**sequence_id)
+                        })
+                        .and_then(|character_charge_level_next| {
+                            character_charge_level_next.sequence_id(*charge_level)
+                        })
+                        .map(|sequence_id| (entity, sequence_id))
+                },
+            )
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|(entity, sequence_id)| {
+                sequence_ids
+                    .insert(entity, sequence_id)
+                    .expect("Failed to insert `SequenceId` component.");
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.sequence_id_rid = Some(WriteStorage::<'_, SequenceId>::fetch(world).register_reader());
+    }
+}
@@ -10,10 +10,13 @@ use collision_model::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Velocity;
+use kinematic_model::config::{FixedPoint, Velocity};
 use mirrored_model::play::Mirrored;
 use object_model::play::HealthPoints;
-use object_status_model::config::StunPoints;
+use object_status_model::{
+    config::{StatusEffectKind, StunPoints},
+    play::StatusEffects,
+};
 use sequence_model::loaded::SequenceId;
 
 const STUN_THRESHOLD_LOW: StunPoints = StunPoints(40);
@@ -21,6 +24,9 @@ const STUN_THRESHOLD_MID: StunPoints = StunPoints(80);
 const STUN_THRESHOLD_HIGH: StunPoints = StunPoints(120);
 
 /// Determines collision effects for characters.
+///
+/// Damage is scaled by the attacker's `DamageUp` and `DamageDown` status effects, and characters
+/// that are `Invulnerable` are not affected by hits.
 #[derive(Debug, Default, new)]
 pub struct CharacterHitEffectSystem {
     /// Reader ID for the `HitEvent` event channel.
@@ -41,15 +47,18 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
+    /// `StatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effectses: ReadStorage<'s, StatusEffects>,
     /// `HealthPoints` components.
     #[derivative(Debug = "ignore")]
     pub health_pointses: WriteStorage<'s, HealthPoints>,
     /// `StunPoints` components.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
-    /// `Velocity<f32>` components.
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
     /// `SequenceId` components.
     #[derivative(Debug = "ignore")]
     pub sequence_ids: WriteStorage<'s, SequenceId>,
@@ -64,6 +73,7 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
             hit_ec,
             mirroreds,
             character_hit_transitionses,
+            status_effectses,
             mut health_pointses,
             mut stun_pointses,
             mut velocities,
@@ -77,7 +87,18 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     .as_mut()
                     .expect("Expected reader ID to exist for CharacterHitEffectSystem."),
             )
+            .filter(|ev| {
+                let invulnerable = status_effectses
+                    .get(ev.to)
+                    .map(|status_effects| status_effects.contains(StatusEffectKind::Invulnerable))
+                    .unwrap_or(false);
+                !invulnerable
+            })
             .for_each(|ev| {
+                let damage_percent = status_effectses
+                    .get(ev.from)
+                    .map(StatusEffects::damage_percent)
+                    .unwrap_or(100);
                 let mirrored = mirroreds
                     .get(ev.from)
                     .map(|mirrored| **mirrored)
@@ -88,31 +109,39 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                 let stun_points = stun_pointses.get_mut(ev.to);
                 let velocity = velocities.get_mut(ev.to);
                 let sequence_id = sequence_ids.get_mut(ev.to);
+                let hit = if let Interaction {
+                    kind: InteractionKind::Hit(hit),
+                    ..
+                } = ev.interaction
+                {
+                    Some(hit)
+                } else {
+                    None
+                };
 
                 if let (
+                    Some(Hit {
+                        hp_damage,
+                        stun,
+                        acceleration,
+                        ..
+                    }),
                     Some(character_hit_transitions),
                     Some(health_points),
                     Some(stun_points),
                     Some(velocity),
                     Some(sequence_id),
                 ) = (
+                    hit,
                     character_hit_transitions,
                     health_points,
                     stun_points,
                     velocity,
                     sequence_id,
                 ) {
+                    let hp_damage = hp_damage * damage_percent / 100;
+
                     // TODO: Split this system with health check system.
-                    let Interaction {
-                        kind:
-                            InteractionKind::Hit(Hit {
-                                hp_damage,
-                                stun,
-                                acceleration,
-                                ..
-                            }),
-                        ..
-                    } = ev.interaction;
                     if health_points.0 < hp_damage {
                         *health_points = HealthPoints(0);
                     } else {
@@ -122,12 +151,12 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     *stun_points += stun;
 
                     if mirrored {
-                        velocity.x -= (*acceleration).x as f32;
+                        velocity.x -= FixedPoint::from((*acceleration).x);
                     } else {
-                        velocity.x += (*acceleration).x as f32;
+                        velocity.x += FixedPoint::from((*acceleration).x);
                     }
-                    velocity.y += (*acceleration).y as f32;
-                    velocity.z += (*acceleration).z as f32;
+                    velocity.y += FixedPoint::from((*acceleration).y);
+                    velocity.z += FixedPoint::from((*acceleration).z);
 
                     let next_sequence_id = if *health_points == 0 {
                         character_hit_transitions.falling
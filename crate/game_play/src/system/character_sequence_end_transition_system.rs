@@ -0,0 +1,100 @@
+use amethyst::{
+    ecs::{Entities, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use character_model::{loaded::CharacterEndTransitions, play::CharacterIrrSystemData};
+use derivative::Derivative;
+use derive_new::new;
+use input_reaction_model::config::InputReactionRequirement;
+use sequence_model::{loaded::SequenceId, play::SequenceUpdateEvent};
+
+/// Transitions characters to the first conditional end transition whose requirement is met.
+///
+/// This overrides the `SequenceEndTransition` and `CharacterSequenceUpdateSystem` transitions, as
+/// the conditional transitions are explicitly configured for the sequence.
+#[derive(Debug, Default, new)]
+pub struct CharacterSequenceEndTransitionSystem {
+    /// Reader ID for the `SequenceUpdateEvent` event channel.
+    #[new(default)]
+    sequence_update_event_rid: Option<ReaderId<SequenceUpdateEvent>>,
+}
+
+/// `CharacterSequenceEndTransitionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterSequenceEndTransitionSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// Event channel for `SequenceUpdateEvent`s.
+    #[derivative(Debug = "ignore")]
+    pub sequence_update_ec: Read<'s, EventChannel<SequenceUpdateEvent>>,
+    /// `CharacterEndTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_end_transitionses: ReadStorage<'s, CharacterEndTransitions>,
+    /// `CharacterIrrSystemData`.
+    pub character_irr_system_data: CharacterIrrSystemData<'s>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for CharacterSequenceEndTransitionSystem {
+    type SystemData = CharacterSequenceEndTransitionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CharacterSequenceEndTransitionSystemData {
+            entities,
+            sequence_update_ec,
+            character_end_transitionses,
+            mut character_irr_system_data,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        sequence_update_ec
+            .read(self.sequence_update_event_rid.as_mut().expect(
+                "Expected `sequence_update_event_rid` to exist for \
+                 `CharacterSequenceEndTransitionSystem`.",
+            ))
+            .filter_map(|ev| {
+                if let SequenceUpdateEvent::SequenceEnd { entity, .. } = ev {
+                    Some(*entity)
+                } else {
+                    None
+                }
+            })
+            // The entity may have been deleted by its `SequenceEndTransition`.
+            .filter(|entity| entities.is_alive(*entity))
+            .for_each(|entity| {
+                let sequence_id = character_end_transitionses
+                    .get(entity)
+                    .and_then(|character_end_transitions| {
+                        character_end_transitions
+                            .iter()
+                            .find(|character_end_transition| {
+                                character_end_transition
+                                    .requirement
+                                    .requirement_met(&mut character_irr_system_data, entity)
+                            })
+                    })
+                    .map(|character_end_transition| character_end_transition.sequence_id);
+
+                if let Some(sequence_id) = sequence_id {
+                    sequence_ids
+                        .insert(entity, sequence_id)
+                        .expect("Failed to insert `SequenceId` component.");
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.sequence_update_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<SequenceUpdateEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -10,7 +10,7 @@ use character_play::{
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::ControllerInput;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 use mirrored_model::play::Mirrored;
 use object_model::play::{Grounding, HealthPoints};
 use sequence_model::{
@@ -40,12 +40,12 @@ pub struct CharacterSequenceUpdateSystemData<'s> {
     /// `ControllerInput` components.
     #[derivative(Debug = "ignore")]
     pub controller_inputs: ReadStorage<'s, ControllerInput>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
-    /// `Velocity<f32>` components.
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: ReadStorage<'s, Velocity<f32>>,
+    pub velocities: ReadStorage<'s, Velocity<FixedPoint>>,
     /// `HealthPoints` components.
     #[derivative(Debug = "ignore")]
     pub health_pointses: ReadStorage<'s, HealthPoints>,
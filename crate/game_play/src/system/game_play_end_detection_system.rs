@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use amethyst::{
-    ecs::{Join, ReadStorage, System, World, Write},
+    ecs::{Join, Read, ReadStorage, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::EventChannel,
 };
@@ -10,6 +10,7 @@ use derive_new::new;
 use game_play_model::{GamePlayEvent, GamePlayStatus};
 use game_stats_model::play::{WinOutcome, WinStatus};
 use object_model::play::HealthPoints;
+use survival_model::play::{SurvivalProgress, SurvivalStatus, SurvivalTeam};
 use team_model::play::Team;
 
 /// Detects the end of a game play round, and fires a `GamePlayEvent::End`.
@@ -41,6 +42,12 @@ pub struct GamePlayEndDetectionSystemData<'s> {
     /// `WinStatus` resource.
     #[derivative(Debug = "ignore")]
     pub win_status: Write<'s, WinStatus>,
+    /// `SurvivalStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_status: Read<'s, SurvivalStatus>,
+    /// `SurvivalProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_progress: Read<'s, SurvivalProgress>,
 }
 
 impl GamePlayEndDetectionSystem {
@@ -50,9 +57,26 @@ impl GamePlayEndDetectionSystem {
         GamePlayEndDetectionSystemData {
             teams,
             health_pointses,
+            survival_status,
+            survival_progress,
             ..
         }: &mut GamePlayEndDetectionSystemData,
     ) -> Option<WinStatus> {
+        // Survival runs only end when all players are down, as enemies keep spawning.
+        if **survival_status == SurvivalStatus::Active {
+            let players_team = Team::Number(SurvivalTeam::PLAYERS);
+            let players_alive = (&*teams, &*health_pointses)
+                .join()
+                .any(|(team, health_points)| *team == players_team && *health_points > 0);
+            return if players_alive {
+                None
+            } else {
+                let waves_survived = survival_progress.waves_survived;
+                let win_outcome = WinOutcome::Survival { waves_survived };
+                Some(WinStatus::new(win_outcome))
+            };
+        }
+
         let team_alive_count = self.team_alive_count(&teams, &health_pointses);
         if team_alive_count == 0 {
             let win_outcome = WinOutcome::Draw;
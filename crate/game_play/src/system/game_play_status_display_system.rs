@@ -128,6 +128,9 @@ impl GamePlayStatusDisplaySystem {
                 format!("Winner: {}", winner)
             }
             WinOutcome::Draw => String::from("Draw"),
+            WinOutcome::Survival { waves_survived } => {
+                format!("Waves survived: {}", waves_survived)
+            }
         }
     }
 }
@@ -4,10 +4,10 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Velocity;
+use kinematic_model::config::{FixedPoint, Velocity};
 use object_model::play::Grounding;
 
-/// Updates `Velocity<f32>` based on grounding.
+/// Updates `Velocity<FixedPoint>` based on grounding.
 #[derive(Debug, Default, new)]
 pub struct GroundingFrictionSystem;
 
@@ -18,9 +18,9 @@ pub struct GroundingFrictionSystemData<'s> {
     /// `Grounding` components.
     #[derivative(Debug = "ignore")]
     pub groundings: ReadStorage<'s, Grounding>,
-    /// `Velocity<f32>` components.
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
 }
 
 impl<'s> System<'s> for GroundingFrictionSystem {
@@ -37,18 +37,18 @@ impl<'s> System<'s> for GroundingFrictionSystem {
             .join()
             .for_each(|(grounding, velocity)| match grounding {
                 Grounding::OnGround => {
-                    if velocity[0].abs() < 11. {
-                        velocity[0] = 0.;
+                    if velocity[0].abs() < FixedPoint::from(11) {
+                        velocity[0] = FixedPoint::ZERO;
                     } else {
-                        velocity[0] /= 2.;
+                        velocity[0] /= FixedPoint::from(2);
                     }
 
-                    velocity[1] = 0.;
+                    velocity[1] = FixedPoint::ZERO;
 
-                    if velocity[2].abs() < 7. {
-                        velocity[2] = 0.;
+                    if velocity[2].abs() < FixedPoint::from(7) {
+                        velocity[2] = FixedPoint::ZERO;
                     } else {
-                        velocity[2] /= 2.;
+                        velocity[2] /= FixedPoint::from(2);
                     }
                 }
                 Grounding::Airborne | Grounding::Underground => {}
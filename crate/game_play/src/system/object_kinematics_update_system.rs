@@ -4,7 +4,7 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 use sequence_model::play::FrameFreezeClock;
 
 /// Updates each entity's `Position` based on their `Velocity` in game.
@@ -20,12 +20,12 @@ pub struct ObjectKinematicsUpdateSystemData<'s> {
     /// `FrameFreezeClock` components.
     #[derivative(Debug = "ignore")]
     pub frame_freeze_clocks: ReadStorage<'s, FrameFreezeClock>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
-    /// `Velocity<f32>` components.
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: ReadStorage<'s, Velocity<f32>>,
+    pub velocities: ReadStorage<'s, Velocity<FixedPoint>>,
 }
 
 impl<'s> System<'s> for ObjectKinematicsUpdateSystem {
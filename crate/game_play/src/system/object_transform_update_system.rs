@@ -7,11 +7,18 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::{config::Position, play::PositionZAsY};
+use kinematic_model::{
+    config::{FixedPoint, Position},
+    play::PositionZAsY,
+};
 use mirrored_model::play::Mirrored;
+use sprite_model::play::SpriteOffsetTranslation;
 
 /// Updates each entity's `Transform` based on their `Position` in game.
 ///
+/// This is the only place game play `Position`s are converted to floating point values, as they
+/// are only needed for rendering.
+///
 /// This system should be run after all other systems that affect kinematics have run.
 #[derive(Debug, Default, new)]
 pub struct ObjectTransformUpdateSystem;
@@ -20,9 +27,9 @@ pub struct ObjectTransformUpdateSystem;
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct ObjectTransformUpdateSystemData<'s> {
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: ReadStorage<'s, Mirrored>,
@@ -32,6 +39,9 @@ pub struct ObjectTransformUpdateSystemData<'s> {
     /// `PositionZAsY` components.
     #[derivative(Debug = "ignore")]
     pub position_z_as_ys: ReadStorage<'s, PositionZAsY>,
+    /// `SpriteOffsetTranslation` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_translations: ReadStorage<'s, SpriteOffsetTranslation>,
     /// `SpriteSheet` assets.
     #[derivative(Debug = "ignore")]
     pub sprite_sheet_assets: Read<'s, AssetStorage<SpriteSheet>>,
@@ -50,19 +60,40 @@ impl<'s> System<'s> for ObjectTransformUpdateSystem {
             mirroreds,
             sprite_renders,
             position_z_as_ys,
+            sprite_offset_translations,
             sprite_sheet_assets,
             mut transforms,
         }: Self::SystemData,
     ) {
-        for (position, mirrored, sprite_render, position_z_as_y, transform) in (
+        for (
+            position,
+            mirrored,
+            sprite_render,
+            position_z_as_y,
+            sprite_offset_translation,
+            transform,
+        ) in (
             &positions,
             mirroreds.maybe(),
             sprite_renders.maybe(),
             position_z_as_ys.maybe(),
+            sprite_offset_translations.maybe(),
             &mut transforms,
         )
             .join()
         {
+            let mut translation = position.to_f32();
+
+            // Sprite offsets shift the sprite in the direction the entity is facing.
+            if let Some(sprite_offset_translation) = sprite_offset_translation {
+                if mirrored.map(|mirrored| mirrored.0).unwrap_or(false) {
+                    translation.x -= sprite_offset_translation.x;
+                } else {
+                    translation.x += sprite_offset_translation.x;
+                }
+                translation.y += sprite_offset_translation.y;
+            }
+
             // Hack: Visual correction when sprites are mirrored.
             if let (Some(mirrored), Some(sprite_render)) = (mirrored, sprite_render) {
                 if mirrored.0 {
@@ -70,22 +101,22 @@ impl<'s> System<'s> for ObjectTransformUpdateSystem {
                         .get(&sprite_render.sprite_sheet)
                         .expect("Expected sprite sheet to be loaded.");
                     let sprite = &sprite_sheet.sprites[sprite_render.sprite_number];
-                    transform.set_translation_x(position.x + sprite.offsets[0]);
+                    transform.set_translation_x(translation.x + sprite.offsets[0]);
                 } else {
-                    transform.set_translation_x(position.x);
+                    transform.set_translation_x(translation.x);
                 }
             } else {
-                transform.set_translation_x(position.x);
+                transform.set_translation_x(translation.x);
             }
 
             if position_z_as_y.is_some() {
                 // We subtract z from the y translation as the z axis increases "out of the screen".
                 // Entities that have a larger Z value are transformed downwards.
-                transform.set_translation_y(position.y - position.z);
+                transform.set_translation_y(translation.y - translation.z);
             } else {
-                transform.set_translation_y(position.y);
+                transform.set_translation_y(translation.y);
             }
-            transform.set_translation_z(position.z);
+            transform.set_translation_z(translation.z);
         }
     }
 }
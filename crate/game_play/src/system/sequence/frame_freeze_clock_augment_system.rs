@@ -11,9 +11,14 @@ use derivative::Derivative;
 use derive_new::new;
 use sequence_model::play::FrameFreezeClock;
 
+/// Number of ticks an object is frozen for when it hits something.
+const HIT_FREEZE_TICKS: usize = 3;
+
 /// Creates `FrameFreezeClock`s for new `Hit` collisions.
 ///
-/// This attaches `FrameFreezeClock` to the entity with the `Interaction`.
+/// This attaches `FrameFreezeClock` to the entity with the `Interaction`. An existing
+/// `FrameFreezeClock` is kept if it freezes the entity for longer, such as from a `Freeze` status
+/// effect.
 #[derive(Debug, Default, new)]
 pub struct FrameFreezeClockAugmentSystem {
     /// Reader ID for the `HitEvent` event channel.
@@ -51,15 +56,22 @@ impl<'s> System<'s> for FrameFreezeClockAugmentSystem {
             )
             .for_each(|ev| {
                 // Only add `FrameFreezeClock` for `Hit` interactions.
-                let Interaction {
+                if let Interaction {
                     kind: InteractionKind::Hit(_),
                     ..
-                } = ev.interaction;
-
-                let frame_freeze_clock = FrameFreezeClock::new(3);
-                frame_freeze_clocks
-                    .insert(ev.from, frame_freeze_clock)
-                    .expect("Failed to insert `FrameFreezeClock`.");
+                } = ev.interaction
+                {
+                    let is_longer_freeze = frame_freeze_clocks
+                        .get(ev.from)
+                        .map(|frame_freeze_clock| frame_freeze_clock.remaining() < HIT_FREEZE_TICKS)
+                        .unwrap_or(true);
+                    if is_longer_freeze {
+                        let frame_freeze_clock = FrameFreezeClock::new(HIT_FREEZE_TICKS);
+                        frame_freeze_clocks
+                            .insert(ev.from, frame_freeze_clock)
+                            .expect("Failed to insert `FrameFreezeClock`.");
+                    }
+                }
             });
     }
 
@@ -0,0 +1,212 @@
+use amethyst::{
+    assets::AssetStorage,
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+};
+use charge_model::play::ChargeTintPulse;
+use charge_play::ChargeTintPulseSystem;
+use derivative::Derivative;
+use derive_new::new;
+use frame_event_model::{
+    loaded::{FrameEvent, FrameEvents},
+    play::HitTint,
+};
+use frame_event_play::HitTintSystem;
+use object_status_model::{config::StatusEffectKind, play::StatusEffects};
+use sequence_model::play::{FrameIndexClock, FrameWaitClock};
+use sprite_model::{
+    config::Interpolation,
+    loaded::{TintSequence, TintSequenceHandle},
+};
+use sprite_play::SpriteInterpolationSystem;
+
+/// Tint when the current frame has none.
+const COLOUR_DEFAULT: [f32; 4] = [1., 1., 1., 1.];
+
+/// Resolves each object's `Tint` from its frame and the effects layered over it.
+///
+/// The layers are applied in the following order, each over the result of the previous layer:
+///
+/// 1. The current frame's `SelfTint` frame event, otherwise the frame's `Tint` blended towards the
+///    next frame's `Tint`.
+/// 2. The `ChargeTintPulse`, which fades back to the frame's `Tint`.
+/// 3. The `HitTint` from a `HitTint` frame event of the object that hit it, which also fades back.
+/// 4. The colour of the most recently applied status effect.
+///
+/// The `Tint` is recomputed every tick, so each layer is restored when its effect ends.
+#[derive(Debug, Default, new)]
+pub struct TintResolutionSystem;
+
+/// `TintResolutionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TintResolutionSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: ReadStorage<'s, FrameIndexClock>,
+    /// `FrameWaitClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_wait_clocks: ReadStorage<'s, FrameWaitClock>,
+    /// `Interpolation` components.
+    #[derivative(Debug = "ignore")]
+    pub interpolations: ReadStorage<'s, Interpolation>,
+    /// `TintSequenceHandle` components.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_handles: ReadStorage<'s, TintSequenceHandle>,
+    /// `TintSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_assets: Read<'s, AssetStorage<TintSequence>>,
+    /// `FrameEvents` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_eventses: ReadStorage<'s, FrameEvents>,
+    /// `ChargeTintPulse` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tint_pulses: ReadStorage<'s, ChargeTintPulse>,
+    /// `HitTint` components.
+    #[derivative(Debug = "ignore")]
+    pub hit_tints: ReadStorage<'s, HitTint>,
+    /// `StatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effectses: ReadStorage<'s, StatusEffects>,
+    /// `Tint` components.
+    #[derivative(Debug = "ignore")]
+    pub tints: WriteStorage<'s, Tint>,
+}
+
+impl TintResolutionSystem {
+    /// Returns the `Tint` multiplied by the status effect's colour.
+    pub fn status_effect_tint(Tint(tint): Tint, kind: StatusEffectKind) -> Tint {
+        let [red, green, blue, alpha] = kind.colour();
+        Tint(Srgba::new(
+            tint.red * red,
+            tint.green * green,
+            tint.blue * blue,
+            tint.alpha * alpha,
+        ))
+    }
+
+    /// Returns the `SelfTint` in the entity's current frame events, if any.
+    fn self_tint(frame_events: Option<&FrameEvents>) -> Option<Tint> {
+        frame_events.and_then(|frame_events| {
+            frame_events
+                .iter()
+                .filter_map(|frame_event| match frame_event {
+                    FrameEvent::SelfTint(tint) => Some(*tint),
+                    _ => None,
+                })
+                .last()
+        })
+    }
+
+    /// Returns the `Tint` of the entity's current frame, blended towards the next frame's `Tint`.
+    fn frame_tint(
+        TintResolutionSystemData {
+            frame_index_clocks,
+            frame_wait_clocks,
+            interpolations,
+            tint_sequence_handles,
+            tint_sequence_assets,
+            ..
+        }: &TintResolutionSystemData<'_>,
+        entity: Entity,
+    ) -> Option<Tint> {
+        let frame_index = frame_index_clocks
+            .get(entity)
+            .map(|frame_index_clock| frame_index_clock.value)
+            .unwrap_or(0);
+        let tint_values = tint_sequence_handles
+            .get(entity)
+            .and_then(|tint_sequence_handle| tint_sequence_assets.get(tint_sequence_handle))
+            .and_then(|tint_sequence| {
+                SpriteInterpolationSystem::current_and_next(tint_sequence, frame_index)
+            });
+
+        tint_values.map(|(current, next)| {
+            let factor = frame_wait_clocks
+                .get(entity)
+                .map(|frame_wait_clock| {
+                    let interpolation = interpolations.get(entity).copied().unwrap_or_default();
+                    SpriteInterpolationSystem::blend_factor(*frame_wait_clock, interpolation)
+                })
+                .unwrap_or(0.);
+            SpriteInterpolationSystem::blend_tint(current, next, factor)
+        })
+    }
+}
+
+impl<'s> System<'s> for TintResolutionSystem {
+    type SystemData = TintResolutionSystemData<'s>;
+
+    fn run(&mut self, mut tint_resolution_system_data: Self::SystemData) {
+        let entity_tints = (
+            &tint_resolution_system_data.entities,
+            tint_resolution_system_data.tint_sequence_handles.maybe(),
+            tint_resolution_system_data.frame_eventses.maybe(),
+            tint_resolution_system_data.charge_tint_pulses.maybe(),
+            tint_resolution_system_data.hit_tints.maybe(),
+            tint_resolution_system_data.status_effectses.maybe(),
+        )
+            .join()
+            .filter_map(
+                |(
+                    entity,
+                    tint_sequence_handle,
+                    frame_events,
+                    charge_tint_pulse,
+                    hit_tint,
+                    status_effects,
+                )| {
+                    let self_tint = Self::self_tint(frame_events);
+
+                    // Leave the `Tint` of entities that are not tinted by any layer untouched.
+                    if tint_sequence_handle.is_none()
+                        && self_tint.is_none()
+                        && charge_tint_pulse.is_none()
+                        && hit_tint.is_none()
+                        && status_effects.is_none()
+                    {
+                        return None;
+                    }
+
+                    let tint = self_tint
+                        .or_else(|| Self::frame_tint(&tint_resolution_system_data, entity))
+                        .unwrap_or_else(|| {
+                            let [red, green, blue, alpha] = COLOUR_DEFAULT;
+                            Tint(Srgba::new(red, green, blue, alpha))
+                        });
+                    let tint = if let Some(charge_tint_pulse) = charge_tint_pulse {
+                        ChargeTintPulseSystem::pulse_blend(tint, *charge_tint_pulse)
+                    } else {
+                        tint
+                    };
+                    let tint = if let Some(hit_tint) = hit_tint {
+                        HitTintSystem::tint_blend(tint, *hit_tint)
+                    } else {
+                        tint
+                    };
+                    let kind = status_effects
+                        .and_then(|status_effects| status_effects.last())
+                        .map(|entry| entry.kind);
+                    let tint = if let Some(kind) = kind {
+                        Self::status_effect_tint(tint, kind)
+                    } else {
+                        tint
+                    };
+
+                    Some((entity, tint))
+                },
+            )
+            .collect::<Vec<(Entity, Tint)>>();
+
+        entity_tints.into_iter().for_each(|(entity, tint)| {
+            tint_resolution_system_data
+                .tints
+                .insert(entity, tint)
+                .expect("Failed to insert `Tint` component.");
+        });
+    } // kcov-ignore
+}
@@ -11,3 +11,12 @@ pub const CP_BAR_LENGTH: f32 = 100.;
 pub const CP_BAR_HEIGHT: f32 = 3.;
 /// Number of sprites on the `CpBar` sprite sheet.
 pub const CP_BAR_SPRITE_COUNT: usize = 10;
+/// Number of ticks a `CpBar` flashes for when a higher `ChargeLevel` is reached.
+pub const CP_BAR_FLASH_DURATION: u32 = 16;
+/// Number of ticks the `CpBar` segment is hidden or shown for while flashing.
+pub const CP_BAR_FLASH_INTERVAL: u32 = 2;
+
+/// Width and height to render each `StatusIcon`.
+pub const STATUS_ICON_SIZE: f32 = 4.;
+/// Horizontal distance between consecutive `StatusIcon`s.
+pub const STATUS_ICON_SPACING: f32 = 6.;
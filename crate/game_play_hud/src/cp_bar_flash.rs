@@ -0,0 +1,19 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Flashes the segment of a `CpBar` for the `ChargeLevel` its parent entity reached.
+///
+/// This is attached to a separate segment entity drawn over the `CpBar`, so that the `CpBar`'s own
+/// visibility is left untouched.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct CpBarFlash {
+    /// The `CpBar` entity whose segment is flashed.
+    pub cp_bar_entity: Entity,
+    /// Start of the segment, as a proportion of the `CpBar` length.
+    pub segment_start: f32,
+    /// End of the segment, as a proportion of the `CpBar` length.
+    pub segment_end: f32,
+    /// Number of ticks the flash has run for.
+    #[new(default)]
+    pub ticks: u32,
+}
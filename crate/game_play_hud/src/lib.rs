@@ -4,17 +4,22 @@
 
 pub use crate::{
     constants::{
-        CP_BAR_HEIGHT, CP_BAR_LENGTH, CP_BAR_SPRITE_COUNT, HP_BAR_HEIGHT, HP_BAR_LENGTH,
-        HP_BAR_SPRITE_COUNT,
+        CP_BAR_FLASH_DURATION, CP_BAR_FLASH_INTERVAL, CP_BAR_HEIGHT, CP_BAR_LENGTH,
+        CP_BAR_SPRITE_COUNT, HP_BAR_HEIGHT, HP_BAR_LENGTH, HP_BAR_SPRITE_COUNT, STATUS_ICON_SIZE,
+        STATUS_ICON_SPACING,
     },
     cp_bar::CpBar,
+    cp_bar_flash::CpBarFlash,
     hp_bar::HpBar,
-    prefab::{CpBarPrefab, HpBarPrefab},
-    system::{CpBarUpdateSystem, HpBarUpdateSystem},
+    prefab::{CpBarPrefab, HpBarPrefab, StatusIconPrefab},
+    status_icon::StatusIcon,
+    system::{CpBarFlashSystem, CpBarUpdateSystem, HpBarUpdateSystem, StatusIconUpdateSystem},
 };
 
 mod constants;
 mod cp_bar;
+mod cp_bar_flash;
 mod hp_bar;
 mod prefab;
+mod status_icon;
 mod system;
@@ -1,4 +1,7 @@
-pub use self::{cp_bar_prefab::CpBarPrefab, hp_bar_prefab::HpBarPrefab};
+pub use self::{
+    cp_bar_prefab::CpBarPrefab, hp_bar_prefab::HpBarPrefab, status_icon_prefab::StatusIconPrefab,
+};
 
 mod cp_bar_prefab;
 mod hp_bar_prefab;
+mod status_icon_prefab;
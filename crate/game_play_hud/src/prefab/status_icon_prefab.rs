@@ -0,0 +1,114 @@
+use amethyst::{
+    assets::PrefabData,
+    core::{math::Vector3, Transform},
+    ecs::{Entity, World, WriteStorage},
+    renderer::{transparent::Transparent, Hidden, SpriteRender},
+    shred::{ResourceId, SystemData},
+    Error,
+};
+use asset_gfx_gen::{ColourSpriteSheetGen, ColourSpriteSheetGenData};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use derivative::Derivative;
+use derive_new::new;
+use object_status_model::config::StatusEffectKind;
+use parent_model::play::ParentEntity;
+
+use crate::{StatusIcon, STATUS_ICON_SIZE};
+
+/// Prefab to attach all components of a status effect icon.
+///
+/// These include:
+///
+/// * `StatusIcon`: Kind of status effect the icon represents.
+/// * `Transform`: Coordinates of the icon to draw.
+/// * `Parent`: Link to the parent entity whose `StatusEffects` the `StatusIcon` entity will
+///    display.
+/// * `SpriteRender`: Square in the status effect's colour.
+/// * `Transparent`: Tags the `StatusIcon` for sorting when rendering.
+/// * `Hidden`: The icon is only shown while the parent entity has the status effect.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct StatusIconPrefab {
+    /// Entity whose `StatusEffects` to display.
+    pub game_object_entity: Entity,
+    /// Kind of status effect the icon represents.
+    pub kind: StatusEffectKind,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusIconPrefabSystemData<'s> {
+    /// `StatusIcon` components.
+    #[derivative(Debug = "ignore")]
+    pub status_icons: WriteStorage<'s, StatusIcon>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// System data needed to load colour sprites.
+    #[derivative(Debug = "ignore")]
+    pub colour_sprite_sheet_gen_data: ColourSpriteSheetGenData<'s>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: WriteStorage<'s, SpriteRender>,
+    /// `Transparent` components.
+    #[derivative(Debug = "ignore")]
+    pub transparents: WriteStorage<'s, Transparent>,
+    /// `Hidden` components.
+    #[derivative(Debug = "ignore")]
+    pub hiddens: WriteStorage<'s, Hidden>,
+}
+
+impl<'s> PrefabData<'s> for StatusIconPrefab {
+    type SystemData = StatusIconPrefabSystemData<'s>;
+    type Result = ();
+
+    fn add_to_entity(
+        &self,
+        entity: Entity,
+        StatusIconPrefabSystemData {
+            status_icons,
+            transforms,
+            parent_entities,
+            target_objects,
+            chase_mode_sticks,
+            colour_sprite_sheet_gen_data,
+            sprite_renders,
+            transparents,
+            hiddens,
+        }: &mut Self::SystemData,
+        _entities: &[Entity],
+        _children: &[Entity],
+    ) -> Result<(), Error> {
+        let parent_translation = transforms
+            .get(self.game_object_entity)
+            .map(Transform::translation)
+            .copied();
+
+        status_icons.insert(entity, StatusIcon::new(self.kind))?;
+        let mut transform = Transform::default();
+        if let Some(translation) = parent_translation {
+            *transform.translation_mut() = translation;
+        }
+        transform.set_scale(Vector3::new(STATUS_ICON_SIZE, STATUS_ICON_SIZE, 1.));
+        transforms.insert(entity, transform)?;
+        parent_entities.insert(entity, ParentEntity::new(self.game_object_entity))?;
+        target_objects.insert(entity, TargetObject::new(self.game_object_entity))?;
+        chase_mode_sticks.insert(entity, Default::default())?;
+
+        let sprite_render =
+            ColourSpriteSheetGen::solid(colour_sprite_sheet_gen_data, self.kind.colour());
+        sprite_renders.insert(entity, sprite_render)?;
+        transparents.insert(entity, Transparent)?;
+        hiddens.insert(entity, Hidden)?;
+
+        Ok(())
+    }
+}
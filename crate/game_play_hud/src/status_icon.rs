@@ -0,0 +1,10 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use object_status_model::config::StatusEffectKind;
+
+/// Icon that is shown while its parent entity has a status effect.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct StatusIcon {
+    /// Kind of status effect that this icon represents.
+    pub kind: StatusEffectKind,
+}
@@ -1,4 +1,9 @@
-pub use self::{cp_bar_update_system::CpBarUpdateSystem, hp_bar_update_system::HpBarUpdateSystem};
+pub use self::{
+    cp_bar_flash_system::CpBarFlashSystem, cp_bar_update_system::CpBarUpdateSystem,
+    hp_bar_update_system::HpBarUpdateSystem, status_icon_update_system::StatusIconUpdateSystem,
+};
 
+mod cp_bar_flash_system;
 mod cp_bar_update_system;
 mod hp_bar_update_system;
+mod status_icon_update_system;
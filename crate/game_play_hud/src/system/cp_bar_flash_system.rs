@@ -0,0 +1,284 @@
+use amethyst::{
+    assets::AssetStorage,
+    audio::{output::Output, Source},
+    core::{math::Vector3, Transform},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{transparent::Transparent, Hidden, SpriteRender},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use character_model::loaded::CharacterChargeLevelSfx;
+use charge_model::{
+    config::{ChargeLevel, ChargeLevels, ChargePoints},
+    play::{ChargeLevelEvent, ChargeTrackerClock},
+};
+use derivative::Derivative;
+use derive_new::new;
+use parent_model::play::ParentEntity;
+use ui_audio_model::{config::UiSfxId, loaded::UiSfxMap};
+
+use crate::{
+    CpBar, CpBarFlash, CP_BAR_FLASH_DURATION, CP_BAR_FLASH_INTERVAL, CP_BAR_HEIGHT, CP_BAR_LENGTH,
+    CP_BAR_SPRITE_COUNT,
+};
+
+/// Default volume to play sounds at.
+const VOLUME: f32 = 1.0;
+/// Move the flashing segment in front of the `CpBar`.
+const Z_OFFSET: f32 = 0.1;
+
+/// Flashes the `CpBar` segment and plays a sound when its parent entity reaches a higher
+/// `ChargeLevel`.
+///
+/// The sound is the character's `CharacterChargeLevelSfx`, falling back to the UI `ChargeLevel`
+/// sound.
+#[derive(Debug, Default, new)]
+pub struct CpBarFlashSystem {
+    /// Reader ID for the `ChargeLevelEvent` channel.
+    #[new(default)]
+    charge_level_event_rid: Option<ReaderId<ChargeLevelEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CpBarFlashSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChargeLevelEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub charge_level_ec: Read<'s, EventChannel<ChargeLevelEvent>>,
+    /// `CpBar` components.
+    #[derivative(Debug = "ignore")]
+    pub cp_bars: ReadStorage<'s, CpBar>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: ReadStorage<'s, ParentEntity>,
+    /// `ChargeLevels` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_levelses: ReadStorage<'s, ChargeLevels>,
+    /// `ChargeTrackerClock` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tracker_clocks: ReadStorage<'s, ChargeTrackerClock>,
+    /// `CharacterChargeLevelSfx` components.
+    #[derivative(Debug = "ignore")]
+    pub character_charge_level_sfxs: ReadStorage<'s, CharacterChargeLevelSfx>,
+    /// `CpBarFlash` components.
+    #[derivative(Debug = "ignore")]
+    pub cp_bar_flashes: WriteStorage<'s, CpBarFlash>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: WriteStorage<'s, SpriteRender>,
+    /// `Transparent` components.
+    #[derivative(Debug = "ignore")]
+    pub transparents: WriteStorage<'s, Transparent>,
+    /// `Hidden` components.
+    #[derivative(Debug = "ignore")]
+    pub hiddens: WriteStorage<'s, Hidden>,
+    /// `UiSfxMap` resource.
+    #[derivative(Debug = "ignore")]
+    pub ui_sfx_map: Read<'s, UiSfxMap>,
+    /// `Source` assets.
+    #[derivative(Debug = "ignore")]
+    pub source_assets: Read<'s, AssetStorage<Source>>,
+    /// `Output` resource.
+    #[derivative(Debug = "ignore")]
+    pub output: Option<Read<'s, Output>>,
+}
+
+impl CpBarFlashSystem {
+    /// Returns the start and end of the segment for a `ChargeLevel`, as proportions of the `CpBar`
+    /// length.
+    ///
+    /// The segment begins at the highest threshold of a lower tier, or at the start of the bar if
+    /// no lower tier has a threshold.
+    fn segment(
+        charge_levels: ChargeLevels,
+        charge_level: ChargeLevel,
+        limit: usize,
+    ) -> Option<(f32, f32)> {
+        if limit == 0 {
+            return None;
+        }
+
+        let thresholds = [
+            (ChargeLevel::Level1, charge_levels.level_1),
+            (ChargeLevel::Level2, charge_levels.level_2),
+            (ChargeLevel::Level3, charge_levels.level_3),
+        ];
+        let threshold_end = thresholds
+            .iter()
+            .find(|(level, _)| *level == charge_level)
+            .and_then(|(_, threshold)| *threshold)?;
+        let threshold_start = thresholds
+            .iter()
+            .filter(|(level, _)| *level < charge_level)
+            .filter_map(|(_, threshold)| *threshold)
+            .max()
+            .unwrap_or_default();
+
+        let proportion = |threshold: ChargePoints| (*threshold as f32 / limit as f32).min(1.);
+        Some((proportion(threshold_start), proportion(threshold_end)))
+    }
+
+    /// Returns the `Transform` of the segment, positioned over the `CpBar`.
+    fn segment_transform(cp_bar_transform: &Transform, cp_bar_flash: CpBarFlash) -> Transform {
+        // The `CpBar` is drawn centered and scaled to the current charge, so its left edge is
+        // half its current length to the left of its translation.
+        let cp_bar_translation = cp_bar_transform.translation();
+        let cp_bar_left = cp_bar_translation.x - cp_bar_transform.scale()[0] / 2.;
+        let segment_length =
+            (cp_bar_flash.segment_end - cp_bar_flash.segment_start) * CP_BAR_LENGTH;
+
+        let mut transform = Transform::default();
+        let translation = transform.translation_mut();
+        translation.x =
+            cp_bar_left + cp_bar_flash.segment_start * CP_BAR_LENGTH + segment_length / 2.;
+        translation.y = cp_bar_translation.y;
+        translation.z = cp_bar_translation.z + Z_OFFSET;
+        transform.set_scale(Vector3::new(segment_length, CP_BAR_HEIGHT, 1.));
+
+        transform
+    }
+}
+
+impl<'s> System<'s> for CpBarFlashSystem {
+    type SystemData = CpBarFlashSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CpBarFlashSystemData {
+            entities,
+            charge_level_ec,
+            cp_bars,
+            parent_entities,
+            charge_levelses,
+            charge_tracker_clocks,
+            character_charge_level_sfxs,
+            mut cp_bar_flashes,
+            mut transforms,
+            mut sprite_renders,
+            mut transparents,
+            mut hiddens,
+            ui_sfx_map,
+            source_assets,
+            output,
+        }: Self::SystemData,
+    ) {
+        let charge_level_event_rid = self
+            .charge_level_event_rid
+            .as_mut()
+            .expect("Expected `charge_level_event_rid` field to be set.");
+
+        charge_level_ec.read(charge_level_event_rid).for_each(|ev| {
+            let segment = charge_levelses.get(ev.entity).and_then(|charge_levels| {
+                charge_tracker_clocks
+                    .get(ev.entity)
+                    .and_then(|charge_tracker_clock| {
+                        Self::segment(*charge_levels, ev.charge_level, charge_tracker_clock.limit)
+                    })
+            });
+
+            if let Some((segment_start, segment_end)) = segment {
+                let cp_bar_entities = (&entities, &cp_bars, &parent_entities)
+                    .join()
+                    .filter(|(_, _, parent_entity)| parent_entity.0 == ev.entity)
+                    .map(|(entity, _, _)| entity)
+                    .collect::<Vec<Entity>>();
+
+                cp_bar_entities.into_iter().for_each(|cp_bar_entity| {
+                    let segment_entity = entities.create();
+                    cp_bar_flashes
+                        .insert(
+                            segment_entity,
+                            CpBarFlash::new(cp_bar_entity, segment_start, segment_end),
+                        )
+                        .expect("Failed to insert `CpBarFlash` component.");
+                    transforms
+                        .insert(segment_entity, Transform::default())
+                        .expect("Failed to insert `Transform` component.");
+                    transparents
+                        .insert(segment_entity, Transparent)
+                        .expect("Failed to insert `Transparent` component.");
+                    hiddens
+                        .insert(segment_entity, Hidden)
+                        .expect("Failed to insert `Hidden` component.");
+
+                    // Draw the segment in the brightest colour of the `CpBar`.
+                    if let Some(sprite_render) = sprite_renders.get(cp_bar_entity).cloned() {
+                        let sprite_render = SpriteRender {
+                            sprite_number: CP_BAR_SPRITE_COUNT - 1,
+                            ..sprite_render
+                        };
+                        sprite_renders
+                            .insert(segment_entity, sprite_render)
+                            .expect("Failed to insert `SpriteRender` component.");
+                    }
+                });
+            }
+
+            if let Some(output) = output.as_ref() {
+                let charge_level_sfx = character_charge_level_sfxs
+                    .get(ev.entity)
+                    .map(|character_charge_level_sfx| &character_charge_level_sfx.0)
+                    .or_else(|| ui_sfx_map.get(&UiSfxId::ChargeLevel))
+                    .and_then(|charge_level_sfx_handle| source_assets.get(charge_level_sfx_handle));
+
+                if let Some(charge_level_sfx) = charge_level_sfx {
+                    output.play_once(charge_level_sfx, VOLUME);
+                }
+            }
+        });
+
+        let flashes_complete = (&entities, &mut cp_bar_flashes)
+            .join()
+            .filter_map(|(entity, cp_bar_flash)| {
+                cp_bar_flash.ticks += 1;
+
+                // The segment is removed when the flash is complete, or the `CpBar` is gone.
+                let cp_bar_transform = match transforms.get(cp_bar_flash.cp_bar_entity) {
+                    Some(cp_bar_transform) if cp_bar_flash.ticks < CP_BAR_FLASH_DURATION => {
+                        cp_bar_transform.clone()
+                    }
+                    _ => return Some(entity),
+                };
+                let segment_transform = Self::segment_transform(&cp_bar_transform, *cp_bar_flash);
+                transforms
+                    .insert(entity, segment_transform)
+                    .expect("Failed to insert `Transform` component.");
+
+                // The segment is never shown while the `CpBar` itself is hidden.
+                let hidden = hiddens.contains(cp_bar_flash.cp_bar_entity)
+                    || (cp_bar_flash.ticks / CP_BAR_FLASH_INTERVAL) % 2 == 0;
+                if hidden {
+                    hiddens
+                        .insert(entity, Hidden)
+                        .expect("Failed to insert `Hidden` component.");
+                } else {
+                    hiddens.remove(entity);
+                }
+
+                None
+            })
+            .collect::<Vec<Entity>>();
+
+        flashes_complete.into_iter().for_each(|entity| {
+            entities
+                .delete(entity)
+                .expect("Failed to delete `CpBarFlash` entity.");
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.charge_level_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ChargeLevelEvent>>()
+                .register_reader(),
+        );
+    }
+}
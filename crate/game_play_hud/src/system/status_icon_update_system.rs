@@ -0,0 +1,91 @@
+use amethyst::{
+    core::Transform,
+    ecs::{Entities, Join, ReadStorage, System, World, WriteStorage},
+    renderer::Hidden,
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use object_status_model::play::StatusEffects;
+use parent_model::play::ParentEntity;
+
+use crate::{StatusIcon, HP_BAR_LENGTH, STATUS_ICON_SIZE, STATUS_ICON_SPACING};
+
+/// Move StatusIcons below the CpBar.
+const Y_OFFSET: f32 = -20.;
+/// Move StatusIcons in front of object.
+const Z_OFFSET: f32 = 1.;
+
+/// Shows `StatusIcon`s for their parent entity's current `StatusEffects`.
+///
+/// Icons are laid out from the left edge of the `HpBar`, in the order the effects were applied.
+#[derive(Debug, Default, new)]
+pub struct StatusIconUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusIconUpdateSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `StatusIcon` components.
+    #[derivative(Debug = "ignore")]
+    pub status_icons: ReadStorage<'s, StatusIcon>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: ReadStorage<'s, ParentEntity>,
+    /// `StatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effectses: ReadStorage<'s, StatusEffects>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `Hidden` components.
+    #[derivative(Debug = "ignore")]
+    pub hiddens: WriteStorage<'s, Hidden>,
+}
+
+impl<'s> System<'s> for StatusIconUpdateSystem {
+    type SystemData = StatusIconUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusIconUpdateSystemData {
+            entities,
+            status_icons,
+            parent_entities,
+            status_effectses,
+            mut transforms,
+            mut hiddens,
+        }: Self::SystemData,
+    ) {
+        (&entities, &status_icons, &parent_entities, &mut transforms)
+            .join()
+            .for_each(|(entity, status_icon, parent_entity, transform)| {
+                let slot = status_effectses
+                    .get(parent_entity.0)
+                    .and_then(|status_effects| {
+                        status_effects
+                            .iter()
+                            .position(|entry| entry.kind == status_icon.kind)
+                    });
+
+                if let Some(slot) = slot {
+                    hiddens.remove(entity);
+
+                    // Sprites are drawn centered, so we shift the icon by half its width to align
+                    // it with the left edge of the `HpBar`.
+                    let translation = transform.translation_mut();
+                    translation.x += -HP_BAR_LENGTH / 2.
+                        + STATUS_ICON_SIZE / 2.
+                        + slot as f32 * STATUS_ICON_SPACING;
+                    translation.y += Y_OFFSET;
+                    translation.z += Z_OFFSET;
+                } else {
+                    hiddens
+                        .insert(entity, Hidden)
+                        .expect("Failed to insert `Hidden` component.");
+                }
+            });
+    }
+}
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -11,7 +12,7 @@ use structopt_derive::StructOpt;
 /// * `game_play resume`
 /// * `game_play end`
 /// * `game_play end_stats`
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[structopt(rename_all = "snake_case")]
 pub enum GamePlayEventArgs {
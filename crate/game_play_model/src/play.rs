@@ -2,8 +2,9 @@
 
 pub use self::{
     game_play_end_transition_delay_clock::GamePlayEndTransitionDelayClock,
-    game_play_status_entity::GamePlayStatusEntity,
+    game_play_status_entity::GamePlayStatusEntity, simulation_status::SimulationStatus,
 };
 
 mod game_play_end_transition_delay_clock;
 mod game_play_status_entity;
+mod simulation_status;
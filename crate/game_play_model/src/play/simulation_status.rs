@@ -0,0 +1,16 @@
+use derivative::Derivative;
+
+/// Whether game objects are being simulated.
+///
+/// Systems that update game objects only run while this is `Running`. Unlike `GamePlayStatus`,
+/// this defaults to `Running` outside of game play, so objects such as character selection
+/// previews are still updated.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum SimulationStatus {
+    /// Game objects are updated.
+    #[derivative(Default)]
+    Running,
+    /// Game objects are frozen, such as when the pause menu is open.
+    Paused,
+}
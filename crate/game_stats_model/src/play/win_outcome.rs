@@ -15,4 +15,9 @@ pub enum WinOutcome {
     },
     /// The round ended in a draw.
     Draw,
+    /// A survival run has ended.
+    Survival {
+        /// Number of waves survived, which is the score of the run.
+        waves_survived: u32,
+    },
 }
@@ -1,4 +1,5 @@
 use amethyst::ecs::Entity;
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -9,7 +10,9 @@ use crate::{
 };
 
 /// Character input reaction requirement.
-#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new,
+)]
 pub struct BasicIrr(pub Vec<BasicIrrPart>);
 
 impl<'s> InputReactionRequirement<'s> for BasicIrr {
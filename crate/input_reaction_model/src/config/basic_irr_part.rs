@@ -1,4 +1,5 @@
 use approx::{relative_eq, relative_ne};
+use asset_schema::AssetSchema;
 use game_input_model::{
     config::{InputDirection, InputDirectionZ},
     play::ControllerInput,
@@ -11,7 +12,7 @@ use crate::config::BasicIrrParams;
 /// `CharacterInputReactionRequirementPart`
 ///
 /// Conditions for a character input reaction to happen.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum BasicIrrPart {
     /// Whether or not there is x axis input, and if it matches the direction the object is facing.
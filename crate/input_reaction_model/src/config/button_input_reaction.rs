@@ -1,4 +1,5 @@
 use amethyst::input::Button;
+use asset_schema::{AssetSchema, ButtonSchema};
 use derive_new::new;
 use sequence_model::config::SequenceName;
 use serde::{Deserialize, Serialize};
@@ -9,7 +10,7 @@ use crate::config::InputReaction;
 ///
 /// This is for reacting to device (keyboard, mouse, etc.) buttons as opposed to `ControlButton`s.
 /// Typically used for UIs for configuring the application, rather than setting up a game.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(rename_all = "snake_case")]
 pub struct ButtonInputReaction<SeqName, IRR>
 where
@@ -17,6 +18,7 @@ where
     IRR: Default,
 {
     /// Device button that was pressed.
+    #[asset_schema(with = "ButtonSchema")]
     pub button: Button,
     /// Variants of how an `InputReaction` may be specified.
     #[serde(flatten)]
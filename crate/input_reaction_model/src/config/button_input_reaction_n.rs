@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use sequence_model::config::SequenceName;
 use serde::{Deserialize, Serialize};
@@ -8,7 +9,7 @@ use crate::config::{ButtonInputReaction, ButtonInputReactions};
 ///
 /// This is primarily to make it more ergonomic for users to specify different kinds of values in
 /// configuration.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields, rename_all = "snake_case", untagged)]
 pub enum ButtonInputReactionN<SeqName, IRR>
 where
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use sequence_model::config::{SequenceName, SequenceNameString};
 use serde::{Deserialize, Serialize};
@@ -8,7 +9,7 @@ use crate::config::{InputReactionMultiple, InputReactionSingle};
 ///
 /// This is primarily to make it more ergonomic for users to specify different kinds of values in
 /// configuration.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields, rename_all = "snake_case", untagged)]
 pub enum InputReaction<SeqName, IRR>
 where
@@ -1,13 +1,17 @@
+use asset_schema::AssetSchema;
 use asset_selection_model::config::AssetSelectionEventCommand;
 use control_settings_model::ControlSettingsEvent;
 use derive_more::From;
 use game_mode_selection_model::GameModeSelectionEventArgs;
 use game_play_model::GamePlayEventArgs;
 use network_mode_selection_model::NetworkModeSelectionEventArgs;
+use object_status_model::config::StatusEffect;
+use pause_menu_model::PauseMenuEventArgs;
 use serde::{Deserialize, Serialize};
 use session_host_model::config::SessionHostEventCommand;
 use session_join_model::config::SessionJoinEventCommand;
 use session_lobby_model::config::SessionLobbyEventCommand;
+use training_model::TrainingEventArgs;
 
 /// Configuration type to indicate what `AppEvent` to send as part of an `InputReaction`.
 ///
@@ -15,7 +19,7 @@ use session_lobby_model::config::SessionLobbyEventCommand;
 ///
 /// * `ControlInputEvent`s are skipped as this is used to indicate events sent upon control input.
 /// * `StdioCommandEvent`s are skipped as those events are not intended to be sent through UI items.
-#[derive(Clone, Copy, Debug, Deserialize, From, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, From, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum InputReactionAppEvent {
     /// `asset_selection` events.
@@ -34,4 +38,10 @@ pub enum InputReactionAppEvent {
     SessionLobby(SessionLobbyEventCommand),
     /// `network_mode_selection` events.
     NetworkModeSelection(NetworkModeSelectionEventArgs),
+    /// `pause_menu` events.
+    PauseMenu(PauseMenuEventArgs),
+    /// Applies a status effect to the entity that the input reaction is sourced from.
+    StatusEffect(StatusEffect),
+    /// `training` events.
+    Training(TrainingEventArgs),
 }
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use sequence_model::config::SequenceName;
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::InputReactionSingle;
 
 /// Configuration type for transition sequence name.
-#[derive(Clone, Debug, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct InputReactionMultiple<SeqName, Req>(pub Vec<InputReactionSingle<SeqName, Req>>)
 where
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use sequence_model::config::{SequenceName, SequenceNameString};
 use serde::{Deserialize, Serialize};
@@ -5,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::InputReactionAppEvents;
 
 /// Configuration type for transition sequence name.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct InputReactionSingle<SeqName, IRR>
 where
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_new::new;
 use sequence_model::config::SequenceName;
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::{BasicIrr, ButtonInputReactionN, InputReaction};
 
 /// Sequence to transition to when a `ControlAction` is pressed, held, or released.
-#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields)]
 pub struct InputReactions<SeqName, IRR = BasicIrr>
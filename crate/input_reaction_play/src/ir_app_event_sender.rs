@@ -7,6 +7,9 @@ use game_play_model::{GamePlayEvent, GamePlayEventArgs};
 use input_reaction_model::config::InputReactionAppEvent;
 use log::{debug, error};
 use network_mode_selection_model::{NetworkModeSelectionEvent, NetworkModeSelectionEventArgs};
+use object_status_model::{config::StatusEffect, play::StatusEffectEvent};
+use pause_menu_model::{PauseMenuEvent, PauseMenuEventArgs};
+use training_model::{TrainingEvent, TrainingEventArgs};
 
 use crate::IrAppEventSenderSystemData;
 
@@ -97,6 +100,18 @@ impl IrAppEventSender {
                     network_mode_selection_event_args,
                 );
             }
+            InputReactionAppEvent::PauseMenu(pause_menu_event_args) => {
+                Self::handle_pause_menu_event(
+                    ir_app_event_sender_system_data,
+                    pause_menu_event_args,
+                );
+            }
+            InputReactionAppEvent::StatusEffect(status_effect) => {
+                Self::handle_status_effect(ir_app_event_sender_system_data, entity, status_effect);
+            }
+            InputReactionAppEvent::Training(training_event_args) => {
+                Self::handle_training_event(ir_app_event_sender_system_data, training_event_args);
+            }
         }
     }
 
@@ -157,6 +172,50 @@ impl IrAppEventSender {
             .single_write(network_mode_selection_event);
     }
 
+    fn handle_pause_menu_event(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        pause_menu_event_args: PauseMenuEventArgs,
+    ) {
+        let pause_menu_event = match pause_menu_event_args {
+            PauseMenuEventArgs::Select { index } => PauseMenuEvent::Select(index),
+            PauseMenuEventArgs::Close => PauseMenuEvent::Close,
+        };
+
+        ir_app_event_sender_system_data
+            .pause_menu_ec
+            .single_write(pause_menu_event);
+    }
+
+    fn handle_status_effect(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        entity: Entity,
+        status_effect: StatusEffect,
+    ) {
+        ir_app_event_sender_system_data
+            .status_effect_ec
+            .single_write(StatusEffectEvent::new(entity, status_effect));
+    }
+
+    fn handle_training_event(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        training_event_args: TrainingEventArgs,
+    ) {
+        let training_event = match training_event_args {
+            TrainingEventArgs::InfiniteHp => TrainingEvent::ToggleInfiniteHp,
+            TrainingEventArgs::InfiniteSp => TrainingEvent::ToggleInfiniteSp,
+            TrainingEventArgs::InfiniteCharge => TrainingEvent::ToggleInfiniteCharge,
+            TrainingEventArgs::DummyBehaviour { behaviour } => {
+                TrainingEvent::DummyBehaviour(behaviour)
+            }
+            TrainingEventArgs::RecordStart => TrainingEvent::RecordStart,
+            TrainingEventArgs::RecordStop => TrainingEvent::RecordStop,
+        };
+
+        ir_app_event_sender_system_data
+            .training_ec
+            .single_write(training_event);
+    }
+
     pub(crate) fn log_component_missing_error(
         asset_ids: &ReadStorage<'_, AssetId>,
         asset_id_mappings: &AssetIdMappings,
@@ -109,6 +109,13 @@ impl IrAssetSelectionEventSender {
                     controller_id,
                 })
             }
+            AssetSelectionEventCommand::VariantSwitch(variant_switch) => {
+                Some(AssetSelectionEvent::VariantSwitch {
+                    entity: Some(ash_entity),
+                    controller_id,
+                    variant_switch,
+                })
+            }
             AssetSelectionEventCommand::Confirm => {
                 if Self::asset_selection_confirm_preconditions_met(
                     ir_app_event_sender_system_data,
@@ -15,10 +15,13 @@ use game_mode_selection_model::GameModeSelectionEvent;
 use game_play_model::GamePlayEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
 use network_session_model::play::SessionCode;
+use object_status_model::play::StatusEffectEvent;
+use pause_menu_model::PauseMenuEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
 use state_registry::StateId;
+use training_model::TrainingEvent;
 use ui_form_model::play::UiFormInputEntities;
 
 /// `IrAppEventSenderSystemData`.
@@ -85,6 +88,9 @@ pub struct IrAppEventSenderSystemData<'s> {
     /// `NetworkModeSelectionEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_mode_selection_ec: Write<'s, EventChannel<NetworkModeSelectionEvent>>,
+    /// `PauseMenuEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub pause_menu_ec: Write<'s, EventChannel<PauseMenuEvent>>,
     /// `SessionHostEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_host_ec: Write<'s, EventChannel<SessionHostEvent>>,
@@ -94,4 +100,10 @@ pub struct IrAppEventSenderSystemData<'s> {
     /// `SessionLobbyEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_lobby_ec: Write<'s, EventChannel<SessionLobbyEvent>>,
+    /// `StatusEffectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub status_effect_ec: Write<'s, EventChannel<StatusEffectEvent>>,
+    /// `TrainingEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub training_ec: Write<'s, EventChannel<TrainingEvent>>,
 }
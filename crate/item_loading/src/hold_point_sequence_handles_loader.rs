@@ -0,0 +1,55 @@
+use item_model::{
+    config::HoldPoint,
+    loaded::{HoldPointSequenceHandle, HoldPointSequenceHandles},
+};
+use sequence_loading_spi::SequenceComponentDataLoader;
+
+use crate::HoldPointSequenceLoader;
+
+/// Loads `HoldPointSequenceHandle`s from collections of sequences that contain `HoldPoint` values.
+#[derive(Debug)]
+pub struct HoldPointSequenceHandlesLoader<'s> {
+    /// `HoldPointSequenceLoader`.
+    pub hold_point_sequence_loader: HoldPointSequenceLoader<'s>,
+}
+
+impl<'s> HoldPointSequenceHandlesLoader<'s> {
+    /// Loads `HoldPointSequenceHandles`.
+    ///
+    /// This is similar to calling the `SequenceComponentDataLoader::load` trait method, with the
+    /// difference that the resources are stored by an instantiation of this type, so they do not
+    /// need to be passed in when this method is called.
+    pub fn items_to_datas<
+        'seq_ref,
+        'frame_ref: 'seq_ref,
+        SequencesIterator,
+        SequenceRef,
+        FnSequencesToSequenceIterator,
+        SequenceIterator,
+        FrameRef,
+    >(
+        &self,
+        sequences_iterator: SequencesIterator,
+        fn_sequences_to_sequence_iterator: FnSequencesToSequenceIterator,
+    ) -> HoldPointSequenceHandles
+    where
+        SequencesIterator: Iterator<Item = SequenceRef>,
+        SequenceRef: 'seq_ref,
+        FnSequencesToSequenceIterator: Fn(SequenceRef) -> SequenceIterator,
+        FrameRef: AsRef<HoldPoint> + 'frame_ref,
+        SequenceIterator: Iterator<Item = FrameRef>,
+    {
+        <Self as SequenceComponentDataLoader>::load(
+            |sequence_ref| {
+                self.hold_point_sequence_loader
+                    .load(fn_sequences_to_sequence_iterator(sequence_ref))
+            },
+            sequences_iterator,
+        )
+    }
+}
+
+impl<'s> SequenceComponentDataLoader for HoldPointSequenceHandlesLoader<'s> {
+    type Component = HoldPointSequenceHandle;
+    type ComponentData = HoldPointSequenceHandles;
+}
@@ -0,0 +1,56 @@
+use amethyst::assets::{AssetStorage, Handle, Loader};
+use derivative::Derivative;
+use item_model::{config::HoldPoint, loaded::HoldPointSequence};
+use sequence_loading_spi::FrameComponentDataLoader;
+
+/// Loads `HoldPointSequence`s from `Sequence` types whose `Frame`s contain a `HoldPoint`.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct HoldPointSequenceLoader<'s> {
+    /// `Loader`.
+    #[derivative(Debug = "ignore")]
+    pub loader: &'s Loader,
+    /// `HoldPointSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub hold_point_sequence_assets: &'s AssetStorage<HoldPointSequence>,
+}
+
+impl<'s> HoldPointSequenceLoader<'s> {
+    /// Loads a `HoldPointSequence` and returns its handle.
+    ///
+    /// This is similar to calling the `FrameComponentDataLoader::load` trait method, with the
+    /// difference that the resources are stored by an instantiation of this type, so they do not
+    /// need to be passed in when this method is called.
+    pub fn load<SequenceIterator, FrameRef>(
+        &self,
+        sequence_iterator: SequenceIterator,
+    ) -> Handle<HoldPointSequence>
+    where
+        SequenceIterator: Iterator<Item = FrameRef>,
+        FrameRef: AsRef<HoldPoint>,
+    {
+        <Self as FrameComponentDataLoader>::load(
+            self.loader,
+            self.hold_point_sequence_assets,
+            Self::frame_to_component,
+            sequence_iterator,
+        )
+    }
+
+    /// Maps the frame to the component.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_ref`: Reference to the frame.
+    pub fn frame_to_component<FrameRef>(frame_ref: FrameRef) -> HoldPoint
+    where
+        FrameRef: AsRef<HoldPoint>,
+    {
+        *AsRef::<HoldPoint>::as_ref(&frame_ref)
+    }
+}
+
+impl<'s> FrameComponentDataLoader for HoldPointSequenceLoader<'s> {
+    type Component = HoldPoint;
+    type ComponentData = HoldPointSequence;
+}
@@ -0,0 +1,12 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes item configuration into the loaded item model.
+
+pub use crate::{
+    hold_point_sequence_handles_loader::HoldPointSequenceHandlesLoader,
+    hold_point_sequence_loader::HoldPointSequenceLoader, item_loading_bundle::ItemLoadingBundle,
+};
+
+mod hold_point_sequence_handles_loader;
+mod hold_point_sequence_loader;
+mod item_loading_bundle;
@@ -0,0 +1,26 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_schema::AssetSchema;
+use derive_new::new;
+use kinematic_model::config::VelocityInit;
+use serde::{Deserialize, Serialize};
+
+/// Where a held item is positioned relative to the holding object, and whether it is thrown.
+///
+/// The `x` offset is relative to the direction the holding object is facing.
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new,
+)]
+#[serde(default, deny_unknown_fields)]
+#[storage(DenseVecStorage)]
+pub struct HoldPoint {
+    /// X offset of the held item from the holding object.
+    pub x: i32,
+    /// Y offset of the held item from the holding object.
+    pub y: i32,
+    /// Z offset of the held item from the holding object.
+    pub z: i32,
+    /// Velocity to throw the held item with on this frame.
+    ///
+    /// When this is `Some`, the held item is released from the holding object.
+    pub throw: Option<VelocityInit>,
+}
@@ -0,0 +1,36 @@
+use asset_schema::AssetSchema;
+use derivative::Derivative;
+use sequence_model::config::SequenceName;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString, IntoStaticStr};
+
+/// `Item` sequence names.
+#[derive(
+    AssetSchema,
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumString,
+    IntoStaticStr,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ItemSequenceName {
+    /// Default sequence for items, such as when lying on the ground.
+    #[derivative(Default)]
+    Idle,
+    /// Sequence to switch to when picked up by another object.
+    Held,
+    /// Sequence to switch to when thrown by the holding object.
+    Thrown,
+}
+
+impl SequenceName for ItemSequenceName {}
@@ -0,0 +1,14 @@
+//! Contains the types that represent processed configuration.
+//!
+//! This differs from the plain configuration types as they would have been processed into the form
+//! that will be used in game.
+
+pub use self::{
+    asset_item_definition_handle::AssetItemDefinitionHandle,
+    hold_point_sequence::{HoldPointSequence, HoldPointSequenceHandle},
+    hold_point_sequence_handles::HoldPointSequenceHandles,
+};
+
+mod asset_item_definition_handle;
+mod hold_point_sequence;
+mod hold_point_sequence_handles;
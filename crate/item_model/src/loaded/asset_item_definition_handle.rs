@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SecondaryMap;
+
+use crate::config::ItemDefinitionHandle;
+
+/// `ItemDefinitionHandle` for an asset.
+pub type AssetItemDefinitionHandle = SecondaryMap<AssetId, ItemDefinitionHandle>;
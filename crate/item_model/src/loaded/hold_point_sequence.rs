@@ -0,0 +1,7 @@
+use sequence_model::frame_component_data;
+
+use crate::config::HoldPoint;
+
+/// Sequence of `HoldPoint` values.
+#[frame_component_data(HoldPoint, copy)]
+pub struct HoldPointSequence;
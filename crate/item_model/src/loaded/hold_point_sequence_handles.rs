@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::HoldPointSequenceHandle;
+
+/// Sequence of `HoldPointSequenceHandle`s.
+#[sequence_component_data(HoldPointSequenceHandle)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct HoldPointSequenceHandles;
+
+impl<'s> ItemComponent<'s> for HoldPointSequenceHandles {
+    type SystemData = ();
+}
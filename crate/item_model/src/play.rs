@@ -0,0 +1,6 @@
+//! Data types used at runtime.
+
+pub use self::{held_item::HeldItem, holdable_item::HoldableItem};
+
+mod held_item;
+mod holdable_item;
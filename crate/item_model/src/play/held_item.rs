@@ -0,0 +1,10 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Links a holding entity to the item entity it is holding.
+///
+/// This component should be attached to the holding entity. The item entity has a `ParentEntity`
+/// component that links back to the holding entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct HeldItem(pub Entity);
@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for items used during game play.
+
+pub use crate::system::{ItemHoldSystem, ItemLandSystem, ItemPickUpSystem, ItemThrowSystem};
+
+mod system;
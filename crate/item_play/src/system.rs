@@ -0,0 +1,9 @@
+pub use self::{
+    item_hold_system::ItemHoldSystem, item_land_system::ItemLandSystem,
+    item_pick_up_system::ItemPickUpSystem, item_throw_system::ItemThrowSystem,
+};
+
+mod item_hold_system;
+mod item_land_system;
+mod item_pick_up_system;
+mod item_throw_system;
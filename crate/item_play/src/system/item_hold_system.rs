@@ -0,0 +1,101 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{config::HoldPoint, play::HeldItem};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
+use mirrored_model::play::Mirrored;
+
+/// Updates a held item's `Position` to match the holding object's `HoldPoint`.
+///
+/// This should run before the `ObjectTransformUpdateSystem`, so that the item's `Transform` is
+/// calculated from the updated `Position`.
+#[derive(Debug, Default, new)]
+pub struct ItemHoldSystem;
+
+/// `ItemHoldSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemHoldSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `HoldPoint` components.
+    #[derivative(Debug = "ignore")]
+    pub hold_points: ReadStorage<'s, HoldPoint>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+}
+
+impl<'s> System<'s> for ItemHoldSystem {
+    type SystemData = ItemHoldSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemHoldSystemData {
+            entities,
+            mut held_items,
+            hold_points,
+            mut positions,
+            mut velocities,
+            mut mirroreds,
+        }: Self::SystemData,
+    ) {
+        let mut holders_stale = Vec::<Entity>::new();
+        (&entities, &held_items, hold_points.maybe())
+            .join()
+            .for_each(|(entity_holder, held_item, hold_point)| {
+                let entity_item = held_item.0;
+                if !entities.is_alive(entity_item) {
+                    holders_stale.push(entity_holder);
+                    return;
+                }
+
+                let hold_point = hold_point.copied().unwrap_or_default();
+                let mirrored = mirroreds.get(entity_holder).copied();
+
+                if let Some(position_holder) = positions.get(entity_holder).copied() {
+                    let offset_x = if let Some(Mirrored(true)) = mirrored {
+                        -hold_point.x
+                    } else {
+                        hold_point.x
+                    };
+                    let position = Position::new(
+                        position_holder.x + FixedPoint::from(offset_x),
+                        position_holder.y + FixedPoint::from(hold_point.y),
+                        position_holder.z + FixedPoint::from(hold_point.z),
+                    );
+                    positions
+                        .insert(entity_item, position)
+                        .expect("Failed to insert `Position<FixedPoint>` component.");
+                }
+
+                // Held items should not accumulate velocity from gravity.
+                velocities
+                    .insert(entity_item, Velocity::default())
+                    .expect("Failed to insert `Velocity<FixedPoint>` component.");
+
+                if let Some(mirrored) = mirrored {
+                    mirroreds
+                        .insert(entity_item, mirrored)
+                        .expect("Failed to insert `Mirrored` component.");
+                }
+            });
+
+        holders_stale.into_iter().for_each(|entity_holder| {
+            held_items.remove(entity_holder);
+        });
+    }
+}
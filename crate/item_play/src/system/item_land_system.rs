@@ -0,0 +1,90 @@
+use amethyst::{
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::loaded::AssetId;
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{config::ItemSequenceName, play::HoldableItem};
+use kinematic_model::config::{FixedPoint, Velocity};
+use object_model::play::Grounding;
+use parent_model::play::ParentEntity;
+use sequence_model::loaded::{AssetSequenceIdMappings, SequenceId};
+
+/// Switches thrown items back to their `Idle` sequence when they land on the ground.
+#[derive(Debug, Default, new)]
+pub struct ItemLandSystem;
+
+/// `ItemLandSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemLandSystemData<'s> {
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `HoldableItem` components.
+    #[derivative(Debug = "ignore")]
+    pub holdable_items: ReadStorage<'s, HoldableItem>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: ReadStorage<'s, ParentEntity>,
+    /// `Grounding` components.
+    #[derivative(Debug = "ignore")]
+    pub groundings: ReadStorage<'s, Grounding>,
+    /// `Velocity<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: ReadStorage<'s, Velocity<FixedPoint>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemLandSystem {
+    type SystemData = ItemLandSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemLandSystemData {
+            asset_sequence_id_mappings_item,
+            asset_ids,
+            holdable_items,
+            parent_entities,
+            groundings,
+            velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        (
+            &asset_ids,
+            &holdable_items,
+            !&parent_entities,
+            &groundings,
+            &velocities,
+            &mut sequence_ids,
+        )
+            .join()
+            .filter(|(_, _, _, grounding, velocity, _)| {
+                // Items thrown upwards are still on the ground on the tick they are thrown.
+                **grounding == Grounding::OnGround && velocity[1] <= FixedPoint::ZERO
+            })
+            .for_each(|(asset_id, _, _, _, _, sequence_id)| {
+                let sequence_id_mappings = asset_sequence_id_mappings_item.get(*asset_id);
+                if let Some(sequence_id_mappings) = sequence_id_mappings {
+                    let sequence_id_thrown =
+                        sequence_id_mappings.id_by_name(ItemSequenceName::Thrown);
+                    let sequence_id_idle = sequence_id_mappings.id_by_name(ItemSequenceName::Idle);
+
+                    if let (Some(sequence_id_thrown), Some(sequence_id_idle)) =
+                        (sequence_id_thrown, sequence_id_idle)
+                    {
+                        if *sequence_id == *sequence_id_thrown {
+                            *sequence_id = *sequence_id_idle;
+                        }
+                    }
+                }
+            });
+    }
+}
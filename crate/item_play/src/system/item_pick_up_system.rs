@@ -0,0 +1,139 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetId;
+use collision_model::{
+    config::{Interaction, InteractionKind},
+    play::ContactEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{
+    config::ItemSequenceName,
+    play::{HeldItem, HoldableItem},
+};
+use kinematic_model::config::{FixedPoint, Velocity};
+use parent_model::play::ParentEntity;
+use sequence_model::loaded::{AssetSequenceIdMappings, SequenceId};
+
+/// Attaches a `HoldableItem` to the object whose `PickUp` interaction contacts it.
+#[derive(Debug, Default, new)]
+pub struct ItemPickUpSystem {
+    /// Reader ID for the `ContactEvent` event channel.
+    #[new(default)]
+    contact_event_rid: Option<ReaderId<ContactEvent>>,
+}
+
+/// `ItemPickUpSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemPickUpSystemData<'s> {
+    /// `ContactEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub contact_ec: Read<'s, EventChannel<ContactEvent>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `HoldableItem` components.
+    #[derivative(Debug = "ignore")]
+    pub holdable_items: ReadStorage<'s, HoldableItem>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `Velocity<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemPickUpSystem {
+    type SystemData = ItemPickUpSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemPickUpSystemData {
+            contact_ec,
+            asset_sequence_id_mappings_item,
+            asset_ids,
+            holdable_items,
+            mut held_items,
+            mut parent_entities,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        contact_ec
+            .read(
+                self.contact_event_rid
+                    .as_mut()
+                    .expect("Expected `contact_event_rid` to exist for `ItemPickUpSystem`."),
+            )
+            .filter(|ev| {
+                if let Interaction {
+                    kind: InteractionKind::PickUp(_),
+                    ..
+                } = ev.interaction
+                {
+                    true
+                } else {
+                    false
+                }
+            })
+            .for_each(|ev| {
+                let entity_holder = ev.from;
+                let entity_item = ev.to;
+
+                // Objects may only hold one item at a time, and an item may only be held by one
+                // object.
+                let can_pick_up = holdable_items.contains(entity_item)
+                    && !held_items.contains(entity_holder)
+                    && !parent_entities.contains(entity_item)
+                    && !held_items.contains(entity_item);
+
+                if can_pick_up {
+                    held_items
+                        .insert(entity_holder, HeldItem::new(entity_item))
+                        .expect("Failed to insert `HeldItem` component.");
+                    parent_entities
+                        .insert(entity_item, ParentEntity::new(entity_holder))
+                        .expect("Failed to insert `ParentEntity` component.");
+
+                    if let Some(velocity) = velocities.get_mut(entity_item) {
+                        *velocity = Velocity::default();
+                    }
+
+                    let sequence_id_held = asset_ids
+                        .get(entity_item)
+                        .and_then(|asset_id| asset_sequence_id_mappings_item.get(*asset_id))
+                        .and_then(|sequence_id_mappings| {
+                            sequence_id_mappings.id_by_name(ItemSequenceName::Held)
+                        })
+                        .copied();
+                    if let Some(sequence_id_held) = sequence_id_held {
+                        sequence_ids
+                            .insert(entity_item, sequence_id_held)
+                            .expect("Failed to insert `SequenceId` component.");
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.contact_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ContactEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::loaded::AssetId;
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{
+    config::{HoldPoint, ItemSequenceName},
+    play::HeldItem,
+};
+use kinematic_model::config::{FixedPoint, Velocity};
+use mirrored_model::play::Mirrored;
+use parent_model::play::ParentEntity;
+use sequence_model::loaded::{AssetSequenceIdMappings, SequenceId};
+use spawn_model::play::SpawnParent;
+use team_model::play::Team;
+
+/// Releases held items when the holding object's `HoldPoint` has a throw velocity.
+///
+/// The thrown item is given the holder's `Team` and a `SpawnParent` that points to the holder, so
+/// that the thrown item's interactions do not hit the holder.
+#[derive(Debug, Default, new)]
+pub struct ItemThrowSystem;
+
+/// `ItemThrowSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemThrowSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `HoldPoint` components.
+    #[derivative(Debug = "ignore")]
+    pub hold_points: ReadStorage<'s, HoldPoint>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: WriteStorage<'s, SpawnParent>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: WriteStorage<'s, Team>,
+    /// `Velocity<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemThrowSystem {
+    type SystemData = ItemThrowSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemThrowSystemData {
+            entities,
+            asset_sequence_id_mappings_item,
+            asset_ids,
+            hold_points,
+            mirroreds,
+            mut held_items,
+            mut parent_entities,
+            mut spawn_parents,
+            mut teams,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        let throws = (&entities, &held_items, &hold_points)
+            .join()
+            .filter_map(|(entity_holder, held_item, hold_point)| {
+                hold_point
+                    .throw
+                    .map(|velocity_init| (entity_holder, held_item.0, velocity_init))
+            })
+            .collect::<Vec<_>>();
+
+        throws
+            .into_iter()
+            .for_each(|(entity_holder, entity_item, velocity_init)| {
+                held_items.remove(entity_holder);
+                if !entities.is_alive(entity_item) {
+                    return;
+                }
+                parent_entities.remove(entity_item);
+
+                let mirrored = mirroreds
+                    .get(entity_holder)
+                    .map(|mirrored| **mirrored)
+                    .unwrap_or(false);
+                let velocity_x = if mirrored {
+                    -velocity_init.x
+                } else {
+                    velocity_init.x
+                };
+                let velocity = Velocity::new(
+                    FixedPoint::from(velocity_x),
+                    FixedPoint::from(velocity_init.y),
+                    FixedPoint::from(velocity_init.z),
+                );
+                velocities
+                    .insert(entity_item, velocity)
+                    .expect("Failed to insert `Velocity<FixedPoint>` component.");
+
+                spawn_parents
+                    .insert(entity_item, SpawnParent::new(entity_holder))
+                    .expect("Failed to insert `SpawnParent` component.");
+                if let Some(team) = teams.get(entity_holder).copied() {
+                    teams
+                        .insert(entity_item, team)
+                        .expect("Failed to insert `Team` component.");
+                }
+
+                let sequence_id_thrown = asset_ids
+                    .get(entity_item)
+                    .and_then(|asset_id| asset_sequence_id_mappings_item.get(*asset_id))
+                    .and_then(|sequence_id_mappings| {
+                        sequence_id_mappings.id_by_name(ItemSequenceName::Thrown)
+                    })
+                    .copied();
+                if let Some(sequence_id_thrown) = sequence_id_thrown {
+                    sequence_ids
+                        .insert(entity_item, sequence_id_thrown)
+                        .expect("Failed to insert `SequenceId` component.");
+                }
+            });
+    }
+}
@@ -0,0 +1,32 @@
+use amethyst::ecs::Entity;
+use item_model::play::HoldableItem;
+use map_model::play::MapUnboundedDelete;
+
+use crate::ItemComponentStorages;
+
+/// Augments an entity with `Item` components.
+#[derive(Debug)]
+pub struct ItemEntityAugmenter;
+
+impl ItemEntityAugmenter {
+    /// Augments an entity with `Item` components.
+    ///
+    /// # Parameters
+    ///
+    /// * `entity`: The entity to augment.
+    /// * `item_component_storages`: Item specific `Component` storages.
+    pub fn augment<'s>(
+        entity: Entity,
+        ItemComponentStorages {
+            map_unbounded_deletes,
+            holdable_items,
+        }: &mut ItemComponentStorages<'s>,
+    ) {
+        map_unbounded_deletes
+            .insert(entity, MapUnboundedDelete::default())
+            .expect("Failed to insert `MapUnboundedDelete` component.");
+        holdable_items
+            .insert(entity, HoldableItem)
+            .expect("Failed to insert `HoldableItem` component.");
+    }
+}
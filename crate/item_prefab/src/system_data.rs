@@ -0,0 +1,3 @@
+pub use self::item_component_storages::ItemComponentStorages;
+
+mod item_component_storages;
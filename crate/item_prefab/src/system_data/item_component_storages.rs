@@ -0,0 +1,22 @@
+use amethyst::{
+    ecs::{World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use item_model::play::HoldableItem;
+use map_model::play::MapUnboundedDelete;
+
+/// Item specific `Component` storages.
+///
+/// These are the storages for the components specific to item objects. See also
+/// `ObjectComponentStorages`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemComponentStorages<'s> {
+    /// `MapUnboundedDelete` component storage.
+    #[derivative(Debug = "ignore")]
+    pub map_unbounded_deletes: WriteStorage<'s, MapUnboundedDelete>,
+    /// `HoldableItem` components.
+    #[derivative(Debug = "ignore")]
+    pub holdable_items: WriteStorage<'s, HoldableItem>,
+}
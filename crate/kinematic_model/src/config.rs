@@ -1,7 +1,8 @@
 //! Contains the types that represent the configuration on disk.
 
 pub use self::{
-    object_acceleration::ObjectAcceleration, object_acceleration_kind::ObjectAccelerationKind,
+    fixed_point::FixedPoint, object_acceleration::ObjectAcceleration,
+    object_acceleration_kind::ObjectAccelerationKind,
     object_acceleration_value::ObjectAccelerationValue,
     object_acceleration_value_expr::ObjectAccelerationValueExpr,
     object_acceleration_value_multiplier::ObjectAccelerationValueMultiplier,
@@ -9,6 +10,7 @@ pub use self::{
     velocity_init::VelocityInit,
 };
 
+mod fixed_point;
 mod object_acceleration;
 mod object_acceleration_kind;
 mod object_acceleration_value;
@@ -28,13 +30,16 @@ use amethyst::{
     core::math,
     ecs::{storage::DenseVecStorage, Component},
 };
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 macro_rules! kinematic_type {
     ($name:ident) => {
         /// #[doc = $name]
         /// of the entity in game.
-        #[derive(Clone, Component, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+        #[derive(
+            AssetSchema, Clone, Component, Copy, Debug, Deserialize, PartialEq, Eq, Serialize,
+        )]
         #[serde(from = "Vector3<S>", into = "Vector3<S>")]
         pub struct $name<S>(pub math::Vector3<S>)
         where
@@ -52,6 +57,24 @@ macro_rules! kinematic_type {
             }
         }
 
+        impl $name<FixedPoint> {
+            /// Returns a `
+            /// #[doc = $name]
+            /// ` from a floating point vector.
+            pub fn from_f32(v: math::Vector3<f32>) -> Self {
+                $name::new(
+                    FixedPoint::from(v.x),
+                    FixedPoint::from(v.y),
+                    FixedPoint::from(v.z),
+                )
+            }
+
+            /// Returns the floating point value of this vector, for rendering.
+            pub fn to_f32(self) -> math::Vector3<f32> {
+                math::Vector3::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+            }
+        }
+
         impl<S> Default for $name<S>
         where
             S: Clone + Copy + Debug + Default + PartialEq + Send + Sync + 'static,
@@ -235,14 +258,12 @@ kinematic_type!(Position);
 kinematic_type!(Velocity);
 kinematic_type!(Acceleration);
 
-impl From<PositionInit> for Position<f32> {
+impl From<PositionInit> for Position<FixedPoint> {
     fn from(position_init: PositionInit) -> Self {
-        // Note: `i32` as `f32` is a lossy conversion, which is why we cannot implement this
-        // generically with `S: From<i32>`, as `f32` is not `From<i32>`.
         Position::new(
-            position_init.x as f32,
-            position_init.y as f32,
-            position_init.z as f32,
+            FixedPoint::from(position_init.x),
+            FixedPoint::from(position_init.y),
+            FixedPoint::from(position_init.z),
         )
     }
 }
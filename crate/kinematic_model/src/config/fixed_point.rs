@@ -0,0 +1,271 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use asset_schema::AssetSchema;
+use serde::{Deserialize, Serialize};
+
+/// Fixed-point number used for game play kinematics.
+///
+/// Values are stored in an `i64` with `FRAC_BITS` fractional bits, so arithmetic gives the same
+/// results on every platform and build. Floating point values should only be converted at the
+/// boundaries -- when reading configuration and input, and when rendering.
+///
+/// This is serialized as a number.
+#[derive(
+    AssetSchema,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[serde(from = "f64", into = "f64")]
+pub struct FixedPoint(i64);
+
+impl FixedPoint {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+    /// `0`.
+    pub const ZERO: FixedPoint = FixedPoint(0);
+    /// `1`.
+    pub const ONE: FixedPoint = FixedPoint(1 << Self::FRAC_BITS);
+    /// Largest representable value.
+    pub const MAX: FixedPoint = FixedPoint(i64::MAX);
+    /// Smallest representable value.
+    pub const MIN: FixedPoint = FixedPoint(i64::MIN);
+    /// `π`, rounded towards zero.
+    pub const PI: FixedPoint = FixedPoint(205_887);
+
+    /// Returns a `FixedPoint` from its underlying representation.
+    ///
+    /// # Parameters
+    ///
+    /// * `bits`: Value multiplied by `2^FRAC_BITS`.
+    pub const fn from_bits(bits: i64) -> Self {
+        FixedPoint(bits)
+    }
+
+    /// Returns the underlying representation of this value.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the `FixedPoint` of an integer.
+    ///
+    /// This is the same as `FixedPoint::from(value)`, usable in constants.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: Integer value.
+    pub const fn from_int(value: i32) -> Self {
+        FixedPoint((value as i64) << Self::FRAC_BITS)
+    }
+
+    /// Returns the `FixedPoint` closest to `numerator / denominator`, rounded towards zero.
+    ///
+    /// This allows fractional constants to be defined without floating point arithmetic.
+    ///
+    /// # Parameters
+    ///
+    /// * `numerator`: Numerator of the fraction.
+    /// * `denominator`: Denominator of the fraction.
+    pub const fn from_ratio(numerator: i64, denominator: i64) -> Self {
+        FixedPoint((numerator << Self::FRAC_BITS) / denominator)
+    }
+
+    /// Returns the absolute value, saturating at `FixedPoint::MAX`.
+    pub fn abs(self) -> Self {
+        FixedPoint(self.0.checked_abs().unwrap_or(i64::MAX))
+    }
+
+    /// Returns `1` if the value is positive, `-1` if negative, and `0` if zero.
+    pub fn signum(self) -> Self {
+        FixedPoint::from_int(self.0.signum() as i32)
+    }
+
+    /// Returns the square root, or `0` if the value is negative.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return FixedPoint::ZERO;
+        }
+
+        // Integer square root using Newton's method, so the result does not depend on the
+        // platform's floating point implementation.
+        let n = (self.0 as u128) << Self::FRAC_BITS;
+        let mut x = n;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        FixedPoint(x as i64)
+    }
+
+    /// Returns the sine of the value, in radians.
+    pub fn sin(self) -> Self {
+        let pi = Self::PI;
+        let half_pi = FixedPoint(pi.0 / 2);
+
+        // Reduce to `[-π/2, π/2]`, where the series converges quickly.
+        let mut x = FixedPoint((self.0.rem_euclid(2 * pi.0) + pi.0).rem_euclid(2 * pi.0) - pi.0);
+        if x > half_pi {
+            x = pi - x;
+        } else if x < -half_pi {
+            x = -pi - x;
+        }
+
+        // Taylor series up to `x^9`, in Horner form.
+        let x_2 = x * x;
+        let series = [72, 42, 20, 6]
+            .iter()
+            .fold(FixedPoint::ONE, |series, divisor| {
+                FixedPoint::ONE - x_2 / FixedPoint::from_int(*divisor) * series
+            });
+        x * series
+    }
+
+    /// Returns the cosine of the value, in radians.
+    pub fn cos(self) -> Self {
+        (self + FixedPoint(Self::PI.0 / 2)).sin()
+    }
+
+    /// Returns the value as an `f32`, for rendering.
+    pub fn to_f32(self) -> f32 {
+        f64::from(self) as f32
+    }
+
+    /// Returns the raw value as a `FixedPoint`, saturating at `FixedPoint::MAX` and
+    /// `FixedPoint::MIN`.
+    fn saturate(value: i128) -> Self {
+        let value = value.max(i128::from(i64::MIN)).min(i128::from(i64::MAX));
+        FixedPoint(value as i64)
+    }
+}
+
+impl fmt::Display for FixedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", f64::from(*self))
+    }
+}
+
+impl From<i32> for FixedPoint {
+    fn from(value: i32) -> Self {
+        FixedPoint::from_int(value)
+    }
+}
+
+impl From<u32> for FixedPoint {
+    fn from(value: u32) -> Self {
+        FixedPoint(i64::from(value) << Self::FRAC_BITS)
+    }
+}
+
+impl From<f32> for FixedPoint {
+    fn from(value: f32) -> Self {
+        FixedPoint::from(f64::from(value))
+    }
+}
+
+impl From<f64> for FixedPoint {
+    fn from(value: f64) -> Self {
+        // Multiplying by a power of two is exact, and rounding is correctly rounded, so this
+        // conversion does not depend on the platform.
+        FixedPoint((value * (1i64 << Self::FRAC_BITS) as f64).round() as i64)
+    }
+}
+
+impl From<FixedPoint> for f64 {
+    fn from(value: FixedPoint) -> Self {
+        value.0 as f64 / (1i64 << FixedPoint::FRAC_BITS) as f64
+    }
+}
+
+impl Add for FixedPoint {
+    type Output = Self;
+
+    /// Adds the value, saturating at `FixedPoint::MAX` and `FixedPoint::MIN`.
+    fn add(self, other: Self) -> Self {
+        FixedPoint(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for FixedPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for FixedPoint {
+    type Output = Self;
+
+    /// Subtracts the value, saturating at `FixedPoint::MAX` and `FixedPoint::MIN`.
+    fn sub(self, other: Self) -> Self {
+        FixedPoint(self.0.saturating_sub(other.0))
+    }
+}
+
+impl SubAssign for FixedPoint {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for FixedPoint {
+    type Output = Self;
+
+    /// Multiplies the value, saturating at `FixedPoint::MAX` and `FixedPoint::MIN`.
+    fn mul(self, other: Self) -> Self {
+        // The product of two `i64`s always fits in an `i128`.
+        let product = (i128::from(self.0) * i128::from(other.0)) >> Self::FRAC_BITS;
+        Self::saturate(product)
+    }
+}
+
+impl MulAssign for FixedPoint {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div for FixedPoint {
+    type Output = Self;
+
+    /// Divides the value, saturating at `FixedPoint::MAX` and `FixedPoint::MIN`.
+    ///
+    /// Like `f32` division producing infinity, dividing a non-zero value by zero saturates in the
+    /// direction of the value's sign. `0 / 0` is `0`.
+    fn div(self, other: Self) -> Self {
+        match (i128::from(self.0) << Self::FRAC_BITS).checked_div(i128::from(other.0)) {
+            Some(quotient) => Self::saturate(quotient),
+            None => match self.0.cmp(&0) {
+                Ordering::Greater => Self::MAX,
+                Ordering::Less => Self::MIN,
+                Ordering::Equal => Self::ZERO,
+            },
+        }
+    }
+}
+
+impl DivAssign for FixedPoint {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Neg for FixedPoint {
+    type Output = Self;
+
+    /// Negates the value, saturating at `FixedPoint::MAX`.
+    fn neg(self) -> Self {
+        FixedPoint(self.0.checked_neg().unwrap_or(i64::MAX))
+    }
+}
@@ -1,11 +1,14 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
 use crate::config::{ObjectAccelerationKind, ObjectAccelerationValue};
 
 /// Acceleration added to an object for movement.
-#[derive(Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new,
+)]
 #[serde(deny_unknown_fields, default)]
 #[storage(DenseVecStorage)]
 pub struct ObjectAcceleration {
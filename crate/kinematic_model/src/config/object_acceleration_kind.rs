@@ -1,8 +1,9 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Whether acceleration is applied once or continuously.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum ObjectAccelerationKind {
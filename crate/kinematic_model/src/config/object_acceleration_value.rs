@@ -1,16 +1,17 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
-use crate::config::ObjectAccelerationValueExpr;
+use crate::config::{FixedPoint, ObjectAccelerationValueExpr};
 
 /// Whether acceleration is applied once or continuously.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case", untagged)]
 pub enum ObjectAccelerationValue {
     /// Acceleration is a constant.
     #[derivative(Default)]
-    Const(f32),
+    Const(FixedPoint),
     /// Acceleration is calculated using an expression.
     Expr(ObjectAccelerationValueExpr),
 }
@@ -6,17 +6,20 @@ use amethyst::{
     shred::{ResourceId, SystemData},
 };
 use asset_model::ItemComponent;
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    config::{Position, ScaleInit},
+    config::{FixedPoint, Position, ScaleInit},
     play::{PositionInitOffset, PositionInitParent},
 };
 
 /// Position initializer for an entity.
-#[derive(Clone, Copy, Debug, Default, Deserialize, Component, PartialEq, Eq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Default, Deserialize, Component, PartialEq, Eq, Serialize, new,
+)]
 #[serde(default)]
 #[storage(DenseVecStorage)]
 pub struct PositionInit {
@@ -91,9 +94,9 @@ pub struct PositionInitSystemData<'s> {
     /// `ScaleInit` components.
     #[derivative(Debug = "ignore")]
     pub scale_inits: ReadStorage<'s, ScaleInit>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
     /// `Transform` components.
     #[derivative(Debug = "ignore")]
     pub transforms: WriteStorage<'s, Transform>,
@@ -118,34 +121,32 @@ impl<'s> ItemComponent<'s> for PositionInit {
         let position_init_offset = position_init_offsets.get(entity);
         let scale_init = scale_inits.get(entity).copied();
 
-        let mut translation = Into::<Position<f32>>::into(*self);
+        let mut position = Into::<Position<FixedPoint>>::into(*self);
         if let Some(position_init_offset) = position_init_offset {
             let mut position_init_offset = **position_init_offset;
 
             // Since scaling affects the whole transform, we need to divide the offset by the scale
             // factor to retain the original values.
             if let Some(scale_init) = scale_init {
-                position_init_offset.x /= scale_init.x;
-                position_init_offset.y /= scale_init.y;
-                position_init_offset.z /= scale_init.z;
+                position_init_offset.x /= FixedPoint::from(scale_init.x);
+                position_init_offset.y /= FixedPoint::from(scale_init.y);
+                position_init_offset.z /= FixedPoint::from(scale_init.z);
             }
 
-            translation += position_init_offset;
+            position += position_init_offset;
         }
-        let mut translation = Into::<Vector3<f32>>::into(translation);
         if let Some(scale_init) = scale_init {
-            translation.x *= scale_init.x;
-            translation.y *= scale_init.y;
-            translation.z *= scale_init.z;
+            position.x *= FixedPoint::from(scale_init.x);
+            position.y *= FixedPoint::from(scale_init.y);
+            position.z *= FixedPoint::from(scale_init.z);
         }
 
         if let Some(position_parent) = position_parent {
-            translation += *position_parent;
+            position += position_parent;
         }
 
-        let position = Position::from(translation);
         let mut transform = Transform::default();
-        transform.set_translation(translation);
+        transform.set_translation(position.to_f32());
 
         if let Some(scale_init) = scale_init {
             transform.set_scale(Vector3::new(scale_init.x, scale_init.y, scale_init.z));
@@ -154,7 +155,7 @@ impl<'s> ItemComponent<'s> for PositionInit {
         if positions.get(entity).is_none() {
             positions
                 .insert(entity, position)
-                .expect("Failed to insert `Position<f32>` component.");
+                .expect("Failed to insert `Position<FixedPoint>` component.");
         }
         if transforms.get(entity).is_none() {
             transforms
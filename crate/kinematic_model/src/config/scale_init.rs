@@ -4,13 +4,16 @@ use amethyst::{
     shred::{ResourceId, SystemData},
 };
 use asset_model::ItemComponent;
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_new::new;
 use log::warn;
 use serde::{Deserialize, Serialize};
 
 /// Scale initializer for an entity.
-#[derive(Clone, Copy, Debug, Default, Deserialize, Component, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Default, Deserialize, Component, PartialEq, Serialize, new,
+)]
 #[serde(default)]
 #[storage(DenseVecStorage)]
 pub struct ScaleInit {
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
@@ -5,7 +6,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// This allows the axes to be named when specifying values, e.g. `{ x: -1, y: 2, z: 3 }`.
 /// It also allows unspecified values to be defaulted.
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct Vector3<S>
 where
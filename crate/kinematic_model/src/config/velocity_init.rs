@@ -6,14 +6,17 @@ use amethyst::{
     shred::{ResourceId, SystemData},
 };
 use asset_model::ItemComponent;
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
-use crate::config::Velocity;
+use crate::config::{FixedPoint, Velocity};
 
 /// Velocity initializer for an entity.
-#[derive(Clone, Copy, Debug, Default, Deserialize, Component, PartialEq, Eq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Default, Deserialize, Component, PartialEq, Eq, Serialize, new,
+)]
 #[serde(default)]
 #[storage(DenseVecStorage)]
 pub struct VelocityInit {
@@ -57,9 +60,9 @@ impl Into<Vector3<f32>> for VelocityInit {
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct VelocityInitSystemData<'s> {
-    /// `Velocity<f32>` components.
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
 }
 
 impl<'s> ItemComponent<'s> for VelocityInit {
@@ -68,12 +71,16 @@ impl<'s> ItemComponent<'s> for VelocityInit {
     fn augment(&self, system_data: &mut Self::SystemData, entity: Entity) {
         let VelocityInitSystemData { velocities } = system_data;
 
-        let velocity = Velocity::from(Into::<Vector3<f32>>::into(*self));
+        let velocity = Velocity::new(
+            FixedPoint::from(self.x),
+            FixedPoint::from(self.y),
+            FixedPoint::from(self.z),
+        );
 
         if velocities.get(entity).is_none() {
             velocities
                 .insert(entity, velocity)
-                .expect("Failed to insert `Velocity<f32>` component.");
+                .expect("Failed to insert `Velocity<FixedPoint>` component.");
         }
     }
 }
@@ -2,11 +2,11 @@ use amethyst::ecs::{storage::DenseVecStorage, Component};
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 
-use crate::config::Position;
+use crate::config::{FixedPoint, Position};
 
 /// Offsets a `PositionInit` by some distance.
 ///
 /// This is useful when the entity should be spawned some distance away from the configuration
 /// `PositionInit` value, where the distance is calculated at runtime.
 #[derive(Clone, Component, Copy, Debug, Deref, DerefMut, new)]
-pub struct PositionInitOffset(pub Position<f32>);
+pub struct PositionInitOffset(pub Position<FixedPoint>);
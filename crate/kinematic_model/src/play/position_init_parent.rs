@@ -1,10 +1,10 @@
 use amethyst::ecs::{storage::VecStorage, Component, Entity};
 use derive_new::new;
 
-/// Links a child entity to a parent entity with a `Position<f32>` component.
+/// Links a child entity to a parent entity with a `Position<FixedPoint>` component.
 ///
-/// This offsets the child entity's `PositionInit` by the parent's `Position<f32>` when first
-/// augmented.
+/// This offsets the child entity's `PositionInit` by the parent's `Position<FixedPoint>` when
+/// first augmented.
 ///
 /// This component should be attached to the child entity.
 #[derive(Clone, Component, Copy, Debug, PartialEq, new)]
@@ -7,15 +7,19 @@ pub use crate::{
     loading_state::LoadingState,
     system::{
         AssetDefinitionLoader, AssetDefinitionLoadingSystem, AssetDiscoverySystem,
-        AssetDiscoverySystemData, AssetIdMapper, AssetIdMappingSystem, AssetPartLoader,
+        AssetDiscoverySystemData, AssetIdMapper, AssetIdMappingSystem, AssetLoadRequestSystem,
+        AssetLoadRequestSystemData, AssetMemorySystem, AssetMemorySystemData, AssetPartLoader,
         AssetPartLoadingCoordinatorSystem, AssetPartLoadingCoordinatorSystemData,
-        AssetPartLoadingSystem, AssetSequenceComponentLoader,
-        AssetSequenceComponentLoaderUiCharacterSelection, AssetSequenceComponentLoaderUiComponents,
-        AssetSequenceComponentLoaderUiControlSettings, AssetSequenceComponentLoaderUiForm,
-        AssetSequenceComponentLoaderUiMapSelection, AssetSequenceComponentLoaderUiMenu,
-        AssetSequenceComponentLoaderUiSessionLobby, AssetSequenceComponentLoadingSystem,
-        AssetSpritesDefinitionLoader, AssetSpritesDefinitionLoadingSystem, AssetTextureLoader,
-        AssetTextureLoadingSystem,
+        AssetPartLoadingSystem, AssetReloadSystem, AssetReloadSystemData,
+        AssetSequenceComponentLoader, AssetSequenceComponentLoaderUiCharacterSelection,
+        AssetSequenceComponentLoaderUiComponents, AssetSequenceComponentLoaderUiControlSettings,
+        AssetSequenceComponentLoaderUiForm, AssetSequenceComponentLoaderUiMapSelection,
+        AssetSequenceComponentLoaderUiMenu, AssetSequenceComponentLoaderUiSessionLobby,
+        AssetSequenceComponentLoadingSystem, AssetSpritesDefinitionLoader,
+        AssetSpritesDefinitionLoadingSystem, AssetTextureLoader, AssetTextureLoadingSystem,
+        AssetUnloadHandles, AssetUnloadSystem, AssetUnloadSystemData, AssetUsageSystem,
+        AssetUsageSystemData, LoadingProgressDisplaySystem, LoadingProgressDisplaySystemData,
+        LoadingProgressSystem, LoadingProgressSystemData,
     },
     system_data::{
         AssetLoadingResources, DefinitionLoadingResources, DefinitionLoadingResourcesRead,
@@ -1,19 +1,27 @@
 use std::{any, path::PathBuf};
 
+#[cfg(not(target_arch = "wasm32"))]
+use amethyst::assets::Loader;
 use amethyst::{
     core::bundle::SystemBundle,
     ecs::{DispatcherBuilder, World},
     Error,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use asset_loading::AssetFsSource;
 use derive_new::new;
 
 use crate::{
     AssetDefinitionLoadingSystem, AssetDiscoverySystem, AssetIdMappingSystem,
-    AssetPartLoadingCoordinatorSystem, AssetSequenceComponentLoadingSystem,
-    AssetSpritesDefinitionLoadingSystem, AssetTextureLoadingSystem,
+    AssetLoadRequestSystem, AssetMemorySystem, AssetPartLoadingCoordinatorSystem,
+    AssetSequenceComponentLoadingSystem, AssetSpritesDefinitionLoadingSystem,
+    AssetTextureLoadingSystem, AssetUnloadSystem, AssetUsageSystem, LoadingProgressDisplaySystem,
+    LoadingProgressSystem,
 };
 
 /// Adds asset discovery and loading systems to the `World`.
+///
+/// This also sets the `Loader`'s default source to read from asset packs.
 #[derive(Debug, new)]
 pub struct LoadingBundle {
     /// Path to the assets directory.
@@ -23,18 +31,32 @@ pub struct LoadingBundle {
 impl<'a, 'b> SystemBundle<'a, 'b> for LoadingBundle {
     fn build(
         self,
-        _world: &mut World,
+        world: &mut World,
         builder: &mut DispatcherBuilder<'a, 'b>,
     ) -> Result<(), Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(mut loader) = world.try_fetch_mut::<Loader>() {
+                loader.set_default_source(AssetFsSource::new(self.assets_dir.clone()));
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = world;
+
         builder.add(
-            AssetDiscoverySystem::new(self.assets_dir),
+            AssetDiscoverySystem::new(self.assets_dir.clone()),
             any::type_name::<AssetDiscoverySystem>(),
             &[],
         ); // kcov-ignore
+        builder.add(
+            AssetLoadRequestSystem::new(),
+            any::type_name::<AssetLoadRequestSystem>(),
+            &[any::type_name::<AssetDiscoverySystem>()],
+        ); // kcov-ignore
         builder.add(
             AssetPartLoadingCoordinatorSystem::new(),
             any::type_name::<AssetPartLoadingCoordinatorSystem>(),
-            &[any::type_name::<AssetDiscoverySystem>()],
+            &[any::type_name::<AssetLoadRequestSystem>()],
         ); // kcov-ignore
         builder.add(
             AssetDefinitionLoadingSystem::new(),
@@ -61,6 +83,31 @@ impl<'a, 'b> SystemBundle<'a, 'b> for LoadingBundle {
             any::type_name::<AssetSequenceComponentLoadingSystem>(),
             &[any::type_name::<AssetTextureLoadingSystem>()],
         ); // kcov-ignore
+        builder.add(
+            LoadingProgressSystem::new(),
+            any::type_name::<LoadingProgressSystem>(),
+            &[any::type_name::<AssetSequenceComponentLoadingSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            LoadingProgressDisplaySystem::new(),
+            any::type_name::<LoadingProgressDisplaySystem>(),
+            &[any::type_name::<LoadingProgressSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            AssetMemorySystem::new(self.assets_dir),
+            any::type_name::<AssetMemorySystem>(),
+            &[any::type_name::<AssetSequenceComponentLoadingSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            AssetUsageSystem::new(),
+            any::type_name::<AssetUsageSystem>(),
+            &[any::type_name::<AssetMemorySystem>()],
+        ); // kcov-ignore
+        builder.add(
+            AssetUnloadSystem::default(),
+            any::type_name::<AssetUnloadSystem>(),
+            &[any::type_name::<AssetUsageSystem>()],
+        ); // kcov-ignore
         Ok(())
     }
 }
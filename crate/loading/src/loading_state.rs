@@ -20,6 +20,8 @@ const LOADING_TIME_LIMIT: Duration = Duration::from_secs(10);
 
 /// `State` where resource loading takes place.
 ///
+/// Assets whose types are in the `DeferredAssetTypes` resource are not waited for.
+///
 /// If you use this `State`, you **MUST** ensure that both the `CharacterLoadingBundle` and
 /// `MapLoadingBundle`s are included in the application dispatcher that this `State` delegates to
 /// to load the assets.
@@ -106,7 +108,8 @@ where
                             panic!("Expected asset `{:?}` to have `LoadStage`.", asset_id)
                         });
 
-                    if load_stage == LoadStage::Complete {
+                    // Deferred assets are loaded when requested, so they are not waited for.
+                    if load_stage == LoadStage::Complete || load_stage == LoadStage::Deferred {
                         Ok(())
                     } else {
                         if let Stopwatch::Ended(..) = &self.stopwatch {
@@ -154,6 +157,7 @@ where
                          * `SpriteLoadingBundle`\n\
                          * `CharacterLoadingBundle`\n\
                          * `EnergyLoadingBundle`\n\
+                         * `ItemLoadingBundle`\n\
                          * `BackgroundLoadingBundle`\n\
                          * `MapLoadingBundle`\n\
                          * `amethyst::audio::AudioBundle`\n\
@@ -2,11 +2,14 @@ pub use self::{
     asset_definition_loading_system::{AssetDefinitionLoader, AssetDefinitionLoadingSystem},
     asset_discovery_system::{AssetDiscoverySystem, AssetDiscoverySystemData},
     asset_id_mapping_system::{AssetIdMapper, AssetIdMappingSystem},
+    asset_load_request_system::{AssetLoadRequestSystem, AssetLoadRequestSystemData},
+    asset_memory_system::{AssetMemorySystem, AssetMemorySystemData},
     asset_part_loader::AssetPartLoader,
     asset_part_loading_coordinator_system::{
         AssetPartLoadingCoordinatorSystem, AssetPartLoadingCoordinatorSystemData,
     },
     asset_part_loading_system::AssetPartLoadingSystem,
+    asset_reload_system::{AssetReloadSystem, AssetReloadSystemData},
     asset_sequence_component_loading_system::{
         AssetSequenceComponentLoader, AssetSequenceComponentLoaderUiCharacterSelection,
         AssetSequenceComponentLoaderUiComponents, AssetSequenceComponentLoaderUiControlSettings,
@@ -18,14 +21,27 @@ pub use self::{
         AssetSpritesDefinitionLoader, AssetSpritesDefinitionLoadingSystem,
     },
     asset_texture_loading_system::{AssetTextureLoader, AssetTextureLoadingSystem},
+    asset_unload_system::{AssetUnloadHandles, AssetUnloadSystem, AssetUnloadSystemData},
+    asset_usage_system::{AssetUsageSystem, AssetUsageSystemData},
+    loading_progress_display_system::{
+        LoadingProgressDisplaySystem, LoadingProgressDisplaySystemData,
+    },
+    loading_progress_system::{LoadingProgressSystem, LoadingProgressSystemData},
 };
 
 mod asset_definition_loading_system;
 mod asset_discovery_system;
 mod asset_id_mapping_system;
+mod asset_load_request_system;
+mod asset_memory_system;
 mod asset_part_loader;
 mod asset_part_loading_coordinator_system;
 mod asset_part_loading_system;
+mod asset_reload_system;
 mod asset_sequence_component_loading_system;
 mod asset_sprites_definition_loading_system;
 mod asset_texture_loading_system;
+mod asset_unload_system;
+mod asset_usage_system;
+mod loading_progress_display_system;
+mod loading_progress_system;
@@ -1,11 +1,11 @@
-use amethyst::assets::ProgressCounter;
-use asset_loading::YamlFormat;
+use amethyst::assets::{Asset, AssetStorage, Handle, Loader, ProgressCounter};
+use asset_loading::{DefinitionFile, DefinitionFormat, ObjectDefinitionMerger};
 use asset_model::{config::AssetType, loaded::AssetId};
 use loading_model::loaded::LoadStage;
-use log::debug;
+use log::{debug, error};
 use object_type::ObjectType;
-#[cfg(target_arch = "wasm32")]
-use wasm_support_fs::PathAccessExt;
+use serde::de::DeserializeOwned;
+use serde_yaml::Value;
 
 use crate::{
     AssetLoadingResources, AssetPartLoader, AssetPartLoadingSystem, DefinitionLoadingResources,
@@ -33,14 +33,17 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         DefinitionLoadingResources {
             character_definition_assets,
             energy_definition_assets,
+            item_definition_assets,
             map_definition_assets,
             background_definition_assets,
             ui_definition_assets,
             asset_character_definition_handle,
             asset_energy_definition_handle,
+            asset_item_definition_handle,
             asset_map_definition_handle,
             asset_background_definition_handle,
             asset_ui_definition_handle,
+            overridden_asset_paths,
         }: &mut DefinitionLoadingResources<'_>,
         asset_id: AssetId,
     ) {
@@ -68,16 +71,38 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
 
         match asset_type {
             AssetType::Object(object_type) => {
-                let object_definition_path = asset_path.join("object.yaml");
-                let object_definition_path = object_definition_path
-                    .to_str()
-                    .expect("Expected path to be valid unicode.");
+                let object_definition_file = DefinitionFile::find_or_default(asset_path, "object");
+
+                // Definitions that extend other definitions are merged before they are loaded.
+                //
+                // In WASM, files cannot be read synchronously, so `extends` is not supported.
+                // `DefinitionFormat` fails to load definitions that use it with an error.
+                #[cfg(not(target_arch = "wasm32"))]
+                let object_definition_merged =
+                    ObjectDefinitionMerger::resolve(&object_definition_file.path, |asset_slug| {
+                        overridden_asset_paths.get(asset_slug).cloned().or_else(|| {
+                            asset_id_mappings
+                                .id(asset_slug)
+                                .and_then(|asset_id| asset_id_to_path.get(*asset_id))
+                                .cloned()
+                        })
+                    })
+                    .unwrap_or_else(|e| {
+                        error!("{}", e);
+                        None
+                    });
+                #[cfg(target_arch = "wasm32")]
+                let object_definition_merged: Option<Value> = {
+                    let _ = overridden_asset_paths;
+                    None
+                };
 
                 match object_type {
                     ObjectType::Character => {
-                        let character_definition_handle = loader.load(
-                            object_definition_path,
-                            YamlFormat,
+                        let character_definition_handle = Self::load_object_definition(
+                            loader,
+                            &object_definition_file,
+                            object_definition_merged,
                             &mut *progress_counter,
                             character_definition_assets,
                         );
@@ -86,25 +111,38 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
                             .insert(asset_id, character_definition_handle);
                     }
                     ObjectType::Energy => {
-                        let energy_definition_handle = loader.load(
-                            object_definition_path,
-                            YamlFormat,
+                        let energy_definition_handle = Self::load_object_definition(
+                            loader,
+                            &object_definition_file,
+                            object_definition_merged,
                             &mut *progress_counter,
                             energy_definition_assets,
                         );
 
                         asset_energy_definition_handle.insert(asset_id, energy_definition_handle);
                     }
+                    ObjectType::Item => {
+                        let item_definition_handle = Self::load_object_definition(
+                            loader,
+                            &object_definition_file,
+                            object_definition_merged,
+                            &mut *progress_counter,
+                            item_definition_assets,
+                        );
+
+                        asset_item_definition_handle.insert(asset_id, item_definition_handle);
+                    }
                     ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
                 }
             }
             AssetType::Map => {
+                let map_definition_file = DefinitionFile::find_or_default(asset_path, "map");
                 let map_definition_handle = loader.load(
-                    asset_path
-                        .join("map.yaml")
+                    map_definition_file
+                        .path
                         .to_str()
                         .expect("Expected path to be valid unicode."),
-                    YamlFormat,
+                    DefinitionFormat::new(map_definition_file.format),
                     &mut *progress_counter,
                     map_definition_assets,
                 );
@@ -112,21 +150,16 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
                 asset_map_definition_handle.insert(asset_id, map_definition_handle);
             }
             AssetType::Ui => {
-                // Load `background.yaml` if it exists, don't error if not.
-                let background_definition_path = asset_path.join("background.yaml");
-
-                #[cfg(not(target_arch = "wasm32"))]
-                let background_definition_path_exists = background_definition_path.exists();
-                #[cfg(target_arch = "wasm32")]
-                let background_definition_path_exists =
-                    background_definition_path.exists_on_server();
-
-                if background_definition_path_exists {
+                // Load the background definition if it exists, don't error if not.
+                if let Some(background_definition_file) =
+                    DefinitionFile::find(asset_path, "background")
+                {
                     let background_definition_handle = loader.load(
-                        background_definition_path
+                        background_definition_file
+                            .path
                             .to_str()
                             .expect("Expected path to be valid unicode."),
-                        YamlFormat,
+                        DefinitionFormat::new(background_definition_file.format),
                         &mut *progress_counter,
                         background_definition_assets,
                     );
@@ -135,20 +168,14 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
                         .insert(asset_id, background_definition_handle);
                 }
 
-                // Load `ui.yaml` if it exists, don't error if not.
-                let ui_definition_path = asset_path.join("ui.yaml");
-
-                #[cfg(not(target_arch = "wasm32"))]
-                let ui_definition_path_exists = ui_definition_path.exists();
-                #[cfg(target_arch = "wasm32")]
-                let ui_definition_path_exists = ui_definition_path.exists_on_server();
-
-                if ui_definition_path_exists {
+                // Load the UI definition if it exists, don't error if not.
+                if let Some(ui_definition_file) = DefinitionFile::find(asset_path, "ui") {
                     let ui_definition_handle = loader.load(
-                        ui_definition_path
+                        ui_definition_file
+                            .path
                             .to_str()
                             .expect("Expected path to be valid unicode."),
-                        YamlFormat,
+                        DefinitionFormat::new(ui_definition_file.format),
                         &mut *progress_counter,
                         ui_definition_assets,
                     );
@@ -167,14 +194,17 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         DefinitionLoadingResources {
             character_definition_assets,
             energy_definition_assets,
+            item_definition_assets,
             map_definition_assets,
             background_definition_assets,
             ui_definition_assets,
             asset_character_definition_handle,
             asset_energy_definition_handle,
+            asset_item_definition_handle,
             asset_map_definition_handle,
             asset_background_definition_handle,
             asset_ui_definition_handle,
+            ..
         }: &DefinitionLoadingResources<'_>,
         asset_id: AssetId,
     ) -> bool {
@@ -196,6 +226,12 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
                         energy_definition_assets.get(character_definition_handle)
                     })
                     .is_some(),
+                ObjectType::Item => asset_item_definition_handle
+                    .get(asset_id)
+                    .and_then(|item_definition_handle| {
+                        item_definition_assets.get(item_definition_handle)
+                    })
+                    .is_some(),
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => asset_map_definition_handle
@@ -223,3 +259,47 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         }
     }
 }
+
+impl AssetDefinitionLoader {
+    /// Loads an object definition, using the merged definition if it extends another.
+    ///
+    /// If the merged definition is invalid, the error is logged and the definition file is loaded
+    /// as is, so that the failure is reported through the progress counter.
+    fn load_object_definition<D>(
+        loader: &Loader,
+        object_definition_file: &DefinitionFile,
+        object_definition_merged: Option<Value>,
+        progress_counter: &mut ProgressCounter,
+        definition_assets: &AssetStorage<D>,
+    ) -> Handle<D>
+    where
+        D: Asset,
+        D::Data: DeserializeOwned + Send + Sync + 'static,
+    {
+        let object_definition = object_definition_merged.and_then(|object_definition_merged| {
+            serde_yaml::from_value::<D::Data>(object_definition_merged)
+                .map_err(|e| {
+                    error!(
+                        "Failed to deserialize merged definition for `{}`: {}",
+                        object_definition_file.path.display(),
+                        e
+                    )
+                })
+                .ok()
+        });
+
+        if let Some(object_definition) = object_definition {
+            loader.load_from_data(object_definition, progress_counter, definition_assets)
+        } else {
+            loader.load(
+                object_definition_file
+                    .path
+                    .to_str()
+                    .expect("Expected path to be valid unicode."),
+                DefinitionFormat::new(object_definition_file.format),
+                progress_counter,
+                definition_assets,
+            )
+        }
+    }
+}
@@ -1,21 +1,30 @@
 use std::path::PathBuf;
 
 use amethyst::{
-    ecs::{System, World, Write},
+    ecs::{Read, System, World, Write},
     shred::{ResourceId, SystemData},
 };
-use asset_loading::AssetDiscovery;
+use asset_loading::{AssetDiscovery, AssetOverrider, NamespaceResolution};
 use asset_model::{
     config::AssetIndex,
-    loaded::{AssetId, AssetIdMappings, AssetTypeMappings},
+    loaded::{
+        AssetId, AssetIdMappings, AssetTypeMappings, NamespaceManifests, OverriddenAssetPaths,
+        OverrideNamespaces,
+    },
 };
 use derivative::Derivative;
 use derive_new::new;
-use loading_model::loaded::{AssetLoadStage, LoadStage};
-use log::debug;
+use loading_model::loaded::{AssetLoadStage, DeferredAssetTypes, LoadStage};
+use log::{debug, error};
 use slotmap::SecondaryMap;
 
 /// Discovers assets and writes to `Option<AssetIndex>`.
+///
+/// Namespaces are loaded in dependency order. Namespaces with unmet dependencies are not loaded.
+///
+/// Assets overridden by namespaces in the `OverrideNamespaces` resource are replaced.
+///
+/// Assets whose type is in the `DeferredAssetTypes` resource are not loaded until requested.
 #[derive(Debug, Default, new)]
 pub struct AssetDiscoverySystem {
     /// Path to the assets directory.
@@ -38,9 +47,21 @@ pub struct AssetDiscoverySystemData<'s> {
     /// `AssetLoadStage` resource.
     #[derivative(Debug = "ignore")]
     pub asset_load_stage: Write<'s, AssetLoadStage>,
+    /// `DeferredAssetTypes` resource.
+    #[derivative(Debug = "ignore")]
+    pub deferred_asset_types: Read<'s, DeferredAssetTypes>,
     /// `SecondaryMap<AssetId, PathBuf>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_id_to_path: Write<'s, SecondaryMap<AssetId, PathBuf>>,
+    /// `NamespaceManifests` resource.
+    #[derivative(Debug = "ignore")]
+    pub namespace_manifests: Write<'s, NamespaceManifests>,
+    /// `OverrideNamespaces` resource.
+    #[derivative(Debug = "ignore")]
+    pub override_namespaces: Read<'s, OverrideNamespaces>,
+    /// `OverriddenAssetPaths` resource.
+    #[derivative(Debug = "ignore")]
+    pub overridden_asset_paths: Write<'s, OverriddenAssetPaths>,
 }
 
 impl<'s> System<'s> for AssetDiscoverySystem {
@@ -53,13 +74,39 @@ impl<'s> System<'s> for AssetDiscoverySystem {
             mut asset_id_mappings,
             mut asset_type_mappings,
             mut asset_load_stage,
+            deferred_asset_types,
             mut asset_id_to_path,
+            mut namespace_manifests,
+            override_namespaces,
+            mut overridden_asset_paths,
         }: Self::SystemData,
     ) {
         // TODO: Do a diff between existing index and directory based on a file watch / notify.
         // TODO: See <https://github.com/polachok/derive-diff>
         if asset_index.is_none() {
-            let asset_index_discovered = AssetDiscovery::asset_index(&self.assets_dir);
+            let NamespaceResolution {
+                namespace_directories,
+                errors,
+            } = AssetDiscovery::namespaces(&self.assets_dir);
+            errors.iter().for_each(|e| error!("{}", e));
+
+            namespace_manifests.clear();
+            namespace_directories
+                .iter()
+                .for_each(|namespace_directory| {
+                    if let Some(manifest) = namespace_directory.manifest.as_ref() {
+                        namespace_manifests
+                            .insert(namespace_directory.namespace.clone(), manifest.clone());
+                    }
+                });
+
+            let mut asset_index_discovered =
+                AssetDiscovery::asset_index_namespaces(&namespace_directories);
+            *overridden_asset_paths = AssetOverrider::apply(
+                &mut asset_index_discovered,
+                &namespace_directories,
+                &override_namespaces,
+            );
             debug!("Indexed assets: {:?}", &asset_index_discovered);
 
             let capacity = asset_index_discovered
@@ -83,8 +130,14 @@ impl<'s> System<'s> for AssetDiscoverySystem {
                         asset_id, &asset_record.asset_slug, asset_type
                     );
 
+                    let load_stage = if deferred_asset_types.contains(&asset_type) {
+                        LoadStage::Deferred
+                    } else {
+                        LoadStage::New
+                    };
+
                     asset_type_mappings.insert(asset_id, asset_type);
-                    asset_load_stage.insert(asset_id, LoadStage::New);
+                    asset_load_stage.insert(asset_id, load_stage);
                     asset_id_to_path.insert(asset_id, asset_record.path.clone());
                 });
 
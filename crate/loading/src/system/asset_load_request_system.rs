@@ -0,0 +1,107 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetTypeMappings;
+use derivative::Derivative;
+use derive_new::new;
+use loading_model::{
+    loaded::{AssetLoadStage, AssetLoadStatus, DeferredAssetTypes, LoadStage},
+    play::AssetLoadRequestEvent,
+};
+use log::debug;
+
+/// Begins or cancels loading of deferred assets when requested.
+#[derive(Debug, Default, new)]
+pub struct AssetLoadRequestSystem {
+    /// Reader ID for the `AssetLoadRequestEvent` channel.
+    #[new(default)]
+    asset_load_request_event_rid: Option<ReaderId<AssetLoadRequestEvent>>,
+}
+
+/// `AssetLoadRequestSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AssetLoadRequestSystemData<'s> {
+    /// `AssetLoadRequestEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_request_ec: Read<'s, EventChannel<AssetLoadRequestEvent>>,
+    /// `AssetTypeMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_type_mappings: Read<'s, AssetTypeMappings>,
+    /// `DeferredAssetTypes` resource.
+    #[derivative(Debug = "ignore")]
+    pub deferred_asset_types: Read<'s, DeferredAssetTypes>,
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Write<'s, AssetLoadStage>,
+    /// `AssetLoadStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_status: Write<'s, AssetLoadStatus>,
+}
+
+impl<'s> System<'s> for AssetLoadRequestSystem {
+    type SystemData = AssetLoadRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AssetLoadRequestSystemData {
+            asset_load_request_ec,
+            asset_type_mappings,
+            deferred_asset_types,
+            mut asset_load_stage,
+            mut asset_load_status,
+        }: Self::SystemData,
+    ) {
+        let asset_load_request_event_rid = self
+            .asset_load_request_event_rid
+            .as_mut()
+            .expect("Expected `asset_load_request_event_rid` field to be set.");
+
+        asset_load_request_ec
+            .read(asset_load_request_event_rid)
+            .for_each(|ev| match *ev {
+                AssetLoadRequestEvent::Request(asset_id) => {
+                    if let Some(load_stage) = asset_load_stage.get_mut(asset_id) {
+                        if *load_stage == LoadStage::Deferred {
+                            debug!("Loading deferred asset: `{:?}`.", asset_id);
+
+                            *load_stage = LoadStage::New;
+                            asset_load_status.remove(asset_id);
+                        }
+                    }
+                }
+                AssetLoadRequestEvent::Cancel(asset_id) => {
+                    // Assets that are always loaded are never cancelled.
+                    let deferred_type = asset_type_mappings
+                        .get(asset_id)
+                        .map(|asset_type| deferred_asset_types.contains(asset_type))
+                        .unwrap_or(false);
+                    let loading = asset_load_stage
+                        .get(asset_id)
+                        .map(|load_stage| {
+                            *load_stage != LoadStage::Deferred && *load_stage != LoadStage::Complete
+                        })
+                        .unwrap_or(false);
+
+                    if deferred_type && loading {
+                        debug!("Cancelling loading of asset: `{:?}`.", asset_id);
+
+                        asset_load_stage.insert(asset_id, LoadStage::Deferred);
+                        asset_load_status.remove(asset_id);
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.asset_load_request_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<AssetLoadRequestEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use asset_fs::AssetFs;
+use asset_loading::DefinitionFile;
+use asset_model::loaded::{AssetId, AssetIdMappings};
+use derivative::Derivative;
+use derive_new::new;
+use loading_model::{
+    loaded::{AssetLoadStage, LoadStage},
+    play::{AssetMemory, AssetMemoryUsage},
+};
+use log::{debug, warn};
+use object_model::config::{GameObjectFrame, GameObjectSequence, ObjectDefinition};
+use serde::{Deserialize, Serialize};
+use slotmap::SecondaryMap;
+use sprite_model::config::{SpriteSheetDefinition, SpritesDefinition};
+
+use crate::DefinitionLoadingResourcesRead;
+
+/// Stems of definition files that may exist for an asset.
+const DEFINITION_STEMS: &[&str] = &["object", "sprites", "map", "background", "ui"];
+
+/// Bytes per pixel of decoded textures.
+const TEXTURE_BYTES_PER_PIXEL: u64 = 4;
+
+/// Estimates the memory used by each loaded asset.
+///
+/// Texture memory is the decoded size of each sprite sheet, and audio and definition memory are
+/// the sizes of the files.
+#[derive(Debug, new)]
+pub struct AssetMemorySystem {
+    /// Path to the assets directory, which object sounds are relative to.
+    assets_dir: PathBuf,
+}
+
+/// `AssetMemorySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AssetMemorySystemData<'s> {
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Read<'s, AssetLoadStage>,
+    /// `SecondaryMap<AssetId, PathBuf>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_to_path: Read<'s, SecondaryMap<AssetId, PathBuf>>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `DefinitionLoadingResourcesRead`.
+    pub definition_loading_resources_read: DefinitionLoadingResourcesRead<'s>,
+    /// `SpritesDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprites_definition_assets: Read<'s, AssetStorage<SpritesDefinition>>,
+    /// `SecondaryMap<AssetId, Handle<SpritesDefinition>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_definition_handles:
+        Read<'s, SecondaryMap<AssetId, Handle<SpritesDefinition>>>,
+    /// `AssetMemoryUsage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_memory_usage: Write<'s, AssetMemoryUsage>,
+}
+
+impl AssetMemorySystem {
+    /// Returns the decoded size of a sprite sheet's texture.
+    fn texture_bytes(sprite_sheet_definition: &SpriteSheetDefinition) -> u64 {
+        let SpriteSheetDefinition {
+            sprite_w,
+            sprite_h,
+            column_count,
+            row_count,
+            has_border,
+            ..
+        } = *sprite_sheet_definition;
+
+        // Borders are only between sprites.
+        let border = if has_border { 1 } else { 0 };
+        let image_w = u64::from(column_count) * u64::from(sprite_w + border);
+        let image_h = u64::from(row_count) * u64::from(sprite_h + border);
+        let image_w = image_w.saturating_sub(u64::from(border));
+        let image_h = image_h.saturating_sub(u64::from(border));
+
+        image_w * image_h * TEXTURE_BYTES_PER_PIXEL
+    }
+
+    /// Returns the size of the file, or `0` if it cannot be read.
+    fn file_bytes(path: &Path) -> u64 {
+        AssetFs::size(path).unwrap_or_else(|e| {
+            warn!("Failed to read size of `{}`: {}", path.display(), e);
+            0
+        })
+    }
+
+    /// Returns the total size of the distinct sounds used by an object.
+    fn audio_bytes<ObjSeq>(assets_dir: &Path, object_definition: &ObjectDefinition<ObjSeq>) -> u64
+    where
+        ObjSeq: GameObjectSequence,
+        ObjSeq::SequenceName: for<'de> Deserialize<'de> + Serialize,
+    {
+        object_definition
+            .sequences
+            .values()
+            .flat_map(|sequence| sequence.object_sequence().sequence.frames.iter())
+            .filter_map(|frame| frame.object_frame().sound.as_ref())
+            .collect::<HashSet<&PathBuf>>()
+            .into_iter()
+            .map(|sound_path| Self::file_bytes(&assets_dir.join(sound_path)))
+            .sum()
+    }
+
+    /// Returns the estimated memory used by an asset.
+    fn asset_memory(
+        &self,
+        asset_id: AssetId,
+        asset_path: &Path,
+        DefinitionLoadingResourcesRead {
+            character_definition_assets,
+            energy_definition_assets,
+            item_definition_assets,
+            asset_character_definition_handle,
+            asset_energy_definition_handle,
+            asset_item_definition_handle,
+            ..
+        }: &DefinitionLoadingResourcesRead<'_>,
+        sprites_definition: Option<&SpritesDefinition>,
+    ) -> AssetMemory {
+        let texture = sprites_definition
+            .map(|sprites_definition| {
+                sprites_definition
+                    .sheets
+                    .iter()
+                    .map(Self::texture_bytes)
+                    .sum()
+            })
+            .unwrap_or(0);
+
+        let assets_dir = &self.assets_dir;
+        let audio = asset_character_definition_handle
+            .get(asset_id)
+            .and_then(|handle| character_definition_assets.get(handle))
+            .map(|definition| Self::audio_bytes(assets_dir, &definition.object_definition))
+            .or_else(|| {
+                asset_energy_definition_handle
+                    .get(asset_id)
+                    .and_then(|handle| energy_definition_assets.get(handle))
+                    .map(|definition| Self::audio_bytes(assets_dir, &definition.object_definition))
+            })
+            .or_else(|| {
+                asset_item_definition_handle
+                    .get(asset_id)
+                    .and_then(|handle| item_definition_assets.get(handle))
+                    .map(|definition| Self::audio_bytes(assets_dir, &definition.object_definition))
+            })
+            .unwrap_or(0);
+
+        let definition = DEFINITION_STEMS
+            .iter()
+            .filter_map(|stem| DefinitionFile::find(asset_path, stem))
+            .map(|definition_file| Self::file_bytes(&definition_file.path))
+            .sum();
+
+        AssetMemory::new(texture, audio, definition)
+    }
+}
+
+impl<'s> System<'s> for AssetMemorySystem {
+    type SystemData = AssetMemorySystemData<'s>;
+
+    fn run(
+        &mut self,
+        AssetMemorySystemData {
+            asset_load_stage,
+            asset_id_to_path,
+            asset_id_mappings,
+            definition_loading_resources_read,
+            sprites_definition_assets,
+            asset_sprites_definition_handles,
+            mut asset_memory_usage,
+        }: Self::SystemData,
+    ) {
+        let is_complete = |asset_id| asset_load_stage.get(asset_id) == Some(&LoadStage::Complete);
+
+        // Assets that are unloaded or reloading are measured again once complete.
+        let asset_ids_outdated = asset_memory_usage
+            .assets
+            .keys()
+            .filter(|asset_id| !is_complete(*asset_id))
+            .collect::<Vec<AssetId>>();
+        let asset_ids_new = asset_load_stage
+            .keys()
+            .filter(|asset_id| {
+                is_complete(*asset_id) && !asset_memory_usage.assets.contains_key(*asset_id)
+            })
+            .collect::<Vec<AssetId>>();
+
+        if asset_ids_outdated.is_empty() && asset_ids_new.is_empty() {
+            return;
+        }
+
+        asset_ids_outdated.into_iter().for_each(|asset_id| {
+            asset_memory_usage.assets.remove(asset_id);
+        });
+        asset_ids_new.into_iter().for_each(|asset_id| {
+            let asset_memory = if let Some(asset_path) = asset_id_to_path.get(asset_id) {
+                let sprites_definition = asset_sprites_definition_handles
+                    .get(asset_id)
+                    .and_then(|handle| sprites_definition_assets.get(handle));
+                self.asset_memory(
+                    asset_id,
+                    asset_path,
+                    &definition_loading_resources_read,
+                    sprites_definition,
+                )
+            } else {
+                AssetMemory::default()
+            };
+            asset_memory_usage.assets.insert(asset_id, asset_memory);
+        });
+
+        asset_memory_usage
+            .namespaces(&asset_id_mappings)
+            .iter()
+            .for_each(|(namespace, asset_memory)| {
+                debug!(
+                    "Asset memory for `{}`: textures: {} B, audio: {} B, definitions: {} B",
+                    namespace, asset_memory.texture, asset_memory.audio, asset_memory.definition
+                );
+            });
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    collections::HashMap,
+    iter,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use asset_fs::AssetFs;
+use asset_loading::{DefinitionFile, ObjectDefinitionMerger};
+use asset_model::{
+    config::{AssetSlug, AssetType},
+    loaded::{AssetId, AssetIdMappings, AssetTypeMappings, OverriddenAssetPaths},
+    play::AssetReloadEvent,
+};
+use character_model::config::CharacterSequenceName;
+use derivative::Derivative;
+use derive_new::new;
+use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
+use loading_model::loaded::{AssetLoadStage, AssetLoadStatus, LoadStage, LoadStatus};
+use log::info;
+use sequence_model::{
+    config::SequenceName,
+    loaded::{AssetSequenceIdMappings, AssetSequenceIdRemaps, SequenceId},
+};
+use slotmap::SecondaryMap;
+
+use crate::IdMappingResourcesRead;
+
+/// Stems of definition files that are watched for each object asset.
+const OBJECT_DEFINITION_STEMS: &[&str] = &["object", "sprites"];
+
+/// Default number of ticks between checking definition files for changes.
+const CHECK_INTERVAL_DEFAULT: usize = 30;
+
+/// Reloads object assets when their definition files change.
+///
+/// Changed assets are sent back through the load stages, and an `AssetReloadEvent` is sent once
+/// they are complete so that entities in play can be updated. The sequence IDs of each reloaded
+/// asset before and after the reload are recorded in the `AssetSequenceIdRemaps` resource.
+///
+/// Assets are also reloaded when an asset they `extends` changes. Packed assets are reloaded when
+/// their asset pack changes.
+///
+/// Texture changes are handled by Amethyst's `HotReloadBundle`.
+#[derive(Debug, new)]
+pub struct AssetReloadSystem {
+    /// Number of ticks between checking definition files for changes.
+    check_interval: usize,
+    /// Ticks since the definition files were last checked.
+    #[new(default)]
+    ticks: usize,
+    /// Last known modification time of each asset's definition files.
+    #[new(default)]
+    asset_modified_times: SecondaryMap<AssetId, SystemTime>,
+    /// Assets that are being reloaded.
+    #[new(default)]
+    assets_reloading: Vec<AssetId>,
+    /// Character sequence ID mappings of assets being reloaded, from before the reload.
+    #[new(default)]
+    sequence_id_mappings_character: AssetSequenceIdMappings<CharacterSequenceName>,
+    /// Energy sequence ID mappings of assets being reloaded, from before the reload.
+    #[new(default)]
+    sequence_id_mappings_energy: AssetSequenceIdMappings<EnergySequenceName>,
+    /// Item sequence ID mappings of assets being reloaded, from before the reload.
+    #[new(default)]
+    sequence_id_mappings_item: AssetSequenceIdMappings<ItemSequenceName>,
+}
+
+/// `AssetReloadSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AssetReloadSystemData<'s> {
+    /// `SecondaryMap<AssetId, PathBuf>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_to_path: Read<'s, SecondaryMap<AssetId, PathBuf>>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `AssetTypeMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_type_mappings: Read<'s, AssetTypeMappings>,
+    /// `OverriddenAssetPaths` resource.
+    #[derivative(Debug = "ignore")]
+    pub overridden_asset_paths: Read<'s, OverriddenAssetPaths>,
+    /// `IdMappingResourcesRead`.
+    pub id_mapping_resources_read: IdMappingResourcesRead<'s>,
+    /// `AssetSequenceIdRemaps` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_remaps: Write<'s, AssetSequenceIdRemaps>,
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Write<'s, AssetLoadStage>,
+    /// `AssetLoadStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_status: Write<'s, AssetLoadStatus>,
+    /// Event channel for `AssetReloadEvent`s.
+    #[derivative(Debug = "ignore")]
+    pub asset_reload_ec: Write<'s, EventChannel<AssetReloadEvent>>,
+}
+
+impl Default for AssetReloadSystem {
+    fn default() -> Self {
+        AssetReloadSystem::new(CHECK_INTERVAL_DEFAULT)
+    }
+}
+
+impl AssetReloadSystem {
+    /// Returns the latest modification time of an object asset's definition files, including
+    /// those of the assets it extends.
+    fn modified_time<F>(asset_path: &Path, fn_asset_dir: F) -> Option<SystemTime>
+    where
+        F: Fn(&AssetSlug) -> Option<PathBuf>,
+    {
+        // Errors in the `extends` chain are reported when the asset is loaded.
+        let object_definition_path = DefinitionFile::find_or_default(asset_path, "object").path;
+        let ancestor_dirs =
+            ObjectDefinitionMerger::ancestor_dirs(&object_definition_path, fn_asset_dir)
+                .unwrap_or_default();
+
+        iter::once(asset_path)
+            .chain(ancestor_dirs.iter().map(PathBuf::as_path))
+            .flat_map(|asset_dir| {
+                OBJECT_DEFINITION_STEMS
+                    .iter()
+                    .flat_map(move |stem| DefinitionFile::candidate_paths(asset_dir, stem))
+            })
+            .filter(|definition_path| AssetFs::exists(definition_path))
+            .filter_map(|definition_path| AssetFs::modified(&definition_path).ok())
+            .max()
+    }
+
+    /// Stores the sequence ID mappings of an asset before it is reloaded.
+    fn sequence_id_mappings_store<SeqName>(
+        sequence_id_mappings_previous: &mut AssetSequenceIdMappings<SeqName>,
+        asset_sequence_id_mappings: &AssetSequenceIdMappings<SeqName>,
+        asset_id: AssetId,
+    ) where
+        SeqName: SequenceName,
+    {
+        if let Some(sequence_id_mappings) = asset_sequence_id_mappings.get(asset_id) {
+            sequence_id_mappings_previous.insert(asset_id, sequence_id_mappings.clone());
+        }
+    }
+
+    /// Returns the sequence IDs of an asset after it is reloaded, for its IDs before the reload.
+    fn sequence_id_remap<SeqName>(
+        sequence_id_mappings_previous: &mut AssetSequenceIdMappings<SeqName>,
+        asset_sequence_id_mappings: &AssetSequenceIdMappings<SeqName>,
+        asset_id: AssetId,
+    ) -> Option<HashMap<SequenceId, SequenceId>>
+    where
+        SeqName: SequenceName,
+    {
+        let sequence_id_mappings_previous = sequence_id_mappings_previous.remove(asset_id)?;
+        asset_sequence_id_mappings
+            .get(asset_id)
+            .map(|sequence_id_mappings| sequence_id_mappings_previous.remap(sequence_id_mappings))
+    }
+}
+
+impl<'s> System<'s> for AssetReloadSystem {
+    type SystemData = AssetReloadSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AssetReloadSystemData {
+            asset_id_to_path,
+            asset_id_mappings,
+            asset_type_mappings,
+            overridden_asset_paths,
+            id_mapping_resources_read:
+                IdMappingResourcesRead {
+                    asset_sequence_id_mappings_character,
+                    asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_item,
+                    ..
+                },
+            mut asset_sequence_id_remaps,
+            mut asset_load_stage,
+            mut asset_load_status,
+            mut asset_reload_ec,
+        }: Self::SystemData,
+    ) {
+        // Notify when reloaded assets have gone through all load stages.
+        let (assets_reloaded, assets_reloading) = self
+            .assets_reloading
+            .drain(..)
+            .partition::<Vec<_>, _>(|asset_id| {
+                asset_load_stage.get(*asset_id).copied() == Some(LoadStage::Complete)
+            });
+        self.assets_reloading = assets_reloading;
+        assets_reloaded.into_iter().for_each(|asset_id| {
+            let sequence_id_remap = Self::sequence_id_remap(
+                &mut self.sequence_id_mappings_character,
+                &asset_sequence_id_mappings_character,
+                asset_id,
+            )
+            .or_else(|| {
+                Self::sequence_id_remap(
+                    &mut self.sequence_id_mappings_energy,
+                    &asset_sequence_id_mappings_energy,
+                    asset_id,
+                )
+            })
+            .or_else(|| {
+                Self::sequence_id_remap(
+                    &mut self.sequence_id_mappings_item,
+                    &asset_sequence_id_mappings_item,
+                    asset_id,
+                )
+            });
+            if let Some(sequence_id_remap) = sequence_id_remap {
+                asset_sequence_id_remaps.insert(asset_id, sequence_id_remap);
+            } else {
+                asset_sequence_id_remaps.remove(asset_id);
+            }
+
+            asset_reload_ec.single_write(AssetReloadEvent::Complete { asset_id });
+        });
+
+        self.ticks += 1;
+        if self.ticks < self.check_interval {
+            return;
+        }
+        self.ticks = 0;
+
+        let fn_asset_dir = |asset_slug: &AssetSlug| {
+            overridden_asset_paths.get(asset_slug).cloned().or_else(|| {
+                asset_id_mappings
+                    .id(asset_slug)
+                    .and_then(|asset_id| asset_id_to_path.get(*asset_id))
+                    .cloned()
+            })
+        };
+        let asset_modified_times = &mut self.asset_modified_times;
+        let assets_reloading = &mut self.assets_reloading;
+        let sequence_id_mappings_character = &mut self.sequence_id_mappings_character;
+        let sequence_id_mappings_energy = &mut self.sequence_id_mappings_energy;
+        let sequence_id_mappings_item = &mut self.sequence_id_mappings_item;
+        asset_load_stage
+            .iter_mut()
+            .filter(|(asset_id, load_stage)| {
+                let is_object =
+                    if let Some(AssetType::Object(_)) = asset_type_mappings.get(*asset_id) {
+                        true
+                    } else {
+                        false
+                    };
+
+                is_object && **load_stage == LoadStage::Complete
+            })
+            .for_each(|(asset_id, load_stage)| {
+                let modified_time = asset_id_to_path
+                    .get(asset_id)
+                    .and_then(|asset_path| Self::modified_time(asset_path, &fn_asset_dir));
+                let modified_time = if let Some(modified_time) = modified_time {
+                    modified_time
+                } else {
+                    return;
+                };
+
+                let modified_time_previous = asset_modified_times.insert(asset_id, modified_time);
+                let changed = modified_time_previous
+                    .map(|modified_time_previous| modified_time != modified_time_previous)
+                    .unwrap_or(false);
+
+                if changed {
+                    if let Some(asset_slug) = asset_id_mappings.slug(asset_id) {
+                        info!("Reloading `{}`.", asset_slug);
+                    }
+
+                    Self::sequence_id_mappings_store(
+                        sequence_id_mappings_character,
+                        &asset_sequence_id_mappings_character,
+                        asset_id,
+                    );
+                    Self::sequence_id_mappings_store(
+                        sequence_id_mappings_energy,
+                        &asset_sequence_id_mappings_energy,
+                        asset_id,
+                    );
+                    Self::sequence_id_mappings_store(
+                        sequence_id_mappings_item,
+                        &asset_sequence_id_mappings_item,
+                        asset_id,
+                    );
+
+                    *load_stage = LoadStage::AssetDefinitionLoading;
+                    asset_load_status.insert(asset_id, LoadStatus::Queued);
+                    assets_reloading.push(asset_id);
+                }
+            });
+    }
+}
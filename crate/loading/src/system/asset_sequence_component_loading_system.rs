@@ -14,13 +14,15 @@ use asset_model::{config::AssetType, loaded::AssetId};
 use audio_model::loaded::SourceSequenceHandles;
 use character_model::loaded::CharacterIrsHandles;
 use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
+use frame_event_model::loaded::FrameEventsSequenceHandles;
 use kinematic_model::loaded::ObjectAccelerationSequenceHandles;
 use loading_model::loaded::LoadStage;
 use log::debug;
 use sequence_model::loaded::WaitSequenceHandles;
 use spawn_model::loaded::SpawnsSequenceHandles;
 use sprite_model::loaded::{
-    ScaleSequenceHandles, SpriteRenderSequenceHandles, TintSequenceHandles,
+    InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+    SpriteRenderSequenceHandles, TintSequenceHandles,
 };
 
 use crate::{
@@ -113,9 +115,12 @@ impl<'s> AssetPartLoader<'s> for AssetSequenceComponentLoader {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            frame_events_sequence_assets,
             character_irs_assets,
             tint_sequence_assets,
             scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             ..
         }: &SequenceComponentLoadingResources<'_>,
         asset_id: AssetId,
@@ -168,8 +173,17 @@ impl<'s> AssetPartLoader<'s> for AssetSequenceComponentLoader {
             && sequence_component_loaded!(BodySequenceHandles, body_sequence_assets)
             && sequence_component_loaded!(InteractionsSequenceHandles, interactions_sequence_assets)
             && sequence_component_loaded!(SpawnsSequenceHandles, spawns_sequence_assets)
+            && sequence_component_loaded!(FrameEventsSequenceHandles, frame_events_sequence_assets)
             && sequence_component_loaded!(CharacterIrsHandles, character_irs_assets)
             && sequence_component_loaded!(TintSequenceHandles, tint_sequence_assets)
             && sequence_component_loaded!(ScaleSequenceHandles, scale_sequence_assets)
+            && sequence_component_loaded!(
+                SpriteOffsetSequenceHandles,
+                sprite_offset_sequence_assets
+            )
+            && sequence_component_loaded!(
+                InterpolationSequenceHandles,
+                interpolation_sequence_assets
+            )
     }
 }
@@ -1,13 +1,15 @@
 use amethyst::ecs::{Builder, WorldExt};
 use asset_model::loaded::{AssetId, ItemId, ItemIds};
 use kinematic_loading::PositionInitsLoader;
-use map_model::loaded::Margins;
+use map_model::loaded::{Margins, Surfaces};
 use sequence_loading::{
     SequenceEndTransitionsLoader, SequenceIdMapper, WaitSequenceHandlesLoader, WaitSequenceLoader,
 };
 use sprite_loading::{
-    ScaleSequenceHandlesLoader, ScaleSequenceLoader, SpriteRenderSequenceHandlesLoader,
-    SpriteRenderSequenceLoader, TintSequenceHandlesLoader, TintSequenceLoader,
+    InterpolationSequenceHandlesLoader, InterpolationSequenceLoader, ScaleSequenceHandlesLoader,
+    ScaleSequenceLoader, SpriteOffsetSequenceHandlesLoader, SpriteOffsetSequenceLoader,
+    SpriteRenderSequenceHandlesLoader, SpriteRenderSequenceLoader, TintSequenceHandlesLoader,
+    TintSequenceLoader,
 };
 use sprite_model::config::SpriteSequenceName;
 
@@ -47,8 +49,12 @@ impl AssetSequenceComponentLoaderMap {
             sprite_render_sequence_assets,
             tint_sequence_assets,
             scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             asset_map_bounds,
             asset_margins,
+            asset_spawn_points,
+            asset_surfaces,
             ..
         }: &mut SequenceComponentLoadingResources<'_>,
         asset_id: AssetId,
@@ -83,9 +89,23 @@ impl AssetSequenceComponentLoaderMap {
             loader,
             scale_sequence_assets,
         };
+        let sprite_offset_sequence_loader = SpriteOffsetSequenceLoader {
+            loader,
+            sprite_offset_sequence_assets,
+        };
+        let interpolation_sequence_loader = InterpolationSequenceLoader {
+            loader,
+            interpolation_sequence_assets,
+        };
         let scale_sequence_handles_loader = ScaleSequenceHandlesLoader {
             scale_sequence_loader,
         };
+        let sprite_offset_sequence_handles_loader = SpriteOffsetSequenceHandlesLoader {
+            sprite_offset_sequence_loader,
+        };
+        let interpolation_sequence_handles_loader = InterpolationSequenceHandlesLoader {
+            interpolation_sequence_loader,
+        };
         let sprite_render_sequence_loader = SpriteRenderSequenceLoader {
             loader,
             sprite_render_sequence_assets,
@@ -140,6 +160,14 @@ impl AssetSequenceComponentLoaderMap {
             .items_to_datas(background_definition.layers.values(), |layer| {
                 layer.sequence.frames.iter()
             });
+        let sprite_offset_sequence_handles = sprite_offset_sequence_handles_loader
+            .items_to_datas(background_definition.layers.values(), |layer| {
+                layer.sequence.frames.iter()
+            });
+        let interpolation_sequence_handles = interpolation_sequence_handles_loader
+            .items_to_datas(background_definition.layers.values(), |layer| {
+                layer.sequence.frames.iter()
+            });
         let sprite_render_sequence_handles = sprite_sheet_handles.map(|sprite_sheet_handles| {
             sprite_render_sequence_handles_loader.items_to_datas(
                 background_definition.layers.values(),
@@ -160,7 +188,9 @@ impl AssetSequenceComponentLoaderMap {
                     .with(sequence_end_transitions.clone())
                     .with(wait_sequence_handles.clone())
                     .with(tint_sequence_handles.clone())
-                    .with(scale_sequence_handles.clone());
+                    .with(scale_sequence_handles.clone())
+                    .with(sprite_offset_sequence_handles.clone())
+                    .with(interpolation_sequence_handles.clone());
 
                 if let Some(sprite_render_sequence_handles) = sprite_render_sequence_handles.clone()
                 {
@@ -179,6 +209,11 @@ impl AssetSequenceComponentLoaderMap {
         asset_map_bounds.insert(asset_id, map_bounds);
 
         let margins = Margins::from(map_bounds);
+        let surfaces = Surfaces::from_platforms(&margins, &map_definition.header.platforms);
         asset_margins.insert(asset_id, margins);
+        asset_surfaces.insert(asset_id, surfaces);
+
+        let spawn_points = map_definition.header.spawn_points.clone();
+        asset_spawn_points.insert(asset_id, spawn_points);
     }
 }
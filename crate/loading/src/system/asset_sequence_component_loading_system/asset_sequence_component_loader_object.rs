@@ -1,14 +1,32 @@
-use amethyst::ecs::{Builder, WorldExt};
-use asset_model::loaded::{AssetId, ItemId, ItemIds};
+use amethyst::ecs::{Builder, Component, Entity, WorldExt};
+use asset_model::{
+    loaded::{AssetId, ItemId, ItemIds},
+    play::AssetWorld,
+};
+use audio_loading::AudioLoader;
+use audio_model::loaded::SourceSequenceHandles;
 use character_loading::CHARACTER_INPUT_REACTIONS_DEFAULT;
 use character_model::{
-    config::{CharacterSequence, CharacterSequenceName},
-    loaded::{CharacterIrsHandle, CharacterIrsHandles},
+    config::{CharacterSequence, CharacterSequenceName, CharacterSequenceNameString},
+    loaded::{
+        CharacterChargeLevelNext, CharacterChargeLevelSfx, CharacterEndTransition,
+        CharacterEndTransitions, CharacterIrsHandle, CharacterIrsHandles,
+        CharacterSequenceChargeLevelNexts, CharacterSequenceEndTransitions,
+    },
 };
+use chase_model::loaded::{ChaseModeOpt, ChaseModes};
+use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
 use energy_model::config::{EnergySequence, EnergySequenceName};
+use frame_event_model::loaded::FrameEventsSequenceHandles;
 use input_reaction_loading::{IrsLoader, IrsLoaderParams};
+use item_loading::{HoldPointSequenceHandlesLoader, HoldPointSequenceLoader};
+use item_model::{
+    config::{ItemSequence, ItemSequenceName},
+    loaded::HoldPointSequenceHandles,
+};
 use kinematic_model::{
     config::{PositionInit, VelocityInit},
+    loaded::ObjectAccelerationSequenceHandles,
     play::PositionZAsY,
 };
 use log::warn;
@@ -16,7 +34,13 @@ use mirrored_model::play::Mirrored;
 use object_loading::{ObjectLoader, ObjectLoaderParams};
 use object_model::{loaded::Object, play::Grounding};
 use object_type::ObjectType;
-use sequence_model::loaded::SequenceId;
+use sequence_loading::SequenceIdMapper;
+use sequence_model::loaded::{SequenceEndTransitions, SequenceId, WaitSequenceHandles};
+use spawn_model::loaded::SpawnsSequenceHandles;
+use sprite_model::loaded::{
+    InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+    SpriteRenderSequenceHandles, TintSequenceHandles,
+};
 
 use crate::{
     AssetLoadingResources, DefinitionLoadingResourcesRead, IdMappingResourcesRead,
@@ -36,14 +60,17 @@ impl AssetSequenceComponentLoaderObject {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    item_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_item_definition_handle,
                     ..
                 },
             id_mapping_resources_read:
                 IdMappingResourcesRead {
                     asset_sequence_id_mappings_character,
                     asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_item,
                     ..
                 },
             texture_loading_resources_read:
@@ -64,8 +91,14 @@ impl AssetSequenceComponentLoaderObject {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            frame_events_sequence_assets,
+            tint_sequence_assets,
+            scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             character_input_reactions_assets,
             character_irs_assets,
+            hold_point_sequence_assets,
             ..
         }: &mut SequenceComponentLoadingResources<'_>,
         asset_id: AssetId,
@@ -82,7 +115,13 @@ impl AssetSequenceComponentLoaderObject {
             .slug(asset_id)
             .expect("Expected `AssetSlug` mapping to exist for `AssetId`.");
 
-        let mut item_entity_builder = asset_world.create_entity();
+        // Reuse the item entity when the asset is reloaded, so that entities in play keep a valid
+        // `ItemId`.
+        let item_entity = asset_item_ids
+            .get(asset_id)
+            .and_then(|item_ids| item_ids.first().copied())
+            .map(|item_id| item_id.0);
+        let mut item_entity_builder = ItemEntityBuilder::new(asset_world, item_entity);
 
         let sprite_sheet_handles = asset_sprite_sheet_handles
             .get(asset_id)
@@ -93,6 +132,7 @@ impl AssetSequenceComponentLoaderObject {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -101,6 +141,11 @@ impl AssetSequenceComponentLoaderObject {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            frame_events_sequence_assets,
+            tint_sequence_assets,
+            scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             body_assets,
             interactions_assets,
             spawns_assets,
@@ -163,7 +208,87 @@ impl AssetSequenceComponentLoaderObject {
                     CharacterIrsHandles::new(character_irs_handles)
                 };
 
-                item_entity_builder = item_entity_builder.with(character_irs_handles);
+                let hold_point_sequence_handles = {
+                    let hold_point_sequence_loader = HoldPointSequenceLoader {
+                        loader,
+                        hold_point_sequence_assets: &*hold_point_sequence_assets,
+                    };
+                    let hold_point_sequence_handles_loader = HoldPointSequenceHandlesLoader {
+                        hold_point_sequence_loader,
+                    };
+                    hold_point_sequence_handles_loader.items_to_datas(
+                        character_definition.object_definition.sequences.values(),
+                        |character_sequence| {
+                            character_sequence.object_sequence.sequence.frames.iter()
+                        },
+                    )
+                };
+
+                let character_sequence_end_transitions = {
+                    let character_end_transitionses = character_definition
+                        .object_definition
+                        .sequences
+                        .values()
+                        .map(|character_sequence| {
+                            let character_end_transitions = character_sequence
+                                .next_conditional
+                                .iter()
+                                .map(|character_end_transition| {
+                                    let sequence_id = SequenceIdMapper::item_to_data(
+                                        sequence_id_mappings,
+                                        asset_slug,
+                                        &character_end_transition.next,
+                                    );
+                                    CharacterEndTransition::new(
+                                        sequence_id,
+                                        character_end_transition.requirement.clone(),
+                                    )
+                                })
+                                .collect::<Vec<CharacterEndTransition>>();
+                            CharacterEndTransitions::new(character_end_transitions)
+                        })
+                        .collect::<Vec<CharacterEndTransitions>>();
+                    CharacterSequenceEndTransitions::new(character_end_transitionses)
+                };
+
+                let character_sequence_charge_level_nexts = {
+                    let character_charge_level_nexts = character_definition
+                        .object_definition
+                        .sequences
+                        .values()
+                        .map(|character_sequence| {
+                            let next_by_charge_level = &character_sequence.next_by_charge_level;
+                            let sequence_id = |next: &Option<CharacterSequenceNameString>| {
+                                next.as_ref().map(|next| {
+                                    SequenceIdMapper::item_to_data(
+                                        sequence_id_mappings,
+                                        asset_slug,
+                                        next,
+                                    )
+                                })
+                            };
+                            CharacterChargeLevelNext::new(
+                                sequence_id(&next_by_charge_level.level_1),
+                                sequence_id(&next_by_charge_level.level_2),
+                                sequence_id(&next_by_charge_level.level_3),
+                            )
+                        })
+                        .collect::<Vec<CharacterChargeLevelNext>>();
+                    CharacterSequenceChargeLevelNexts::new(character_charge_level_nexts)
+                };
+
+                item_entity_builder = item_entity_builder
+                    .with(character_irs_handles)
+                    .with(hold_point_sequence_handles)
+                    .with(character_sequence_end_transitions)
+                    .with(character_sequence_charge_level_nexts);
+
+                if let Some(charge_level_sound) = character_definition.charge_level_sound.as_ref() {
+                    let source_handle =
+                        AudioLoader::load(loader, source_assets, (), charge_level_sound);
+                    item_entity_builder =
+                        item_entity_builder.with(CharacterChargeLevelSfx::new(source_handle));
+                }
 
                 let object = ObjectLoader::load::<CharacterSequence>(
                     object_loader_params,
@@ -199,6 +324,18 @@ impl AssetSequenceComponentLoaderObject {
                         })
                 };
 
+                let chase_modes = {
+                    let chase_modes = energy_definition
+                        .object_definition
+                        .sequences
+                        .values()
+                        .map(|energy_sequence| ChaseModeOpt::new(energy_sequence.chase))
+                        .collect::<Vec<ChaseModeOpt>>();
+                    ChaseModes::new(chase_modes)
+                };
+
+                item_entity_builder = item_entity_builder.with(chase_modes);
+
                 let object = ObjectLoader::load::<EnergySequence>(
                     object_loader_params,
                     &energy_definition.object_definition,
@@ -206,6 +343,40 @@ impl AssetSequenceComponentLoaderObject {
 
                 (sequence_id_init, object)
             }
+            ObjectType::Item => {
+                let item_definition = asset_item_definition_handle
+                    .get(asset_id)
+                    .and_then(|item_definition_handle| {
+                        item_definition_assets.get(item_definition_handle)
+                    })
+                    .expect("Expected `ItemDefinition` to be loaded.");
+
+                let sequence_id_mappings = asset_sequence_id_mappings_item
+                    .get(asset_id)
+                    .expect("Expected `SequenceIdMapping` to be loaded.");
+                let sequence_id_init = {
+                    let sequence_name_default = ItemSequenceName::default();
+                    sequence_id_mappings
+                        .id_by_name(sequence_name_default)
+                        .copied()
+                        .unwrap_or_else(|| {
+                            warn!(
+                                "`{}` sequence ID not found for asset: `{}`. \
+                                 Falling back to first declared sequence.",
+                                sequence_name_default, asset_slug
+                            );
+
+                            SequenceId::new(0)
+                        })
+                };
+
+                let object = ObjectLoader::load::<ItemSequence>(
+                    object_loader_params,
+                    &item_definition.object_definition,
+                );
+
+                (sequence_id_init, object)
+            }
             ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
         };
         let Object {
@@ -217,6 +388,11 @@ impl AssetSequenceComponentLoaderObject {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            frame_events_sequence_handles,
+            tint_sequence_handles,
+            scale_sequence_handles,
+            sprite_offset_sequence_handles,
+            interpolation_sequence_handles,
         } = object;
 
         let item_id = {
@@ -235,6 +411,11 @@ impl AssetSequenceComponentLoaderObject {
                 .with(body_sequence_handles)
                 .with(interactions_sequence_handles)
                 .with(spawns_sequence_handles)
+                .with(frame_events_sequence_handles)
+                .with(tint_sequence_handles)
+                .with(scale_sequence_handles)
+                .with(sprite_offset_sequence_handles)
+                .with(interpolation_sequence_handles)
                 .build();
             ItemId::new(item_entity)
         };
@@ -243,3 +424,83 @@ impl AssetSequenceComponentLoaderObject {
         asset_item_ids.insert(asset_id, item_ids);
     }
 }
+
+/// Builds an object's item entity, reusing an existing item entity if there is one.
+#[derive(Debug)]
+struct ItemEntityBuilder<'w> {
+    /// `AssetWorld` that the item entity lives in.
+    asset_world: &'w AssetWorld,
+    /// The item entity.
+    item_entity: Entity,
+}
+
+impl<'w> ItemEntityBuilder<'w> {
+    /// Returns a builder for the given item entity, creating one if it is `None`.
+    ///
+    /// Item components are removed from an existing item entity, so that it does not keep
+    /// components that the reloaded definition no longer produces.
+    fn new(asset_world: &'w mut AssetWorld, item_entity: Option<Entity>) -> Self {
+        if let Some(item_entity) = item_entity {
+            Self::without_item_components(asset_world, item_entity);
+        }
+        let item_entity = item_entity.unwrap_or_else(|| asset_world.create_entity().build());
+
+        ItemEntityBuilder {
+            asset_world,
+            item_entity,
+        }
+    }
+
+    /// Removes the item components that objects may be built with.
+    fn without_item_components(asset_world: &AssetWorld, item_entity: Entity) {
+        fn remove<C>(asset_world: &AssetWorld, item_entity: Entity)
+        where
+            C: Component + Send + Sync,
+        {
+            asset_world.write_storage::<C>().remove(item_entity);
+        }
+
+        remove::<CharacterIrsHandles>(asset_world, item_entity);
+        remove::<HoldPointSequenceHandles>(asset_world, item_entity);
+        remove::<CharacterSequenceEndTransitions>(asset_world, item_entity);
+        remove::<CharacterSequenceChargeLevelNexts>(asset_world, item_entity);
+        remove::<CharacterChargeLevelSfx>(asset_world, item_entity);
+        remove::<ChaseModes>(asset_world, item_entity);
+        remove::<PositionInit>(asset_world, item_entity);
+        remove::<VelocityInit>(asset_world, item_entity);
+        remove::<PositionZAsY>(asset_world, item_entity);
+        remove::<Mirrored>(asset_world, item_entity);
+        remove::<Grounding>(asset_world, item_entity);
+        remove::<SequenceId>(asset_world, item_entity);
+        remove::<SequenceEndTransitions>(asset_world, item_entity);
+        remove::<WaitSequenceHandles>(asset_world, item_entity);
+        remove::<SourceSequenceHandles>(asset_world, item_entity);
+        remove::<ObjectAccelerationSequenceHandles>(asset_world, item_entity);
+        remove::<SpriteRenderSequenceHandles>(asset_world, item_entity);
+        remove::<BodySequenceHandles>(asset_world, item_entity);
+        remove::<InteractionsSequenceHandles>(asset_world, item_entity);
+        remove::<SpawnsSequenceHandles>(asset_world, item_entity);
+        remove::<FrameEventsSequenceHandles>(asset_world, item_entity);
+        remove::<TintSequenceHandles>(asset_world, item_entity);
+        remove::<ScaleSequenceHandles>(asset_world, item_entity);
+        remove::<SpriteOffsetSequenceHandles>(asset_world, item_entity);
+        remove::<InterpolationSequenceHandles>(asset_world, item_entity);
+    }
+
+    /// Inserts the item component, replacing any existing one.
+    fn with<C>(self, component: C) -> Self
+    where
+        C: Component + Send + Sync,
+    {
+        self.asset_world
+            .write_storage::<C>()
+            .insert(self.item_entity, component)
+            .expect("Failed to insert item component.");
+        self
+    }
+
+    /// Returns the item entity.
+    fn build(self) -> Entity {
+        self.item_entity
+    }
+}
@@ -22,12 +22,17 @@ use sequence_model::{
 };
 use smallvec::SmallVec;
 use sprite_loading::{
-    ScaleSequenceHandlesLoader, ScaleSequenceLoader, SpriteRenderSequenceHandlesLoader,
-    SpriteRenderSequenceLoader, TintSequenceHandlesLoader, TintSequenceLoader,
+    InterpolationSequenceHandlesLoader, InterpolationSequenceLoader, ScaleSequenceHandlesLoader,
+    ScaleSequenceLoader, SpriteOffsetSequenceHandlesLoader, SpriteOffsetSequenceLoader,
+    SpriteRenderSequenceHandlesLoader, SpriteRenderSequenceLoader, TintSequenceHandlesLoader,
+    TintSequenceLoader,
 };
 use sprite_model::{
-    config::{Scale, SpriteRef, SpriteSequenceName, Tint},
-    loaded::{ScaleSequenceHandles, SpriteRenderSequenceHandles, TintSequenceHandles},
+    config::{Interpolation, Scale, SpriteOffset, SpriteRef, SpriteSequenceName, Tint},
+    loaded::{
+        InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+        SpriteRenderSequenceHandles, TintSequenceHandles,
+    },
 };
 use state_registry::StateId;
 use ui_model::config::{UiDefinition, UiType};
@@ -83,6 +88,8 @@ impl AssetSequenceComponentLoaderUi {
             ref input_reactions_sequence_assets,
             ref tint_sequence_assets,
             ref scale_sequence_assets,
+            ref sprite_offset_sequence_assets,
+            ref interpolation_sequence_assets,
             ..
         } = sequence_component_loading_resources;
 
@@ -110,9 +117,23 @@ impl AssetSequenceComponentLoaderUi {
             loader,
             scale_sequence_assets,
         };
+        let sprite_offset_sequence_loader = SpriteOffsetSequenceLoader {
+            loader,
+            sprite_offset_sequence_assets,
+        };
+        let interpolation_sequence_loader = InterpolationSequenceLoader {
+            loader,
+            interpolation_sequence_assets,
+        };
         let scale_sequence_handles_loader = ScaleSequenceHandlesLoader {
             scale_sequence_loader,
         };
+        let sprite_offset_sequence_handles_loader = SpriteOffsetSequenceHandlesLoader {
+            sprite_offset_sequence_loader,
+        };
+        let interpolation_sequence_handles_loader = InterpolationSequenceHandlesLoader {
+            interpolation_sequence_loader,
+        };
         let sprite_render_sequence_loader = SpriteRenderSequenceLoader {
             loader,
             sprite_render_sequence_assets,
@@ -189,6 +210,14 @@ impl AssetSequenceComponentLoaderUi {
                 .items_to_datas(background_definition.layers.values(), |layer| {
                     layer.sequence.frames.iter()
                 });
+            let sprite_offset_sequence_handles = sprite_offset_sequence_handles_loader
+                .items_to_datas(background_definition.layers.values(), |layer| {
+                    layer.sequence.frames.iter()
+                });
+            let interpolation_sequence_handles = interpolation_sequence_handles_loader
+                .items_to_datas(background_definition.layers.values(), |layer| {
+                    layer.sequence.frames.iter()
+                });
             let sprite_render_sequence_handles = sprite_sheet_handles.map(|sprite_sheet_handles| {
                 sprite_render_sequence_handles_loader.items_to_datas(
                     background_definition.layers.values(),
@@ -210,7 +239,9 @@ impl AssetSequenceComponentLoaderUi {
                         .with(sequence_end_transitions.clone())
                         .with(wait_sequence_handles.clone())
                         .with(tint_sequence_handles.clone())
-                        .with(scale_sequence_handles.clone());
+                        .with(scale_sequence_handles.clone())
+                        .with(sprite_offset_sequence_handles.clone())
+                        .with(interpolation_sequence_handles.clone());
 
                     if let Some(sprite_render_sequence_handles) =
                         sprite_render_sequence_handles.clone()
@@ -254,6 +285,8 @@ impl AssetSequenceComponentLoaderUi {
                 wait_sequence_handles,
                 tint_sequence_handles,
                 scale_sequence_handles,
+                sprite_offset_sequence_handles,
+                interpolation_sequence_handles,
                 sprite_render_sequence_handles,
             ) = Self::sequence_components(
                 asset_loading_resources,
@@ -304,6 +337,8 @@ impl AssetSequenceComponentLoaderUi {
                             .with(wait_sequence_handles.clone())
                             .with(tint_sequence_handles.clone())
                             .with(scale_sequence_handles.clone())
+                            .with(sprite_offset_sequence_handles.clone())
+                            .with(interpolation_sequence_handles.clone())
                             .with(input_reactions_sequence_handles.clone())
                             .with(ButtonInputControlled)
                             .with(NormalInputControlled::new(index as u32));
@@ -332,6 +367,8 @@ impl AssetSequenceComponentLoaderUi {
                     wait_sequence_handles,
                     tint_sequence_handles,
                     scale_sequence_handles,
+                    sprite_offset_sequence_handles,
+                    interpolation_sequence_handles,
                     input_reactions_sequence_handles,
                     sprite_render_sequence_handles,
                 };
@@ -428,11 +465,18 @@ impl AssetSequenceComponentLoaderUi {
         WaitSequenceHandles,
         TintSequenceHandles,
         ScaleSequenceHandles,
+        SpriteOffsetSequenceHandles,
+        InterpolationSequenceHandles,
         Option<SpriteRenderSequenceHandles>,
     )
     where
         Seq: AsRef<Sequence<SpriteSequenceName, Frame>>,
-        Frame: AsRef<Wait> + AsRef<SpriteRef> + AsRef<Tint> + AsRef<Scale>,
+        Frame: AsRef<Wait>
+            + AsRef<SpriteRef>
+            + AsRef<Tint>
+            + AsRef<Scale>
+            + AsRef<SpriteOffset>
+            + AsRef<Interpolation>,
     {
         let AssetLoadingResources {
             asset_id_mappings,
@@ -454,6 +498,8 @@ impl AssetSequenceComponentLoaderUi {
             sprite_render_sequence_assets,
             tint_sequence_assets,
             scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             ..
         } = sequence_component_loading_resources;
 
@@ -481,9 +527,23 @@ impl AssetSequenceComponentLoaderUi {
             loader,
             scale_sequence_assets,
         };
+        let sprite_offset_sequence_loader = SpriteOffsetSequenceLoader {
+            loader,
+            sprite_offset_sequence_assets,
+        };
+        let interpolation_sequence_loader = InterpolationSequenceLoader {
+            loader,
+            interpolation_sequence_assets,
+        };
         let scale_sequence_handles_loader = ScaleSequenceHandlesLoader {
             scale_sequence_loader,
         };
+        let sprite_offset_sequence_handles_loader = SpriteOffsetSequenceHandlesLoader {
+            sprite_offset_sequence_loader,
+        };
+        let interpolation_sequence_handles_loader = InterpolationSequenceHandlesLoader {
+            interpolation_sequence_loader,
+        };
         let sprite_render_sequence_loader = SpriteRenderSequenceLoader {
             loader,
             sprite_render_sequence_assets,
@@ -525,6 +585,18 @@ impl AssetSequenceComponentLoaderUi {
                     .frames
                     .iter()
             });
+        let sprite_offset_sequence_handles =
+            sprite_offset_sequence_handles_loader.items_to_datas(sequences.values(), |seq| {
+                AsRef::<Sequence<SpriteSequenceName, Frame>>::as_ref(seq)
+                    .frames
+                    .iter()
+            });
+        let interpolation_sequence_handles =
+            interpolation_sequence_handles_loader.items_to_datas(sequences.values(), |seq| {
+                AsRef::<Sequence<SpriteSequenceName, Frame>>::as_ref(seq)
+                    .frames
+                    .iter()
+            });
         let sprite_render_sequence_handles = sprite_sheet_handles.map(|sprite_sheet_handles| {
             sprite_render_sequence_handles_loader.items_to_datas(
                 sequences.values(),
@@ -542,6 +614,8 @@ impl AssetSequenceComponentLoaderUi {
             wait_sequence_handles,
             tint_sequence_handles,
             scale_sequence_handles,
+            sprite_offset_sequence_handles,
+            interpolation_sequence_handles,
             sprite_render_sequence_handles,
         )
     }
@@ -631,6 +705,8 @@ impl AssetSequenceComponentLoaderUi {
                         wait_sequence_handles,
                         tint_sequence_handles,
                         scale_sequence_handles,
+                        sprite_offset_sequence_handles,
+                        interpolation_sequence_handles,
                         sprite_render_sequence_handles,
                     ) = Self::sequence_components(
                         asset_loading_resources,
@@ -664,6 +740,8 @@ impl AssetSequenceComponentLoaderUi {
                             wait_sequence_handles,
                             tint_sequence_handles,
                             scale_sequence_handles,
+                            sprite_offset_sequence_handles,
+                            interpolation_sequence_handles,
                             sprite_render_sequence_handles,
                             input_reactions_sequence_handles,
                         };
@@ -724,6 +802,8 @@ impl AssetSequenceComponentLoaderUi {
                         wait_sequence_handles,
                         tint_sequence_handles,
                         scale_sequence_handles,
+                        sprite_offset_sequence_handles,
+                        interpolation_sequence_handles,
                         input_reactions_sequence_handles,
                         sprite_render_sequence_handles,
                     } = asset_sequence_component_loader_ui_components.clone();
@@ -736,6 +816,8 @@ impl AssetSequenceComponentLoaderUi {
                         .with(wait_sequence_handles)
                         .with(tint_sequence_handles)
                         .with(scale_sequence_handles)
+                        .with(sprite_offset_sequence_handles)
+                        .with(interpolation_sequence_handles)
                         .with(input_reactions_sequence_handles)
                         .with(ButtonInputControlled);
 
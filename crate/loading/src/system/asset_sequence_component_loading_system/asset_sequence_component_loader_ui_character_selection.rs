@@ -24,7 +24,7 @@ use character_selection_ui_model::config::{
 use chase_model::play::ChaseModeStick;
 use game_input_model::play::InputControlled;
 use kinematic_loading::PositionInitsLoader;
-use kinematic_model::config::{Position, PositionInit};
+use kinematic_model::config::{FixedPoint, Position, PositionInit};
 use object_type::ObjectType;
 use sequence_loading::SequenceIdMapper;
 use sequence_model::{config::SequenceNameString, loaded::SequenceIdMappings};
@@ -99,6 +99,8 @@ impl AssetSequenceComponentLoaderUiCharacterSelection {
                             wait_sequence_handles,
                             tint_sequence_handles,
                             scale_sequence_handles,
+                            sprite_offset_sequence_handles,
+                            interpolation_sequence_handles,
                             input_reactions_sequence_handles,
                             sprite_render_sequence_handles,
                         } = asset_sequence_component_loader_ui_components.clone();
@@ -111,6 +113,8 @@ impl AssetSequenceComponentLoaderUiCharacterSelection {
                             .with(wait_sequence_handles)
                             .with(tint_sequence_handles)
                             .with(scale_sequence_handles)
+                            .with(sprite_offset_sequence_handles)
+                            .with(interpolation_sequence_handles)
                             .with(input_reactions_sequence_handles);
 
                         match csw_layer {
@@ -253,7 +257,7 @@ impl AssetSequenceComponentLoaderUiCharacterSelection {
             .map(|(index, ash_template)| {
                 let ui_sprite_label = &ash_template.sprite;
                 let position_init = ui_sprite_label.position;
-                let offset = Position::<f32>::from(position_init);
+                let offset = Position::<FixedPoint>::from(position_init);
 
                 let sequence_id_init = SequenceIdMapper::<SpriteSequenceName>::item_to_data(
                     sequence_id_mappings,
@@ -266,6 +270,8 @@ impl AssetSequenceComponentLoaderUiCharacterSelection {
                     wait_sequence_handles,
                     tint_sequence_handles,
                     scale_sequence_handles,
+                    sprite_offset_sequence_handles,
+                    interpolation_sequence_handles,
                     input_reactions_sequence_handles,
                     sprite_render_sequence_handles,
                 } = asset_sequence_component_loader_ui_components.clone();
@@ -281,6 +287,8 @@ impl AssetSequenceComponentLoaderUiCharacterSelection {
                     .with(wait_sequence_handles)
                     .with(tint_sequence_handles)
                     .with(scale_sequence_handles)
+                    .with(sprite_offset_sequence_handles)
+                    .with(interpolation_sequence_handles)
                     .with(input_reactions_sequence_handles);
 
                 if let Some(sprite_render_sequence_handles) = sprite_render_sequence_handles {
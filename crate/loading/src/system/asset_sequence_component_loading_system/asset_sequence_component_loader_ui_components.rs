@@ -1,7 +1,8 @@
 use input_reaction_model::loaded::InputReactionsSequenceHandles;
 use sequence_model::loaded::{SequenceEndTransitions, WaitSequenceHandles};
 use sprite_model::loaded::{
-    ScaleSequenceHandles, SpriteRenderSequenceHandles, TintSequenceHandles,
+    InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+    SpriteRenderSequenceHandles, TintSequenceHandles,
 };
 
 /// Common components for UI items.
@@ -15,6 +16,10 @@ pub struct AssetSequenceComponentLoaderUiComponents {
     pub tint_sequence_handles: TintSequenceHandles,
     /// Sequence of `ScaleSequenceHandle`s.
     pub scale_sequence_handles: ScaleSequenceHandles,
+    /// Sequence of `SpriteOffsetSequenceHandle`s.
+    pub sprite_offset_sequence_handles: SpriteOffsetSequenceHandles,
+    /// Sequence of `InterpolationSequenceHandle`s.
+    pub interpolation_sequence_handles: InterpolationSequenceHandles,
     /// Sequence of `InputReactionsSequenceHandle`s.
     pub input_reactions_sequence_handles: InputReactionsSequenceHandles,
     /// Sequence of `SpriteRenderSequenceHandle`s.
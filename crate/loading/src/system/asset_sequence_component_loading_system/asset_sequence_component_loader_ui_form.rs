@@ -101,6 +101,8 @@ impl AssetSequenceComponentLoaderUiForm {
             wait_sequence_handles,
             tint_sequence_handles,
             scale_sequence_handles,
+            sprite_offset_sequence_handles,
+            interpolation_sequence_handles,
             input_reactions_sequence_handles,
             sprite_render_sequence_handles,
         } = asset_sequence_component_loader_ui_components.clone();
@@ -114,6 +116,8 @@ impl AssetSequenceComponentLoaderUiForm {
                 .with(wait_sequence_handles)
                 .with(tint_sequence_handles)
                 .with(scale_sequence_handles)
+                .with(sprite_offset_sequence_handles)
+                .with(interpolation_sequence_handles)
                 .with(input_reactions_sequence_handles)
                 .with(widget_status_sequences);
 
@@ -21,7 +21,7 @@ use asset_ui_model::{
 use chase_model::play::ChaseModeStick;
 use game_input_model::play::SharedInputControlled;
 use kinematic_loading::PositionInitsLoader;
-use kinematic_model::config::{Position, PositionInit};
+use kinematic_model::config::{FixedPoint, Position, PositionInit};
 use map_selection_ui_model::config::{MapSelectionUi, MpwTemplate, MswLayer, MswLayerName};
 use sequence_loading::SequenceIdMapper;
 use sequence_model::{config::SequenceNameString, loaded::SequenceIdMappings};
@@ -95,6 +95,8 @@ impl AssetSequenceComponentLoaderUiMapSelection {
                         wait_sequence_handles,
                         tint_sequence_handles,
                         scale_sequence_handles,
+                        sprite_offset_sequence_handles,
+                        interpolation_sequence_handles,
                         input_reactions_sequence_handles,
                         sprite_render_sequence_handles,
                     } = asset_sequence_component_loader_ui_components.clone();
@@ -107,6 +109,8 @@ impl AssetSequenceComponentLoaderUiMapSelection {
                         .with(wait_sequence_handles)
                         .with(tint_sequence_handles)
                         .with(scale_sequence_handles)
+                        .with(sprite_offset_sequence_handles)
+                        .with(interpolation_sequence_handles)
                         .with(input_reactions_sequence_handles);
 
                     match msw_layer {
@@ -240,7 +244,7 @@ impl AssetSequenceComponentLoaderUiMapSelection {
             .map(|ash_template| {
                 let ui_sprite_label = &ash_template.sprite;
                 let position_init = ui_sprite_label.position;
-                let offset = Position::<f32>::from(position_init);
+                let offset = Position::<FixedPoint>::from(position_init);
 
                 let sequence_id_init = SequenceIdMapper::<SpriteSequenceName>::item_to_data(
                     sequence_id_mappings,
@@ -253,6 +257,8 @@ impl AssetSequenceComponentLoaderUiMapSelection {
                     wait_sequence_handles,
                     tint_sequence_handles,
                     scale_sequence_handles,
+                    sprite_offset_sequence_handles,
+                    interpolation_sequence_handles,
                     input_reactions_sequence_handles,
                     sprite_render_sequence_handles,
                 } = asset_sequence_component_loader_ui_components.clone();
@@ -268,6 +274,8 @@ impl AssetSequenceComponentLoaderUiMapSelection {
                     .with(wait_sequence_handles)
                     .with(tint_sequence_handles)
                     .with(scale_sequence_handles)
+                    .with(sprite_offset_sequence_handles)
+                    .with(interpolation_sequence_handles)
                     .with(input_reactions_sequence_handles);
 
                 if let Some(sprite_render_sequence_handles) = sprite_render_sequence_handles {
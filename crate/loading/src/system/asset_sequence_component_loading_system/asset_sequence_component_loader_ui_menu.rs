@@ -42,6 +42,8 @@ impl AssetSequenceComponentLoaderUiMenu {
                     wait_sequence_handles,
                     tint_sequence_handles,
                     scale_sequence_handles,
+                    sprite_offset_sequence_handles,
+                    interpolation_sequence_handles,
                     input_reactions_sequence_handles,
                     sprite_render_sequence_handles,
                 } = asset_sequence_component_loader_ui_components.clone();
@@ -92,6 +94,8 @@ impl AssetSequenceComponentLoaderUiMenu {
                         .with(wait_sequence_handles)
                         .with(tint_sequence_handles)
                         .with(scale_sequence_handles)
+                        .with(sprite_offset_sequence_handles)
+                        .with(interpolation_sequence_handles)
                         .with(input_reactions_sequence_handles)
                         .with(SharedInputControlled)
                         .with(ButtonInputControlled)
@@ -1,10 +1,8 @@
 use amethyst::assets::ProgressCounter;
-use asset_loading::YamlFormat;
+use asset_loading::{DefinitionFile, DefinitionFormat, ObjectDefinitionMerger};
 use asset_model::{config::AssetType, loaded::AssetId};
 use loading_model::loaded::LoadStage;
-use log::debug;
-#[cfg(target_arch = "wasm32")]
-use wasm_support_fs::PathAccessExt;
+use log::{debug, error};
 
 use crate::{
     AssetLoadingResources, AssetPartLoader, AssetPartLoadingSystem,
@@ -34,6 +32,8 @@ impl<'s> AssetPartLoader<'s> for AssetSpritesDefinitionLoader {
         SpritesDefinitionLoadingResources {
             sprites_definition_assets,
             asset_sprites_definition_handles,
+            asset_sprites_dirs,
+            overridden_asset_paths,
         }: &mut SpritesDefinitionLoadingResources<'_>,
         asset_id: AssetId,
     ) {
@@ -52,39 +52,65 @@ impl<'s> AssetPartLoader<'s> for AssetSpritesDefinitionLoader {
             .get(asset_id)
             .expect("Expected `PathBuf` mapping to exist for `AssetId`.");
 
-        let sprites_definition_path = asset_path.join("sprites.yaml");
-        if let AssetType::Map | AssetType::Ui = asset_type {
-            // Return early if `sprites.yaml` does not exist.
-            // This means `asset_sprites_definition_handles` will not have a key for the current
-            // `asset_id`.
-            #[cfg(not(target_arch = "wasm32"))]
-            let sprites_definition_path_exists = sprites_definition_path.exists();
-            #[cfg(target_arch = "wasm32")]
-            let sprites_definition_path_exists = sprites_definition_path.exists_on_server();
-
-            if !sprites_definition_path_exists {
-                return;
+        let sprites_dir = match asset_type {
+            AssetType::Map | AssetType::Ui => {
+                // Return early if the sprites definition does not exist.
+                // This means `asset_sprites_definition_handles` will not have a key for the
+                // current `asset_id`.
+                if DefinitionFile::find(asset_path, "sprites").is_some() {
+                    asset_path.clone()
+                } else {
+                    return;
+                }
+            }
+            AssetType::Object(_) => {
+                // Objects that extend another object use its sprites if they have none.
+                //
+                // In WASM, files cannot be read synchronously, so `extends` is not supported.
+                #[cfg(not(target_arch = "wasm32"))]
+                let sprites_dir = ObjectDefinitionMerger::sprites_dir(asset_path, |asset_slug| {
+                    overridden_asset_paths.get(asset_slug).cloned().or_else(|| {
+                        asset_id_mappings
+                            .id(asset_slug)
+                            .and_then(|asset_id| asset_id_to_path.get(*asset_id))
+                            .cloned()
+                    })
+                })
+                .unwrap_or_else(|e| {
+                    error!("{}", e);
+                    None
+                });
+                #[cfg(target_arch = "wasm32")]
+                let sprites_dir: Option<std::path::PathBuf> = {
+                    let _ = overridden_asset_paths;
+                    None
+                };
+
+                sprites_dir.unwrap_or_else(|| asset_path.clone())
             }
-        }
+        };
+        let sprites_definition_file = DefinitionFile::find_or_default(&sprites_dir, "sprites");
 
-        let sprites_definition_path = sprites_definition_path
+        let sprites_definition_path = sprites_definition_file
+            .path
             .to_str()
             .expect("Expected path to be valid unicode.");
 
         debug!(
             "Loading `{}` sprites definition from: `{}`",
             asset_slug,
-            asset_path.display()
+            sprites_dir.display()
         );
 
         let sprites_definition_handle = loader.load(
             sprites_definition_path,
-            YamlFormat,
+            DefinitionFormat::new(sprites_definition_file.format),
             &mut *progress_counter,
             sprites_definition_assets,
         );
 
         asset_sprites_definition_handles.insert(asset_id, sprites_definition_handle);
+        asset_sprites_dirs.insert(asset_id, sprites_dir);
     }
 
     /// Returns whether the `SpritesDefinition` asset has been loaded.
@@ -118,16 +144,9 @@ impl<'s> AssetPartLoader<'s> for AssetSpritesDefinitionLoader {
                     .get(asset_id)
                     .expect("Expected `PathBuf` mapping to exist for `AssetId`.");
 
-                let sprites_definition_path = asset_path.join("sprites.yaml");
-
                 if let AssetType::Map | AssetType::Ui = asset_type {
                     // If there is no sprites definition, return `true`. Otherwise return `false`.
-                    #[cfg(not(target_arch = "wasm32"))]
-                    let sprites_definition_path_exists = sprites_definition_path.exists();
-                    #[cfg(target_arch = "wasm32")]
-                    let sprites_definition_path_exists = sprites_definition_path.exists_on_server();
-
-                    !sprites_definition_path_exists
+                    DefinitionFile::find(asset_path, "sprites").is_none()
                 } else {
                     false
                 }
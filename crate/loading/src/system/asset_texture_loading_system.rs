@@ -1,8 +1,8 @@
-use amethyst::assets::ProgressCounter;
+use amethyst::{assets::ProgressCounter, renderer::sprite::SpriteSheetHandle};
 use asset_model::loaded::AssetId;
 use loading_model::loaded::LoadStage;
 use log::debug;
-use sprite_loading::SpriteLoader;
+use sprite_loading::{SpriteLoader, SpriteVariantLoader};
 
 use crate::{
     AssetLoadingResources, AssetPartLoader, AssetPartLoadingSystem,
@@ -34,10 +34,14 @@ impl<'s> AssetPartLoader<'s> for AssetTextureLoader {
                 SpritesDefinitionLoadingResourcesRead {
                     sprites_definition_assets,
                     asset_sprites_definition_handles,
+                    asset_sprites_dirs,
                 },
             texture_assets,
             sprite_sheet_assets,
             asset_sprite_sheet_handles,
+            character_definition_assets,
+            asset_character_definition_handle,
+            asset_sprite_variant_sheet_handles,
         }: &mut TextureLoadingResources<'_>,
         asset_id: AssetId,
     ) {
@@ -60,10 +64,12 @@ impl<'s> AssetPartLoader<'s> for AssetTextureLoader {
                 });
 
         if let Some(sprites_definition) = sprites_definition {
+            // Sprite images are relative to the sprites definition, which may be a parent asset's.
+            let sprites_dir = asset_sprites_dirs.get(asset_id).unwrap_or(asset_path);
             debug!(
                 "Loading `{}` textures from: `{}`",
                 asset_slug,
-                asset_path.display()
+                sprites_dir.display()
             );
 
             let sprite_sheet_handles = SpriteLoader::load(
@@ -72,10 +78,33 @@ impl<'s> AssetPartLoader<'s> for AssetTextureLoader {
                 &texture_assets,
                 &sprite_sheet_assets,
                 &sprites_definition,
-                &asset_path,
+                &sprites_dir,
             )
             .expect("Failed to load textures and sprite sheets.");
 
+            let sprite_variants = asset_character_definition_handle
+                .get(asset_id)
+                .and_then(|character_definition_handle| {
+                    character_definition_assets.get(character_definition_handle)
+                })
+                .map(|character_definition| &character_definition.variants)
+                .filter(|sprite_variants| !sprite_variants.is_empty());
+            if let Some(sprite_variants) = sprite_variants {
+                let sprite_variant_sheet_handles = SpriteVariantLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &sprite_sheet_handles,
+                    sprite_variants,
+                    &sprites_dir,
+                    &asset_path,
+                );
+
+                asset_sprite_variant_sheet_handles.insert(asset_id, sprite_variant_sheet_handles);
+            }
+
             asset_sprite_sheet_handles.insert(asset_id, sprite_sheet_handles);
         }
     }
@@ -89,20 +118,29 @@ impl<'s> AssetPartLoader<'s> for AssetTextureLoader {
             texture_assets,
             sprite_sheet_assets,
             asset_sprite_sheet_handles,
+            asset_sprite_variant_sheet_handles,
             ..
         }: &TextureLoadingResources<'_>,
         asset_id: AssetId,
     ) -> bool {
+        let is_loaded = |sprite_sheet_handles: &Vec<SpriteSheetHandle>| {
+            sprite_sheet_handles.iter().all(|sprite_sheet_handle| {
+                sprite_sheet_assets
+                    .get(sprite_sheet_handle)
+                    .and_then(|sprite_sheet| texture_assets.get(&sprite_sheet.texture))
+                    .is_some()
+            })
+        };
+
         asset_sprite_sheet_handles
             .get(asset_id)
-            .map(|sprite_sheet_handles| {
-                sprite_sheet_handles.iter().all(|sprite_sheet_handle| {
-                    sprite_sheet_assets
-                        .get(sprite_sheet_handle)
-                        .and_then(|sprite_sheet| texture_assets.get(&sprite_sheet.texture))
-                        .is_some()
-                })
-            })
+            .map(is_loaded)
             .unwrap_or(true)
+            && asset_sprite_variant_sheet_handles
+                .get(asset_id)
+                .map(|sprite_variant_sheet_handles| {
+                    sprite_variant_sheet_handles.iter().all(is_loaded)
+                })
+                .unwrap_or(true)
     }
 }
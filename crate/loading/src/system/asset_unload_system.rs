@@ -0,0 +1,262 @@
+use amethyst::{
+    assets::Handle,
+    ecs::{Entity, Read, System, World, WorldExt, Write},
+    renderer::sprite::SpriteSheetHandle,
+    shred::{ResourceId, SystemData},
+};
+use asset_model::{
+    loaded::{AssetId, AssetIdMappings, AssetItemIds, AssetTypeMappings},
+    play::AssetWorld,
+};
+use background_model::loaded::AssetBackgroundDefinitionHandle;
+use character_model::{config::CharacterSequenceName, loaded::AssetCharacterDefinitionHandle};
+use derivative::Derivative;
+use derive_new::new;
+use energy_model::{config::EnergySequenceName, loaded::AssetEnergyDefinitionHandle};
+use item_model::{config::ItemSequenceName, loaded::AssetItemDefinitionHandle};
+use loading_model::{
+    loaded::{AssetLoadStage, AssetLoadStatus, DeferredAssetTypes, LoadStage},
+    play::{AssetMemoryBudget, AssetMemoryUsage, AssetUsage},
+};
+use log::{info, warn};
+use map_model::loaded::{
+    AssetMapBounds, AssetMapDefinitionHandle, AssetMargins, AssetSpawnPoints, AssetSurfaces,
+};
+use sequence_model::loaded::AssetSequenceIdMappings;
+use slotmap::SecondaryMap;
+use sprite_model::{
+    config::{SpriteSequenceName, SpritesDefinition},
+    loaded::{AssetSpriteVariantSheetHandles, AssetSpritesDirs},
+};
+use ui_model::loaded::AssetUiDefinitionHandle;
+
+/// Default number of ticks an asset must be unused before it may be unloaded.
+const UNUSED_TICKS_MIN_DEFAULT: u64 = 60;
+
+/// Unloads the least recently used assets when the `AssetMemoryBudget` is exceeded.
+///
+/// Only assets of the `DeferredAssetTypes` are unloaded, as they are loaded again when requested.
+/// Handles to an asset's definitions and sprite sheets are dropped, so the underlying assets are
+/// freed once nothing else holds them. The asset's sequence ID mappings and map data are removed,
+/// and its sequence component entities are deleted from the `AssetWorld`.
+#[derive(Debug, new)]
+pub struct AssetUnloadSystem {
+    /// Number of ticks an asset must be unused before it may be unloaded.
+    unused_ticks_min: u64,
+}
+
+/// `AssetUnloadSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AssetUnloadSystemData<'s> {
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `AssetTypeMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_type_mappings: Read<'s, AssetTypeMappings>,
+    /// `DeferredAssetTypes` resource.
+    #[derivative(Debug = "ignore")]
+    pub deferred_asset_types: Read<'s, DeferredAssetTypes>,
+    /// `AssetMemoryBudget` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_memory_budget: Read<'s, AssetMemoryBudget>,
+    /// `AssetUsage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_usage: Read<'s, AssetUsage>,
+    /// `AssetMemoryUsage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_memory_usage: Write<'s, AssetMemoryUsage>,
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Write<'s, AssetLoadStage>,
+    /// `AssetLoadStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_status: Write<'s, AssetLoadStatus>,
+    /// `AssetUnloadHandles`.
+    pub asset_unload_handles: AssetUnloadHandles<'s>,
+}
+
+/// Per-asset handles and data that are removed when an asset is unloaded.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AssetUnloadHandles<'s> {
+    /// `AssetCharacterDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_character_definition_handle: Write<'s, AssetCharacterDefinitionHandle>,
+    /// `AssetEnergyDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_energy_definition_handle: Write<'s, AssetEnergyDefinitionHandle>,
+    /// `AssetItemDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_item_definition_handle: Write<'s, AssetItemDefinitionHandle>,
+    /// `AssetMapDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_definition_handle: Write<'s, AssetMapDefinitionHandle>,
+    /// `AssetBackgroundDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_background_definition_handle: Write<'s, AssetBackgroundDefinitionHandle>,
+    /// `AssetUiDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_ui_definition_handle: Write<'s, AssetUiDefinitionHandle>,
+    /// `SecondaryMap<AssetId, Handle<SpritesDefinition>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_definition_handles:
+        Write<'s, SecondaryMap<AssetId, Handle<SpritesDefinition>>>,
+    /// `SecondaryMap<AssetId, Vec<SpriteSheetHandle>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprite_sheet_handles: Write<'s, SecondaryMap<AssetId, Vec<SpriteSheetHandle>>>,
+    /// `AssetSpriteVariantSheetHandles` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprite_variant_sheet_handles: Write<'s, AssetSpriteVariantSheetHandles>,
+    /// `AssetSpritesDirs` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_dirs: Write<'s, AssetSpritesDirs>,
+    /// `AssetSequenceIdMappings<SpriteSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_sprite: Write<'s, AssetSequenceIdMappings<SpriteSequenceName>>,
+    /// `AssetSequenceIdMappings<CharacterSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_character:
+        Write<'s, AssetSequenceIdMappings<CharacterSequenceName>>,
+    /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_energy: Write<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Write<'s, AssetSequenceIdMappings<ItemSequenceName>>,
+    /// `AssetMapBounds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_bounds: Write<'s, AssetMapBounds>,
+    /// `AssetMargins` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_margins: Write<'s, AssetMargins>,
+    /// `AssetSpawnPoints` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_spawn_points: Write<'s, AssetSpawnPoints>,
+    /// `AssetSurfaces` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_surfaces: Write<'s, AssetSurfaces>,
+    /// `AssetWorld` for loaded item components.
+    #[derivative(Debug = "ignore")]
+    pub asset_world: Write<'s, AssetWorld>,
+    /// `AssetItemIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_item_ids: Write<'s, AssetItemIds>,
+}
+
+impl Default for AssetUnloadSystem {
+    fn default() -> Self {
+        AssetUnloadSystem::new(UNUSED_TICKS_MIN_DEFAULT)
+    }
+}
+
+impl AssetUnloadSystem {
+    /// Drops the handles and data held for an asset, and deletes its items from the `AssetWorld`.
+    fn unload(
+        AssetUnloadHandles {
+            asset_character_definition_handle,
+            asset_energy_definition_handle,
+            asset_item_definition_handle,
+            asset_map_definition_handle,
+            asset_background_definition_handle,
+            asset_ui_definition_handle,
+            asset_sprites_definition_handles,
+            asset_sprite_sheet_handles,
+            asset_sprite_variant_sheet_handles,
+            asset_sprites_dirs,
+            asset_sequence_id_mappings_sprite,
+            asset_sequence_id_mappings_character,
+            asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
+            asset_map_bounds,
+            asset_margins,
+            asset_spawn_points,
+            asset_surfaces,
+            asset_world,
+            asset_item_ids,
+        }: &mut AssetUnloadHandles<'_>,
+        asset_id: AssetId,
+    ) {
+        asset_character_definition_handle.remove(asset_id);
+        asset_energy_definition_handle.remove(asset_id);
+        asset_item_definition_handle.remove(asset_id);
+        asset_map_definition_handle.remove(asset_id);
+        asset_background_definition_handle.remove(asset_id);
+        asset_ui_definition_handle.remove(asset_id);
+        asset_sprites_definition_handles.remove(asset_id);
+        asset_sprite_sheet_handles.remove(asset_id);
+        asset_sprite_variant_sheet_handles.remove(asset_id);
+        asset_sprites_dirs.remove(asset_id);
+        asset_sequence_id_mappings_sprite.remove(asset_id);
+        asset_sequence_id_mappings_character.remove(asset_id);
+        asset_sequence_id_mappings_energy.remove(asset_id);
+        asset_sequence_id_mappings_item.remove(asset_id);
+        asset_map_bounds.remove(asset_id);
+        asset_margins.remove(asset_id);
+        asset_spawn_points.remove(asset_id);
+        asset_surfaces.remove(asset_id);
+
+        if let Some(item_ids) = asset_item_ids.remove(asset_id) {
+            let item_entities = item_ids
+                .iter()
+                .map(|item_id| item_id.0)
+                .collect::<Vec<Entity>>();
+            if let Err(e) = asset_world.delete_entities(&item_entities) {
+                warn!("Failed to delete asset items: {}", e);
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for AssetUnloadSystem {
+    type SystemData = AssetUnloadSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AssetUnloadSystemData {
+            asset_id_mappings,
+            asset_type_mappings,
+            deferred_asset_types,
+            asset_memory_budget,
+            asset_usage,
+            mut asset_memory_usage,
+            mut asset_load_stage,
+            mut asset_load_status,
+            mut asset_unload_handles,
+        }: Self::SystemData,
+    ) {
+        let budget = if let Some(budget) = asset_memory_budget.bytes {
+            budget
+        } else {
+            return;
+        };
+
+        let unused_ticks_min = self.unused_ticks_min;
+        let asset_ids_unload = asset_memory_usage.lru_unloads(budget, &asset_usage, |asset_id| {
+            let is_deferred_type = asset_type_mappings
+                .get(asset_id)
+                .map(|asset_type| deferred_asset_types.contains(asset_type))
+                .unwrap_or(false);
+            let is_complete = asset_load_stage.get(asset_id) == Some(&LoadStage::Complete);
+
+            is_deferred_type
+                && is_complete
+                && asset_usage.ticks_unused(asset_id) >= unused_ticks_min
+        });
+
+        asset_ids_unload.into_iter().for_each(|asset_id| {
+            if let Some(asset_slug) = asset_id_mappings.slug(asset_id) {
+                info!(
+                    "Unloading `{}` to stay within the asset memory budget.",
+                    asset_slug
+                );
+            }
+
+            Self::unload(&mut asset_unload_handles, asset_id);
+            asset_memory_usage.assets.remove(asset_id);
+            asset_load_stage.insert(asset_id, LoadStage::Deferred);
+            asset_load_status.remove(asset_id);
+        });
+    }
+}
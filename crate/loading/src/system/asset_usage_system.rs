@@ -0,0 +1,71 @@
+use amethyst::{
+    ecs::{Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::loaded::AssetId;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use loading_model::{
+    loaded::{AssetLoadStage, LoadStage},
+    play::AssetUsage,
+};
+use map_selection_model::MapSelection;
+
+/// Records which assets are in use each tick.
+///
+/// An asset is in use when an entity references it, when it is selected, or while it is loading.
+#[derive(Debug, Default, new)]
+pub struct AssetUsageSystem;
+
+/// `AssetUsageSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AssetUsageSystemData<'s> {
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Read<'s, AssetLoadStage>,
+    /// `AssetUsage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_usage: Write<'s, AssetUsage>,
+}
+
+impl<'s> System<'s> for AssetUsageSystem {
+    type SystemData = AssetUsageSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AssetUsageSystemData {
+            asset_ids,
+            character_selections,
+            map_selection,
+            asset_load_stage,
+            mut asset_usage,
+        }: Self::SystemData,
+    ) {
+        let asset_ids_loading = asset_load_stage
+            .iter()
+            .filter(|(_, load_stage)| {
+                **load_stage != LoadStage::Deferred && **load_stage != LoadStage::Complete
+            })
+            .map(|(asset_id, _)| asset_id);
+
+        asset_usage.update(
+            (&asset_ids)
+                .join()
+                .copied()
+                .chain(character_selections.selections.values().copied())
+                .chain(map_selection.asset_id())
+                .chain(asset_ids_loading),
+        );
+    }
+}
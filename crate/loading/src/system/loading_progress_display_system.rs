@@ -0,0 +1,194 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, UiImage, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use loading_model::play::{ByteProgress, LoadingProgress, LoadingProgressEntity};
+use log::error;
+use state_registry::StateId;
+
+const BAR_COLOUR_BACKGROUND: [f32; 4] = [0.1, 0.1, 0.1, 0.8];
+const BAR_COLOUR_FILL: [f32; 4] = [0.4, 0.7, 0.4, 1.];
+const BAR_WIDTH: f32 = 400.;
+const BAR_HEIGHT: f32 = 16.;
+const BAR_MARGIN_BOTTOM: f32 = 60.;
+const FONT_COLOUR_LABEL: [f32; 4] = [0.9, 0.9, 0.9, 1.];
+const FONT_SIZE_LABEL: f32 = 16.;
+const LABEL_HEIGHT: f32 = 24.;
+
+const ID_BAR: &str = "loading_progress_bar";
+const ID_FILL: &str = "loading_progress_fill";
+const ID_LABEL: &str = "loading_progress_label";
+
+/// Number of bytes in a kibibyte.
+const KIB: u64 = 1024;
+
+/// Displays a progress bar while in the `Loading` state.
+#[derive(Debug, Default, new)]
+pub struct LoadingProgressDisplaySystem;
+
+/// `LoadingProgressDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct LoadingProgressDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `LoadingProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub loading_progress: Read<'s, LoadingProgress>,
+    /// `LoadingProgressEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub loading_progress_entities: WriteStorage<'s, LoadingProgressEntity>,
+
+    // Resources needed to display the bar.
+    /// `Theme` resource.
+    ///
+    /// The progress bar is not displayed if the application UI is not set up.
+    #[derivative(Debug = "ignore")]
+    pub theme: Option<ReadExpect<'s, Theme>>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiImage` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_images: WriteStorage<'s, UiImage>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl LoadingProgressDisplaySystem {
+    fn label_text(loading_progress: &LoadingProgress) -> String {
+        let assets = loading_progress.assets();
+        format!(
+            "assets: {} / {}  textures: {}  audio: {}",
+            assets.done,
+            assets.total,
+            Self::bytes_text(loading_progress.texture_bytes),
+            Self::bytes_text(loading_progress.audio_bytes),
+        )
+    }
+
+    fn bytes_text(byte_progress: ByteProgress) -> String {
+        format!(
+            "{} / {} KiB",
+            byte_progress.loaded / KIB,
+            byte_progress.total / KIB
+        )
+    }
+
+    fn bar_transform(id: &str, z: f32, width: f32) -> UiTransform {
+        UiTransform::new(
+            String::from(id),
+            Anchor::BottomMiddle,
+            Anchor::MiddleLeft,
+            -BAR_WIDTH / 2.,
+            BAR_MARGIN_BOTTOM,
+            z,
+            width,
+            BAR_HEIGHT,
+        )
+    }
+}
+
+impl<'s> System<'s> for LoadingProgressDisplaySystem {
+    type SystemData = LoadingProgressDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        LoadingProgressDisplaySystemData {
+            entities,
+            state_id,
+            loading_progress,
+            mut loading_progress_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_images,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let theme = match theme {
+            Some(theme) if *state_id == StateId::Loading => theme,
+            _ => {
+                (&entities, &loading_progress_entities)
+                    .join()
+                    .for_each(|(entity, _)| {
+                        if let Err(e) = entities.delete(entity) {
+                            error!("Failed to delete loading progress entity: {}", e);
+                        }
+                    });
+                return;
+            }
+        };
+
+        let fill_width = BAR_WIDTH * loading_progress.fraction();
+        let label_text = Self::label_text(&loading_progress);
+
+        let mut bar_exists = false;
+        (&loading_progress_entities, &mut ui_transforms)
+            .join()
+            .filter(|(_, ui_transform)| ui_transform.id == ID_FILL)
+            .for_each(|(_, ui_transform)| {
+                bar_exists = true;
+                ui_transform.width = fill_width;
+            });
+        (&loading_progress_entities, &mut ui_texts)
+            .join()
+            .for_each(|(_, ui_text)| {
+                if ui_text.text != label_text {
+                    ui_text.text = label_text.clone();
+                }
+            });
+
+        if !bar_exists {
+            entities
+                .build_entity()
+                .with(LoadingProgressEntity, &mut loading_progress_entities)
+                .with(
+                    Self::bar_transform(ID_BAR, 1., BAR_WIDTH),
+                    &mut ui_transforms,
+                )
+                .with(UiImage::SolidColor(BAR_COLOUR_BACKGROUND), &mut ui_images)
+                .build();
+
+            entities
+                .build_entity()
+                .with(LoadingProgressEntity, &mut loading_progress_entities)
+                .with(
+                    Self::bar_transform(ID_FILL, 2., fill_width),
+                    &mut ui_transforms,
+                )
+                .with(UiImage::SolidColor(BAR_COLOUR_FILL), &mut ui_images)
+                .build();
+
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+            let label_transform = UiTransform::new(
+                String::from(ID_LABEL),
+                Anchor::BottomMiddle,
+                Anchor::BottomMiddle,
+                0.,
+                BAR_MARGIN_BOTTOM + BAR_HEIGHT,
+                1.,
+                BAR_WIDTH,
+                LABEL_HEIGHT,
+            );
+            let ui_text = UiText::new(font.clone(), label_text, FONT_COLOUR_LABEL, FONT_SIZE_LABEL);
+            entities
+                .build_entity()
+                .with(LoadingProgressEntity, &mut loading_progress_entities)
+                .with(label_transform, &mut ui_transforms)
+                .with(ui_text, &mut ui_texts)
+                .build();
+        }
+    }
+}
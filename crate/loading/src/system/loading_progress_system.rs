@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use asset_fs::AssetFs;
+use asset_model::loaded::AssetId;
+use derivative::Derivative;
+use derive_new::new;
+use loading_model::{
+    loaded::{AssetLoadStage, LoadStage},
+    play::LoadingProgress,
+};
+use log::warn;
+use slotmap::SecondaryMap;
+use sprite_model::config::SpritesDefinition;
+
+/// Updates the `LoadingProgress` resource with the progress of each load stage and textures.
+///
+/// Texture sizes are only known once an asset's sprites definition is loaded, so the total texture
+/// bytes grow as assets reach the `TextureLoading` stage.
+#[derive(Debug, Default, new)]
+pub struct LoadingProgressSystem {
+    /// Bytes of textures for each asset that has reached the `TextureLoading` stage.
+    #[new(default)]
+    asset_texture_bytes: SecondaryMap<AssetId, u64>,
+}
+
+/// `LoadingProgressSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct LoadingProgressSystemData<'s> {
+    /// `AssetLoadStage` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_load_stage: Read<'s, AssetLoadStage>,
+    /// `SecondaryMap<AssetId, PathBuf>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_to_path: Read<'s, SecondaryMap<AssetId, PathBuf>>,
+    /// `SpritesDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprites_definition_assets: Read<'s, AssetStorage<SpritesDefinition>>,
+    /// `SecondaryMap<AssetId, Handle<SpritesDefinition>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_definition_handles:
+        Read<'s, SecondaryMap<AssetId, Handle<SpritesDefinition>>>,
+    /// `LoadingProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub loading_progress: Write<'s, LoadingProgress>,
+}
+
+impl LoadingProgressSystem {
+    /// Returns the total size of the sprite sheet images in bytes.
+    fn texture_bytes(asset_path: &Path, sprites_definition: &SpritesDefinition) -> u64 {
+        sprites_definition
+            .sheets
+            .iter()
+            .map(|sheet| {
+                let sheet_path = asset_path.join(&sheet.path);
+                AssetFs::size(&sheet_path).unwrap_or_else(|e| {
+                    warn!("Failed to read size of `{}`: {}", sheet_path.display(), e);
+                    0
+                })
+            })
+            .sum()
+    }
+}
+
+impl<'s> System<'s> for LoadingProgressSystem {
+    type SystemData = LoadingProgressSystemData<'s>;
+
+    fn run(
+        &mut self,
+        LoadingProgressSystemData {
+            asset_load_stage,
+            asset_id_to_path,
+            sprites_definition_assets,
+            asset_sprites_definition_handles,
+            mut loading_progress,
+        }: Self::SystemData,
+    ) {
+        loading_progress.update_stages(asset_load_stage.values().copied());
+
+        // Sprites definitions may change when assets are reloaded or requested again.
+        let asset_texture_bytes = &mut self.asset_texture_bytes;
+        asset_load_stage
+            .iter()
+            .filter(|(_, load_stage)| **load_stage < LoadStage::TextureLoading)
+            .for_each(|(asset_id, _)| {
+                asset_texture_bytes.remove(asset_id);
+            });
+
+        asset_load_stage
+            .iter()
+            .filter(|(asset_id, load_stage)| {
+                **load_stage >= LoadStage::TextureLoading
+                    && !asset_texture_bytes.contains_key(*asset_id)
+            })
+            .for_each(|(asset_id, _)| {
+                let sprites_definition = asset_sprites_definition_handles
+                    .get(asset_id)
+                    .and_then(|handle| sprites_definition_assets.get(handle));
+                let texture_bytes = match (sprites_definition, asset_id_to_path.get(asset_id)) {
+                    (Some(sprites_definition), Some(asset_path)) => {
+                        Self::texture_bytes(asset_path, sprites_definition)
+                    }
+                    _ => 0,
+                };
+                asset_texture_bytes.insert(asset_id, texture_bytes);
+            });
+
+        let (loaded, total) = asset_texture_bytes.iter().fold(
+            (0, 0),
+            |(loaded, total), (asset_id, texture_bytes)| {
+                let textures_loaded = asset_load_stage
+                    .get(asset_id)
+                    .map(|load_stage| *load_stage > LoadStage::TextureLoading)
+                    .unwrap_or(false);
+                if textures_loaded {
+                    (loaded + texture_bytes, total + texture_bytes)
+                } else {
+                    (loaded, total + texture_bytes)
+                }
+            },
+        );
+        loading_progress.texture_bytes.loaded = loaded;
+        loading_progress.texture_bytes.total = total;
+    }
+}
@@ -13,13 +13,17 @@ use collision_model::{
     loaded::{BodySequence, InteractionsSequence},
 };
 use derivative::Derivative;
+use frame_event_model::loaded::FrameEventsSequence;
 use game_input_model::{config::PlayerInputConfigs, loaded::PlayerControllers};
 use input_reaction_model::loaded::{InputReaction, InputReactions, InputReactionsSequence};
+use item_model::loaded::HoldPointSequence;
 use kinematic_model::loaded::ObjectAccelerationSequence;
-use map_model::loaded::{AssetMapBounds, AssetMargins};
+use map_model::loaded::{AssetMapBounds, AssetMargins, AssetSpawnPoints, AssetSurfaces};
 use sequence_model::loaded::WaitSequence;
 use spawn_model::loaded::{Spawns, SpawnsSequence};
-use sprite_model::loaded::{ScaleSequence, SpriteRenderSequence, TintSequence};
+use sprite_model::loaded::{
+    InterpolationSequence, ScaleSequence, SpriteOffsetSequence, SpriteRenderSequence, TintSequence,
+};
 
 use crate::{DefinitionLoadingResourcesRead, IdMappingResourcesRead, TextureLoadingResourcesRead};
 
@@ -84,6 +88,9 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `SpawnsSequence` assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: Read<'s, AssetStorage<SpawnsSequence>>,
+    /// `FrameEventsSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub frame_events_sequence_assets: Read<'s, AssetStorage<FrameEventsSequence>>,
 
     /// `InputReactions` assets.
     #[derivative(Debug = "ignore")]
@@ -98,6 +105,9 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `CharacterIrs` assets.
     #[derivative(Debug = "ignore")]
     pub character_irs_assets: Read<'s, AssetStorage<CharacterIrs>>,
+    /// `HoldPointSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub hold_point_sequence_assets: Read<'s, AssetStorage<HoldPointSequence>>,
 
     /// `TintSequence` assets.
     #[derivative(Debug = "ignore")]
@@ -105,6 +115,12 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `ScaleSequence` assets.
     #[derivative(Debug = "ignore")]
     pub scale_sequence_assets: Read<'s, AssetStorage<ScaleSequence>>,
+    /// `SpriteOffsetSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_assets: Read<'s, AssetStorage<SpriteOffsetSequence>>,
+    /// `InterpolationSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub interpolation_sequence_assets: Read<'s, AssetStorage<InterpolationSequence>>,
 
     /// `AssetMapBounds` resource.
     #[derivative(Debug = "ignore")]
@@ -112,6 +128,12 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Write<'s, AssetMargins>,
+    /// `AssetSpawnPoints` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_spawn_points: Write<'s, AssetSpawnPoints>,
+    /// `AssetSurfaces` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_surfaces: Write<'s, AssetSurfaces>,
 }
 
 /// `SequenceComponentLoadingResourcesRead`.
@@ -159,6 +181,9 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `SpawnsSequence` assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: Read<'s, AssetStorage<SpawnsSequence>>,
+    /// `FrameEventsSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub frame_events_sequence_assets: Read<'s, AssetStorage<FrameEventsSequence>>,
 
     /// `CharacterInputReactions` assets.
     #[derivative(Debug = "ignore")]
@@ -166,6 +191,9 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `CharacterIrs` assets.
     #[derivative(Debug = "ignore")]
     pub character_irs_assets: Read<'s, AssetStorage<CharacterIrs>>,
+    /// `HoldPointSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub hold_point_sequence_assets: Read<'s, AssetStorage<HoldPointSequence>>,
 
     /// `TintSequence` assets.
     #[derivative(Debug = "ignore")]
@@ -173,6 +201,12 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `ScaleSequence` assets.
     #[derivative(Debug = "ignore")]
     pub scale_sequence_assets: Read<'s, AssetStorage<ScaleSequence>>,
+    /// `SpriteOffsetSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_assets: Read<'s, AssetStorage<SpriteOffsetSequence>>,
+    /// `InterpolationSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub interpolation_sequence_assets: Read<'s, AssetStorage<InterpolationSequence>>,
 
     /// `AssetMapBounds` resource.
     #[derivative(Debug = "ignore")]
@@ -180,4 +214,10 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
+    /// `AssetSpawnPoints` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_spawn_points: Read<'s, AssetSpawnPoints>,
+    /// `AssetSurfaces` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_surfaces: Read<'s, AssetSurfaces>,
 }
@@ -3,10 +3,10 @@ use amethyst::{
     ecs::{Read, World, Write},
     shred::{ResourceId, SystemData},
 };
-use asset_model::loaded::AssetId;
+use asset_model::loaded::{AssetId, OverriddenAssetPaths};
 use derivative::Derivative;
 use slotmap::SecondaryMap;
-use sprite_model::config::SpritesDefinition;
+use sprite_model::{config::SpritesDefinition, loaded::AssetSpritesDirs};
 
 /// `SpritesDefinitionLoadingResources`.
 #[derive(Derivative, SystemData)]
@@ -19,6 +19,12 @@ pub struct SpritesDefinitionLoadingResources<'s> {
     #[derivative(Debug = "ignore")]
     pub asset_sprites_definition_handles:
         Write<'s, SecondaryMap<AssetId, Handle<SpritesDefinition>>>,
+    /// `AssetSpritesDirs` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_dirs: Write<'s, AssetSpritesDirs>,
+    /// `OverriddenAssetPaths` resource.
+    #[derivative(Debug = "ignore")]
+    pub overridden_asset_paths: Read<'s, OverriddenAssetPaths>,
 }
 
 /// `SpritesDefinitionLoadingResourcesRead`.
@@ -32,4 +38,7 @@ pub struct SpritesDefinitionLoadingResourcesRead<'s> {
     #[derivative(Debug = "ignore")]
     pub asset_sprites_definition_handles:
         Read<'s, SecondaryMap<AssetId, Handle<SpritesDefinition>>>,
+    /// `AssetSpritesDirs` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_dirs: Read<'s, AssetSpritesDirs>,
 }
@@ -5,8 +5,10 @@ use amethyst::{
     shred::{ResourceId, SystemData},
 };
 use asset_model::loaded::AssetId;
+use character_model::{config::CharacterDefinition, loaded::AssetCharacterDefinitionHandle};
 use derivative::Derivative;
 use slotmap::SecondaryMap;
+use sprite_model::loaded::AssetSpriteVariantSheetHandles;
 
 use crate::SpritesDefinitionLoadingResourcesRead;
 
@@ -26,6 +28,15 @@ pub struct TextureLoadingResources<'s> {
     /// `SecondaryMap<AssetId, Vec<SpriteSheetHandle>>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sprite_sheet_handles: Write<'s, SecondaryMap<AssetId, Vec<SpriteSheetHandle>>>,
+    /// `CharacterDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub character_definition_assets: Read<'s, AssetStorage<CharacterDefinition>>,
+    /// `AssetCharacterDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_character_definition_handle: Read<'s, AssetCharacterDefinitionHandle>,
+    /// `AssetSpriteVariantSheetHandles` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprite_variant_sheet_handles: Write<'s, AssetSpriteVariantSheetHandles>,
 }
 
 /// `TextureLoadingResourcesRead`.
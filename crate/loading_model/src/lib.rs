@@ -3,3 +3,4 @@
 //! Types used to represent loading status.
 
 pub mod loaded;
+pub mod play;
@@ -1,11 +1,12 @@
 //! Types that represent processed configuration.
 
 pub use self::{
-    asset_load_stage::AssetLoadStage, asset_load_status::AssetLoadStatus, load_stage::LoadStage,
-    load_status::LoadStatus,
+    asset_load_stage::AssetLoadStage, asset_load_status::AssetLoadStatus,
+    deferred_asset_types::DeferredAssetTypes, load_stage::LoadStage, load_status::LoadStatus,
 };
 
 mod asset_load_stage;
 mod asset_load_status;
+mod deferred_asset_types;
 mod load_stage;
 mod load_status;
@@ -0,0 +1,12 @@
+use std::collections::HashSet;
+
+use asset_model::config::AssetType;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Asset types that are only loaded when requested.
+///
+/// Assets of these types begin in the `LoadStage::Deferred` stage, and are not waited for by the
+/// `LoadingState`. They are loaded when an `AssetLoadRequestEvent::Request` is sent for them.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+pub struct DeferredAssetTypes(pub HashSet<AssetType>);
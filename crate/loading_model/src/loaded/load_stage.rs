@@ -1,6 +1,10 @@
 /// Each asset's loading status.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+///
+/// Stages are ordered by loading progress, so a stage compares greater than the stages before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum LoadStage {
+    /// The collective asset is not loaded until it is requested.
+    Deferred,
     /// The collective asset hasn't been loaded.
     New,
     /// Asset definition loading from disk.
@@ -18,11 +22,21 @@ pub enum LoadStage {
 }
 
 impl LoadStage {
+    /// Stages where asset parts are loaded.
+    pub const LOADING_STAGES: [LoadStage; 5] = [
+        Self::AssetDefinitionLoading,
+        Self::IdMapping,
+        Self::SpritesDefinitionLoading,
+        Self::TextureLoading,
+        Self::SequenceComponentLoading,
+    ];
+
     /// Returns the next variant in the list of stages.
     ///
-    /// Returns `None` if this is on the final stage.
+    /// Returns `None` if this is on the final stage, or the asset is deferred.
     pub fn next(self) -> Option<LoadStage> {
         match self {
+            Self::Deferred => None,
             Self::New => Some(Self::AssetDefinitionLoading),
             Self::AssetDefinitionLoading => Some(Self::IdMapping),
             Self::IdMapping => Some(Self::SpritesDefinitionLoading),
@@ -35,9 +49,10 @@ impl LoadStage {
 
     /// Returns the previous variant in the list of stages.
     ///
-    /// Returns `None` if this is on the first stage.
+    /// Returns `None` if this is on the first stage, or the asset is deferred.
     pub fn prev(self) -> Option<LoadStage> {
         match self {
+            Self::Deferred => None,
             Self::New => None,
             Self::AssetDefinitionLoading => Some(Self::New),
             Self::IdMapping => Some(Self::AssetDefinitionLoading),
@@ -0,0 +1,18 @@
+//! Data types used at runtime.
+
+pub use self::{
+    asset_load_request_event::AssetLoadRequestEvent, asset_memory::AssetMemory,
+    asset_memory_budget::AssetMemoryBudget, asset_memory_usage::AssetMemoryUsage,
+    asset_usage::AssetUsage, byte_progress::ByteProgress, loading_progress::LoadingProgress,
+    loading_progress_entity::LoadingProgressEntity, stage_progress::StageProgress,
+};
+
+mod asset_load_request_event;
+mod asset_memory;
+mod asset_memory_budget;
+mod asset_memory_usage;
+mod asset_usage;
+mod byte_progress;
+mod loading_progress;
+mod loading_progress_entity;
+mod stage_progress;
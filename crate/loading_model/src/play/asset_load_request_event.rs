@@ -0,0 +1,12 @@
+use asset_model::loaded::AssetId;
+
+/// Requests an asset of a `DeferredAssetTypes` type to be loaded or unloaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetLoadRequestEvent {
+    /// Begins loading the asset, if it is deferred.
+    Request(AssetId),
+    /// Stops loading the asset, if it has not completed loading.
+    ///
+    /// The asset returns to the `LoadStage::Deferred` stage, and may be requested again.
+    Cancel(AssetId),
+}
@@ -0,0 +1,39 @@
+use std::ops::{Add, AddAssign};
+
+use derive_new::new;
+
+/// Estimated memory used by an asset, in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct AssetMemory {
+    /// Bytes of decoded texture data.
+    pub texture: u64,
+    /// Bytes of audio files.
+    pub audio: u64,
+    /// Bytes of definition files.
+    pub definition: u64,
+}
+
+impl AssetMemory {
+    /// Returns the total bytes used.
+    pub fn total(self) -> u64 {
+        self.texture + self.audio + self.definition
+    }
+}
+
+impl Add for AssetMemory {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        AssetMemory {
+            texture: self.texture + other.texture,
+            audio: self.audio + other.audio,
+            definition: self.definition + other.definition,
+        }
+    }
+}
+
+impl AddAssign for AssetMemory {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
@@ -0,0 +1,11 @@
+use derive_new::new;
+
+/// Maximum estimated memory for loaded assets, in bytes.
+///
+/// When exceeded, the least recently used assets of the `DeferredAssetTypes` are unloaded. They
+/// are loaded again when requested.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct AssetMemoryBudget {
+    /// Budget in bytes, or `None` to keep all assets loaded.
+    pub bytes: Option<u64>,
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use asset_model::loaded::{AssetId, AssetIdMappings};
+use slotmap::SecondaryMap;
+
+use crate::play::{AssetMemory, AssetUsage};
+
+/// Estimated memory used by each loaded asset.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetMemoryUsage {
+    /// Memory used by each asset.
+    pub assets: SecondaryMap<AssetId, AssetMemory>,
+}
+
+impl AssetMemoryUsage {
+    /// Returns the memory used by all assets.
+    pub fn total(&self) -> AssetMemory {
+        self.assets
+            .values()
+            .fold(AssetMemory::default(), |total, asset_memory| {
+                total + *asset_memory
+            })
+    }
+
+    /// Returns the memory used by the assets in each namespace.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_id_mappings`: Mappings from `AssetId` to `AssetSlug`.
+    pub fn namespaces(&self, asset_id_mappings: &AssetIdMappings) -> BTreeMap<String, AssetMemory> {
+        self.assets
+            .iter()
+            .filter_map(|(asset_id, asset_memory)| {
+                asset_id_mappings
+                    .slug(asset_id)
+                    .map(|asset_slug| (&asset_slug.namespace, *asset_memory))
+            })
+            .fold(
+                BTreeMap::new(),
+                |mut namespaces, (namespace, asset_memory)| {
+                    *namespaces.entry(namespace.clone()).or_default() += asset_memory;
+                    namespaces
+                },
+            )
+    }
+
+    /// Returns the least recently used assets to unload to bring memory usage within the budget.
+    ///
+    /// Assets in use are never returned. If the budget cannot be met by unloading every candidate,
+    /// all candidates are returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `budget`: Maximum number of bytes to use.
+    /// * `asset_usage`: Tracks when each asset was last used.
+    /// * `fn_unloadable`: Returns whether an asset that is not in use may be unloaded.
+    pub fn lru_unloads<F>(
+        &self,
+        budget: u64,
+        asset_usage: &AssetUsage,
+        fn_unloadable: F,
+    ) -> Vec<AssetId>
+    where
+        F: Fn(AssetId) -> bool,
+    {
+        let mut total = self.total().total();
+        if total <= budget {
+            return Vec::new();
+        }
+
+        let mut candidates = self
+            .assets
+            .iter()
+            .filter(|(asset_id, _)| {
+                !asset_usage.in_use.contains(asset_id) && fn_unloadable(*asset_id)
+            })
+            .map(|(asset_id, asset_memory)| {
+                let last_used = asset_usage.last_used.get(asset_id).copied().unwrap_or(0);
+                (last_used, asset_id, asset_memory.total())
+            })
+            .collect::<Vec<(u64, AssetId, u64)>>();
+        candidates.sort_by_key(|(last_used, _, _)| *last_used);
+
+        candidates
+            .into_iter()
+            .take_while(|(_, _, bytes)| {
+                if total > budget {
+                    total = total.saturating_sub(*bytes);
+                    true
+                } else {
+                    false
+                }
+            })
+            .map(|(_, asset_id, _)| asset_id)
+            .collect::<Vec<AssetId>>()
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use asset_model::loaded::AssetId;
+use slotmap::SecondaryMap;
+
+/// Tracks which assets are in use, and when each asset was last used.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetUsage {
+    /// Number of times usage has been updated.
+    pub tick: u64,
+    /// Tick that each asset was last used.
+    pub last_used: SecondaryMap<AssetId, u64>,
+    /// Assets in use as of the current tick.
+    pub in_use: HashSet<AssetId>,
+}
+
+impl AssetUsage {
+    /// Advances the tick, and records the assets that are in use.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_ids`: Assets in use, which may contain duplicates.
+    pub fn update<I>(&mut self, asset_ids: I)
+    where
+        I: IntoIterator<Item = AssetId>,
+    {
+        self.tick += 1;
+        self.in_use.clear();
+
+        let tick = self.tick;
+        let last_used = &mut self.last_used;
+        let in_use = &mut self.in_use;
+        asset_ids.into_iter().for_each(|asset_id| {
+            last_used.insert(asset_id, tick);
+            in_use.insert(asset_id);
+        });
+    }
+
+    /// Returns the number of ticks since the asset was last used.
+    ///
+    /// Assets that have never been used are treated as last used at tick `0`.
+    pub fn ticks_unused(&self, asset_id: AssetId) -> u64 {
+        self.tick - self.last_used.get(asset_id).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,10 @@
+use derive_new::new;
+
+/// Number of bytes loaded out of the bytes known to need loading.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct ByteProgress {
+    /// Number of bytes loaded.
+    pub loaded: u64,
+    /// Number of bytes to load.
+    pub total: u64,
+}
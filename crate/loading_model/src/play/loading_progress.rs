@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::{
+    loaded::LoadStage,
+    play::{ByteProgress, StageProgress},
+};
+
+/// Progress of asset loading.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoadingProgress {
+    /// Progress of each of the `LoadStage::LOADING_STAGES`.
+    pub stages: HashMap<LoadStage, StageProgress>,
+    /// Bytes of textures loaded.
+    pub texture_bytes: ByteProgress,
+    /// Bytes of audio loaded.
+    pub audio_bytes: ByteProgress,
+}
+
+impl LoadingProgress {
+    /// Recalculates the progress of each stage from the assets' current stages.
+    ///
+    /// Deferred assets are not counted.
+    ///
+    /// # Parameters
+    ///
+    /// * `load_stages`: Current `LoadStage` of each asset.
+    pub fn update_stages<I>(&mut self, load_stages: I)
+    where
+        I: IntoIterator<Item = LoadStage>,
+    {
+        let mut stages = LoadStage::LOADING_STAGES
+            .iter()
+            .map(|load_stage| (*load_stage, StageProgress::default()))
+            .collect::<HashMap<LoadStage, StageProgress>>();
+
+        load_stages
+            .into_iter()
+            .filter(|load_stage| *load_stage != LoadStage::Deferred)
+            .for_each(|load_stage| {
+                stages.iter_mut().for_each(|(stage, stage_progress)| {
+                    stage_progress.total += 1;
+                    if load_stage > *stage {
+                        stage_progress.done += 1;
+                    }
+                });
+            });
+
+        self.stages = stages;
+    }
+
+    /// Returns the number of assets that have completed all stages, and the number of assets.
+    pub fn assets(&self) -> StageProgress {
+        LoadStage::LOADING_STAGES
+            .last()
+            .and_then(|load_stage| self.stages.get(load_stage))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the fraction of stages completed across all assets, between `0.` and `1.`.
+    ///
+    /// Returns `1.` if there are no assets to load.
+    pub fn fraction(&self) -> f32 {
+        let (done, total) = self
+            .stages
+            .values()
+            .fold((0, 0), |(done, total), stage_progress| {
+                (done + stage_progress.done, total + stage_progress.total)
+            });
+
+        if total == 0 {
+            1.
+        } else {
+            done as f32 / total as f32
+        }
+    }
+}
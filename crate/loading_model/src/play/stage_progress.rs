@@ -0,0 +1,17 @@
+use derive_new::new;
+
+/// Number of assets that have completed a load stage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct StageProgress {
+    /// Number of assets that have completed the stage.
+    pub done: usize,
+    /// Number of assets to load.
+    pub total: usize,
+}
+
+impl StageProgress {
+    /// Returns whether every asset has completed the stage.
+    pub fn is_complete(self) -> bool {
+        self.done >= self.total
+    }
+}
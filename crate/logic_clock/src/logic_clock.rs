@@ -42,6 +42,11 @@ impl LogicClock {
         !(self.is_beginning() || self.is_complete())
     }
 
+    /// Returns the number of ticks until this clock reaches its limit.
+    pub fn remaining(self) -> usize {
+        self.limit.saturating_sub(self.value)
+    }
+
     /// Increments this clock's value if it hasn't reached its limit.
     ///
     /// **Note:**
@@ -4,8 +4,16 @@ pub use self::{
     map_bounds::MapBounds,
     map_definition::{MapDefinition, MapDefinitionHandle},
     map_header::MapHeader,
+    platform::Platform,
+    platforms::Platforms,
+    spawn_point::SpawnPoint,
+    spawn_points::SpawnPoints,
 };
 
 mod map_bounds;
 mod map_definition;
 mod map_header;
+mod platform;
+mod platforms;
+mod spawn_point;
+mod spawn_points;
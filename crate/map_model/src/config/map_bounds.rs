@@ -1,9 +1,10 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
 /// Boundary of the playable area of the map.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, new, Component)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, Serialize, PartialEq, new, Component)]
 #[storage(DenseVecStorage)]
 pub struct MapBounds {
     /// X coordinate of the map's left boundary.
@@ -1,4 +1,5 @@
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use background_model::config::BackgroundDefinition;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::MapHeader;
 
 /// Defines a playable area that objects can reside in.
-#[derive(Asset, Clone, Debug, Deserialize, Serialize, PartialEq, new)]
+#[derive(Asset, AssetSchema, Clone, Debug, Deserialize, Serialize, PartialEq, new)]
 pub struct MapDefinition {
     /// Base information of the map.
     pub header: MapHeader,
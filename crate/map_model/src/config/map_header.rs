@@ -1,13 +1,22 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
-use crate::config::MapBounds;
+use crate::config::{MapBounds, Platforms, SpawnPoints};
 
 /// Base information of the map.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 pub struct MapHeader {
     /// Name of the map, shown to players.
     pub name: String,
     /// Boundary of the playable area of the map.
     pub bounds: MapBounds,
+    /// Locations to place characters when the game begins.
+    ///
+    /// When there are not enough spawn points, characters are spread evenly across the map.
+    #[serde(default)]
+    pub spawn_points: SpawnPoints,
+    /// Raised ground, ledges and ramps in the map.
+    #[serde(default)]
+    pub platforms: Platforms,
 }
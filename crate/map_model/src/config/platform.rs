@@ -0,0 +1,30 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Raised ground that objects can stand on.
+///
+/// Platforms are axis-aligned boxes that rise from the map's bottom boundary. A platform whose top
+/// surface has a different height at its left and right edges is a ramp.
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Platform {
+    /// X coordinate of the platform's left edge, relative to the map's left boundary.
+    pub x: u32,
+    /// Z coordinate of the platform's back edge, relative to the map's back boundary.
+    pub z: u32,
+    /// Distance that the platform extends to the right.
+    pub width: u32,
+    /// Distance that the platform extends forwards.
+    pub depth: u32,
+    /// Height of the top surface above the map's bottom boundary.
+    pub height: u32,
+    /// Height of the top surface at the platform's right edge, for ramps.
+    ///
+    /// When `None`, the top surface is flat at `height`.
+    pub height_right: Option<u32>,
+    /// Whether objects may pass through the platform from below and from the sides.
+    ///
+    /// Objects only land on one-way platforms when falling onto them from above.
+    pub one_way: bool,
+}
@@ -0,0 +1,13 @@
+use asset_schema::AssetSchema;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Platform;
+
+/// Platforms declared by a map.
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new,
+)]
+#[serde(transparent)]
+pub struct Platforms(pub Vec<Platform>);
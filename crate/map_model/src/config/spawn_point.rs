@@ -0,0 +1,19 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use kinematic_model::config::PositionInit;
+use serde::{Deserialize, Serialize};
+use team_model::play::TeamCounter;
+
+/// Location and facing of a character when a game begins.
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpawnPoint {
+    /// Position to place the character.
+    pub position: PositionInit,
+    /// Whether the character faces left.
+    pub mirrored: bool,
+    /// Team that this spawn point is reserved for.
+    ///
+    /// When `None`, the spawn point is used for players in slot order.
+    pub team: Option<TeamCounter>,
+}
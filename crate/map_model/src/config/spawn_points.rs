@@ -0,0 +1,43 @@
+use asset_schema::AssetSchema;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use team_model::play::TeamCounter;
+
+use crate::config::SpawnPoint;
+
+/// Spawn points declared by a map.
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new,
+)]
+#[serde(transparent)]
+pub struct SpawnPoints(pub Vec<SpawnPoint>);
+
+impl SpawnPoints {
+    /// Returns the spawn point for the player in the given slot.
+    ///
+    /// Only spawn points that are not reserved for a team are considered.
+    ///
+    /// # Parameters
+    ///
+    /// * `slot`: Index of the player, in order of their controller ID.
+    pub fn player(&self, slot: usize) -> Option<&SpawnPoint> {
+        self.0
+            .iter()
+            .filter(|spawn_point| spawn_point.team.is_none())
+            .nth(slot)
+    }
+
+    /// Returns the spawn point for the `index`th member of the given team.
+    ///
+    /// # Parameters
+    ///
+    /// * `team`: Team that the character belongs to.
+    /// * `index`: Index of the character within the team.
+    pub fn team(&self, team: TeamCounter, index: usize) -> Option<&SpawnPoint> {
+        self.0
+            .iter()
+            .filter(|spawn_point| spawn_point.team == Some(team))
+            .nth(index)
+    }
+}
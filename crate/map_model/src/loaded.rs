@@ -2,10 +2,15 @@
 
 pub use self::{
     asset_map_bounds::AssetMapBounds, asset_map_definition_handle::AssetMapDefinitionHandle,
-    asset_margins::AssetMargins, margins::Margins,
+    asset_margins::AssetMargins, asset_spawn_points::AssetSpawnPoints,
+    asset_surfaces::AssetSurfaces, margins::Margins, surface::Surface, surfaces::Surfaces,
 };
 
 mod asset_map_bounds;
 mod asset_map_definition_handle;
 mod asset_margins;
+mod asset_spawn_points;
+mod asset_surfaces;
 mod margins;
+mod surface;
+mod surfaces;
@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SparseSecondaryMap;
+
+use crate::config::SpawnPoints;
+
+/// `SpawnPoints` for an asset.
+pub type AssetSpawnPoints = SparseSecondaryMap<AssetId, SpawnPoints>;
@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SparseSecondaryMap;
+
+use crate::loaded::Surfaces;
+
+/// `Surfaces` for an asset.
+pub type AssetSurfaces = SparseSecondaryMap<AssetId, Surfaces>;
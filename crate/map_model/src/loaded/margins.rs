@@ -1,5 +1,6 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
 use derive_new::new;
+use kinematic_model::config::FixedPoint;
 use serde::{Deserialize, Serialize};
 
 use crate::config::MapBounds;
@@ -9,17 +10,17 @@ use crate::config::MapBounds;
 #[storage(DenseVecStorage)]
 pub struct Margins {
     /// X coordinate of the map's left boundary.
-    pub left: f32,
+    pub left: FixedPoint,
     /// X coordinate of the map's right boundary.
-    pub right: f32,
+    pub right: FixedPoint,
     /// Y coordinate of the map's bottom boundary.
-    pub bottom: f32,
+    pub bottom: FixedPoint,
     /// Y coordinate of the map's top boundary.
-    pub top: f32,
+    pub top: FixedPoint,
     /// Z coordinate of the map's back boundary.
-    pub back: f32,
+    pub back: FixedPoint,
     /// Z coordinate of the map's front boundary.
-    pub front: f32,
+    pub front: FixedPoint,
 }
 
 impl From<MapBounds> for Margins {
@@ -28,12 +29,12 @@ impl From<MapBounds> for Margins {
         // axis upwards on screen.
         let bottom = map_bounds.y + map_bounds.z + map_bounds.depth;
         Margins {
-            left: map_bounds.x as f32,
-            right: (map_bounds.x + map_bounds.width) as f32,
-            bottom: bottom as f32,
-            top: (bottom + map_bounds.height) as f32,
-            back: map_bounds.z as f32,
-            front: (map_bounds.z + map_bounds.depth) as f32,
+            left: FixedPoint::from(map_bounds.x),
+            right: FixedPoint::from(map_bounds.x + map_bounds.width),
+            bottom: FixedPoint::from(bottom),
+            top: FixedPoint::from(bottom + map_bounds.height),
+            back: FixedPoint::from(map_bounds.z),
+            front: FixedPoint::from(map_bounds.z + map_bounds.depth),
         }
     }
 }
@@ -0,0 +1,91 @@
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position};
+
+use crate::{config::Platform, loaded::Margins};
+
+/// Top surface of a platform, in game play coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct Surface {
+    /// X coordinate of the surface's left edge.
+    pub left: FixedPoint,
+    /// X coordinate of the surface's right edge.
+    pub right: FixedPoint,
+    /// Z coordinate of the surface's back edge.
+    pub back: FixedPoint,
+    /// Z coordinate of the surface's front edge.
+    pub front: FixedPoint,
+    /// Y coordinate of the surface at its left edge.
+    pub y_left: FixedPoint,
+    /// Y coordinate of the surface at its right edge.
+    pub y_right: FixedPoint,
+    /// Whether objects may pass through the surface from below and from the sides.
+    pub one_way: bool,
+}
+
+impl Surface {
+    /// Returns the `Surface` of a platform in a map with the given margins.
+    ///
+    /// # Parameters
+    ///
+    /// * `margins`: Margins of the map that the platform is in.
+    /// * `platform`: Platform configuration.
+    pub fn from_platform(margins: &Margins, platform: &Platform) -> Self {
+        let height_right = platform.height_right.unwrap_or(platform.height);
+        Surface {
+            left: margins.left + FixedPoint::from(platform.x),
+            right: margins.left + FixedPoint::from(platform.x + platform.width),
+            back: margins.back + FixedPoint::from(platform.z),
+            front: margins.back + FixedPoint::from(platform.z + platform.depth),
+            y_left: margins.bottom + FixedPoint::from(platform.height),
+            y_right: margins.bottom + FixedPoint::from(height_right),
+            one_way: platform.one_way,
+        }
+    }
+
+    /// Returns whether the position is within the X and Z extents of this surface.
+    pub fn contains(&self, position: Position<FixedPoint>) -> bool {
+        position[0] >= self.left
+            && position[0] <= self.right
+            && position[2] >= self.back
+            && position[2] <= self.front
+    }
+
+    /// Returns the Y coordinate of the surface at the given X coordinate.
+    ///
+    /// X coordinates beyond the surface's edges are treated as being on the nearest edge.
+    pub fn y_at(&self, x: FixedPoint) -> FixedPoint {
+        if self.y_left == self.y_right || self.right <= self.left {
+            self.y_left
+        } else {
+            let x = x.max(self.left).min(self.right);
+            self.y_left + (self.y_right - self.y_left) * (x - self.left) / (self.right - self.left)
+        }
+    }
+
+    /// Returns whether this surface holds up an object that moved between the given positions.
+    ///
+    /// Objects are held up when they are over the surface, and were on or above it on the previous
+    /// frame.
+    pub fn supports(
+        &self,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
+    ) -> bool {
+        self.contains(position) && position_last[1] >= self.y_at(position_last[0])
+    }
+
+    /// Returns whether this surface prevents an object from moving between the given positions.
+    ///
+    /// Solid surfaces block objects from walking or flying into the platform's sides.
+    pub fn blocks(
+        &self,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
+    ) -> bool {
+        !self.one_way
+            && self.contains(position)
+            && !self.contains(position_last)
+            && position[1] < self.y_at(position[0])
+            && !self.supports(position_last, position)
+    }
+}
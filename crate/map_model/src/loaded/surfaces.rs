@@ -0,0 +1,85 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position};
+
+use crate::{
+    config::Platforms,
+    loaded::{Margins, Surface},
+};
+
+/// Platform surfaces of a map.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct Surfaces(pub Vec<Surface>);
+
+impl Surfaces {
+    /// Returns the `Surfaces` of the platforms in a map with the given margins.
+    ///
+    /// # Parameters
+    ///
+    /// * `margins`: Margins of the map that the platforms are in.
+    /// * `platforms`: Platforms declared by the map.
+    pub fn from_platforms(margins: &Margins, platforms: &Platforms) -> Self {
+        let surfaces = platforms
+            .iter()
+            .map(|platform| Surface::from_platform(margins, platform))
+            .collect::<Vec<Surface>>();
+
+        Surfaces(surfaces)
+    }
+
+    /// Returns the Y coordinate of the highest surface holding up an object, if any.
+    ///
+    /// When `None`, the object is only held up by the map's bottom boundary.
+    ///
+    /// # Parameters
+    ///
+    /// * `position_last`: Position of the object on the previous frame.
+    /// * `position`: Position of the object.
+    pub fn supporting_y(
+        &self,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
+    ) -> Option<FixedPoint> {
+        self.0
+            .iter()
+            .filter(|surface| surface.supports(position_last, position))
+            .map(|surface| surface.y_at(position[0]))
+            .max()
+    }
+
+    /// Returns the surface that an object was standing on, if it is still over that surface.
+    ///
+    /// This is used to keep objects on ramps when they walk downhill, instead of becoming airborne.
+    ///
+    /// # Parameters
+    ///
+    /// * `position_last`: Position of the object on the previous frame.
+    /// * `position`: Position of the object.
+    pub fn stood_on(
+        &self,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
+    ) -> Option<&Surface> {
+        self.0.iter().find(|surface| {
+            surface.contains(position_last)
+                && position_last[1] == surface.y_at(position_last[0])
+                && surface.contains(position)
+        })
+    }
+
+    /// Returns whether any solid surface prevents an object from moving between the positions.
+    ///
+    /// # Parameters
+    ///
+    /// * `position_last`: Position of the object on the previous frame.
+    /// * `position`: Position of the object.
+    pub fn blocks(
+        &self,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
+    ) -> bool {
+        self.0
+            .iter()
+            .any(|surface| surface.blocks(position_last, position))
+    }
+}
@@ -1,4 +1,4 @@
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 use map_model::loaded::Margins;
 
 use crate::Comparative;
@@ -22,7 +22,7 @@ impl MapBoundsChecks {
     /// Returns a 3-tuple of `Comparative`s whether the position is within margins on each axis.
     pub fn position_comparative(
         map_margins: &Margins,
-        position: Position<f32>,
+        position: Position<FixedPoint>,
     ) -> (Comparative, Comparative, Comparative) {
         let within_x = Self::value_comparative(map_margins.left, map_margins.right, position[0]);
         let within_y = Self::value_comparative(map_margins.bottom, map_margins.top, position[1]);
@@ -32,7 +32,11 @@ impl MapBoundsChecks {
     }
 
     /// Returns whether the value is between the lower and upper limits (inclusive at both ends).
-    pub fn value_comparative(lower: f32, upper: f32, value: f32) -> Comparative {
+    pub fn value_comparative(
+        lower: FixedPoint,
+        upper: FixedPoint,
+        value: FixedPoint,
+    ) -> Comparative {
         if value >= lower {
             if value <= upper {
                 Comparative::Within
@@ -1,18 +1,22 @@
 use amethyst::{
-    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 use map_model::{
-    loaded::AssetMargins,
+    loaded::{AssetMargins, AssetSurfaces, Surfaces},
     play::{BoundaryFace, MapBoundaryEvent, MapBoundaryEventData, MapBounded},
 };
 use map_selection_model::MapSelection;
+use tracker::Last;
 
 /// Keeps entities within map bounds.
+///
+/// Entities are also kept on top of platform surfaces that hold them up, and out of the sides of
+/// solid platforms.
 #[derive(Debug, Default, new)]
 pub struct KeepWithinMapBoundsSystem {
     /// Reader ID for the `MapBoundaryEvent` channel.
@@ -32,12 +36,18 @@ pub struct KeepWithinMapBoundsSystemData<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
+    /// `AssetSurfaces` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_surfaces: Read<'s, AssetSurfaces>,
     /// `MapBounded` components.
     #[derivative(Debug = "ignore")]
     pub map_boundeds: ReadStorage<'s, MapBounded>,
-    /// `Position<f32>` components.
+    /// `Last<Position<FixedPoint>>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions_last: ReadStorage<'s, Last<Position<FixedPoint>>>,
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
 }
 
 impl<'s> System<'s> for KeepWithinMapBoundsSystem {
@@ -49,16 +59,17 @@ impl<'s> System<'s> for KeepWithinMapBoundsSystem {
             map_boundary_ec,
             map_selection,
             asset_margins,
+            asset_surfaces,
             map_boundeds,
+            positions_last,
             mut positions,
         }: Self::SystemData,
     ) {
+        let map_asset_id = map_selection
+            .asset_id()
+            .expect("Expected `MapSelection` asset ID to exist.");
         let map_margins = asset_margins
-            .get(
-                map_selection
-                    .asset_id()
-                    .expect("Expected `MapSelection` asset ID to exist."),
-            )
+            .get(map_asset_id)
             .expect("Expected `Margins` to be loaded.");
 
         let map_boundary_event_rid = self
@@ -96,6 +107,15 @@ impl<'s> System<'s> for KeepWithinMapBoundsSystem {
                 }
             }
         });
+
+        if let Some(surfaces) = asset_surfaces.get(map_asset_id) {
+            (&map_boundeds, positions_last.maybe(), &mut positions)
+                .join()
+                .for_each(|(_, position_last, position)| {
+                    let position_last = position_last.map(|position_last| **position_last);
+                    Self::keep_on_surfaces(surfaces, position_last.unwrap_or(*position), position);
+                });
+        }
     }
 
     fn setup(&mut self, world: &mut World) {
@@ -108,3 +128,27 @@ impl<'s> System<'s> for KeepWithinMapBoundsSystem {
         );
     }
 }
+
+impl KeepWithinMapBoundsSystem {
+    /// Moves the position onto surfaces that hold it up, and back out of solid platform sides.
+    fn keep_on_surfaces(
+        surfaces: &Surfaces,
+        position_last: Position<FixedPoint>,
+        position: &mut Position<FixedPoint>,
+    ) {
+        if surfaces.blocks(position_last, *position) {
+            position[0] = position_last[0];
+            position[2] = position_last[2];
+        }
+
+        if let Some(surface_y) = surfaces.supporting_y(position_last, *position) {
+            // Landing or walking uphill places the entity on the surface, and walking downhill
+            // keeps the entity on the surface instead of it becoming airborne.
+            let walking_downhill = position[1] <= position_last[1]
+                && surfaces.stood_on(position_last, *position).is_some();
+            if position[1] < surface_y || walking_downhill {
+                position[1] = surface_y;
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use enumflags2::BitFlags;
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 use map_model::{
     loaded::{AssetMargins, Margins},
     play::{BoundaryFace, MapBoundaryEvent, MapBoundaryEventData},
@@ -32,12 +32,12 @@ pub struct MapEnterExitDetectionSystemData<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
-    /// `Last<Position<f32>>` components.
+    /// `Last<Position<FixedPoint>>` components.
     #[derivative(Debug = "ignore")]
-    pub positions_last: ReadStorage<'s, Last<Position<f32>>>,
-    /// `Position<f32>` components.
+    pub positions_last: ReadStorage<'s, Last<Position<FixedPoint>>>,
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
     /// `MapBoundaryEvent` channel.
     #[derivative(Debug = "ignore")]
     pub map_boundary_ec: Write<'s, EventChannel<MapBoundaryEvent>>,
@@ -81,8 +81,8 @@ impl MapEnterExitDetectionSystem {
     fn detect_enter_exit(
         map_margins: &Margins,
         entity: Entity,
-        position_last: Position<f32>,
-        position: Position<f32>,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
     ) -> Option<MapBoundaryEvent> {
         let (within_x_last, within_y_last, within_z_last) =
             MapBoundsChecks::position_comparative(map_margins, position_last);
@@ -6,7 +6,7 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use enumflags2::BitFlags;
-use kinematic_model::config::Position;
+use kinematic_model::config::{FixedPoint, Position};
 use map_model::{
     loaded::{AssetMargins, Margins},
     play::{BoundaryFace, MapBoundaryEvent, MapBoundaryEventData},
@@ -36,9 +36,9 @@ pub struct MapSpawnOutOfBoundsDetectionSystemData<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
     /// `MapBoundaryEvent` channel.
     #[derivative(Debug = "ignore")]
     pub map_boundary_ec: Write<'s, EventChannel<MapBoundaryEvent>>,
@@ -49,7 +49,7 @@ impl MapSpawnOutOfBoundsDetectionSystem {
     fn detect_enter_exit(
         map_margins: &Margins,
         entity: Entity,
-        position: Position<f32>,
+        position: Position<FixedPoint>,
     ) -> Option<MapBoundaryEvent> {
         let (within_x, within_y, within_z) =
             MapBoundsChecks::position_comparative(map_margins, position);
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use asset_ui_model::config::AswPortraits;
 use indexmap::IndexMap;
 use kinematic_model::config::PositionInit;
@@ -8,7 +9,7 @@ use ui_model_spi::config::Dimensions;
 use crate::config::MswLayer;
 
 /// Configuration for initializing the map selection preview.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct MpwTemplate {
     /// Position of the map selection widget on screen.
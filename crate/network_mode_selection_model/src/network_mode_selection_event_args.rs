@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -8,7 +9,7 @@ use crate::NetworkModeIndex;
 /// # Examples
 ///
 /// * `network_mode_selection select -s start_game`
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[structopt(rename_all = "snake_case")]
 pub enum NetworkModeSelectionEventArgs {
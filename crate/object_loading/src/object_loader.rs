@@ -1,19 +1,22 @@
 use std::{collections::HashMap, str::FromStr};
 
-use amethyst::{assets::Handle, renderer::SpriteRender};
-use asset_model::config::AssetType;
+use amethyst::{
+    assets::Handle,
+    renderer::{resources::Tint, SpriteRender},
+};
+use asset_model::config::{AssetSlug, AssetType};
 use audio_loading::AudioLoader;
 use audio_model::loaded::{SourceHandleOpt, SourceSequence, SourceSequenceHandles};
-use character_model::config::CharacterSequenceName;
 use collision_model::{
     config::{Body, Interactions},
     loaded::{
         BodySequence, BodySequenceHandles, InteractionsSequence, InteractionsSequenceHandles,
     },
 };
-use energy_model::config::EnergySequenceName;
+use frame_event_loading::FrameEventsLoader;
+use frame_event_model::loaded::{FrameEvents, FrameEventsSequence, FrameEventsSequenceHandles};
 use kinematic_model::{
-    config::{ObjectAcceleration, Position, Velocity},
+    config::{FixedPoint, ObjectAcceleration, Position, Velocity},
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
 };
 use log::error;
@@ -23,15 +26,26 @@ use object_model::{
 };
 use object_type::ObjectType;
 use sequence_model::{
-    config::{SequenceNameString, Wait},
+    config::{SequenceName, SequenceNameString, Wait},
     loaded::{
-        SequenceEndTransition, SequenceEndTransitions, SequenceId, WaitSequence,
-        WaitSequenceHandles,
+        SequenceEndTransition, SequenceEndTransitions, SequenceId, SequenceIdMappings,
+        WaitSequence, WaitSequenceHandles,
     },
 };
 use serde::{Deserialize, Serialize};
 use spawn_model::loaded::{Spawn, Spawns, SpawnsSequence, SpawnsSequenceHandles};
-use sprite_model::loaded::{SpriteRenderSequence, SpriteRenderSequenceHandles};
+use sprite_loading::{
+    InterpolationSequenceLoader, ScaleSequenceLoader, SpriteOffsetSequenceLoader,
+    TintSequenceLoader,
+};
+use sprite_model::{
+    config::{Interpolation, Scale, SpriteOffset},
+    loaded::{
+        InterpolationSequence, InterpolationSequenceHandles, ScaleSequence, ScaleSequenceHandles,
+        SpriteOffsetSequence, SpriteOffsetSequenceHandles, SpriteRenderSequence,
+        SpriteRenderSequenceHandles, TintSequence, TintSequenceHandles,
+    },
+};
 
 use crate::ObjectLoaderParams;
 
@@ -53,6 +67,7 @@ impl ObjectLoader {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -61,6 +76,11 @@ impl ObjectLoader {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            frame_events_sequence_assets,
+            tint_sequence_assets,
+            scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             sprite_sheet_handles,
             body_assets,
             interactions_assets,
@@ -117,6 +137,11 @@ impl ObjectLoader {
             BodySequenceHandles::default(),
             InteractionsSequenceHandles::default(),
             SpawnsSequenceHandles::default(),
+            FrameEventsSequenceHandles::default(),
+            TintSequenceHandles::default(),
+            ScaleSequenceHandles::default(),
+            SpriteOffsetSequenceHandles::default(),
+            InterpolationSequenceHandles::default(),
         );
         let (
             wait_sequence_handles,
@@ -126,6 +151,11 @@ impl ObjectLoader {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            frame_events_sequence_handles,
+            tint_sequence_handles,
+            scale_sequence_handles,
+            sprite_offset_sequence_handles,
+            interpolation_sequence_handles,
         ) = object_definition.sequences.values().fold(
             sequences_handles,
             |(
@@ -136,6 +166,11 @@ impl ObjectLoader {
                 mut body_sequence_handles,
                 mut interactions_sequence_handles,
                 mut spawns_sequence_handles,
+                mut frame_events_sequence_handles,
+                mut tint_sequence_handles,
+                mut scale_sequence_handles,
+                mut sprite_offset_sequence_handles,
+                mut interpolation_sequence_handles,
             ),
              sequence| {
                 let object_sequence = sequence.object_sequence();
@@ -235,73 +270,76 @@ impl ObjectLoader {
                                     let spawn_asset_id = asset_id_mappings
                                         .id(spawn_asset_slug)
                                         .copied()
-                                        .unwrap_or_else(|| panic!("Asset ID not found for `{}`.", spawn_asset_slug));
+                                        .unwrap_or_else(|| {
+                                            panic!("Asset ID not found for `{}`.", spawn_asset_slug)
+                                        });
                                     let spawn_asset_type = asset_type_mappings
                                         .get(spawn_asset_id)
                                         .expect("Expected `AssetType` mapping to exist.");
                                     let position = {
                                         let position_config = spawn_config.position;
-                                        Position::<f32>::new(position_config.x as f32, position_config.y as f32, position_config.z as f32)
+                                        Position::<FixedPoint>::new(
+                                            FixedPoint::from(position_config.x),
+                                            FixedPoint::from(position_config.y),
+                                            FixedPoint::from(position_config.z),
+                                        )
                                     };
                                     let velocity = {
                                         let velocity_config = spawn_config.velocity;
-                                        Velocity::<f32>::new(velocity_config.x as f32, velocity_config.y as f32, velocity_config.z as f32)
+                                        Velocity::<FixedPoint>::new(
+                                            FixedPoint::from(velocity_config.x),
+                                            FixedPoint::from(velocity_config.y),
+                                            FixedPoint::from(velocity_config.z),
+                                        )
                                     };
 
                                     let sequence_id = match spawn_asset_type {
-                                        AssetType::Object(spawn_object_type) => match spawn_object_type {
-                                            ObjectType::Character => {
-                                                let spawn_sequence_id_mappings = asset_sequence_id_mappings_character.get(spawn_asset_id)
-                                                    .unwrap_or_else(|| panic!("`SequenceIdMappings<Character>` not found for `{}`.", spawn_asset_slug));
-
-                                                if let Some(sequence_string) = spawn_config.sequence.as_ref() {
-                                                    let sequence_name_string = SequenceNameString::from_str(sequence_string).expect("Expected `SequenceNameString::from_str` to succeed.");
-                                                    spawn_sequence_id_mappings.id(&sequence_name_string).copied().unwrap_or_else(|| {
-                                                        let message = format!("Sequence ID not found for string: `{}` in `{}`. Falling back to default.", sequence_string, spawn_asset_slug);
-                                                        error!("{}", message);
-
-                                                        let sequence_default = CharacterSequenceName::default();
-                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
-                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
-                                                    })
-                                                } else {
-                                                    let sequence_default = CharacterSequenceName::default();
-                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
-                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
-                                                }
-                                            }
-                                            ObjectType::Energy => {
-                                                let spawn_sequence_id_mappings = asset_sequence_id_mappings_energy.get(spawn_asset_id)
-                                                    .unwrap_or_else(|| panic!("`SequenceIdMappings<Energy>` not found for `{}`.", spawn_asset_slug));
-
-                                                if let Some(sequence_string) = spawn_config.sequence.as_ref() {
-                                                    let sequence_name_string = SequenceNameString::from_str(sequence_string).expect("Expected `SequenceNameString::from_str` to succeed.");
-                                                    spawn_sequence_id_mappings.id(&sequence_name_string).copied().unwrap_or_else(|| {
-                                                        let message = format!("Sequence ID not found for string: `{}` in `{}`. Falling back to default.", sequence_string, spawn_asset_slug);
-                                                        error!("{}", message);
-
-                                                        let sequence_default = EnergySequenceName::default();
-                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
-                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
-                                                    })
-                                                } else {
-                                                    let sequence_default = EnergySequenceName::default();
-                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
-                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
-                                                }
-                                            }
-                                            ObjectType::TestObject => {
-                                                panic!("Spawning `TestObject`s is not supported.")
-                                            }
-                                        },
-                                        AssetType::Map | AssetType::Ui => panic!("Spawning `Map`s is not supported."),
-                                    };
+                                        AssetType::Object(ObjectType::Character) => {
+                                            Self::spawn_sequence_id(
+                                                asset_sequence_id_mappings_character
+                                                    .get(spawn_asset_id),
+                                                spawn_asset_slug,
+                                                spawn_config.sequence.as_ref(),
+                                            )
+                                        }
+                                        AssetType::Object(ObjectType::Energy) => {
+                                            Self::spawn_sequence_id(
+                                                asset_sequence_id_mappings_energy
+                                                    .get(spawn_asset_id),
+                                                spawn_asset_slug,
+                                                spawn_config.sequence.as_ref(),
+                                            )
+                                        }
+                                        AssetType::Object(ObjectType::Item) => {
+                                            Self::spawn_sequence_id(
+                                                asset_sequence_id_mappings_item.get(spawn_asset_id),
+                                                spawn_asset_slug,
+                                                spawn_config.sequence.as_ref(),
+                                            )
+                                        }
+                                        AssetType::Object(ObjectType::TestObject) => {
+                                            panic!("Spawning `TestObject`s is not supported.")
+                                        }
+                                        AssetType::Map | AssetType::Ui => {
+                                            panic!("Spawning `Map`s is not supported.")
+                                        }
+                                    }
+                                    .unwrap_or_else(|e| {
+                                        error!("{} Falling back to first declared sequence.", e);
+                                        SequenceId::new(0)
+                                    });
 
                                     Spawn {
                                         object: spawn_asset_id,
                                         position,
                                         velocity,
                                         sequence_id,
+                                        count: spawn_config.count,
+                                        spread: spawn_config.spread,
+                                        jitter: spawn_config.jitter,
+                                        condition: spawn_config.condition,
+                                        limit: spawn_config.limit,
+                                        status_effect: spawn_config.status_effect,
                                     }
                                 })
                                 .collect::<Vec<Spawn>>();
@@ -312,6 +350,57 @@ impl ObjectLoader {
                         .collect::<Vec<Handle<Spawns>>>(),
                 );
 
+                let frame_events_sequence = FrameEventsSequence::new(
+                    object_sequence
+                        .sequence
+                        .frames
+                        .iter()
+                        .map(|frame| {
+                            FrameEventsLoader::load(
+                                loader,
+                                source_assets,
+                                &frame.object_frame().events,
+                            )
+                        })
+                        .collect::<Vec<FrameEvents>>(),
+                );
+                let tint_sequence = TintSequence::new(
+                    object_sequence
+                        .sequence
+                        .frames
+                        .iter()
+                        .map(|frame| TintSequenceLoader::frame_to_component(frame.object_frame()))
+                        .collect::<Vec<Tint>>(),
+                );
+                let scale_sequence = ScaleSequence::new(
+                    object_sequence
+                        .sequence
+                        .frames
+                        .iter()
+                        .map(|frame| ScaleSequenceLoader::frame_to_component(frame.object_frame()))
+                        .collect::<Vec<Scale>>(),
+                );
+                let sprite_offset_sequence = SpriteOffsetSequence::new(
+                    object_sequence
+                        .sequence
+                        .frames
+                        .iter()
+                        .map(|frame| {
+                            SpriteOffsetSequenceLoader::frame_to_component(frame.object_frame())
+                        })
+                        .collect::<Vec<SpriteOffset>>(),
+                );
+                let interpolation_sequence = InterpolationSequence::new(
+                    object_sequence
+                        .sequence
+                        .frames
+                        .iter()
+                        .map(|frame| {
+                            InterpolationSequenceLoader::frame_to_component(frame.object_frame())
+                        })
+                        .collect::<Vec<Interpolation>>(),
+                );
+
                 let wait_sequence_handle =
                     loader.load_from_data(wait_sequence, (), wait_sequence_assets);
                 let source_sequence_handle =
@@ -332,6 +421,22 @@ impl ObjectLoader {
                     loader.load_from_data(interactions_sequence, (), interactions_sequence_assets);
                 let spawns_sequence_handle =
                     loader.load_from_data(spawns_sequence, (), spawns_sequence_assets);
+                let frame_events_sequence_handle =
+                    loader.load_from_data(frame_events_sequence, (), frame_events_sequence_assets);
+                let tint_sequence_handle =
+                    loader.load_from_data(tint_sequence, (), tint_sequence_assets);
+                let scale_sequence_handle =
+                    loader.load_from_data(scale_sequence, (), scale_sequence_assets);
+                let sprite_offset_sequence_handle = loader.load_from_data(
+                    sprite_offset_sequence,
+                    (),
+                    sprite_offset_sequence_assets,
+                );
+                let interpolation_sequence_handle = loader.load_from_data(
+                    interpolation_sequence,
+                    (),
+                    interpolation_sequence_assets,
+                );
 
                 wait_sequence_handles.push(wait_sequence_handle);
                 source_sequence_handles.push(source_sequence_handle);
@@ -340,6 +445,11 @@ impl ObjectLoader {
                 body_sequence_handles.push(body_sequence_handle);
                 interactions_sequence_handles.push(interactions_sequence_handle);
                 spawns_sequence_handles.push(spawns_sequence_handle);
+                frame_events_sequence_handles.push(frame_events_sequence_handle);
+                tint_sequence_handles.push(tint_sequence_handle);
+                scale_sequence_handles.push(scale_sequence_handle);
+                sprite_offset_sequence_handles.push(sprite_offset_sequence_handle);
+                interpolation_sequence_handles.push(interpolation_sequence_handle);
 
                 (
                     wait_sequence_handles,
@@ -349,6 +459,11 @@ impl ObjectLoader {
                     body_sequence_handles,
                     interactions_sequence_handles,
                     spawns_sequence_handles,
+                    frame_events_sequence_handles,
+                    tint_sequence_handles,
+                    scale_sequence_handles,
+                    sprite_offset_sequence_handles,
+                    interpolation_sequence_handles,
                 )
             },
         );
@@ -361,7 +476,56 @@ impl ObjectLoader {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            frame_events_sequence_handles,
+            tint_sequence_handles,
+            scale_sequence_handles,
+            sprite_offset_sequence_handles,
+            interpolation_sequence_handles,
             SequenceEndTransitions::new(sequence_end_transitions),
         )
     }
+
+    /// Returns the sequence ID that a spawned object begins with.
+    ///
+    /// If the spawn's sequence is not found, the spawned object's default sequence is used.
+    ///
+    /// # Parameters
+    ///
+    /// * `sequence_id_mappings`: Sequence ID mappings of the spawned object.
+    /// * `spawn_asset_slug`: Slug of the spawned object.
+    /// * `sequence_string`: Sequence specified in the spawn configuration, if any.
+    fn spawn_sequence_id<SeqName>(
+        sequence_id_mappings: Option<&SequenceIdMappings<SeqName>>,
+        spawn_asset_slug: &AssetSlug,
+        sequence_string: Option<&String>,
+    ) -> Result<SequenceId, String>
+    where
+        SeqName: SequenceName,
+    {
+        let sequence_id_mappings = sequence_id_mappings
+            .ok_or_else(|| format!("`SequenceIdMappings` not found for `{}`.", spawn_asset_slug))?;
+
+        let sequence_id = sequence_string.and_then(|sequence_string| {
+            let sequence_name_string = SequenceNameString::<SeqName>::from_str(sequence_string)
+                .expect("Expected `SequenceNameString::from_str` to succeed.");
+            let sequence_id = sequence_id_mappings.id(&sequence_name_string).copied();
+            if sequence_id.is_none() {
+                error!(
+                    "Sequence ID not found for string: `{}` in `{}`. Falling back to default.",
+                    sequence_string, spawn_asset_slug
+                );
+            }
+            sequence_id
+        });
+
+        sequence_id
+            .or_else(|| sequence_id_mappings.id_by_name(SeqName::default()).copied())
+            .ok_or_else(|| {
+                format!(
+                    "`{}` sequence not found for `{}`.",
+                    SeqName::default(),
+                    spawn_asset_slug
+                )
+            })
+    }
 }
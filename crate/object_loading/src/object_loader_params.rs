@@ -12,10 +12,14 @@ use collision_model::{
 };
 use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
+use frame_event_model::loaded::FrameEventsSequence;
+use item_model::config::ItemSequenceName;
 use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
-use sprite_model::loaded::SpriteRenderSequence;
+use sprite_model::loaded::{
+    InterpolationSequence, ScaleSequence, SpriteOffsetSequence, SpriteRenderSequence, TintSequence,
+};
 
 use crate::ObjectLoaderSystemData;
 
@@ -38,6 +42,9 @@ pub struct ObjectLoaderParams<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: &'s AssetSequenceIdMappings<EnergySequenceName>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: &'s AssetSequenceIdMappings<ItemSequenceName>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: &'s AssetStorage<WaitSequence>,
@@ -62,6 +69,21 @@ pub struct ObjectLoaderParams<'s> {
     /// `SpawnsSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: &'s AssetStorage<SpawnsSequence>,
+    /// `FrameEventsSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub frame_events_sequence_assets: &'s AssetStorage<FrameEventsSequence>,
+    /// `TintSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_assets: &'s AssetStorage<TintSequence>,
+    /// `ScaleSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub scale_sequence_assets: &'s AssetStorage<ScaleSequence>,
+    /// `SpriteOffsetSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_assets: &'s AssetStorage<SpriteOffsetSequence>,
+    /// `InterpolationSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub interpolation_sequence_assets: &'s AssetStorage<InterpolationSequence>,
     /// `Body` assets.
     #[derivative(Debug = "ignore")]
     pub body_assets: &'s AssetStorage<Body>,
@@ -90,6 +112,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             ref asset_type_mappings,
             ref asset_sequence_id_mappings_character,
             ref asset_sequence_id_mappings_energy,
+            ref asset_sequence_id_mappings_item,
             ref wait_sequence_assets,
             ref source_assets,
             ref source_sequence_assets,
@@ -98,6 +121,11 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             ref body_sequence_assets,
             ref interactions_sequence_assets,
             ref spawns_sequence_assets,
+            ref frame_events_sequence_assets,
+            ref tint_sequence_assets,
+            ref scale_sequence_assets,
+            ref sprite_offset_sequence_assets,
+            ref interpolation_sequence_assets,
             ref body_assets,
             ref interactions_assets,
             ref spawns_assets,
@@ -109,6 +137,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -117,6 +146,11 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            frame_events_sequence_assets,
+            tint_sequence_assets,
+            scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            interpolation_sequence_assets,
             body_assets,
             interactions_assets,
             spawns_assets,
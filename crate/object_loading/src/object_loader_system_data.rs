@@ -13,10 +13,14 @@ use collision_model::{
 };
 use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
+use frame_event_model::loaded::FrameEventsSequence;
+use item_model::config::ItemSequenceName;
 use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
-use sprite_model::loaded::SpriteRenderSequence;
+use sprite_model::loaded::{
+    InterpolationSequence, ScaleSequence, SpriteOffsetSequence, SpriteRenderSequence, TintSequence,
+};
 
 /// Resources needed to load an object.
 #[derive(Derivative, SystemData)]
@@ -38,6 +42,9 @@ pub struct ObjectLoaderSystemData<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: Read<'s, AssetStorage<WaitSequence>>,
@@ -62,6 +69,21 @@ pub struct ObjectLoaderSystemData<'s> {
     /// `SpawnsSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: Read<'s, AssetStorage<SpawnsSequence>>,
+    /// `FrameEventsSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub frame_events_sequence_assets: Read<'s, AssetStorage<FrameEventsSequence>>,
+    /// `TintSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_assets: Read<'s, AssetStorage<TintSequence>>,
+    /// `ScaleSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub scale_sequence_assets: Read<'s, AssetStorage<ScaleSequence>>,
+    /// `SpriteOffsetSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_assets: Read<'s, AssetStorage<SpriteOffsetSequence>>,
+    /// `InterpolationSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub interpolation_sequence_assets: Read<'s, AssetStorage<InterpolationSequence>>,
     /// `Body` assets.
     #[derivative(Debug = "ignore")]
     pub body_assets: Read<'s, AssetStorage<Body>>,
@@ -1,5 +1,6 @@
 use amethyst::ecs::{storage::VecStorage, Component};
 use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use kinematic_model::config::FixedPoint;
 use numeric_newtype_derive::numeric_newtype;
 use serde::{Deserialize, Serialize};
 
@@ -9,4 +10,4 @@ use serde::{Deserialize, Serialize};
 #[numeric_newtype]
 #[derive(Component, Debug, Default, Deserialize, Serialize)]
 #[storage(VecStorage)]
-pub struct Mass(pub f32);
+pub struct Mass(pub FixedPoint);
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_new::new;
 use indexmap::IndexMap;
@@ -13,7 +14,7 @@ use crate::config::GameObjectSequence;
 /// [char_definition] for characters.
 ///
 /// [char_definition]: ../character/struct.CharacterDefinition.html
-#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
 #[derivative(Default(bound = ""))] // Don't require `ObjSeq: Default`
 pub struct ObjectDefinition<ObjSeq>
 where
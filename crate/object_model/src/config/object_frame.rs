@@ -1,23 +1,25 @@
 use std::path::PathBuf;
 
+use asset_schema::AssetSchema;
 use collision_model::config::{Body, Interactions};
 use derive_new::new;
+use frame_event_model::config::FrameEvents;
 use kinematic_model::config::ObjectAcceleration;
 use sequence_model::config::Wait;
 use serde::{Deserialize, Serialize};
 use spawn_model::config::Spawns;
-use sprite_model::config::SpriteRef;
+use sprite_model::config::{Interpolation, Scale, SpriteOffset, SpriteRef, Tint};
 
 /// Common object behaviour specification that can change each tick.
 ///
 /// Frames are the level of detail that should carry the following information:
 ///
-/// * **Render information:** Sprite sheet, sprite, and transition delay.
+/// * **Render information:** Sprite sheet, sprite, tint, scale, and transition delay.
 /// * **Interaction:** Collision zones, type of interactions.
-/// * **Effects:** Sound(s) to play.
+/// * **Effects:** Sound(s) to play, and other frame events.
 /// * **Spawning:** Spawning additional object(s).
 /// * **Weapon:** Where an active weapon should be.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct ObjectFrame {
     /// Number of ticks to wait before the sequence switches to the next frame.
@@ -28,12 +30,22 @@ pub struct ObjectFrame {
     pub acceleration: Option<ObjectAcceleration>,
     /// Sprite to render.
     pub sprite: SpriteRef,
+    /// Tint to apply to the sprite.
+    pub tint: Tint,
+    /// Scaling to apply to the sprite.
+    pub scale: Scale,
+    /// Pixel offsets to shift the sprite by.
+    pub offset: SpriteOffset,
+    /// How the tint, scale, and offset blend into the next frame's values.
+    pub interpolation: Interpolation,
     /// Hittable volume of the object.
     pub body: Body,
     /// Interaction volumes of the object.
     pub interactions: Interactions,
     /// Objects to spawn.
     pub spawns: Spawns,
+    /// Events that happen when this frame begins.
+    pub events: FrameEvents,
 }
 
 impl AsRef<Wait> for ObjectFrame {
@@ -41,3 +53,27 @@ impl AsRef<Wait> for ObjectFrame {
         &self.wait
     }
 }
+
+impl AsRef<Tint> for ObjectFrame {
+    fn as_ref(&self) -> &Tint {
+        &self.tint
+    }
+}
+
+impl AsRef<Scale> for ObjectFrame {
+    fn as_ref(&self) -> &Scale {
+        &self.scale
+    }
+}
+
+impl AsRef<SpriteOffset> for ObjectFrame {
+    fn as_ref(&self) -> &SpriteOffset {
+        &self.offset
+    }
+}
+
+impl AsRef<Interpolation> for ObjectFrame {
+    fn as_ref(&self) -> &Interpolation {
+        &self.interpolation
+    }
+}
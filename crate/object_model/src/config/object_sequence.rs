@@ -7,6 +7,7 @@
 //! because different object types have different valid sequence names, and we want to be able to
 //! define this at compile time rather than needing to process this at run time.
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::ObjectAcceleration;
 use sequence_model::config::{Sequence, SequenceName, Wait};
@@ -18,7 +19,7 @@ use crate::config::{GameObjectFrame, ObjectFrame};
 ///
 /// This carries the information necessary for an `Animation`, as well as the effects and
 /// interactions that happen during each frame of that animation.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct ObjectSequence<SeqName, Frame = ObjectFrame>
 where
@@ -2,10 +2,14 @@ use audio_model::loaded::SourceSequenceHandles;
 use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
 use derivative::Derivative;
 use derive_new::new;
+use frame_event_model::loaded::FrameEventsSequenceHandles;
 use kinematic_model::loaded::ObjectAccelerationSequenceHandles;
 use sequence_model::loaded::{SequenceEndTransitions, WaitSequenceHandles};
 use spawn_model::loaded::SpawnsSequenceHandles;
-use sprite_model::loaded::SpriteRenderSequenceHandles;
+use sprite_model::loaded::{
+    InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+    SpriteRenderSequenceHandles, TintSequenceHandles,
+};
 
 /// Represents an in-game object that has been loaded.
 #[allow(clippy::too_many_arguments)]
@@ -26,6 +30,16 @@ pub struct Object {
     pub interactions_sequence_handles: InteractionsSequenceHandles,
     /// Handles to `SpawnsSequence`s that this object uses.
     pub spawns_sequence_handles: SpawnsSequenceHandles,
+    /// Handles to `FrameEventsSequence`s that this object uses.
+    pub frame_events_sequence_handles: FrameEventsSequenceHandles,
+    /// Handles to `TintSequence`s that this object uses.
+    pub tint_sequence_handles: TintSequenceHandles,
+    /// Handles to `ScaleSequence`s that this object uses.
+    pub scale_sequence_handles: ScaleSequenceHandles,
+    /// Handles to `SpriteOffsetSequence`s that this object uses.
+    pub sprite_offset_sequence_handles: SpriteOffsetSequenceHandles,
+    /// Handles to `InterpolationSequence`s that this object uses.
+    pub interpolation_sequence_handles: InterpolationSequenceHandles,
     /// Sequence transition when a sequence ends.
     pub sequence_end_transitions: SequenceEndTransitions,
 }
@@ -7,13 +7,17 @@ use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::ControllerInput;
 use kinematic_model::config::{
-    ObjectAcceleration, ObjectAccelerationKind, ObjectAccelerationValue,
+    FixedPoint, ObjectAcceleration, ObjectAccelerationKind, ObjectAccelerationValue,
     ObjectAccelerationValueExpr, ObjectAccelerationValueMultiplier, Velocity,
 };
 use mirrored_model::play::Mirrored;
+use object_status_model::play::StatusEffects;
 use sequence_model::play::SequenceUpdateEvent;
 
 /// Increases velocity of `Object`s based on their `ObjectAcceleration`.
+///
+/// Acceleration along the X and Z axes is scaled by the object's `SpeedUp` and `SpeedDown` status
+/// effects.
 #[derive(Debug, Default, new)]
 pub struct ObjectAccelerationSystem {
     /// Reader ID for the `SequenceUpdateEvent` event channel.
@@ -36,33 +40,44 @@ pub struct ObjectAccelerationSystemData<'s> {
     /// `ObjectAcceleration` components.
     #[derivative(Debug = "ignore")]
     pub object_accelerations: ReadStorage<'s, ObjectAcceleration>,
-    /// `Velocity<f32>` components.
+    /// `StatusEffects` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub status_effectses: ReadStorage<'s, StatusEffects>,
+    /// `Velocity<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
 }
 
 impl ObjectAccelerationSystem {
     fn update_velocity(
         controller_input: Option<ControllerInput>,
         mirrored: Option<Mirrored>,
+        status_effects: Option<&StatusEffects>,
         object_acceleration: ObjectAcceleration,
-        velocity: &mut Velocity<f32>,
+        velocity: &mut Velocity<FixedPoint>,
     ) {
         let negate = mirrored.map(|mirrored| mirrored.0).unwrap_or(false);
-        let acc_x = Self::acceleration_value(controller_input, object_acceleration.x);
+        let speed_multiplier = status_effects
+            .map(|status_effects| {
+                FixedPoint::from_ratio(i64::from(status_effects.speed_percent()), 100)
+            })
+            .unwrap_or(FixedPoint::ONE);
+        let acc_x =
+            Self::acceleration_value(controller_input, object_acceleration.x) * speed_multiplier;
         if negate {
             velocity[0] -= acc_x;
         } else {
             velocity[0] += acc_x;
         }
         velocity[1] += Self::acceleration_value(controller_input, object_acceleration.y);
-        velocity[2] += Self::acceleration_value(controller_input, object_acceleration.z);
+        velocity[2] +=
+            Self::acceleration_value(controller_input, object_acceleration.z) * speed_multiplier;
     }
 
     fn acceleration_value(
         controller_input: Option<ControllerInput>,
         object_acceleration_value: ObjectAccelerationValue,
-    ) -> f32 {
+    ) -> FixedPoint {
         match object_acceleration_value {
             ObjectAccelerationValue::Const(value) => value,
             ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr { multiplier, value }) => {
@@ -71,13 +86,15 @@ impl ObjectAccelerationSystem {
                     ObjectAccelerationValueMultiplier::XAxis => {
                         let multiplier = controller_input
                             .map(|controller_input| controller_input.x_axis_value.abs())
-                            .unwrap_or(0.);
+                            .map(FixedPoint::from)
+                            .unwrap_or(FixedPoint::ZERO);
                         multiplier * value
                     }
                     ObjectAccelerationValueMultiplier::ZAxis => {
                         let multiplier = controller_input
                             .map(|controller_input| controller_input.z_axis_value)
-                            .unwrap_or(0.);
+                            .map(FixedPoint::from)
+                            .unwrap_or(FixedPoint::ZERO);
                         multiplier * value
                     }
                 }
@@ -96,6 +113,7 @@ impl<'s> System<'s> for ObjectAccelerationSystem {
             controller_inputs,
             mirroreds,
             object_accelerations,
+            status_effectses,
             mut velocities,
         }: Self::SystemData,
     ) {
@@ -114,6 +132,7 @@ impl<'s> System<'s> for ObjectAccelerationSystem {
                     let velocity = velocities.get_mut(entity);
                     let controller_input = controller_inputs.get(entity).copied();
                     let mirrored = mirroreds.get(entity).copied();
+                    let status_effects = status_effectses.get(entity);
 
                     if let (Some(object_acceleration), Some(velocity)) =
                         (object_acceleration, velocity)
@@ -122,6 +141,7 @@ impl<'s> System<'s> for ObjectAccelerationSystem {
                             Self::update_velocity(
                                 controller_input,
                                 mirrored,
+                                status_effects,
                                 *object_acceleration,
                                 velocity,
                             );
@@ -135,16 +155,18 @@ impl<'s> System<'s> for ObjectAccelerationSystem {
             &mut velocities,
             controller_inputs.maybe(),
             mirroreds.maybe(),
+            status_effectses.maybe(),
         )
             .join()
-            .filter(|(object_acceleration, _, _, _)| {
+            .filter(|(object_acceleration, _, _, _, _)| {
                 object_acceleration.kind == ObjectAccelerationKind::Continuous
             })
             .for_each(
-                |(object_acceleration, velocity, controller_input, mirrored)| {
+                |(object_acceleration, velocity, controller_input, mirrored, status_effects)| {
                     Self::update_velocity(
                         controller_input.copied(),
                         mirrored.copied(),
+                        status_effects,
                         *object_acceleration,
                         velocity,
                     );
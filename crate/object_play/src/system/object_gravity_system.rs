@@ -4,7 +4,7 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Velocity;
+use kinematic_model::config::{FixedPoint, Velocity};
 use object_model::{config::Mass, play::Grounding};
 
 /// Increases velocity of `Object`s that have `Mass` and are `Airborne`.
@@ -20,9 +20,9 @@ pub struct ObjectGravitySystemData<'s> {
     /// `Mass` components.
     #[derivative(Debug = "ignore")]
     pub masses: ReadStorage<'s, Mass>,
-    /// `Velocity<f32>` components.
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
 }
 
 impl<'s> System<'s> for ObjectGravitySystem {
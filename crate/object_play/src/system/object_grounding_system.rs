@@ -4,12 +4,16 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Position;
-use map_model::loaded::AssetMargins;
+use kinematic_model::config::{FixedPoint, Position};
+use map_model::loaded::{AssetMargins, AssetSurfaces};
 use map_selection_model::MapSelection;
 use object_model::play::Grounding;
+use tracker::Last;
 
-/// Updates `Grounding` to `Airborne` for objects above the map bottom boundary.
+/// Updates `Grounding` to `Airborne` for objects above the ground.
+///
+/// The ground is the highest platform surface holding up the object, or the map bottom boundary
+/// when there is none.
 #[derive(Debug, Default, new)]
 pub struct ObjectGroundingSystem;
 
@@ -22,9 +26,15 @@ pub struct ObjectGroundingSystemData<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
-    /// `Position<f32>` components.
+    /// `AssetSurfaces` resource.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
+    pub asset_surfaces: Read<'s, AssetSurfaces>,
+    /// `Last<Position<FixedPoint>>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions_last: ReadStorage<'s, Last<Position<FixedPoint>>>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
     /// `Grounding` components.
     #[derivative(Debug = "ignore")]
     pub groundings: WriteStorage<'s, Grounding>,
@@ -38,24 +48,33 @@ impl<'s> System<'s> for ObjectGroundingSystem {
         ObjectGroundingSystemData {
             map_selection,
             asset_margins,
+            asset_surfaces,
+            positions_last,
             positions,
             mut groundings,
         }: Self::SystemData,
     ) {
+        let map_asset_id = map_selection
+            .asset_id()
+            .expect("Expected `MapSelection` asset ID to exist.");
         let map_margins = asset_margins
-            .get(
-                map_selection
-                    .asset_id()
-                    .expect("Expected `MapSelection` asset ID to exist."),
-            )
+            .get(map_asset_id)
             .expect("Expected `Margins` to be loaded.");
+        let surfaces = asset_surfaces.get(map_asset_id);
 
-        (&positions, &mut groundings)
+        (positions_last.maybe(), &positions, &mut groundings)
             .join()
-            .for_each(|(position, grounding)| {
-                if position[1] > map_margins.bottom {
+            .for_each(|(position_last, position, grounding)| {
+                let position_last = position_last
+                    .map(|position_last| **position_last)
+                    .unwrap_or(*position);
+                let ground_y = surfaces
+                    .and_then(|surfaces| surfaces.supporting_y(position_last, *position))
+                    .unwrap_or(map_margins.bottom);
+
+                if position[1] > ground_y {
                     *grounding = Grounding::Airborne;
-                } else if position[1] < map_margins.bottom {
+                } else if position[1] < ground_y {
                     *grounding = Grounding::Underground;
                 } else {
                     *grounding = Grounding::OnGround;
@@ -1,5 +1,13 @@
 //! Contains the types that represent the configuration on disk.
 
-pub use self::stun_points::{StunPoints, STUN_POINTS_DEFAULT};
+pub use self::{
+    status_effect::StatusEffect,
+    status_effect_kind::StatusEffectKind,
+    status_effect_stacking::{StatusEffectStacking, STATUS_EFFECT_STACKS_MAX},
+    stun_points::{StunPoints, STUN_POINTS_DEFAULT},
+};
 
+mod status_effect;
+mod status_effect_kind;
+mod status_effect_stacking;
 mod stun_points;
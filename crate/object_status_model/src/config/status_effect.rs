@@ -0,0 +1,19 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{StatusEffectKind, StatusEffectStacking};
+
+/// Timed effect to apply to an object.
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct StatusEffect {
+    /// Kind of status effect.
+    pub kind: StatusEffectKind,
+    /// Number of ticks the effect lasts for.
+    pub duration: u32,
+    /// How the effect is applied when the object already has an effect of the same kind.
+    #[new(default)]
+    #[serde(default)]
+    pub stacking: StatusEffectStacking,
+}
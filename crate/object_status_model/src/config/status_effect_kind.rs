@@ -0,0 +1,46 @@
+use asset_schema::AssetSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+/// Kinds of timed effects that may be applied to an object.
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Deserialize, Display, EnumIter, Hash, PartialEq, Eq, Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StatusEffectKind {
+    /// Object accelerates faster when moving.
+    SpeedUp,
+    /// Object accelerates slower when moving.
+    SpeedDown,
+    /// Object's hits deal more damage.
+    DamageUp,
+    /// Object's hits deal less damage.
+    DamageDown,
+    /// Object is not affected by hits.
+    Invulnerable,
+    /// Object loses health points periodically.
+    Poison,
+    /// Object loses health points periodically, faster than `Poison`.
+    Burn,
+    /// Object's sequence and movement are halted.
+    Freeze,
+}
+
+impl StatusEffectKind {
+    /// Returns the colour that represents this status effect.
+    ///
+    /// This is used to tint the affected object, and for the effect's HUD icon.
+    pub fn colour(self) -> [f32; 4] {
+        match self {
+            StatusEffectKind::SpeedUp => [0.6, 1., 1., 1.],
+            StatusEffectKind::SpeedDown => [0.6, 0.6, 0.8, 1.],
+            StatusEffectKind::DamageUp => [1., 0.5, 0.5, 1.],
+            StatusEffectKind::DamageDown => [0.7, 0.7, 0.7, 1.],
+            StatusEffectKind::Invulnerable => [1., 1., 0.6, 1.],
+            StatusEffectKind::Poison => [0.6, 1., 0.5, 1.],
+            StatusEffectKind::Burn => [1., 0.6, 0.3, 1.],
+            StatusEffectKind::Freeze => [0.5, 0.8, 1., 1.],
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use asset_schema::AssetSchema;
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of stacks of a status effect.
+pub const STATUS_EFFECT_STACKS_MAX: u32 = 3;
+
+/// How a status effect is applied when the object already has an effect of the same kind.
+#[derive(AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum StatusEffectStacking {
+    /// Existing effect's duration is reset to the new duration.
+    #[derivative(Default)]
+    Refresh,
+    /// New duration is added to the existing effect's remaining duration.
+    Extend,
+    /// Existing effect gains a stack, up to `STATUS_EFFECT_STACKS_MAX`, and its duration is reset.
+    Stack,
+    /// New effect is ignored.
+    Ignore,
+}
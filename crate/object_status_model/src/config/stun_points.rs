@@ -1,4 +1,5 @@
 use amethyst::ecs::{storage::VecStorage, Component};
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
 use numeric_newtype_derive::numeric_newtype;
@@ -9,7 +10,7 @@ pub const STUN_POINTS_DEFAULT: u32 = 30;
 
 /// Stun points of an object.
 #[numeric_newtype]
-#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[derive(AssetSchema, Component, Debug, Derivative, Deserialize, Hash, Serialize)]
 #[derivative(Default)]
 #[storage(VecStorage)]
 pub struct StunPoints(#[derivative(Default(value = "STUN_POINTS_DEFAULT"))] pub u32);
@@ -3,3 +3,4 @@
 //! Types used to represent object status.
 
 pub mod config;
+pub mod play;
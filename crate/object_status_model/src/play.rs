@@ -0,0 +1,13 @@
+//! Contains data types used during game play.
+
+pub use self::{
+    status_effect_clock::StatusEffectClock,
+    status_effect_entry::StatusEffectEntry,
+    status_effect_event::StatusEffectEvent,
+    status_effects::{StatusEffects, STATUS_EFFECT_MODIFIER_PERCENT},
+};
+
+mod status_effect_clock;
+mod status_effect_entry;
+mod status_effect_event;
+mod status_effects;
@@ -0,0 +1,24 @@
+use derive_new::new;
+
+use crate::{config::StatusEffectKind, play::StatusEffectClock};
+
+/// Status effect that an object currently has.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct StatusEffectEntry {
+    /// Kind of status effect.
+    pub kind: StatusEffectKind,
+    /// Logic clock to track how long the effect has lasted.
+    ///
+    /// When this clock has reached its limit, the effect is removed.
+    pub clock: StatusEffectClock,
+    /// Number of times the effect is stacked.
+    #[new(value = "1")]
+    pub stacks: u32,
+}
+
+impl StatusEffectEntry {
+    /// Returns the number of ticks until this effect is removed.
+    pub fn remaining(&self) -> usize {
+        self.clock.limit.saturating_sub(self.clock.value)
+    }
+}
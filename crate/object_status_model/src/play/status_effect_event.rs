@@ -0,0 +1,13 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+
+use crate::config::StatusEffect;
+
+/// Event indicating a status effect should be applied to an object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct StatusEffectEvent {
+    /// Entity to apply the status effect to.
+    pub entity: Entity,
+    /// Status effect to apply.
+    pub status_effect: StatusEffect,
+}
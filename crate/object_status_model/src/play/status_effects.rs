@@ -0,0 +1,76 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::{
+    config::{StatusEffect, StatusEffectKind, StatusEffectStacking, STATUS_EFFECT_STACKS_MAX},
+    play::{StatusEffectClock, StatusEffectEntry},
+};
+
+/// Percentage that each stack of a speed or damage status effect changes the value by.
+pub const STATUS_EFFECT_MODIFIER_PERCENT: u32 = 25;
+
+/// Status effects that an object currently has, in the order they were first applied.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+pub struct StatusEffects(pub Vec<StatusEffectEntry>);
+
+impl StatusEffects {
+    /// Applies a status effect, following its `StatusEffectStacking` if the object already has an
+    /// effect of the same kind.
+    pub fn apply(&mut self, status_effect: StatusEffect) {
+        let StatusEffect {
+            kind,
+            duration,
+            stacking,
+        } = status_effect;
+        let duration = duration as usize;
+
+        if let Some(entry) = self.iter_mut().find(|entry| entry.kind == kind) {
+            match stacking {
+                StatusEffectStacking::Refresh => entry.clock = StatusEffectClock::new(duration),
+                StatusEffectStacking::Extend => entry.clock.limit += duration,
+                StatusEffectStacking::Stack => {
+                    entry.stacks = (entry.stacks + 1).min(STATUS_EFFECT_STACKS_MAX);
+                    entry.clock = StatusEffectClock::new(duration);
+                }
+                StatusEffectStacking::Ignore => {}
+            }
+        } else {
+            self.push(StatusEffectEntry::new(
+                kind,
+                StatusEffectClock::new(duration),
+            ));
+        }
+    }
+
+    /// Returns the entry for the given kind of status effect, if the object has it.
+    pub fn get(&self, kind: StatusEffectKind) -> Option<&StatusEffectEntry> {
+        self.iter().find(|entry| entry.kind == kind)
+    }
+
+    /// Returns whether the object has the given kind of status effect.
+    pub fn contains(&self, kind: StatusEffectKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    /// Returns the number of stacks of the given kind of status effect, `0` if there are none.
+    pub fn stacks(&self, kind: StatusEffectKind) -> u32 {
+        self.get(kind).map(|entry| entry.stacks).unwrap_or(0)
+    }
+
+    /// Returns the percentage to scale the object's movement acceleration by.
+    pub fn speed_percent(&self) -> u32 {
+        self.modifier_percent(StatusEffectKind::SpeedUp, StatusEffectKind::SpeedDown)
+    }
+
+    /// Returns the percentage to scale the damage of the object's hits by.
+    pub fn damage_percent(&self) -> u32 {
+        self.modifier_percent(StatusEffectKind::DamageUp, StatusEffectKind::DamageDown)
+    }
+
+    fn modifier_percent(&self, kind_up: StatusEffectKind, kind_down: StatusEffectKind) -> u32 {
+        let percent_up = self.stacks(kind_up) * STATUS_EFFECT_MODIFIER_PERCENT;
+        let percent_down = self.stacks(kind_down) * STATUS_EFFECT_MODIFIER_PERCENT;
+        (100 + percent_up).saturating_sub(percent_down)
+    }
+}
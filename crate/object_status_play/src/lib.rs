@@ -2,6 +2,10 @@
 
 //! Provides logic used during game play.
 
-pub use crate::system::StunPointsReductionSystem;
+pub use crate::system::{
+    StatusEffectApplySystem, StatusEffectHitSystem, StatusEffectSpawnSystem,
+    StatusEffectTickSystem, StunPointsReductionSystem, BURN_HP_DAMAGE, BURN_INTERVAL,
+    POISON_HP_DAMAGE, POISON_INTERVAL,
+};
 
 mod system;
@@ -1,3 +1,15 @@
-pub use self::stun_points_reduction_system::StunPointsReductionSystem;
+pub use self::{
+    status_effect_apply_system::StatusEffectApplySystem,
+    status_effect_hit_system::StatusEffectHitSystem,
+    status_effect_spawn_system::StatusEffectSpawnSystem,
+    status_effect_tick_system::{
+        StatusEffectTickSystem, BURN_HP_DAMAGE, BURN_INTERVAL, POISON_HP_DAMAGE, POISON_INTERVAL,
+    },
+    stun_points_reduction_system::StunPointsReductionSystem,
+};
 
+mod status_effect_apply_system;
+mod status_effect_hit_system;
+mod status_effect_spawn_system;
+mod status_effect_tick_system;
 mod stun_points_reduction_system;
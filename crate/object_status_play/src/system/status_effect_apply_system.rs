@@ -0,0 +1,98 @@
+use amethyst::{
+    ecs::{Entities, Read, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use object_status_model::{
+    config::StatusEffectKind,
+    play::{StatusEffectEvent, StatusEffects},
+};
+use sequence_model::play::FrameFreezeClock;
+
+/// Applies status effects from `StatusEffectEvent`s to objects.
+///
+/// `Freeze` effects halt the object's sequence and movement with a `FrameFreezeClock` that lasts as
+/// long as the effect, unless the object already has a `FrameFreezeClock` that lasts longer.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectApplySystem {
+    /// Reader ID for the `StatusEffectEvent` channel.
+    #[new(default)]
+    status_effect_event_rid: Option<ReaderId<StatusEffectEvent>>,
+}
+
+/// `StatusEffectApplySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectApplySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `StatusEffectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub status_effect_ec: Read<'s, EventChannel<StatusEffectEvent>>,
+    /// `StatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effectses: WriteStorage<'s, StatusEffects>,
+    /// `FrameFreezeClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_freeze_clocks: WriteStorage<'s, FrameFreezeClock>,
+}
+
+impl<'s> System<'s> for StatusEffectApplySystem {
+    type SystemData = StatusEffectApplySystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectApplySystemData {
+            entities,
+            status_effect_ec,
+            mut status_effectses,
+            mut frame_freeze_clocks,
+        }: Self::SystemData,
+    ) {
+        let status_effect_event_rid = self
+            .status_effect_event_rid
+            .as_mut()
+            .expect("Expected `status_effect_event_rid` field to be set.");
+
+        status_effect_ec
+            .read(status_effect_event_rid)
+            // The entity may have been deleted since the event was sent.
+            .filter(|ev| entities.is_alive(ev.entity))
+            .for_each(|ev| {
+                let status_effects = status_effectses
+                    .entry(ev.entity)
+                    .expect("Failed to get `StatusEffects` entry.")
+                    .or_insert_with(StatusEffects::default);
+                status_effects.apply(ev.status_effect);
+
+                if ev.status_effect.kind == StatusEffectKind::Freeze {
+                    let freeze_remaining = status_effects
+                        .get(StatusEffectKind::Freeze)
+                        .map(|entry| entry.remaining())
+                        .unwrap_or(0);
+                    let is_longer_freeze = frame_freeze_clocks
+                        .get(ev.entity)
+                        .map(|frame_freeze_clock| frame_freeze_clock.remaining() < freeze_remaining)
+                        .unwrap_or(true);
+                    if is_longer_freeze {
+                        frame_freeze_clocks
+                            .insert(ev.entity, FrameFreezeClock::new(freeze_remaining))
+                            .expect("Failed to insert `FrameFreezeClock` component.");
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.status_effect_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<StatusEffectEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::{Hit, Interaction, InteractionKind},
+    play::HitEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use object_status_model::{
+    config::StatusEffectKind,
+    play::{StatusEffectEvent, StatusEffects},
+};
+
+/// Sends `StatusEffectEvent`s for `Hit`s that apply a status effect.
+///
+/// Objects that are `Invulnerable` are not affected.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectHitSystem {
+    /// Reader ID for the `HitEvent` event channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `StatusEffectHitSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectHitSystemData<'s> {
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `StatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effectses: ReadStorage<'s, StatusEffects>,
+    /// `StatusEffectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub status_effect_ec: Write<'s, EventChannel<StatusEffectEvent>>,
+}
+
+impl<'s> System<'s> for StatusEffectHitSystem {
+    type SystemData = StatusEffectHitSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectHitSystemData {
+            hit_ec,
+            status_effectses,
+            mut status_effect_ec,
+        }: Self::SystemData,
+    ) {
+        let hit_event_rid = self
+            .hit_event_rid
+            .as_mut()
+            .expect("Expected `hit_event_rid` field to be set.");
+
+        let status_effect_events = hit_ec
+            .read(hit_event_rid)
+            .filter_map(|ev| {
+                if let Interaction {
+                    kind:
+                        InteractionKind::Hit(Hit {
+                            status_effect: Some(status_effect),
+                            ..
+                        }),
+                    ..
+                } = ev.interaction
+                {
+                    Some(StatusEffectEvent::new(ev.to, status_effect))
+                } else {
+                    None
+                }
+            })
+            .filter(|status_effect_event| {
+                let invulnerable = status_effectses
+                    .get(status_effect_event.entity)
+                    .map(|status_effects| status_effects.contains(StatusEffectKind::Invulnerable))
+                    .unwrap_or(false);
+                !invulnerable
+            })
+            .collect::<Vec<StatusEffectEvent>>();
+
+        status_effect_ec.iter_write(status_effect_events);
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
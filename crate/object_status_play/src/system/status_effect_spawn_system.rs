@@ -0,0 +1,67 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use object_status_model::play::StatusEffectEvent;
+use spawn_model::play::SpawnEvent;
+
+/// Sends `StatusEffectEvent`s for spawned objects whose `Spawn` applies a status effect.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectSpawnSystem {
+    /// Reader ID for the `SpawnEvent` channel.
+    #[new(default)]
+    spawn_event_rid: Option<ReaderId<SpawnEvent>>,
+}
+
+/// `StatusEffectSpawnSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectSpawnSystemData<'s> {
+    /// `SpawnEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub spawn_ec: Read<'s, EventChannel<SpawnEvent>>,
+    /// `StatusEffectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub status_effect_ec: Write<'s, EventChannel<StatusEffectEvent>>,
+}
+
+impl<'s> System<'s> for StatusEffectSpawnSystem {
+    type SystemData = StatusEffectSpawnSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectSpawnSystemData {
+            spawn_ec,
+            mut status_effect_ec,
+        }: Self::SystemData,
+    ) {
+        let spawn_event_rid = self
+            .spawn_event_rid
+            .as_mut()
+            .expect("Expected `spawn_event_rid` field to be set.");
+
+        let status_effect_events = spawn_ec
+            .read(spawn_event_rid)
+            .filter_map(|ev| {
+                ev.spawn
+                    .status_effect
+                    .map(|status_effect| StatusEffectEvent::new(ev.entity_spawned, status_effect))
+            })
+            .collect::<Vec<StatusEffectEvent>>();
+
+        status_effect_ec.iter_write(status_effect_events);
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.spawn_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<SpawnEvent>>()
+                .register_reader(),
+        );
+    }
+}
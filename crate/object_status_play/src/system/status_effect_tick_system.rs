@@ -0,0 +1,91 @@
+use amethyst::{
+    ecs::{Join, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use object_model::play::HealthPoints;
+use object_status_model::{
+    config::StatusEffectKind,
+    play::{StatusEffectEntry, StatusEffects},
+};
+
+/// Number of ticks between each `Poison` health point reduction.
+pub const POISON_INTERVAL: usize = 20;
+/// Health points that each stack of `Poison` reduces per interval.
+pub const POISON_HP_DAMAGE: u32 = 2;
+/// Number of ticks between each `Burn` health point reduction.
+pub const BURN_INTERVAL: usize = 10;
+/// Health points that each stack of `Burn` reduces per interval.
+pub const BURN_HP_DAMAGE: u32 = 3;
+
+/// Ticks status effects, reducing health points for `Poison` and `Burn`, and removing effects that
+/// have ended.
+///
+/// `Poison` and `Burn` do not reduce health points below 1, so an object is only defeated by a hit.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectTickSystem;
+
+/// `StatusEffectTickSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectTickSystemData<'s> {
+    /// `StatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effectses: WriteStorage<'s, StatusEffects>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+}
+
+impl StatusEffectTickSystem {
+    /// Returns the health points that the status effect reduces on this tick.
+    fn hp_damage(status_effect_entry: &StatusEffectEntry) -> u32 {
+        let (interval, hp_damage) = match status_effect_entry.kind {
+            StatusEffectKind::Poison => (POISON_INTERVAL, POISON_HP_DAMAGE),
+            StatusEffectKind::Burn => (BURN_INTERVAL, BURN_HP_DAMAGE),
+            _ => return 0,
+        };
+
+        if status_effect_entry.clock.value % interval == 0 {
+            hp_damage * status_effect_entry.stacks
+        } else {
+            0
+        }
+    }
+}
+
+impl<'s> System<'s> for StatusEffectTickSystem {
+    type SystemData = StatusEffectTickSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectTickSystemData {
+            mut status_effectses,
+            mut health_pointses,
+        }: Self::SystemData,
+    ) {
+        (&mut status_effectses, (&mut health_pointses).maybe())
+            .join()
+            .for_each(|(status_effects, health_points)| {
+                let hp_damage = status_effects
+                    .iter_mut()
+                    .map(|status_effect_entry| {
+                        status_effect_entry.clock.tick();
+                        Self::hp_damage(status_effect_entry)
+                    })
+                    .sum::<u32>();
+
+                if let Some(health_points) = health_points {
+                    if health_points.0 <= hp_damage {
+                        *health_points = HealthPoints(health_points.0.min(1));
+                    } else {
+                        (*health_points) -= hp_damage;
+                    }
+                }
+
+                status_effects
+                    .retain(|status_effect_entry| !status_effect_entry.clock.is_complete());
+            });
+    } // kcov-ignore
+}
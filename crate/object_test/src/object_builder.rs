@@ -14,6 +14,7 @@ use collision_model::{
     },
 };
 use derive_new::new;
+use frame_event_model::loaded::FrameEventsSequenceHandles;
 use kinematic_model::{
     config::ObjectAcceleration,
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
@@ -25,7 +26,10 @@ use sequence_model::{
     loaded::{SequenceEndTransition, SequenceEndTransitions, WaitSequence, WaitSequenceHandles},
 };
 use spawn_model::loaded::{Spawns, SpawnsSequence, SpawnsSequenceHandles};
-use sprite_model::loaded::{SpriteRenderSequence, SpriteRenderSequenceHandles};
+use sprite_model::loaded::{
+    InterpolationSequenceHandles, ScaleSequenceHandles, SpriteOffsetSequenceHandles,
+    SpriteRenderSequence, SpriteRenderSequenceHandles, TintSequenceHandles,
+};
 
 /// Builds an `Object` in-memory.
 ///
@@ -210,6 +214,11 @@ impl ObjectBuilder {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            FrameEventsSequenceHandles::default(),
+            TintSequenceHandles::default(),
+            ScaleSequenceHandles::default(),
+            SpriteOffsetSequenceHandles::default(),
+            InterpolationSequenceHandles::default(),
             sequence_end_transitions,
         )
     }
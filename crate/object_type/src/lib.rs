@@ -18,6 +18,9 @@ pub enum ObjectType {
     /// Energy / aura / spark effects.
     #[evt(skip)]
     Energy,
+    /// Items that can be picked up, held, and thrown.
+    #[evt(skip)]
+    Item,
     /// Used in tests.
     #[evt(skip)]
     TestObject,
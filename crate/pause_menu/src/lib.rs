@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! State for the menu shown when game play is paused.
+
+pub use crate::{
+    pause_menu_state::{PauseMenuState, PauseMenuStateBuilder, PauseMenuStateDelegate},
+    pause_menu_trans::PauseMenuTrans,
+};
+
+mod pause_menu_state;
+mod pause_menu_trans;
@@ -0,0 +1,77 @@
+use amethyst::{
+    input::{is_key_down, VirtualKeyCode},
+    GameData, State, StateData, Trans,
+};
+use application_event::AppEvent;
+use application_state::{AppState, AppStateBuilder};
+use derivative::Derivative;
+use derive_new::new;
+use log::debug;
+use menu_model::MenuEvent;
+use pause_menu_model::PauseMenuEntity;
+use state_registry::StateId;
+
+use crate::PauseMenuTrans;
+
+/// `State` where the pause menu is displayed over game play.
+///
+/// This state is not intended to be constructed directly, but through the
+/// [`PauseMenuStateBuilder`][state_builder].
+///
+/// [state_builder]: pause_menu_state/struct.PauseMenuStateBuilder.html
+pub type PauseMenuState = AppState<'static, 'static, PauseMenuStateDelegate, PauseMenuEntity>;
+
+/// Builder for a `PauseMenuState`.
+///
+/// `SystemBundle`s to run in the `PauseMenuState`'s dispatcher are registered on this builder.
+pub type PauseMenuStateBuilder =
+    AppStateBuilder<'static, 'static, PauseMenuStateDelegate, PauseMenuEntity>;
+
+/// Delegate `State` for the pause menu.
+///
+/// This state is not intended to be used directly, but wrapped in an `AppState`. The
+/// `PauseMenuState` is an alias with this as a delegate state.
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct PauseMenuStateDelegate;
+
+impl PauseMenuStateDelegate {
+    fn initialize_state(data: StateData<'_, GameData<'static, 'static>>) {
+        data.world.insert(StateId::PauseMenu);
+    }
+}
+
+impl State<GameData<'static, 'static>, AppEvent> for PauseMenuStateDelegate {
+    fn on_start(&mut self, data: StateData<'_, GameData<'static, 'static>>) {
+        Self::initialize_state(data);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'static, 'static>>) {
+        Self::initialize_state(data);
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'static, 'static>>,
+        event: AppEvent,
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
+        match event {
+            AppEvent::Window(window_event) => {
+                if is_key_down(&window_event, VirtualKeyCode::Escape) {
+                    debug!("Resuming from `PauseMenuState`.");
+                    Trans::Pop
+                } else {
+                    Trans::None
+                }
+            }
+            AppEvent::PauseMenu(pause_menu_event) => {
+                debug!("Received pause_menu_event: {:?}", pause_menu_event);
+                match pause_menu_event {
+                    MenuEvent::Select(idx) => PauseMenuTrans::trans(data.world, idx),
+                    MenuEvent::Close => Trans::Pop,
+                }
+            }
+            _ => Trans::None,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use amethyst::{
+    ecs::{World, WorldExt},
+    shrev::EventChannel,
+    GameData, Trans,
+};
+use application_event::AppEvent;
+use control_settings::ControlSettingsState;
+use game_play_model::GamePlayEvent;
+use pause_menu_model::PauseMenuIndex;
+
+/// Returns the `Trans` for a given `PauseMenuIndex`.
+#[derive(Debug)]
+pub struct PauseMenuTrans;
+
+impl PauseMenuTrans {
+    /// Returns a transition when a menu item has been selected.
+    ///
+    /// `Restart` and `ReturnToMenu` are forwarded to the `GamePlayState` as `GamePlayEvent`s, which
+    /// is handled after this state is popped.
+    ///
+    /// # Parameters
+    ///
+    /// * `world`: `World` to send the `GamePlayEvent` through.
+    /// * `pause_menu_index`: The selected index.
+    pub fn trans(
+        world: &World,
+        pause_menu_index: PauseMenuIndex,
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
+        match pause_menu_index {
+            PauseMenuIndex::Resume => Trans::Pop,
+            PauseMenuIndex::Restart => {
+                Self::send_game_play_event(world, GamePlayEvent::Restart);
+                Trans::Pop
+            }
+            PauseMenuIndex::ControlSettings => Trans::Push(Box::new(ControlSettingsState::new())),
+            PauseMenuIndex::ReturnToMenu => {
+                Self::send_game_play_event(world, GamePlayEvent::Return);
+                Trans::Pop
+            }
+        }
+    } // kcov-ignore
+
+    fn send_game_play_event(world: &World, game_play_event: GamePlayEvent) {
+        world
+            .write_resource::<EventChannel<GamePlayEvent>>()
+            .single_write(game_play_event);
+    }
+}
@@ -0,0 +1,13 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used in the pause menu.
+
+pub use crate::{
+    pause_menu_entity::PauseMenuEntity, pause_menu_event::PauseMenuEvent,
+    pause_menu_event_args::PauseMenuEventArgs, pause_menu_index::PauseMenuIndex,
+};
+
+mod pause_menu_entity;
+mod pause_menu_event;
+mod pause_menu_event_args;
+mod pause_menu_index;
@@ -0,0 +1,6 @@
+use menu_model::MenuEvent;
+
+use crate::PauseMenuIndex;
+
+/// Event indicating pause menu selection.
+pub type PauseMenuEvent = MenuEvent<PauseMenuIndex>;
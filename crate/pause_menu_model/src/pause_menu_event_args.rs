@@ -0,0 +1,23 @@
+use asset_schema::AssetSchema;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::PauseMenuIndex;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `pause_menu select -s resume`
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum PauseMenuEventArgs {
+    /// Select event.
+    Select {
+        /// Index of the selection.
+        index: PauseMenuIndex,
+    },
+    /// Close event.
+    Close,
+}
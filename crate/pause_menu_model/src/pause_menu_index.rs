@@ -0,0 +1,30 @@
+use asset_schema::AssetSchema;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Pause menu indicies.
+#[derive(
+    AssetSchema,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PauseMenuIndex {
+    /// Resumes the round.
+    Resume,
+    /// Restarts the round with the same selections.
+    Restart,
+    /// Opens control settings.
+    ControlSettings,
+    /// Ends the round and returns to the menu.
+    ReturnToMenu,
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable `pause_menu` to be controlled by stdio.
+
+pub use crate::{
+    pause_menu_event_stdin_mapper::PauseMenuEventStdinMapper,
+    pause_menu_stdio_bundle::PauseMenuStdioBundle,
+};
+
+mod pause_menu_event_stdin_mapper;
+mod pause_menu_stdio_bundle;
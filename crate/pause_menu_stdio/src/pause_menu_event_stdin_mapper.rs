@@ -0,0 +1,21 @@
+use amethyst::Error;
+use menu_model::MenuEvent;
+use pause_menu_model::{PauseMenuEvent, PauseMenuEventArgs};
+use stdio_spi::StdinMapper;
+
+/// Builds a `PauseMenuEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct PauseMenuEventStdinMapper;
+
+impl StdinMapper for PauseMenuEventStdinMapper {
+    type SystemData = ();
+    type Event = PauseMenuEvent;
+    type Args = PauseMenuEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        match args {
+            PauseMenuEventArgs::Select { index } => Ok(MenuEvent::Select(index)),
+            PauseMenuEventArgs::Close => Ok(MenuEvent::Close),
+        }
+    }
+}
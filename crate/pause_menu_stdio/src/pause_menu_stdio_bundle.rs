@@ -0,0 +1,31 @@
+use std::any;
+
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::PauseMenuEventStdinMapper;
+
+/// Adds a `MapperSystem<PauseMenuEventStdinMapper>` to the `World`.
+#[derive(Debug, new)]
+pub struct PauseMenuStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for PauseMenuStdioBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<PauseMenuEventStdinMapper>::new(AppEventVariant::PauseMenu),
+            any::type_name::<MapperSystem<PauseMenuEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
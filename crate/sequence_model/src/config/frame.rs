@@ -1,12 +1,13 @@
 use std::convert::AsRef;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
 use crate::config::Wait;
 
 /// Common frame components.
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default)]
 pub struct Frame {
     /// Number of ticks to wait before the sequence switches to the next frame.
@@ -1,5 +1,6 @@
 use std::convert::AsRef;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +11,7 @@ use crate::config::{Frame, SequenceEndTransition, SequenceName, Wait};
 /// Sequences are shared by different object types, and are genericized by the sequence name. This
 /// is because different object types have different valid sequence names, and we want to be able to
 /// define this at compile time rather than needing to process this at runtime.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct Sequence<SeqName, Frm = Frame>
 where
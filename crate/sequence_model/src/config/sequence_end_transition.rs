@@ -1,5 +1,9 @@
 use std::{fmt, marker::PhantomData, str::FromStr};
 
+use asset_schema::{
+    serde_json::{json, Value},
+    AssetSchema, EnumSchema, SchemaGen, SchemaUtils,
+};
 use derivative::Derivative;
 use derive_new::new;
 use serde::{
@@ -85,6 +89,35 @@ where
     }
 }
 
+impl<SeqName> AssetSchema for SequenceEndTransition<SeqName>
+where
+    SeqName: AssetSchema + SequenceName,
+{
+    fn schema(schema_gen: &mut SchemaGen) -> Value {
+        // Unit variants and sequence names are both plain strings.
+        let unit_variants = EnumSchema::new(false)
+            .unit(
+                SequenceEndTransition::<SeqName>::None.into(),
+                Some("Don't transition, stay on the last frame."),
+            )
+            .unit(
+                SequenceEndTransition::<SeqName>::Repeat.into(),
+                Some("Repeat the current sequence."),
+            )
+            .unit(
+                SequenceEndTransition::<SeqName>::Delete.into(),
+                Some("Delete the object after the sequence has ended."),
+            )
+            .into_schema();
+        let sequence_name = SchemaUtils::describe(
+            schema_gen.subschema::<SequenceNameString<SeqName>>(),
+            Some("Transition to the specified sequence."),
+        );
+
+        json!({ "anyOf": [unit_variants, sequence_name] })
+    }
+}
+
 #[derive(new)]
 struct SequenceEndTransitionVisitor<SeqName>(PhantomData<SeqName>)
 where
@@ -2,13 +2,14 @@ use std::fmt::{self, Display};
 
 use std::{convert::Infallible, str::FromStr};
 
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 use crate::config::SequenceName;
 
 /// Enclosing type for either a known sequence name, or an arbitrary string.
-#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Derivative, Deserialize, PartialEq, Eq, Hash, Serialize)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case", untagged)]
 pub enum SequenceNameString<SeqName>
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
@@ -16,7 +17,9 @@ use crate::config::{Sequence, SequenceName, SequenceNameString, Wait};
 /// automatically using a default value.
 ///
 /// See <https://github.com/serde-rs/serde/issues/1660>.
-#[derive(Clone, Debug, Deref, DerefMut, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Debug, Deref, DerefMut, Derivative, Deserialize, PartialEq, Serialize, new,
+)]
 #[derivative(Default(bound = ""))] // Don't require `Seq: Default`
 pub struct Sequences<Seq, SeqName, Frm>
 where
@@ -2,6 +2,7 @@
 
 pub use self::{
     asset_sequence_id_mappings::AssetSequenceIdMappings,
+    asset_sequence_id_remaps::AssetSequenceIdRemaps,
     sequence_end_transition::SequenceEndTransition,
     sequence_end_transitions::SequenceEndTransitions,
     sequence_id::SequenceId,
@@ -11,6 +12,7 @@ pub use self::{
 };
 
 mod asset_sequence_id_mappings;
+mod asset_sequence_id_remaps;
 mod sequence_end_transition;
 mod sequence_end_transitions;
 mod sequence_id;
@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+use asset_model::loaded::AssetId;
+use slotmap::SecondaryMap;
+
+use crate::loaded::SequenceId;
+
+/// Mappings from a reloaded asset's previous sequence IDs to the IDs of the same named sequences.
+///
+/// Sequences that no longer exist after the reload have no mapping.
+pub type AssetSequenceIdRemaps = SecondaryMap<AssetId, HashMap<SequenceId, SequenceId>>;
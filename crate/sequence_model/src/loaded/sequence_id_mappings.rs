@@ -1,4 +1,4 @@
-use std::iter::FromIterator;
+use std::{collections::HashMap, iter::FromIterator};
 
 use amethyst::{
     ecs::{storage::DenseVecStorage, Component, Entity, World, WriteStorage},
@@ -57,6 +57,20 @@ where
         self.sequence_name_to_id
             .get_by_left(&SequenceNameString::Name(sequence_name))
     }
+
+    /// Returns the IDs in `other` of the sequences in these mappings, matched by sequence name.
+    ///
+    /// Sequences that are not in `other` have no mapping.
+    pub fn remap(&self, other: &Self) -> HashMap<SequenceId, SequenceId> {
+        self.sequence_name_to_id
+            .iter()
+            .filter_map(|(sequence_name_string, sequence_id)| {
+                other
+                    .id(sequence_name_string)
+                    .map(|sequence_id_other| (*sequence_id, *sequence_id_other))
+            })
+            .collect::<HashMap<SequenceId, SequenceId>>()
+    }
 }
 
 impl<SeqName> FromIterator<(SequenceNameString<SeqName>, SequenceId)>
@@ -28,6 +28,17 @@ pub enum SequenceUpdateEvent {
         /// Last valid frame index.
         frame_index: usize,
     },
+    /// The current sequence's data has been reloaded from disk.
+    ///
+    /// This is sent instead of `SequenceBegin` so that the entity stays on its current frame.
+    SequenceReload {
+        /// Entity whose sequence data was reloaded.
+        entity: Entity,
+        /// ID of the current sequence.
+        sequence_id: SequenceId,
+        /// Current valid frame index.
+        frame_index: usize,
+    },
 }
 
 impl SequenceUpdateEvent {
@@ -36,7 +47,8 @@ impl SequenceUpdateEvent {
         match self {
             SequenceUpdateEvent::SequenceBegin { entity, .. }
             | SequenceUpdateEvent::FrameBegin { entity, .. }
-            | SequenceUpdateEvent::SequenceEnd { entity, .. } => entity,
+            | SequenceUpdateEvent::SequenceEnd { entity, .. }
+            | SequenceUpdateEvent::SequenceReload { entity, .. } => entity,
         }
     }
 
@@ -45,7 +57,8 @@ impl SequenceUpdateEvent {
         match self {
             SequenceUpdateEvent::SequenceBegin { .. } => 0,
             SequenceUpdateEvent::FrameBegin { frame_index, .. }
-            | SequenceUpdateEvent::SequenceEnd { frame_index, .. } => frame_index,
+            | SequenceUpdateEvent::SequenceEnd { frame_index, .. }
+            | SequenceUpdateEvent::SequenceReload { frame_index, .. } => frame_index,
         }
     }
 }
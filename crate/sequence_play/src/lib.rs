@@ -5,8 +5,9 @@
 pub use crate::system::{
     FrameComponentUpdateSystem, FrameComponentUpdateSystemData, SequenceComponentUpdateSystem,
     SequenceComponentUpdateSystemData, SequenceEndTransitionSystem,
-    SequenceEndTransitionSystemData, SequenceStatusUpdateSystem, SequenceStatusUpdateSystemData,
-    SequenceUpdateSystem, SequenceUpdateSystemData,
+    SequenceEndTransitionSystemData, SequenceReloadSystem, SequenceReloadSystemData,
+    SequenceStatusUpdateSystem, SequenceStatusUpdateSystemData, SequenceUpdateSystem,
+    SequenceUpdateSystemData,
 };
 
 mod system;
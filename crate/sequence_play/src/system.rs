@@ -6,6 +6,7 @@ pub use self::{
     sequence_end_transition_system::{
         SequenceEndTransitionSystem, SequenceEndTransitionSystemData,
     },
+    sequence_reload_system::{SequenceReloadSystem, SequenceReloadSystemData},
     sequence_status_update_system::{SequenceStatusUpdateSystem, SequenceStatusUpdateSystemData},
     sequence_update_system::{SequenceUpdateSystem, SequenceUpdateSystemData},
 };
@@ -13,5 +14,6 @@ pub use self::{
 mod frame_component_update_system;
 mod sequence_component_update_system;
 mod sequence_end_transition_system;
+mod sequence_reload_system;
 mod sequence_status_update_system;
 mod sequence_update_system;
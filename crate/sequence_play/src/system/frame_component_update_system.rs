@@ -105,7 +105,8 @@ where
             )
             .filter(|ev| {
                 if let SequenceUpdateEvent::SequenceBegin { .. }
-                | SequenceUpdateEvent::FrameBegin { .. } = ev
+                | SequenceUpdateEvent::FrameBegin { .. }
+                | SequenceUpdateEvent::SequenceReload { .. } = ev
                 {
                     true
                 } else {
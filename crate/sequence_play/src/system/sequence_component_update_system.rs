@@ -96,16 +96,17 @@ where
                     .as_mut()
                     .expect("Expected reader ID to exist for SequenceComponentUpdateSystem."),
             )
-            .filter_map(|ev| {
-                if let SequenceUpdateEvent::SequenceBegin {
+            .filter_map(|ev| match ev {
+                SequenceUpdateEvent::SequenceBegin {
                     entity,
                     sequence_id,
-                } = ev
-                {
-                    Some((*entity, *sequence_id))
-                } else {
-                    None
                 }
+                | SequenceUpdateEvent::SequenceReload {
+                    entity,
+                    sequence_id,
+                    ..
+                } => Some((*entity, *sequence_id)),
+                _ => None,
             })
             .for_each(|(entity, sequence_id)| {
                 let item_components = asset_world.read_storage::<ICSCD>();
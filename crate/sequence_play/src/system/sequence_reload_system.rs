@@ -0,0 +1,192 @@
+use amethyst::{
+    assets::AssetStorage,
+    ecs::{Entities, Join, Read, ReadStorage, System, World, WorldExt, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::{
+    loaded::{AssetId, AssetItemIds, ItemId},
+    play::{AssetReloadEvent, AssetWorld},
+};
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::{
+    loaded::{AssetSequenceIdRemaps, SequenceId, WaitSequence, WaitSequenceHandles},
+    play::{FrameIndexClock, FrameWaitClock, SequenceUpdateEvent},
+};
+
+/// Refreshes sequence components on entities whose asset has been reloaded.
+///
+/// Entities stay on their current sequence and frame. Sequence IDs are remapped by name, as the
+/// reloaded asset may have added or removed sequences. If the sequence no longer exists, the entity
+/// restarts on the asset's initial sequence.
+///
+/// This **must** run before `SequenceStatusUpdateSystem` and the `SequenceComponentUpdateSystem`s,
+/// as they update the entity's components from the `SequenceUpdateEvent`s sent by this system.
+#[derive(Debug, Default, new)]
+pub struct SequenceReloadSystem {
+    /// Reader ID for the `AssetReloadEvent` event channel.
+    #[new(default)]
+    asset_reload_event_rid: Option<ReaderId<AssetReloadEvent>>,
+}
+
+/// `SequenceReloadSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SequenceReloadSystemData<'s> {
+    /// Event channel for `AssetReloadEvent`s.
+    #[derivative(Debug = "ignore")]
+    pub asset_reload_ec: Read<'s, EventChannel<AssetReloadEvent>>,
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `AssetItemIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_item_ids: Read<'s, AssetItemIds>,
+    /// `AssetSequenceIdRemaps` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_remaps: Read<'s, AssetSequenceIdRemaps>,
+    /// `AssetWorld` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_world: Read<'s, AssetWorld>,
+    /// `ItemId` components.
+    #[derivative(Debug = "ignore")]
+    pub item_ids: ReadStorage<'s, ItemId>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `WaitSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub wait_sequence_assets: Read<'s, AssetStorage<WaitSequence>>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: WriteStorage<'s, FrameIndexClock>,
+    /// `FrameWaitClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_wait_clocks: WriteStorage<'s, FrameWaitClock>,
+    /// Event channel for `SequenceUpdateEvent`s.
+    #[derivative(Debug = "ignore")]
+    pub sequence_update_ec: Write<'s, EventChannel<SequenceUpdateEvent>>,
+}
+
+impl<'s> System<'s> for SequenceReloadSystem {
+    type SystemData = SequenceReloadSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SequenceReloadSystemData {
+            asset_reload_ec,
+            entities,
+            asset_item_ids,
+            asset_sequence_id_remaps,
+            asset_world,
+            item_ids,
+            mut sequence_ids,
+            wait_sequence_assets,
+            mut frame_index_clocks,
+            mut frame_wait_clocks,
+            mut sequence_update_ec,
+        }: Self::SystemData,
+    ) {
+        let item_ids_reloaded = asset_reload_ec
+            .read(
+                self.asset_reload_event_rid
+                    .as_mut()
+                    .expect("Expected reader ID to exist for SequenceReloadSystem."),
+            )
+            .filter_map(|ev| {
+                let AssetReloadEvent::Complete { asset_id } = *ev;
+                asset_item_ids
+                    .get(asset_id)
+                    .map(|item_ids| (asset_id, item_ids))
+            })
+            .flat_map(|(asset_id, item_ids)| {
+                item_ids
+                    .iter()
+                    .copied()
+                    .map(move |item_id| (item_id, asset_id))
+            })
+            .collect::<Vec<(ItemId, AssetId)>>();
+
+        if item_ids_reloaded.is_empty() {
+            return;
+        }
+
+        // Collect first, as mutably joining over `SequenceId`s flags every one as modified.
+        let entities_reloaded = (&entities, &item_ids, &sequence_ids)
+            .join()
+            .filter_map(|(entity, item_id, sequence_id)| {
+                item_ids_reloaded
+                    .iter()
+                    .find(|(item_id_reloaded, _)| item_id_reloaded == item_id)
+                    .map(|(_, asset_id)| (entity, *item_id, *asset_id, *sequence_id))
+            })
+            .collect::<Vec<_>>();
+
+        let wait_sequence_handleses = asset_world.read_storage::<WaitSequenceHandles>();
+        let sequence_id_inits = asset_world.read_storage::<SequenceId>();
+        entities_reloaded.into_iter().for_each(
+            |(entity, item_id, asset_id, sequence_id_previous)| {
+                // Assets reloaded without going through ID mapping keep their sequence IDs.
+                let sequence_id = match asset_sequence_id_remaps.get(asset_id) {
+                    Some(sequence_id_remap) => {
+                        sequence_id_remap.get(&sequence_id_previous).copied()
+                    }
+                    None => Some(sequence_id_previous),
+                };
+
+                let wait_sequence = sequence_id.and_then(|sequence_id| {
+                    wait_sequence_handleses
+                        .get(item_id.0)
+                        .and_then(|wait_sequence_handles| wait_sequence_handles.get(*sequence_id))
+                        .and_then(|handle| wait_sequence_assets.get(handle))
+                        .filter(|wait_sequence| !wait_sequence.is_empty())
+                        .map(|wait_sequence| (sequence_id, wait_sequence))
+                });
+
+                if let Some((sequence_id, wait_sequence)) = wait_sequence {
+                    if sequence_id != sequence_id_previous {
+                        // Don't emit a modification event, as that restarts the sequence.
+                        sequence_ids.set_event_emission(false);
+                        sequence_ids
+                            .insert(entity, sequence_id)
+                            .expect("Failed to insert `SequenceId` component.");
+                        sequence_ids.set_event_emission(true);
+                    }
+
+                    let frame_index = frame_index_clocks
+                        .get_mut(entity)
+                        .map(|frame_index_clock| {
+                            (*frame_index_clock).limit = wait_sequence.len();
+                            (*frame_index_clock).value =
+                                (*frame_index_clock).value.min(wait_sequence.len() - 1);
+                            (*frame_index_clock).value
+                        })
+                        .unwrap_or(0);
+                    if let Some(frame_wait_clock) = frame_wait_clocks.get_mut(entity) {
+                        (*frame_wait_clock).limit = *wait_sequence[frame_index] as usize;
+                    }
+
+                    sequence_update_ec.single_write(SequenceUpdateEvent::SequenceReload {
+                        entity,
+                        sequence_id,
+                        frame_index,
+                    });
+                } else if let Some(sequence_id_init) = sequence_id_inits.get(item_id.0).copied() {
+                    sequence_ids
+                        .insert(entity, sequence_id_init)
+                        .expect("Failed to insert `SequenceId` component.");
+                }
+            },
+        );
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.asset_reload_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<AssetReloadEvent>>()
+                .register_reader(),
+        );
+    }
+}
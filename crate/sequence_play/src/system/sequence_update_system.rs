@@ -9,7 +9,8 @@ use derive_new::new;
 use sequence_model::{
     loaded::{WaitSequence, WaitSequenceHandle},
     play::{
-        FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequenceStatus, SequenceUpdateEvent,
+        FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequencePause, SequenceStatus,
+        SequenceUpdateEvent,
     },
 };
 
@@ -21,6 +22,8 @@ use sequence_model::{
 /// * `FrameWaitClock`
 /// * `FrameIndexClock`
 ///
+/// Entities with the `SequencePause` component are not updated.
+///
 /// This system **must** be run before all systems that update the frame components that are
 /// attached to entities, as the `SequenceUpdateEvent`s include the new frame index, which is only
 /// guaranteed to be valid for the current dispatcher run.
@@ -52,6 +55,9 @@ pub struct SequenceUpdateSystemData<'s> {
     /// `SequenceStatus` component storage.
     #[derivative(Debug = "ignore")]
     pub sequence_statuses: WriteStorage<'s, SequenceStatus>,
+    /// `SequencePause` component storage.
+    #[derivative(Debug = "ignore")]
+    pub sequence_pauses: ReadStorage<'s, SequencePause>,
     /// Event channel for `SequenceUpdateEvent`s.
     #[derivative(Debug = "ignore")]
     pub sequence_update_ec: Write<'s, EventChannel<SequenceUpdateEvent>>,
@@ -189,6 +195,7 @@ impl<'s> System<'s> for SequenceUpdateSystem {
             mut frame_freeze_clocks,
             mut frame_wait_clocks,
             mut sequence_statuses,
+            sequence_pauses,
             mut sequence_update_ec,
         }: Self::SystemData,
     ) {
@@ -198,6 +205,7 @@ impl<'s> System<'s> for SequenceUpdateSystem {
             &mut frame_index_clocks,
             &mut frame_wait_clocks,
             &mut sequence_statuses,
+            !&sequence_pauses,
         )
             .join()
             .for_each(
@@ -207,6 +215,7 @@ impl<'s> System<'s> for SequenceUpdateSystem {
                     mut frame_index_clock,
                     mut frame_wait_clock,
                     mut sequence_status,
+                    _,
                 )| {
                     let sequence_update_params = SequenceUpdateParams {
                         entity,
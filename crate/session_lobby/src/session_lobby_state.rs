@@ -5,6 +5,7 @@ use application_event::AppEvent;
 use application_state::{AppState, AppStateBuilder};
 use character_selection::{
     CharacterSelectionStateBuilder, CharacterSelectionStateDelegate, CharacterSelectionSystem,
+    CharacterVariantInputSystem,
 };
 use derivative::Derivative;
 use derive_new::new;
@@ -59,10 +60,15 @@ impl SessionLobbyStateDelegate {
         let state = CharacterSelectionStateBuilder::new(CharacterSelectionStateDelegate::new(
             map_selection_fn,
         ))
+        .with_system(
+            CharacterVariantInputSystem::new(),
+            any::type_name::<CharacterVariantInputSystem>(),
+            &[],
+        )
         .with_system(
             CharacterSelectionSystem::new(),
             any::type_name::<CharacterSelectionSystem>(),
-            &[],
+            &[any::type_name::<CharacterVariantInputSystem>()],
         )
         .build();
 
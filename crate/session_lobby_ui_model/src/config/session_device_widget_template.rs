@@ -1,9 +1,10 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 use ui_label_model::config::UiLabel;
 use ui_model_spi::config::Dimensions;
 
 /// Configuration for displaying information about a particular session device.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct SessionDeviceWidgetTemplate {
     /// Dimensions of the widget.
@@ -1,10 +1,11 @@
+use asset_schema::AssetSchema;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
 
 use crate::config::SessionDeviceWidgetTemplate;
 
 /// Configuration the widget to display all session devices.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct SessionDevicesWidget {
     /// Position of the widget.
@@ -1,10 +1,11 @@
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 use ui_label_model::config::UiLabel;
 
 use crate::config::SessionDevicesWidget;
 
 /// Configuration for initializing the session lobby UI.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct SessionLobbyUi {
     /// Attributes of the session code label.
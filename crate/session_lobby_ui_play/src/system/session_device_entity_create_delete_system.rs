@@ -8,7 +8,7 @@ use asset_model::loaded::ItemId;
 use derivative::Derivative;
 use derive_new::new;
 use kinematic_model::{
-    config::Position,
+    config::{FixedPoint, Position},
     play::{PositionInitOffset, PositionInitParent},
 };
 use log::debug;
@@ -154,9 +154,12 @@ impl<'s> System<'s> for SessionDeviceEntityCreateDeleteSystem {
                             let parent_entity = ParentEntity::new(session_devices_entity);
                             let position_init_parent =
                                 PositionInitParent::new(session_devices_entity);
-                            let y_offset = -((n * dimensions.h as usize) as f32);
-                            let position_init_offset =
-                                PositionInitOffset::new(Position::new(0., y_offset, 0.));
+                            let y_offset = -FixedPoint::from(n as u32 * dimensions.h);
+                            let position_init_offset = PositionInitOffset::new(Position::new(
+                                FixedPoint::ZERO,
+                                y_offset,
+                                FixedPoint::ZERO,
+                            ));
 
                             let item_id_session_device_id =
                                 session_devices_widget.item_id_session_device_id;
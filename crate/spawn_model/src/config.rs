@@ -1,6 +1,12 @@
 //! User defined configuration types for spawns.
 
-pub use self::{spawn::Spawn, spawns::Spawns};
+pub use self::{
+    spawn::Spawn, spawn_condition::SpawnCondition, spawn_jitter::SpawnJitter,
+    spawn_spread::SpawnSpread, spawns::Spawns,
+};
 
 mod spawn;
+mod spawn_condition;
+mod spawn_jitter;
+mod spawn_spread;
 mod spawns;
@@ -1,10 +1,14 @@
 use asset_model::config::AssetSlug;
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::{Position, Velocity};
+use object_status_model::config::StatusEffect;
 use serde::{Deserialize, Serialize};
 
+use crate::config::{SpawnCondition, SpawnJitter, SpawnSpread};
+
 /// Specifies an object to spawn.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct Spawn {
     /// Slug of the game object to spawn.
@@ -22,4 +26,34 @@ pub struct Spawn {
     /// `SequenceNameString` that the spawned object should begin with.
     #[serde(default)]
     pub sequence: Option<String>,
+    /// Number of copies of the object to spawn.
+    #[new(value = "1")]
+    #[serde(default = "Spawn::count_default")]
+    pub count: u32,
+    /// How the copies are spread out from each other.
+    #[new(default)]
+    #[serde(default)]
+    pub spread: SpawnSpread,
+    /// Maximum random offset applied to each copy.
+    #[new(default)]
+    #[serde(default)]
+    pub jitter: SpawnJitter,
+    /// Condition the parent must meet for the object to be spawned.
+    #[new(default)]
+    #[serde(default)]
+    pub condition: Option<SpawnCondition>,
+    /// Maximum number of these objects the parent may have in play at the same time.
+    #[new(default)]
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Status effect to apply to the spawned object.
+    #[new(default)]
+    #[serde(default)]
+    pub status_effect: Option<StatusEffect>,
+}
+
+impl Spawn {
+    fn count_default() -> u32 {
+        1
+    }
 }
@@ -0,0 +1,14 @@
+use asset_schema::AssetSchema;
+use charge_model::config::ChargePoints;
+use game_input_model::config::ControlAction;
+use serde::{Deserialize, Serialize};
+
+/// Condition the parent object must meet for a `Spawn` to happen.
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum SpawnCondition {
+    /// Minimum `ChargePoints` the parent must have.
+    Charge(ChargePoints),
+    /// `ControlAction` the parent must be holding.
+    Hold(ControlAction),
+}
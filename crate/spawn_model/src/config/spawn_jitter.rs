@@ -0,0 +1,16 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use kinematic_model::config::{Position, Velocity};
+use serde::{Deserialize, Serialize};
+
+/// Maximum random offset applied to each spawned copy.
+///
+/// Each coordinate is offset by a value between `-jitter` and `jitter`.
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpawnJitter {
+    /// Maximum offset to the `Position` of each copy.
+    pub position: Position<i32>,
+    /// Maximum offset to the `Velocity` of each copy.
+    pub velocity: Velocity<i32>,
+}
@@ -0,0 +1,16 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use kinematic_model::config::{FixedPoint, Position};
+use serde::{Deserialize, Serialize};
+
+/// How spawned copies are spread out from each other.
+///
+/// Copies are spread evenly on both sides of the configured position and velocity.
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpawnSpread {
+    /// Angle between the velocities of consecutive copies on the ground plane, in radians.
+    pub angle: FixedPoint,
+    /// Offset between the positions of consecutive copies.
+    pub position: Position<i32>,
+}
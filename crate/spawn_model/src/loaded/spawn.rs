@@ -1,18 +1,39 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
 use asset_model::loaded::AssetId;
 use derive_new::new;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
+use object_status_model::config::StatusEffect;
 use sequence_model::loaded::SequenceId;
 
+use crate::config::{SpawnCondition, SpawnJitter, SpawnSpread};
+
 /// Specifies an object to spawn.
 #[derive(Clone, Component, Debug, PartialEq, new)]
 pub struct Spawn {
     /// Asset ID of the game object to spawn.
     pub object: AssetId,
     /// `Position` that the spawned object begins with, relative to its parent.
-    pub position: Position<f32>,
+    pub position: Position<FixedPoint>,
     /// `Velocity` that the spawned object begins with, relative to its parent.
-    pub velocity: Velocity<f32>,
+    pub velocity: Velocity<FixedPoint>,
     /// `SequenceId` that the spawned object should begin with.
     pub sequence_id: SequenceId,
+    /// Number of copies of the object to spawn.
+    #[new(value = "1")]
+    pub count: u32,
+    /// How the copies are spread out from each other.
+    #[new(default)]
+    pub spread: SpawnSpread,
+    /// Maximum random offset applied to each copy.
+    #[new(default)]
+    pub jitter: SpawnJitter,
+    /// Condition the parent must meet for the object to be spawned.
+    #[new(default)]
+    pub condition: Option<SpawnCondition>,
+    /// Maximum number of these objects the parent may have in play at the same time.
+    #[new(default)]
+    pub limit: Option<u32>,
+    /// Status effect to apply to the spawned object.
+    #[new(default)]
+    pub status_effect: Option<StatusEffect>,
 }
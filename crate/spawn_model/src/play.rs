@@ -1,6 +1,7 @@
 //! Contains data types used during game play.
 
-pub use self::{spawn_event::SpawnEvent, spawn_parent::SpawnParent};
+pub use self::{spawn_event::SpawnEvent, spawn_parent::SpawnParent, spawn_rng::SpawnRng};
 
 mod spawn_event;
 mod spawn_parent;
+mod spawn_rng;
@@ -0,0 +1,20 @@
+use derive_deref::{Deref, DerefMut};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+/// Random number generator for spawn jitter.
+///
+/// This is seeded with a constant, so that the same inputs produce the same game play on every
+/// machine.
+#[derive(Clone, Debug, Deref, DerefMut)]
+pub struct SpawnRng(pub ChaCha8Rng);
+
+impl SpawnRng {
+    /// Seed that the random number generator begins with.
+    pub const SEED: u64 = 0;
+}
+
+impl Default for SpawnRng {
+    fn default() -> Self {
+        SpawnRng(ChaCha8Rng::seed_from_u64(Self::SEED))
+    }
+}
@@ -2,6 +2,7 @@ use amethyst::ecs::Entity;
 use asset_model::config::AssetType;
 use character_prefab::CharacterEntityAugmenter;
 use energy_prefab::EnergyEntityAugmenter;
+use item_prefab::ItemEntityAugmenter;
 use log::{debug, error};
 use object_type::ObjectType;
 use spawn_model::{loaded::Spawn, play::SpawnEvent};
@@ -28,6 +29,7 @@ impl GameObjectSpawner {
             character_spawning_resources,
             character_component_storages,
             energy_component_storages,
+            item_component_storages,
             spawn_ec,
         }: &mut SpawnGameObjectResources<'_>,
         entity_parent: Entity,
@@ -72,12 +74,16 @@ impl GameObjectSpawner {
                     character_spawning_resources,
                     character_component_storages,
                     asset_id,
+                    0,
                     entity_spawned,
                 );
             }
             AssetType::Object(ObjectType::Energy) => {
                 EnergyEntityAugmenter::augment(entity_spawned, energy_component_storages);
             }
+            AssetType::Object(ObjectType::Item) => {
+                ItemEntityAugmenter::augment(entity_spawned, item_component_storages);
+            }
             _ => {
                 let asset_slug = asset_id_mappings
                     .slug(asset_id)
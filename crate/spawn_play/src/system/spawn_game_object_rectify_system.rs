@@ -5,7 +5,7 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::{Position, Velocity};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
 use mirrored_model::play::Mirrored;
 use sequence_model::loaded::SequenceId;
 use spawn_model::{
@@ -31,12 +31,12 @@ pub struct SpawnGameObjectRectifySystemData<'s> {
     /// `SpawnParent` components.
     #[derivative(Debug = "ignore")]
     pub spawn_parents: WriteStorage<'s, SpawnParent>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: WriteStorage<'s, Position<f32>>,
-    /// `Velocity<f32>` components.
+    pub positions: WriteStorage<'s, Position<FixedPoint>>,
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: WriteStorage<'s, Mirrored>,
@@ -117,13 +117,13 @@ impl<'s> System<'s> for SpawnGameObjectRectifySystem {
 }
 
 impl SpawnGameObjectRectifySystem {
-    /// Returns the rectified `Position<f32>` for the spawned entity.
+    /// Returns the rectified `Position<FixedPoint>` for the spawned entity.
     fn position_rectify(
-        positions: &WriteStorage<'_, Position<f32>>,
+        positions: &WriteStorage<'_, Position<FixedPoint>>,
         spawn: &Spawn,
         entity_parent: Entity,
         mirrored_parent: Option<Mirrored>,
-    ) -> Position<f32> {
+    ) -> Position<FixedPoint> {
         let spawn_position = spawn.position;
         let spawn_position_x = if let Some(Mirrored(true)) = mirrored_parent {
             -spawn_position.x
@@ -138,13 +138,13 @@ impl SpawnGameObjectRectifySystem {
         position
     }
 
-    /// Returns the rectified `Velocity<f32>` for the spawned entity.
+    /// Returns the rectified `Velocity<FixedPoint>` for the spawned entity.
     fn velocity_rectify(
-        velocities: &WriteStorage<'_, Velocity<f32>>,
+        velocities: &WriteStorage<'_, Velocity<FixedPoint>>,
         spawn: &Spawn,
         entity_parent: Entity,
         mirrored_parent: Option<Mirrored>,
-    ) -> Velocity<f32> {
+    ) -> Velocity<FixedPoint> {
         let spawn_velocity = spawn.velocity;
         let spawn_velocity_x = if let Some(Mirrored(true)) = mirrored_parent {
             -spawn_velocity.x
@@ -1,13 +1,24 @@
+use std::collections::HashMap;
+
 use amethyst::{
     assets::AssetStorage,
-    ecs::{Entity, Read, ReadStorage, System, World},
+    ecs::{Entity, Join, Read, ReadStorage, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
+use asset_model::loaded::AssetId;
+use character_prefab::CharacterComponentStorages;
 use derivative::Derivative;
 use derive_new::new;
+use game_input_model::{config::ControlAction, play::ControllerInput};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
+use rand::Rng;
 use sequence_model::play::SequenceUpdateEvent;
-use spawn_model::loaded::{Spawns, SpawnsHandle};
+use spawn_model::{
+    config::{SpawnCondition, SpawnJitter, SpawnSpread},
+    loaded::{Spawn, Spawns, SpawnsHandle},
+    play::{SpawnParent, SpawnRng},
+};
 
 use crate::{GameObjectSpawner, SpawnGameObjectResources};
 
@@ -33,19 +44,163 @@ pub struct SpawnGameObjectSystemData<'s> {
     /// `Spawns` assets.
     #[derivative(Debug = "ignore")]
     pub spawns_assets: Read<'s, AssetStorage<Spawns>>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `SpawnRng` resource.
+    #[derivative(Debug = "ignore")]
+    pub spawn_rng: Write<'s, SpawnRng>,
 }
 
 impl SpawnGameObjectSystem {
-    /// Creates an entity for each `Spawn` and attaches its prefab handle.
+    /// Creates entities for each `Spawn` whose condition is met, and attaches their prefab handles.
+    ///
+    /// # Parameters
+    ///
+    /// * `spawned_counts`: Number of objects spawned this tick, per parent and asset. Objects
+    ///   only receive their `SpawnParent` component after this system runs, so these are counted
+    ///   separately.
     fn spawn_game_objects(
         spawn_game_object_resources: &mut SpawnGameObjectResources<'_>,
+        spawn_parents: &ReadStorage<'_, SpawnParent>,
+        spawn_rng: &mut SpawnRng,
+        spawned_counts: &mut HashMap<(Entity, AssetId), u32>,
         spawns: &Spawns,
         entity_parent: Entity,
     ) {
         spawns.iter().for_each(|spawn| {
-            GameObjectSpawner::spawn(spawn_game_object_resources, entity_parent, spawn);
+            let condition_met = Self::condition_met(
+                &spawn_game_object_resources.character_component_storages,
+                spawn.condition,
+                entity_parent,
+            );
+            if !condition_met {
+                return;
+            }
+
+            let spawned_count = spawned_counts
+                .entry((entity_parent, spawn.object))
+                .or_insert(0);
+            let count = if let Some(limit) = spawn.limit {
+                let active_count = Self::active_count(
+                    spawn_game_object_resources,
+                    spawn_parents,
+                    entity_parent,
+                    spawn.object,
+                ) + *spawned_count;
+
+                spawn.count.min(limit.saturating_sub(active_count))
+            } else {
+                spawn.count
+            };
+
+            (0..count).for_each(|index| {
+                let spawn_copy = Self::spawn_copy(spawn, index, spawn_rng);
+                GameObjectSpawner::spawn(spawn_game_object_resources, entity_parent, &spawn_copy);
+            });
+            *spawned_count += count;
         });
     }
+
+    /// Returns whether the parent entity meets the spawn condition.
+    fn condition_met(
+        character_component_storages: &CharacterComponentStorages<'_>,
+        condition: Option<SpawnCondition>,
+        entity_parent: Entity,
+    ) -> bool {
+        match condition {
+            None => true,
+            Some(SpawnCondition::Charge(charge_points)) => character_component_storages
+                .charge_tracker_clocks
+                .get(entity_parent)
+                .map(|charge_tracker_clock| charge_tracker_clock.value >= *charge_points as usize)
+                .unwrap_or(false),
+            Some(SpawnCondition::Hold(control_action)) => character_component_storages
+                .controller_inputs
+                .get(entity_parent)
+                .map(|controller_input| Self::action_held(*controller_input, control_action))
+                .unwrap_or(false),
+        }
+    }
+
+    /// Returns whether the control action button is held.
+    fn action_held(controller_input: ControllerInput, control_action: ControlAction) -> bool {
+        match control_action {
+            ControlAction::Defend => controller_input.defend,
+            ControlAction::Jump => controller_input.jump,
+            ControlAction::Attack => controller_input.attack,
+            ControlAction::Special => controller_input.special,
+        }
+    }
+
+    /// Returns the number of objects of the given asset in play that were spawned by the parent.
+    fn active_count(
+        spawn_game_object_resources: &SpawnGameObjectResources<'_>,
+        spawn_parents: &ReadStorage<'_, SpawnParent>,
+        entity_parent: Entity,
+        object: AssetId,
+    ) -> u32 {
+        (&spawn_game_object_resources.asset_ids, spawn_parents)
+            .join()
+            .filter(|(asset_id, spawn_parent)| {
+                **asset_id == object && spawn_parent.entity == entity_parent
+            })
+            .count() as u32
+    }
+
+    /// Returns the `Spawn` for the `index`th copy, with spread and jitter applied.
+    fn spawn_copy(spawn: &Spawn, index: u32, spawn_rng: &mut SpawnRng) -> Spawn {
+        let SpawnSpread {
+            angle,
+            position: position_spread,
+        } = spawn.spread;
+        let SpawnJitter {
+            position: position_jitter,
+            velocity: velocity_jitter,
+        } = spawn.jitter;
+
+        // Copies are spread evenly on both sides of the configured position and velocity.
+        let offset =
+            FixedPoint::from(index) - FixedPoint::from(spawn.count - 1) / FixedPoint::from(2);
+
+        let position = spawn.position
+            + Position::new(
+                FixedPoint::from(position_spread.x) * offset,
+                FixedPoint::from(position_spread.y) * offset,
+                FixedPoint::from(position_spread.z) * offset,
+            )
+            + Position::new(
+                Self::jitter(spawn_rng, position_jitter.x),
+                Self::jitter(spawn_rng, position_jitter.y),
+                Self::jitter(spawn_rng, position_jitter.z),
+            );
+
+        // Fan the velocity out on the ground plane.
+        let angle = angle * offset;
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let velocity = spawn.velocity;
+        let velocity = Velocity::new(
+            velocity.x * cos - velocity.z * sin,
+            velocity.y,
+            velocity.x * sin + velocity.z * cos,
+        ) + Velocity::new(
+            Self::jitter(spawn_rng, velocity_jitter.x),
+            Self::jitter(spawn_rng, velocity_jitter.y),
+            Self::jitter(spawn_rng, velocity_jitter.z),
+        );
+
+        Spawn::new(spawn.object, position, velocity, spawn.sequence_id)
+    }
+
+    /// Returns a random value between `-max` and `max`.
+    fn jitter(spawn_rng: &mut SpawnRng, max: i32) -> FixedPoint {
+        let bits = FixedPoint::from(max).abs().to_bits();
+        if bits == 0 {
+            FixedPoint::ZERO
+        } else {
+            FixedPoint::from_bits(spawn_rng.gen_range(-bits, bits + 1))
+        }
+    }
 }
 
 impl<'s> System<'s> for SpawnGameObjectSystem {
@@ -58,8 +213,11 @@ impl<'s> System<'s> for SpawnGameObjectSystem {
             sequence_update_ec,
             spawns_handles,
             spawns_assets,
+            spawn_parents,
+            mut spawn_rng,
         }: Self::SystemData,
     ) {
+        let mut spawned_counts = HashMap::new();
         sequence_update_ec
             .read(
                 self.reader_id
@@ -88,6 +246,9 @@ impl<'s> System<'s> for SpawnGameObjectSystem {
 
                     Self::spawn_game_objects(
                         &mut spawn_game_object_resources,
+                        &spawn_parents,
+                        &mut *spawn_rng,
+                        &mut spawned_counts,
                         spawns,
                         entity_parent,
                     );
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use amethyst::renderer::{
+    rendy::{
+        hal::{
+            format::Format,
+            image::{Filter, Kind, SamplerDesc, ViewKind, WrapMode},
+        },
+        texture::TextureBuilder,
+    },
+    types::TextureData,
+};
+use asset_fs::AssetFs;
+use derivative::Derivative;
+use derive_new::new;
+use sprite_model::config::PaletteSwap;
+
+/// Number of bytes per RGBA pixel.
+const BYTES_PER_PIXEL: usize = 4;
+
+/// Decoded RGBA image data, used to pack sprite sheets into texture atlases.
+#[derive(Clone, Derivative, PartialEq, Eq, new)]
+#[derivative(Debug)]
+pub struct AtlasImage {
+    /// Width of the image in pixels.
+    pub width: u32,
+    /// Height of the image in pixels.
+    pub height: u32,
+    /// RGBA bytes of each pixel, row by row from the top left.
+    #[derivative(Debug = "ignore")]
+    pub pixels: Vec<u8>,
+}
+
+impl AtlasImage {
+    /// Returns a transparent image of the given size.
+    ///
+    /// # Parameters
+    ///
+    /// * `width`: Width of the image in pixels.
+    /// * `height`: Height of the image in pixels.
+    pub fn transparent(width: u32, height: u32) -> Self {
+        let pixels = vec![0; width as usize * height as usize * BYTES_PER_PIXEL];
+        AtlasImage::new(width, height, pixels)
+    }
+
+    /// Returns the decoded image at the given path.
+    ///
+    /// # Parameters
+    ///
+    /// * `image_path`: Path to the image.
+    pub fn decode(image_path: &Path) -> Result<Self, String> {
+        let bytes = AssetFs::read(image_path).map_err(|e| e.to_string())?;
+        let image = image::load_from_memory(&bytes)
+            .map_err(|e| e.to_string())?
+            .to_rgba();
+        let (width, height) = image.dimensions();
+
+        Ok(AtlasImage::new(width, height, image.into_raw()))
+    }
+
+    /// Returns `TextureData` for this image.
+    pub fn into_texture_data(self) -> TextureData {
+        let AtlasImage {
+            width,
+            height,
+            pixels,
+        } = self;
+
+        TextureBuilder::new()
+            .with_kind(Kind::D2(width, height, 1, 1))
+            .with_view_kind(ViewKind::D2)
+            .with_data_width(width)
+            .with_data_height(height)
+            .with_sampler_info(SamplerDesc::new(Filter::Nearest, WrapMode::Clamp))
+            .with_raw_data(pixels, Format::Rgba8Srgb)
+            .into()
+    }
+
+    /// Replaces colours in this image.
+    ///
+    /// Only the RGB values are compared and replaced, so each pixel keeps its alpha value.
+    ///
+    /// # Parameters
+    ///
+    /// * `palette`: Colours to replace, and their replacements.
+    pub fn swap_palette(&mut self, palette: &[PaletteSwap]) {
+        self.pixels
+            .chunks_exact_mut(BYTES_PER_PIXEL)
+            .for_each(|pixel| {
+                let palette_swap = palette
+                    .iter()
+                    .find(|palette_swap| pixel[..3] == palette_swap.from);
+                if let Some(palette_swap) = palette_swap {
+                    pixel[..3].copy_from_slice(&palette_swap.to);
+                }
+            });
+    }
+
+    /// Copies another image into this image, with its top left corner at the given position.
+    ///
+    /// Pixels that fall outside this image are not copied.
+    ///
+    /// # Parameters
+    ///
+    /// * `image`: Image to copy.
+    /// * `x`: Pixel column to copy the image to.
+    /// * `y`: Pixel row to copy the image to.
+    pub fn blit(&mut self, image: &AtlasImage, x: u32, y: u32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let copy_w = image.width.min(self.width - x) as usize * BYTES_PER_PIXEL;
+        let copy_h = image.height.min(self.height - y) as usize;
+        let row_len_src = image.width as usize * BYTES_PER_PIXEL;
+        let row_len_dest = self.width as usize * BYTES_PER_PIXEL;
+
+        (0..copy_h).for_each(|row| {
+            let src_begin = row * row_len_src;
+            let dest_begin = (y as usize + row) * row_len_dest + x as usize * BYTES_PER_PIXEL;
+            self.pixels[dest_begin..dest_begin + copy_w]
+                .copy_from_slice(&image.pixels[src_begin..src_begin + copy_w]);
+        });
+    }
+}
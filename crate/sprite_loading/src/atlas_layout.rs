@@ -0,0 +1,12 @@
+use derive_new::new;
+
+use crate::AtlasPlacement;
+
+/// Layout of images packed into texture atlases.
+#[derive(Clone, Debug, Default, PartialEq, Eq, new)]
+pub struct AtlasLayout {
+    /// Width and height of each atlas in pixels.
+    pub atlas_sizes: Vec<(u32, u32)>,
+    /// Placement of each image, in the order the images were given.
+    pub placements: Vec<AtlasPlacement>,
+}
@@ -0,0 +1,36 @@
+use std::{error, fmt};
+
+/// Errors when packing images into texture atlases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AtlasPackError {
+    /// An image is larger than the atlas.
+    ImageTooLarge {
+        /// Index of the image.
+        index: usize,
+        /// Width of the image in pixels.
+        width: u32,
+        /// Height of the image in pixels.
+        height: u32,
+        /// Width and height of the atlas in pixels.
+        atlas_size: u32,
+    },
+}
+
+impl fmt::Display for AtlasPackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ImageTooLarge {
+                index,
+                width,
+                height,
+                atlas_size,
+            } => write!(
+                f,
+                "Image `{}` is `{}x{}` pixels, which does not fit in a `{}x{}` atlas.",
+                index, width, height, atlas_size, atlas_size
+            ),
+        }
+    }
+}
+
+impl error::Error for AtlasPackError {}
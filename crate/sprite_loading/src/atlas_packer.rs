@@ -0,0 +1,171 @@
+use derive_new::new;
+
+use crate::{AtlasImage, AtlasLayout, AtlasPackError, AtlasPlacement};
+
+/// Default width and height of texture atlases in pixels.
+pub const ATLAS_SIZE_DEFAULT: u32 = 2048;
+
+/// Default number of transparent pixels between packed images.
+///
+/// This stops neighbouring images from bleeding into each other when textures are sampled.
+pub const PADDING_DEFAULT: u32 = 1;
+
+/// Row of images within an atlas.
+#[derive(Clone, Copy, Debug)]
+struct Shelf {
+    /// Pixel row of the top of the shelf.
+    y: u32,
+    /// Height of the tallest image on the shelf.
+    height: u32,
+    /// Pixel column that the next image is placed at.
+    x_next: u32,
+}
+
+/// Packs images into square texture atlases.
+///
+/// Images are sorted by height then width, largest first, and placed on the first shelf with
+/// space for them. Ties are broken by the order the images are given in, so the same images always
+/// produce the same layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct AtlasPacker {
+    /// Maximum width and height of each atlas in pixels.
+    pub atlas_size: u32,
+    /// Number of transparent pixels between packed images.
+    pub padding: u32,
+}
+
+impl Default for AtlasPacker {
+    fn default() -> Self {
+        AtlasPacker::new(ATLAS_SIZE_DEFAULT, PADDING_DEFAULT)
+    }
+}
+
+impl AtlasPacker {
+    /// Returns the layout of images with the given sizes packed into atlases.
+    ///
+    /// # Parameters
+    ///
+    /// * `image_sizes`: Width and height of each image in pixels.
+    pub fn layout(&self, image_sizes: &[(u32, u32)]) -> Result<AtlasLayout, AtlasPackError> {
+        let atlas_size = self.atlas_size;
+        if let Some((index, (width, height))) = image_sizes
+            .iter()
+            .copied()
+            .enumerate()
+            .find(|(_, (width, height))| *width > atlas_size || *height > atlas_size)
+        {
+            return Err(AtlasPackError::ImageTooLarge {
+                index,
+                width,
+                height,
+                atlas_size,
+            });
+        }
+
+        let mut image_indices = (0..image_sizes.len()).collect::<Vec<usize>>();
+        image_indices.sort_by(|index_a, index_b| {
+            let (width_a, height_a) = image_sizes[*index_a];
+            let (width_b, height_b) = image_sizes[*index_b];
+            height_b
+                .cmp(&height_a)
+                .then(width_b.cmp(&width_a))
+                .then(index_a.cmp(index_b))
+        });
+
+        let mut atlases_shelves: Vec<Vec<Shelf>> = Vec::new();
+        let mut placements = vec![AtlasPlacement::default(); image_sizes.len()];
+        image_indices.into_iter().for_each(|index| {
+            let (width, height) = image_sizes[index];
+            let placement = atlases_shelves
+                .iter_mut()
+                .enumerate()
+                .find_map(|(atlas_index, shelves)| {
+                    self.place(shelves, width, height)
+                        .map(|(x, y)| AtlasPlacement::new(atlas_index, x, y))
+                })
+                .unwrap_or_else(|| {
+                    let mut shelves = Vec::new();
+                    let (x, y) = self
+                        .place(&mut shelves, width, height)
+                        .expect("Expected image to fit in an empty atlas.");
+                    atlases_shelves.push(shelves);
+                    AtlasPlacement::new(atlases_shelves.len() - 1, x, y)
+                });
+            placements[index] = placement;
+        });
+
+        let mut atlas_sizes = vec![(0, 0); atlases_shelves.len()];
+        placements
+            .iter()
+            .zip(image_sizes.iter())
+            .for_each(|(placement, (width, height))| {
+                let atlas_size = &mut atlas_sizes[placement.atlas_index];
+                atlas_size.0 = atlas_size.0.max(placement.x + width);
+                atlas_size.1 = atlas_size.1.max(placement.y + height);
+            });
+
+        Ok(AtlasLayout::new(atlas_sizes, placements))
+    }
+
+    /// Returns atlases with the images copied in, and the layout of the images.
+    ///
+    /// # Parameters
+    ///
+    /// * `images`: Images to pack.
+    pub fn pack(
+        &self,
+        images: &[AtlasImage],
+    ) -> Result<(Vec<AtlasImage>, AtlasLayout), AtlasPackError> {
+        let image_sizes = images
+            .iter()
+            .map(|image| (image.width, image.height))
+            .collect::<Vec<(u32, u32)>>();
+        let atlas_layout = self.layout(&image_sizes)?;
+
+        let mut atlases = atlas_layout
+            .atlas_sizes
+            .iter()
+            .map(|(width, height)| AtlasImage::transparent(*width, *height))
+            .collect::<Vec<AtlasImage>>();
+        images
+            .iter()
+            .zip(atlas_layout.placements.iter())
+            .for_each(|(image, placement)| {
+                atlases[placement.atlas_index].blit(image, placement.x, placement.y);
+            });
+
+        Ok((atlases, atlas_layout))
+    }
+
+    /// Places an image on the first shelf with space, or a new shelf, and returns its position.
+    ///
+    /// Returns `None` if the image does not fit in the atlas.
+    fn place(&self, shelves: &mut Vec<Shelf>, width: u32, height: u32) -> Option<(u32, u32)> {
+        let atlas_size = self.atlas_size;
+        let padding = self.padding;
+
+        if let Some(shelf) = shelves
+            .iter_mut()
+            .find(|shelf| height <= shelf.height && shelf.x_next + width <= atlas_size)
+        {
+            let x = shelf.x_next;
+            shelf.x_next += width + padding;
+            return Some((x, shelf.y));
+        }
+
+        let y = shelves
+            .last()
+            .map(|shelf| shelf.y + shelf.height + padding)
+            .unwrap_or(0);
+        if y + height <= atlas_size {
+            shelves.push(Shelf {
+                y,
+                height,
+                x_next: width + padding,
+            });
+            Some((0, y))
+        } else {
+            None
+        }
+    }
+}
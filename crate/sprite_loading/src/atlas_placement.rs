@@ -0,0 +1,12 @@
+use derive_new::new;
+
+/// Position of an image within a texture atlas.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct AtlasPlacement {
+    /// Index of the atlas that the image is placed in.
+    pub atlas_index: usize,
+    /// Pixel column of the image's left edge.
+    pub x: u32,
+    /// Pixel row of the image's top edge.
+    pub y: u32,
+}
@@ -0,0 +1,56 @@
+use sequence_loading_spi::SequenceComponentDataLoader;
+use sprite_model::{
+    config::Interpolation,
+    loaded::{InterpolationSequenceHandle, InterpolationSequenceHandles},
+};
+
+use crate::InterpolationSequenceLoader;
+
+/// Loads `InterpolationSequenceHandle`s from collections of sequences that contain
+/// `Interpolation` values.
+#[derive(Debug)]
+pub struct InterpolationSequenceHandlesLoader<'s> {
+    /// `InterpolationSequenceLoader`.
+    pub interpolation_sequence_loader: InterpolationSequenceLoader<'s>,
+}
+
+impl<'s> InterpolationSequenceHandlesLoader<'s> {
+    /// Loads `InterpolationSequenceHandles`.
+    ///
+    /// This is similar to calling the `SequenceComponentDataLoader::load` trait method, with the
+    /// difference that the resources are stored by an instantiation of this type, so they do not
+    /// need to be passed in when this method is called.
+    pub fn items_to_datas<
+        'seq_ref,
+        'frame_ref: 'seq_ref,
+        SequencesIterator,
+        SequenceRef,
+        FnSequencesToSequenceIterator,
+        SequenceIterator,
+        FrameRef,
+    >(
+        &self,
+        sequences_iterator: SequencesIterator,
+        fn_sequences_to_sequence_iterator: FnSequencesToSequenceIterator,
+    ) -> InterpolationSequenceHandles
+    where
+        SequencesIterator: Iterator<Item = SequenceRef>,
+        SequenceRef: 'seq_ref,
+        FnSequencesToSequenceIterator: Fn(SequenceRef) -> SequenceIterator,
+        FrameRef: AsRef<Interpolation> + 'frame_ref,
+        SequenceIterator: Iterator<Item = FrameRef>,
+    {
+        <Self as SequenceComponentDataLoader>::load(
+            |sequence_ref| {
+                self.interpolation_sequence_loader
+                    .load(fn_sequences_to_sequence_iterator(sequence_ref))
+            },
+            sequences_iterator,
+        )
+    }
+}
+
+impl<'s> SequenceComponentDataLoader for InterpolationSequenceHandlesLoader<'s> {
+    type Component = InterpolationSequenceHandle;
+    type ComponentData = InterpolationSequenceHandles;
+}
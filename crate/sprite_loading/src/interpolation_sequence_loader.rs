@@ -0,0 +1,57 @@
+use amethyst::assets::{AssetStorage, Handle, Loader};
+use derivative::Derivative;
+use sequence_loading_spi::FrameComponentDataLoader;
+use sprite_model::{config::Interpolation, loaded::InterpolationSequence};
+
+/// Loads `InterpolationSequence`s from `Sequence` types whose `Frame`s contain an
+/// `Interpolation` value.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct InterpolationSequenceLoader<'s> {
+    /// `Loader`.
+    #[derivative(Debug = "ignore")]
+    pub loader: &'s Loader,
+    /// `InterpolationSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub interpolation_sequence_assets: &'s AssetStorage<InterpolationSequence>,
+}
+
+impl<'s> InterpolationSequenceLoader<'s> {
+    /// Loads a `InterpolationSequence` and returns its handle.
+    ///
+    /// This is similar to calling the `FrameComponentDataLoader::load` trait method, with the
+    /// difference that the resources are stored by an instantiation of this type, so they do not
+    /// need to be passed in when this method is called.
+    pub fn load<SequenceIterator, FrameRef>(
+        &self,
+        sequence_iterator: SequenceIterator,
+    ) -> Handle<InterpolationSequence>
+    where
+        SequenceIterator: Iterator<Item = FrameRef>,
+        FrameRef: AsRef<Interpolation>,
+    {
+        <Self as FrameComponentDataLoader>::load(
+            self.loader,
+            self.interpolation_sequence_assets,
+            Self::frame_to_component,
+            sequence_iterator,
+        )
+    }
+
+    /// Maps the frame to the component.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_ref`: Reference to the frame.
+    pub fn frame_to_component<FrameRef>(frame_ref: FrameRef) -> Interpolation
+    where
+        FrameRef: AsRef<Interpolation>,
+    {
+        *AsRef::<Interpolation>::as_ref(&frame_ref)
+    }
+}
+
+impl<'s> FrameComponentDataLoader for InterpolationSequenceLoader<'s> {
+    type Component = Interpolation;
+    type ComponentData = InterpolationSequence;
+}
@@ -3,24 +3,49 @@
 //! Processes sprite configuration into the loaded sprite model.
 
 pub use crate::{
+    atlas_image::AtlasImage,
+    atlas_layout::AtlasLayout,
+    atlas_pack_error::AtlasPackError,
+    atlas_packer::{AtlasPacker, ATLAS_SIZE_DEFAULT, PADDING_DEFAULT},
+    atlas_placement::AtlasPlacement,
+    interpolation_sequence_handles_loader::InterpolationSequenceHandlesLoader,
+    interpolation_sequence_loader::InterpolationSequenceLoader,
     scale_sequence_handles_loader::ScaleSequenceHandlesLoader,
-    scale_sequence_loader::ScaleSequenceLoader, sprite_loader::SpriteLoader,
+    scale_sequence_loader::ScaleSequenceLoader,
+    sprite_atlas_system::{SpriteAtlasSystem, SpriteAtlasSystemData},
+    sprite_loader::SpriteLoader,
     sprite_loading_bundle::SpriteLoadingBundle,
+    sprite_offset_sequence_handles_loader::SpriteOffsetSequenceHandlesLoader,
+    sprite_offset_sequence_loader::SpriteOffsetSequenceLoader,
     sprite_render_sequence_handles_loader::SpriteRenderSequenceHandlesLoader,
     sprite_render_sequence_loader::SpriteRenderSequenceLoader,
-    sprite_sheet_loader::SpriteSheetLoader, sprite_sheet_mapper::SpriteSheetMapper,
-    texture_loader::TextureLoader, tint_sequence_handles_loader::TintSequenceHandlesLoader,
+    sprite_sheet_loader::SpriteSheetLoader,
+    sprite_sheet_mapper::SpriteSheetMapper,
+    sprite_variant_loader::SpriteVariantLoader,
+    texture_loader::TextureLoader,
+    tint_sequence_handles_loader::TintSequenceHandlesLoader,
     tint_sequence_loader::TintSequenceLoader,
 };
 
+mod atlas_image;
+mod atlas_layout;
+mod atlas_pack_error;
+mod atlas_packer;
+mod atlas_placement;
+mod interpolation_sequence_handles_loader;
+mod interpolation_sequence_loader;
 mod scale_sequence_handles_loader;
 mod scale_sequence_loader;
+mod sprite_atlas_system;
 mod sprite_loader;
 mod sprite_loading_bundle;
+mod sprite_offset_sequence_handles_loader;
+mod sprite_offset_sequence_loader;
 mod sprite_render_sequence_handles_loader;
 mod sprite_render_sequence_loader;
 mod sprite_sheet_loader;
 mod sprite_sheet_mapper;
+mod sprite_variant_loader;
 mod texture_loader;
 mod tint_sequence_handles_loader;
 mod tint_sequence_loader;
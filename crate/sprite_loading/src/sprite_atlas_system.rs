@@ -0,0 +1,215 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use amethyst::{
+    assets::{AssetStorage, Handle, Loader},
+    ecs::{Read, ReadExpect, System, World, Write},
+    renderer::{sprite::SpriteSheetHandle, SpriteSheet, Texture},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetId;
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, warn};
+use slotmap::SecondaryMap;
+use sprite_model::{
+    config::{SpriteSheetDefinition, SpritesDefinition},
+    loaded::AssetSpritesDirs,
+    play::SpriteAtlasRequestEvent,
+};
+
+use crate::{AtlasImage, AtlasPacker, SpriteSheetMapper, TextureLoader};
+
+/// Packs the sprite sheets of requested assets into texture atlases.
+///
+/// Each `SpriteSheet` is replaced in its `AssetStorage` with one whose sprites lie in an atlas, so
+/// existing `SpriteRender`s draw from the atlas without their handles changing.
+///
+/// # Limitations
+///
+/// * The sprite sheet images are decoded from disk synchronously when the request is read, so the
+///   frame that handles the request takes longer. Requests are only sent once while a game is
+///   loading, when a longer frame is not noticeable.
+/// * Only the object's own sprite sheets are packed. Sprite sheets of `SpriteVariant`s that replace
+///   the image or swap the palette keep drawing from their own textures. Variant sheets that do
+///   not change the image share the object's handle, so they draw from the atlas.
+#[derive(Debug, Default, new)]
+pub struct SpriteAtlasSystem {
+    /// Packs sprite sheet images into atlases.
+    atlas_packer: AtlasPacker,
+    /// Reader ID for the `SpriteAtlasRequestEvent` channel.
+    #[new(default)]
+    sprite_atlas_request_event_rid: Option<ReaderId<SpriteAtlasRequestEvent>>,
+}
+
+/// `SpriteAtlasSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SpriteAtlasSystemData<'s> {
+    /// `SpriteAtlasRequestEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub sprite_atlas_request_ec: Read<'s, EventChannel<SpriteAtlasRequestEvent>>,
+    /// `SecondaryMap<AssetId, PathBuf>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_to_path: Read<'s, SecondaryMap<AssetId, PathBuf>>,
+    /// `AssetSpritesDirs` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_dirs: Read<'s, AssetSpritesDirs>,
+    /// `SpritesDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprites_definition_assets: Read<'s, AssetStorage<SpritesDefinition>>,
+    /// `SecondaryMap<AssetId, Handle<SpritesDefinition>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprites_definition_handles:
+        Read<'s, SecondaryMap<AssetId, Handle<SpritesDefinition>>>,
+    /// `SecondaryMap<AssetId, Vec<SpriteSheetHandle>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sprite_sheet_handles: Read<'s, SecondaryMap<AssetId, Vec<SpriteSheetHandle>>>,
+    /// `Loader` to load assets.
+    #[derivative(Debug = "ignore")]
+    pub loader: ReadExpect<'s, Loader>,
+    /// `Texture` assets.
+    #[derivative(Debug = "ignore")]
+    pub texture_assets: Read<'s, AssetStorage<Texture>>,
+    /// `SpriteSheet` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_sheet_assets: Write<'s, AssetStorage<SpriteSheet>>,
+}
+
+/// Sprite sheet to pack into an atlas.
+#[derive(Debug)]
+struct SheetToPack<'d> {
+    /// Definition of the sprite layout on the sprite sheet.
+    definition: &'d SpriteSheetDefinition,
+    /// Handle of the sprite sheet to replace.
+    sprite_sheet_handle: SpriteSheetHandle,
+}
+
+impl<'s> System<'s> for SpriteAtlasSystem {
+    type SystemData = SpriteAtlasSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SpriteAtlasSystemData {
+            sprite_atlas_request_ec,
+            asset_id_to_path,
+            asset_sprites_dirs,
+            sprites_definition_assets,
+            asset_sprites_definition_handles,
+            asset_sprite_sheet_handles,
+            loader,
+            texture_assets,
+            mut sprite_sheet_assets,
+        }: Self::SystemData,
+    ) {
+        let sprite_atlas_request_event_rid = self
+            .sprite_atlas_request_event_rid
+            .as_mut()
+            .expect("Expected `sprite_atlas_request_event_rid` field to be set.");
+
+        let atlas_packer = self.atlas_packer;
+        sprite_atlas_request_ec
+            .read(sprite_atlas_request_event_rid)
+            .for_each(|sprite_atlas_request_event| {
+                // The same asset may be selected more than once.
+                let mut asset_ids_seen = HashSet::new();
+                let (sheets_to_pack, images): (Vec<SheetToPack<'_>>, Vec<AtlasImage>) =
+                    sprite_atlas_request_event
+                        .asset_ids
+                        .iter()
+                        .copied()
+                        .filter(|asset_id| asset_ids_seen.insert(*asset_id))
+                        .filter_map(|asset_id| {
+                            // Sprites may be defined by the asset that this asset extends.
+                            let sprites_dir = asset_sprites_dirs
+                                .get(asset_id)
+                                .or_else(|| asset_id_to_path.get(asset_id))?;
+                            let sprites_definition = asset_sprites_definition_handles
+                                .get(asset_id)
+                                .and_then(|handle| sprites_definition_assets.get(handle))?;
+                            let sprite_sheet_handles = asset_sprite_sheet_handles.get(asset_id)?;
+
+                            Some((sprites_dir, sprites_definition, sprite_sheet_handles))
+                        })
+                        .flat_map(|(sprites_dir, sprites_definition, sprite_sheet_handles)| {
+                            sprites_definition
+                                .sheets
+                                .iter()
+                                .zip(sprite_sheet_handles.iter())
+                                .map(move |(definition, sprite_sheet_handle)| {
+                                    (sprites_dir, definition, sprite_sheet_handle)
+                                })
+                        })
+                        .filter_map(|(sprites_dir, definition, sprite_sheet_handle)| {
+                            let image_path = TextureLoader::image_path(sprites_dir, definition);
+                            match AtlasImage::decode(&image_path) {
+                                Ok(image) => {
+                                    let sheet_to_pack = SheetToPack {
+                                        definition,
+                                        sprite_sheet_handle: sprite_sheet_handle.clone(),
+                                    };
+                                    Some((sheet_to_pack, image))
+                                }
+                                Err(e) => {
+                                    warn!(
+                                        "Not packing `{}` into an atlas, failed to decode: {}",
+                                        image_path.display(),
+                                        e
+                                    );
+                                    None
+                                }
+                            }
+                        })
+                        .unzip();
+                let (atlas_images, atlas_layout) = match atlas_packer.pack(&images) {
+                    Ok(atlases_and_layout) => atlases_and_layout,
+                    Err(e) => {
+                        warn!("Failed to pack sprite sheets into atlases: {}", e);
+                        return;
+                    }
+                };
+
+                debug!(
+                    "Packed {} sprite sheets into {} atlases.",
+                    sheets_to_pack.len(),
+                    atlas_images.len()
+                );
+
+                let atlas_texture_handles = atlas_images
+                    .into_iter()
+                    .map(|atlas_image| {
+                        loader.load_from_data(atlas_image.into_texture_data(), (), &texture_assets)
+                    })
+                    .collect::<Vec<Handle<Texture>>>();
+
+                sheets_to_pack
+                    .iter()
+                    .zip(atlas_layout.placements.iter())
+                    .for_each(|(sheet_to_pack, placement)| {
+                        let atlas_index = placement.atlas_index;
+                        let sprite_sheet_atlas = SpriteSheetMapper::map_atlas_region(
+                            atlas_texture_handles[atlas_index].clone(),
+                            sheet_to_pack.definition,
+                            atlas_layout.atlas_sizes[atlas_index],
+                            *placement,
+                        );
+
+                        if let Some(sprite_sheet) =
+                            sprite_sheet_assets.get_mut(&sheet_to_pack.sprite_sheet_handle)
+                        {
+                            *sprite_sheet = sprite_sheet_atlas;
+                        }
+                    });
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.sprite_atlas_request_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<SpriteAtlasRequestEvent>>()
+                .register_reader(),
+        );
+    }
+}
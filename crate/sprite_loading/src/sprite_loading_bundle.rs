@@ -7,7 +7,10 @@ use amethyst::{
 use derive_new::new;
 use sprite_model::{
     config::SpritesDefinition,
-    loaded::{ScaleSequence, SpriteRenderSequence, TintSequence},
+    loaded::{
+        InterpolationSequence, ScaleSequence, SpriteOffsetSequence, SpriteRenderSequence,
+        TintSequence,
+    },
 };
 
 /// Adds the following systems to the dispatcher:
@@ -16,6 +19,8 @@ use sprite_model::{
 /// * `Processor::<SpriteRenderSequence>`
 /// * `Processor::<TintSequence>`
 /// * `Processor::<ScaleSequence>`
+/// * `Processor::<SpriteOffsetSequence>`
+/// * `Processor::<InterpolationSequence>`
 #[derive(Debug, new)]
 pub struct SpriteLoadingBundle;
 
@@ -45,6 +50,16 @@ impl<'a, 'b> SystemBundle<'a, 'b> for SpriteLoadingBundle {
             "scale_sequence_processor",
             &["sprites_definition_processor"],
         );
+        builder.add(
+            Processor::<SpriteOffsetSequence>::new(),
+            "sprite_offset_sequence_processor",
+            &["sprites_definition_processor"],
+        );
+        builder.add(
+            Processor::<InterpolationSequence>::new(),
+            "interpolation_sequence_processor",
+            &["sprites_definition_processor"],
+        );
         Ok(())
     }
 }
@@ -0,0 +1,56 @@
+use sequence_loading_spi::SequenceComponentDataLoader;
+use sprite_model::{
+    config::SpriteOffset,
+    loaded::{SpriteOffsetSequenceHandle, SpriteOffsetSequenceHandles},
+};
+
+use crate::SpriteOffsetSequenceLoader;
+
+/// Loads `SpriteOffsetSequenceHandle`s from collections of sequences that contain `SpriteOffset`
+/// values.
+#[derive(Debug)]
+pub struct SpriteOffsetSequenceHandlesLoader<'s> {
+    /// `SpriteOffsetSequenceLoader`.
+    pub sprite_offset_sequence_loader: SpriteOffsetSequenceLoader<'s>,
+}
+
+impl<'s> SpriteOffsetSequenceHandlesLoader<'s> {
+    /// Loads `SpriteOffsetSequenceHandles`.
+    ///
+    /// This is similar to calling the `SequenceComponentDataLoader::load` trait method, with the
+    /// difference that the resources are stored by an instantiation of this type, so they do not
+    /// need to be passed in when this method is called.
+    pub fn items_to_datas<
+        'seq_ref,
+        'frame_ref: 'seq_ref,
+        SequencesIterator,
+        SequenceRef,
+        FnSequencesToSequenceIterator,
+        SequenceIterator,
+        FrameRef,
+    >(
+        &self,
+        sequences_iterator: SequencesIterator,
+        fn_sequences_to_sequence_iterator: FnSequencesToSequenceIterator,
+    ) -> SpriteOffsetSequenceHandles
+    where
+        SequencesIterator: Iterator<Item = SequenceRef>,
+        SequenceRef: 'seq_ref,
+        FnSequencesToSequenceIterator: Fn(SequenceRef) -> SequenceIterator,
+        FrameRef: AsRef<SpriteOffset> + 'frame_ref,
+        SequenceIterator: Iterator<Item = FrameRef>,
+    {
+        <Self as SequenceComponentDataLoader>::load(
+            |sequence_ref| {
+                self.sprite_offset_sequence_loader
+                    .load(fn_sequences_to_sequence_iterator(sequence_ref))
+            },
+            sequences_iterator,
+        )
+    }
+}
+
+impl<'s> SequenceComponentDataLoader for SpriteOffsetSequenceHandlesLoader<'s> {
+    type Component = SpriteOffsetSequenceHandle;
+    type ComponentData = SpriteOffsetSequenceHandles;
+}
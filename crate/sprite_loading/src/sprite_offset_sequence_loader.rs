@@ -0,0 +1,57 @@
+use amethyst::assets::{AssetStorage, Handle, Loader};
+use derivative::Derivative;
+use sequence_loading_spi::FrameComponentDataLoader;
+use sprite_model::{config::SpriteOffset, loaded::SpriteOffsetSequence};
+
+/// Loads `SpriteOffsetSequence`s from `Sequence` types whose `Frame`s contain a `SpriteOffset`
+/// value.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct SpriteOffsetSequenceLoader<'s> {
+    /// `Loader`.
+    #[derivative(Debug = "ignore")]
+    pub loader: &'s Loader,
+    /// `SpriteOffsetSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_assets: &'s AssetStorage<SpriteOffsetSequence>,
+}
+
+impl<'s> SpriteOffsetSequenceLoader<'s> {
+    /// Loads a `SpriteOffsetSequence` and returns its handle.
+    ///
+    /// This is similar to calling the `FrameComponentDataLoader::load` trait method, with the
+    /// difference that the resources are stored by an instantiation of this type, so they do not
+    /// need to be passed in when this method is called.
+    pub fn load<SequenceIterator, FrameRef>(
+        &self,
+        sequence_iterator: SequenceIterator,
+    ) -> Handle<SpriteOffsetSequence>
+    where
+        SequenceIterator: Iterator<Item = FrameRef>,
+        FrameRef: AsRef<SpriteOffset>,
+    {
+        <Self as FrameComponentDataLoader>::load(
+            self.loader,
+            self.sprite_offset_sequence_assets,
+            Self::frame_to_component,
+            sequence_iterator,
+        )
+    }
+
+    /// Maps the frame to the component.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_ref`: Reference to the frame.
+    pub fn frame_to_component<FrameRef>(frame_ref: FrameRef) -> SpriteOffset
+    where
+        FrameRef: AsRef<SpriteOffset>,
+    {
+        *AsRef::<SpriteOffset>::as_ref(&frame_ref)
+    }
+}
+
+impl<'s> FrameComponentDataLoader for SpriteOffsetSequenceLoader<'s> {
+    type Component = SpriteOffset;
+    type ComponentData = SpriteOffsetSequence;
+}
@@ -6,6 +6,8 @@ use asset_gfx_gen::{SpriteGenParams, SpriteSheetGen};
 use log::trace;
 use sprite_model::config::SpriteSheetDefinition;
 
+use crate::AtlasPlacement;
+
 /// Maps sprite sheet definitions and texture handles to sprite sheets.
 #[derive(Debug)]
 pub struct SpriteSheetMapper;
@@ -30,6 +32,28 @@ impl SpriteSheetMapper {
             .collect::<Vec<SpriteSheet>>()
     }
 
+    /// Returns an Amethyst `SpriteSheet` whose sprites lie in a region of a texture atlas.
+    ///
+    /// # Parameters
+    ///
+    /// * `atlas_texture_handle`: Handle of the atlas texture.
+    /// * `definition`: Definition of the sprite layout on the sprite sheet.
+    /// * `atlas_size`: Width and height of the atlas in pixels.
+    /// * `placement`: Position of the sprite sheet image within the atlas.
+    pub fn map_atlas_region(
+        atlas_texture_handle: Handle<Texture>,
+        definition: &SpriteSheetDefinition,
+        atlas_size: (u32, u32),
+        placement: AtlasPlacement,
+    ) -> SpriteSheet {
+        Self::definition_to_sprite_sheet_in(
+            atlas_texture_handle,
+            definition,
+            atlas_size,
+            (placement.x, placement.y),
+        )
+    }
+
     /// Converts a `SpriteSheetDefinition` into a `SpriteSheet`.
     ///
     /// # Parameters:
@@ -40,14 +64,33 @@ impl SpriteSheetMapper {
         texture_handle: Handle<Texture>,
         definition: &SpriteSheetDefinition,
     ) -> SpriteSheet {
-        let mut sprites =
-            Vec::with_capacity((definition.row_count * definition.column_count) as usize);
         let (offset_w, offset_h) = Self::offset_distances(definition);
-        let (image_w, image_h) = (
+        let image_size = (
             offset_w * definition.column_count,
             offset_h * definition.row_count,
         );
 
+        Self::definition_to_sprite_sheet_in(texture_handle, definition, image_size, (0, 0))
+    }
+
+    /// Converts a `SpriteSheetDefinition` into a `SpriteSheet` whose image lies within a texture.
+    ///
+    /// # Parameters:
+    ///
+    /// * `texture_handle`: Handle of the texture containing the sprite sheet image.
+    /// * `definition`: Definition of the sprite layout on the sprite sheet.
+    /// * `(image_w, image_h)`: Width and height of the texture in pixels.
+    /// * `(image_x, image_y)`: Position of the sprite sheet image within the texture.
+    fn definition_to_sprite_sheet_in(
+        texture_handle: Handle<Texture>,
+        definition: &SpriteSheetDefinition,
+        (image_w, image_h): (u32, u32),
+        (image_x, image_y): (u32, u32),
+    ) -> SpriteSheet {
+        let mut sprites =
+            Vec::with_capacity((definition.row_count * definition.column_count) as usize);
+        let (offset_w, offset_h) = Self::offset_distances(definition);
+
         let sprite_offsets = definition.offsets.as_ref();
 
         for row in 0..definition.row_count {
@@ -101,8 +144,8 @@ impl SpriteSheetMapper {
                     image_h,
                     sprite_w: definition.sprite_w,
                     sprite_h: definition.sprite_h,
-                    pixel_left: offset_x,
-                    pixel_top: offset_y,
+                    pixel_left: image_x + offset_x,
+                    pixel_top: image_y + offset_y,
                     offsets,
                 };
 
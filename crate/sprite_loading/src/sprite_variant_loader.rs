@@ -0,0 +1,144 @@
+use std::{path::Path, slice};
+
+use amethyst::{
+    assets::{AssetStorage, Handle, Loader, ProgressCounter},
+    renderer::{sprite::SpriteSheetHandle, SpriteSheet, Texture},
+    Error,
+};
+use log::error;
+use sprite_model::config::{SpriteSheetDefinition, SpriteVariant, SpritesDefinition};
+
+use crate::{AtlasImage, SpriteSheetLoader, TextureLoader};
+
+/// Loads the sprite sheets of each `SpriteVariant` of an object.
+#[derive(Debug)]
+pub struct SpriteVariantLoader;
+
+impl SpriteVariantLoader {
+    /// Loads the sprite sheets for each variant and returns their handles.
+    ///
+    /// Sprite sheets that a variant does not change reuse the object's sprite sheet handle, so
+    /// their textures are not loaded again. If a variant's sprite sheet fails to load, the error
+    /// is logged and the object's sprite sheet is used instead.
+    ///
+    /// Sheets that a variant overrides are relative to the object's own directory, while sheets
+    /// from the sprites definition are relative to the directory of that definition, which may be
+    /// a parent asset's.
+    ///
+    /// # Parameters
+    ///
+    /// * `progress_counter`: `ProgressCounter` to track loading.
+    /// * `loader`: `Loader` to load assets.
+    /// * `texture_assets`: `AssetStorage` for `Texture`s.
+    /// * `sprite_sheet_assets`: `AssetStorage` for `SpriteSheet`s.
+    /// * `sprites_definition`: The loaded `sprites.yaml`.
+    /// * `sprite_sheet_handles`: Handles of the object's sprite sheets.
+    /// * `sprite_variants`: Variants of the object's sprites.
+    /// * `sprites_dir`: Directory of the sprites definition.
+    /// * `asset_dir`: Object configuration base directory.
+    #[allow(clippy::too_many_arguments)]
+    pub fn load(
+        progress_counter: &mut ProgressCounter,
+        loader: &Loader,
+        texture_assets: &AssetStorage<Texture>,
+        sprite_sheet_assets: &AssetStorage<SpriteSheet>,
+        sprites_definition: &SpritesDefinition,
+        sprite_sheet_handles: &[SpriteSheetHandle],
+        sprite_variants: &[SpriteVariant],
+        sprites_dir: &Path,
+        asset_dir: &Path,
+    ) -> Vec<Vec<SpriteSheetHandle>> {
+        sprite_variants
+            .iter()
+            .map(|sprite_variant| {
+                sprites_definition
+                    .sheets
+                    .iter()
+                    .zip(sprite_sheet_handles.iter())
+                    .enumerate()
+                    .map(|(index, (sheet_definition, sprite_sheet_handle))| {
+                        let sheet_path = sprite_variant.sheets.get(index);
+                        if sheet_path.is_none() && sprite_variant.palette.is_empty() {
+                            return sprite_sheet_handle.clone();
+                        }
+
+                        let (sheet_definition, base_dir) = match sheet_path {
+                            Some(sheet_path) => {
+                                let sheet_definition = SpriteSheetDefinition {
+                                    path: sheet_path.clone(),
+                                    ..sheet_definition.clone()
+                                };
+                                (sheet_definition, asset_dir)
+                            }
+                            None => (sheet_definition.clone(), sprites_dir),
+                        };
+                        let texture_handle = match Self::load_texture(
+                            progress_counter,
+                            loader,
+                            texture_assets,
+                            &sheet_definition,
+                            sprite_variant,
+                            base_dir,
+                        ) {
+                            Ok(texture_handle) => texture_handle,
+                            Err(e) => {
+                                error!(
+                                    "Failed to load sprite variant sheet, using the object's \
+                                     sprite sheet instead: {}",
+                                    e
+                                );
+                                return sprite_sheet_handle.clone();
+                            }
+                        };
+
+                        let mut variant_sheet_handles = SpriteSheetLoader::load(
+                            progress_counter,
+                            loader,
+                            sprite_sheet_assets,
+                            slice::from_ref(&texture_handle),
+                            slice::from_ref(&sheet_definition),
+                        );
+                        variant_sheet_handles.remove(0)
+                    })
+                    .collect::<Vec<SpriteSheetHandle>>()
+            })
+            .collect::<Vec<Vec<SpriteSheetHandle>>>()
+    }
+
+    /// Loads a variant's sprite sheet image, replacing its palette colours if there are any.
+    fn load_texture(
+        progress_counter: &mut ProgressCounter,
+        loader: &Loader,
+        texture_assets: &AssetStorage<Texture>,
+        sheet_definition: &SpriteSheetDefinition,
+        sprite_variant: &SpriteVariant,
+        base_dir: &Path,
+    ) -> Result<Handle<Texture>, Error> {
+        if sprite_variant.palette.is_empty() {
+            let mut texture_handles = TextureLoader::load_textures(
+                progress_counter,
+                loader,
+                texture_assets,
+                base_dir,
+                slice::from_ref(sheet_definition),
+            )?;
+            Ok(texture_handles.remove(0))
+        } else {
+            let image_path = TextureLoader::image_path(base_dir, sheet_definition);
+            let mut image = AtlasImage::decode(&image_path).map_err(|e| {
+                Error::from_string(format!(
+                    "Failed to decode sprite variant image `{}`: {}",
+                    image_path.display(),
+                    e
+                ))
+            })?;
+            image.swap_palette(&sprite_variant.palette);
+
+            Ok(loader.load_from_data(
+                image.into_texture_data(),
+                &mut *progress_counter,
+                texture_assets,
+            ))
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::{Component, Path};
+use std::path::{Component, Path, PathBuf};
 
 use amethyst::{
     assets::{AssetStorage, Handle, Loader, ProgressCounter},
@@ -32,28 +32,7 @@ impl TextureLoader {
         let texture_results = sprite_sheet_definitions
             .iter()
             .map(|sheet_definition| {
-                // We need to do this to handle mixed slashes on Windows.
-                let sheet_definition_path = Path::new(&sheet_definition.path);
-                let sprite_image_path = if sheet_definition_path.is_absolute() {
-                    sheet_definition_path.to_path_buf()
-                } else {
-                    sheet_definition_path.components().fold(
-                        object_directory.to_path_buf(),
-                        |mut sprite_image_path, sheet_definition_component| {
-                            match sheet_definition_component {
-                                Component::ParentDir => {
-                                    sprite_image_path.pop();
-                                }
-                                Component::Normal(segment) => {
-                                    sprite_image_path.push(segment);
-                                }
-                                Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
-                            }
-
-                            sprite_image_path
-                        },
-                    )
-                };
+                let sprite_image_path = Self::image_path(object_directory, sheet_definition);
 
                 let error_msg = format!(
                     "Failed to transform sprite image path to String: `{}`",
@@ -103,6 +82,40 @@ impl TextureLoader {
         Ok(texture_handles)
     }
 
+    /// Returns the path to a sprite sheet's image.
+    ///
+    /// # Parameters
+    ///
+    /// * `object_directory`: Object configuration base directory.
+    /// * `sheet_definition`: Metadata of the sprite sheet.
+    pub fn image_path(
+        object_directory: &Path,
+        sheet_definition: &SpriteSheetDefinition,
+    ) -> PathBuf {
+        // We need to do this to handle mixed slashes on Windows.
+        let sheet_definition_path = Path::new(&sheet_definition.path);
+        if sheet_definition_path.is_absolute() {
+            sheet_definition_path.to_path_buf()
+        } else {
+            sheet_definition_path.components().fold(
+                object_directory.to_path_buf(),
+                |mut sprite_image_path, sheet_definition_component| {
+                    match sheet_definition_component {
+                        Component::ParentDir => {
+                            sprite_image_path.pop();
+                        }
+                        Component::Normal(segment) => {
+                            sprite_image_path.push(segment);
+                        }
+                        Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+                    }
+
+                    sprite_image_path
+                },
+            )
+        }
+    }
+
     /// Returns a `Handle<Texture>` to the image.
     ///
     /// This function expects the image to be in PNG format.
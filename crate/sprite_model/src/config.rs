@@ -1,12 +1,15 @@
 //! User defined configuration types for sprites.
 
 pub use self::{
-    scale::Scale, sprite_frame::SpriteFrame, sprite_item::SpriteItem, sprite_offset::SpriteOffset,
+    interpolation::Interpolation, palette_swap::PaletteSwap, scale::Scale,
+    sprite_frame::SpriteFrame, sprite_item::SpriteItem, sprite_offset::SpriteOffset,
     sprite_ref::SpriteRef, sprite_sequence::SpriteSequence,
     sprite_sequence_name::SpriteSequenceName, sprite_sheet_definition::SpriteSheetDefinition,
-    sprites_definition::SpritesDefinition, tint::Tint,
+    sprite_variant::SpriteVariant, sprites_definition::SpritesDefinition, tint::Tint,
 };
 
+mod interpolation;
+mod palette_swap;
 mod scale;
 mod sprite_frame;
 mod sprite_item;
@@ -15,5 +18,6 @@ mod sprite_ref;
 mod sprite_sequence;
 mod sprite_sequence_name;
 mod sprite_sheet_definition;
+mod sprite_variant;
 mod sprites_definition;
 mod tint;
@@ -0,0 +1,51 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_schema::AssetSchema;
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// How a frame's `Tint`, `Scale`, and `SpriteOffset` blend into the next frame's values.
+///
+/// Values are blended across the frame's `Wait` duration. The last frame of a sequence is not
+/// blended, as there is no next frame to blend towards.
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Interpolation {
+    /// Values switch to the next frame's values when the next frame begins.
+    #[derivative(Default)]
+    Step,
+    /// Values change at a constant rate.
+    Linear,
+    /// Values change slowly at the start, then speed up.
+    EaseIn,
+    /// Values change quickly at the start, then slow down.
+    EaseOut,
+    /// Values change slowly at the start and end, and quickly in the middle.
+    EaseInOut,
+}
+
+impl Interpolation {
+    /// Returns how far to blend from the current value to the next value.
+    ///
+    /// # Parameters
+    ///
+    /// * `progress`: Proportion of the frame's `Wait` that has elapsed, between 0.0 and 1.0.
+    pub fn blend_factor(self, progress: f32) -> f32 {
+        let t = progress.max(0.).min(1.);
+        match self {
+            Interpolation::Step => 0.,
+            Interpolation::Linear => t,
+            Interpolation::EaseIn => t * t,
+            Interpolation::EaseOut => t * (2. - t),
+            Interpolation::EaseInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    -1. + (4. - 2. * t) * t
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Replaces a colour in sprite sheet images with another colour.
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct PaletteSwap {
+    /// RGB values of the colour to replace.
+    pub from: [u8; 3],
+    /// RGB values of the replacement colour.
+    pub to: [u8; 3],
+}
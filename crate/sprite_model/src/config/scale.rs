@@ -1,10 +1,13 @@
 use std::ops::{Deref, DerefMut};
 
 use amethyst::ecs::{storage::VecStorage, Component};
+use asset_schema::AssetSchema;
 use serde::{Deserialize, Serialize};
 
 /// Number of ticks to stay on the current frame before switching to the next frame.
-#[derive(Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Serialize,
+)]
 #[storage(VecStorage)]
 pub struct Scale(pub Option<f32>);
 
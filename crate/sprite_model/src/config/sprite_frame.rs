@@ -1,15 +1,16 @@
 use std::convert::AsRef;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use sequence_model::config::Wait;
 use serde::{Deserialize, Serialize};
 
-use crate::config::{Scale, SpriteRef, Tint};
+use crate::config::{Interpolation, Scale, SpriteOffset, SpriteRef, Tint};
 
 /// Frame with a `SpriteRef`.
 ///
 /// This is useful when the sequence does not need any other behaviour besides displaying a sprite.
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default)]
 pub struct SpriteFrame {
     /// Number of ticks to wait before the sequence switches to the next frame.
@@ -20,6 +21,10 @@ pub struct SpriteFrame {
     pub tint: Tint,
     /// Scaling to apply to the sprite.
     pub scale: Scale,
+    /// Pixel offsets to shift the sprite by.
+    pub offset: SpriteOffset,
+    /// How the tint, scale, and offset blend into the next frame's values.
+    pub interpolation: Interpolation,
 }
 
 impl AsRef<Wait> for SpriteFrame {
@@ -45,3 +50,15 @@ impl AsRef<Scale> for SpriteFrame {
         &self.scale
     }
 }
+
+impl AsRef<SpriteOffset> for SpriteFrame {
+    fn as_ref(&self) -> &SpriteOffset {
+        &self.offset
+    }
+}
+
+impl AsRef<Interpolation> for SpriteFrame {
+    fn as_ref(&self) -> &Interpolation {
+        &self.interpolation
+    }
+}
@@ -1,5 +1,6 @@
 use std::convert::AsRef;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use sequence_model::config::SequenceName;
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::{SpriteSequence, SpriteSequenceName};
 
 /// Sequence of sprites in a static position.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 pub struct SpriteItem<SeqName = SpriteSequenceName>
 where
     SeqName: SequenceName,
@@ -1,3 +1,5 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
@@ -5,7 +7,11 @@ use serde::{Deserialize, Serialize};
 ///
 /// A positive x value shifts the sprite to the left by that many pixels.
 /// A positive y value shifts the sprite upwards by that many pixels.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Serialize, new,
+)]
+#[serde(default)]
+#[storage(VecStorage)]
 pub struct SpriteOffset {
     /// Number of pixels to shift the sprite to the left, relative to the entity's position.
     pub x: i32,
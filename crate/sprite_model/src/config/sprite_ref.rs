@@ -1,10 +1,22 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
 /// Animation frame that displays a sprite.
 #[derive(
-    Clone, Component, Copy, Debug, Default, Deserialize, Hash, PartialEq, Eq, Serialize, new,
+    AssetSchema,
+    Clone,
+    Component,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+    new,
 )]
 pub struct SpriteRef {
     /// Sprite sheet number.
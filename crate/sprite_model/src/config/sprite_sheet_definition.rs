@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
@@ -6,7 +7,7 @@ use crate::config::SpriteOffset;
 /// Information about how sprites are laid out on the sprite sheet.
 ///
 /// This is used to calculate the texture coordinates of each sprite.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 pub struct SpriteSheetDefinition {
     /// Path to the sprite sheet, relative to the object's directory.
     ///
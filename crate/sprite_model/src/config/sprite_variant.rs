@@ -0,0 +1,20 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::PaletteSwap;
+
+/// Alternate appearance of an object's sprites.
+///
+/// A variant uses the same sprite layout as the object's sprite sheets, so sequences that refer
+/// to sprites are shared between variants.
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpriteVariant {
+    /// Paths to images to use in place of each sprite sheet, relative to the object's directory.
+    ///
+    /// Sprite sheets beyond the end of this list use the object's sprite sheet image.
+    pub sheets: Vec<String>,
+    /// Colours to replace in the sprite sheet images.
+    pub palette: Vec<PaletteSwap>,
+}
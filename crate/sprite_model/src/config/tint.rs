@@ -1,8 +1,9 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
 /// RGBA multipliers to apply to the sprite.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields, default)]
 pub struct Tint {
     /// Value between 0.0 and 1.0 (inclusive) to multiply the red channel with.
@@ -1,17 +1,31 @@
 //! Types that represent processed configuration.
 
 pub use self::{
+    asset_sprite_variant_sheet_handles::AssetSpriteVariantSheetHandles,
+    asset_sprites_dirs::AssetSpritesDirs,
+    interpolation_sequence::{InterpolationSequence, InterpolationSequenceHandle},
+    interpolation_sequence_handles::InterpolationSequenceHandles,
     scale_sequence::{ScaleSequence, ScaleSequenceHandle},
     scale_sequence_handles::ScaleSequenceHandles,
+    sprite_offset_sequence::{SpriteOffsetSequence, SpriteOffsetSequenceHandle},
+    sprite_offset_sequence_handles::SpriteOffsetSequenceHandles,
     sprite_render_sequence::{SpriteRenderSequence, SpriteRenderSequenceHandle},
     sprite_render_sequence_handles::SpriteRenderSequenceHandles,
+    sprite_sheet_variant::SpriteSheetVariant,
     tint_sequence::{TintSequence, TintSequenceHandle},
     tint_sequence_handles::TintSequenceHandles,
 };
 
+mod asset_sprite_variant_sheet_handles;
+mod asset_sprites_dirs;
+mod interpolation_sequence;
+mod interpolation_sequence_handles;
 mod scale_sequence;
 mod scale_sequence_handles;
+mod sprite_offset_sequence;
+mod sprite_offset_sequence_handles;
 mod sprite_render_sequence;
 mod sprite_render_sequence_handles;
+mod sprite_sheet_variant;
 mod tint_sequence;
 mod tint_sequence_handles;
@@ -0,0 +1,9 @@
+use amethyst::renderer::sprite::SpriteSheetHandle;
+use asset_model::loaded::AssetId;
+use slotmap::SecondaryMap;
+
+/// Sprite sheet handles of each `SpriteVariant` of an asset.
+///
+/// The outer `Vec` is indexed by variant, and each inner `Vec` holds a handle for each of the
+/// asset's sprite sheets.
+pub type AssetSpriteVariantSheetHandles = SecondaryMap<AssetId, Vec<Vec<SpriteSheetHandle>>>;
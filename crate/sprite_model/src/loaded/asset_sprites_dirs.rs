@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use asset_model::loaded::AssetId;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use slotmap::SecondaryMap;
+
+/// Directory that each asset's sprites definition and images are loaded from.
+///
+/// This differs from the asset's directory when an object extends another object and uses its
+/// sprites.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct AssetSpritesDirs(pub SecondaryMap<AssetId, PathBuf>);
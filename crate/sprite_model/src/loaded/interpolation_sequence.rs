@@ -0,0 +1,7 @@
+use sequence_model::frame_component_data;
+
+use crate::config::Interpolation;
+
+/// Sequence of `Interpolation` values.
+#[frame_component_data(Interpolation, copy)]
+pub struct InterpolationSequence;
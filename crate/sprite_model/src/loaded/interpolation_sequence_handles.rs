@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::InterpolationSequenceHandle;
+
+/// Sequence of `InterpolationSequenceHandle`s.
+#[sequence_component_data(InterpolationSequenceHandle)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct InterpolationSequenceHandles;
+
+impl<'s> ItemComponent<'s> for InterpolationSequenceHandles {
+    type SystemData = ();
+}
@@ -0,0 +1,7 @@
+use sequence_model::frame_component_data;
+
+use crate::config::SpriteOffset;
+
+/// Sequence of `SpriteOffset` values.
+#[frame_component_data(SpriteOffset, copy)]
+pub struct SpriteOffsetSequence;
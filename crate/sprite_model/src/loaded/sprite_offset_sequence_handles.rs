@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::SpriteOffsetSequenceHandle;
+
+/// Sequence of `SpriteOffsetSequenceHandle`s.
+#[sequence_component_data(SpriteOffsetSequenceHandle)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct SpriteOffsetSequenceHandles;
+
+impl<'s> ItemComponent<'s> for SpriteOffsetSequenceHandles {
+    type SystemData = ();
+}
@@ -0,0 +1,30 @@
+use amethyst::{
+    ecs::{storage::DenseVecStorage, Component},
+    renderer::sprite::SpriteSheetHandle,
+};
+use derive_new::new;
+
+/// Sprite sheets to render in place of an object's sprite sheets.
+#[derive(Clone, Component, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct SpriteSheetVariant {
+    /// Handles of the object's sprite sheets.
+    pub sprite_sheet_handles: Vec<SpriteSheetHandle>,
+    /// Handles of the sprite sheets to render instead, in the same order.
+    pub variant_sheet_handles: Vec<SpriteSheetHandle>,
+}
+
+impl SpriteSheetVariant {
+    /// Returns the handle of the sprite sheet to render in place of the given sprite sheet.
+    ///
+    /// Returns `None` if the sprite sheet is not one of the object's sprite sheets.
+    pub fn variant_of(
+        &self,
+        sprite_sheet_handle: &SpriteSheetHandle,
+    ) -> Option<&SpriteSheetHandle> {
+        self.sprite_sheet_handles
+            .iter()
+            .position(|handle| handle == sprite_sheet_handle)
+            .and_then(|index| self.variant_sheet_handles.get(index))
+    }
+}
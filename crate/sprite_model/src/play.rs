@@ -0,0 +1,9 @@
+//! Data types used at runtime.
+
+pub use self::{
+    sprite_atlas_request_event::SpriteAtlasRequestEvent,
+    sprite_offset_translation::SpriteOffsetTranslation,
+};
+
+mod sprite_atlas_request_event;
+mod sprite_offset_translation;
@@ -0,0 +1,9 @@
+use asset_model::loaded::AssetId;
+use derive_new::new;
+
+/// Event requesting that the sprite sheets of assets be packed into texture atlases.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct SpriteAtlasRequestEvent {
+    /// IDs of the assets whose sprite sheets to pack together.
+    pub asset_ids: Vec<AssetId>,
+}
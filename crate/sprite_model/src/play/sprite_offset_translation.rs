@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derive_new::new;
+
+/// Translation applied to an entity's `Transform` from its interpolated `SpriteOffset`.
+///
+/// This is tracked so that the previous translation can be replaced when the offset changes.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, new)]
+#[storage(VecStorage)]
+pub struct SpriteOffsetTranslation {
+    /// Number of pixels the sprite is shifted to the right.
+    pub x: f32,
+    /// Number of pixels the sprite is shifted upwards.
+    pub y: f32,
+}
@@ -2,6 +2,9 @@
 
 //! Provides logic for sprite components at runtime.
 
-pub use crate::system::SpriteScaleUpdateSystem;
+pub use crate::system::{
+    SpriteInterpolationSystem, SpriteInterpolationSystemData, SpriteScaleUpdateSystem,
+    SpriteSheetVariantSystem, SpriteSheetVariantSystemData,
+};
 
 mod system;
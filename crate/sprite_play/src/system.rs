@@ -1,3 +1,9 @@
-pub use self::sprite_scale_update_system::SpriteScaleUpdateSystem;
+pub use self::{
+    sprite_interpolation_system::{SpriteInterpolationSystem, SpriteInterpolationSystemData},
+    sprite_scale_update_system::SpriteScaleUpdateSystem,
+    sprite_sheet_variant_system::{SpriteSheetVariantSystem, SpriteSheetVariantSystemData},
+};
 
+mod sprite_interpolation_system;
 mod sprite_scale_update_system;
+mod sprite_sheet_variant_system;
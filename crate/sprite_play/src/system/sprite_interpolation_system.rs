@@ -0,0 +1,184 @@
+use amethyst::{
+    assets::AssetStorage,
+    ecs::{Entities, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::play::{FrameIndexClock, FrameWaitClock};
+use sprite_model::{
+    config::{Interpolation, Scale, SpriteOffset},
+    loaded::{
+        ScaleSequence, ScaleSequenceHandle, SpriteOffsetSequence, SpriteOffsetSequenceHandle,
+    },
+    play::SpriteOffsetTranslation,
+};
+
+/// Blends each entity's `Scale` and `SpriteOffset` towards the next frame's values.
+///
+/// The `SpriteOffsetTranslation` is updated every tick, even when the frame is not blended. `Tint`
+/// is blended with [`SpriteInterpolationSystem::blend_tint`] by the system that resolves an
+/// object's `Tint`, as other effects are layered over it.
+#[derive(Debug, Default, new)]
+pub struct SpriteInterpolationSystem;
+
+/// `SpriteInterpolationSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SpriteInterpolationSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: ReadStorage<'s, FrameIndexClock>,
+    /// `FrameWaitClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_wait_clocks: ReadStorage<'s, FrameWaitClock>,
+    /// `Interpolation` components.
+    #[derivative(Debug = "ignore")]
+    pub interpolations: ReadStorage<'s, Interpolation>,
+    /// `ScaleSequenceHandle` components.
+    #[derivative(Debug = "ignore")]
+    pub scale_sequence_handles: ReadStorage<'s, ScaleSequenceHandle>,
+    /// `SpriteOffsetSequenceHandle` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_handles: ReadStorage<'s, SpriteOffsetSequenceHandle>,
+    /// `ScaleSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub scale_sequence_assets: Read<'s, AssetStorage<ScaleSequence>>,
+    /// `SpriteOffsetSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_sequence_assets: Read<'s, AssetStorage<SpriteOffsetSequence>>,
+    /// `Scale` components.
+    #[derivative(Debug = "ignore")]
+    pub scales: WriteStorage<'s, Scale>,
+    /// `SpriteOffsetTranslation` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_offset_translations: WriteStorage<'s, SpriteOffsetTranslation>,
+}
+
+impl SpriteInterpolationSystem {
+    /// Returns how far to blend the current frame's values towards the next frame's values.
+    pub fn blend_factor(frame_wait_clock: FrameWaitClock, interpolation: Interpolation) -> f32 {
+        let progress = if frame_wait_clock.limit > 0 {
+            frame_wait_clock.value as f32 / frame_wait_clock.limit as f32
+        } else {
+            0.
+        };
+        interpolation.blend_factor(progress)
+    }
+
+    /// Returns the `Tint` blended from `current` towards `next`.
+    pub fn blend_tint(current: Tint, next: Tint, factor: f32) -> Tint {
+        let (current, next) = (current.0, next.0);
+        Tint(Srgba::new(
+            Self::lerp(current.red, next.red, factor),
+            Self::lerp(current.green, next.green, factor),
+            Self::lerp(current.blue, next.blue, factor),
+            Self::lerp(current.alpha, next.alpha, factor),
+        ))
+    }
+
+    /// Returns the `Scale` blended from `current` towards `next`.
+    ///
+    /// If either scale is not set, the current scale is returned.
+    pub fn blend_scale(current: Scale, next: Scale, factor: f32) -> Scale {
+        match (*current, *next) {
+            (Some(current), Some(next)) => Scale::new(Self::lerp(current, next, factor)),
+            _ => current,
+        }
+    }
+
+    /// Returns the translation for the `SpriteOffset` blended from `current` towards `next`.
+    ///
+    /// `SpriteOffset` x values shift the sprite to the left, so the x translation is negated.
+    pub fn blend_offset(
+        current: SpriteOffset,
+        next: SpriteOffset,
+        factor: f32,
+    ) -> SpriteOffsetTranslation {
+        let x = Self::lerp(current.x as f32, next.x as f32, factor);
+        let y = Self::lerp(current.y as f32, next.y as f32, factor);
+        SpriteOffsetTranslation::new(-x, y)
+    }
+
+    fn lerp(current: f32, next: f32, factor: f32) -> f32 {
+        current + (next - current) * factor
+    }
+
+    /// Returns the current and next values in a frame component sequence.
+    ///
+    /// The next value is the current value when the current frame is the last frame.
+    pub fn current_and_next<C>(values: &[C], frame_index: usize) -> Option<(C, C)>
+    where
+        C: Copy,
+    {
+        values.get(frame_index).copied().map(|current| {
+            let next = values.get(frame_index + 1).copied().unwrap_or(current);
+            (current, next)
+        })
+    }
+}
+
+impl<'s> System<'s> for SpriteInterpolationSystem {
+    type SystemData = SpriteInterpolationSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SpriteInterpolationSystemData {
+            entities,
+            frame_index_clocks,
+            frame_wait_clocks,
+            interpolations,
+            scale_sequence_handles,
+            sprite_offset_sequence_handles,
+            scale_sequence_assets,
+            sprite_offset_sequence_assets,
+            mut scales,
+            mut sprite_offset_translations,
+        }: Self::SystemData,
+    ) {
+        (
+            &entities,
+            &frame_index_clocks,
+            &frame_wait_clocks,
+            interpolations.maybe(),
+        )
+            .join()
+            .for_each(
+                |(entity, frame_index_clock, frame_wait_clock, interpolation)| {
+                    let interpolation = interpolation.copied().unwrap_or_default();
+                    let factor = Self::blend_factor(*frame_wait_clock, interpolation);
+                    let frame_index = frame_index_clock.value;
+
+                    let sprite_offsets = sprite_offset_sequence_handles
+                        .get(entity)
+                        .and_then(|handle| sprite_offset_sequence_assets.get(handle))
+                        .and_then(|sequence| Self::current_and_next(sequence, frame_index));
+                    if let Some((current, next)) = sprite_offsets {
+                        sprite_offset_translations
+                            .insert(entity, Self::blend_offset(current, next, factor))
+                            .expect("Failed to insert `SpriteOffsetTranslation` component.");
+                    }
+
+                    // `Scale` is already set to the frame's value by the frame component update
+                    // system.
+                    if interpolation == Interpolation::Step {
+                        return;
+                    }
+
+                    let scale_values = scale_sequence_handles
+                        .get(entity)
+                        .and_then(|handle| scale_sequence_assets.get(handle))
+                        .and_then(|sequence| Self::current_and_next(sequence, frame_index));
+                    if let Some((current, next)) = scale_values {
+                        scales
+                            .insert(entity, Self::blend_scale(current, next, factor))
+                            .expect("Failed to insert `Scale` component.");
+                    }
+                },
+            );
+    } // kcov-ignore
+}
@@ -0,0 +1,49 @@
+use amethyst::{
+    ecs::{Join, ReadStorage, System, World, WriteStorage},
+    renderer::SpriteRender,
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use sprite_model::loaded::SpriteSheetVariant;
+
+/// Swaps the sprite sheet of each `SpriteRender` for the entity's variant sprite sheet.
+///
+/// `SpriteRender`s are set from the object's sprite sheets each frame, so this runs every tick.
+#[derive(Debug, Default, new)]
+pub struct SpriteSheetVariantSystem;
+
+/// `SpriteSheetVariantSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SpriteSheetVariantSystemData<'s> {
+    /// `SpriteSheetVariant` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_sheet_variants: ReadStorage<'s, SpriteSheetVariant>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: WriteStorage<'s, SpriteRender>,
+}
+
+impl<'s> System<'s> for SpriteSheetVariantSystem {
+    type SystemData = SpriteSheetVariantSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SpriteSheetVariantSystemData {
+            sprite_sheet_variants,
+            mut sprite_renders,
+        }: Self::SystemData,
+    ) {
+        (&sprite_sheet_variants, &mut sprite_renders)
+            .join()
+            .for_each(|(sprite_sheet_variant, sprite_render)| {
+                let variant_sheet_handle = sprite_sheet_variant
+                    .variant_of(&sprite_render.sprite_sheet)
+                    .cloned();
+                if let Some(variant_sheet_handle) = variant_sheet_handle {
+                    sprite_render.sprite_sheet = variant_sheet_handle;
+                }
+            });
+    } // kcov-ignore
+}
@@ -40,6 +40,8 @@ pub enum StateId {
     MapSelection,
     /// `NetworkModeSelectionState` ID.
     NetworkModeSelection,
+    /// `PauseMenuState` ID.
+    PauseMenu,
     /// `SessionHostState` ID.
     SessionHost,
     /// `SessionJoinState` ID.
@@ -0,0 +1,8 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Loads the waves played in survival mode.
+
+pub use crate::{survival_loading_bundle::SurvivalLoadingBundle, system::SurvivalLoadingSystem};
+
+mod survival_loading_bundle;
+mod system;
@@ -0,0 +1,42 @@
+use std::{any, path::PathBuf};
+
+use amethyst::{
+    assets::Processor,
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use derive_new::new;
+use survival_model::config::SurvivalWaves;
+
+use crate::SurvivalLoadingSystem;
+
+/// Adds the following systems to the `World`:
+///
+/// * `Processor<SurvivalWaves>`
+/// * `SurvivalLoadingSystem`
+#[derive(Debug, new)]
+pub struct SurvivalLoadingBundle {
+    /// Path to the assets directory.
+    assets_dir: PathBuf,
+}
+
+impl<'a, 'b> SystemBundle<'a, 'b> for SurvivalLoadingBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            Processor::<SurvivalWaves>::new(),
+            "survival_waves_processor",
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            SurvivalLoadingSystem::new(self.assets_dir),
+            any::type_name::<SurvivalLoadingSystem>(),
+            &["survival_waves_processor"],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub use self::survival_loading_system::SurvivalLoadingSystem;
+
+mod survival_loading_system;
@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+
+use amethyst::{
+    assets::{AssetStorage, Handle, Loader, ProgressCounter},
+    ecs::{Read, ReadExpect, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use asset_loading::YamlFormat;
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error, warn};
+use survival_model::{config::SurvivalWaves, SurvivalLoadingStatus};
+#[cfg(target_arch = "wasm32")]
+use wasm_support_fs::PathAccessExt;
+
+/// File name of the survival waves configuration.
+const SURVIVAL_WAVES_YAML: &str = "survival_waves.yaml";
+
+/// Loads the `SurvivalWaves` configuration.
+#[derive(Default, Derivative, new)]
+#[derivative(Debug)]
+pub struct SurvivalLoadingSystem {
+    /// Path to the assets directory.
+    assets_dir: PathBuf,
+    /// `Handle` to the `SurvivalWaves`.
+    #[new(default)]
+    survival_waves_handle: Option<Handle<SurvivalWaves>>,
+    /// Tracks loaded assets.
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    progress_counter: ProgressCounter,
+}
+
+/// `SurvivalLoadingSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SurvivalLoadingSystemData<'s> {
+    /// `Loader` to load assets.
+    #[derivative(Debug = "ignore")]
+    pub loader: ReadExpect<'s, Loader>,
+    /// `SurvivalWaves` assets.
+    #[derivative(Debug = "ignore")]
+    pub survival_waves_assets: Read<'s, AssetStorage<SurvivalWaves>>,
+    /// `SurvivalWaves` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_waves: Write<'s, SurvivalWaves>,
+    /// `SurvivalLoadingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_loading_status: Write<'s, SurvivalLoadingStatus>,
+}
+
+impl<'s> System<'s> for SurvivalLoadingSystem {
+    type SystemData = SurvivalLoadingSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SurvivalLoadingSystemData {
+            loader,
+            survival_waves_assets,
+            mut survival_waves,
+            mut survival_loading_status,
+        }: Self::SystemData,
+    ) {
+        if *survival_loading_status == SurvivalLoadingStatus::NotStarted {
+            *survival_loading_status = SurvivalLoadingStatus::InProgress;
+
+            let survival_waves_yaml_path = self.assets_dir.join(SURVIVAL_WAVES_YAML);
+            #[cfg(not(target_arch = "wasm32"))]
+            let survival_waves_yaml_path_exists = survival_waves_yaml_path.exists();
+            #[cfg(target_arch = "wasm32")]
+            let survival_waves_yaml_path_exists = survival_waves_yaml_path.exists_on_server();
+
+            if survival_waves_yaml_path_exists {
+                let handle = loader.load(
+                    SURVIVAL_WAVES_YAML,
+                    YamlFormat,
+                    &mut self.progress_counter,
+                    &survival_waves_assets,
+                );
+                self.survival_waves_handle = Some(handle);
+            } else {
+                warn!(
+                    "`{}` does not exist in `assets` directory, survival mode will not spawn \
+                     enemies.",
+                    SURVIVAL_WAVES_YAML
+                );
+                *survival_loading_status = SurvivalLoadingStatus::Complete;
+            }
+        }
+
+        if *survival_loading_status == SurvivalLoadingStatus::InProgress {
+            let survival_waves_loaded = self
+                .survival_waves_handle
+                .as_ref()
+                .and_then(|survival_waves_handle| survival_waves_assets.get(survival_waves_handle));
+
+            if let Some(survival_waves_loaded) = survival_waves_loaded {
+                debug!("Survival waves: {:?}", survival_waves_loaded);
+
+                let mut survival_waves_loaded = survival_waves_loaded.clone();
+
+                // Empty waves would be survived immediately, and repeatedly if it is the last.
+                survival_waves_loaded
+                    .waves
+                    .retain(|wave| !wave.enemies.is_empty());
+
+                *survival_waves = survival_waves_loaded;
+                *survival_loading_status = SurvivalLoadingStatus::Complete;
+            } else if self.progress_counter.num_failed() > 0 {
+                self.progress_counter
+                    .errors()
+                    .iter()
+                    .for_each(|error_meta| {
+                        error!(
+                            "Failed to load `{}`, survival mode will not spawn enemies: {}",
+                            error_meta.asset_name, error_meta.error
+                        );
+                    });
+                *survival_loading_status = SurvivalLoadingStatus::Complete;
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! User defined configuration types for survival mode.
+
+pub use self::{
+    ai_profile::AiProfile, survival_waves::SurvivalWaves, wave::Wave, wave_enemy::WaveEnemy,
+};
+
+mod ai_profile;
+mod survival_waves;
+mod wave;
+mod wave_enemy;
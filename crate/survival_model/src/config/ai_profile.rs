@@ -0,0 +1,35 @@
+use asset_schema::AssetSchema;
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// How an AI controlled character behaves.
+///
+/// Behaviour is expressed as control input, so what each profile looks like is determined by the
+/// character's input reactions.
+#[derive(
+    AssetSchema,
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AiProfile {
+    /// Character does not send any input.
+    Idle,
+    /// Character walks to the nearest player, and attacks when in range.
+    #[derivative(Default)]
+    Chase,
+    /// Character walks to the nearest player, and alternates attacks and special attacks.
+    Aggressive,
+}
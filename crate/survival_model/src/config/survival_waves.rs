@@ -0,0 +1,17 @@
+use asset_derive::Asset;
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Wave;
+
+/// Waves of enemies to fight in survival mode.
+///
+/// When the last wave is survived, it is repeated until all players are down.
+#[derive(Asset, AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct SurvivalWaves {
+    /// Waves in the order they are played.
+    #[serde(default)]
+    pub waves: Vec<Wave>,
+}
@@ -0,0 +1,16 @@
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::WaveEnemy;
+
+/// Enemies to spawn in a survival wave.
+///
+/// A wave is survived when all of its enemies have been spawned and are down.
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct Wave {
+    /// Groups of enemies to spawn.
+    #[serde(default)]
+    pub enemies: Vec<WaveEnemy>,
+}
@@ -0,0 +1,39 @@
+use asset_model::config::AssetSlug;
+use asset_schema::AssetSchema;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::AiProfile;
+
+/// A group of enemies to spawn in a wave.
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct WaveEnemy {
+    /// Slug of the character to spawn.
+    #[serde(
+        serialize_with = "AssetSlug::serialize_str",
+        deserialize_with = "AssetSlug::deserialize_str"
+    )]
+    pub object: AssetSlug,
+    /// Number of enemies to spawn.
+    #[serde(default = "WaveEnemy::count_default")]
+    pub count: u32,
+    /// How the enemies behave.
+    #[serde(default)]
+    pub ai: AiProfile,
+    /// Index of the map's enemy team spawn point to spawn at.
+    ///
+    /// If this is not set, or the map does not have the spawn point, enemies are spread along the
+    /// right side of the map.
+    #[serde(default)]
+    pub spawn_point: Option<usize>,
+    /// Number of ticks after the wave begins to spawn the enemies.
+    #[serde(default)]
+    pub delay: usize,
+}
+
+impl WaveEnemy {
+    fn count_default() -> u32 {
+        1
+    }
+}
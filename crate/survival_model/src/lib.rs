@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used in survival mode.
+
+pub use crate::survival_loading_status::SurvivalLoadingStatus;
+
+pub mod config;
+pub mod play;
+
+mod survival_loading_status;
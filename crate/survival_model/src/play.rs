@@ -0,0 +1,13 @@
+//! Data types used at runtime.
+
+pub use self::{
+    ai_controlled::AiControlled, survival_hud_entity::SurvivalHudEntity,
+    survival_progress::SurvivalProgress, survival_status::SurvivalStatus,
+    survival_team::SurvivalTeam,
+};
+
+mod ai_controlled;
+mod survival_hud_entity;
+mod survival_progress;
+mod survival_status;
+mod survival_team;
@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+use crate::config::AiProfile;
+
+/// Marks an entity as controlled by AI.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Eq, new)]
+#[storage(VecStorage)]
+pub struct AiControlled {
+    /// How the entity behaves.
+    pub ai_profile: AiProfile,
+}
+
+impl AiControlled {
+    /// Controller ID that control input events for AI controlled entities are sent with.
+    pub const CONTROLLER_ID: ControllerId = std::usize::MAX;
+}
@@ -0,0 +1,18 @@
+use amethyst::ecs::Entity;
+
+/// Progress of the survival run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SurvivalProgress {
+    /// Number of waves survived, which is the score of the run.
+    ///
+    /// This is also the index of the wave in play, limited to the last wave.
+    pub waves_survived: u32,
+    /// Number of ticks since the current wave began.
+    pub wave_tick: usize,
+    /// Whether each group of enemies in the current wave has been spawned.
+    pub groups_spawned: Vec<bool>,
+    /// Enemies spawned in the current wave.
+    pub enemies: Vec<Entity>,
+    /// Entity that enemies are spawned from, which gives them their team.
+    pub enemy_parent: Option<Entity>,
+}
@@ -0,0 +1,12 @@
+use derivative::Derivative;
+
+/// Whether game play is a survival run.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum SurvivalStatus {
+    /// Game play is a normal match.
+    #[derivative(Default)]
+    Inactive,
+    /// Game play is a survival run.
+    Active,
+}
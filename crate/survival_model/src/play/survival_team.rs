@@ -0,0 +1,12 @@
+use team_model::play::TeamCounter;
+
+/// Teams that characters are placed on in survival mode.
+#[derive(Debug)]
+pub struct SurvivalTeam;
+
+impl SurvivalTeam {
+    /// Team that players are on.
+    pub const PLAYERS: TeamCounter = TeamCounter(0);
+    /// Team that enemies are on.
+    pub const ENEMIES: TeamCounter = TeamCounter(1);
+}
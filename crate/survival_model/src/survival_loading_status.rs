@@ -0,0 +1,14 @@
+use derivative::Derivative;
+
+/// Status of survival waves loading.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum SurvivalLoadingStatus {
+    /// Survival waves loading has not started.
+    #[derivative(Default)]
+    NotStarted,
+    /// Survival waves loading is in progress.
+    InProgress,
+    /// Survival waves loading is complete.
+    Complete,
+}
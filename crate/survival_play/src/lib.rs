@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for survival mode.
+
+pub use crate::system::{EnemyAiInputSystem, SurvivalHudDisplaySystem, SurvivalWaveSpawnSystem};
+
+mod system;
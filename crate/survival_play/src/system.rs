@@ -0,0 +1,9 @@
+pub use self::{
+    enemy_ai_input_system::EnemyAiInputSystem,
+    survival_hud_display_system::SurvivalHudDisplaySystem,
+    survival_wave_spawn_system::SurvivalWaveSpawnSystem,
+};
+
+mod enemy_ai_input_system;
+mod survival_hud_display_system;
+mod survival_wave_spawn_system;
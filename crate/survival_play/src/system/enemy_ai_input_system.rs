@@ -0,0 +1,181 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::{ControlInputEvent, ControllerInput};
+use kinematic_model::config::{FixedPoint, Position};
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use survival_model::{
+    config::AiProfile,
+    play::{AiControlled, SurvivalStatus, SurvivalTeam},
+};
+use team_model::play::Team;
+
+/// Horizontal distance within which enemies attack.
+const ATTACK_RANGE_X: FixedPoint = FixedPoint::from_int(60);
+/// Depth distance within which enemies attack.
+const ATTACK_RANGE_Z: FixedPoint = FixedPoint::from_int(10);
+
+/// Sends `ControlInputEvent`s for AI controlled enemies during a survival run.
+///
+/// Enemies are controlled through events rather than setting their `ControllerInput` directly, so
+/// that both `press_*` and `hold_*` input reactions are triggered.
+#[derive(Debug, Default, new)]
+pub struct EnemyAiInputSystem;
+
+/// `EnemyAiInputSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct EnemyAiInputSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+    /// `SurvivalStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_status: Read<'s, SurvivalStatus>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
+    /// `ControllerInput` components.
+    #[derivative(Debug = "ignore")]
+    pub controller_inputs: ReadStorage<'s, ControllerInput>,
+    /// `Position<FixedPoint>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+}
+
+impl EnemyAiInputSystem {
+    /// Returns the input the enemy should have for the given profile.
+    ///
+    /// # Parameters
+    ///
+    /// * `ai_profile`: How the enemy behaves.
+    /// * `current`: The enemy's current input.
+    /// * `position`: The enemy's position.
+    /// * `mirrored`: Whether the enemy is facing left.
+    /// * `target`: Position of the nearest player, if any.
+    fn profile_input(
+        ai_profile: AiProfile,
+        current: ControllerInput,
+        position: Position<FixedPoint>,
+        mirrored: Mirrored,
+        target: Option<Position<FixedPoint>>,
+    ) -> ControllerInput {
+        let mut controller_input = ControllerInput::default();
+        let target = match (ai_profile, target) {
+            (AiProfile::Idle, _) | (_, None) => return controller_input,
+            (_, Some(target)) => target,
+        };
+
+        let dx = target.x - position.x;
+        let dz = target.z - position.z;
+        let in_range_x = dx.abs() <= ATTACK_RANGE_X;
+        let in_range_z = dz.abs() <= ATTACK_RANGE_Z;
+
+        if !in_range_x {
+            controller_input.x_axis_value = dx.signum().to_f32();
+        }
+        if !in_range_z {
+            controller_input.z_axis_value = dz.signum().to_f32();
+        }
+
+        if in_range_x && in_range_z {
+            let facing_target = mirrored.0 == (dx < FixedPoint::ZERO);
+            if !facing_target {
+                // Tap towards the target to turn around.
+                controller_input.x_axis_value = dx.signum().to_f32();
+            } else {
+                // Buttons are released between presses so that `press_*` reactions trigger.
+                match ai_profile {
+                    AiProfile::Idle => {}
+                    AiProfile::Chase => controller_input.attack = !current.attack,
+                    AiProfile::Aggressive => {
+                        controller_input.attack = !current.attack && !current.special;
+                        controller_input.special = current.attack;
+                    }
+                }
+            }
+        }
+
+        controller_input
+    }
+}
+
+impl<'s> System<'s> for EnemyAiInputSystem {
+    type SystemData = EnemyAiInputSystemData<'s>;
+
+    fn run(
+        &mut self,
+        EnemyAiInputSystemData {
+            entities,
+            mut control_input_ec,
+            survival_status,
+            ai_controlleds,
+            controller_inputs,
+            positions,
+            mirroreds,
+            teams,
+            health_pointses,
+        }: Self::SystemData,
+    ) {
+        if *survival_status != SurvivalStatus::Active {
+            return;
+        }
+
+        let player_team = Team::Number(SurvivalTeam::PLAYERS);
+        let player_positions = (&teams, &health_pointses, &positions)
+            .join()
+            .filter(|(team, health_points, _)| **team == player_team && **health_points > 0)
+            .map(|(_, _, position)| *position)
+            .collect::<Vec<Position<FixedPoint>>>();
+
+        let mut events = (
+            &entities,
+            &ai_controlleds,
+            &controller_inputs,
+            &health_pointses,
+            &positions,
+        )
+            .join()
+            .filter(|(_, _, _, health_points, _)| **health_points > 0)
+            .flat_map(|(entity, ai_controlled, controller_input, _, position)| {
+                let position = *position;
+                let target = player_positions
+                    .iter()
+                    .copied()
+                    .min_by_key(|player_position| {
+                        let delta = *player_position - position;
+                        delta.x * delta.x + delta.y * delta.y + delta.z * delta.z
+                    });
+                let mirrored = mirroreds.get(entity).copied().unwrap_or_default();
+                let desired = Self::profile_input(
+                    ai_controlled.ai_profile,
+                    *controller_input,
+                    position,
+                    mirrored,
+                    target,
+                );
+
+                controller_input.change_events(desired, entity, AiControlled::CONTROLLER_ID)
+            })
+            .collect::<Vec<ControlInputEvent>>();
+
+        control_input_ec.drain_vec_write(&mut events);
+    }
+}
@@ -0,0 +1,129 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::{GamePlayEntity, GamePlayStatus};
+use survival_model::play::{SurvivalHudEntity, SurvivalProgress, SurvivalStatus};
+
+const FONT_COLOUR_HUD: [f32; 4] = [0.9, 0.9, 0.7, 1.];
+const FONT_SIZE_HUD: f32 = 20.;
+const LABEL_WIDTH: f32 = 300.;
+const LABEL_HEIGHT: f32 = 30.;
+const LABEL_MARGIN: f32 = 10.;
+
+/// Displays the current wave during a survival run.
+#[derive(Debug, Default, new)]
+pub struct SurvivalHudDisplaySystem;
+
+/// `SurvivalHudDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SurvivalHudDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SurvivalStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_status: Read<'s, SurvivalStatus>,
+    /// `SurvivalProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_progress: Read<'s, SurvivalProgress>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `SurvivalHudEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub survival_hud_entities: WriteStorage<'s, SurvivalHudEntity>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: WriteStorage<'s, GamePlayEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl SurvivalHudDisplaySystem {
+    fn hud_text(survival_progress: &SurvivalProgress) -> String {
+        format!(
+            "wave: {}  survived: {}",
+            survival_progress.waves_survived + 1,
+            survival_progress.waves_survived
+        )
+    }
+}
+
+impl<'s> System<'s> for SurvivalHudDisplaySystem {
+    type SystemData = SurvivalHudDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SurvivalHudDisplaySystemData {
+            entities,
+            survival_status,
+            survival_progress,
+            game_play_status,
+            mut survival_hud_entities,
+            mut game_play_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        if *survival_status != SurvivalStatus::Active || *game_play_status == GamePlayStatus::None {
+            return;
+        }
+
+        let hud_text = Self::hud_text(&survival_progress);
+
+        let mut hud_exists = false;
+        (&survival_hud_entities, &mut ui_texts)
+            .join()
+            .for_each(|(_, ui_text)| {
+                hud_exists = true;
+                if ui_text.text != hud_text {
+                    ui_text.text = hud_text.clone();
+                }
+            });
+
+        if !hud_exists {
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+
+            let ui_transform = UiTransform::new(
+                String::from("survival_hud_text"),
+                Anchor::TopLeft,
+                Anchor::TopLeft,
+                LABEL_MARGIN,
+                -LABEL_MARGIN,
+                1.,
+                LABEL_WIDTH,
+                LABEL_HEIGHT,
+            );
+
+            let mut ui_text = UiText::new(font.clone(), hud_text, FONT_COLOUR_HUD, FONT_SIZE_HUD);
+            ui_text.align = Anchor::TopLeft;
+
+            entities
+                .build_entity()
+                .with(GamePlayEntity, &mut game_play_entities)
+                .with(SurvivalHudEntity, &mut survival_hud_entities)
+                .with(ui_transform, &mut ui_transforms)
+                .with(ui_text, &mut ui_texts)
+                .build();
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use amethyst::{
+    ecs::{Entity, Read, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use character_model::config::CharacterSequenceName;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::{GamePlayEntity, GamePlayStatus};
+use kinematic_model::config::{FixedPoint, Position, Velocity};
+use log::error;
+use map_model::{
+    config::{MapBounds, SpawnPoints},
+    loaded::{AssetMapBounds, AssetSpawnPoints},
+};
+use map_selection_model::MapSelection;
+use sequence_model::{config::SequenceNameString, loaded::SequenceId};
+use spawn_model::loaded::Spawn;
+use spawn_play::{GameObjectSpawner, SpawnGameObjectResources};
+use survival_model::{
+    config::{SurvivalWaves, WaveEnemy},
+    play::{AiControlled, SurvivalProgress, SurvivalStatus, SurvivalTeam},
+};
+use team_model::play::Team;
+
+/// Proportion of the map width to spawn enemies at, when there is no spawn point.
+const ENEMY_SPAWN_X_RATIO: FixedPoint = FixedPoint::from_ratio(9, 10);
+
+/// Spawns waves of enemies during a survival run, and tracks the waves survived.
+///
+/// Enemies are spawned from a parent entity on the enemy team, so `SpawnGameObjectRectifySystem`
+/// places them on that team.
+#[derive(Debug, Default, new)]
+pub struct SurvivalWaveSpawnSystem;
+
+/// `SurvivalWaveSpawnSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SurvivalWaveSpawnSystemData<'s> {
+    /// `SpawnGameObjectResources`.
+    pub spawn_game_object_resources: SpawnGameObjectResources<'s>,
+    /// `SurvivalStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_status: Read<'s, SurvivalStatus>,
+    /// `SurvivalWaves` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_waves: Read<'s, SurvivalWaves>,
+    /// `SurvivalProgress` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_progress: Write<'s, SurvivalProgress>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetMapBounds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_bounds: Read<'s, AssetMapBounds>,
+    /// `AssetSpawnPoints` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_spawn_points: Read<'s, AssetSpawnPoints>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: WriteStorage<'s, Team>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: WriteStorage<'s, AiControlled>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: WriteStorage<'s, GamePlayEntity>,
+}
+
+impl SurvivalWaveSpawnSystem {
+    /// Returns the position to spawn the `index`th enemy of a wave group.
+    fn enemy_position(
+        spawn_points: Option<&SpawnPoints>,
+        map_bounds: Option<MapBounds>,
+        wave_enemy: &WaveEnemy,
+        index: usize,
+    ) -> Position<FixedPoint> {
+        let spawn_point = spawn_points.and_then(|spawn_points| {
+            wave_enemy
+                .spawn_point
+                .and_then(|spawn_point| spawn_points.team(SurvivalTeam::ENEMIES, spawn_point))
+        });
+
+        if let Some(spawn_point) = spawn_point {
+            Position::<FixedPoint>::from(spawn_point.position)
+        } else if let Some(map_bounds) = map_bounds {
+            let (width, height, depth) = (
+                FixedPoint::from(map_bounds.width),
+                FixedPoint::from(map_bounds.height),
+                FixedPoint::from(map_bounds.depth),
+            );
+            let count = wave_enemy.count;
+            let z = depth * FixedPoint::from(index as u32 + 1) / FixedPoint::from(count + 1);
+
+            Position::<FixedPoint>::new(
+                FixedPoint::from(map_bounds.x) + width * ENEMY_SPAWN_X_RATIO,
+                FixedPoint::from(map_bounds.y) + height / FixedPoint::from(2),
+                FixedPoint::from(map_bounds.z) + z,
+            )
+        } else {
+            Position::<FixedPoint>::default()
+        }
+    }
+
+    /// Returns the entity that enemies are spawned from, creating it if necessary.
+    fn enemy_parent(
+        spawn_game_object_resources: &SpawnGameObjectResources<'_>,
+        survival_progress: &mut SurvivalProgress,
+        teams: &mut WriteStorage<'_, Team>,
+        game_play_entities: &mut WriteStorage<'_, GamePlayEntity>,
+    ) -> Entity {
+        let entities = &spawn_game_object_resources.entities;
+        match survival_progress.enemy_parent {
+            Some(enemy_parent) if entities.is_alive(enemy_parent) => enemy_parent,
+            _ => {
+                let enemy_parent = entities.create();
+                teams
+                    .insert(enemy_parent, Team::Number(SurvivalTeam::ENEMIES))
+                    .expect("Failed to insert `Team` component.");
+                game_play_entities
+                    .insert(enemy_parent, GamePlayEntity)
+                    .expect("Failed to insert `GamePlayEntity` component.");
+                survival_progress.enemy_parent = Some(enemy_parent);
+
+                enemy_parent
+            }
+        }
+    }
+
+    /// Returns whether any enemy in the current wave is still in play.
+    fn enemies_alive(
+        spawn_game_object_resources: &SpawnGameObjectResources<'_>,
+        survival_progress: &SurvivalProgress,
+    ) -> bool {
+        let entities = &spawn_game_object_resources.entities;
+        let health_pointses = &spawn_game_object_resources
+            .character_component_storages
+            .health_pointses;
+        survival_progress.enemies.iter().any(|enemy| {
+            entities.is_alive(*enemy)
+                && health_pointses
+                    .get(*enemy)
+                    .map(|health_points| *health_points > 0)
+                    .unwrap_or(false)
+        })
+    }
+}
+
+impl<'s> System<'s> for SurvivalWaveSpawnSystem {
+    type SystemData = SurvivalWaveSpawnSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SurvivalWaveSpawnSystemData {
+            mut spawn_game_object_resources,
+            survival_status,
+            survival_waves,
+            mut survival_progress,
+            game_play_status,
+            map_selection,
+            asset_map_bounds,
+            asset_spawn_points,
+            mut teams,
+            mut ai_controlleds,
+            mut game_play_entities,
+        }: Self::SystemData,
+    ) {
+        if *survival_status != SurvivalStatus::Active
+            || *game_play_status != GamePlayStatus::Playing
+            || survival_waves.waves.is_empty()
+        {
+            return;
+        }
+
+        let survival_progress = &mut *survival_progress;
+
+        // The last wave is repeated once all waves are survived.
+        let wave_index =
+            (survival_progress.waves_survived as usize).min(survival_waves.waves.len() - 1);
+        let wave = &survival_waves.waves[wave_index];
+
+        let map_asset_id = map_selection.asset_id();
+        let map_bounds =
+            map_asset_id.and_then(|map_asset_id| asset_map_bounds.get(map_asset_id).copied());
+        let spawn_points =
+            map_asset_id.and_then(|map_asset_id| asset_spawn_points.get(map_asset_id));
+
+        survival_progress
+            .groups_spawned
+            .resize(wave.enemies.len(), false);
+        let wave_tick = survival_progress.wave_tick;
+        let groups_to_spawn = wave
+            .enemies
+            .iter()
+            .enumerate()
+            .filter(|(group_index, wave_enemy)| {
+                !survival_progress.groups_spawned[*group_index] && wave_tick >= wave_enemy.delay
+            })
+            .collect::<Vec<_>>();
+
+        groups_to_spawn
+            .into_iter()
+            .for_each(|(group_index, wave_enemy)| {
+                survival_progress.groups_spawned[group_index] = true;
+
+                let asset_id = spawn_game_object_resources
+                    .asset_id_mappings
+                    .id(&wave_enemy.object)
+                    .copied();
+                let asset_id = if let Some(asset_id) = asset_id {
+                    asset_id
+                } else {
+                    error!(
+                        "Survival wave enemy `{}` does not exist, skipping.",
+                        wave_enemy.object
+                    );
+                    return;
+                };
+
+                // Enemies start in their `Stand` sequence, as players do.
+                let sequence_id = spawn_game_object_resources
+                    .character_spawning_resources
+                    .asset_sequence_id_mappings_character
+                    .get(asset_id)
+                    .and_then(|sequence_id_mappings| {
+                        sequence_id_mappings
+                            .id(&SequenceNameString::Name(CharacterSequenceName::Stand))
+                            .copied()
+                    })
+                    .unwrap_or(SequenceId(0));
+
+                let enemy_parent = Self::enemy_parent(
+                    &spawn_game_object_resources,
+                    survival_progress,
+                    &mut teams,
+                    &mut game_play_entities,
+                );
+
+                (0..wave_enemy.count as usize).for_each(|index| {
+                    let position =
+                        Self::enemy_position(spawn_points, map_bounds, wave_enemy, index);
+                    let spawn = Spawn::new(
+                        asset_id,
+                        position,
+                        Velocity::<FixedPoint>::default(),
+                        sequence_id,
+                    );
+                    let enemy = GameObjectSpawner::spawn(
+                        &mut spawn_game_object_resources,
+                        enemy_parent,
+                        &spawn,
+                    );
+
+                    ai_controlleds
+                        .insert(enemy, AiControlled::new(wave_enemy.ai))
+                        .expect("Failed to insert `AiControlled` component.");
+                    survival_progress.enemies.push(enemy);
+                });
+            });
+
+        survival_progress.wave_tick += 1;
+
+        let all_spawned = survival_progress
+            .groups_spawned
+            .iter()
+            .all(|spawned| *spawned);
+        if all_spawned && !Self::enemies_alive(&spawn_game_object_resources, survival_progress) {
+            survival_progress.waves_survived += 1;
+            survival_progress.wave_tick = 0;
+            survival_progress.groups_spawned.clear();
+            survival_progress.enemies.clear();
+        }
+    }
+}
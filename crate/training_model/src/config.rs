@@ -0,0 +1,5 @@
+//! User defined configuration types for training mode.
+
+pub use self::dummy_behaviour::DummyBehaviour;
+
+mod dummy_behaviour;
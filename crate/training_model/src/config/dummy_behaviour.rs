@@ -0,0 +1,46 @@
+use asset_schema::AssetSchema;
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// How the training dummy behaves.
+///
+/// The dummy's behaviour is expressed as control input, so what each behaviour looks like is
+/// determined by the dummy character's input reactions.
+///
+/// There is no crouch behaviour, as characters have neither a crouch sequence nor a control input
+/// to crouch with.
+#[derive(
+    AssetSchema,
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyBehaviour {
+    /// Dummy does not send any input.
+    #[derivative(Default)]
+    Stand,
+    /// Dummy holds the `Defend` button.
+    Defend,
+    /// Dummy jumps whenever it is on the ground.
+    Jump,
+    /// Dummy holds the `Defend` button, and presses it again whenever it is hit.
+    ///
+    /// `Defend` is released and pressed within the same tick, so it is never seen as released by
+    /// `hold_defend` input reactions, while `press_defend` input reactions can guard out of hit
+    /// stun.
+    BlockAll,
+    /// Dummy replays the player's last recorded input.
+    RecordReplay,
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used in training mode.
+
+pub use crate::{training_event::TrainingEvent, training_event_args::TrainingEventArgs};
+
+pub mod config;
+pub mod play;
+
+mod training_event;
+mod training_event_args;
@@ -0,0 +1,17 @@
+//! Data types used at runtime.
+
+pub use self::{
+    frame_data::FrameData, hit_advantage_tracker::HitAdvantageTracker,
+    input_recording::InputRecording, move_frames::MoveFrames, training_entities::TrainingEntities,
+    training_hud_entity::TrainingHudEntity, training_settings::TrainingSettings,
+    training_status::TrainingStatus,
+};
+
+mod frame_data;
+mod hit_advantage_tracker;
+mod input_recording;
+mod move_frames;
+mod training_entities;
+mod training_hud_entity;
+mod training_settings;
+mod training_status;
@@ -0,0 +1,23 @@
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+use crate::play::{HitAdvantageTracker, MoveFrames};
+
+/// Frame data of the training player's character, measured in ticks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, new)]
+pub struct FrameData {
+    /// Current `SequenceId` of the player.
+    pub sequence_id: Option<SequenceId>,
+    /// Current frame index within the sequence.
+    pub frame_index: usize,
+    /// Frame counts of the current sequence.
+    pub move_frames: MoveFrames,
+    /// Frame counts of the most recent sequence that had active frames.
+    pub last_move_frames: Option<MoveFrames>,
+    /// Number of ticks the player can act before the dummy after the last hit.
+    ///
+    /// Negative values mean the dummy recovers first.
+    pub hit_advantage: Option<isize>,
+    /// Tracks recovery of the player and dummy after a hit.
+    pub hit_advantage_tracker: Option<HitAdvantageTracker>,
+}
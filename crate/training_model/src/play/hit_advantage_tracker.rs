@@ -0,0 +1,24 @@
+use derive_new::new;
+
+/// Tracks when the player and dummy are able to act after a hit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct HitAdvantageTracker {
+    /// Ticks elapsed since the hit.
+    pub elapsed: usize,
+    /// Tick at which the player was able to act.
+    pub player_actionable: Option<usize>,
+    /// Tick at which the dummy was able to act.
+    pub dummy_actionable: Option<usize>,
+}
+
+impl HitAdvantageTracker {
+    /// Returns the hit advantage once both the player and dummy are able to act.
+    pub fn hit_advantage(self) -> Option<isize> {
+        match (self.player_actionable, self.dummy_actionable) {
+            (Some(player_actionable), Some(dummy_actionable)) => {
+                Some(dummy_actionable as isize - player_actionable as isize)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use game_input_model::play::ControlInputEvent;
+
+/// Player input recorded for the training dummy to replay.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputRecording {
+    /// Whether the player's input is being recorded.
+    pub recording: bool,
+    /// Number of ticks the recording spans.
+    pub length: usize,
+    /// Recorded events, with the tick they were sent relative to the start of the recording.
+    pub events: Vec<(usize, ControlInputEvent)>,
+    /// Tick of the recording that is being replayed.
+    pub replay_tick: usize,
+}
+
+impl InputRecording {
+    /// Clears the recording and begins recording.
+    pub fn start(&mut self) {
+        self.recording = true;
+        self.length = 0;
+        self.events.clear();
+        self.replay_tick = 0;
+    }
+
+    /// Stops recording.
+    pub fn stop(&mut self) {
+        self.recording = false;
+        self.replay_tick = 0;
+    }
+}
@@ -0,0 +1,12 @@
+use derive_new::new;
+
+/// Number of startup, active, and recovery ticks of a sequence.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct MoveFrames {
+    /// Ticks before the first frame with a hit interaction.
+    pub startup: usize,
+    /// Ticks with a hit interaction.
+    pub active: usize,
+    /// Ticks after the first active frame without a hit interaction.
+    pub recovery: usize,
+}
@@ -0,0 +1,17 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+/// Characters in a training session.
+#[derive(Clone, Copy, Debug, Default, PartialEq, new)]
+pub struct TrainingEntities {
+    /// Character controlled by the player.
+    pub player: Option<Entity>,
+    /// Character controlled by the training dummy.
+    ///
+    /// This is `None` when only one character is selected, as the dummy is the second selected
+    /// character.
+    pub dummy: Option<Entity>,
+    /// ID of the controller that the dummy character was selected with.
+    pub dummy_controller_id: Option<ControllerId>,
+}
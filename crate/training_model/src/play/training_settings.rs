@@ -0,0 +1,16 @@
+use derive_new::new;
+
+use crate::config::DummyBehaviour;
+
+/// Settings for a training session.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct TrainingSettings {
+    /// Whether health points are refilled.
+    pub infinite_hp: bool,
+    /// Whether skill points are refilled.
+    pub infinite_sp: bool,
+    /// Whether charge is refilled.
+    pub infinite_charge: bool,
+    /// How the training dummy behaves.
+    pub dummy_behaviour: DummyBehaviour,
+}
@@ -0,0 +1,12 @@
+use derivative::Derivative;
+
+/// Whether game play is a training session.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum TrainingStatus {
+    /// Game play is a normal match.
+    #[derivative(Default)]
+    Inactive,
+    /// Game play is a training session.
+    Active,
+}
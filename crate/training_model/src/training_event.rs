@@ -0,0 +1,18 @@
+use crate::config::DummyBehaviour;
+
+/// Event signalling a change in training settings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrainingEvent {
+    /// Toggles whether health points are refilled.
+    ToggleInfiniteHp,
+    /// Toggles whether skill points are refilled.
+    ToggleInfiniteSp,
+    /// Toggles whether charge is refilled.
+    ToggleInfiniteCharge,
+    /// Sets the training dummy's behaviour.
+    DummyBehaviour(DummyBehaviour),
+    /// Begins recording the player's input.
+    RecordStart,
+    /// Stops recording the player's input.
+    RecordStop,
+}
@@ -0,0 +1,37 @@
+use asset_schema::AssetSchema;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::config::DummyBehaviour;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `training infinite_hp`
+/// * `training infinite_sp`
+/// * `training infinite_charge`
+/// * `training dummy_behaviour --behaviour block_all`
+/// * `training record_start`
+/// * `training record_stop`
+#[derive(AssetSchema, Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum TrainingEventArgs {
+    /// Toggles whether health points are refilled.
+    InfiniteHp,
+    /// Toggles whether skill points are refilled.
+    InfiniteSp,
+    /// Toggles whether charge is refilled.
+    InfiniteCharge,
+    /// Sets the training dummy's behaviour.
+    DummyBehaviour {
+        /// Behaviour of the dummy.
+        #[structopt(short, long)]
+        behaviour: DummyBehaviour,
+    },
+    /// Begins recording the player's input.
+    RecordStart,
+    /// Stops recording the player's input.
+    RecordStop,
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for training mode.
+
+pub use crate::system::{
+    FrameDataUpdateSystem, TrainingDummyInputSystem, TrainingEntitiesUpdateSystem,
+    TrainingEventSystem, TrainingHudDisplaySystem, TrainingKeyInputSystem, TrainingRefillSystem,
+    DUMMY_BEHAVIOUR_NEXT_KEY, RECORD_TOGGLE_KEY,
+};
+
+mod system;
@@ -0,0 +1,19 @@
+pub use self::{
+    frame_data_update_system::FrameDataUpdateSystem,
+    training_dummy_input_system::TrainingDummyInputSystem,
+    training_entities_update_system::TrainingEntitiesUpdateSystem,
+    training_event_system::TrainingEventSystem,
+    training_hud_display_system::TrainingHudDisplaySystem,
+    training_key_input_system::{
+        TrainingKeyInputSystem, DUMMY_BEHAVIOUR_NEXT_KEY, RECORD_TOGGLE_KEY,
+    },
+    training_refill_system::TrainingRefillSystem,
+};
+
+mod frame_data_update_system;
+mod training_dummy_input_system;
+mod training_entities_update_system;
+mod training_event_system;
+mod training_hud_display_system;
+mod training_key_input_system;
+mod training_refill_system;
@@ -0,0 +1,216 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Entity, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetId;
+use character_model::config::CharacterSequenceName;
+use collision_model::{
+    config::{InteractionKind, Interactions},
+    play::HitEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::{
+    loaded::{AssetSequenceIdMappings, SequenceId},
+    play::FrameIndexClock,
+};
+use training_model::play::{
+    FrameData, HitAdvantageTracker, MoveFrames, TrainingEntities, TrainingStatus,
+};
+
+/// Measures frame data of the training player's character.
+///
+/// A tick is active when the character's current frame has a `Hit` interaction. Characters are
+/// able to act when they are in the `Stand` or `Walk` sequence.
+#[derive(Debug, Default, new)]
+pub struct FrameDataUpdateSystem {
+    /// Reader ID for the `HitEvent` event channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `FrameDataUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct FrameDataUpdateSystemData<'s> {
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `TrainingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_status: Read<'s, TrainingStatus>,
+    /// `TrainingEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_entities: Read<'s, TrainingEntities>,
+    /// `AssetSequenceIdMappings<CharacterSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_character:
+        Read<'s, AssetSequenceIdMappings<CharacterSequenceName>>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: ReadStorage<'s, FrameIndexClock>,
+    /// `Handle<Interactions>` components.
+    #[derivative(Debug = "ignore")]
+    pub interactions_handles: ReadStorage<'s, Handle<Interactions>>,
+    /// `Interactions` assets.
+    #[derivative(Debug = "ignore")]
+    pub interactions_assets: Read<'s, AssetStorage<Interactions>>,
+    /// `FrameData` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_data: Write<'s, FrameData>,
+}
+
+impl FrameDataUpdateSystem {
+    /// Returns whether the entity's current frame has a `Hit` interaction.
+    fn is_active(
+        interactions_handles: &ReadStorage<'_, Handle<Interactions>>,
+        interactions_assets: &AssetStorage<Interactions>,
+        entity: Entity,
+    ) -> bool {
+        interactions_handles
+            .get(entity)
+            .and_then(|interactions_handle| interactions_assets.get(interactions_handle))
+            .map(|interactions| {
+                interactions.iter().any(|interaction| {
+                    if let InteractionKind::Hit(_) = interaction.kind {
+                        true
+                    } else {
+                        false
+                    }
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the entity is in a sequence that it is able to act from.
+    fn is_actionable(
+        asset_sequence_id_mappings_character: &AssetSequenceIdMappings<CharacterSequenceName>,
+        asset_ids: &ReadStorage<'_, AssetId>,
+        sequence_ids: &ReadStorage<'_, SequenceId>,
+        entity: Entity,
+    ) -> bool {
+        let sequence_id_mappings = asset_ids
+            .get(entity)
+            .and_then(|asset_id| asset_sequence_id_mappings_character.get(*asset_id));
+        let sequence_id = sequence_ids.get(entity);
+
+        if let (Some(sequence_id_mappings), Some(sequence_id)) = (sequence_id_mappings, sequence_id)
+        {
+            [CharacterSequenceName::Stand, CharacterSequenceName::Walk]
+                .iter()
+                .filter_map(|sequence_name| sequence_id_mappings.id_by_name(*sequence_name))
+                .any(|sequence_id_actionable| sequence_id_actionable == sequence_id)
+        } else {
+            false
+        }
+    }
+}
+
+impl<'s> System<'s> for FrameDataUpdateSystem {
+    type SystemData = FrameDataUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        FrameDataUpdateSystemData {
+            hit_ec,
+            training_status,
+            training_entities,
+            asset_sequence_id_mappings_character,
+            asset_ids,
+            sequence_ids,
+            frame_index_clocks,
+            interactions_handles,
+            interactions_assets,
+            mut frame_data,
+        }: Self::SystemData,
+    ) {
+        let hit_event_rid = self
+            .hit_event_rid
+            .as_mut()
+            .expect("Expected `hit_event_rid` field to be set.");
+
+        let TrainingEntities { player, dummy, .. } = *training_entities;
+        let player_hit_dummy = hit_ec
+            .read(hit_event_rid)
+            .any(|hit_event| Some(hit_event.from) == player && Some(hit_event.to) == dummy);
+
+        if *training_status != TrainingStatus::Active {
+            return;
+        }
+        let player = if let Some(player) = player {
+            player
+        } else {
+            return;
+        };
+
+        let frame_data = &mut *frame_data;
+
+        let sequence_id = sequence_ids.get(player).copied();
+        if sequence_id != frame_data.sequence_id {
+            if frame_data.move_frames.active > 0 {
+                frame_data.last_move_frames = Some(frame_data.move_frames);
+            }
+            frame_data.sequence_id = sequence_id;
+            frame_data.move_frames = MoveFrames::default();
+        }
+        frame_data.frame_index = frame_index_clocks
+            .get(player)
+            .map(|frame_index_clock| frame_index_clock.value)
+            .unwrap_or(0);
+
+        let move_frames = &mut frame_data.move_frames;
+        if Self::is_active(&interactions_handles, &interactions_assets, player) {
+            move_frames.active += 1;
+        } else if move_frames.active == 0 {
+            move_frames.startup += 1;
+        } else {
+            move_frames.recovery += 1;
+        }
+
+        if player_hit_dummy {
+            frame_data.hit_advantage_tracker = Some(HitAdvantageTracker::default());
+        } else if let (Some(hit_advantage_tracker), Some(dummy)) =
+            (frame_data.hit_advantage_tracker.as_mut(), dummy)
+        {
+            hit_advantage_tracker.elapsed += 1;
+            let elapsed = hit_advantage_tracker.elapsed;
+
+            let is_actionable = |entity| {
+                Self::is_actionable(
+                    &asset_sequence_id_mappings_character,
+                    &asset_ids,
+                    &sequence_ids,
+                    entity,
+                )
+            };
+            if hit_advantage_tracker.player_actionable.is_none() && is_actionable(player) {
+                hit_advantage_tracker.player_actionable = Some(elapsed);
+            }
+            if hit_advantage_tracker.dummy_actionable.is_none() && is_actionable(dummy) {
+                hit_advantage_tracker.dummy_actionable = Some(elapsed);
+            }
+
+            if let Some(hit_advantage) = hit_advantage_tracker.hit_advantage() {
+                frame_data.hit_advantage = Some(hit_advantage);
+                frame_data.hit_advantage_tracker = None;
+            }
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use amethyst::{
+    ecs::{Entity, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::play::HitEvent;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+};
+use object_model::play::Grounding;
+use training_model::{
+    config::DummyBehaviour,
+    play::{InputRecording, TrainingEntities, TrainingSettings, TrainingStatus},
+};
+
+/// Sends `ControlInputEvent`s for the training dummy, and records the player's input.
+///
+/// The dummy is controlled through events rather than setting its `ControllerInput` directly, so
+/// that both `press_*` and `hold_*` input reactions are triggered.
+#[derive(Debug, Default, new)]
+pub struct TrainingDummyInputSystem {
+    /// Reader ID for the `ControlInputEvent` event channel.
+    #[new(default)]
+    control_input_event_rid: Option<ReaderId<ControlInputEvent>>,
+    /// Reader ID for the `HitEvent` event channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `TrainingDummyInputSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TrainingDummyInputSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `TrainingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_status: Read<'s, TrainingStatus>,
+    /// `TrainingSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_settings: Read<'s, TrainingSettings>,
+    /// `TrainingEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_entities: Read<'s, TrainingEntities>,
+    /// `InputRecording` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_recording: Write<'s, InputRecording>,
+    /// `ControllerInput` components.
+    #[derivative(Debug = "ignore")]
+    pub controller_inputs: ReadStorage<'s, ControllerInput>,
+    /// `Grounding` components.
+    #[derivative(Debug = "ignore")]
+    pub groundings: ReadStorage<'s, Grounding>,
+}
+
+impl TrainingDummyInputSystem {
+    /// Returns the input the dummy should have for the given behaviour.
+    fn behaviour_input(dummy_behaviour: DummyBehaviour, grounding: Grounding) -> ControllerInput {
+        let mut controller_input = ControllerInput::default();
+        match dummy_behaviour {
+            DummyBehaviour::Stand | DummyBehaviour::RecordReplay => {}
+            DummyBehaviour::Defend | DummyBehaviour::BlockAll => controller_input.defend = true,
+            DummyBehaviour::Jump => controller_input.jump = grounding == Grounding::OnGround,
+        }
+        controller_input
+    }
+
+    /// Returns the events to send to change the dummy's input to the desired input.
+    fn input_change_events(
+        entity: Entity,
+        controller_id: ControllerId,
+        current: ControllerInput,
+        desired: ControllerInput,
+    ) -> Vec<ControlInputEvent> {
+        let axis_events = [
+            (Axis::X, current.x_axis_value, desired.x_axis_value),
+            (Axis::Z, current.z_axis_value, desired.z_axis_value),
+        ]
+        .iter()
+        .copied()
+        .filter(|(_, current, desired)| (current - desired).abs() > std::f32::EPSILON)
+        .map(|(axis, _, value)| {
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id,
+                entity,
+                axis,
+                value,
+            })
+        });
+
+        let control_action_events = [
+            (ControlAction::Defend, current.defend, desired.defend),
+            (ControlAction::Jump, current.jump, desired.jump),
+            (ControlAction::Attack, current.attack, desired.attack),
+            (ControlAction::Special, current.special, desired.special),
+        ]
+        .iter()
+        .copied()
+        .filter(|(_, current, desired)| current != desired)
+        .map(|(control_action, _, pressed)| {
+            let control_action_event_data = ControlActionEventData {
+                controller_id,
+                entity,
+                control_action,
+            };
+            if pressed {
+                ControlInputEvent::ControlActionPress(control_action_event_data)
+            } else {
+                ControlInputEvent::ControlActionRelease(control_action_event_data)
+            }
+        });
+
+        axis_events.chain(control_action_events).collect::<Vec<_>>()
+    }
+
+    /// Returns events to release and press `Defend` again within the same tick.
+    fn defend_repress_events(
+        entity: Entity,
+        controller_id: ControllerId,
+    ) -> Vec<ControlInputEvent> {
+        let control_action_event_data = ControlActionEventData {
+            controller_id,
+            entity,
+            control_action: ControlAction::Defend,
+        };
+        vec![
+            ControlInputEvent::ControlActionRelease(control_action_event_data),
+            ControlInputEvent::ControlActionPress(control_action_event_data),
+        ]
+    }
+
+    /// Returns the recorded events for the current replay tick, and advances the replay.
+    fn replay_events(
+        input_recording: &mut InputRecording,
+        entity: Entity,
+        controller_id: ControllerId,
+        current: ControllerInput,
+    ) -> Vec<ControlInputEvent> {
+        if input_recording.recording || input_recording.length == 0 {
+            return Self::input_change_events(
+                entity,
+                controller_id,
+                current,
+                ControllerInput::default(),
+            );
+        }
+
+        let replay_tick = input_recording.replay_tick;
+
+        // Each replay begins from neutral input.
+        let mut events = if replay_tick == 0 {
+            Self::input_change_events(entity, controller_id, current, ControllerInput::default())
+        } else {
+            Vec::new()
+        };
+        events.extend(
+            input_recording
+                .events
+                .iter()
+                .filter(|(tick, _)| *tick == replay_tick)
+                .map(|(_, ev)| Self::retarget(*ev, entity, controller_id)),
+        );
+
+        input_recording.replay_tick = (replay_tick + 1) % input_recording.length;
+
+        events
+    }
+
+    /// Returns the event with its entity and controller ID replaced.
+    fn retarget(
+        ev: ControlInputEvent,
+        entity: Entity,
+        controller_id: ControllerId,
+    ) -> ControlInputEvent {
+        match ev {
+            ControlInputEvent::AxisMoved(axis_move_event_data) => {
+                ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id,
+                    entity,
+                    ..axis_move_event_data
+                })
+            }
+            ControlInputEvent::ControlActionPress(control_action_event_data) => {
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    controller_id,
+                    entity,
+                    ..control_action_event_data
+                })
+            }
+            ControlInputEvent::ControlActionRelease(control_action_event_data) => {
+                ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                    controller_id,
+                    entity,
+                    ..control_action_event_data
+                })
+            }
+        }
+    }
+
+    /// Returns the entity that a `ControlInputEvent` applies to.
+    fn event_entity(ev: &ControlInputEvent) -> Entity {
+        match ev {
+            ControlInputEvent::AxisMoved(AxisMoveEventData { entity, .. })
+            | ControlInputEvent::ControlActionPress(ControlActionEventData { entity, .. })
+            | ControlInputEvent::ControlActionRelease(ControlActionEventData { entity, .. }) => {
+                *entity
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for TrainingDummyInputSystem {
+    type SystemData = TrainingDummyInputSystemData<'s>;
+
+    fn run(
+        &mut self,
+        TrainingDummyInputSystemData {
+            mut control_input_ec,
+            hit_ec,
+            training_status,
+            training_settings,
+            training_entities,
+            mut input_recording,
+            controller_inputs,
+            groundings,
+        }: Self::SystemData,
+    ) {
+        let control_input_event_rid = self
+            .control_input_event_rid
+            .as_mut()
+            .expect("Expected `control_input_event_rid` field to be set.");
+        let hit_event_rid = self
+            .hit_event_rid
+            .as_mut()
+            .expect("Expected `hit_event_rid` field to be set.");
+
+        // Always read events so that the readers do not fall behind outside of training.
+        let control_input_events = control_input_ec
+            .read(control_input_event_rid)
+            .copied()
+            .collect::<Vec<ControlInputEvent>>();
+        let TrainingEntities {
+            player,
+            dummy,
+            dummy_controller_id,
+        } = *training_entities;
+        let dummy_hit = hit_ec
+            .read(hit_event_rid)
+            .any(|hit_event| Some(hit_event.to) == dummy);
+
+        if *training_status != TrainingStatus::Active {
+            return;
+        }
+
+        if input_recording.recording {
+            let tick = input_recording.length;
+            if let Some(player) = player {
+                let player_events = control_input_events
+                    .iter()
+                    .filter(|ev| Self::event_entity(ev) == player)
+                    .map(|ev| (tick, *ev));
+                input_recording.events.extend(player_events);
+            }
+            input_recording.length += 1;
+        }
+
+        let dummy = if let Some(dummy) = dummy {
+            dummy
+        } else {
+            return;
+        };
+        let controller_id = dummy_controller_id.unwrap_or_default();
+        let current = controller_inputs.get(dummy).copied().unwrap_or_default();
+
+        let mut events = match training_settings.dummy_behaviour {
+            DummyBehaviour::RecordReplay => {
+                Self::replay_events(&mut input_recording, dummy, controller_id, current)
+            }
+            dummy_behaviour => {
+                let grounding = groundings.get(dummy).copied().unwrap_or_default();
+                let desired = Self::behaviour_input(dummy_behaviour, grounding);
+                let mut events = Self::input_change_events(dummy, controller_id, current, desired);
+
+                // `Defend` is already held, so it must be pressed again to guard out of hit stun.
+                if dummy_behaviour == DummyBehaviour::BlockAll && dummy_hit && current.defend {
+                    events.extend(Self::defend_repress_events(dummy, controller_id));
+                }
+
+                events
+            }
+        };
+
+        control_input_ec.drain_vec_write(&mut events);
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.control_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ControlInputEvent>>()
+                .register_reader(),
+        );
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use amethyst::{
+    ecs::{Entities, Read, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::InputControlled;
+use game_model::play::GameEntities;
+use object_type::ObjectType;
+use training_model::play::{FrameData, TrainingEntities, TrainingStatus};
+
+/// Determines the player and dummy characters of a training session.
+///
+/// The character with the lowest controller ID is the player, and the next character is the
+/// dummy. The dummy's `InputControlled` component is removed so that it is only controlled through
+/// the `TrainingDummyInputSystem`.
+///
+/// `FrameData` is reset whenever the training characters change.
+#[derive(Debug, Default, new)]
+pub struct TrainingEntitiesUpdateSystem;
+
+/// `TrainingEntitiesUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TrainingEntitiesUpdateSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `TrainingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_status: Read<'s, TrainingStatus>,
+    /// `GameEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_entities: Read<'s, GameEntities>,
+    /// `TrainingEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_entities: Write<'s, TrainingEntities>,
+    /// `FrameData` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_data: Write<'s, FrameData>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: WriteStorage<'s, InputControlled>,
+}
+
+impl<'s> System<'s> for TrainingEntitiesUpdateSystem {
+    type SystemData = TrainingEntitiesUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        TrainingEntitiesUpdateSystemData {
+            entities,
+            training_status,
+            game_entities,
+            mut training_entities,
+            mut frame_data,
+            mut input_controlleds,
+        }: Self::SystemData,
+    ) {
+        if *training_status != TrainingStatus::Active {
+            return;
+        }
+
+        // Characters are recreated when the round is restarted.
+        let player_alive = training_entities
+            .player
+            .map(|player| entities.is_alive(player))
+            .unwrap_or(false);
+        if player_alive {
+            return;
+        }
+
+        let mut characters = game_entities
+            .objects
+            .get(&ObjectType::Character)
+            .map(|characters| {
+                characters
+                    .iter()
+                    .copied()
+                    .filter_map(|entity| {
+                        input_controlleds
+                            .get(entity)
+                            .map(|input_controlled| (input_controlled.controller_id, entity))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_else(Vec::new);
+        if characters.is_empty() {
+            return;
+        }
+        characters.sort_by_key(|(controller_id, _)| *controller_id);
+
+        let mut characters = characters.into_iter();
+        let player = characters.next().map(|(_, entity)| entity);
+        let (dummy, dummy_controller_id) = characters
+            .next()
+            .map(|(controller_id, entity)| (Some(entity), Some(controller_id)))
+            .unwrap_or((None, None));
+
+        if let Some(dummy) = dummy {
+            input_controlleds.remove(dummy);
+        }
+
+        *training_entities = TrainingEntities::new(player, dummy, dummy_controller_id);
+        *frame_data = FrameData::default();
+    }
+}
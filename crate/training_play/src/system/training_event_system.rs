@@ -0,0 +1,81 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use training_model::{
+    play::{InputRecording, TrainingSettings},
+    TrainingEvent,
+};
+
+/// Updates `TrainingSettings` and `InputRecording` based on `TrainingEvent`s.
+#[derive(Debug, Default, new)]
+pub struct TrainingEventSystem {
+    /// Reader ID for the `TrainingEvent` event channel.
+    #[new(default)]
+    training_event_rid: Option<ReaderId<TrainingEvent>>,
+}
+
+/// `TrainingEventSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TrainingEventSystemData<'s> {
+    /// `TrainingEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub training_ec: Read<'s, EventChannel<TrainingEvent>>,
+    /// `TrainingSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_settings: Write<'s, TrainingSettings>,
+    /// `InputRecording` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_recording: Write<'s, InputRecording>,
+}
+
+impl<'s> System<'s> for TrainingEventSystem {
+    type SystemData = TrainingEventSystemData<'s>;
+
+    fn run(
+        &mut self,
+        TrainingEventSystemData {
+            training_ec,
+            mut training_settings,
+            mut input_recording,
+        }: Self::SystemData,
+    ) {
+        let training_event_rid = self
+            .training_event_rid
+            .as_mut()
+            .expect("Expected `training_event_rid` field to be set.");
+
+        training_ec
+            .read(training_event_rid)
+            .copied()
+            .for_each(|ev| match ev {
+                TrainingEvent::ToggleInfiniteHp => {
+                    training_settings.infinite_hp = !training_settings.infinite_hp;
+                }
+                TrainingEvent::ToggleInfiniteSp => {
+                    training_settings.infinite_sp = !training_settings.infinite_sp;
+                }
+                TrainingEvent::ToggleInfiniteCharge => {
+                    training_settings.infinite_charge = !training_settings.infinite_charge;
+                }
+                TrainingEvent::DummyBehaviour(dummy_behaviour) => {
+                    training_settings.dummy_behaviour = dummy_behaviour;
+                }
+                TrainingEvent::RecordStart => input_recording.start(),
+                TrainingEvent::RecordStop => input_recording.stop(),
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.training_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<TrainingEvent>>()
+                .register_reader(),
+        );
+    }
+}
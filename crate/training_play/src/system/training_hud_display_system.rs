@@ -0,0 +1,196 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, LineMode, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::GamePlayEntity;
+use training_model::play::{
+    FrameData, InputRecording, TrainingEntities, TrainingHudEntity, TrainingSettings,
+    TrainingStatus,
+};
+
+use crate::{DUMMY_BEHAVIOUR_NEXT_KEY, RECORD_TOGGLE_KEY};
+
+const FONT_COLOUR_HUD: [f32; 4] = [0.9, 0.9, 0.7, 1.];
+const FONT_SIZE_HUD: f32 = 20.;
+const LABEL_WIDTH: f32 = 400.;
+const LABEL_HEIGHT: f32 = 150.;
+const LABEL_MARGIN: f32 = 10.;
+
+/// Displays frame data and training settings during a training session.
+#[derive(Debug, Default, new)]
+pub struct TrainingHudDisplaySystem;
+
+/// `TrainingHudDisplaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TrainingHudDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `TrainingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_status: Read<'s, TrainingStatus>,
+    /// `TrainingEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_entities: Read<'s, TrainingEntities>,
+    /// `TrainingSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_settings: Read<'s, TrainingSettings>,
+    /// `InputRecording` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_recording: Read<'s, InputRecording>,
+    /// `FrameData` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_data: Read<'s, FrameData>,
+    /// `TrainingHudEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub training_hud_entities: WriteStorage<'s, TrainingHudEntity>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: WriteStorage<'s, GamePlayEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl TrainingHudDisplaySystem {
+    fn hud_text(
+        frame_data: &FrameData,
+        training_settings: &TrainingSettings,
+        input_recording: &InputRecording,
+    ) -> String {
+        let sequence_id = frame_data
+            .sequence_id
+            .map(|sequence_id| sequence_id.to_string())
+            .unwrap_or_else(|| String::from("-"));
+
+        // Show the sequence being performed, otherwise the last move that had active frames.
+        let move_frames = if frame_data.move_frames.active > 0 {
+            Some(frame_data.move_frames)
+        } else {
+            frame_data.last_move_frames
+        };
+        let move_frames = move_frames
+            .map(|move_frames| {
+                format!(
+                    "startup: {}  active: {}  recovery: {}",
+                    move_frames.startup, move_frames.active, move_frames.recovery
+                )
+            })
+            .unwrap_or_else(|| String::from("startup: -  active: -  recovery: -"));
+
+        let hit_advantage = frame_data
+            .hit_advantage
+            .map(|hit_advantage| format!("{:+}", hit_advantage))
+            .unwrap_or_else(|| String::from("-"));
+
+        let toggle = |enabled: bool| if enabled { "on" } else { "off" };
+        let recording = if input_recording.recording {
+            "  [recording]"
+        } else {
+            ""
+        };
+
+        format!(
+            "sequence: {}  frame: {}\n\
+             {}\n\
+             advantage: {}\n\
+             hp: {}  sp: {}  charge: {}\n\
+             dummy: {} ({:?}: next, {:?}: record){}",
+            sequence_id,
+            frame_data.frame_index,
+            move_frames,
+            hit_advantage,
+            toggle(training_settings.infinite_hp),
+            toggle(training_settings.infinite_sp),
+            toggle(training_settings.infinite_charge),
+            training_settings.dummy_behaviour,
+            DUMMY_BEHAVIOUR_NEXT_KEY,
+            RECORD_TOGGLE_KEY,
+            recording,
+        )
+    }
+}
+
+impl<'s> System<'s> for TrainingHudDisplaySystem {
+    type SystemData = TrainingHudDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        TrainingHudDisplaySystemData {
+            entities,
+            training_status,
+            training_entities,
+            training_settings,
+            input_recording,
+            frame_data,
+            mut training_hud_entities,
+            mut game_play_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let in_session = training_entities
+            .player
+            .map(|player| entities.is_alive(player))
+            .unwrap_or(false);
+        if *training_status != TrainingStatus::Active || !in_session {
+            return;
+        }
+
+        let hud_text = Self::hud_text(&frame_data, &training_settings, &input_recording);
+
+        let mut hud_exists = false;
+        (&training_hud_entities, &mut ui_texts)
+            .join()
+            .for_each(|(_, ui_text)| {
+                hud_exists = true;
+                if ui_text.text != hud_text {
+                    ui_text.text = hud_text.clone();
+                }
+            });
+
+        if !hud_exists {
+            let font = theme
+                .fonts
+                .get(&FontVariant::Regular)
+                .expect("Failed to get regular font handle.");
+
+            let ui_transform = UiTransform::new(
+                String::from("training_hud_text"),
+                Anchor::TopLeft,
+                Anchor::TopLeft,
+                LABEL_MARGIN,
+                -LABEL_MARGIN,
+                1.,
+                LABEL_WIDTH,
+                LABEL_HEIGHT,
+            );
+
+            let mut ui_text = UiText::new(font.clone(), hud_text, FONT_COLOUR_HUD, FONT_SIZE_HUD);
+            ui_text.line_mode = LineMode::Wrap;
+            ui_text.align = Anchor::TopLeft;
+
+            entities
+                .build_entity()
+                .with(GamePlayEntity, &mut game_play_entities)
+                .with(TrainingHudEntity, &mut training_hud_entities)
+                .with(ui_transform, &mut ui_transforms)
+                .with(ui_text, &mut ui_texts)
+                .build();
+        }
+    }
+}
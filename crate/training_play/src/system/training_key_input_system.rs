@@ -0,0 +1,123 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    input::{InputEvent, VirtualKeyCode},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::config::ControlBindings;
+use strum::IntoEnumIterator;
+use training_model::{
+    config::DummyBehaviour,
+    play::{InputRecording, TrainingSettings, TrainingStatus},
+    TrainingEvent,
+};
+
+/// Key that switches the training dummy to its next behaviour.
+pub const DUMMY_BEHAVIOUR_NEXT_KEY: VirtualKeyCode = VirtualKeyCode::F1;
+/// Key that starts and stops recording the player's input.
+pub const RECORD_TOGGLE_KEY: VirtualKeyCode = VirtualKeyCode::F2;
+
+/// Sends `TrainingEvent`s when training keys are pressed during a training session.
+///
+/// This allows the dummy's behaviour to be changed in game, as well as through stdin.
+#[derive(Debug, Default, new)]
+pub struct TrainingKeyInputSystem {
+    /// Reader ID for the `InputEvent<ControlBindings>` event channel.
+    #[new(default)]
+    input_event_rid: Option<ReaderId<InputEvent<ControlBindings>>>,
+}
+
+/// `TrainingKeyInputSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TrainingKeyInputSystemData<'s> {
+    /// `InputEvent<ControlBindings>` channel.
+    #[derivative(Debug = "ignore")]
+    pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `TrainingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_status: Read<'s, TrainingStatus>,
+    /// `TrainingSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_settings: Read<'s, TrainingSettings>,
+    /// `InputRecording` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_recording: Read<'s, InputRecording>,
+    /// `TrainingEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub training_ec: Write<'s, EventChannel<TrainingEvent>>,
+}
+
+impl TrainingKeyInputSystem {
+    /// Returns the behaviour after the given behaviour, wrapping around to the first.
+    fn dummy_behaviour_next(dummy_behaviour: DummyBehaviour) -> DummyBehaviour {
+        DummyBehaviour::iter()
+            .cycle()
+            .skip_while(|behaviour| *behaviour != dummy_behaviour)
+            .nth(1)
+            .unwrap_or_default()
+    }
+}
+
+impl<'s> System<'s> for TrainingKeyInputSystem {
+    type SystemData = TrainingKeyInputSystemData<'s>;
+
+    fn run(
+        &mut self,
+        TrainingKeyInputSystemData {
+            input_ec,
+            training_status,
+            training_settings,
+            input_recording,
+            mut training_ec,
+        }: Self::SystemData,
+    ) {
+        let input_event_rid = self
+            .input_event_rid
+            .as_mut()
+            .expect("Expected `input_event_rid` field to be set.");
+
+        // Events are always read, so that keys pressed outside training are not handled later.
+        let key_codes = input_ec
+            .read(input_event_rid)
+            .filter_map(|ev| match ev {
+                InputEvent::KeyPressed { key_code, .. } => Some(*key_code),
+                _ => None,
+            })
+            .collect::<Vec<VirtualKeyCode>>();
+
+        if *training_status != TrainingStatus::Active {
+            return;
+        }
+
+        // Settings are only updated by the `TrainingEventSystem`, so track changes made by earlier
+        // key presses in this frame.
+        let mut dummy_behaviour = training_settings.dummy_behaviour;
+        let mut recording = input_recording.recording;
+        key_codes.into_iter().for_each(|key_code| {
+            if key_code == DUMMY_BEHAVIOUR_NEXT_KEY {
+                dummy_behaviour = Self::dummy_behaviour_next(dummy_behaviour);
+                training_ec.single_write(TrainingEvent::DummyBehaviour(dummy_behaviour));
+            } else if key_code == RECORD_TOGGLE_KEY {
+                let training_event = if recording {
+                    TrainingEvent::RecordStop
+                } else {
+                    TrainingEvent::RecordStart
+                };
+                recording = !recording;
+                training_ec.single_write(training_event);
+            }
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<InputEvent<ControlBindings>>>()
+                .register_reader(),
+        );
+    }
+}
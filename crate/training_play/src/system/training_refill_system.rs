@@ -0,0 +1,80 @@
+use amethyst::{
+    ecs::{Read, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use charge_model::play::ChargeTrackerClock;
+use derivative::Derivative;
+use derive_new::new;
+use object_model::play::{HealthPoints, SkillPoints};
+use training_model::play::{TrainingEntities, TrainingSettings, TrainingStatus};
+
+/// Refills health points, skill points, and charge of training characters.
+#[derive(Debug, Default, new)]
+pub struct TrainingRefillSystem;
+
+/// `TrainingRefillSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TrainingRefillSystemData<'s> {
+    /// `TrainingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_status: Read<'s, TrainingStatus>,
+    /// `TrainingSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_settings: Read<'s, TrainingSettings>,
+    /// `TrainingEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub training_entities: Read<'s, TrainingEntities>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `SkillPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub skill_pointses: WriteStorage<'s, SkillPoints>,
+    /// `ChargeTrackerClock` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tracker_clocks: WriteStorage<'s, ChargeTrackerClock>,
+}
+
+impl<'s> System<'s> for TrainingRefillSystem {
+    type SystemData = TrainingRefillSystemData<'s>;
+
+    fn run(
+        &mut self,
+        TrainingRefillSystemData {
+            training_status,
+            training_settings,
+            training_entities,
+            mut health_pointses,
+            mut skill_pointses,
+            mut charge_tracker_clocks,
+        }: Self::SystemData,
+    ) {
+        if *training_status != TrainingStatus::Active {
+            return;
+        }
+
+        let TrainingEntities { player, dummy, .. } = *training_entities;
+        [player, dummy]
+            .iter()
+            .copied()
+            .flatten()
+            .for_each(|entity| {
+                if training_settings.infinite_hp {
+                    if let Some(health_points) = health_pointses.get_mut(entity) {
+                        *health_points = HealthPoints::default();
+                    }
+                }
+                if training_settings.infinite_sp {
+                    if let Some(skill_points) = skill_pointses.get_mut(entity) {
+                        *skill_points = SkillPoints::default();
+                    }
+                }
+                if training_settings.infinite_charge {
+                    if let Some(charge_tracker_clock) = charge_tracker_clocks.get_mut(entity) {
+                        charge_tracker_clock.complete();
+                    }
+                }
+            });
+    }
+}
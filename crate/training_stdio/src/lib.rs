@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable training mode to be controlled by stdio.
+
+pub use crate::{
+    training_event_stdin_mapper::TrainingEventStdinMapper,
+    training_stdio_bundle::TrainingStdioBundle,
+};
+
+mod training_event_stdin_mapper;
+mod training_stdio_bundle;
@@ -0,0 +1,26 @@
+use amethyst::Error;
+use stdio_spi::StdinMapper;
+use training_model::{TrainingEvent, TrainingEventArgs};
+
+/// Builds a `TrainingEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct TrainingEventStdinMapper;
+
+impl StdinMapper for TrainingEventStdinMapper {
+    type SystemData = ();
+    type Event = TrainingEvent;
+    type Args = TrainingEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        match args {
+            TrainingEventArgs::InfiniteHp => Ok(TrainingEvent::ToggleInfiniteHp),
+            TrainingEventArgs::InfiniteSp => Ok(TrainingEvent::ToggleInfiniteSp),
+            TrainingEventArgs::InfiniteCharge => Ok(TrainingEvent::ToggleInfiniteCharge),
+            TrainingEventArgs::DummyBehaviour { behaviour } => {
+                Ok(TrainingEvent::DummyBehaviour(behaviour))
+            }
+            TrainingEventArgs::RecordStart => Ok(TrainingEvent::RecordStart),
+            TrainingEventArgs::RecordStop => Ok(TrainingEvent::RecordStop),
+        }
+    }
+}
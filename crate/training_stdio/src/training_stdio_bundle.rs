@@ -0,0 +1,31 @@
+use std::any;
+
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::TrainingEventStdinMapper;
+
+/// Adds a `MapperSystem<TrainingEventStdinMapper>` to the `World`.
+#[derive(Debug, new)]
+pub struct TrainingStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for TrainingStdioBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<TrainingEventStdinMapper>::new(AppEventVariant::Training),
+            any::type_name::<MapperSystem<TrainingEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -6,9 +6,11 @@ use amethyst::{
     ecs::{Read, ReadExpect, System, World, Write},
     shred::{ResourceId, SystemData},
 };
+use asset_fs::AssetFs;
 use asset_loading::YamlFormat;
 use derivative::Derivative;
 use derive_new::new;
+use loading_model::play::LoadingProgress;
 use log::{debug, error};
 use ui_audio_model::{
     config::{UiSfxId, UiSfxPaths},
@@ -34,6 +36,9 @@ pub struct UiAudioLoadingSystem {
     #[derivative(Debug = "ignore")]
     #[new(default)]
     progress_counter: ProgressCounter,
+    /// Bytes of the audio files being loaded.
+    #[new(default)]
+    audio_bytes: u64,
 }
 
 #[derive(Derivative, SystemData)]
@@ -54,6 +59,9 @@ pub struct UiAudioLoadingSystemData<'s> {
     /// `UiAudioLoadingStatus` resource.
     #[derivative(Debug = "ignore")]
     ui_audio_loading_status: Write<'s, UiAudioLoadingStatus>,
+    /// `LoadingProgress` resource.
+    #[derivative(Debug = "ignore")]
+    loading_progress: Write<'s, LoadingProgress>,
 }
 
 impl<'s> System<'s> for UiAudioLoadingSystem {
@@ -67,6 +75,7 @@ impl<'s> System<'s> for UiAudioLoadingSystem {
             source_assets,
             mut ui_sfx_map,
             mut ui_audio_loading_status,
+            mut loading_progress,
         }: Self::SystemData,
     ) {
         if *ui_audio_loading_status == UiAudioLoadingStatus::NotStarted {
@@ -113,6 +122,11 @@ impl<'s> System<'s> for UiAudioLoadingSystem {
                         .collect::<Vec<(&UiSfxId, &PathBuf)>>();
 
                     sfx_to_load.into_iter().for_each(|(ui_sfx_id, path)| {
+                        // Sizes are not known in WASM, so audio bytes are not tracked there.
+                        let audio_bytes = AssetFs::size(&self.assets_dir.join(path)).unwrap_or(0);
+                        self.audio_bytes += audio_bytes;
+                        loading_progress.audio_bytes.total += audio_bytes;
+
                         macro_rules! load {
                             ($audio_format:expr) => {
                                 loader.load(
@@ -162,6 +176,7 @@ impl<'s> System<'s> for UiAudioLoadingSystem {
                     if all_loaded {
                         debug!("UI audio assets loaded: {:?}", &*ui_sfx_map);
                         *ui_audio_loading_status = UiAudioLoadingStatus::Complete;
+                        loading_progress.audio_bytes.loaded += self.audio_bytes;
                     }
                 }
             }
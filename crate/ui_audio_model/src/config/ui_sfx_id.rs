@@ -1,15 +1,20 @@
 use std::str::FromStr;
 
+use asset_schema::AssetSchema;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
 
 /// Logical IDs to reference audio used for UI.
-#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, Hash, PartialEq, Eq, Serialize)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Display, EnumIter, EnumString, Hash, PartialEq, Eq, Serialize,
+)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum UiSfxId {
     /// Sound used for a `Cancel` action.
     Cancel,
+    /// Sound used when a higher charge level is reached.
+    ChargeLevel,
     /// Sound used for a `Confirm` action.
     Confirm,
     /// Sound used for a `Deselect` action.
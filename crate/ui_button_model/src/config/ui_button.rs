@@ -1,10 +1,11 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
 use ui_label_model::config::{UiLabel, UiSpriteLabel};
 
 /// Defines a UI widget with text, sprite, and responsive behaviour.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct UiButton {
     /// Position of the button.
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
@@ -9,7 +10,7 @@ use crate::config::UiTextInput;
 /// Specifies a form item to fill in.
 ///
 /// This includes the label for the item, and the input field.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct UiFormItem {
     /// Position of the form item.
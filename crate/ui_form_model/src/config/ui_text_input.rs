@@ -4,6 +4,7 @@ use amethyst::{
     ui::TextEditing,
 };
 use asset_model::ItemComponent;
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -15,7 +16,9 @@ const SELECTED_BACKGROUND_COLOUR_DEFAULT: [f32; 4] = [1., 1., 1., 0.8];
 const USE_BLOCK_CURSOR: bool = false;
 
 /// Defines a text input field.
-#[derive(Clone, Component, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Component, Debug, Derivative, Deserialize, PartialEq, Serialize, new,
+)]
 #[derivative(Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct UiTextInput {
@@ -1,5 +1,4 @@
 use amethyst::{
-    core::math::Vector3,
     ecs::{
         storage::DenseVecStorage, Component, Entity, ReadExpect, ReadStorage, World, WriteStorage,
     },
@@ -8,9 +7,10 @@ use amethyst::{
 };
 use application_ui::{FontVariant, Theme};
 use asset_model::ItemComponent;
+use asset_schema::{AnchorSchema, AssetSchema, LineModeSchema};
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::{Position, PositionInit};
+use kinematic_model::config::{FixedPoint, Position, PositionInit};
 use serde::{Deserialize, Serialize};
 use ui_model_spi::config::Dimensions;
 
@@ -20,7 +20,9 @@ const LABEL_WIDTH: u32 = 400;
 const LABEL_HEIGHT: u32 = 75;
 
 /// Defines text to display.
-#[derive(Clone, Debug, Derivative, Deserialize, Component, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Debug, Derivative, Deserialize, Component, PartialEq, Serialize, new,
+)]
 #[derivative(Default)]
 #[serde(default, deny_unknown_fields)]
 #[storage(DenseVecStorage)]
@@ -34,9 +36,11 @@ pub struct UiLabel {
     pub dimensions: Dimensions,
     /// Where to align the text within the text field.
     #[derivative(Default(value = "Anchor::Middle"))]
+    #[asset_schema(with = "AnchorSchema")]
     pub align: Anchor,
     /// Whether text should be on one line, or wrap.
     #[derivative(Default(value = "LineMode::Single"))]
+    #[asset_schema(with = "LineModeSchema")]
     pub line_mode: LineMode,
     /// Font colour of the text.
     #[derivative(Default(value = "FONT_COLOUR"))]
@@ -62,9 +66,9 @@ pub struct UiLabelSystemData<'s> {
     /// `Theme` resource.
     #[derivative(Debug = "ignore")]
     pub theme: ReadExpect<'s, Theme>,
-    /// `Position<f32>` components.
+    /// `Position<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub positions: ReadStorage<'s, Position<f32>>,
+    pub positions: ReadStorage<'s, Position<FixedPoint>>,
     /// `UiTransform` components.
     #[derivative(Debug = "ignore")]
     pub ui_transforms: WriteStorage<'s, UiTransform>,
@@ -92,8 +96,8 @@ impl<'s> ItemComponent<'s> for UiLabel {
         let position = positions
             .get(entity)
             .copied()
-            .unwrap_or_else(|| Position::from(self.position));
-        let position = Into::<Vector3<f32>>::into(position);
+            .unwrap_or_else(|| Position::from(self.position))
+            .to_f32();
 
         let mut ui_transform = UiTransform::new(
             self.text.clone(),
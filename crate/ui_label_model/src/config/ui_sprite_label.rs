@@ -1,5 +1,6 @@
 use std::convert::AsRef;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use sequence_model::config::SequenceNameString;
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use sprite_model::config::SpriteSequenceName;
 
 /// Defines a sprite sequence to display.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct UiSpriteLabel {
     /// Position of the label relative to its parent.
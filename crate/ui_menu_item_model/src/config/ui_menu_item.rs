@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use kinematic_model::config::PositionInit;
 use serde::{Deserialize, Serialize};
@@ -5,7 +6,7 @@ use ui_label_model::config::{UiLabel, UiSpriteLabel};
 use ui_model_spi::config::WidgetStatusSequences;
 
 /// Specifies an object to ui_menu_item.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct UiMenuItem<I> {
     /// Position of the menu item.
@@ -1,4 +1,5 @@
 use asset_derive::Asset;
+use asset_schema::AssetSchema;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use ui_button_model::config::UiButtons;
@@ -6,7 +7,7 @@ use ui_button_model::config::UiButtons;
 use crate::config::{UiSequences, UiType};
 
 /// Definition for a `State`'s UI.
-#[derive(Asset, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[derive(Asset, AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct UiDefinition {
     /// Type of UI -- generic menu, character selection, map selection.
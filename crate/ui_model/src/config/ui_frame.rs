@@ -1,13 +1,16 @@
 use std::convert::AsRef;
 
+use asset_schema::AssetSchema;
 use derive_new::new;
 use input_reaction_model::config::InputReactions;
 use sequence_model::config::Wait;
 use serde::{Deserialize, Serialize};
-use sprite_model::config::{Scale, SpriteFrame, SpriteRef, SpriteSequenceName, Tint};
+use sprite_model::config::{
+    Interpolation, Scale, SpriteFrame, SpriteOffset, SpriteRef, SpriteSequenceName, Tint,
+};
 
 /// Sequence frame type for characters.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct UiFrame {
     /// Sprite rendering information.
@@ -42,6 +45,18 @@ impl AsRef<Scale> for UiFrame {
     }
 }
 
+impl AsRef<SpriteOffset> for UiFrame {
+    fn as_ref(&self) -> &SpriteOffset {
+        &self.sprite_frame.offset
+    }
+}
+
+impl AsRef<Interpolation> for UiFrame {
+    fn as_ref(&self) -> &Interpolation {
+        &self.sprite_frame.interpolation
+    }
+}
+
 impl AsRef<InputReactions<SpriteSequenceName>> for UiFrame {
     fn as_ref(&self) -> &InputReactions<SpriteSequenceName> {
         &self.input_reactions
@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_new::new;
 use input_reaction_model::config::InputReactions;
 use sequence_model::config::Sequence;
@@ -7,7 +8,7 @@ use sprite_model::config::SpriteSequenceName;
 use crate::config::UiFrame;
 
 /// Sequence of `UiFrame`s.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[derive(AssetSchema, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(deny_unknown_fields)]
 pub struct UiSequence {
     /// Object sequence for common object fields.
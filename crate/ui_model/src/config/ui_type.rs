@@ -1,4 +1,5 @@
 use application_menu::MenuIndex;
+use asset_schema::AssetSchema;
 use character_selection_ui_model::config::CharacterSelectionUi;
 use control_settings_model::config::ControlSettings;
 use map_selection_ui_model::config::MapSelectionUi;
@@ -8,7 +9,7 @@ use ui_form_model::config::UiFormItems;
 use ui_menu_item_model::config::UiMenuItems;
 
 /// UI types -- generic menu, character selection, map selection.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum UiType {
     /// Character selection UI.
@@ -3,11 +3,12 @@ use amethyst::{
     shred::{ResourceId, SystemData},
 };
 use asset_model::ItemComponent;
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Width and height of a UI element.
-#[derive(Clone, Component, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(AssetSchema, Clone, Component, Copy, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Dimensions {
     /// Width of the element.
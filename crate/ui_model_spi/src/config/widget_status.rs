@@ -2,11 +2,14 @@ use amethyst::ecs::{
     storage::{FlaggedStorage, VecStorage},
     Component,
 };
+use asset_schema::AssetSchema;
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Active / Idle status of a UI widget.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[derive(
+    AssetSchema, Clone, Copy, Debug, Derivative, Deserialize, Hash, PartialEq, Eq, Serialize,
+)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum WidgetStatus {
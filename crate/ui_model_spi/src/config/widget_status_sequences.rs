@@ -1,3 +1,4 @@
+use asset_schema::AssetSchema;
 use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use indexmap::IndexMap;
@@ -8,7 +9,9 @@ use sprite_model::config::SpriteSequenceName;
 use crate::config::WidgetStatus;
 
 /// Sequence to use when transitioning to a particular `WidgetStatus`.
-#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[derive(
+    AssetSchema, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new,
+)]
 pub struct WidgetStatusSequences(
     pub IndexMap<WidgetStatus, SequenceNameString<SpriteSequenceName>>,
 );
@@ -2,5 +2,6 @@ mod app_dir;
 mod app_file;
 mod discovery_context;
 mod find_context;
+mod format;
 mod io_support;
 mod io_utils;
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use application::{AppFile, Format};
+    use serde::Deserialize;
+
+    #[test]
+    fn from_path_returns_format_for_known_extensions() {
+        assert_eq!(
+            Some(Format::Ron),
+            Format::from_path(Path::new("object.ron"))
+        );
+        assert_eq!(
+            Some(Format::Yaml),
+            Format::from_path(Path::new("object.yaml"))
+        );
+        assert_eq!(
+            Some(Format::Yaml),
+            Format::from_path(Path::new("object.yml"))
+        );
+        assert_eq!(
+            Some(Format::Json),
+            Format::from_path(Path::new("object.json"))
+        );
+    }
+
+    #[test]
+    fn from_path_returns_none_for_unknown_extensions() {
+        assert_eq!(None, Format::from_path(Path::new("object.toml")));
+        assert_eq!(None, Format::from_path(Path::new("object")));
+    }
+
+    #[test]
+    fn extension_round_trips_through_from_path() {
+        [Format::Ron, Format::Yaml, Format::Json]
+            .iter()
+            .for_each(|format| {
+                let path = Path::new("object").with_extension(format.extension());
+
+                assert_eq!(Some(*format), Format::from_path(&path));
+            });
+    }
+
+    #[test]
+    fn load_bytes_deserializes_json() {
+        let thing = AppFile::load_bytes::<Thing>(br#"{ "val": 123 }"#, Format::Json)
+            .expect("Expected JSON to be deserialized.");
+
+        assert_eq!(Thing { val: 123 }, thing);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Thing {
+        val: i32,
+    }
+}
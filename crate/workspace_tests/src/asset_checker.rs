@@ -0,0 +1,3 @@
+mod asset_checker;
+mod definition_reader;
+mod input_reactions_checker;
@@ -0,0 +1,357 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs, io,
+        path::{Path, PathBuf},
+    };
+
+    use asset_loading::{ObjectDefinitionMergeError, ASSETS_TEST_DIR};
+    use asset_model::config::AssetSlug;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use asset_checker::{AssetCheckError, AssetCheckErrorKind, AssetChecker};
+
+    const SPRITES_YAML: &str = "\
+sheets:
+  - path: sheet_0.png
+    sprite_w: 1
+    sprite_h: 1
+    row_count: 1
+    column_count: 2
+";
+
+    #[test]
+    fn returns_no_errors_for_valid_assets() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+
+        write_asset(
+            assets_dir,
+            &["object", "character", "char_0"],
+            &[
+                (
+                    "object.yaml",
+                    "\
+sequences:
+  stand:
+    next: walk
+    frames:
+      - sprite: { sheet: 0, index: 1 }
+        sound: test/sfx/hit.wav
+        spawns: [{ object: test/char_1 }]
+        input_reactions:
+          press_attack: walk
+  walk:
+    frames:
+      - sprite: { sheet: 0, index: 0 }
+",
+                ),
+                ("sprites.yaml", SPRITES_YAML),
+            ],
+        )?;
+        write_asset(
+            assets_dir,
+            &["object", "character", "char_1"],
+            &[("object.yaml", "sequences: {}")],
+        )?;
+        write_asset(assets_dir, &["sfx"], &[("hit.wav", "")])?;
+
+        assert_eq!(
+            Vec::<AssetCheckError>::new(),
+            AssetChecker::check(assets_dir)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_deserialize_errors_for_every_asset() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+
+        let char_0_dir = write_asset(
+            assets_dir,
+            &["object", "character", "char_0"],
+            &[("object.yaml", "sequences:\n  stand:\n    frames: 1\n")],
+        )?;
+        let map_0_dir = write_asset(
+            assets_dir,
+            &["map", "map_0"],
+            &[("map.yaml", "header: {}\n")],
+        )?;
+
+        assert_eq!(
+            vec![
+                (map_0_dir.join("map.yaml"), true),
+                (char_0_dir.join("object.yaml"), true),
+            ],
+            AssetChecker::check(assets_dir)
+                .iter()
+                .map(|asset_check_error| {
+                    let is_deserialize_error =
+                        if let AssetCheckErrorKind::Deserialize { .. } = asset_check_error.kind {
+                            true
+                        } else {
+                            false
+                        };
+                    (asset_check_error.path.clone(), is_deserialize_error)
+                })
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_unresolved_references() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+
+        let char_0_dir = write_asset(
+            assets_dir,
+            &["object", "character", "char_0"],
+            &[
+                (
+                    "object.yaml",
+                    "\
+sequences:
+  stand:
+    next: missing_next
+    input_reactions:
+      press_jump: missing_jump
+    frames:
+      - sprite: { sheet: 0, index: 2 }
+        sound: sfx/missing.wav
+        spawns: [{ object: test/missing }]
+        input_reactions:
+          press_attack:
+            - next: missing_attack
+      - sprite: { sheet: 0, index: 0 }
+        spawns: [{ object: test/missing }]
+",
+                ),
+                (
+                    "sprites.yaml",
+                    "\
+sheets:
+  - path: sheet_0.png
+    sprite_w: 1
+    sprite_h: 1
+    row_count: 1
+    column_count: 2
+  - path: sheet_1.png
+    sprite_w: 1
+    sprite_h: 1
+    row_count: 1
+    column_count: 1
+",
+                ),
+            ],
+        )?;
+        let object_path = char_0_dir.join("object.yaml");
+        let sprites_path = char_0_dir.join("sprites.yaml");
+        let sequence = String::from("stand");
+
+        assert_eq!(
+            vec![
+                AssetCheckError::new(
+                    object_path.clone(),
+                    Some(2),
+                    AssetCheckErrorKind::SequenceNotFound {
+                        sequence: sequence.clone(),
+                        sequence_name: String::from("missing_next"),
+                    }
+                ),
+                AssetCheckError::new(
+                    object_path.clone(),
+                    Some(2),
+                    AssetCheckErrorKind::SequenceNotFound {
+                        sequence: sequence.clone(),
+                        sequence_name: String::from("missing_jump"),
+                    }
+                ),
+                AssetCheckError::new(
+                    object_path.clone(),
+                    Some(2),
+                    AssetCheckErrorKind::SequenceNotFound {
+                        sequence: sequence.clone(),
+                        sequence_name: String::from("missing_attack"),
+                    }
+                ),
+                AssetCheckError::new(
+                    object_path.clone(),
+                    Some(2),
+                    AssetCheckErrorKind::SpriteIndexOutOfBounds {
+                        sequence: sequence.clone(),
+                        frame_index: 0,
+                        sheet: 0,
+                        index: 2,
+                        sprite_count: 2,
+                    }
+                ),
+                AssetCheckError::new(
+                    object_path.clone(),
+                    Some(2),
+                    AssetCheckErrorKind::SoundNotFound {
+                        sequence: sequence.clone(),
+                        frame_index: 0,
+                        sound: PathBuf::from("sfx/missing.wav"),
+                    }
+                ),
+                AssetCheckError::new(
+                    object_path.clone(),
+                    Some(2),
+                    AssetCheckErrorKind::SpawnObjectNotFound {
+                        sequence,
+                        asset_slug: asset_slug("test/missing"),
+                    }
+                ),
+                AssetCheckError::new(
+                    sprites_path,
+                    Some(7),
+                    AssetCheckErrorKind::SpriteSheetUnused {
+                        sheet: 1,
+                        sheet_path: String::from("sheet_1.png"),
+                    }
+                ),
+            ],
+            AssetChecker::check(assets_dir)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_sprite_sheet_not_found_for_map_layers() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+
+        let map_0_dir = write_asset(
+            assets_dir,
+            &["map", "map_0"],
+            &[
+                (
+                    "map.yaml",
+                    "\
+header:
+  name: Map 0
+  bounds: { x: 0, y: 0, z: 0, width: 10, height: 10, depth: 10 }
+layers:
+  ground:
+    frames:
+      - sprite: { sheet: 0, index: 0 }
+  sky:
+    frames:
+      - sprite: { sheet: 1, index: 0 }
+",
+                ),
+                ("sprites.yaml", SPRITES_YAML),
+            ],
+        )?;
+
+        assert_eq!(
+            vec![AssetCheckError::new(
+                map_0_dir.join("map.yaml"),
+                Some(8),
+                AssetCheckErrorKind::SpriteSheetNotFound {
+                    sequence: String::from("sky"),
+                    frame_index: 0,
+                    sheet: 1,
+                    sheet_count: 1,
+                }
+            )],
+            AssetChecker::check(assets_dir)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_merged_definition_of_extending_objects() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+
+        write_asset(
+            assets_dir,
+            &["object", "character", "char_0"],
+            &[
+                (
+                    "object.yaml",
+                    "\
+sequences:
+  stand:
+    frames:
+      - sprite: { sheet: 0, index: 0 }
+",
+                ),
+                ("sprites.yaml", SPRITES_YAML),
+            ],
+        )?;
+        write_asset(
+            assets_dir,
+            &["object", "character", "char_1"],
+            &[
+                (
+                    "object.yaml",
+                    "\
+extends: test/char_0
+sequences:
+  stand:
+    merge: true
+    next: walk
+  walk:
+    frames:
+      - sprite: { sheet: 0, index: 1 }
+",
+                ),
+                ("sprites.yaml", SPRITES_YAML),
+            ],
+        )?;
+        let char_2_dir = write_asset(
+            assets_dir,
+            &["object", "character", "char_2"],
+            &[("object.yaml", "extends: test/missing\nsequences: {}\n")],
+        )?;
+        let char_2_definition_path = char_2_dir.join("object.yaml");
+
+        assert_eq!(
+            vec![AssetCheckError::new(
+                char_2_definition_path.clone(),
+                None,
+                AssetCheckErrorKind::Extends {
+                    message: ObjectDefinitionMergeError::ParentNotFound {
+                        path: char_2_definition_path,
+                        asset_slug: asset_slug("test/missing"),
+                    }
+                    .to_string(),
+                }
+            )],
+            AssetChecker::check(assets_dir)
+        );
+
+        Ok(())
+    }
+
+    fn write_asset(
+        assets_dir: &Path,
+        segments: &[&str],
+        files: &[(&str, &str)],
+    ) -> io::Result<PathBuf> {
+        let asset_dir = segments
+            .iter()
+            .fold(assets_dir.join(ASSETS_TEST_DIR), |path, segment| {
+                path.join(segment)
+            });
+        fs::create_dir_all(&asset_dir)?;
+        files
+            .iter()
+            .try_for_each(|(file_name, contents)| fs::write(asset_dir.join(file_name), contents))?;
+
+        Ok(asset_dir)
+    }
+
+    fn asset_slug(slug: &str) -> AssetSlug {
+        slug.parse().expect("Expected asset slug to be valid.")
+    }
+}
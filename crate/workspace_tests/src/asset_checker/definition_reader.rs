@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, io};
+
+    use pretty_assertions::assert_eq;
+    use sprite_model::config::SpritesDefinition;
+    use tempfile::tempdir;
+
+    use asset_checker::{AssetCheckErrorKind, DefinitionReader};
+
+    #[test]
+    fn read_returns_definition_and_contents() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let definition_path = tempdir.path().join("sprites.yaml");
+        fs::write(&definition_path, "sheets: []\n")?;
+
+        let (sprites_definition, contents) =
+            DefinitionReader::read::<SpritesDefinition>(&definition_path)
+                .expect("Expected `sprites.yaml` to be read.");
+
+        assert_eq!(SpritesDefinition::new(Vec::new()), sprites_definition);
+        assert_eq!("sheets: []\n", contents);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_returns_error_with_line_when_deserialization_fails() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let definition_path = tempdir.path().join("sprites.yaml");
+        fs::write(
+            &definition_path,
+            "sheets:\n  - path: sheet_0.png\n    sprite_w: wide\n",
+        )?;
+
+        let asset_check_error = DefinitionReader::read::<SpritesDefinition>(&definition_path)
+            .expect_err("Expected `sprites.yaml` to fail to deserialize.");
+
+        assert_eq!(definition_path, asset_check_error.path);
+        assert_eq!(Some(3), asset_check_error.line);
+        let is_deserialize_error =
+            if let AssetCheckErrorKind::Deserialize { .. } = asset_check_error.kind {
+                true
+            } else {
+                false
+            };
+        assert!(is_deserialize_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_deserializes_definition_by_file_extension() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let ron_path = tempdir.path().join("sprites.ron");
+        let json_path = tempdir.path().join("sprites.json");
+        fs::write(&ron_path, "(sheets: [])\n")?;
+        fs::write(&json_path, "{ \"sheets\": [] }\n")?;
+
+        let (sprites_definition_ron, _) = DefinitionReader::read::<SpritesDefinition>(&ron_path)
+            .expect("Expected `sprites.ron` to be read.");
+        let (sprites_definition_json, _) = DefinitionReader::read::<SpritesDefinition>(&json_path)
+            .expect("Expected `sprites.json` to be read.");
+
+        assert_eq!(SpritesDefinition::new(Vec::new()), sprites_definition_ron);
+        assert_eq!(SpritesDefinition::new(Vec::new()), sprites_definition_json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_returns_error_with_line_when_json_deserialization_fails() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let definition_path = tempdir.path().join("sprites.json");
+        fs::write(
+            &definition_path,
+            r#"{
+  "sheets": [
+    { "path": "sheet_0.png", "sprite_w": "wide" }
+  ]
+}
+"#,
+        )?;
+
+        let asset_check_error = DefinitionReader::read::<SpritesDefinition>(&definition_path)
+            .expect_err("Expected `sprites.json` to fail to deserialize.");
+
+        assert_eq!(definition_path, asset_check_error.path);
+        assert_eq!(Some(3), asset_check_error.line);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_returns_io_error_when_file_does_not_exist() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let definition_path = tempdir.path().join("sprites.yaml");
+
+        let asset_check_error = DefinitionReader::read::<SpritesDefinition>(&definition_path)
+            .expect_err("Expected `sprites.yaml` to fail to be read.");
+
+        assert_eq!(definition_path, asset_check_error.path);
+        assert_eq!(None, asset_check_error.line);
+        let is_io_error = if let AssetCheckErrorKind::Io { .. } = asset_check_error.kind {
+            true
+        } else {
+            false
+        };
+        assert!(is_io_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn key_line_returns_line_of_nested_key() {
+        let contents = "sequences:\n  stand:\n    next: walk\n  walk:\n    frames: []\n";
+
+        assert_eq!(Some(2), DefinitionReader::key_line(contents, "stand"));
+        assert_eq!(Some(4), DefinitionReader::key_line(contents, "walk"));
+        assert_eq!(None, DefinitionReader::key_line(contents, "sequences"));
+        assert_eq!(None, DefinitionReader::key_line(contents, "jump"));
+    }
+
+    #[test]
+    fn key_line_returns_line_of_nested_quoted_key() {
+        let contents = "{\n  \"sequences\": {\n    \"stand\": {},\n    \"walk\": {}\n  }\n}\n";
+
+        assert_eq!(Some(3), DefinitionReader::key_line(contents, "stand"));
+        assert_eq!(Some(4), DefinitionReader::key_line(contents, "walk"));
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use character_model::config::{CharacterInputReactions, CharacterSequenceName};
+    use pretty_assertions::assert_eq;
+    use sequence_model::config::SequenceNameString;
+
+    use asset_checker::InputReactionsChecker;
+
+    #[test]
+    fn sequence_names_returns_names_from_all_input_reactions() {
+        let input_reactions = serde_yaml::from_str::<CharacterInputReactions>(
+            "\
+press_attack: stand_attack_0
+hold_jump:
+  next: jump
+release_x:
+  - next: walk
+  - next: custom
+press_button:
+  - button: { Key: A }
+    next: dash_forward
+",
+        )
+        .expect("Failed to deserialize `CharacterInputReactions`.");
+
+        assert_eq!(
+            vec![
+                &SequenceNameString::Name(CharacterSequenceName::StandAttack0),
+                &SequenceNameString::Name(CharacterSequenceName::Jump),
+                &SequenceNameString::Name(CharacterSequenceName::Walk),
+                &SequenceNameString::String(String::from("custom")),
+                &SequenceNameString::Name(CharacterSequenceName::DashForward),
+            ],
+            InputReactionsChecker::sequence_names(&input_reactions)
+        );
+    }
+}
@@ -0,0 +1,2 @@
+mod asset_fs;
+mod asset_pack;
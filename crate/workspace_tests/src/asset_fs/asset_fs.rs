@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, io};
+
+    use tempfile::tempdir;
+
+    use asset_fs::{AssetFs, AssetPackWriter};
+
+    #[test]
+    fn accesses_files_within_pack_as_directories() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let namespace_dir = tempdir.path().join("user1");
+        let pack_path = tempdir.path().join("user1.pack");
+        let char_0_dir = namespace_dir
+            .join("object")
+            .join("character")
+            .join("char_0");
+        fs::create_dir_all(&char_0_dir)?;
+        fs::write(char_0_dir.join("object.yaml"), "sequences: {}")?;
+        AssetPackWriter::write(&namespace_dir, &pack_path)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        let character_dir = pack_path.join("object").join("character");
+        let definition_path = character_dir.join("char_0").join("object.yaml");
+
+        assert!(AssetFs::exists(&pack_path));
+        assert!(AssetFs::is_dir(&pack_path));
+        assert!(AssetFs::exists(&definition_path));
+        assert!(!AssetFs::is_dir(&definition_path));
+        assert!(!AssetFs::exists(&character_dir.join("char_1")));
+        assert_eq!(
+            vec![character_dir.join("char_0")],
+            AssetFs::child_dirs(&character_dir)
+        );
+        assert_eq!(b"sequences: {}".to_vec(), AssetFs::read(&definition_path)?);
+        assert_eq!(13, AssetFs::size(&definition_path)?);
+        assert_eq!(
+            vec![pack_path.clone()],
+            AssetFs::child_packs(tempdir.path())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn accesses_files_outside_packs() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let char_0_dir = tempdir.path().join("object").join("char_0");
+        fs::create_dir_all(&char_0_dir)?;
+        fs::write(char_0_dir.join("object.yaml"), "sequences: {}")?;
+
+        assert!(AssetFs::exists(&char_0_dir.join("object.yaml")));
+        assert!(AssetFs::is_dir(&char_0_dir));
+        assert_eq!(
+            vec![char_0_dir.clone()],
+            AssetFs::child_dirs(&tempdir.path().join("object"))
+        );
+        assert_eq!(
+            b"sequences: {}".to_vec(),
+            AssetFs::read(&char_0_dir.join("object.yaml"))?
+        );
+        assert_eq!(13, AssetFs::size(&char_0_dir.join("object.yaml"))?);
+        assert!(AssetFs::child_packs(tempdir.path()).is_empty());
+
+        Ok(())
+    }
+}
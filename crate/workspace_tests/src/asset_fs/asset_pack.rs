@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, io, path::Path};
+
+    use tempfile::tempdir;
+
+    use asset_fs::{AssetPack, AssetPackEntry, AssetPackError, AssetPackWriter};
+
+    #[test]
+    fn writes_and_reads_files_in_pack() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let namespace_dir = tempdir.path().join("user1");
+        let pack_path = tempdir.path().join("user1.pack");
+        write_namespace(&namespace_dir)?;
+
+        let entries = AssetPackWriter::write(&namespace_dir, &pack_path).map_err(io_error)?;
+        let asset_pack = AssetPack::open(&pack_path).map_err(io_error)?;
+
+        assert_eq!(
+            entries,
+            asset_pack
+                .entries()
+                .cloned()
+                .collect::<Vec<AssetPackEntry>>()
+        );
+        assert_eq!(
+            vec![
+                "namespace.yaml",
+                "object/character/char_0/object.yaml",
+                "object/character/char_1/object.yaml",
+            ],
+            asset_pack
+                .entries()
+                .map(|entry| entry.path.as_str())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!(
+            b"sequences: {}".to_vec(),
+            asset_pack
+                .read("object/character/char_1/object.yaml")
+                .map_err(io_error)?
+        );
+        assert!(asset_pack.is_file("namespace.yaml"));
+        assert!(!asset_pack.is_file("object"));
+        assert!(asset_pack.is_dir(""));
+        assert!(asset_pack.is_dir("object/character"));
+        assert!(!asset_pack.is_dir("object/char"));
+        assert_eq!(
+            vec![String::from("char_0"), String::from("char_1")],
+            asset_pack.child_dirs("object/character")
+        );
+        assert_eq!(vec![String::from("object")], asset_pack.child_dirs(""));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_skips_pack_within_namespace_directory() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let namespace_dir = tempdir.path();
+        let pack_path = namespace_dir.join("user1.pack");
+        write_namespace(&namespace_dir)?;
+
+        AssetPackWriter::write(&namespace_dir, &pack_path).map_err(io_error)?;
+        let entries = AssetPackWriter::write(&namespace_dir, &pack_path).map_err(io_error)?;
+
+        assert_eq!(3, entries.len());
+        assert!(entries.iter().all(|entry| entry.path != "user1.pack"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_returns_magic_invalid_error_when_file_is_not_pack() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let pack_path = tempdir.path().join("user1.pack");
+        fs::write(&pack_path, "sequences: {}")?;
+
+        let result = AssetPack::open(&pack_path);
+
+        if let Err(AssetPackError::MagicInvalid { path }) = result {
+            assert_eq!(pack_path, path);
+        } else {
+            panic!("Expected `MagicInvalid` error, but was: {:?}", result);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_returns_checksum_mismatch_error_when_data_is_corrupted() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let namespace_dir = tempdir.path().join("user1");
+        let pack_path = tempdir.path().join("user1.pack");
+        write_namespace(&namespace_dir)?;
+        AssetPackWriter::write(&namespace_dir, &pack_path).map_err(io_error)?;
+
+        let mut bytes = fs::read(&pack_path)?;
+        if let Some(byte) = bytes.last_mut() {
+            *byte = b'!';
+        }
+        fs::write(&pack_path, bytes)?;
+
+        let asset_pack = AssetPack::open(&pack_path).map_err(io_error)?;
+        let result = asset_pack.read("object/character/char_1/object.yaml");
+
+        if let Err(AssetPackError::ChecksumMismatch { entry_path, .. }) = result {
+            assert_eq!("object/character/char_1/object.yaml", entry_path);
+        } else {
+            panic!("Expected `ChecksumMismatch` error, but was: {:?}", result);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_returns_index_truncated_error_when_entry_count_exceeds_pack() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let pack_path = tempdir.path().join("user1.pack");
+        fs::write(&pack_path, pack_header(u32::MAX))?;
+
+        let result = AssetPack::open(&pack_path);
+
+        if let Err(AssetPackError::IndexTruncated { path }) = result {
+            assert_eq!(pack_path, path);
+        } else {
+            panic!("Expected `IndexTruncated` error, but was: {:?}", result);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_returns_index_truncated_error_when_path_length_exceeds_pack() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let pack_path = tempdir.path().join("user1.pack");
+        let mut bytes = pack_header(1);
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 20]);
+        fs::write(&pack_path, bytes)?;
+
+        let result = AssetPack::open(&pack_path);
+
+        if let Err(AssetPackError::IndexTruncated { path }) = result {
+            assert_eq!(pack_path, path);
+        } else {
+            panic!("Expected `IndexTruncated` error, but was: {:?}", result);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_returns_entry_out_of_bounds_error_when_data_exceeds_pack() -> io::Result<()> {
+        let tempdir = tempdir()?;
+        let pack_path = tempdir.path().join("user1.pack");
+        let mut bytes = pack_header(1);
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(b"a");
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        fs::write(&pack_path, bytes)?;
+
+        let result = AssetPack::open(&pack_path);
+
+        if let Err(AssetPackError::EntryOutOfBounds {
+            entry_path,
+            length,
+            pack_len,
+            ..
+        }) = result
+        {
+            assert_eq!("a", entry_path);
+            assert_eq!(u64::MAX, length);
+            assert_eq!(37, pack_len);
+        } else {
+            panic!("Expected `EntryOutOfBounds` error, but was: {:?}", result);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the header of an asset pack with the given entry count.
+    fn pack_header(entry_count: u32) -> Vec<u8> {
+        let mut bytes = b"WPAK".to_vec();
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&entry_count.to_le_bytes());
+        bytes
+    }
+
+    fn write_namespace(namespace_dir: &Path) -> io::Result<()> {
+        let character_dir = namespace_dir.join("object").join("character");
+        fs::create_dir_all(character_dir.join("char_0"))?;
+        fs::create_dir_all(character_dir.join("char_1"))?;
+        fs::write(namespace_dir.join("namespace.yaml"), "version: 0.1.0")?;
+        fs::write(
+            character_dir.join("char_0").join("object.yaml"),
+            "sequences:\n  stand: { frames: [] }\n",
+        )?;
+        fs::write(
+            character_dir.join("char_1").join("object.yaml"),
+            "sequences: {}",
+        )
+    }
+
+    fn io_error(e: AssetPackError) -> io::Error {
+        io::Error::new(io::ErrorKind::Other, e)
+    }
+}
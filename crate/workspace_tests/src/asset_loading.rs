@@ -1,8 +1,13 @@
 mod asset_discovery;
 mod asset_indexer;
 mod asset_indexing_utils;
+mod asset_overrider;
+mod definition_file;
+mod definition_format;
 mod dir_traverse;
 mod flat_indexer;
 mod namespace_discoverer;
+mod namespace_resolver;
+mod object_definition_merger;
 mod object_indexer;
 mod yaml_format;
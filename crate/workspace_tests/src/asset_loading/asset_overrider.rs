@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use asset_model::{
+        config::{AssetIndex, AssetRecord, AssetSlug, AssetType, NamespaceManifest},
+        loaded::OverriddenAssetPaths,
+    };
+    use indexmap::IndexMap;
+    use object_type::ObjectType;
+    use pretty_assertions::assert_eq;
+    use semver::Version;
+
+    use asset_loading::{AssetOverrider, NamespaceDirectory};
+
+    #[test]
+    fn replaces_overridden_asset_path_when_namespace_enabled() {
+        let mut asset_index = asset_index();
+
+        let overridden_asset_paths = AssetOverrider::apply(
+            &mut asset_index,
+            &namespace_directories(),
+            &[String::from("remix")],
+        );
+
+        let mut asset_index_expected = AssetIndex::default();
+        asset_index_expected.insert(
+            AssetType::Object(ObjectType::Character),
+            vec![
+                asset_record("default/will", "remix/will"),
+                asset_record("default/bat", "default/bat"),
+            ],
+        );
+        let mut overridden_asset_paths_expected = HashMap::new();
+        overridden_asset_paths_expected.insert(asset_slug("default/will"), path("default/will"));
+
+        assert_eq!(asset_index_expected, asset_index);
+        assert_eq!(
+            OverriddenAssetPaths::new(overridden_asset_paths_expected),
+            overridden_asset_paths
+        );
+    }
+
+    #[test]
+    fn ignores_overrides_when_namespace_not_enabled() {
+        let mut asset_index = asset_index();
+
+        let overridden_asset_paths =
+            AssetOverrider::apply(&mut asset_index, &namespace_directories(), &[]);
+
+        assert_eq!(self::asset_index(), asset_index);
+        assert_eq!(OverriddenAssetPaths::default(), overridden_asset_paths);
+    }
+
+    fn asset_index() -> AssetIndex {
+        let mut asset_index = AssetIndex::default();
+        asset_index.insert(
+            AssetType::Object(ObjectType::Character),
+            vec![
+                asset_record("default/will", "default/will"),
+                asset_record("default/bat", "default/bat"),
+                asset_record("remix/will", "remix/will"),
+            ],
+        );
+        asset_index
+    }
+
+    fn namespace_directories() -> Vec<NamespaceDirectory> {
+        let mut remix_directory =
+            NamespaceDirectory::new(String::from("remix"), PathBuf::from("remix"));
+        remix_directory.manifest = Some(NamespaceManifest::new(
+            None,
+            Version::new(1, 0, 0),
+            Vec::new(),
+            IndexMap::new(),
+            vec![asset_slug("default/will")],
+        ));
+
+        vec![
+            NamespaceDirectory::new(String::from("default"), PathBuf::from("default")),
+            remix_directory,
+        ]
+    }
+
+    fn asset_record(slug: &str, asset_path: &str) -> AssetRecord {
+        AssetRecord::new(asset_slug(slug), path(asset_path))
+    }
+
+    fn asset_slug(slug: &str) -> AssetSlug {
+        slug.parse().expect("Expected asset slug to be valid.")
+    }
+
+    fn path(asset_path: &str) -> PathBuf {
+        PathBuf::from(asset_path)
+    }
+}
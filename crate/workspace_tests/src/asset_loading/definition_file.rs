@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, io};
+
+    use application::Format;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use asset_loading::DefinitionFile;
+
+    #[test]
+    fn find_returns_definition_file_in_any_format() -> io::Result<()> {
+        let asset_dir = tempdir()?;
+        let definition_path = asset_dir.path().join("object.ron");
+        fs::write(&definition_path, "()")?;
+
+        assert_eq!(
+            Some(DefinitionFile {
+                path: definition_path,
+                format: Format::Ron,
+            }),
+            DefinitionFile::find(asset_dir.path(), "object")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_prefers_yaml_when_multiple_formats_exist() -> io::Result<()> {
+        let asset_dir = tempdir()?;
+        let definition_path = asset_dir.path().join("object.yaml");
+        fs::write(&definition_path, "{}")?;
+        fs::write(asset_dir.path().join("object.json"), "{}")?;
+
+        assert_eq!(
+            Some(DefinitionFile {
+                path: definition_path,
+                format: Format::Yaml,
+            }),
+            DefinitionFile::find(asset_dir.path(), "object")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_returns_none_when_definition_does_not_exist() -> io::Result<()> {
+        let asset_dir = tempdir()?;
+        fs::write(asset_dir.path().join("sprites.yaml"), "{}")?;
+
+        assert_eq!(None, DefinitionFile::find(asset_dir.path(), "object"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_or_default_returns_yaml_path_when_definition_does_not_exist() -> io::Result<()> {
+        let asset_dir = tempdir()?;
+
+        assert_eq!(
+            DefinitionFile {
+                path: asset_dir.path().join("map.yaml"),
+                format: Format::Yaml,
+            },
+            DefinitionFile::find_or_default(asset_dir.path(), "map")
+        );
+
+        Ok(())
+    }
+}
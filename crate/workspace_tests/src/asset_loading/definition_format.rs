@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Debug, fs, path::Path};
+
+    use amethyst::{assets::Format as AmethystFormat, Error};
+    use application::Format;
+    use assets_test::{
+        CHAR_BAT_PATH, ENERGY_SQUARE_PATH, MAP_EMPTY_PATH, MAP_FADE_PATH, NAMESPACE_TEST_PATH,
+        UI_CHARACTER_SELECTION_PATH, UI_LOADING_PATH, UI_PAUSE_MENU_PATH,
+    };
+    use background_model::config::BackgroundDefinition;
+    use character_model::config::CharacterDefinition;
+    use energy_model::config::EnergyDefinition;
+    use map_model::config::MapDefinition;
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+    use sprite_model::config::SpritesDefinition;
+    use ui_model::config::UiDefinition;
+
+    use asset_loading::{DefinitionFile, DefinitionFormat};
+
+    #[test]
+    fn character_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        round_trip::<CharacterDefinition>(&CHAR_BAT_PATH, "object")
+    }
+
+    #[test]
+    fn energy_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        round_trip::<EnergyDefinition>(&ENERGY_SQUARE_PATH, "object")
+    }
+
+    #[test]
+    fn sprites_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        [
+            &*CHAR_BAT_PATH,
+            &*ENERGY_SQUARE_PATH,
+            &*MAP_FADE_PATH,
+            &*UI_CHARACTER_SELECTION_PATH,
+        ]
+        .iter()
+        .try_for_each(|asset_dir| round_trip::<SpritesDefinition>(asset_dir, "sprites"))
+    }
+
+    #[test]
+    fn map_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        round_trip::<MapDefinition>(&MAP_EMPTY_PATH, "map")?;
+        round_trip::<MapDefinition>(&MAP_FADE_PATH, "map")
+    }
+
+    #[test]
+    fn background_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        round_trip::<BackgroundDefinition>(&UI_CHARACTER_SELECTION_PATH, "background")?;
+        round_trip::<BackgroundDefinition>(&UI_LOADING_PATH, "background")
+    }
+
+    #[test]
+    fn ui_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        let asset_dir = NAMESPACE_TEST_PATH.join("ui").join("game_mode_selection");
+        round_trip::<UiDefinition>(&asset_dir, "ui")
+    }
+
+    #[test]
+    fn pause_menu_ui_definition_round_trips_through_ron_and_json() -> Result<(), Error> {
+        round_trip::<UiDefinition>(&UI_PAUSE_MENU_PATH, "ui")
+    }
+
+    /// Loads the YAML definition, and asserts it is unchanged after a RON and JSON round trip.
+    fn round_trip<D>(asset_dir: &Path, stem: &str) -> Result<(), Error>
+    where
+        D: Debug + PartialEq + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let definition_file = DefinitionFile::find(asset_dir, stem).unwrap_or_else(|| {
+            panic!(
+                "Expected `{}` definition to exist in `{}`.",
+                stem,
+                asset_dir.display()
+            )
+        });
+        assert_eq!(Format::Yaml, definition_file.format);
+
+        let definition = load::<D>(Format::Yaml, fs::read(&definition_file.path)?)?;
+
+        let definition_ron = ron::ser::to_string(&definition)?.into_bytes();
+        assert_eq!(definition, load::<D>(Format::Ron, definition_ron)?);
+
+        let definition_json = serde_json::to_vec(&definition)?;
+        assert_eq!(definition, load::<D>(Format::Json, definition_json)?);
+
+        Ok(())
+    }
+
+    fn load<D>(format: Format, bytes: Vec<u8>) -> Result<D, Error>
+    where
+        D: for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        AmethystFormat::<D>::import_simple(&DefinitionFormat::new(format), bytes)
+    }
+}
@@ -2,12 +2,16 @@
 mod tests {
     use std::{fs, io};
 
+    use asset_fs::AssetPackWriter;
+    use asset_model::config::NamespaceManifest;
     use hamcrest::prelude::*;
+    use indexmap::IndexMap;
+    use semver::{Version, VersionReq};
     use tempfile::tempdir;
 
     use asset_loading::{
         NamespaceDirectory, NamespaceDiscoverer, ASSETS_DEFAULT_DIR, ASSETS_DOWNLOAD_DIR,
-        ASSETS_TEST_DIR,
+        ASSETS_TEST_DIR, NAMESPACE_MANIFEST_FILE,
     };
 
     #[test]
@@ -45,4 +49,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn reads_namespace_manifest_and_skips_namespaces_with_invalid_manifest() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+
+        let default_dir = assets_dir.join(ASSETS_DEFAULT_DIR);
+        let download_dir = assets_dir.join(ASSETS_DOWNLOAD_DIR);
+        let user1_dir = download_dir.join("user1");
+        let user2_dir = download_dir.join("user2");
+        [&default_dir, &download_dir, &user1_dir, &user2_dir]
+            .iter()
+            .fold(Ok(()), |result, dir| {
+                result.and_then(|_| fs::create_dir(&dir))
+            })?;
+        fs::write(
+            user1_dir.join(NAMESPACE_MANIFEST_FILE),
+            "\
+name: User One
+version: 1.2.0
+authors: [user1]
+dependencies:
+  default: \"*\"
+",
+        )?;
+        fs::write(user2_dir.join(NAMESPACE_MANIFEST_FILE), "version: abc")?;
+
+        let mut dependencies = IndexMap::new();
+        dependencies.insert(
+            String::from(ASSETS_DEFAULT_DIR),
+            VersionReq::parse("*").expect("Expected version requirement to be valid."),
+        );
+        let manifest = NamespaceManifest::new(
+            Some(String::from("User One")),
+            Version::new(1, 2, 0),
+            vec![String::from("user1")],
+            dependencies,
+            Vec::new(),
+        );
+        let mut user1_namespace_directory = NamespaceDirectory::new("user1".to_string(), user1_dir);
+        user1_namespace_directory.manifest = Some(manifest);
+
+        assert_that!(
+            &NamespaceDiscoverer::discover(&assets_dir),
+            contains(vec![
+                NamespaceDirectory::new(ASSETS_DEFAULT_DIR.to_string(), default_dir),
+                user1_namespace_directory,
+            ])
+            .exactly()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn discovers_packed_namespaces_and_reads_their_manifest() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let source_tempdir = tempdir()?;
+        let source_dir = source_tempdir.path();
+
+        let download_dir = assets_dir.join(ASSETS_DOWNLOAD_DIR);
+        let user1_dir = download_dir.join("user1");
+        let user2_pack = download_dir.join("user2.pack");
+        fs::create_dir_all(&user1_dir)?;
+        fs::write(
+            source_dir.join(NAMESPACE_MANIFEST_FILE),
+            "version: 0.3.0\nauthors: [user2]\n",
+        )?;
+        AssetPackWriter::write(source_dir, &user2_pack)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        let manifest = NamespaceManifest::new(
+            None,
+            Version::new(0, 3, 0),
+            vec![String::from("user2")],
+            IndexMap::new(),
+            Vec::new(),
+        );
+        let mut user2_namespace_directory =
+            NamespaceDirectory::new("user2".to_string(), user2_pack);
+        user2_namespace_directory.manifest = Some(manifest);
+
+        assert_that!(
+            &NamespaceDiscoverer::discover(&assets_dir),
+            contains(vec![
+                NamespaceDirectory::new("user1".to_string(), user1_dir),
+                user2_namespace_directory,
+            ])
+            .exactly()
+        );
+
+        Ok(())
+    }
 }
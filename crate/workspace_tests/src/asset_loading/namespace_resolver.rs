@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use asset_model::config::NamespaceManifest;
+    use indexmap::IndexMap;
+    use pretty_assertions::assert_eq;
+    use semver::{Version, VersionReq};
+
+    use asset_loading::{
+        NamespaceDirectory, NamespaceResolution, NamespaceResolutionError, NamespaceResolver,
+    };
+
+    #[test]
+    fn orders_namespaces_after_their_dependencies() {
+        let namespace_directories = vec![
+            namespace_directory("a", Some(("1.0.0", &[("b", "^1"), ("c", "*")]))),
+            namespace_directory("b", Some(("1.3.0", &[("c", "*")]))),
+            namespace_directory("c", None),
+            namespace_directory("d", None),
+        ];
+
+        assert_eq!(
+            NamespaceResolution::new(
+                vec![
+                    namespace_directories[2].clone(),
+                    namespace_directories[1].clone(),
+                    namespace_directories[0].clone(),
+                    namespace_directories[3].clone(),
+                ],
+                vec![],
+            ),
+            NamespaceResolver::resolve(namespace_directories)
+        );
+    }
+
+    #[test]
+    fn refuses_namespaces_with_missing_dependencies_and_their_dependents() {
+        let namespace_directories = vec![
+            namespace_directory("a", Some(("1.0.0", &[("missing", "^1")]))),
+            namespace_directory("b", Some(("1.0.0", &[("a", "*")]))),
+            namespace_directory("c", None),
+        ];
+
+        assert_eq!(
+            NamespaceResolution::new(
+                vec![namespace_directories[2].clone()],
+                vec![
+                    NamespaceResolutionError::DependencyMissing {
+                        namespace: String::from("a"),
+                        dependency: String::from("missing"),
+                        version_req: version_req("^1"),
+                    },
+                    NamespaceResolutionError::DependencyRefused {
+                        namespace: String::from("b"),
+                        dependency: String::from("a"),
+                    },
+                ],
+            ),
+            NamespaceResolver::resolve(namespace_directories)
+        );
+    }
+
+    #[test]
+    fn refuses_namespaces_with_mismatched_dependency_versions() {
+        let namespace_directories = vec![
+            namespace_directory("a", Some(("1.0.0", &[("b", "^2")]))),
+            namespace_directory("b", Some(("1.5.0", &[]))),
+            namespace_directory("c", Some(("1.0.0", &[("d", "^1")]))),
+            namespace_directory("d", None),
+        ];
+
+        assert_eq!(
+            NamespaceResolution::new(
+                vec![
+                    namespace_directories[1].clone(),
+                    namespace_directories[3].clone(),
+                ],
+                vec![
+                    NamespaceResolutionError::DependencyVersionMismatch {
+                        namespace: String::from("a"),
+                        dependency: String::from("b"),
+                        version_req: version_req("^2"),
+                        version: Some(Version::new(1, 5, 0)),
+                    },
+                    NamespaceResolutionError::DependencyVersionMismatch {
+                        namespace: String::from("c"),
+                        dependency: String::from("d"),
+                        version_req: version_req("^1"),
+                        version: None,
+                    },
+                ],
+            ),
+            NamespaceResolver::resolve(namespace_directories)
+        );
+    }
+
+    #[test]
+    fn refuses_namespaces_in_dependency_cycle() {
+        let namespace_directories = vec![
+            namespace_directory("a", Some(("1.0.0", &[("b", "*")]))),
+            namespace_directory("b", Some(("1.0.0", &[("a", "*")]))),
+            namespace_directory("c", Some(("1.0.0", &[("a", "*")]))),
+            namespace_directory("d", None),
+        ];
+
+        assert_eq!(
+            NamespaceResolution::new(
+                vec![namespace_directories[3].clone()],
+                vec![
+                    NamespaceResolutionError::DependencyCycle {
+                        namespace: String::from("a"),
+                    },
+                    NamespaceResolutionError::DependencyCycle {
+                        namespace: String::from("b"),
+                    },
+                    NamespaceResolutionError::DependencyRefused {
+                        namespace: String::from("c"),
+                        dependency: String::from("a"),
+                    },
+                ],
+            ),
+            NamespaceResolver::resolve(namespace_directories)
+        );
+    }
+
+    fn namespace_directory(
+        namespace: &str,
+        manifest: Option<(&str, &[(&str, &str)])>,
+    ) -> NamespaceDirectory {
+        let mut namespace_directory =
+            NamespaceDirectory::new(namespace.to_string(), PathBuf::from(namespace));
+        namespace_directory.manifest = manifest.map(|(version, dependencies)| {
+            let dependencies = dependencies
+                .iter()
+                .map(|(dependency, req)| (dependency.to_string(), version_req(req)))
+                .collect::<IndexMap<_, _>>();
+            NamespaceManifest::new(
+                None,
+                Version::parse(version).expect("Expected version to be valid."),
+                Vec::new(),
+                dependencies,
+                Vec::new(),
+            )
+        });
+        namespace_directory
+    }
+
+    fn version_req(req: &str) -> VersionReq {
+        VersionReq::parse(req).expect("Expected version requirement to be valid.")
+    }
+}
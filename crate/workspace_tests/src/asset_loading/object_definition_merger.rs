@@ -0,0 +1,313 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        fs, io,
+        path::{Path, PathBuf},
+    };
+
+    use asset_model::config::AssetSlug;
+    use pretty_assertions::assert_eq;
+    use serde_yaml::Value;
+    use tempfile::tempdir;
+
+    use asset_loading::{ObjectDefinitionMergeError, ObjectDefinitionMerger};
+
+    #[test]
+    fn merge_replaces_top_level_fields_and_sequences() {
+        let parent = yaml(
+            "\
+extends: default/base
+acceleration: 1
+sequences:
+  stand: { next: walk, frames: [{ wait: 1 }] }
+  walk: { frames: [{ wait: 2 }] }
+",
+        );
+        let child = yaml(
+            "\
+extends: default/will
+acceleration: 2
+sequences:
+  stand: { frames: [{ wait: 3 }] }
+  run: { frames: [{ wait: 4 }] }
+",
+        );
+
+        assert_eq!(
+            yaml(
+                "\
+acceleration: 2
+sequences:
+  stand: { frames: [{ wait: 3 }] }
+  walk: { frames: [{ wait: 2 }] }
+  run: { frames: [{ wait: 4 }] }
+"
+            ),
+            ObjectDefinitionMerger::merge(parent, child)
+        );
+    }
+
+    #[test]
+    fn merge_merges_sequence_fields_when_sequence_merge_is_true() {
+        let parent = yaml(
+            "\
+sequences:
+  stand:
+    next: walk
+    frames: [{ wait: 1 }]
+    input_reactions: { press_attack: attack, press_jump: jump }
+",
+        );
+        let child = yaml(
+            "\
+sequences:
+  stand:
+    merge: true
+    next: stand
+    input_reactions: { press_attack: stand_attack_2 }
+",
+        );
+
+        assert_eq!(
+            yaml(
+                "\
+sequences:
+  stand:
+    next: stand
+    frames: [{ wait: 1 }]
+    input_reactions: { press_attack: stand_attack_2, press_jump: jump }
+"
+            ),
+            ObjectDefinitionMerger::merge(parent, child)
+        );
+    }
+
+    #[test]
+    fn resolve_returns_none_when_definition_does_not_extend() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let will_dir = write_definition(assets_tempdir.path(), "will", "sequences: {}")?;
+
+        assert_eq!(
+            Ok(None),
+            ObjectDefinitionMerger::resolve(&will_dir.join("object.yaml"), |_| None)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_merges_definitions_along_extends_chain() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let base_dir = write_definition(
+            assets_dir,
+            "base",
+            "sequences: { stand: { frames: [{ wait: 1 }] } }",
+        )?;
+        let will_dir = write_definition(
+            assets_dir,
+            "will",
+            "extends: test/base\nsequences: { walk: { frames: [{ wait: 2 }] } }",
+        )?;
+        let remix_dir = write_definition(
+            assets_dir,
+            "remix",
+            "extends: test/will\nsequences: { run: { frames: [{ wait: 3 }] } }",
+        )?;
+        let asset_dirs = asset_dirs(&[("test/base", base_dir), ("test/will", will_dir)]);
+
+        assert_eq!(
+            Ok(Some(yaml(
+                "\
+sequences:
+  stand: { frames: [{ wait: 1 }] }
+  walk: { frames: [{ wait: 2 }] }
+  run: { frames: [{ wait: 3 }] }
+"
+            ))),
+            ObjectDefinitionMerger::resolve(&remix_dir.join("object.yaml"), |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_merges_definitions_written_in_different_formats() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let base_dir = assets_dir.join("base");
+        fs::create_dir_all(&base_dir)?;
+        fs::write(
+            base_dir.join("object.json"),
+            r#"{ "sequences": { "stand": { "frames": [{ "wait": 1 }] } } }"#,
+        )?;
+        let will_dir = write_definition(
+            assets_dir,
+            "will",
+            "extends: test/base\nsequences: { walk: { frames: [{ wait: 2 }] } }",
+        )?;
+        let asset_dirs = asset_dirs(&[("test/base", base_dir)]);
+
+        assert_eq!(
+            Ok(Some(yaml(
+                "\
+sequences:
+  stand: { frames: [{ wait: 1 }] }
+  walk: { frames: [{ wait: 2 }] }
+"
+            ))),
+            ObjectDefinitionMerger::resolve(&will_dir.join("object.yaml"), |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_returns_error_when_parent_not_found() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let will_dir = write_definition(
+            assets_tempdir.path(),
+            "will",
+            "extends: test/missing\nsequences: {}",
+        )?;
+        let definition_path = will_dir.join("object.yaml");
+
+        assert_eq!(
+            Err(ObjectDefinitionMergeError::ParentNotFound {
+                path: definition_path.clone(),
+                asset_slug: asset_slug("test/missing"),
+            }),
+            ObjectDefinitionMerger::resolve(&definition_path, |_| None)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_returns_error_when_extends_cycle() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let will_dir = write_definition(assets_dir, "will", "extends: test/bat\nsequences: {}")?;
+        let bat_dir = write_definition(assets_dir, "bat", "extends: test/will\nsequences: {}")?;
+        let asset_dirs = asset_dirs(&[("test/will", will_dir.clone()), ("test/bat", bat_dir)]);
+
+        assert_eq!(
+            Err(ObjectDefinitionMergeError::ExtendsCycle {
+                path: asset_dirs[&asset_slug("test/bat")].join("object.yaml"),
+                asset_slug: asset_slug("test/will"),
+            }),
+            ObjectDefinitionMerger::resolve(&will_dir.join("object.yaml"), |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ancestor_dirs_returns_extended_asset_dirs_nearest_first() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let base_dir = write_definition(assets_dir, "base", "sequences: {}")?;
+        let will_dir = write_definition(assets_dir, "will", "extends: test/base\nsequences: {}")?;
+        let remix_dir = write_definition(assets_dir, "remix", "extends: test/will\nsequences: {}")?;
+        let asset_dirs = asset_dirs(&[
+            ("test/base", base_dir.clone()),
+            ("test/will", will_dir.clone()),
+        ]);
+
+        assert_eq!(
+            Ok(vec![will_dir, base_dir]),
+            ObjectDefinitionMerger::ancestor_dirs(&remix_dir.join("object.yaml"), |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sprites_dir_returns_asset_dir_when_asset_has_sprites_definition() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let base_dir = write_definition(assets_dir, "base", "sequences: {}")?;
+        let will_dir = write_definition(assets_dir, "will", "extends: test/base\nsequences: {}")?;
+        fs::write(base_dir.join("sprites.yaml"), "sheets: []")?;
+        fs::write(will_dir.join("sprites.yaml"), "sheets: []")?;
+        let asset_dirs = asset_dirs(&[("test/base", base_dir)]);
+
+        assert_eq!(
+            Ok(Some(will_dir.clone())),
+            ObjectDefinitionMerger::sprites_dir(&will_dir, |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sprites_dir_returns_nearest_ancestor_dir_with_sprites_definition() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let base_dir = write_definition(assets_dir, "base", "sequences: {}")?;
+        let will_dir = write_definition(assets_dir, "will", "extends: test/base\nsequences: {}")?;
+        let remix_dir = write_definition(assets_dir, "remix", "extends: test/will\nsequences: {}")?;
+        fs::write(base_dir.join("sprites.yaml"), "sheets: []")?;
+        let asset_dirs = asset_dirs(&[("test/base", base_dir.clone()), ("test/will", will_dir)]);
+
+        assert_eq!(
+            Ok(Some(base_dir)),
+            ObjectDefinitionMerger::sprites_dir(&remix_dir, |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sprites_dir_returns_none_when_no_asset_has_sprites_definition() -> io::Result<()> {
+        let assets_tempdir = tempdir()?;
+        let assets_dir = assets_tempdir.path();
+        let base_dir = write_definition(assets_dir, "base", "sequences: {}")?;
+        let will_dir = write_definition(assets_dir, "will", "extends: test/base\nsequences: {}")?;
+        let asset_dirs = asset_dirs(&[("test/base", base_dir)]);
+
+        assert_eq!(
+            Ok(None),
+            ObjectDefinitionMerger::sprites_dir(&will_dir, |asset_slug| {
+                asset_dirs.get(asset_slug).cloned()
+            })
+        );
+
+        Ok(())
+    }
+
+    fn write_definition(assets_dir: &Path, name: &str, contents: &str) -> io::Result<PathBuf> {
+        let asset_dir = assets_dir.join(name);
+        fs::create_dir_all(&asset_dir)?;
+        fs::write(asset_dir.join("object.yaml"), contents)?;
+        Ok(asset_dir)
+    }
+
+    fn asset_dirs(asset_dirs: &[(&str, PathBuf)]) -> HashMap<AssetSlug, PathBuf> {
+        asset_dirs
+            .iter()
+            .map(|(slug, asset_dir)| (asset_slug(slug), asset_dir.clone()))
+            .collect::<HashMap<_, _>>()
+    }
+
+    fn asset_slug(slug: &str) -> AssetSlug {
+        slug.parse().expect("Expected asset slug to be valid.")
+    }
+
+    fn yaml(contents: &str) -> Value {
+        serde_yaml::from_str(contents).expect("Expected YAML to be valid.")
+    }
+}
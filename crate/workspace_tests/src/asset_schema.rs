@@ -0,0 +1,2 @@
+mod object_schema;
+mod schema_gen;
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use asset_schema::{serde_json::json, ObjectSchema};
+
+    #[test]
+    fn into_schema_denies_unknown_fields() {
+        let schema = ObjectSchema::new(true)
+            .property("a", json!({ "type": "string" }), Some("Field a."), true)
+            .property("b", json!({ "type": "integer" }), None, false)
+            .into_schema();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "a": { "type": "string", "description": "Field a." },
+                    "b": { "type": "integer" },
+                },
+                "required": ["a"],
+                "additionalProperties": false,
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn flatten_merges_object_properties() {
+        let flattened = ObjectSchema::new(true)
+            .property("b", json!({ "type": "integer" }), None, true)
+            .into_schema();
+
+        let schema = ObjectSchema::new(true)
+            .property("a", json!({ "type": "string" }), None, true)
+            .flatten(flattened)
+            .into_schema();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "a": { "type": "string" },
+                    "b": { "type": "integer" },
+                },
+                "required": ["a", "b"],
+                "additionalProperties": false,
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn flatten_map_allows_additional_properties() {
+        let flattened = json!({
+            "type": "object",
+            "additionalProperties": { "type": "integer" },
+            "propertyNames": { "type": "string" },
+        });
+
+        let schema = ObjectSchema::new(true)
+            .property("a", json!({ "type": "string" }), None, false)
+            .flatten(flattened)
+            .into_schema();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": { "a": { "type": "string" } },
+                "additionalProperties": { "type": "integer" },
+                "propertyNames": { "type": "string" },
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn flatten_enum_adds_to_all_of_and_allows_unknown_fields() {
+        let flattened = json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": { "x": { "type": "integer" } },
+                    "required": ["x"],
+                    "additionalProperties": false,
+                },
+                { "const": "y" },
+            ],
+        });
+
+        let schema = ObjectSchema::new(true)
+            .property("a", json!({ "type": "string" }), None, false)
+            .flatten(flattened)
+            .into_schema();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": { "a": { "type": "string" } },
+                "allOf": [{
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": { "x": { "type": "integer" } },
+                            "required": ["x"],
+                        },
+                        { "const": "y" },
+                    ],
+                }],
+            }),
+            schema
+        );
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use asset_schema::{
+        serde_json::{json, Value},
+        AssetSchema, SchemaGen, JSON_SCHEMA_DRAFT,
+    };
+
+    #[test]
+    fn subschema_references_named_type_definition() {
+        let mut schema_gen = SchemaGen::new();
+
+        let first = schema_gen.subschema::<Named>();
+        let second = schema_gen.subschema::<Named>();
+        let root_schema = schema_gen.root_schema::<Vec<Named>>();
+
+        assert_eq!(json!({ "$ref": "#/definitions/Named" }), first);
+        assert_eq!(first, second);
+        assert_eq!(
+            json!({ "type": "integer", "minimum": 0 }),
+            root_schema["definitions"]["Named"]
+        );
+    }
+
+    #[test]
+    fn subschema_inlines_unnamed_type() {
+        let mut schema_gen = SchemaGen::new();
+
+        let schema = schema_gen.subschema::<Option<String>>();
+
+        assert_eq!(
+            json!({ "anyOf": [{ "type": "string" }, { "type": "null" }] }),
+            schema
+        );
+    }
+
+    #[test]
+    fn subschema_suffixes_definition_name_when_types_share_name() {
+        let mut schema_gen = SchemaGen::new();
+
+        let named = schema_gen.subschema::<Named>();
+        let named_other = schema_gen.subschema::<other::Named>();
+
+        assert_eq!(json!({ "$ref": "#/definitions/Named" }), named);
+        assert_eq!(json!({ "$ref": "#/definitions/Named2" }), named_other);
+    }
+
+    #[test]
+    fn inline_schema_resolves_reference() {
+        let mut schema_gen = SchemaGen::new();
+
+        let schema = schema_gen.inline_schema::<Wrapper>();
+
+        assert_eq!(json!({ "type": "integer", "minimum": 0 }), schema);
+    }
+
+    #[test]
+    fn root_schema_inlines_root_type_and_includes_definitions() {
+        let root_schema = SchemaGen::new().root_schema::<Wrapper>();
+
+        assert_eq!(
+            json!({
+                "$schema": JSON_SCHEMA_DRAFT,
+                "title": "Wrapper",
+                "type": "integer",
+                "minimum": 0,
+                "definitions": {
+                    "Named": { "type": "integer", "minimum": 0 },
+                },
+            }),
+            root_schema
+        );
+    }
+
+    #[derive(Debug)]
+    struct Named;
+
+    impl AssetSchema for Named {
+        fn schema_name() -> Option<String> {
+            Some(String::from("Named"))
+        }
+
+        fn schema(schema_gen: &mut SchemaGen) -> Value {
+            schema_gen.subschema::<u32>()
+        }
+    }
+
+    #[derive(Debug)]
+    struct Wrapper;
+
+    impl AssetSchema for Wrapper {
+        fn schema_name() -> Option<String> {
+            Some(String::from("Wrapper"))
+        }
+
+        fn schema(schema_gen: &mut SchemaGen) -> Value {
+            schema_gen.subschema::<Named>()
+        }
+    }
+
+    mod other {
+        use asset_schema::{
+            serde_json::{json, Value},
+            AssetSchema, SchemaGen,
+        };
+
+        #[derive(Debug)]
+        pub struct Named;
+
+        impl AssetSchema for Named {
+            fn schema_name() -> Option<String> {
+                Some(String::from("Named"))
+            }
+
+            fn schema(_schema_gen: &mut SchemaGen) -> Value {
+                json!({ "type": "string" })
+            }
+        }
+    }
+}
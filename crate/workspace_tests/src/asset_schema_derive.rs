@@ -0,0 +1,273 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, marker::PhantomData};
+
+    use asset_schema::{serde_json::json, AssetSchema, SchemaGen};
+    use derivative::Derivative;
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn struct_fields_use_serde_attributes() {
+        let schema = SchemaGen::new().inline_schema::<Widget>();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "description": "Widget on screen.",
+                "properties": {
+                    "name": { "type": "string", "description": "Name of the widget." },
+                    "position_x": { "type": "integer" },
+                    "width": { "type": "integer", "minimum": 0 },
+                    "label": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+                    "tags": {
+                        "type": "object",
+                        "additionalProperties": { "type": "integer", "minimum": 0 },
+                        "propertyNames": { "type": "string" },
+                    },
+                },
+                "required": ["name", "position_x"],
+                "additionalProperties": false,
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn container_default_makes_fields_optional() {
+        let schema = SchemaGen::new().inline_schema::<Defaulted>();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": { "value": { "type": "number" } },
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn enum_variants_are_externally_tagged() {
+        let schema = SchemaGen::new().inline_schema::<Shape>();
+
+        assert_eq!(
+            json!({
+                "oneOf": [
+                    { "const": "point", "description": "No area." },
+                    {
+                        "type": "object",
+                        "properties": { "circle": { "type": "number" } },
+                        "required": ["circle"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "rect": {
+                                "type": "array",
+                                "items": [{ "type": "number" }, { "type": "number" }],
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                        },
+                        "required": ["rect"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "polygon": {
+                                "type": "object",
+                                "properties": { "sides": { "type": "integer", "minimum": 0 } },
+                                "required": ["sides"],
+                                "additionalProperties": false,
+                            },
+                        },
+                        "required": ["polygon"],
+                        "additionalProperties": false,
+                    },
+                ],
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn untagged_enum_variants_are_any_of_contents() {
+        let schema = SchemaGen::new().inline_schema::<Amount>();
+
+        assert_eq!(
+            json!({
+                "anyOf": [
+                    { "type": "integer", "minimum": 0 },
+                    { "type": "string" },
+                ],
+            }),
+            schema
+        );
+    }
+
+    #[test]
+    fn enum_without_variants_matches_nothing() {
+        let schema = SchemaGen::new().inline_schema::<Unused>();
+
+        assert_eq!(json!({ "not": {} }), schema);
+    }
+
+    #[test]
+    fn flatten_merges_fields_and_references_named_types() {
+        let root_schema = SchemaGen::new().root_schema::<Outer>();
+
+        assert_eq!(
+            json!({ "$ref": "#/definitions/Shape" }),
+            root_schema["properties"]["shape"]
+        );
+        assert_eq!(
+            json!({ "type": "number" }),
+            root_schema["properties"]["value"]
+        );
+        assert_eq!(json!(["shape"]), root_schema["required"]);
+        assert_eq!(json!(false), root_schema["additionalProperties"]);
+        assert!(root_schema["definitions"]["Shape"].is_object());
+    }
+
+    #[test]
+    fn transparent_newtype_and_from_use_inner_schema() {
+        let mut schema_gen = SchemaGen::new();
+
+        assert_eq!(
+            json!({ "type": "array", "items": { "type": "string" } }),
+            schema_gen.inline_schema::<Names>()
+        );
+        assert_eq!(
+            json!({ "type": "integer", "minimum": 0, "description": "Number of frames." }),
+            schema_gen.inline_schema::<Frames>()
+        );
+        assert_eq!(
+            json!({ "type": "string" }),
+            schema_gen.inline_schema::<FromString>()
+        );
+    }
+
+    #[test]
+    fn with_attribute_overrides_field_schema() {
+        let schema = SchemaGen::new().inline_schema::<Overridden>();
+
+        assert_eq!(json!({ "type": "string" }), schema["properties"]["version"]);
+    }
+
+    #[test]
+    fn generic_types_are_inlined() {
+        let root_schema = SchemaGen::new().root_schema::<Holder>();
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": { "value": { "type": "boolean" } },
+                "required": ["value"],
+            }),
+            root_schema["properties"]["generic"]
+        );
+        assert!(root_schema.get("definitions").is_none());
+    }
+
+    /// Widget on screen.
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    #[serde(deny_unknown_fields)]
+    struct Widget {
+        /// Name of the widget.
+        name: String,
+        #[serde(rename = "position_x")]
+        x: i32,
+        #[serde(default)]
+        width: u32,
+        label: Option<String>,
+        #[serde(default)]
+        tags: HashMap<String, u32>,
+        #[serde(skip)]
+        marker: PhantomData<u8>,
+    }
+
+    #[derive(AssetSchema, Debug, Default, Deserialize, Serialize)]
+    #[serde(default)]
+    struct Defaulted {
+        value: f32,
+    }
+
+    #[derive(AssetSchema, Debug, Derivative, Deserialize, Serialize)]
+    #[derivative(Default)]
+    #[serde(deny_unknown_fields, rename_all = "snake_case")]
+    enum Shape {
+        /// No area.
+        Point,
+        Circle(f32),
+        Rect(f32, f32),
+        Polygon {
+            sides: u32,
+        },
+        #[derivative(Default)]
+        #[serde(skip)]
+        Hidden,
+    }
+
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Count(u32),
+        Named(String),
+    }
+
+    #[derive(AssetSchema, Debug, Derivative, Deserialize, Serialize)]
+    #[derivative(Default)]
+    enum Unused {
+        #[derivative(Default)]
+        #[serde(skip)]
+        Unused,
+    }
+
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    #[serde(deny_unknown_fields)]
+    struct Outer {
+        shape: Shape,
+        #[serde(flatten)]
+        defaulted: Defaulted,
+    }
+
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct Names(Vec<String>);
+
+    /// Number of frames.
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    struct Frames(u32);
+
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    #[serde(from = "String")]
+    struct FromString(String);
+
+    impl From<String> for FromString {
+        fn from(string: String) -> Self {
+            FromString(string)
+        }
+    }
+
+    #[derive(AssetSchema, Debug)]
+    struct Overridden {
+        #[asset_schema(with = "String")]
+        version: PhantomData<Version>,
+    }
+
+    #[derive(Debug)]
+    struct Version;
+
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    struct Generic<T> {
+        value: T,
+        #[serde(skip)]
+        marker: PhantomData<T>,
+    }
+
+    #[derive(AssetSchema, Debug, Deserialize, Serialize)]
+    struct Holder {
+        generic: Generic<bool>,
+    }
+}
@@ -0,0 +1 @@
+mod asset_schema_gen;
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use asset_schema::{
+        serde_json::{self, Value},
+        JSON_SCHEMA_DRAFT,
+    };
+    use asset_schema_gen::AssetSchemaGen;
+    use jsonschema::JSONSchema;
+
+    const CHARACTER_DEFINITION_YAML: &str = r#"
+sequences:
+  stand:
+    frames: []
+"#;
+    const CHARACTER_DEFINITION_EXTENDING_YAML: &str = r#"
+extends: default/will
+charge_limit: 100
+sequences:
+  stand:
+    merge: true
+    frames: []
+"#;
+
+    #[test]
+    fn schemas_are_generated_for_each_asset_definition() {
+        let schemas = AssetSchemaGen::schemas();
+
+        let names = schemas.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "background",
+                "character",
+                "collision_audio",
+                "energy",
+                "item",
+                "map",
+                "namespace",
+                "sprites",
+                "survival_waves",
+                "ui",
+                "ui_audio",
+            ],
+            names
+        );
+        schemas.iter().for_each(|(name, schema)| {
+            assert_eq!(
+                Some(JSON_SCHEMA_DRAFT),
+                schema["$schema"].as_str(),
+                "Expected `{}` schema to declare the draft.",
+                name
+            );
+        });
+    }
+
+    #[test]
+    fn character_schema_defines_custom_serialized_types() {
+        let schemas = AssetSchemaGen::schemas();
+        let (_, character_schema) = schemas
+            .iter()
+            .find(|(name, _)| *name == "character")
+            .expect("Expected `character` schema to exist.");
+
+        let definitions = character_schema["definitions"]
+            .as_object()
+            .expect("Expected `character` schema to have definitions.");
+        assert!(definitions.contains_key("HitLimit"));
+        assert!(definitions.contains_key("ObjectAccelerationValue"));
+    }
+
+    #[test]
+    fn character_schema_accepts_definition_without_extends() {
+        assert!(is_valid("character", CHARACTER_DEFINITION_YAML));
+    }
+
+    #[test]
+    fn character_schema_rejects_definition_without_extends_or_sequences() {
+        assert!(!is_valid("character", "charge_limit: 100"));
+    }
+
+    #[test]
+    fn character_schema_accepts_extending_definition() {
+        assert!(is_valid("character", CHARACTER_DEFINITION_EXTENDING_YAML));
+    }
+
+    #[test]
+    fn energy_and_item_schemas_accept_extending_definition() {
+        assert!(is_valid("energy", "extends: default/fireball"));
+        assert!(is_valid("item", "extends: default/fireball"));
+    }
+
+    #[test]
+    fn write_writes_schema_files_to_output_dir() -> Result<(), Box<dyn std::error::Error>> {
+        let output_dir = tempfile::tempdir()?;
+
+        let schema_paths = AssetSchemaGen::write(output_dir.path())?;
+
+        assert_eq!(AssetSchemaGen::schemas().len(), schema_paths.len());
+        let sprites_schema_path = output_dir.path().join("sprites.schema.json");
+        assert!(schema_paths.contains(&sprites_schema_path));
+
+        let sprites_schema =
+            serde_json::from_str::<serde_json::Value>(&fs::read_to_string(&sprites_schema_path)?)?;
+        assert_eq!(Some("SpritesDefinition"), sprites_schema["title"].as_str());
+
+        Ok(())
+    }
+
+    fn is_valid(schema_name: &str, definition: &str) -> bool {
+        let schemas = AssetSchemaGen::schemas();
+        let (_, schema) = schemas
+            .iter()
+            .find(|(name, _)| *name == schema_name)
+            .unwrap_or_else(|| panic!("Expected `{}` schema to exist.", schema_name));
+        let schema = JSONSchema::compile(schema, None)
+            .unwrap_or_else(|e| panic!("Failed to compile `{}` schema. {:?}", schema_name, e));
+        let definition = serde_yaml::from_str::<Value>(definition)
+            .unwrap_or_else(|e| panic!("Failed to parse definition. {}", e));
+
+        schema.is_valid(&definition)
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroIsize;
+
     use amethyst::{
         ecs::{Read, World, WorldExt},
         Error,
@@ -7,7 +9,7 @@ mod tests {
     use application_test_support::{AssetQueries, AutexousiousApplication};
     use asset_model::loaded::AssetIdMappings;
     use asset_selection_model::{
-        config::AssetSelectionEventArgs,
+        config::{AssetSelectionEventArgs, AssetSwitch},
         play::{AssetSelection, AssetSelectionEvent},
     };
     use assets_test::CHAR_BAT_SLUG;
@@ -193,6 +195,33 @@ mod tests {
     test_map_with_controller_id!(maps_leave_event, Leave);
     test_map_with_controller_id!(maps_deselect_event, Deselect);
     test_map_direct!(maps_return_event, Return);
+
+    #[test]
+    fn maps_variant_switch_event() {
+        let controller_id = 0;
+        let skip = NonZeroIsize::new(-1).expect("Expected `-1` to be non-zero.");
+        let args = AssetSelectionEventArgs::VariantSwitch {
+            controller_id,
+            skip,
+        };
+        let mut world = World::empty();
+        world.insert(AssetIdMappings::new());
+
+        let result = AssetSelectionEventStdinMapper::map(
+            &Read::from(world.fetch::<AssetIdMappings>()),
+            args,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(
+            AssetSelectionEvent::VariantSwitch {
+                entity: None,
+                controller_id,
+                variant_switch: AssetSwitch::Skip(skip),
+            },
+            result.unwrap()
+        )
+    }
     test_map_direct!(maps_confirm_event, Confirm);
 
     fn expect_err_msg(result: Result<AssetSelectionEvent, Error>, expected: &str) {
@@ -1,2 +1,4 @@
+mod camera_shake_system;
 mod camera_tracking_system;
 mod camera_velocity_system;
+mod screen_flash_system;
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        core::Transform,
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use approx::assert_relative_eq;
+    use camera_model::play::CameraShake;
+    use pretty_assertions::assert_eq;
+
+    use camera_play::CameraShakeSystem;
+
+    #[test]
+    fn offset_alternates_and_decays() {
+        let offsets = (0..5)
+            .map(|ticks| {
+                let mut camera_shake = CameraShake::new(8., 4);
+                camera_shake.ticks = ticks;
+                CameraShakeSystem::offset(camera_shake)
+            })
+            .collect::<Vec<(f32, f32)>>();
+
+        assert_eq!(
+            vec![(8., 4.), (-6., 3.), (4., -2.), (-2., -1.), (0., 0.)],
+            offsets
+        );
+    }
+
+    #[test]
+    fn offsets_transform_and_removes_shake_when_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CameraShakeSystem::new(),
+                any::type_name::<CameraShakeSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let mut transform = Transform::default();
+                transform.set_translation_xyz(10., 20., 0.);
+
+                let entity = world
+                    .create_entity()
+                    .with(transform)
+                    .with(CameraShake::new(4., 2))
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let camera_shakes = world.read_storage::<CameraShake>();
+                let transforms = world.read_storage::<Transform>();
+                let translation = transforms
+                    .get(entity)
+                    .expect("Expected entity to have `Transform` component.")
+                    .translation();
+
+                assert_eq!(
+                    Some(1),
+                    camera_shakes
+                        .get(entity)
+                        .map(|camera_shake| camera_shake.ticks)
+                );
+                assert_relative_eq!(14., translation.x);
+                assert_relative_eq!(22., translation.y);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let camera_shakes = world.read_storage::<CameraShake>();
+                let transforms = world.read_storage::<Transform>();
+                let translation = transforms
+                    .get(entity)
+                    .expect("Expected entity to have `Transform` component.")
+                    .translation();
+
+                assert!(camera_shakes.get(entity).is_none());
+                assert_relative_eq!(12., translation.x);
+                assert_relative_eq!(23., translation.y);
+            })
+            .run()
+    }
+}
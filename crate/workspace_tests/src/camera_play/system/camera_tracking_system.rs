@@ -13,7 +13,7 @@ mod tests {
         CameraTargetCoordinates, CameraTracked, CAMERA_ZOOM_DEPTH_DEFAULT,
         CAMERA_ZOOM_HEIGHT_DEFAULT, CAMERA_ZOOM_WIDTH_DEFAULT,
     };
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position};
     use map_loading::MapLoadingBundle;
     use map_model::{
         config::MapBounds,
@@ -35,8 +35,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., 1500., 0.), None),
-                    (Position::new(1100., 1500., 0.), None),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        None,
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -55,8 +69,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., 900., 500.), None),
-                    (Position::new(1100., 1100., 700.), None),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::from(900),
+                            FixedPoint::from(500),
+                        ),
+                        None,
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::from(1100),
+                            FixedPoint::from(700),
+                        ),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -75,8 +103,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(0., 900., 500.), None),
-                    (Position::new(0., 1100., 700.), None),
+                    (
+                        Position::new(
+                            FixedPoint::ZERO,
+                            FixedPoint::from(900),
+                            FixedPoint::from(500),
+                        ),
+                        None,
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::ZERO,
+                            FixedPoint::from(1100),
+                            FixedPoint::from(700),
+                        ),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -95,8 +137,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(MAP_WIDTH, 900., 500.), None),
-                    (Position::new(MAP_WIDTH, 1100., 700.), None),
+                    (
+                        Position::new(
+                            FixedPoint::from(MAP_WIDTH),
+                            FixedPoint::from(900),
+                            FixedPoint::from(500),
+                        ),
+                        None,
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(MAP_WIDTH),
+                            FixedPoint::from(1100),
+                            FixedPoint::from(700),
+                        ),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -115,8 +171,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., MAP_HEIGHT, 0.), None),
-                    (Position::new(1100., MAP_HEIGHT, 0.), None),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::from(MAP_HEIGHT),
+                            FixedPoint::ZERO,
+                        ),
+                        None,
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::from(MAP_HEIGHT),
+                            FixedPoint::ZERO,
+                        ),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -135,8 +205,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., 0., MAP_DEPTH), None),
-                    (Position::new(1100., 0., MAP_DEPTH), None),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::ZERO,
+                            FixedPoint::from(MAP_DEPTH),
+                        ),
+                        None,
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::ZERO,
+                            FixedPoint::from(MAP_DEPTH),
+                        ),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -155,8 +239,14 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(0., 0., 0.), None),
-                    (Position::new(0., 0., 0.), None),
+                    (
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                        None,
+                    ),
+                    (
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                        None,
+                    ),
                 ],
                 setup_map_selection_fn: setup_small_map,
             },
@@ -175,8 +265,14 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(0., 0., 0.), Some(Mirrored::new(true))),
-                    (Position::new(0., 0., 0.), Some(Mirrored::new(true))),
+                    (
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                        Some(Mirrored::new(true)),
+                    ),
+                    (
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                        Some(Mirrored::new(true)),
+                    ),
                 ],
                 setup_map_selection_fn: setup_small_map,
             },
@@ -195,8 +291,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., 1500., 0.), Some(Mirrored::new(false))),
-                    (Position::new(1100., 1500., 0.), Some(Mirrored::new(false))),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        Some(Mirrored::new(false)),
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        Some(Mirrored::new(false)),
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -217,8 +327,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., 1500., 0.), Some(Mirrored::new(true))),
-                    (Position::new(1100., 1500., 0.), Some(Mirrored::new(true))),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        Some(Mirrored::new(true)),
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        Some(Mirrored::new(true)),
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -239,8 +363,22 @@ mod tests {
         run_test(
             SetupParams {
                 position_mirroreds: vec![
-                    (Position::new(900., 1500., 0.), Some(Mirrored::new(true))),
-                    (Position::new(1100., 1500., 0.), Some(Mirrored::new(false))),
+                    (
+                        Position::new(
+                            FixedPoint::from(900),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        Some(Mirrored::new(true)),
+                    ),
+                    (
+                        Position::new(
+                            FixedPoint::from(1100),
+                            FixedPoint::from(1500),
+                            FixedPoint::ZERO,
+                        ),
+                        Some(Mirrored::new(false)),
+                    ),
                 ],
                 setup_map_selection_fn: setup_big_map,
             },
@@ -346,7 +484,7 @@ mod tests {
     }
 
     struct SetupParams {
-        position_mirroreds: Vec<(Position<f32>, Option<Mirrored>)>,
+        position_mirroreds: Vec<(Position<FixedPoint>, Option<Mirrored>)>,
         setup_map_selection_fn: fn(&mut World),
     }
 
@@ -9,7 +9,7 @@ mod tests {
     };
     use amethyst_test::{AmethystApplication, HIDPI, SCREEN_HEIGHT, SCREEN_WIDTH};
     use camera_model::play::CameraTargetCoordinates;
-    use kinematic_model::config::{Position, Velocity};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use pretty_assertions::assert_eq;
 
     use camera_play::{CameraCreator, CameraVelocitySystem};
@@ -19,11 +19,18 @@ mod tests {
         run_test(
             SetupParams {
                 camera_target_coordinates: CameraTargetCoordinates::new(100., 200., 300.),
-                position: Position::new(100., 200., 300.),
-                velocity: Velocity::new(0., 0., 0.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(200),
+                    FixedPoint::from(300),
+                ),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
             },
             ExpectedParams {
-                velocity_steps: vec![Velocity::new(0., 0., 0.), Velocity::new(0., 0., 0.)],
+                velocity_steps: vec![
+                    Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                    Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                ],
             },
         )
     }
@@ -33,14 +40,26 @@ mod tests {
         run_test(
             SetupParams {
                 camera_target_coordinates: CameraTargetCoordinates::new(100., 200., 300.),
-                position: Position::new(0., 0., 0.),
-                velocity: Velocity::new(0., 0., 0.),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
             },
             ExpectedParams {
                 velocity_steps: vec![
-                    Velocity::new(5., 10., 15.),
-                    Velocity::new(7.5, 15., 22.5),
-                    Velocity::new(8.75, 17.5, 26.25),
+                    Velocity::new(
+                        FixedPoint::from(5),
+                        FixedPoint::from(10),
+                        FixedPoint::from(15),
+                    ),
+                    Velocity::new(
+                        FixedPoint::from(7.5),
+                        FixedPoint::from(15),
+                        FixedPoint::from(22.5),
+                    ),
+                    Velocity::new(
+                        FixedPoint::from(8.75),
+                        FixedPoint::from(17.5),
+                        FixedPoint::from(26.25),
+                    ),
                 ],
             },
         )
@@ -58,7 +77,7 @@ mod tests {
             .with_resource(ScreenDimensions::new(SCREEN_WIDTH, SCREEN_HEIGHT, HIDPI))
             .with_system(
                 CameraVelocitySystem {
-                    smoothing_factor: 2.,
+                    smoothing_factor: FixedPoint::from(2),
                 },
                 any::type_name::<CameraVelocitySystem>(),
                 &[],
@@ -70,8 +89,8 @@ mod tests {
                     let (mut camera_target_coordinateses, mut positions, mut velocities) = world
                         .system_data::<(
                             WriteStorage<'_, CameraTargetCoordinates>,
-                            WriteStorage<'_, Position<f32>>,
-                            WriteStorage<'_, Velocity<f32>>,
+                            WriteStorage<'_, Position<FixedPoint>>,
+                            WriteStorage<'_, Velocity<FixedPoint>>,
                         )>();
 
                     camera_target_coordinateses
@@ -79,10 +98,10 @@ mod tests {
                         .expect("Failed to insert `CameraTargetCoordinates` component.");
                     positions
                         .insert(camera_entity, position)
-                        .expect("Failed to insert `Position<f32>` component.");
+                        .expect("Failed to insert `Position<FixedPoint>` component.");
                     velocities
                         .insert(camera_entity, velocity_setup)
-                        .expect("Failed to insert `Velocity<f32>` component.");
+                        .expect("Failed to insert `Velocity<FixedPoint>` component.");
                 }
 
                 world.insert(camera_entity);
@@ -93,7 +112,7 @@ mod tests {
             |amethyst_application, velocity_expected| {
                 amethyst_application.with_assertion(move |world| {
                     let entity = *world.read_resource::<Entity>();
-                    let velocities = world.read_storage::<Velocity<f32>>();
+                    let velocities = world.read_storage::<Velocity<FixedPoint>>();
                     let velocity_actual = velocities
                         .get(entity)
                         .copied()
@@ -109,11 +128,11 @@ mod tests {
 
     struct SetupParams {
         camera_target_coordinates: CameraTargetCoordinates,
-        position: Position<f32>,
-        velocity: Velocity<f32>,
+        position: Position<FixedPoint>,
+        velocity: Velocity<FixedPoint>,
     }
 
     struct ExpectedParams {
-        velocity_steps: Vec<Velocity<f32>>,
+        velocity_steps: Vec<Velocity<FixedPoint>>,
     }
 }
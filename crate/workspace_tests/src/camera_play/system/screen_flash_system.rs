@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        ui::{UiImage, UiTransform},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use camera_model::play::ScreenFlash;
+    use pretty_assertions::assert_eq;
+
+    use camera_play::ScreenFlashSystem;
+
+    #[test]
+    fn color_fades_alpha_over_duration() {
+        let colors = (0..3)
+            .map(|ticks| {
+                let mut screen_flash = ScreenFlash::new([1., 0.5, 0.25, 0.8], 2);
+                screen_flash.ticks = ticks;
+                ScreenFlashSystem::color(screen_flash)
+            })
+            .collect::<Vec<[f32; 4]>>();
+
+        assert_eq!(
+            vec![
+                [1., 0.5, 0.25, 0.8],
+                [1., 0.5, 0.25, 0.4],
+                [1., 0.5, 0.25, 0.]
+            ],
+            colors
+        );
+    }
+
+    #[test]
+    fn displays_flash_and_deletes_entity_when_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                ScreenFlashSystem::new(),
+                any::type_name::<ScreenFlashSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(ScreenFlash::new([1., 1., 1., 0.8], 2))
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(|world| assert_color(world, 0.8))
+            .with_assertion(|world| assert_color(world, 0.4))
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                assert!(!world.entities().is_alive(entity));
+            })
+            .run()
+    }
+
+    fn assert_color(world: &mut World, alpha: f32) {
+        let entity = *world.read_resource::<Entity>();
+        let ui_transforms = world.read_storage::<UiTransform>();
+        let ui_images = world.read_storage::<UiImage>();
+
+        assert!(ui_transforms.contains(entity));
+        match ui_images.get(entity) {
+            Some(UiImage::SolidColor(color)) => assert_eq!([1., 1., 1., alpha], *color),
+            ui_image => panic!("Expected `UiImage::SolidColor`, got: {:?}", ui_image),
+        }
+    }
+}
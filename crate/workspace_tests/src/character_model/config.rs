@@ -3,12 +3,15 @@ mod character_sequence;
 
 #[cfg(test)]
 mod test {
+    use std::path::PathBuf;
+
     use charge_model::config::ChargePoints;
     use collision_model::config::Body;
     use indexmap::IndexMap;
     use input_reaction_model::config::{
         InputReaction, InputReactionAppEvents, InputReactionMultiple, InputReactionSingle,
     };
+    use item_model::config::HoldPoint;
     use object_model::{
         config::{ObjectDefinition, ObjectFrame, ObjectSequence},
         play::{HealthPoints, SkillPoints},
@@ -16,7 +19,7 @@ mod test {
     use sequence_model::config::{Sequence, SequenceEndTransition, SequenceNameString, Wait};
     use serde_yaml;
     use shape_model::Volume;
-    use sprite_model::config::SpriteRef;
+    use sprite_model::config::{PaletteSwap, SpriteRef, SpriteVariant};
 
     use character_model::config::{
         CharacterDefinition, CharacterFrame, CharacterInputReactions, CharacterIrr,
@@ -66,6 +69,18 @@ sequences:
     frames: []
 ";
 
+    const VARIANTS_YAML: &str = "\
+sequences: {}
+variants:
+  - palette: [{ from: [255, 0, 0], to: [0, 0, 255] }]
+  - sheets: ['costume.png']
+";
+
+    const CHARGE_LEVEL_SOUND_YAML: &str = "\
+sequences: {}
+charge_level_sound: 'default/sfx/charge_level.wav'
+";
+
     #[test]
     fn deserialize_character_definition() {
         let char_definition = serde_yaml::from_str::<CharacterDefinition>(OBJECT_YAML)
@@ -104,6 +119,29 @@ sequences:
         assert_eq!(expected, char_definition);
     }
 
+    #[test]
+    fn deserialize_character_definition_with_variants() {
+        let char_definition = serde_yaml::from_str::<CharacterDefinition>(VARIANTS_YAML)
+            .expect("Failed to deserialize character definition.");
+
+        let expected = vec![
+            SpriteVariant::new(vec![], vec![PaletteSwap::new([255, 0, 0], [0, 0, 255])]),
+            SpriteVariant::new(vec![String::from("costume.png")], vec![]),
+        ];
+        assert_eq!(expected, char_definition.variants);
+    }
+
+    #[test]
+    fn deserialize_character_definition_with_charge_level_sound() {
+        let char_definition = serde_yaml::from_str::<CharacterDefinition>(CHARGE_LEVEL_SOUND_YAML)
+            .expect("Failed to deserialize character definition.");
+
+        assert_eq!(
+            Some(PathBuf::from("default/sfx/charge_level.wav")),
+            char_definition.charge_level_sound
+        );
+    }
+
     fn stand_sequence() -> CharacterSequence {
         let frames = vec![CharacterFrame::new(
             ObjectFrame {
@@ -153,6 +191,7 @@ sequences:
                 })),
                 ..Default::default()
             }, // kcov-ignore
+            HoldPoint::default(),
         )];
 
         let character_input_reactions = CharacterInputReactions {
@@ -200,6 +239,7 @@ sequences:
                 })),
                 ..Default::default()
             }, // kcov-ignore
+            HoldPoint::default(),
         )];
 
         let character_input_reactions = CharacterInputReactions {
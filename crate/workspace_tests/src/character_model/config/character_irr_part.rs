@@ -1,15 +1,17 @@
 #[cfg(test)]
 mod tests {
+    use amethyst::ecs::{Builder, World, WorldExt};
     use charge_model::{
-        config::{ChargePoints, ChargeUseMode},
+        config::{ChargeLevel, ChargePoints, ChargeUseMode},
         play::ChargeTrackerClock,
     };
     use game_input_model::{
         config::{InputDirection, InputDirectionZ},
         play::ControllerInput,
     };
+    use item_model::play::HeldItem;
     use mirrored_model::play::Mirrored;
-    use object_model::play::{HealthPoints, SkillPoints};
+    use object_model::play::{Grounding, HealthPoints, SkillPoints};
 
     use character_model::config::{CharacterIrrPart, InputReactionRequirementParams};
 
@@ -100,6 +102,122 @@ mod tests {
         assert!(!requirement.is_met(params));
     }
 
+    #[test]
+    fn holding_requirement_met_when_holding_matches() {
+        let mut world = World::new();
+        let item_entity = world.create_entity().build();
+
+        let requirement = CharacterIrrPart::Holding(true);
+        let params = InputReactionRequirementParams {
+            held_item: Some(HeldItem::new(item_entity)),
+            ..Default::default()
+        };
+        assert!(requirement.is_met(params));
+
+        let requirement = CharacterIrrPart::Holding(false);
+        let params = InputReactionRequirementParams::default();
+        assert!(requirement.is_met(params));
+    }
+
+    #[test]
+    fn holding_requirement_not_met_when_holding_does_not_match() {
+        let mut world = World::new();
+        let item_entity = world.create_entity().build();
+
+        let requirement = CharacterIrrPart::Holding(false);
+        let params = InputReactionRequirementParams {
+            held_item: Some(HeldItem::new(item_entity)),
+            ..Default::default()
+        };
+        assert!(!requirement.is_met(params));
+
+        let requirement = CharacterIrrPart::Holding(true);
+        let params = InputReactionRequirementParams::default();
+        assert!(!requirement.is_met(params));
+    }
+
+    #[test]
+    fn airborne_requirement_met_when_grounding_matches() {
+        let requirement = CharacterIrrPart::Airborne(true);
+        let params = InputReactionRequirementParams {
+            grounding: Some(Grounding::Airborne),
+            ..Default::default()
+        };
+        assert!(requirement.is_met(params));
+
+        let requirement = CharacterIrrPart::Airborne(false);
+        let params = InputReactionRequirementParams {
+            grounding: Some(Grounding::OnGround),
+            ..Default::default()
+        };
+        assert!(requirement.is_met(params));
+    }
+
+    #[test]
+    fn airborne_requirement_not_met_when_grounding_does_not_match() {
+        let requirement = CharacterIrrPart::Airborne(true);
+        let params = InputReactionRequirementParams {
+            grounding: Some(Grounding::OnGround),
+            ..Default::default()
+        };
+        assert!(!requirement.is_met(params));
+
+        let requirement = CharacterIrrPart::Airborne(false);
+        let params = InputReactionRequirementParams::default();
+        assert!(!requirement.is_met(params));
+    }
+
+    #[test]
+    fn health_points_below_requirement_met_when_less_than() {
+        let requirement = CharacterIrrPart::HpBelow(HealthPoints::new(10));
+        let params = InputReactionRequirementParams {
+            health_points: Some(HealthPoints::new(9)),
+            ..Default::default()
+        };
+
+        assert!(requirement.is_met(params));
+    }
+
+    #[test]
+    fn health_points_below_requirement_not_met_when_greater_equal() {
+        let requirement = CharacterIrrPart::HpBelow(HealthPoints::new(10));
+        let params = InputReactionRequirementParams {
+            health_points: Some(HealthPoints::new(10)),
+            ..Default::default()
+        };
+
+        assert!(!requirement.is_met(params));
+    }
+
+    #[test]
+    fn charge_level_requirement_met_when_greater_equal() {
+        let requirement = CharacterIrrPart::ChargeLevel(ChargeLevel::Level2);
+        let params = InputReactionRequirementParams {
+            charge_level: Some(ChargeLevel::Level2),
+            ..Default::default()
+        };
+        assert!(requirement.is_met(params));
+
+        let params = InputReactionRequirementParams {
+            charge_level: Some(ChargeLevel::Level3),
+            ..Default::default()
+        };
+        assert!(requirement.is_met(params));
+    }
+
+    #[test]
+    fn charge_level_requirement_not_met_when_less_than() {
+        let requirement = CharacterIrrPart::ChargeLevel(ChargeLevel::Level2);
+        let params = InputReactionRequirementParams {
+            charge_level: Some(ChargeLevel::Level1),
+            ..Default::default()
+        };
+        assert!(!requirement.is_met(params));
+
+        let params = InputReactionRequirementParams::default();
+        assert!(!requirement.is_met(params));
+    }
+
     macro_rules! input_x_test {
         ($test_name:ident, $variant:ident, $controller_input:expr, $mirrored:expr, true $(,)?) => {
             #[test]
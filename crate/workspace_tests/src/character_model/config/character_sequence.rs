@@ -3,14 +3,18 @@ mod tests {
     use input_reaction_model::config::{
         InputReaction, InputReactionAppEvents, InputReactionSingle,
     };
-    use object_model::config::{ObjectFrame, ObjectSequence};
+    use item_model::config::HoldPoint;
+    use object_model::{
+        config::{ObjectFrame, ObjectSequence},
+        play::HealthPoints,
+    };
     use sequence_model::config::{Sequence, SequenceEndTransition, SequenceNameString, Wait};
     use serde_yaml;
     use sprite_model::config::SpriteRef;
 
     use character_model::config::{
-        CharacterFrame, CharacterInputReactions, CharacterIrr, CharacterSequence,
-        CharacterSequenceName,
+        CharacterChargeLevelNext, CharacterEndTransition, CharacterFrame, CharacterInputReactions,
+        CharacterIrr, CharacterIrrPart, CharacterSequence, CharacterSequenceName,
     };
 
     const SEQUENCE_WITH_FRAMES_EMPTY: &str = "frames: []";
@@ -24,6 +28,23 @@ frames:
     input_reactions:
       press_attack: "stand_attack_0"
       hold_jump: { next: "jump" }
+"#;
+    const SEQUENCE_WITH_NEXT_CONDITIONAL: &str = r#"---
+next: "stand"
+next_conditional:
+  - next: "jump_descend"
+    requirement: [{ airborne: true }]
+  - next: "lie_face_down"
+    requirement: [{ hp_below: 20 }]
+
+frames: []
+"#;
+    const SEQUENCE_WITH_NEXT_BY_CHARGE_LEVEL: &str = r#"---
+next_by_charge_level:
+  level_1: "charge_attack_1"
+  level_3: "charge_attack_3"
+
+frames: []
 "#;
 
     #[test]
@@ -57,6 +78,7 @@ frames:
                 })),
                 ..Default::default()
             }, // kcov-ignore
+            HoldPoint::default(),
         )];
         let character_input_reactions = CharacterInputReactions {
             press_defend: Some(InputReaction::SequenceNameString(SequenceNameString::Name(
@@ -77,4 +99,51 @@ frames:
 
         assert_eq!(expected, sequence);
     }
+
+    #[test]
+    fn sequence_with_next_conditional() {
+        let sequence = serde_yaml::from_str::<CharacterSequence>(SEQUENCE_WITH_NEXT_CONDITIONAL)
+            .expect("Failed to deserialize sequence.");
+
+        let mut expected = CharacterSequence::new(
+            ObjectSequence {
+                sequence: Sequence {
+                    next: SequenceEndTransition::SequenceName(SequenceNameString::Name(
+                        CharacterSequenceName::Stand,
+                    )),
+                    frames: Vec::new(),
+                },
+                ..Default::default()
+            },
+            None,
+        );
+        expected.next_conditional = vec![
+            CharacterEndTransition::new(
+                SequenceNameString::Name(CharacterSequenceName::JumpDescend),
+                CharacterIrr::new(vec![CharacterIrrPart::Airborne(true)]),
+            ),
+            CharacterEndTransition::new(
+                SequenceNameString::Name(CharacterSequenceName::LieFaceDown),
+                CharacterIrr::new(vec![CharacterIrrPart::HpBelow(HealthPoints::new(20))]),
+            ),
+        ];
+
+        assert_eq!(expected, sequence);
+    }
+
+    #[test]
+    fn sequence_with_next_by_charge_level() {
+        let sequence =
+            serde_yaml::from_str::<CharacterSequence>(SEQUENCE_WITH_NEXT_BY_CHARGE_LEVEL)
+                .expect("Failed to deserialize sequence.");
+
+        let mut expected = CharacterSequence::new(ObjectSequence::default(), None);
+        expected.next_by_charge_level = CharacterChargeLevelNext::new(
+            Some(SequenceNameString::String(String::from("charge_attack_1"))),
+            None,
+            Some(SequenceNameString::String(String::from("charge_attack_3"))),
+        );
+
+        assert_eq!(expected, sequence);
+    }
 }
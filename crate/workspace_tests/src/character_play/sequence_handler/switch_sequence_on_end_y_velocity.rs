@@ -2,7 +2,7 @@
 mod test {
     use character_model::{config::CharacterSequenceName, play::RunCounter};
     use game_input_model::play::ControllerInput;
-    use kinematic_model::config::{Position, Velocity};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use mirrored_model::play::Mirrored;
     use object_model::play::{Grounding, HealthPoints};
     use sequence_model::play::SequenceStatus;
@@ -36,7 +36,7 @@ mod test {
     #[test]
     fn switches_to_upwards_when_sequence_ended_and_velocity_positive() {
         let mut velocity = Velocity::default();
-        velocity[1] = 1.;
+        velocity[1] = FixedPoint::ONE;
 
         assert_eq!(
             Some(CharacterSequenceName::DashForwardAscend),
@@ -60,7 +60,7 @@ mod test {
     #[test]
     fn switches_to_downwards_when_sequence_ended_and_velocity_negative() {
         let mut velocity = Velocity::default();
-        velocity[1] = -1.;
+        velocity[1] = -FixedPoint::ONE;
 
         assert_eq!(
             Some(CharacterSequenceName::DashForwardDescend),
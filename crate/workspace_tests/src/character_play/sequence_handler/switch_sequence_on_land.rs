@@ -2,7 +2,7 @@
 mod test {
     use character_model::{config::CharacterSequenceName, play::RunCounter};
     use game_input_model::play::ControllerInput;
-    use kinematic_model::config::{Position, Velocity};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use mirrored_model::play::Mirrored;
     use object_model::play::{Grounding, HealthPoints};
     use sequence_model::play::SequenceStatus;
@@ -15,7 +15,7 @@ mod test {
     fn no_update_when_sequence_not_ended() {
         let input = ControllerInput::new(0., 0., false, false, false, false);
         let mut velocity = Velocity::default();
-        velocity[1] = -1.;
+        velocity[1] = -FixedPoint::ONE;
 
         assert_eq!(
             None,
@@ -39,7 +39,7 @@ mod test {
     fn switches_to_land_when_on_ground() {
         let input = ControllerInput::new(0., 0., false, false, false, false);
         let mut velocity = Velocity::default();
-        velocity[1] = -1.;
+        velocity[1] = -FixedPoint::ONE;
 
         assert_eq!(
             Some(CharacterSequenceName::FallForwardLand),
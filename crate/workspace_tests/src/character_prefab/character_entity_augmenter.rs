@@ -47,6 +47,7 @@ mod test {
                     &character_spawning_resources,
                     &mut character_component_storages,
                     asset_id,
+                    0,
                     entity,
                 );
             }
@@ -1 +1,2 @@
 mod character_selection_system;
+mod character_variant_input_system;
@@ -3,6 +3,7 @@ mod tests {
     use std::{any, str::FromStr};
 
     use amethyst::{
+        assets::{AssetStorage, Loader, Processor},
         core::TransformBundle,
         ecs::{World, WorldExt},
         shrev::EventChannel,
@@ -16,10 +17,15 @@ mod tests {
         config::{AssetSlug, AssetType},
         loaded::{AssetId, AssetTypeMappings},
     };
-    use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
+    use asset_selection_model::{
+        config::AssetSwitch,
+        play::{AssetSelection, AssetSelectionEvent},
+    };
+    use character_model::{config::CharacterDefinition, loaded::AssetCharacterDefinitionHandle};
     use character_selection_model::CharacterSelections;
     use game_input_model::config::ControlBindings;
     use object_type::ObjectType;
+    use sprite_model::config::SpriteVariant;
 
     use character_selection::CharacterSelectionSystem;
 
@@ -28,6 +34,9 @@ mod tests {
         run_test(
             SetupParams {
                 with_character_selection_initial: false,
+                own_selection_variant: None,
+                other_selection_variant: None,
+                variant_count: 1,
                 asset_selection_event_fn: |asset_id| AssetSelectionEvent::Select {
                     entity: None,
                     controller_id: 123,
@@ -36,6 +45,7 @@ mod tests {
             },
             ExpectedParams {
                 character_selection_fn: |asset_id| Some(asset_id),
+                variant_index: Some(0),
             },
         )
     }
@@ -45,6 +55,9 @@ mod tests {
         run_test(
             SetupParams {
                 with_character_selection_initial: true,
+                own_selection_variant: None,
+                other_selection_variant: None,
+                variant_count: 1,
                 asset_selection_event_fn: |asset_id| AssetSelectionEvent::Select {
                     entity: None,
                     controller_id: 123,
@@ -53,6 +66,7 @@ mod tests {
             },
             ExpectedParams {
                 character_selection_fn: |asset_id| Some(asset_id),
+                variant_index: Some(0),
             },
         )
     }
@@ -62,6 +76,9 @@ mod tests {
         run_test(
             SetupParams {
                 with_character_selection_initial: false,
+                own_selection_variant: None,
+                other_selection_variant: None,
+                variant_count: 1,
                 asset_selection_event_fn: |_| AssetSelectionEvent::Deselect {
                     entity: None,
                     controller_id: 123,
@@ -69,6 +86,92 @@ mod tests {
             },
             ExpectedParams {
                 character_selection_fn: |_| None,
+                variant_index: None,
+            },
+        )
+    }
+
+    #[test]
+    fn assigns_next_variant_when_character_is_selected_by_another_controller() -> Result<(), Error>
+    {
+        run_test(
+            SetupParams {
+                with_character_selection_initial: false,
+                own_selection_variant: None,
+                other_selection_variant: Some(0),
+                variant_count: 2,
+                asset_selection_event_fn: |asset_id| AssetSelectionEvent::Select {
+                    entity: None,
+                    controller_id: 123,
+                    asset_selection: AssetSelection::Id(asset_id),
+                },
+            },
+            ExpectedParams {
+                character_selection_fn: |asset_id| Some(asset_id),
+                variant_index: Some(1),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_assign_variant_beyond_variant_count() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                with_character_selection_initial: false,
+                own_selection_variant: None,
+                other_selection_variant: Some(0),
+                variant_count: 1,
+                asset_selection_event_fn: |asset_id| AssetSelectionEvent::Select {
+                    entity: None,
+                    controller_id: 123,
+                    asset_selection: AssetSelection::Id(asset_id),
+                },
+            },
+            ExpectedParams {
+                character_selection_fn: |asset_id| Some(asset_id),
+                variant_index: Some(0),
+            },
+        )
+    }
+
+    #[test]
+    fn switches_to_next_unused_variant_on_variant_switch_event() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                with_character_selection_initial: false,
+                own_selection_variant: Some(0),
+                other_selection_variant: Some(1),
+                variant_count: 3,
+                asset_selection_event_fn: |_| AssetSelectionEvent::VariantSwitch {
+                    entity: None,
+                    controller_id: 123,
+                    variant_switch: AssetSwitch::Next,
+                },
+            },
+            ExpectedParams {
+                character_selection_fn: |asset_id| Some(asset_id),
+                variant_index: Some(2),
+            },
+        )
+    }
+
+    #[test]
+    fn ignores_variant_switch_event_when_character_not_selected() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                with_character_selection_initial: false,
+                own_selection_variant: None,
+                other_selection_variant: None,
+                variant_count: 3,
+                asset_selection_event_fn: |_| AssetSelectionEvent::VariantSwitch {
+                    entity: None,
+                    controller_id: 123,
+                    variant_switch: AssetSwitch::Next,
+                },
+            },
+            ExpectedParams {
+                character_selection_fn: |_| None,
+                variant_index: None,
             },
         )
     }
@@ -76,10 +179,14 @@ mod tests {
     fn run_test(
         SetupParams {
             with_character_selection_initial,
+            own_selection_variant,
+            other_selection_variant,
+            variant_count,
             asset_selection_event_fn,
         }: SetupParams,
         ExpectedParams {
             character_selection_fn,
+            variant_index,
         }: ExpectedParams,
     ) -> Result<(), Error> {
         AmethystApplication::blank()
@@ -92,12 +199,40 @@ mod tests {
                 any::type_name::<CharacterSelectionSystem>(),
                 &[],
             ) // kcov-ignore
+            .with_system(Processor::<CharacterDefinition>::new(), "", &[])
             .with_effect(move |world| {
                 let asset_slug_zero =
                     AssetSlug::from_str("test/zero").expect("Expected `AssetSlug` to be valid.");
                 let asset_id_zero = AssetQueries::id_generate(world, asset_slug_zero);
                 world.insert(asset_id_zero);
 
+                let character_definition = CharacterDefinition {
+                    variants: vec![SpriteVariant::default(); variant_count - 1],
+                    ..Default::default()
+                };
+                let character_definition_handle = {
+                    let loader = world.read_resource::<Loader>();
+                    let character_definition_assets =
+                        world.read_resource::<AssetStorage<CharacterDefinition>>();
+                    loader.load_from_data(character_definition, (), &character_definition_assets)
+                };
+                world
+                    .write_resource::<AssetCharacterDefinitionHandle>()
+                    .insert(asset_id_zero, character_definition_handle);
+
+                if let Some(variant_index_own) = own_selection_variant {
+                    let controller_id = 123;
+
+                    let mut character_selections = world.write_resource::<CharacterSelections>();
+
+                    character_selections
+                        .selections
+                        .insert(controller_id, asset_id_zero);
+                    character_selections
+                        .variants
+                        .insert(controller_id, variant_index_own);
+                }
+
                 if with_character_selection_initial {
                     let asset_slug =
                         AssetSlug::from_str("test/one").expect("Expected `AssetSlug` to be valid.");
@@ -112,12 +247,28 @@ mod tests {
                         .insert(controller_id, asset_id_one);
                 }
 
+                if let Some(variant_index_other) = other_selection_variant {
+                    let controller_id = 456;
+
+                    let mut character_selections = world.write_resource::<CharacterSelections>();
+
+                    character_selections
+                        .selections
+                        .insert(controller_id, asset_id_zero);
+                    character_selections
+                        .variants
+                        .insert(controller_id, variant_index_other);
+                }
+
                 {
                     let mut asset_type_mappings = world.write_resource::<AssetTypeMappings>();
                     asset_type_mappings
                         .insert(asset_id_zero, AssetType::Object(ObjectType::Character));
                 }
-
+            })
+            .with_effect(move |world| {
+                // Sent in a later frame so that the `CharacterDefinition` has been processed.
+                let asset_id_zero = *world.read_resource::<AssetId>();
                 let asset_selection_event = asset_selection_event_fn(asset_id_zero);
                 send_event(world, asset_selection_event);
             })
@@ -131,6 +282,10 @@ mod tests {
                     character_selection_expected,
                     character_selections.selections.get(&123).cloned()
                 );
+                assert_eq!(
+                    variant_index,
+                    character_selections.variants.get(&123).copied()
+                );
             })
             .run_winit_loop()
     }
@@ -143,10 +298,14 @@ mod tests {
 
     struct SetupParams {
         with_character_selection_initial: bool,
+        own_selection_variant: Option<usize>,
+        other_selection_variant: Option<usize>,
+        variant_count: usize,
         asset_selection_event_fn: fn(AssetId) -> AssetSelectionEvent,
     }
 
     struct ExpectedParams {
         character_selection_fn: fn(AssetId) -> Option<AssetId>,
+        variant_index: Option<usize>,
     }
 }
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use asset_selection_model::{config::AssetSwitch, play::AssetSelectionEvent};
+    use character_selection_model::CharacterSelections;
+    use game_input_model::{
+        config::{ControlAction, ControllerId},
+        play::{ControlActionEventData, ControlInputEvent},
+    };
+
+    use character_selection::{CharacterVariantInputSystem, VARIANT_SWITCH_CONTROL_ACTION};
+
+    const CONTROLLER_ID_SELECTED: ControllerId = 0;
+    const CONTROLLER_ID_UNSELECTED: ControllerId = 1;
+
+    #[test]
+    fn sends_variant_switch_event_when_selected_controller_presses_action() -> Result<(), Error> {
+        run_test(
+            CONTROLLER_ID_SELECTED,
+            VARIANT_SWITCH_CONTROL_ACTION,
+            vec![AssetSelectionEvent::VariantSwitch {
+                entity: None,
+                controller_id: CONTROLLER_ID_SELECTED,
+                variant_switch: AssetSwitch::Next,
+            }],
+        )
+    }
+
+    #[test]
+    fn does_not_send_variant_switch_event_when_controller_has_not_selected() -> Result<(), Error> {
+        run_test(
+            CONTROLLER_ID_UNSELECTED,
+            VARIANT_SWITCH_CONTROL_ACTION,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn does_not_send_variant_switch_event_for_other_actions() -> Result<(), Error> {
+        run_test(CONTROLLER_ID_SELECTED, ControlAction::Attack, vec![])
+    }
+
+    fn run_test(
+        controller_id: ControllerId,
+        control_action: ControlAction,
+        asset_selection_events_expected: Vec<AssetSelectionEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterVariantInputSystem::new(), "", &[])
+            .with_effect(move |world| {
+                setup_selections(world);
+
+                let entity = world.create_entity().build();
+                world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .single_write(ControlInputEvent::ControlActionPress(
+                        ControlActionEventData {
+                            controller_id,
+                            entity,
+                            control_action,
+                        },
+                    ));
+            })
+            .with_assertion(move |world| {
+                let mut reader_id = world.write_resource::<ReaderId<AssetSelectionEvent>>();
+                let asset_selection_events = world
+                    .read_resource::<EventChannel<AssetSelectionEvent>>()
+                    .read(&mut reader_id)
+                    .copied()
+                    .collect::<Vec<AssetSelectionEvent>>();
+
+                assert_eq!(asset_selection_events_expected, asset_selection_events);
+            })
+            .run()
+    }
+
+    fn setup_selections(world: &mut World) {
+        let asset_id = AssetQueries::id_generate_any(world);
+        world
+            .write_resource::<CharacterSelections>()
+            .selections
+            .insert(CONTROLLER_ID_SELECTED, asset_id);
+
+        let reader_id = world
+            .write_resource::<EventChannel<AssetSelectionEvent>>()
+            .register_reader();
+        world.insert(reader_id);
+    }
+}
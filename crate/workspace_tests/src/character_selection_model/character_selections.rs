@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use asset_model::loaded::AssetId;
+    use game_input_model::config::ControllerId;
+    use slotmap::SlotMap;
+
+    use character_selection_model::CharacterSelections;
+
+    #[test]
+    fn variant_available_is_zero_when_character_is_not_selected_by_others() {
+        let (asset_id_zero, asset_id_one) = asset_ids();
+        let character_selections = character_selections(vec![(1, asset_id_one, 0)]);
+
+        assert_eq!(
+            0,
+            character_selections.variant_available(0, asset_id_zero, 3)
+        );
+    }
+
+    #[test]
+    fn variant_available_is_lowest_variant_not_used_by_others() {
+        let (asset_id_zero, _) = asset_ids();
+        let character_selections =
+            character_selections(vec![(1, asset_id_zero, 0), (2, asset_id_zero, 2)]);
+
+        assert_eq!(
+            1,
+            character_selections.variant_available(0, asset_id_zero, 3)
+        );
+    }
+
+    #[test]
+    fn variant_available_ignores_own_selection() {
+        let (asset_id_zero, _) = asset_ids();
+        let character_selections = character_selections(vec![(0, asset_id_zero, 0)]);
+
+        assert_eq!(
+            0,
+            character_selections.variant_available(0, asset_id_zero, 3)
+        );
+    }
+
+    #[test]
+    fn variant_available_is_default_when_all_variants_used_by_others() {
+        let (asset_id_zero, _) = asset_ids();
+        let character_selections =
+            character_selections(vec![(1, asset_id_zero, 0), (2, asset_id_zero, 1)]);
+
+        assert_eq!(
+            0,
+            character_selections.variant_available(0, asset_id_zero, 2)
+        );
+    }
+
+    #[test]
+    fn variant_switched_is_none_when_character_not_selected() {
+        let (asset_id_zero, _) = asset_ids();
+        let character_selections = character_selections(vec![(1, asset_id_zero, 0)]);
+
+        assert_eq!(None, character_selections.variant_switched(0, 3, 1));
+    }
+
+    #[test]
+    fn variant_switched_skips_variants_used_by_others() {
+        let (asset_id_zero, asset_id_one) = asset_ids();
+        let character_selections = character_selections(vec![
+            (0, asset_id_zero, 0),
+            (1, asset_id_zero, 1),
+            (2, asset_id_one, 2),
+        ]);
+
+        assert_eq!(Some(2), character_selections.variant_switched(0, 3, 1));
+    }
+
+    #[test]
+    fn variant_switched_wraps_within_variant_count() {
+        let (asset_id_zero, _) = asset_ids();
+        let character_selections = character_selections(vec![(0, asset_id_zero, 0)]);
+
+        assert_eq!(Some(2), character_selections.variant_switched(0, 3, -1));
+        assert_eq!(Some(1), character_selections.variant_switched(0, 3, 4));
+    }
+
+    #[test]
+    fn variant_switched_keeps_variant_when_others_use_the_rest() {
+        let (asset_id_zero, _) = asset_ids();
+        let character_selections =
+            character_selections(vec![(0, asset_id_zero, 1), (1, asset_id_zero, 0)]);
+
+        assert_eq!(Some(1), character_selections.variant_switched(0, 2, 1));
+    }
+
+    fn asset_ids() -> (AssetId, AssetId) {
+        let mut asset_ids = SlotMap::<AssetId, ()>::with_key();
+        (asset_ids.insert(()), asset_ids.insert(()))
+    }
+
+    fn character_selections(
+        selections: Vec<(ControllerId, AssetId, usize)>,
+    ) -> CharacterSelections {
+        let (selections, variants) = selections.into_iter().fold(
+            (HashMap::new(), HashMap::new()),
+            |(mut selections, mut variants), (controller_id, asset_id, variant_index)| {
+                selections.insert(controller_id, asset_id);
+                variants.insert(controller_id, variant_index);
+                (selections, variants)
+            },
+        );
+
+        CharacterSelections {
+            selections,
+            variants,
+        }
+    }
+}
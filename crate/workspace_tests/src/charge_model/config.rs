@@ -0,0 +1 @@
+mod charge_levels;
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use charge_model::config::{ChargeLevel, ChargeLevels, ChargePoints};
+
+    const CHARGE_LEVELS_YAML: &str = r#"
+level_1: 3
+level_3: 10
+"#;
+
+    #[test]
+    fn level_is_none_when_no_threshold_is_met() {
+        let charge_levels = charge_levels();
+
+        assert_eq!(ChargeLevel::None, charge_levels.level(ChargePoints::new(2)));
+    }
+
+    #[test]
+    fn level_is_highest_tier_whose_threshold_is_met() {
+        let charge_levels = charge_levels();
+
+        assert_eq!(
+            ChargeLevel::Level1,
+            charge_levels.level(ChargePoints::new(3))
+        );
+        assert_eq!(
+            ChargeLevel::Level2,
+            charge_levels.level(ChargePoints::new(8))
+        );
+        assert_eq!(
+            ChargeLevel::Level3,
+            charge_levels.level(ChargePoints::new(10))
+        );
+    }
+
+    #[test]
+    fn level_skips_tiers_without_threshold() {
+        let charge_levels = ChargeLevels::new(Some(ChargePoints::new(3)), None, None);
+
+        assert_eq!(
+            ChargeLevel::Level1,
+            charge_levels.level(ChargePoints::new(10))
+        );
+    }
+
+    #[test]
+    fn deserializes_thresholds_from_yaml() {
+        let charge_levels = serde_yaml::from_str::<ChargeLevels>(CHARGE_LEVELS_YAML)
+            .expect("Failed to deserialize `ChargeLevels`.");
+
+        assert_eq!(
+            ChargeLevels::new(
+                Some(ChargePoints::new(3)),
+                None,
+                Some(ChargePoints::new(10))
+            ),
+            charge_levels
+        );
+    }
+
+    fn charge_levels() -> ChargeLevels {
+        ChargeLevels::new(
+            Some(ChargePoints::new(3)),
+            Some(ChargePoints::new(6)),
+            Some(ChargePoints::new(10)),
+        )
+    }
+}
@@ -1,5 +1,7 @@
 mod charge_increment_system;
 mod charge_initialize_delay_system;
 mod charge_initialize_detection_system;
+mod charge_level_update_system;
 mod charge_retention_system;
+mod charge_tint_pulse_system;
 mod charge_usage_system;
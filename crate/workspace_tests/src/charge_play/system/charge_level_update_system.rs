@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, ReadStorage, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use charge_model::{
+        config::{ChargeLevel, ChargeLevels, ChargePoints},
+        play::{ChargeLevelEvent, ChargeTrackerClock},
+    };
+    use pretty_assertions::assert_eq;
+
+    use charge_play::ChargeLevelUpdateSystem;
+
+    #[test]
+    fn sends_event_when_higher_level_is_reached() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                charge_points: 6,
+                charge_level: ChargeLevel::Level1,
+            },
+            ExpectedParams {
+                charge_level: ChargeLevel::Level2,
+                charge_level_events_fn: |entity| {
+                    vec![ChargeLevelEvent::new(entity, ChargeLevel::Level2)]
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_event_when_level_is_unchanged() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                charge_points: 7,
+                charge_level: ChargeLevel::Level2,
+            },
+            ExpectedParams {
+                charge_level: ChargeLevel::Level2,
+                charge_level_events_fn: |_| vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_event_when_level_decreases() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                charge_points: 1,
+                charge_level: ChargeLevel::Level3,
+            },
+            ExpectedParams {
+                charge_level: ChargeLevel::None,
+                charge_level_events_fn: |_| vec![],
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            charge_points,
+            charge_level,
+        }: SetupParams,
+        ExpectedParams {
+            charge_level: charge_level_expected,
+            charge_level_events_fn,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ChargeLevelUpdateSystem::new(), "", &[])
+            .with_effect(register_event_reader)
+            .with_effect(move |world| {
+                let charge_levels = ChargeLevels::new(
+                    Some(ChargePoints::new(3)),
+                    Some(ChargePoints::new(6)),
+                    Some(ChargePoints::new(10)),
+                );
+                let entity = world
+                    .create_entity()
+                    .with(charge_levels)
+                    .with(ChargeTrackerClock::new_with_value(10, charge_points))
+                    .with(charge_level)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let charge_level = world
+                    .system_data::<ReadStorage<'_, ChargeLevel>>()
+                    .get(entity)
+                    .copied();
+
+                assert_eq!(Some(charge_level_expected), charge_level);
+                assert_charge_level_events(world, charge_level_events_fn(entity));
+            })
+            .run()
+    }
+
+    fn register_event_reader(world: &mut World) {
+        let reader_id = {
+            let mut charge_level_ec = world.write_resource::<EventChannel<ChargeLevelEvent>>();
+            charge_level_ec.register_reader()
+        }; // kcov-ignore
+        world.insert(reader_id);
+    }
+
+    fn assert_charge_level_events(
+        world: &mut World,
+        charge_level_events_expected: Vec<ChargeLevelEvent>,
+    ) {
+        let mut reader_id = &mut world.write_resource::<ReaderId<ChargeLevelEvent>>();
+        let charge_level_ec = world.read_resource::<EventChannel<ChargeLevelEvent>>();
+
+        let charge_level_events_actual = charge_level_ec
+            .read(&mut reader_id)
+            .copied()
+            .collect::<Vec<ChargeLevelEvent>>();
+
+        assert_eq!(charge_level_events_expected, charge_level_events_actual);
+    }
+
+    struct SetupParams {
+        charge_points: usize,
+        charge_level: ChargeLevel,
+    }
+
+    struct ExpectedParams {
+        charge_level: ChargeLevel,
+        charge_level_events_fn: fn(Entity) -> Vec<ChargeLevelEvent>,
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use approx::assert_relative_eq;
+    use charge_model::{
+        config::ChargeLevel,
+        play::{ChargeLevelEvent, ChargeTintPulse},
+    };
+    use pretty_assertions::assert_eq;
+
+    use charge_play::{ChargeTintPulseSystem, CHARGE_TINT_PULSE_DURATION};
+
+    #[test]
+    fn pulse_tint_is_white_for_no_charge_level() {
+        let Tint(tint) = ChargeTintPulseSystem::pulse_tint(ChargeLevel::None);
+
+        assert_relative_eq!(1., tint.red);
+        assert_relative_eq!(1., tint.green);
+        assert_relative_eq!(1., tint.blue);
+        assert_relative_eq!(1., tint.alpha);
+    }
+
+    #[test]
+    fn pulse_blend_fades_from_pulse_tint_to_tint() {
+        let tint = Tint(Srgba::new(0.5, 1., 1., 1.));
+        let Tint(pulse_tint) = ChargeTintPulseSystem::pulse_tint(ChargeLevel::Level3);
+
+        let Tint(tint_begin) =
+            ChargeTintPulseSystem::pulse_blend(tint, ChargeTintPulse::new(ChargeLevel::Level3));
+        assert_relative_eq!(pulse_tint.red, tint_begin.red);
+        assert_relative_eq!(pulse_tint.green, tint_begin.green);
+        assert_relative_eq!(pulse_tint.blue, tint_begin.blue);
+
+        let charge_tint_pulse_end = ChargeTintPulse {
+            charge_level: ChargeLevel::Level3,
+            ticks: CHARGE_TINT_PULSE_DURATION,
+        };
+        let Tint(tint_end) = ChargeTintPulseSystem::pulse_blend(tint, charge_tint_pulse_end);
+        assert_relative_eq!(0.5, tint_end.red);
+        assert_relative_eq!(1., tint_end.green);
+        assert_relative_eq!(1., tint_end.blue);
+    }
+
+    #[test]
+    fn inserts_pulse_on_charge_level_event() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                ChargeTintPulseSystem::new(),
+                any::type_name::<ChargeTintPulseSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let entity = world.create_entity().build();
+                world
+                    .write_resource::<EventChannel<ChargeLevelEvent>>()
+                    .single_write(ChargeLevelEvent::new(entity, ChargeLevel::Level3));
+
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let charge_tint_pulses = world.read_storage::<ChargeTintPulse>();
+
+                let charge_tint_pulse_expected = ChargeTintPulse {
+                    charge_level: ChargeLevel::Level3,
+                    ticks: 1,
+                };
+                assert_eq!(
+                    Some(&charge_tint_pulse_expected),
+                    charge_tint_pulses.get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn removes_pulse_when_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                ChargeTintPulseSystem::new(),
+                any::type_name::<ChargeTintPulseSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let charge_tint_pulse = ChargeTintPulse {
+                    charge_level: ChargeLevel::Level1,
+                    ticks: CHARGE_TINT_PULSE_DURATION - 1,
+                };
+                let entity = world.create_entity().with(charge_tint_pulse).build();
+
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let charge_tint_pulses = world.read_storage::<ChargeTintPulse>();
+
+                assert!(charge_tint_pulses.get(entity).is_none());
+            })
+            .run()
+    }
+}
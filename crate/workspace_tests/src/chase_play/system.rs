@@ -1 +1,4 @@
+mod follow_target_object_system;
+mod home_towards_enemy_system;
+mod orbit_target_object_system;
 mod stick_to_target_object_system;
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        core::math::Vector3,
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::{config::ChaseMode, loaded::ChaseModeOpt, play::TargetObject};
+    use kinematic_model::config::{FixedPoint, Position};
+
+    use chase_play::FollowTargetObjectSystem;
+
+    #[test]
+    fn moves_to_target_position_when_delay_is_zero() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FollowTargetObjectSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_target_and_follower(world, Some(ChaseMode::Follow { delay: 0 }))
+            })
+            .with_assertion(|world| assert_follower_position(world, 10., 0., 5.))
+            .run()
+    }
+
+    #[test]
+    fn does_not_move_until_delay_has_elapsed() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FollowTargetObjectSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_target_and_follower(world, Some(ChaseMode::Follow { delay: 2 }))
+            })
+            .with_effect(|world| set_target_position(world, 20., 0., 5.))
+            .with_assertion(|world| assert_follower_position(world, 0., 0., 0.))
+            .run()
+    }
+
+    #[test]
+    fn moves_to_target_position_delay_ticks_ago() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FollowTargetObjectSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_target_and_follower(world, Some(ChaseMode::Follow { delay: 2 }))
+            })
+            .with_effect(|world| set_target_position(world, 20., 0., 5.))
+            .with_effect(|world| set_target_position(world, 30., 0., 5.))
+            .with_assertion(|world| assert_follower_position(world, 10., 0., 5.))
+            .run()
+    }
+
+    #[test]
+    fn does_not_update_position_for_other_chase_modes() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FollowTargetObjectSystem::new(), "", &[])
+            .with_effect(|world| create_target_and_follower(world, None))
+            .with_assertion(|world| assert_follower_position(world, 0., 0., 0.))
+            .run()
+    }
+
+    fn create_target_and_follower(world: &mut World, chase_mode: Option<ChaseMode>) {
+        let target = world
+            .create_entity()
+            .with(Position::<FixedPoint>::from_f32(Vector3::new(10., 0., 5.)))
+            .build();
+        let follower = world
+            .create_entity()
+            .with(ChaseModeOpt::new(chase_mode))
+            .with(TargetObject::new(target))
+            .with(Position::<FixedPoint>::default())
+            .build();
+
+        world.insert((target, follower));
+    }
+
+    fn set_target_position(world: &mut World, x: f32, y: f32, z: f32) {
+        let (target, _follower) = *world.read_resource::<(Entity, Entity)>();
+        let mut positions = world.write_storage::<Position<FixedPoint>>();
+        positions
+            .insert(target, Position::from_f32(Vector3::new(x, y, z)))
+            .expect("Failed to insert `Position<FixedPoint>` component.");
+    }
+
+    fn assert_follower_position(world: &mut World, x: f32, y: f32, z: f32) {
+        let (_target, follower) = *world.read_resource::<(Entity, Entity)>();
+        let positions = world.read_storage::<Position<FixedPoint>>();
+        let follower_position = positions
+            .get(follower)
+            .expect("Expected follower entity to have `Position` component.");
+
+        assert_eq!(
+            &Position::from_f32(Vector3::new(x, y, z)),
+            follower_position
+        );
+    }
+}
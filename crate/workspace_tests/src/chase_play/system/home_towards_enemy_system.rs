@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        core::math::Vector3,
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::{config::ChaseMode, loaded::ChaseModeOpt};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use team_model::play::{Team, TeamCounter};
+
+    use chase_play::HomeTowardsEnemySystem;
+
+    const HOMING: ChaseMode = ChaseMode::Homing {
+        speed: FixedPoint::from_int(5),
+        turn_rate: FixedPoint::from_ratio(1, 2),
+    };
+
+    #[test]
+    fn moves_towards_enemy_ahead_at_speed() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: Some(HOMING),
+                velocity: Vector3::new(0., 0., 0.),
+                others: vec![(1, 100, Vector3::new(100., 0., 0.))],
+            },
+            Vector3::new(5., 0., 0.),
+            false,
+        )
+    }
+
+    #[test]
+    fn turns_towards_enemy_by_at_most_turn_rate() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: Some(HOMING),
+                velocity: Vector3::new(5., 3., 0.),
+                others: vec![(1, 100, Vector3::new(0., 0., 100.))],
+            },
+            Vector3::new(5. * 0.5f32.cos(), 3., 5. * 0.5f32.sin()),
+            false,
+        )
+    }
+
+    #[test]
+    fn moves_towards_nearest_enemy() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: Some(ChaseMode::Homing {
+                    speed: FixedPoint::from_int(5),
+                    turn_rate: FixedPoint::PI,
+                }),
+                velocity: Vector3::new(0., 0., 0.),
+                others: vec![
+                    (1, 100, Vector3::new(100., 0., 0.)),
+                    (1, 100, Vector3::new(-50., 0., 0.)),
+                ],
+            },
+            Vector3::new(-5., 0., 0.),
+            true,
+        )
+    }
+
+    #[test]
+    fn ignores_allies_and_defeated_enemies() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: Some(ChaseMode::Homing {
+                    speed: FixedPoint::from_int(5),
+                    turn_rate: FixedPoint::PI,
+                }),
+                velocity: Vector3::new(0., 0., 0.),
+                others: vec![
+                    (0, 100, Vector3::new(-10., 0., 0.)),
+                    (1, 0, Vector3::new(-20., 0., 0.)),
+                    (1, 100, Vector3::new(100., 0., 0.)),
+                ],
+            },
+            Vector3::new(5., 0., 0.),
+            false,
+        )
+    }
+
+    #[test]
+    fn keeps_direction_when_there_is_no_enemy() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: Some(HOMING),
+                velocity: Vector3::new(0., 0., -2.),
+                others: vec![(0, 100, Vector3::new(100., 0., 0.))],
+            },
+            Vector3::new(0., 0., -5.),
+            false,
+        )
+    }
+
+    #[test]
+    fn does_not_update_velocity_for_other_chase_modes() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: Some(ChaseMode::Follow { delay: 1 }),
+                velocity: Vector3::new(1., 2., 3.),
+                others: vec![(1, 100, Vector3::new(-100., 0., 0.))],
+            },
+            Vector3::new(1., 2., 3.),
+            false,
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            chase_mode,
+            velocity,
+            others,
+        }: SetupParams,
+        velocity_expected: Vector3<f32>,
+        mirrored_expected: bool,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(HomeTowardsEnemySystem::new(), "", &[])
+            .with_effect(move |world| {
+                others.iter().for_each(|(team, health_points, position)| {
+                    world
+                        .create_entity()
+                        .with(Team::Number(TeamCounter::new(*team)))
+                        .with(HealthPoints::new(*health_points))
+                        .with(Position::<FixedPoint>::from_f32(*position))
+                        .build();
+                });
+
+                let entity = world
+                    .create_entity()
+                    .with(ChaseModeOpt::new(chase_mode))
+                    .with(Team::Number(TeamCounter::new(0)))
+                    .with(Position::<FixedPoint>::default())
+                    .with(Velocity::<FixedPoint>::from_f32(velocity))
+                    .with(Mirrored(false))
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                assert_velocity_and_mirrored(world, velocity_expected, mirrored_expected)
+            })
+            .run()
+    }
+
+    fn assert_velocity_and_mirrored(
+        world: &mut World,
+        velocity_expected: Vector3<f32>,
+        mirrored_expected: bool,
+    ) {
+        let entity = *world.read_resource::<Entity>();
+        let velocities = world.read_storage::<Velocity<FixedPoint>>();
+        let mirroreds = world.read_storage::<Mirrored>();
+        let velocity = velocities
+            .get(entity)
+            .copied()
+            .expect("Expected entity to have `Velocity<FixedPoint>` component.")
+            .to_f32();
+
+        assert!(
+            (velocity - velocity_expected).amax() < 0.01,
+            "Expected velocity to be `{}`, but was `{}`.",
+            velocity_expected,
+            velocity
+        );
+        assert_eq!(Some(&Mirrored(mirrored_expected)), mirroreds.get(entity));
+    }
+
+    struct SetupParams {
+        chase_mode: Option<ChaseMode>,
+        velocity: Vector3<f32>,
+        others: Vec<(u32, u32, Vector3<f32>)>,
+    }
+}
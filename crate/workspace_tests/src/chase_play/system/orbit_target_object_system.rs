@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        core::math::Vector3,
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::{config::ChaseMode, loaded::ChaseModeOpt, play::TargetObject};
+    use kinematic_model::config::{FixedPoint, Position};
+    use spawn_model::play::SpawnParent;
+    use tracker::Last;
+
+    use chase_play::OrbitTargetObjectSystem;
+
+    #[test]
+    fn rotates_around_target_by_angular_speed() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: orbit(10, FixedPoint::PI / FixedPoint::from(2)),
+                target_link: TargetLink::TargetObject,
+                target_position: Vector3::new(100., 0., 0.),
+                target_position_last: None,
+                position: Vector3::new(110., 20., 0.),
+                position_last: None,
+            },
+            Vector3::new(100., 20., 10.),
+        )
+    }
+
+    #[test]
+    fn moves_with_target_using_last_positions() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: orbit(10, FixedPoint::PI),
+                target_link: TargetLink::TargetObject,
+                target_position: Vector3::new(150., 0., 0.),
+                target_position_last: Some(Vector3::new(100., 0., 0.)),
+                position: Vector3::new(115., 20., 0.),
+                position_last: Some(Vector3::new(110., 20., 0.)),
+            },
+            Vector3::new(140., 20., 0.),
+        )
+    }
+
+    #[test]
+    fn keeps_radius_from_target() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: orbit(20, FixedPoint::ZERO),
+                target_link: TargetLink::TargetObject,
+                target_position: Vector3::new(100., 0., 50.),
+                target_position_last: None,
+                position: Vector3::new(100., 0., 55.),
+                position_last: None,
+            },
+            Vector3::new(100., 0., 70.),
+        )
+    }
+
+    #[test]
+    fn starts_on_the_right_of_target_when_on_target() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: orbit(20, FixedPoint::ONE),
+                target_link: TargetLink::TargetObject,
+                target_position: Vector3::new(100., 0., 50.),
+                target_position_last: None,
+                position: Vector3::new(100., 0., 50.),
+                position_last: None,
+            },
+            Vector3::new(120., 0., 50.),
+        )
+    }
+
+    #[test]
+    fn orbits_spawn_parent_when_there_is_no_target_object() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: orbit(10, FixedPoint::PI),
+                target_link: TargetLink::SpawnParent,
+                target_position: Vector3::new(100., 0., 0.),
+                target_position_last: None,
+                position: Vector3::new(110., 0., 0.),
+                position_last: None,
+            },
+            Vector3::new(90., 0., 0.),
+        )
+    }
+
+    #[test]
+    fn does_not_update_position_without_target() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase_mode: orbit(10, FixedPoint::PI),
+                target_link: TargetLink::None,
+                target_position: Vector3::new(100., 0., 0.),
+                target_position_last: None,
+                position: Vector3::new(110., 0., 0.),
+                position_last: None,
+            },
+            Vector3::new(110., 0., 0.),
+        )
+    }
+
+    fn orbit(radius: i32, angular_speed: FixedPoint) -> Option<ChaseMode> {
+        Some(ChaseMode::Orbit {
+            radius: FixedPoint::from(radius),
+            angular_speed,
+        })
+    }
+
+    fn run_test(
+        SetupParams {
+            chase_mode,
+            target_link,
+            target_position,
+            target_position_last,
+            position,
+            position_last,
+        }: SetupParams,
+        position_expected: Vector3<f32>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(OrbitTargetObjectSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let target = {
+                    let mut entity_builder = world
+                        .create_entity()
+                        .with(Position::<FixedPoint>::from_f32(target_position));
+                    if let Some(target_position_last) = target_position_last {
+                        entity_builder = entity_builder
+                            .with(Last(Position::<FixedPoint>::from_f32(target_position_last)));
+                    }
+                    entity_builder.build()
+                };
+
+                let mut entity_builder = world
+                    .create_entity()
+                    .with(ChaseModeOpt::new(chase_mode))
+                    .with(Position::<FixedPoint>::from_f32(position));
+                if let Some(position_last) = position_last {
+                    entity_builder =
+                        entity_builder.with(Last(Position::<FixedPoint>::from_f32(position_last)));
+                }
+                match target_link {
+                    TargetLink::TargetObject => {
+                        entity_builder = entity_builder.with(TargetObject::new(target));
+                    }
+                    TargetLink::SpawnParent => {
+                        entity_builder = entity_builder.with(SpawnParent::new(target));
+                    }
+                    TargetLink::None => {}
+                }
+                let entity = entity_builder.build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| assert_position(world, position_expected))
+            .run()
+    }
+
+    fn assert_position(world: &mut World, position_expected: Vector3<f32>) {
+        let entity = *world.read_resource::<Entity>();
+        let positions = world.read_storage::<Position<FixedPoint>>();
+        let position = positions
+            .get(entity)
+            .copied()
+            .expect("Expected entity to have `Position<FixedPoint>` component.")
+            .to_f32();
+
+        assert!(
+            (position - position_expected).amax() < 0.01,
+            "Expected position to be `{}`, but was `{}`.",
+            position_expected,
+            position
+        );
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum TargetLink {
+        None,
+        TargetObject,
+        SpawnParent,
+    }
+
+    struct SetupParams {
+        chase_mode: Option<ChaseMode>,
+        target_link: TargetLink,
+        target_position: Vector3<f32>,
+        target_position_last: Option<Vector3<f32>>,
+        position: Vector3<f32>,
+        position_last: Option<Vector3<f32>>,
+    }
+}
@@ -7,7 +7,7 @@ mod tests {
     };
     use amethyst_test::AmethystApplication;
     use chase_model::play::{ChaseModeStick, TargetObject};
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position};
 
     use chase_play::StickToTargetObjectSystem;
 
@@ -52,7 +52,11 @@ mod tests {
             .with_effect(|world| {
                 create_target_and_child_entity(
                     world,
-                    WithChaseModeStick::Present(Some(Position::new(10., 20., 30.))),
+                    WithChaseModeStick::Present(Some(Position::new(
+                        FixedPoint::from(10),
+                        FixedPoint::from(20),
+                        FixedPoint::from(30),
+                    ))),
                     true,
                     false,
                 )
@@ -69,7 +73,11 @@ mod tests {
             .with_effect(|world| {
                 create_target_and_child_entity(
                     world,
-                    WithChaseModeStick::Present(Some(Position::new(10., 20., 30.))),
+                    WithChaseModeStick::Present(Some(Position::new(
+                        FixedPoint::from(10),
+                        FixedPoint::from(20),
+                        FixedPoint::from(30),
+                    ))),
                     false,
                     true,
                 )
@@ -110,7 +118,7 @@ mod tests {
                 entity_builder = entity_builder.with(Transform::default());
             }
             if with_position {
-                entity_builder = entity_builder.with(Position::<f32>::default());
+                entity_builder = entity_builder.with(Position::<FixedPoint>::default());
             }
 
             entity_builder.build()
@@ -130,10 +138,10 @@ mod tests {
 
     fn set_target_position(world: &mut World, x: f32, y: f32, z: f32) {
         let (target, _child) = *world.read_resource::<(Entity, Entity)>();
-        let mut positions = world.write_storage::<Position<f32>>();
+        let mut positions = world.write_storage::<Position<FixedPoint>>();
         positions
-            .insert(target, Position::new(x, y, z))
-            .expect("Failed to insert `Position<f32>` component.");
+            .insert(target, Position::from_f32(Vector3::new(x, y, z)))
+            .expect("Failed to insert `Position<FixedPoint>` component.");
     }
 
     fn assert_child_entity_translation(world: &mut World, x: f32, y: f32, z: f32) {
@@ -151,17 +159,17 @@ mod tests {
 
     fn assert_child_entity_position(world: &mut World, x: f32, y: f32, z: f32) {
         let (_target, child) = *world.read_resource::<(Entity, Entity)>();
-        let positions = world.read_storage::<Position<f32>>();
+        let positions = world.read_storage::<Position<FixedPoint>>();
         let child_position = positions
             .get(child)
             .expect("Expected child entity to have `Position` component.");
 
-        assert_eq!(&Position::new(x, y, z), child_position);
+        assert_eq!(&Position::from_f32(Vector3::new(x, y, z)), child_position);
     }
 
     #[derive(Clone, Copy, Debug)]
     enum WithChaseModeStick {
         Absent,
-        Present(Option<Position<f32>>),
+        Present(Option<Position<FixedPoint>>),
     }
 }
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod test {
     use kinematic_model::config::Acceleration;
-    use object_status_model::config::StunPoints;
+    use object_status_model::config::{
+        StatusEffect, StatusEffectKind, StatusEffectStacking, StunPoints,
+    };
     use serde_yaml;
 
     use collision_model::config::{Hit, HitLimit, HitRepeatDelay};
@@ -13,6 +15,10 @@ hp_damage: 3
 sp_damage: 4
 stun: 5
 acceleration: { x: -1, y: 2 }
+"#;
+
+    const HIT_STATUS_EFFECT_YAML: &str = r#"---
+status_effect: { kind: freeze, duration: 40, stacking: extend }
 "#;
 
     #[test]
@@ -31,4 +37,21 @@ acceleration: { x: -1, y: 2 }
 
         assert_eq!(expected, hit_deserialized);
     }
+
+    #[test]
+    fn deserialize_hit_with_status_effect() {
+        let hit_deserialized = serde_yaml::from_str::<Hit>(HIT_STATUS_EFFECT_YAML)
+            .expect("Failed to deserialize `Hit`.");
+
+        let expected = Hit {
+            status_effect: Some(StatusEffect {
+                kind: StatusEffectKind::Freeze,
+                duration: 40,
+                stacking: StatusEffectStacking::Extend,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(expected, hit_deserialized);
+    }
 }
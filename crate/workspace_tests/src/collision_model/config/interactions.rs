@@ -44,6 +44,7 @@ interactions:
                 sp_damage: 50,
                 stun: StunPoints::new(33),
                 acceleration: Acceleration::new(-1, 2, 0),
+                status_effect: None,
             }),
             bounds: vec![Volume::Sphere {
                 x: 1,
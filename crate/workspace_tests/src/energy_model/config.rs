@@ -40,15 +40,18 @@ sequences:
             }]),
             ..Default::default()
         })];
-        let sequence = EnergySequence::new(ObjectSequence {
-            sequence: Sequence {
-                next: SequenceEndTransition::SequenceName(SequenceNameString::Name(
-                    EnergySequenceName::Hover,
-                )),
-                frames,
+        let sequence = EnergySequence::new(
+            ObjectSequence {
+                sequence: Sequence {
+                    next: SequenceEndTransition::SequenceName(SequenceNameString::Name(
+                        EnergySequenceName::Hover,
+                    )),
+                    frames,
+                },
+                ..Default::default()
             },
-            ..Default::default()
-        });
+            None,
+        );
         let mut sequences = IndexMap::new();
         sequences.insert(
             SequenceNameString::Name(EnergySequenceName::Hover),
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+    use chase_model::config::ChaseMode;
+    use kinematic_model::config::FixedPoint;
     use object_model::config::{ObjectFrame, ObjectSequence};
     use sequence_model::config::{Sequence, Wait};
     use serde_yaml;
@@ -12,6 +14,18 @@ mod tests {
 frames:
   - wait: 2
     sprite: { sheet: 0, index: 4 }
+"#;
+    const SEQUENCE_WITH_CHASE_HOMING: &str = r#"---
+chase: { homing: { speed: 6, turn_rate: 0.125 } }
+frames: []
+"#;
+    const SEQUENCE_WITH_CHASE_ORBIT: &str = r#"---
+chase: { orbit: { radius: 40, angular_speed: 0.25 } }
+frames: []
+"#;
+    const SEQUENCE_WITH_CHASE_FOLLOW: &str = r#"---
+chase: { follow: { delay: 10 } }
+frames: []
 "#;
 
     #[test]
@@ -33,14 +47,50 @@ frames:
             sprite: SpriteRef::new(0, 4),
             ..Default::default()
         })];
-        let expected = EnergySequence::new(ObjectSequence {
-            sequence: Sequence {
-                frames,
+        let expected = EnergySequence::new(
+            ObjectSequence {
+                sequence: Sequence {
+                    frames,
+                    ..Default::default()
+                },
                 ..Default::default()
             },
-            ..Default::default()
-        });
+            None,
+        );
 
         assert_eq!(expected, sequence);
     }
+
+    #[test]
+    fn sequence_with_chase_homing() {
+        let sequence = serde_yaml::from_str::<EnergySequence>(SEQUENCE_WITH_CHASE_HOMING)
+            .expect("Failed to deserialize sequence.");
+
+        let expected = Some(ChaseMode::Homing {
+            speed: FixedPoint::from(6),
+            turn_rate: FixedPoint::from_ratio(1, 8),
+        });
+        assert_eq!(expected, sequence.chase);
+    }
+
+    #[test]
+    fn sequence_with_chase_orbit() {
+        let sequence = serde_yaml::from_str::<EnergySequence>(SEQUENCE_WITH_CHASE_ORBIT)
+            .expect("Failed to deserialize sequence.");
+
+        let expected = Some(ChaseMode::Orbit {
+            radius: FixedPoint::from(40),
+            angular_speed: FixedPoint::from_ratio(1, 4),
+        });
+        assert_eq!(expected, sequence.chase);
+    }
+
+    #[test]
+    fn sequence_with_chase_follow() {
+        let sequence = serde_yaml::from_str::<EnergySequence>(SEQUENCE_WITH_CHASE_FOLLOW)
+            .expect("Failed to deserialize sequence.");
+
+        let expected = Some(ChaseMode::Follow { delay: 10 });
+        assert_eq!(expected, sequence.chase);
+    }
 }
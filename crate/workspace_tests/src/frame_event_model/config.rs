@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde::{Deserialize, Serialize};
+    use serde_yaml;
+    use sprite_model::config::Tint;
+
+    use frame_event_model::config::{FrameEvent, FrameEvents};
+
+    const FRAME_EVENTS_YAML: &str = r#"
+events:
+  - camera_shake: { magnitude: 4.0, duration: 10 }
+  - screen_flash: { color: { g: 0.5, b: 0.5 }, duration: 6 }
+  - self_tint: { a: 0.5 }
+  - hit_tint: { color: { g: 0.2, b: 0.2 }, duration: 8 }
+  - sound: { path: "sfx/hit.wav" }
+  - sound: { path: "sfx/hit.wav", volume: 0.5, pitch: 1.5 }
+  - detach
+  - custom: "sparkle"
+"#;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Config {
+        events: FrameEvents,
+    }
+
+    #[test]
+    fn deserialize_frame_events() {
+        let config = serde_yaml::from_str::<Config>(FRAME_EVENTS_YAML)
+            .expect("Failed to deserialize `FrameEvents`.");
+
+        assert_eq!(
+            FrameEvents::new(vec![
+                FrameEvent::CameraShake {
+                    magnitude: 4.,
+                    duration: 10,
+                },
+                FrameEvent::ScreenFlash {
+                    color: Tint::new(1., 0.5, 0.5, 1.),
+                    duration: 6,
+                },
+                FrameEvent::SelfTint(Tint::new(1., 1., 1., 0.5)),
+                FrameEvent::HitTint {
+                    color: Tint::new(1., 0.2, 0.2, 1.),
+                    duration: 8,
+                },
+                FrameEvent::Sound {
+                    path: PathBuf::from("sfx/hit.wav"),
+                    volume: 1.,
+                    pitch: 1.,
+                },
+                FrameEvent::Sound {
+                    path: PathBuf::from("sfx/hit.wav"),
+                    volume: 0.5,
+                    pitch: 1.5,
+                },
+                FrameEvent::Detach,
+                FrameEvent::Custom(String::from("sparkle")),
+            ]),
+            config.events
+        );
+    }
+
+    #[test]
+    fn deserialize_frame_events_rejects_unknown_event() {
+        let result = serde_yaml::from_str::<Config>("events: [unknown]");
+
+        assert!(result.is_err());
+    }
+}
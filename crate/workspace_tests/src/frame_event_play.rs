@@ -0,0 +1,2 @@
+mod system;
+mod wav_pitch;
@@ -0,0 +1,2 @@
+mod frame_event_system;
+mod hit_tint_system;
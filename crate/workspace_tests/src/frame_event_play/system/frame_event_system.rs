@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, Join, World, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        shrev::{EventChannel, ReaderId},
+        window::ScreenDimensions,
+        Error,
+    };
+    use amethyst_test::{AmethystApplication, HIDPI, SCREEN_HEIGHT, SCREEN_WIDTH};
+    use camera_model::play::{CameraShake, ScreenFlash};
+    use camera_play::CameraCreator;
+    use frame_event_model::{
+        loaded::{FrameEvent, FrameEvents},
+        play::CustomFrameEvent,
+    };
+    use parent_model::play::ParentEntity;
+    use pretty_assertions::assert_eq;
+    use sequence_model::{loaded::SequenceId, play::SequenceUpdateEvent};
+
+    use frame_event_play::FrameEventSystem;
+
+    #[test]
+    fn leaves_self_tint_to_tint_resolution() -> Result<(), Error> {
+        run_test(
+            vec![FrameEvent::SelfTint(Tint(Srgba::new(1., 0.5, 0.5, 1.)))],
+            frame_begin,
+            |world, entity| {
+                let tints = world.read_storage::<Tint>();
+                assert_eq!(None, tints.get(entity));
+            },
+        )
+    }
+
+    #[test]
+    fn removes_parent_entity_on_detach() -> Result<(), Error> {
+        run_test(vec![FrameEvent::Detach], sequence_begin, |world, entity| {
+            let parent_entities = world.read_storage::<ParentEntity>();
+            assert!(parent_entities.get(entity).is_none());
+        })
+    }
+
+    #[test]
+    fn does_not_apply_events_on_sequence_end() -> Result<(), Error> {
+        run_test(vec![FrameEvent::Detach], sequence_end, |world, entity| {
+            let parent_entities = world.read_storage::<ParentEntity>();
+            assert!(parent_entities.get(entity).is_some());
+        })
+    }
+
+    #[test]
+    fn sends_custom_frame_event() -> Result<(), Error> {
+        run_test(
+            vec![FrameEvent::Custom(String::from("sparkle"))],
+            frame_begin,
+            |world, entity| {
+                let mut reader_id = world.write_resource::<ReaderId<CustomFrameEvent>>();
+                let custom_frame_ec = world.read_resource::<EventChannel<CustomFrameEvent>>();
+                let custom_frame_events = custom_frame_ec
+                    .read(&mut reader_id)
+                    .cloned()
+                    .collect::<Vec<CustomFrameEvent>>();
+
+                assert_eq!(
+                    vec![CustomFrameEvent::new(entity, String::from("sparkle"))],
+                    custom_frame_events
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn inserts_camera_shake_on_camera() -> Result<(), Error> {
+        run_test(
+            vec![FrameEvent::CameraShake {
+                magnitude: 4.,
+                duration: 10,
+            }],
+            frame_begin,
+            |world, _entity| {
+                let camera_entity = *world.read_resource::<CameraEntity>();
+                let camera_shakes = world.read_storage::<CameraShake>();
+                assert_eq!(
+                    Some(&CameraShake::new(4., 10)),
+                    camera_shakes.get(camera_entity.0)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn creates_screen_flash_entity() -> Result<(), Error> {
+        run_test(
+            vec![FrameEvent::ScreenFlash {
+                color: Tint(Srgba::new(1., 1., 1., 0.8)),
+                duration: 6,
+            }],
+            frame_begin,
+            |world, _entity| {
+                let screen_flashes = world
+                    .read_storage::<ScreenFlash>()
+                    .join()
+                    .copied()
+                    .collect::<Vec<ScreenFlash>>();
+                assert_eq!(vec![ScreenFlash::new([1., 1., 1., 0.8], 6)], screen_flashes);
+            },
+        )
+    }
+
+    fn run_test(
+        frame_events: Vec<FrameEvent>,
+        sequence_update_event_fn: fn(Entity) -> SequenceUpdateEvent,
+        assertion_fn: fn(&mut World, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(ScreenDimensions::new(SCREEN_WIDTH, SCREEN_HEIGHT, HIDPI))
+            .with_system(
+                FrameEventSystem::new(),
+                any::type_name::<FrameEventSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let camera_entity = CameraCreator::create_in_world(world);
+                world.insert(CameraEntity(camera_entity));
+
+                let reader_id = world
+                    .write_resource::<EventChannel<CustomFrameEvent>>()
+                    .register_reader();
+                world.insert(reader_id);
+
+                let parent = world.create_entity().build();
+                let entity = world
+                    .create_entity()
+                    .with(FrameEvents::new(frame_events))
+                    .with(ParentEntity::new(parent))
+                    .build();
+                world.insert(entity);
+
+                world
+                    .write_resource::<EventChannel<SequenceUpdateEvent>>()
+                    .single_write(sequence_update_event_fn(entity));
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                assertion_fn(world, entity);
+            })
+            .run()
+    }
+
+    fn sequence_begin(entity: Entity) -> SequenceUpdateEvent {
+        SequenceUpdateEvent::SequenceBegin {
+            entity,
+            sequence_id: SequenceId::new(0),
+        }
+    }
+
+    fn frame_begin(entity: Entity) -> SequenceUpdateEvent {
+        SequenceUpdateEvent::FrameBegin {
+            entity,
+            frame_index: 1,
+        }
+    }
+
+    fn sequence_end(entity: Entity) -> SequenceUpdateEvent {
+        SequenceUpdateEvent::SequenceEnd {
+            entity,
+            frame_index: 1,
+        }
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct CameraEntity(Entity);
+}
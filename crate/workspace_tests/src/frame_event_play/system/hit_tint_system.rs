@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use frame_event_model::{
+        loaded::{FrameEvent, FrameEvents},
+        play::HitTint,
+    };
+    use pretty_assertions::assert_eq;
+    use shape_model::Volume;
+
+    use frame_event_play::HitTintSystem;
+
+    #[test]
+    fn inserts_hit_tint_on_hit_object() -> Result<(), Error> {
+        run_test(
+            vec![FrameEvent::HitTint {
+                color: color(),
+                duration: 10,
+            }],
+            None,
+            Some(HitTint::new(color(), 10)),
+        )
+    }
+
+    #[test]
+    fn does_not_insert_hit_tint_when_frame_has_no_hit_tint() -> Result<(), Error> {
+        run_test(vec![FrameEvent::Detach], None, None)
+    }
+
+    #[test]
+    fn ticks_hit_tint() -> Result<(), Error> {
+        run_test(
+            vec![],
+            Some(HitTint::new(color(), 10)),
+            Some(HitTint {
+                color: color(),
+                duration: 10,
+                ticks: 1,
+            }),
+        )
+    }
+
+    #[test]
+    fn removes_hit_tint_when_complete() -> Result<(), Error> {
+        run_test(vec![], Some(HitTint::new(color(), 1)), None)
+    }
+
+    #[test]
+    fn tint_blend_fades_from_hit_tint_colour() {
+        let tint = Tint(Srgba::new(1., 1., 1., 1.));
+        let hit_tint = HitTint::new(color(), 4);
+
+        assert_eq!(color(), HitTintSystem::tint_blend(tint, hit_tint));
+        assert_eq!(
+            Tint(Srgba::new(1., 0.75, 0.75, 1.)),
+            HitTintSystem::tint_blend(
+                tint,
+                HitTint {
+                    ticks: 2,
+                    ..hit_tint
+                }
+            )
+        );
+        assert_eq!(
+            tint,
+            HitTintSystem::tint_blend(
+                tint,
+                HitTint {
+                    ticks: 4,
+                    ..hit_tint
+                }
+            )
+        );
+    }
+
+    fn run_test(
+        frame_events: Vec<FrameEvent>,
+        hit_tint_initial: Option<HitTint>,
+        hit_tint_expected: Option<HitTint>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(HitTintSystem::new(), any::type_name::<HitTintSystem>(), &[])
+            .with_effect(move |world| {
+                let entity_from = world
+                    .create_entity()
+                    .with(FrameEvents::new(frame_events.clone()))
+                    .build();
+                let mut entity_to_builder = world.create_entity();
+                if let Some(hit_tint_initial) = hit_tint_initial {
+                    entity_to_builder = entity_to_builder.with(hit_tint_initial);
+                }
+                let entity_to = entity_to_builder.build();
+
+                // Objects that are already tinted are not hit again, so that they only tick.
+                if hit_tint_initial.is_none() {
+                    world
+                        .write_resource::<EventChannel<HitEvent>>()
+                        .single_write(HitEvent::new(entity_from, entity_to, interaction(), body()));
+                }
+
+                world.insert(entity_to);
+            })
+            .with_assertion(move |world| {
+                let entity_to = *world.read_resource::<Entity>();
+                let hit_tints = world.read_storage::<HitTint>();
+
+                assert_eq!(hit_tint_expected, hit_tints.get(entity_to).copied());
+            })
+            .run()
+    }
+
+    fn color() -> Tint {
+        Tint(Srgba::new(1., 0., 0., 1.))
+    }
+
+    fn interaction() -> Interaction {
+        Interaction::new(InteractionKind::Hit(Hit::default()), vec![], true)
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use frame_event_play::WavPitch;
+
+    #[test]
+    fn scales_sample_rate_and_byte_rate() {
+        let bytes = wav_bytes(&[], 44_100);
+
+        let bytes_pitched = WavPitch::apply(&bytes, 1.5).expect("Expected pitch to be applied.");
+
+        assert_eq!(wav_bytes(&[], 66_150), bytes_pitched);
+    }
+
+    #[test]
+    fn skips_chunks_before_fmt_chunk() {
+        // Odd length chunks are padded with one byte.
+        let chunk_list = [b'L', b'I', b'S', b'T', 3, 0, 0, 0, 1, 2, 3, 0];
+        let bytes = wav_bytes(&chunk_list, 22_050);
+
+        let bytes_pitched = WavPitch::apply(&bytes, 0.5).expect("Expected pitch to be applied.");
+
+        assert_eq!(wav_bytes(&chunk_list, 11_025), bytes_pitched);
+    }
+
+    #[test]
+    fn returns_none_when_not_wav() {
+        assert_eq!(None, WavPitch::apply(b"OggS\0\0\0\0\0\0\0\0\0\0\0\0", 1.5));
+    }
+
+    #[test]
+    fn returns_none_when_pitch_not_positive() {
+        let bytes = wav_bytes(&[], 44_100);
+
+        assert_eq!(None, WavPitch::apply(&bytes, 0.));
+        assert_eq!(None, WavPitch::apply(&bytes, -1.));
+    }
+
+    /// Returns the bytes of a 16-bit stereo PCM WAV file with no samples.
+    fn wav_bytes(chunks_before_fmt: &[u8], sample_rate: u32) -> Vec<u8> {
+        let channels = 2u16;
+        let block_align = 4u16;
+        let byte_rate = sample_rate * u32::from(block_align);
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + chunks_before_fmt.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"WAVE");
+        bytes.extend_from_slice(chunks_before_fmt);
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&byte_rate.to_le_bytes());
+        bytes.extend_from_slice(&block_align.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes
+    }
+}
@@ -1 +1,2 @@
+mod controller_input;
 mod move_direction;
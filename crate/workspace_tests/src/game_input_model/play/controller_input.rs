@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::ecs::{Builder, Entity, World, WorldExt};
+    use game_input_model::{
+        config::{Axis, ControlAction},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn change_events_is_empty_when_input_is_unchanged() {
+        let entity = entity();
+        let current = ControllerInput::new(1., 0., true, false, false, false);
+
+        assert_eq!(
+            Vec::<ControlInputEvent>::new(),
+            current.change_events(current, entity, 0)
+        );
+    }
+
+    #[test]
+    fn change_events_moves_axes_that_differ() {
+        let entity = entity();
+        let current = ControllerInput::new(1., 0., false, false, false, false);
+        let desired = ControllerInput::new(-1., 0., false, false, false, false);
+
+        assert_eq!(
+            vec![ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id: 2,
+                entity,
+                axis: Axis::X,
+                value: -1.,
+            })],
+            current.change_events(desired, entity, 2)
+        );
+    }
+
+    #[test]
+    fn change_events_presses_and_releases_control_actions_that_differ() {
+        let entity = entity();
+        let current = ControllerInput::new(0., 0., true, false, false, false);
+        let desired = ControllerInput::new(0., 0., false, false, true, false);
+
+        assert_eq!(
+            vec![
+                ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                    controller_id: 1,
+                    entity,
+                    control_action: ControlAction::Defend,
+                }),
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    controller_id: 1,
+                    entity,
+                    control_action: ControlAction::Attack,
+                }),
+            ],
+            current.change_events(desired, entity, 1)
+        );
+    }
+
+    fn entity() -> Entity {
+        World::new().create_entity().build()
+    }
+}
@@ -3,7 +3,7 @@ mod tests {
     use std::{any, collections::HashMap};
 
     use amethyst::{
-        ecs::{Join, Read, ReadStorage, World, WorldExt},
+        ecs::{Entities, Join, Read, ReadStorage, World, WorldExt, WriteStorage},
         shred::SystemData,
         Error, State, StateData, Trans,
     };
@@ -15,13 +15,20 @@ mod tests {
     };
     use assets_test::MAP_FADE_SLUG;
     use character_selection_model::CharacterSelections;
+    use game_input_model::{config::ControllerId, play::InputControlled};
     use game_model::play::GameEntities;
     use game_play_hud::{CpBar, HpBar};
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position, PositionInit};
     use loading_model::loaded::{AssetLoadStage, LoadStage};
+    use map_model::{
+        config::{SpawnPoint, SpawnPoints},
+        loaded::AssetSpawnPoints,
+    };
     use map_selection::MapSelectionStatus;
     use map_selection_model::MapSelection;
+    use mirrored_model::play::Mirrored;
     use object_type::ObjectType;
+    use team_model::play::{Team, TeamCounter};
 
     use game_loading::{
         CharacterAugmentRectifySystem, CharacterAugmentRectifySystemData, CharacterAugmentStatus,
@@ -48,8 +55,15 @@ mod tests {
                     .expect("Expected character entity to exist.");
                 assert_eq!(
                     // Default is inserted by character augmenter.
-                    Some(Position::<f32>::new(0., 0., 0.)).as_ref(),
-                    world.read_storage::<Position<f32>>().get(char_entity)
+                    Some(Position::<FixedPoint>::new(
+                        FixedPoint::ZERO,
+                        FixedPoint::ZERO,
+                        FixedPoint::ZERO
+                    ))
+                    .as_ref(),
+                    world
+                        .read_storage::<Position<FixedPoint>>()
+                        .get(char_entity)
                 );
             },
         )
@@ -77,9 +91,13 @@ mod tests {
                 assert_eq!(
                     // kcov-ignore-end
                     // See assets_test/assets/test/map/fade/map.yaml
-                    Position::<f32>::new(400., 200., 100.),
+                    Position::<FixedPoint>::new(
+                        FixedPoint::from(401),
+                        FixedPoint::from(202),
+                        FixedPoint::from(103)
+                    ),
                     *world
-                        .read_storage::<Position<f32>>()
+                        .read_storage::<Position<FixedPoint>>()
                         .get(char_entity)
                         .expect("Expected entity to have position.")
                 );
@@ -93,6 +111,122 @@ mod tests {
         )
     }
 
+    #[test]
+    fn spreads_characters_evenly_when_map_has_no_spawn_points() -> Result<(), Error> {
+        run_test_with_controllers(
+            &[0, 1],
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Rectify;
+                world.insert(game_loading_status);
+            },
+            |world| {
+                let (input_controlleds, positions, mirroreds) = world.system_data::<(
+                    ReadStorage<'_, InputControlled>,
+                    ReadStorage<'_, Position<FixedPoint>>,
+                    ReadStorage<'_, Mirrored>,
+                )>();
+                let mut placements = (&input_controlleds, &positions, &mirroreds)
+                    .join()
+                    .map(|(input_controlled, position, mirrored)| {
+                        (input_controlled.controller_id, *position, *mirrored)
+                    })
+                    .collect::<Vec<_>>();
+                placements.sort_by_key(|(controller_id, _, _)| *controller_id);
+
+                // See assets_test/assets/test/map/fade/map.yaml
+                assert_eq!(
+                    vec![
+                        (
+                            0,
+                            Position::<FixedPoint>::new(
+                                FixedPoint::from(1) + FixedPoint::from(800) / FixedPoint::from(3),
+                                FixedPoint::from(202),
+                                FixedPoint::from(103)
+                            ),
+                            Mirrored::new(false)
+                        ),
+                        (
+                            1,
+                            Position::<FixedPoint>::new(
+                                FixedPoint::from(1) + FixedPoint::from(1600) / FixedPoint::from(3),
+                                FixedPoint::from(202),
+                                FixedPoint::from(103)
+                            ),
+                            Mirrored::new(true)
+                        ),
+                    ],
+                    placements
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn team_member_falls_back_to_player_spawn_point_of_its_slot() -> Result<(), Error> {
+        run_test_with_controllers(
+            &[0, 1, 2],
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Rectify;
+                world.insert(game_loading_status);
+
+                let team = TeamCounter(0);
+                let map_asset_id = world
+                    .read_resource::<MapSelection>()
+                    .asset_id()
+                    .expect("Expected map selection to have an `AssetId`.");
+                let spawn_points = SpawnPoints::new(vec![
+                    SpawnPoint::new(PositionInit::new(100, 0, 0), false, None),
+                    SpawnPoint::new(PositionInit::new(200, 0, 0), false, None),
+                    SpawnPoint::new(PositionInit::new(250, 0, 0), false, None),
+                    SpawnPoint::new(PositionInit::new(300, 0, 0), true, Some(team)),
+                ]);
+                world
+                    .write_resource::<AssetSpawnPoints>()
+                    .insert(map_asset_id, spawn_points);
+
+                let (entities, input_controlleds, mut teams) = world.system_data::<(
+                    Entities<'_>,
+                    ReadStorage<'_, InputControlled>,
+                    WriteStorage<'_, Team>,
+                )>();
+                (&entities, &input_controlleds)
+                    .join()
+                    .filter(|(_, input_controlled)| input_controlled.controller_id != 0)
+                    .for_each(|(entity, _)| {
+                        teams
+                            .insert(entity, Team::Number(team))
+                            .expect("Failed to insert `Team` component.");
+                    });
+            },
+            |world| {
+                let (input_controlleds, positions) = world.system_data::<(
+                    ReadStorage<'_, InputControlled>,
+                    ReadStorage<'_, Position<FixedPoint>>,
+                )>();
+                let mut placements = (&input_controlleds, &positions)
+                    .join()
+                    .map(|(input_controlled, position)| {
+                        (input_controlled.controller_id, position.x)
+                    })
+                    .collect::<Vec<_>>();
+                placements.sort_by_key(|(controller_id, _)| *controller_id);
+
+                // The second team member takes the player spawn point of the third slot, as the
+                // team only has one spawn point.
+                assert_eq!(
+                    vec![
+                        (0, FixedPoint::from(100)),
+                        (1, FixedPoint::from(300)),
+                        (2, FixedPoint::from(250)),
+                    ],
+                    placements
+                );
+            },
+        )
+    }
+
     #[test]
     fn creates_hp_and_cp_bar_entities_per_character_selection() -> Result<(), Error> {
         run_test(
@@ -111,6 +245,18 @@ mod tests {
     }
 
     fn run_test<FnS, FnA>(fn_setup: FnS, fn_assert: FnA) -> Result<(), Error>
+    where
+        FnS: Fn(&mut World) + Send + Sync + 'static,
+        FnA: Fn(&mut World) + Send + Sync + 'static,
+    {
+        run_test_with_controllers(&[123], fn_setup, fn_assert)
+    }
+
+    fn run_test_with_controllers<FnS, FnA>(
+        controller_ids: &'static [ControllerId],
+        fn_setup: FnS,
+        fn_assert: FnA,
+    ) -> Result<(), Error>
     where
         FnS: Fn(&mut World) + Send + Sync + 'static,
         FnA: Fn(&mut World) + Send + Sync + 'static,
@@ -123,7 +269,7 @@ mod tests {
             .with_setup(CharacterAugmentRectifySystemData::setup)
             .with_state(|| wait_for_load)
             .with_effect(|world| setup_map_selection(world, &*MAP_FADE_SLUG))
-            .with_effect(|world| {
+            .with_effect(move |world| {
                 let mut game_loading_status = GameLoadingStatus::new();
                 game_loading_status.character_augment_status = CharacterAugmentStatus::Prefab;
                 world.insert(game_loading_status);
@@ -136,8 +282,10 @@ mod tests {
                         .copied()
                         .expect("Expected at least one character to be loaded.")
                 };
-                let mut character_selections = HashMap::new();
-                character_selections.insert(123, asset_id);
+                let character_selections = controller_ids
+                    .iter()
+                    .map(|controller_id| (*controller_id, asset_id))
+                    .collect::<HashMap<_, _>>();
                 let character_selections = CharacterSelections::new(character_selections);
                 world.insert(character_selections);
             })
@@ -16,6 +16,7 @@ mod tests {
     use game_input_model::play::InputControlled;
     use game_model::play::GameEntities;
     use object_type::ObjectType;
+    use survival_model::play::{SurvivalStatus, SurvivalTeam};
     use team_model::play::{IndependentCounter, Team};
 
     use game_loading::{
@@ -129,6 +130,40 @@ mod tests {
         )
     }
 
+    #[test]
+    fn spawns_characters_on_players_team_when_survival_is_active() -> Result<(), Error> {
+        run_test(
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Prefab;
+                world.insert(game_loading_status);
+                world.insert(SurvivalStatus::Active);
+
+                let asset_id = first_character_asset_id(world);
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, asset_id);
+                character_selections.selections.insert(123, asset_id);
+                world.insert(character_selections);
+            },
+            |world| {
+                let (input_controlleds, teams) = world.system_data::<TestSystemData<'_>>();
+                let teams = (&input_controlleds, &teams)
+                    .join()
+                    .map(|(_, team)| *team)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    vec![
+                        Team::Number(SurvivalTeam::PLAYERS),
+                        Team::Number(SurvivalTeam::PLAYERS)
+                    ],
+                    teams
+                );
+            },
+        )
+    }
+
     fn run_test(setup_fn: fn(&mut World), assertion_fn: fn(&mut World)) -> Result<(), Error> {
         AutexousiousApplication::config_base()
             .with_effect(<CharacterSelectionSpawningSystem as System>::SystemData::setup)
@@ -15,6 +15,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn trans_returns_push_for_training() {
+        assert_eq_trans(
+            &Trans::Push(Box::new(MockState)),
+            &GameModeSelectionTrans::trans(GameModeIndex::Training),
+        );
+    }
+
+    #[test]
+    fn trans_returns_push_for_survival() {
+        assert_eq_trans(
+            &Trans::Push(Box::new(MockState)),
+            &GameModeSelectionTrans::trans(GameModeIndex::Survival),
+        );
+    }
+
     #[test]
     fn trans_returns_quit_for_exit() {
         assert_eq_trans(
@@ -1,2 +1,3 @@
 mod game_play_bundle;
+mod game_play_state;
 mod system;
@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        GameData, GameDataBuilder, State, StateData, Trans,
+    };
+    use application_event::AppEvent;
+    use debug_util_amethyst::assert_eq_trans;
+    use game_model::play::GameEntities;
+    use game_play_model::{play::SimulationStatus, GamePlayEntity, GamePlayEvent, GamePlayStatus};
+    use network_session_model::play::SessionStatus;
+    use pretty_assertions::assert_eq;
+    use rayon::ThreadPoolBuilder;
+    use sequence_model::play::SequencePause;
+    use state_registry::StateId;
+
+    use game_play::GamePlayState;
+
+    #[test]
+    fn pause_event_pushes_pause_menu_state() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Playing);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Pause),
+        );
+
+        assert_eq_trans(&Trans::Push(Box::new(MockState)), &trans);
+        assert_eq!(
+            GamePlayStatus::Paused,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    #[test]
+    fn pause_event_does_nothing_when_not_playing() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Ended);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Pause),
+        );
+
+        assert_eq_trans(&Trans::None, &trans);
+        assert_eq!(
+            GamePlayStatus::Ended,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    #[test]
+    fn pause_event_does_nothing_during_network_session() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Playing);
+        world.insert(SessionStatus::HostEstablished);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Pause),
+        );
+
+        assert_eq_trans(&Trans::None, &trans);
+        assert_eq!(
+            GamePlayStatus::Playing,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    #[test]
+    fn on_pause_pauses_game_entities() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Paused);
+        let (game_entity, game_play_entity, other_entity) = create_entities(&mut world);
+        let mut state = GamePlayState::new();
+
+        state.on_pause(StateData::new(&mut world, &mut game_data));
+
+        assert_eq!(
+            SimulationStatus::Paused,
+            *world.read_resource::<SimulationStatus>()
+        );
+        let sequence_pauses = world.read_storage::<SequencePause>();
+        assert!(sequence_pauses.contains(game_entity));
+        assert!(sequence_pauses.contains(game_play_entity));
+        assert!(!sequence_pauses.contains(other_entity));
+    }
+
+    #[test]
+    fn on_resume_resumes_game_entities_and_game_play() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Playing);
+        let (game_entity, game_play_entity, _other_entity) = create_entities(&mut world);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Pause),
+        );
+        assert_eq_trans(&Trans::Push(Box::new(MockState)), &trans);
+        state.on_pause(StateData::new(&mut world, &mut game_data));
+        world.insert(StateId::PauseMenu);
+        state.on_resume(StateData::new(&mut world, &mut game_data));
+
+        assert_eq!(StateId::GamePlay, *world.read_resource::<StateId>());
+        assert_eq!(
+            GamePlayStatus::Playing,
+            *world.read_resource::<GamePlayStatus>()
+        );
+        assert_eq!(
+            SimulationStatus::Running,
+            *world.read_resource::<SimulationStatus>()
+        );
+        let sequence_pauses = world.read_storage::<SequencePause>();
+        assert!(!sequence_pauses.contains(game_entity));
+        assert!(!sequence_pauses.contains(game_play_entity));
+    }
+
+    #[test]
+    fn on_resume_does_not_resume_game_play_that_has_ended() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Ended);
+        let mut state = GamePlayState::new();
+
+        state.on_resume(StateData::new(&mut world, &mut game_data));
+
+        assert_eq!(
+            GamePlayStatus::Ended,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    #[test]
+    fn resume_event_sets_status_to_playing() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Paused);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Resume),
+        );
+
+        assert_eq_trans(&Trans::None, &trans);
+        assert_eq!(
+            GamePlayStatus::Playing,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    #[test]
+    fn restart_event_switches_to_game_loading_state() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Paused);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Restart),
+        );
+
+        assert_eq_trans(&Trans::Switch(Box::new(MockState)), &trans);
+        assert_eq!(
+            GamePlayStatus::None,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    #[test]
+    fn return_event_pops_state() {
+        let (mut world, mut game_data) = setup(GamePlayStatus::Paused);
+        let mut state = GamePlayState::new();
+
+        let trans = state.handle_event(
+            StateData::new(&mut world, &mut game_data),
+            AppEvent::GamePlay(GamePlayEvent::Return),
+        );
+
+        assert_eq_trans(&Trans::Pop, &trans);
+        assert_eq!(
+            GamePlayStatus::None,
+            *world.read_resource::<GamePlayStatus>()
+        );
+    }
+
+    fn setup(game_play_status: GamePlayStatus) -> (World, GameData<'static, 'static>) {
+        let mut world = World::new();
+        world.register::<GamePlayEntity>();
+        world.register::<SequencePause>();
+        world.insert(GameEntities::default());
+        world.insert(game_play_status);
+        world.insert(Arc::new(
+            ThreadPoolBuilder::default()
+                .build()
+                .unwrap_or_else(|e| panic!("Failed to build ThreadPool. {}", e)), // kcov-ignore
+        ));
+        let game_data = GameDataBuilder::default().build(&mut world);
+
+        (world, game_data)
+    }
+
+    /// Returns an object entity, a `GamePlayEntity`, and an entity outside of game play.
+    fn create_entities(world: &mut World) -> (Entity, Entity, Entity) {
+        let game_entity = world.create_entity().build();
+        let game_play_entity = world.create_entity().with(GamePlayEntity).build();
+        let other_entity = world.create_entity().build();
+        world
+            .write_resource::<GameEntities>()
+            .map_layers
+            .push(game_entity);
+
+        (game_entity, game_play_entity, other_entity)
+    }
+
+    #[derive(Debug)]
+    struct MockState;
+    impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for MockState {}
+}
@@ -1,4 +1,6 @@
+mod character_charge_level_transition_system;
 mod character_hit_effect_system;
+mod character_sequence_end_transition_system;
 mod character_sequence_update_system;
 mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
@@ -8,3 +10,4 @@ mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
 mod sequence;
+mod tint_resolution_system;
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::{CharacterChargeLevelNext, CharacterSequenceChargeLevelNexts};
+    use charge_model::config::ChargeLevel;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+
+    use game_play::CharacterChargeLevelTransitionSystem;
+
+    #[test]
+    fn transitions_to_sequence_for_charge_level_reached() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(1),
+                charge_level: ChargeLevel::Level2,
+            },
+            SequenceId::new(3),
+        )
+    }
+
+    #[test]
+    fn transitions_to_highest_configured_sequence_below_charge_level() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(1),
+                charge_level: ChargeLevel::Level3,
+            },
+            SequenceId::new(3),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_when_no_charge_level_is_reached() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(1),
+                charge_level: ChargeLevel::None,
+            },
+            SequenceId::new(1),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_when_sequence_has_no_charge_level_sequences() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(0),
+                charge_level: ChargeLevel::Level3,
+            },
+            SequenceId::new(0),
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            sequence_id,
+            charge_level,
+        }: SetupParams,
+        sequence_id_expected: SequenceId,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterChargeLevelTransitionSystem::new(),
+                any::type_name::<CharacterChargeLevelTransitionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let character_sequence_charge_level_nexts =
+                    CharacterSequenceChargeLevelNexts::new(vec![
+                        CharacterChargeLevelNext::default(),
+                        CharacterChargeLevelNext::new(
+                            Some(SequenceId::new(2)),
+                            Some(SequenceId::new(3)),
+                            None,
+                        ),
+                    ]);
+                let entity = world
+                    .create_entity()
+                    .with(character_sequence_charge_level_nexts)
+                    .with(charge_level)
+                    .with(sequence_id)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let sequence_ids = world.read_storage::<SequenceId>();
+
+                assert_eq!(Some(&sequence_id_expected), sequence_ids.get(entity));
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        sequence_id: SequenceId,
+        charge_level: ChargeLevel,
+    }
+}
@@ -10,7 +10,7 @@ use collision_model::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Velocity;
+use kinematic_model::config::{FixedPoint, Velocity};
 use mirrored_model::play::Mirrored;
 use object_model::play::HealthPoints;
 use object_status_model::config::StunPoints;
@@ -46,9 +46,9 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `StunPoints` components.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
-    /// `Velocity<f32>` components.
+    /// `Velocity<FixedPoint>` components.
     #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    pub velocities: WriteStorage<'s, Velocity<FixedPoint>>,
     /// `SequenceId` components.
     #[derivative(Debug = "ignore")]
     pub sequence_ids: WriteStorage<'s, SequenceId>,
@@ -121,12 +121,12 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     *stun_points += stun;
 
                     if mirrored {
-                        velocity.x -= (*acceleration).x as f32;
+                        velocity.x -= FixedPoint::from((*acceleration).x);
                     } else {
-                        velocity.x += (*acceleration).x as f32;
+                        velocity.x += FixedPoint::from((*acceleration).x);
                     }
-                    velocity.y += (*acceleration).y as f32;
-                    velocity.z += (*acceleration).z as f32;
+                    velocity.y += FixedPoint::from((*acceleration).y);
+                    velocity.z += FixedPoint::from((*acceleration).z);
 
                     let next_sequence_id = if *health_points == 0 {
                         character_hit_transitions.falling
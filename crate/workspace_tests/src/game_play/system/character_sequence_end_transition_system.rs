@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::{
+        config::{CharacterIrr, CharacterIrrPart},
+        loaded::{CharacterEndTransition, CharacterEndTransitions},
+    };
+    use object_model::play::{Grounding, HealthPoints};
+    use pretty_assertions::assert_eq;
+    use sequence_model::{loaded::SequenceId, play::SequenceUpdateEvent};
+
+    use game_play::CharacterSequenceEndTransitionSystem;
+
+    #[test]
+    fn transitions_to_first_transition_whose_requirement_is_met() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::Airborne,
+                health_points: HealthPoints::new(10),
+                sequence_update_event_fn: sequence_end_event,
+            },
+            SequenceId::new(3),
+        )
+    }
+
+    #[test]
+    fn transitions_to_later_transition_when_earlier_requirement_is_not_met() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::OnGround,
+                health_points: HealthPoints::new(10),
+                sequence_update_event_fn: sequence_end_event,
+            },
+            SequenceId::new(4),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_when_no_requirement_is_met() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::OnGround,
+                health_points: HealthPoints::new(50),
+                sequence_update_event_fn: sequence_end_event,
+            },
+            SequenceId::new(1),
+        )
+    }
+
+    #[test]
+    fn does_not_transition_on_sequence_begin_event() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::Airborne,
+                health_points: HealthPoints::new(10),
+                sequence_update_event_fn: sequence_begin_event,
+            },
+            SequenceId::new(1),
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            grounding,
+            health_points,
+            sequence_update_event_fn,
+        }: SetupParams,
+        sequence_id_expected: SequenceId,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSequenceEndTransitionSystem::new(),
+                any::type_name::<CharacterSequenceEndTransitionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let character_end_transitions = CharacterEndTransitions::new(vec![
+                    CharacterEndTransition::new(
+                        SequenceId::new(3),
+                        CharacterIrr::new(vec![CharacterIrrPart::Airborne(true)]),
+                    ),
+                    CharacterEndTransition::new(
+                        SequenceId::new(4),
+                        CharacterIrr::new(vec![CharacterIrrPart::HpBelow(HealthPoints::new(20))]),
+                    ),
+                ]);
+                let entity = world
+                    .create_entity()
+                    .with(SequenceId::new(1))
+                    .with(character_end_transitions)
+                    .with(grounding)
+                    .with(health_points)
+                    .build();
+
+                world
+                    .write_resource::<EventChannel<SequenceUpdateEvent>>()
+                    .single_write(sequence_update_event_fn(entity));
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let sequence_ids = world.read_storage::<SequenceId>();
+
+                assert_eq!(Some(&sequence_id_expected), sequence_ids.get(entity));
+            })
+            .run()
+    }
+
+    fn sequence_begin_event(entity: Entity) -> SequenceUpdateEvent {
+        SequenceUpdateEvent::SequenceBegin {
+            entity,
+            sequence_id: SequenceId::new(1),
+        }
+    }
+
+    fn sequence_end_event(entity: Entity) -> SequenceUpdateEvent {
+        SequenceUpdateEvent::SequenceEnd {
+            entity,
+            frame_index: 0,
+        }
+    }
+
+    struct SetupParams {
+        grounding: Grounding,
+        health_points: HealthPoints,
+        sequence_update_event_fn: fn(Entity) -> SequenceUpdateEvent,
+    }
+}
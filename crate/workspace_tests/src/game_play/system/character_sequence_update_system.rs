@@ -8,7 +8,7 @@ mod tests {
     };
     use application_test_support::AutexousiousApplication;
     use game_input_model::play::ControllerInput;
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position};
     use map_model::loaded::AssetMargins;
     use map_selection_model::MapSelection;
     use mirrored_model::play::Mirrored;
@@ -142,7 +142,7 @@ mod tests {
         WriteStorage<'s, ControllerInput>,
         WriteStorage<'s, SequenceId>,
         WriteStorage<'s, SequenceStatus>,
-        WriteStorage<'s, Position<f32>>,
+        WriteStorage<'s, Position<FixedPoint>>,
         WriteStorage<'s, Mirrored>,
         WriteStorage<'s, Grounding>,
     );
@@ -11,6 +11,7 @@ mod tests {
     use game_stats_model::play::{WinOutcome, WinStatus};
     use object_model::play::HealthPoints;
     use std::any;
+    use survival_model::play::{SurvivalProgress, SurvivalStatus, SurvivalTeam};
     use team_model::play::{IndependentCounter, Team, TeamCounter};
 
     use game_play::{GamePlayEndDetectionSystem, GamePlayEndDetectionSystemData};
@@ -20,6 +21,7 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Ended,
+                survival_status: SurvivalStatus::Inactive,
                 objects: vec![
                     ObjectStatus {
                         team: Team::Number(TeamCounter::new(0)),
@@ -45,6 +47,7 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                survival_status: SurvivalStatus::Inactive,
                 objects: vec![
                     ObjectStatus {
                         team: winning_team,
@@ -71,6 +74,7 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                survival_status: SurvivalStatus::Inactive,
                 objects: vec![
                     ObjectStatus {
                         team: winning_team,
@@ -99,6 +103,7 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                survival_status: SurvivalStatus::Inactive,
                 objects: vec![
                     ObjectStatus {
                         team: Team::Independent(IndependentCounter::new(0)),
@@ -124,6 +129,7 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                survival_status: SurvivalStatus::Inactive,
                 objects: vec![
                     ObjectStatus {
                         team: Team::Independent(IndependentCounter::new(0)),
@@ -143,9 +149,63 @@ mod tests {
         )
     }
 
+    #[test]
+    fn does_not_send_game_play_end_event_when_survival_player_alive() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                survival_status: SurvivalStatus::Active,
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Number(SurvivalTeam::PLAYERS),
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: Team::Number(SurvivalTeam::PLAYERS),
+                        liveness: Liveness::Dead,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_play_events: vec![],
+                win_status: WinStatus::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_game_play_end_event_with_waves_survived_when_survival_players_down(
+    ) -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                survival_status: SurvivalStatus::Active,
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Number(SurvivalTeam::PLAYERS),
+                        liveness: Liveness::Dead,
+                    },
+                    ObjectStatus {
+                        team: Team::Number(SurvivalTeam::ENEMIES),
+                        liveness: Liveness::Alive,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::Survival {
+                    waves_survived: WAVES_SURVIVED,
+                }),
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             game_play_status: game_play_status_setup,
+            survival_status,
             objects,
         }: SetupParams,
         ExpectedParams {
@@ -159,6 +219,12 @@ mod tests {
             .with_setup(GamePlayEndDetectionSystemData::setup)
             .with_setup(register_event_reader)
             .with_effect(move |world| {
+                world.insert(survival_status);
+                world.insert(SurvivalProgress {
+                    waves_survived: WAVES_SURVIVED,
+                    ..Default::default()
+                });
+
                 objects.into_iter().for_each(|object_status| {
                     let ObjectStatus { liveness, team } = object_status;
 
@@ -206,8 +272,11 @@ mod tests {
         assert_eq!(game_play_events_expected, game_play_events_actual);
     }
 
+    const WAVES_SURVIVED: u32 = 3;
+
     struct SetupParams {
         game_play_status: GamePlayStatus,
+        survival_status: SurvivalStatus,
         objects: Vec<ObjectStatus>,
     }
 
@@ -10,7 +10,7 @@ mod tests {
     use amethyst_test::AmethystApplication;
     use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
     use game_play_model::GamePlayEntity;
-    use kinematic_model::config::{Position, Velocity};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use sequence_model::loaded::SequenceId;
     use spawn_model::{loaded::Spawn, play::SpawnEvent};
     use state_registry::StateId;
@@ -55,8 +55,16 @@ mod tests {
 
         let spawn = Spawn::new(
             asset_id,
-            Position::<f32>::new(10., 20., 30.),
-            Velocity::<f32>::new(40., 50., 60.),
+            Position::<FixedPoint>::new(
+                FixedPoint::from(10),
+                FixedPoint::from(20),
+                FixedPoint::from(30),
+            ),
+            Velocity::<FixedPoint>::new(
+                FixedPoint::from(40),
+                FixedPoint::from(50),
+                FixedPoint::from(60),
+            ),
             SequenceId::new(0),
         );
 
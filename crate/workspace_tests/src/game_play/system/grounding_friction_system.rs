@@ -7,7 +7,7 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use kinematic_model::config::Velocity;
+    use kinematic_model::config::{FixedPoint, Velocity};
     use object_model::play::Grounding;
 
     use game_play::GroundingFrictionSystem;
@@ -17,9 +17,9 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                velocity: Velocity::new(11., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(11), FixedPoint::ZERO, FixedPoint::ZERO),
             },
-            Velocity::new(5.5, 0., 0.),
+            Velocity::new(FixedPoint::from(5.5), FixedPoint::ZERO, FixedPoint::ZERO),
         )
     }
 
@@ -28,9 +28,9 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                velocity: Velocity::new(0., 0., 7.),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(7)),
             },
-            Velocity::new(0., 0., 3.5),
+            Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(3.5)),
         )
     }
 
@@ -39,9 +39,9 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                velocity: Velocity::new(0., -15., 0.),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::from(-15), FixedPoint::ZERO),
             },
-            Velocity::new(0., 0., 0.),
+            Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
         )
     }
 
@@ -50,9 +50,9 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                velocity: Velocity::new(9.99, 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(9.99), FixedPoint::ZERO, FixedPoint::ZERO),
             },
-            Velocity::new(0., 0., 0.),
+            Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
         )
     }
 
@@ -61,9 +61,9 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                velocity: Velocity::new(0., 0., 6.99),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(6.99)),
             },
-            Velocity::new(0., 0., 0.),
+            Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
         )
     }
 
@@ -73,7 +73,7 @@ mod tests {
             velocity: velocity_setup,
             ..
         }: SetupParams,
-        velocity_expected: Velocity<f32>,
+        velocity_expected: Velocity<FixedPoint>,
     ) -> Result<(), Error> {
         AmethystApplication::blank()
             // kcov-ignore-start
@@ -93,11 +93,11 @@ mod tests {
             })
             .with_assertion(move |world| {
                 let entity = *world.read_resource::<Entity>();
-                let velocities = world.read_storage::<Velocity<f32>>();
+                let velocities = world.read_storage::<Velocity<FixedPoint>>();
                 let velocity_actual = velocities
                     .get(entity)
                     .copied()
-                    .expect("Expected entity to have `Velocity<f32>` component.");
+                    .expect("Expected entity to have `Velocity<FixedPoint>` component.");
 
                 assert_eq!(velocity_expected, velocity_actual);
             })
@@ -107,6 +107,6 @@ mod tests {
     #[derive(Debug)]
     struct SetupParams {
         grounding: Grounding,
-        velocity: Velocity<f32>,
+        velocity: Velocity<FixedPoint>,
     }
 }
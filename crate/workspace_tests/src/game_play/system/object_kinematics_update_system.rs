@@ -1,14 +1,21 @@
 #[cfg(test)]
 mod tests {
+    use std::{
+        any,
+        sync::{Arc, Mutex},
+    };
+
     use amethyst::{
         ecs::{Builder, Entity, SystemData, WorldExt},
         input::StringBindings,
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use kinematic_model::config::{Position, Velocity};
+    use application_test_support::AutexousiousApplication;
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
+    use object_model::{config::Mass, play::Grounding};
+    use object_play::ObjectGravitySystem;
     use sequence_model::play::FrameFreezeClock;
-    use std::any;
 
     use game_play::{ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData};
 
@@ -19,11 +26,23 @@ mod tests {
 
         run_test(
             SetupParams {
-                position: Position::<f32>::new(-2., -2., -2.),
-                velocity: Velocity::<f32>::new(-3., -3., -3.),
+                position: Position::<FixedPoint>::new(
+                    FixedPoint::from(-2),
+                    FixedPoint::from(-2),
+                    FixedPoint::from(-2),
+                ),
+                velocity: Velocity::<FixedPoint>::new(
+                    FixedPoint::from(-3),
+                    FixedPoint::from(-3),
+                    FixedPoint::from(-3),
+                ),
                 frame_freeze_clock: None,
             },
-            Position::<f32>::new(-5., -5., -5.),
+            Position::<FixedPoint>::new(
+                FixedPoint::from(-5),
+                FixedPoint::from(-5),
+                FixedPoint::from(-5),
+            ),
         )
     }
 
@@ -31,11 +50,23 @@ mod tests {
     fn does_not_add_velocity_to_position_when_frame_freeze_clock_incomplete() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::<f32>::new(-2., -2., -2.),
-                velocity: Velocity::<f32>::new(-3., -3., -3.),
+                position: Position::<FixedPoint>::new(
+                    FixedPoint::from(-2),
+                    FixedPoint::from(-2),
+                    FixedPoint::from(-2),
+                ),
+                velocity: Velocity::<FixedPoint>::new(
+                    FixedPoint::from(-3),
+                    FixedPoint::from(-3),
+                    FixedPoint::from(-3),
+                ),
                 frame_freeze_clock: Some(FrameFreezeClock::new(1)),
             },
-            Position::<f32>::new(-2., -2., -2.),
+            Position::<FixedPoint>::new(
+                FixedPoint::from(-2),
+                FixedPoint::from(-2),
+                FixedPoint::from(-2),
+            ),
         )
     }
 
@@ -46,21 +77,108 @@ mod tests {
 
         run_test(
             SetupParams {
-                position: Position::<f32>::new(-2., -2., -2.),
-                velocity: Velocity::<f32>::new(-3., -3., -3.),
+                position: Position::<FixedPoint>::new(
+                    FixedPoint::from(-2),
+                    FixedPoint::from(-2),
+                    FixedPoint::from(-2),
+                ),
+                velocity: Velocity::<FixedPoint>::new(
+                    FixedPoint::from(-3),
+                    FixedPoint::from(-3),
+                    FixedPoint::from(-3),
+                ),
                 frame_freeze_clock: Some(frame_freeze_clock),
             },
-            Position::<f32>::new(-5., -5., -5.),
+            Position::<FixedPoint>::new(
+                FixedPoint::from(-5),
+                FixedPoint::from(-5),
+                FixedPoint::from(-5),
+            ),
         )
     }
 
+    #[test]
+    fn trajectory_is_bit_identical_across_runs() -> Result<(), Error> {
+        let trajectory_first = run_trajectory()?;
+        let trajectory_second = run_trajectory()?;
+
+        assert_eq!(TRAJECTORY_TICKS, trajectory_first.len());
+        assert_ne!(trajectory_first.first(), trajectory_first.last());
+        assert_eq!(trajectory_first, trajectory_second);
+
+        Ok(())
+    }
+
+    const TRAJECTORY_TICKS: usize = 10;
+
+    /// Returns the position bits of an airborne object for each tick.
+    fn run_trajectory() -> Result<Vec<(i64, i64, i64)>, Error> {
+        let trajectory = Arc::new(Mutex::new(Vec::with_capacity(TRAJECTORY_TICKS)));
+
+        // Same order as the game play dispatcher: kinematics update, then gravity.
+        let app = AutexousiousApplication::ui_base()
+            .with_system(
+                ObjectKinematicsUpdateSystem::new(),
+                any::type_name::<ObjectKinematicsUpdateSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_system(
+                ObjectGravitySystem::new(),
+                any::type_name::<ObjectGravitySystem>(),
+                &[any::type_name::<ObjectKinematicsUpdateSystem>()],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(Position::<FixedPoint>::default())
+                    .with(Velocity::new(
+                        FixedPoint::from(3.3),
+                        FixedPoint::from(12),
+                        FixedPoint::ZERO,
+                    ))
+                    .with(Mass(FixedPoint::from_ratio(7, 10)))
+                    .with(Grounding::Airborne)
+                    .build();
+
+                world.insert(entity);
+            });
+
+        (0..TRAJECTORY_TICKS)
+            .fold(app, |app, _| {
+                let trajectory = Arc::clone(&trajectory);
+                app.with_effect(move |world| {
+                    let entity = *world.read_resource::<Entity>();
+                    let positions = world.read_storage::<Position<FixedPoint>>();
+                    let position = positions
+                        .get(entity)
+                        .expect("Expected entity to have `Position<FixedPoint>` component.");
+
+                    trajectory
+                        .lock()
+                        .expect("Failed to lock trajectory.")
+                        .push((
+                            position.x.to_bits(),
+                            position.y.to_bits(),
+                            position.z.to_bits(),
+                        ));
+                })
+            })
+            .run()?;
+
+        let trajectory = trajectory
+            .lock()
+            .expect("Failed to lock trajectory.")
+            .clone();
+        Ok(trajectory)
+    }
+
     fn run_test(
         SetupParams {
             position,
             velocity,
             frame_freeze_clock,
         }: SetupParams,
-        expected_position: Position<f32>,
+        expected_position: Position<FixedPoint>,
     ) -> Result<(), Error> {
         AmethystApplication::ui_base::<StringBindings>()
             .with_effect(move |world| {
@@ -81,7 +199,7 @@ mod tests {
             ) // kcov-ignore
             .with_assertion(move |world| {
                 let entity = *world.read_resource::<Entity>();
-                let positions = world.read_storage::<Position<f32>>();
+                let positions = world.read_storage::<Position<FixedPoint>>();
 
                 assert_eq!(Some(&expected_position), positions.get(entity));
             })
@@ -89,8 +207,8 @@ mod tests {
     }
 
     struct SetupParams {
-        position: Position<f32>,
-        velocity: Velocity<f32>,
+        position: Position<FixedPoint>,
+        velocity: Velocity<FixedPoint>,
         frame_freeze_clock: Option<FrameFreezeClock>,
     }
 }
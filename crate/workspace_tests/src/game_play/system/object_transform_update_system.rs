@@ -7,7 +7,12 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use kinematic_model::{config::Position, play::PositionZAsY};
+    use kinematic_model::{
+        config::{FixedPoint, Position},
+        play::PositionZAsY,
+    };
+    use mirrored_model::play::Mirrored;
+    use sprite_model::play::SpriteOffsetTranslation;
     use std::any;
 
     use game_play::ObjectTransformUpdateSystem;
@@ -16,8 +21,14 @@ mod tests {
     fn updates_transform_with_x_and_yz_when_position_z_as_y_present() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., -10., 1.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(-10),
+                    FixedPoint::from(1),
+                ),
                 position_z_as_y: true,
+                mirrored: false,
+                sprite_offset_translation: None,
             },
             ExpectedParams {
                 transform: Transform::from(Vector3::new(100., -11., 1.)),
@@ -29,8 +40,14 @@ mod tests {
     fn updates_transform_xyz() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., -10., 1.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(-10),
+                    FixedPoint::from(1),
+                ),
                 position_z_as_y: false,
+                mirrored: false,
+                sprite_offset_translation: None,
             },
             ExpectedParams {
                 transform: Transform::from(Vector3::new(100., -10., 1.)),
@@ -38,10 +55,50 @@ mod tests {
         )
     }
 
+    #[test]
+    fn adds_sprite_offset_translation() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(-10),
+                    FixedPoint::from(1),
+                ),
+                position_z_as_y: false,
+                mirrored: false,
+                sprite_offset_translation: Some(SpriteOffsetTranslation::new(-3., 2.)),
+            },
+            ExpectedParams {
+                transform: Transform::from(Vector3::new(97., -8., 1.)),
+            },
+        )
+    }
+
+    #[test]
+    fn adds_mirrored_sprite_offset_translation_when_mirrored() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(-10),
+                    FixedPoint::from(1),
+                ),
+                position_z_as_y: false,
+                mirrored: true,
+                sprite_offset_translation: Some(SpriteOffsetTranslation::new(-3., 2.)),
+            },
+            ExpectedParams {
+                transform: Transform::from(Vector3::new(103., -8., 1.)),
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             position,
             position_z_as_y,
+            mirrored,
+            sprite_offset_translation,
         }: SetupParams,
         ExpectedParams {
             transform: transform_expected,
@@ -63,6 +120,12 @@ mod tests {
                     if position_z_as_y {
                         entity_builder = entity_builder.with(PositionZAsY);
                     }
+                    if mirrored {
+                        entity_builder = entity_builder.with(Mirrored::new(true));
+                    }
+                    if let Some(sprite_offset_translation) = sprite_offset_translation {
+                        entity_builder = entity_builder.with(sprite_offset_translation);
+                    }
 
                     entity_builder.build()
                 };
@@ -85,8 +148,10 @@ mod tests {
     }
 
     struct SetupParams {
-        position: Position<f32>,
+        position: Position<FixedPoint>,
         position_z_as_y: bool,
+        mirrored: bool,
+        sprite_offset_translation: Option<SpriteOffsetTranslation>,
     }
     struct ExpectedParams {
         transform: Transform,
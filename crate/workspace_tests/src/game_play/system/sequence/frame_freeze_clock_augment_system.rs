@@ -64,6 +64,58 @@ mod tests {
             .run()
     }
 
+    #[test]
+    fn keeps_existing_frame_freeze_clock_that_lasts_longer() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FrameFreezeClockAugmentSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world
+                    .create_entity()
+                    .with(FrameFreezeClock::new(10))
+                    .build();
+                let entity_to = world.create_entity().build();
+
+                let event = HitEvent::new(entity_from, entity_to, interaction(), body());
+                send_event(world, event);
+
+                world.insert(entity_from);
+            })
+            .with_assertion(|world| {
+                let entity_from = *world.read_resource::<Entity>();
+                let frame_freeze_clocks = world.read_storage::<FrameFreezeClock>();
+                let frame_freeze_clock = frame_freeze_clocks.get(entity_from);
+
+                assert_eq!(Some(&FrameFreezeClock::new(10)), frame_freeze_clock);
+            })
+            .run()
+    }
+
+    #[test]
+    fn replaces_existing_frame_freeze_clock_that_ends_sooner() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FrameFreezeClockAugmentSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world
+                    .create_entity()
+                    .with(FrameFreezeClock::new_with_value(3, 2))
+                    .build();
+                let entity_to = world.create_entity().build();
+
+                let event = HitEvent::new(entity_from, entity_to, interaction(), body());
+                send_event(world, event);
+
+                world.insert(entity_from);
+            })
+            .with_assertion(|world| {
+                let entity_from = *world.read_resource::<Entity>();
+                let frame_freeze_clocks = world.read_storage::<FrameFreezeClock>();
+                let frame_freeze_clock = frame_freeze_clocks.get(entity_from);
+
+                assert_eq!(Some(&FrameFreezeClock::new(3)), frame_freeze_clock);
+            })
+            .run()
+    }
+
     fn send_event(world: &mut World, event: HitEvent) {
         let mut ec = world.write_resource::<EventChannel<HitEvent>>();
         ec.single_write(event)
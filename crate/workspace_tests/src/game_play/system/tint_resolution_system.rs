@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        assets::{AssetStorage, Loader},
+        ecs::{Builder, Entity, Read, ReadExpect, World, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use approx::assert_relative_eq;
+    use charge_model::{config::ChargeLevel, play::ChargeTintPulse};
+    use frame_event_model::{
+        loaded::{FrameEvent, FrameEvents},
+        play::HitTint,
+    };
+    use object_status_model::{
+        config::StatusEffectKind,
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffects},
+    };
+    use sequence_model::play::{FrameIndexClock, FrameWaitClock};
+    use sprite_loading::SpriteLoadingBundle;
+    use sprite_model::{config::Interpolation, loaded::TintSequence};
+
+    use game_play::TintResolutionSystem;
+
+    #[test]
+    fn status_effect_tint_multiplies_tint_by_colour() {
+        let Tint(tint) = TintResolutionSystem::status_effect_tint(
+            Tint(Srgba::new(0.5, 1., 1., 1.)),
+            StatusEffectKind::Freeze,
+        );
+
+        assert_relative_eq!(0.25, tint.red);
+        assert_relative_eq!(0.8, tint.green);
+        assert_relative_eq!(1., tint.blue);
+        assert_relative_eq!(1., tint.alpha);
+    }
+
+    #[test]
+    fn uses_frame_tint_for_step_frames() -> Result<(), Error> {
+        run_test(SetupParams::default(), (1., 1., 1.))
+    }
+
+    #[test]
+    fn blends_frame_tint_towards_next_frame() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                interpolation: Interpolation::Linear,
+                ..Default::default()
+            },
+            (0.5, 1., 1.),
+        )
+    }
+
+    #[test]
+    fn self_tint_replaces_frame_tint() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                interpolation: Interpolation::Linear,
+                frame_events: vec![FrameEvent::SelfTint(Tint(Srgba::new(1., 0.5, 0.5, 1.)))],
+                ..Default::default()
+            },
+            (1., 0.5, 0.5),
+        )
+    }
+
+    #[test]
+    fn layers_charge_tint_pulse_over_frame_tint() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                charge_tint_pulse: Some(ChargeTintPulse::new(ChargeLevel::Level3)),
+                ..Default::default()
+            },
+            (1., 0.4, 0.4),
+        )
+    }
+
+    #[test]
+    fn layers_hit_tint_over_charge_tint_pulse() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                charge_tint_pulse: Some(ChargeTintPulse::new(ChargeLevel::Level3)),
+                hit_tint: Some(HitTint::new(Tint(Srgba::new(0., 0., 1., 1.)), 10)),
+                ..Default::default()
+            },
+            (0., 0., 1.),
+        )
+    }
+
+    #[test]
+    fn layers_status_effect_over_charge_tint_pulse() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                charge_tint_pulse: Some(ChargeTintPulse::new(ChargeLevel::Level3)),
+                status_effect_entries: Some(vec![StatusEffectEntry::new(
+                    StatusEffectKind::Freeze,
+                    StatusEffectClock::new(10),
+                )]),
+                ..Default::default()
+            },
+            (0.5, 0.32, 0.4),
+        )
+    }
+
+    #[test]
+    fn restores_frame_tint_when_status_effects_end() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                status_effect_entries: Some(vec![]),
+                tint: Some(Tint(Srgba::new(0.5, 0.8, 1., 1.))),
+                ..Default::default()
+            },
+            (1., 1., 1.),
+        )
+    }
+
+    #[test]
+    fn does_not_change_tint_of_entities_without_tint_layers() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                TintResolutionSystem::new(),
+                any::type_name::<TintResolutionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(Tint(Srgba::new(0.5, 0.5, 0.5, 1.)))
+                    .build();
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let tints = world.read_storage::<Tint>();
+
+                let Tint(tint) = *tints.get(entity).expect("Expected `Tint` to exist.");
+                assert_relative_eq!(0.5, tint.red);
+                assert_relative_eq!(0.5, tint.green);
+                assert_relative_eq!(0.5, tint.blue);
+            })
+            .run()
+    }
+
+    fn run_test(
+        setup_params: SetupParams,
+        (red, green, blue): (f32, f32, f32),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(SpriteLoadingBundle)
+            .with_system(
+                TintResolutionSystem::new(),
+                any::type_name::<TintResolutionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let entity = create_entity(world, setup_params.clone());
+                world.insert(entity);
+            })
+            // Wait for the sequence assets to be processed.
+            .with_effect(|_| {})
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let tints = world.read_storage::<Tint>();
+
+                let Tint(tint) = *tints.get(entity).expect("Expected `Tint` to exist.");
+                assert_relative_eq!(red, tint.red);
+                assert_relative_eq!(green, tint.green);
+                assert_relative_eq!(blue, tint.blue);
+            })
+            .run()
+    }
+
+    fn create_entity(
+        world: &mut World,
+        SetupParams {
+            interpolation,
+            frame_events,
+            charge_tint_pulse,
+            hit_tint,
+            status_effect_entries,
+            tint,
+        }: SetupParams,
+    ) -> Entity {
+        let tint_sequence_handle = {
+            let (loader, tint_sequence_assets) = world
+                .system_data::<(ReadExpect<'_, Loader>, Read<'_, AssetStorage<TintSequence>>)>();
+
+            let tint_sequence = TintSequence::new(vec![
+                Tint(Srgba::new(1., 1., 1., 1.)),
+                Tint(Srgba::new(0., 1., 1., 1.)),
+            ]);
+            loader.load_from_data(tint_sequence, (), &tint_sequence_assets)
+        };
+
+        let mut entity_builder = world
+            .create_entity()
+            .with(FrameIndexClock::new(2))
+            .with(FrameWaitClock::new_with_value(4, 2))
+            .with(interpolation)
+            .with(tint_sequence_handle)
+            .with(FrameEvents::new(frame_events));
+        if let Some(charge_tint_pulse) = charge_tint_pulse {
+            entity_builder = entity_builder.with(charge_tint_pulse);
+        }
+        if let Some(hit_tint) = hit_tint {
+            entity_builder = entity_builder.with(hit_tint);
+        }
+        if let Some(status_effect_entries) = status_effect_entries {
+            entity_builder = entity_builder.with(StatusEffects::new(status_effect_entries));
+        }
+        if let Some(tint) = tint {
+            entity_builder = entity_builder.with(tint);
+        }
+        entity_builder.build()
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct SetupParams {
+        interpolation: Interpolation,
+        frame_events: Vec<FrameEvent>,
+        charge_tint_pulse: Option<ChargeTintPulse>,
+        hit_tint: Option<HitTint>,
+        status_effect_entries: Option<Vec<StatusEffectEntry>>,
+        tint: Option<Tint>,
+    }
+}
@@ -1,2 +1,4 @@
+mod cp_bar_flash_system;
 mod cp_bar_update_system;
 mod hp_bar_update_system;
+mod status_icon_update_system;
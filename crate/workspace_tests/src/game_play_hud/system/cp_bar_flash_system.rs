@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        core::{math::Vector3, Transform},
+        ecs::{Builder, Entity, Join, WorldExt},
+        renderer::Hidden,
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use approx::assert_relative_eq;
+    use charge_model::{
+        config::{ChargeLevel, ChargeLevels, ChargePoints},
+        play::{ChargeLevelEvent, ChargeTrackerClock},
+    };
+    use parent_model::play::ParentEntity;
+    use pretty_assertions::assert_eq;
+
+    use game_play_hud::{
+        CpBar, CpBarFlash, CpBarFlashSystem, CP_BAR_FLASH_DURATION, CP_BAR_FLASH_INTERVAL,
+    };
+
+    #[test]
+    fn flashes_cp_bar_segment_of_charge_level_reached() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CpBarFlashSystem::new(),
+                any::type_name::<CpBarFlashSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let char_entity = world
+                    .create_entity()
+                    .with(charge_levels())
+                    .with(ChargeTrackerClock::new(100))
+                    .build();
+                let cp_bar_entity = world
+                    .create_entity()
+                    .with(CpBar)
+                    .with(ParentEntity::new(char_entity))
+                    .with(cp_bar_transform())
+                    .build();
+
+                world
+                    .write_resource::<EventChannel<ChargeLevelEvent>>()
+                    .single_write(ChargeLevelEvent::new(char_entity, ChargeLevel::Level2));
+
+                world.insert(cp_bar_entity);
+            })
+            .with_assertion(|world| {
+                let cp_bar_entity = *world.read_resource::<Entity>();
+                let cp_bar_flashes = world.read_storage::<CpBarFlash>();
+                let hiddens = world.read_storage::<Hidden>();
+
+                let segments = cp_bar_flashes
+                    .join()
+                    .map(|cp_bar_flash| {
+                        (
+                            cp_bar_flash.cp_bar_entity,
+                            cp_bar_flash.segment_start,
+                            cp_bar_flash.segment_end,
+                        )
+                    })
+                    .collect::<Vec<(Entity, f32, f32)>>();
+                assert_eq!(vec![(cp_bar_entity, 0.3, 0.6)], segments);
+                assert!(hiddens.get(cp_bar_entity).is_none());
+            })
+            .with_assertion(|world| {
+                let cp_bar_flashes = world.read_storage::<CpBarFlash>();
+                let transforms = world.read_storage::<Transform>();
+
+                let segment_transform = (&cp_bar_flashes, &transforms)
+                    .join()
+                    .map(|(_, transform)| transform)
+                    .next()
+                    .expect("Expected `CpBarFlash` segment to have a `Transform`.");
+
+                assert_relative_eq!(45., segment_transform.translation().x, epsilon = 0.001);
+                assert_relative_eq!(30., segment_transform.scale()[0], epsilon = 0.001);
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_flash_cp_bar_of_other_entity() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CpBarFlashSystem::new(),
+                any::type_name::<CpBarFlashSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let char_entity = world
+                    .create_entity()
+                    .with(charge_levels())
+                    .with(ChargeTrackerClock::new(100))
+                    .build();
+                let char_entity_other = world
+                    .create_entity()
+                    .with(charge_levels())
+                    .with(ChargeTrackerClock::new(100))
+                    .build();
+                let cp_bar_entity = world
+                    .create_entity()
+                    .with(CpBar)
+                    .with(ParentEntity::new(char_entity))
+                    .with(cp_bar_transform())
+                    .build();
+
+                world
+                    .write_resource::<EventChannel<ChargeLevelEvent>>()
+                    .single_write(ChargeLevelEvent::new(
+                        char_entity_other,
+                        ChargeLevel::Level1,
+                    ));
+
+                world.insert(cp_bar_entity);
+            })
+            .with_assertion(|world| {
+                let cp_bar_entity = *world.read_resource::<Entity>();
+                let cp_bar_flashes = world.read_storage::<CpBarFlash>();
+                let hiddens = world.read_storage::<Hidden>();
+
+                assert_eq!(0, cp_bar_flashes.join().count());
+                assert!(hiddens.get(cp_bar_entity).is_none());
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_show_segment_while_cp_bar_is_hidden() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CpBarFlashSystem::new(),
+                any::type_name::<CpBarFlashSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let cp_bar_entity = world
+                    .create_entity()
+                    .with(CpBar)
+                    .with(cp_bar_transform())
+                    .with(Hidden)
+                    .build();
+
+                // The segment is shown on the next tick when the `CpBar` is visible.
+                let cp_bar_flash = CpBarFlash {
+                    ticks: CP_BAR_FLASH_INTERVAL - 1,
+                    ..CpBarFlash::new(cp_bar_entity, 0.3, 0.6)
+                };
+                let segment_entity = world.create_entity().with(cp_bar_flash).build();
+
+                world.insert(segment_entity);
+            })
+            .with_assertion(|world| {
+                let segment_entity = *world.read_resource::<Entity>();
+                let hiddens = world.read_storage::<Hidden>();
+
+                assert!(hiddens.get(segment_entity).is_some());
+            })
+            .run()
+    }
+
+    #[test]
+    fn removes_segment_and_keeps_cp_bar_visibility_when_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CpBarFlashSystem::new(),
+                any::type_name::<CpBarFlashSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let cp_bar_entity = world
+                    .create_entity()
+                    .with(CpBar)
+                    .with(cp_bar_transform())
+                    .with(Hidden)
+                    .build();
+                let cp_bar_flash = CpBarFlash {
+                    ticks: CP_BAR_FLASH_DURATION - 1,
+                    ..CpBarFlash::new(cp_bar_entity, 0.3, 0.6)
+                };
+                let segment_entity = world
+                    .create_entity()
+                    .with(cp_bar_flash)
+                    .with(Hidden)
+                    .build();
+
+                world.insert((cp_bar_entity, segment_entity));
+            })
+            .with_assertion(|world| {
+                let (cp_bar_entity, segment_entity) = *world.read_resource::<(Entity, Entity)>();
+                let hiddens = world.read_storage::<Hidden>();
+
+                assert!(!world.entities().is_alive(segment_entity));
+                assert!(hiddens.get(cp_bar_entity).is_some());
+            })
+            .run()
+    }
+
+    fn charge_levels() -> ChargeLevels {
+        ChargeLevels::new(
+            Some(ChargePoints::new(30)),
+            Some(ChargePoints::new(60)),
+            Some(ChargePoints::new(90)),
+        )
+    }
+
+    /// Returns the `Transform` of a full `CpBar` whose left edge is at 0.
+    fn cp_bar_transform() -> Transform {
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(50., -14., 1.);
+        transform.set_scale(Vector3::new(100., 3., 1.));
+        transform
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::PrefabData,
+        core::{math::Vector3, Transform, TransformBundle},
+        ecs::{Builder, Entity, System, SystemData, World, WorldExt},
+        renderer::{types::DefaultBackend, Hidden, RenderEmptyBundle},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use object_status_model::{
+        config::StatusEffectKind,
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffects},
+    };
+
+    use game_play_hud::{StatusIconPrefab, StatusIconUpdateSystem};
+
+    #[test]
+    fn shows_icon_in_slot_of_status_effect() -> Result<(), Error> {
+        run_test(StatusEffectKind::Burn, |world, status_icon_entity| {
+            let transforms = world.read_storage::<Transform>();
+            let transform = transforms
+                .get(status_icon_entity)
+                .expect("Expected status icon to have `Transform` component.");
+            let hiddens = world.read_storage::<Hidden>();
+
+            // -100 / 2 = -50 (left edge of `HpBar`)
+            // -50 + 4 / 2 = -48 (half sprite width shift)
+            // -48 + 1 * 6 = -42 (second slot)
+            // -42 + 123. = 81. (parent shift)
+            assert_eq!(&Vector3::new(81., 436., 790.), transform.translation());
+            assert!(!hiddens.contains(status_icon_entity));
+        })
+    }
+
+    #[test]
+    fn hides_icon_when_status_effect_absent() -> Result<(), Error> {
+        run_test(StatusEffectKind::Freeze, |world, status_icon_entity| {
+            let hiddens = world.read_storage::<Hidden>();
+
+            assert!(hiddens.contains(status_icon_entity));
+        })
+    }
+
+    fn run_test(kind: StatusEffectKind, assertion_fn: fn(&mut World, Entity)) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_effect(move |world| {
+                <StatusIconPrefab as PrefabData>::SystemData::setup(world);
+                <StatusIconUpdateSystem as System>::SystemData::setup(world);
+
+                let mut transform = Transform::default();
+                transform.set_translation_x(123.);
+                transform.set_translation_y(456.);
+                transform.set_translation_z(789.);
+                let status_effects = StatusEffects::new(vec![
+                    StatusEffectEntry::new(StatusEffectKind::Poison, StatusEffectClock::new(10)),
+                    StatusEffectEntry::new(StatusEffectKind::Burn, StatusEffectClock::new(10)),
+                ]);
+                let char_entity = {
+                    world
+                        .create_entity()
+                        .with(transform)
+                        .with(status_effects)
+                        .build()
+                };
+
+                let status_icon_entity = {
+                    let status_icon_entity = world.create_entity().build();
+
+                    let mut status_icon_prefab_system_data =
+                        world.system_data::<<StatusIconPrefab as PrefabData>::SystemData>();
+                    let status_icon_prefab = StatusIconPrefab::new(char_entity, kind);
+
+                    status_icon_prefab
+                        .add_to_entity(
+                            status_icon_entity,
+                            &mut status_icon_prefab_system_data,
+                            &[],
+                            &[],
+                        )
+                        .expect("`StatusIconPrefab` failed to augment entity.");
+
+                    status_icon_entity
+                };
+
+                world.insert(status_icon_entity);
+            })
+            .with_system_single(StatusIconUpdateSystem::new(), "", &[])
+            .with_assertion(move |world| {
+                let status_icon_entity = *world.read_resource::<Entity>();
+
+                assertion_fn(world, status_icon_entity);
+            })
+            .run_winit_loop()
+    }
+}
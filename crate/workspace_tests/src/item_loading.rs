@@ -0,0 +1 @@
+mod item_loading_bundle;
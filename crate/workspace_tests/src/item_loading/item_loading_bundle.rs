@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod test {
+    use amethyst::{assets::AssetStorage, ecs::WorldExt, Error};
+    use amethyst_test::AmethystApplication;
+    use item_model::{config::ItemDefinition, loaded::HoldPointSequence};
+
+    use item_loading::ItemLoadingBundle;
+
+    #[test]
+    fn bundle_build() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(ItemLoadingBundle::new())
+            .with_assertion(|world| {
+                // Panics if the Processors are not added.
+                world.read_resource::<AssetStorage<ItemDefinition>>();
+                world.read_resource::<AssetStorage<HoldPointSequence>>();
+            })
+            .run()
+    }
+}
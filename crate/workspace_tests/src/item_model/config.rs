@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+    use collision_model::config::Body;
+    use indexmap::IndexMap;
+    use kinematic_model::config::VelocityInit;
+    use object_model::config::{ObjectDefinition, ObjectFrame, ObjectSequence};
+    use sequence_model::config::{Sequence, SequenceEndTransition, SequenceNameString, Wait};
+    use serde_yaml;
+    use shape_model::Volume;
+    use sprite_model::config::SpriteRef;
+
+    use item_model::config::{
+        HoldPoint, ItemDefinition, ItemFrame, ItemSequence, ItemSequenceName,
+    };
+
+    const OBJECT_YAML: &str = r#"---
+sequences:
+  idle:
+    next: "idle"
+    frames:
+      - wait: 5
+        sprite: { sheet: 1, index: 3 }
+        body: [{ box: { x: 25, y: 11, w: 31, h: 68 } }]
+"#;
+
+    const HOLD_POINT_YAML: &str = r#"---
+x: 20
+y: 35
+throw: { x: 10, y: 5 }
+"#;
+
+    #[test]
+    fn deserialize_item_definition() {
+        let item_definition = serde_yaml::from_str::<ItemDefinition>(OBJECT_YAML)
+            .expect("Failed to deserialize `ItemDefinition`.");
+
+        let frames = vec![ItemFrame::new(ObjectFrame {
+            wait: Wait::new(5),
+            sprite: SpriteRef::new(1, 3),
+            body: Body::new(vec![Volume::Box {
+                x: 25,
+                y: 11,
+                z: 0,
+                w: 31,
+                h: 68,
+                d: 26,
+            }]),
+            ..Default::default()
+        })];
+        let sequence = ItemSequence::new(ObjectSequence {
+            sequence: Sequence {
+                next: SequenceEndTransition::SequenceName(SequenceNameString::Name(
+                    ItemSequenceName::Idle,
+                )),
+                frames,
+            },
+            ..Default::default()
+        });
+        let mut sequences = IndexMap::new();
+        sequences.insert(SequenceNameString::Name(ItemSequenceName::Idle), sequence);
+        let object_definition = ObjectDefinition::new(sequences);
+        let expected = ItemDefinition::new(object_definition);
+        assert_eq!(expected, item_definition);
+    }
+
+    #[test]
+    fn deserialize_hold_point() {
+        let hold_point = serde_yaml::from_str::<HoldPoint>(HOLD_POINT_YAML)
+            .expect("Failed to deserialize `HoldPoint`.");
+
+        let expected = HoldPoint::new(20, 35, 0, Some(VelocityInit::new(10, 5, 0)));
+        assert_eq!(expected, hold_point);
+    }
+}
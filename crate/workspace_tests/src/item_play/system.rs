@@ -0,0 +1,4 @@
+mod item_hold_system;
+mod item_land_system;
+mod item_pick_up_system;
+mod item_throw_system;
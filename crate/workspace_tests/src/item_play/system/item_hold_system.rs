@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use item_model::{config::HoldPoint, play::HeldItem};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use pretty_assertions::assert_eq;
+
+    use item_play::ItemHoldSystem;
+
+    #[test]
+    fn updates_item_position_to_hold_point() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemHoldSystem::new(), "", &[])
+            .with_effect(|world| create_holder_and_item(world, Mirrored(false)))
+            .with_assertion(|world| {
+                assert_item(
+                    world,
+                    Position::new(
+                        FixedPoint::from(30),
+                        FixedPoint::from(55),
+                        FixedPoint::from(5),
+                    ),
+                    Mirrored(false),
+                )
+            })
+            .run()
+    }
+
+    #[test]
+    fn updates_item_position_to_mirrored_hold_point() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemHoldSystem::new(), "", &[])
+            .with_effect(|world| create_holder_and_item(world, Mirrored(true)))
+            .with_assertion(|world| {
+                assert_item(
+                    world,
+                    Position::new(
+                        FixedPoint::from(-10),
+                        FixedPoint::from(55),
+                        FixedPoint::from(5),
+                    ),
+                    Mirrored(true),
+                )
+            })
+            .run()
+    }
+
+    #[test]
+    fn removes_held_item_from_holder_when_item_is_deleted() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemHoldSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_holder_and_item(world, Mirrored(false));
+
+                let (_holder, item) = *world.read_resource::<(Entity, Entity)>();
+                world
+                    .delete_entity(item)
+                    .expect("Failed to delete item entity.");
+            })
+            .with_assertion(|world| {
+                let (holder, _item) = *world.read_resource::<(Entity, Entity)>();
+                assert!(!world.read_storage::<HeldItem>().contains(holder));
+            })
+            .run()
+    }
+
+    fn create_holder_and_item(world: &mut World, mirrored: Mirrored) {
+        let item = world
+            .create_entity()
+            .with(Position::<FixedPoint>::default())
+            .with(Velocity::<FixedPoint>::new(
+                FixedPoint::from(3),
+                FixedPoint::from(-2),
+                FixedPoint::from(1),
+            ))
+            .with(Mirrored(false))
+            .build();
+        let holder = world
+            .create_entity()
+            .with(HeldItem::new(item))
+            .with(HoldPoint::new(20, 35, 0, None))
+            .with(Position::<FixedPoint>::new(
+                FixedPoint::from(10),
+                FixedPoint::from(20),
+                FixedPoint::from(5),
+            ))
+            .with(mirrored)
+            .build();
+        world.insert((holder, item));
+    }
+
+    fn assert_item(world: &mut World, position_expected: Position<FixedPoint>, mirrored: Mirrored) {
+        let (_holder, item) = *world.read_resource::<(Entity, Entity)>();
+        let positions = world.read_storage::<Position<FixedPoint>>();
+        let velocities = world.read_storage::<Velocity<FixedPoint>>();
+        let mirroreds = world.read_storage::<Mirrored>();
+
+        assert_eq!(Some(&position_expected), positions.get(item));
+        assert_eq!(Some(&Velocity::default()), velocities.get(item));
+        assert_eq!(Some(&mirrored), mirroreds.get(item));
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use item_model::{config::ItemSequenceName, play::HoldableItem};
+    use kinematic_model::config::{FixedPoint, Velocity};
+    use object_model::play::Grounding;
+    use parent_model::play::ParentEntity;
+    use pretty_assertions::assert_eq;
+    use sequence_model::{
+        config::SequenceNameString,
+        loaded::{AssetSequenceIdMappings, SequenceId, SequenceIdMappings},
+    };
+
+    use item_play::ItemLandSystem;
+
+    const SEQUENCE_ID_IDLE: SequenceId = SequenceId(0);
+    const SEQUENCE_ID_HELD: SequenceId = SequenceId(1);
+    const SEQUENCE_ID_THROWN: SequenceId = SequenceId(2);
+
+    #[test]
+    fn switches_thrown_item_to_idle_when_landed() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SEQUENCE_ID_THROWN,
+                grounding: Grounding::OnGround,
+                velocity_y: 0,
+                held: false,
+            },
+            SEQUENCE_ID_IDLE,
+        )
+    }
+
+    #[test]
+    fn does_not_switch_thrown_item_that_is_airborne() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SEQUENCE_ID_THROWN,
+                grounding: Grounding::Airborne,
+                velocity_y: -2,
+                held: false,
+            },
+            SEQUENCE_ID_THROWN,
+        )
+    }
+
+    #[test]
+    fn does_not_switch_thrown_item_that_is_moving_upwards() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SEQUENCE_ID_THROWN,
+                grounding: Grounding::OnGround,
+                velocity_y: 3,
+                held: false,
+            },
+            SEQUENCE_ID_THROWN,
+        )
+    }
+
+    #[test]
+    fn does_not_switch_held_item() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id: SEQUENCE_ID_HELD,
+                grounding: Grounding::OnGround,
+                velocity_y: 0,
+                held: true,
+            },
+            SEQUENCE_ID_HELD,
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            sequence_id,
+            grounding,
+            velocity_y,
+            held,
+        }: SetupParams,
+        sequence_id_expected: SequenceId,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemLandSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let item = create_item(world, sequence_id, grounding, velocity_y, held);
+                world.insert(item);
+            })
+            .with_assertion(move |world| {
+                let item = *world.read_resource::<Entity>();
+                assert_eq!(
+                    Some(&sequence_id_expected),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+            })
+            .run()
+    }
+
+    fn create_item(
+        world: &mut World,
+        sequence_id: SequenceId,
+        grounding: Grounding,
+        velocity_y: i32,
+        held: bool,
+    ) -> Entity {
+        let asset_id = AssetQueries::id_generate_any(world);
+        let sequence_id_mappings = vec![
+            SequenceNameString::Name(ItemSequenceName::Idle),
+            SequenceNameString::Name(ItemSequenceName::Held),
+            SequenceNameString::Name(ItemSequenceName::Thrown),
+        ]
+        .into_iter()
+        .collect::<SequenceIdMappings<ItemSequenceName>>();
+        world
+            .write_resource::<AssetSequenceIdMappings<ItemSequenceName>>()
+            .insert(asset_id, sequence_id_mappings);
+
+        let holder = world.create_entity().build();
+        let mut entity_builder = world
+            .create_entity()
+            .with(asset_id)
+            .with(HoldableItem)
+            .with(grounding)
+            .with(Velocity::<FixedPoint>::new(
+                FixedPoint::ZERO,
+                FixedPoint::from(velocity_y),
+                FixedPoint::ZERO,
+            ))
+            .with(sequence_id);
+        if held {
+            entity_builder = entity_builder.with(ParentEntity::new(holder));
+        }
+        entity_builder.build()
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct SetupParams {
+        sequence_id: SequenceId,
+        grounding: Grounding,
+        velocity_y: i32,
+        held: bool,
+    }
+}
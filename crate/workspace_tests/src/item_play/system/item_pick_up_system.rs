@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Interaction, InteractionKind, PickUp},
+        play::ContactEvent,
+    };
+    use item_model::play::{HeldItem, HoldableItem};
+    use parent_model::play::ParentEntity;
+    use pretty_assertions::assert_eq;
+    use shape_model::Volume;
+
+    use item_play::ItemPickUpSystem;
+
+    #[test]
+    fn attaches_holdable_item_to_holder() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_holder_and_item(world, true);
+                send_pick_up_event(world);
+            })
+            .with_assertion(|world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+                assert_eq!(
+                    Some(&HeldItem::new(item)),
+                    world.read_storage::<HeldItem>().get(holder)
+                );
+                assert_eq!(
+                    Some(&ParentEntity::new(holder)),
+                    world.read_storage::<ParentEntity>().get(item)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_attach_non_holdable_entity() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_holder_and_item(world, false);
+                send_pick_up_event(world);
+            })
+            .with_assertion(|world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+                assert!(!world.read_storage::<HeldItem>().contains(holder));
+                assert!(!world.read_storage::<ParentEntity>().contains(item));
+            })
+            .run()
+    }
+
+    fn create_holder_and_item(world: &mut World, holdable: bool) {
+        let holder = world.create_entity().build();
+        let item = {
+            let mut entity_builder = world.create_entity();
+            if holdable {
+                entity_builder = entity_builder.with(HoldableItem);
+            }
+            entity_builder.build()
+        };
+        world.insert((holder, item));
+    }
+
+    fn send_pick_up_event(world: &mut World) {
+        let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+        let interaction = Interaction::new(InteractionKind::PickUp(PickUp::new()), vec![], false);
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<ContactEvent>>();
+        ec.single_write(ContactEvent::new(holder, item, interaction, body));
+    }
+}
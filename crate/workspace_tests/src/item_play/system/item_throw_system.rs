@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use item_model::{
+        config::{HoldPoint, ItemSequenceName},
+        play::HeldItem,
+    };
+    use kinematic_model::config::{FixedPoint, Velocity, VelocityInit};
+    use mirrored_model::play::Mirrored;
+    use parent_model::play::ParentEntity;
+    use pretty_assertions::assert_eq;
+    use sequence_model::{
+        config::SequenceNameString,
+        loaded::{AssetSequenceIdMappings, SequenceId, SequenceIdMappings},
+    };
+    use spawn_model::play::SpawnParent;
+    use team_model::play::{Team, TeamCounter};
+
+    use item_play::ItemThrowSystem;
+
+    #[test]
+    fn throws_held_item_in_facing_direction() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_holder_and_item(world, Some(VelocityInit::new(5, 3, 1)));
+            })
+            .with_assertion(|world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(!world.read_storage::<HeldItem>().contains(holder));
+                assert!(!world.read_storage::<ParentEntity>().contains(item));
+                assert_eq!(
+                    Some(&Velocity::new(
+                        FixedPoint::from(-5),
+                        FixedPoint::from(3),
+                        FixedPoint::from(1),
+                    )),
+                    world.read_storage::<Velocity<FixedPoint>>().get(item)
+                );
+                assert_eq!(
+                    Some(&SpawnParent::new(holder)),
+                    world.read_storage::<SpawnParent>().get(item)
+                );
+                assert_eq!(
+                    Some(&Team::Number(TeamCounter(1))),
+                    world.read_storage::<Team>().get(item)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(2)),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn keeps_holding_item_when_hold_point_has_no_throw() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| create_holder_and_item(world, None))
+            .with_assertion(|world| {
+                let (holder, item) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&HeldItem::new(item)),
+                    world.read_storage::<HeldItem>().get(holder)
+                );
+                assert_eq!(
+                    Some(&ParentEntity::new(holder)),
+                    world.read_storage::<ParentEntity>().get(item)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(item)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn releases_held_item_that_is_deleted() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_holder_and_item(world, Some(VelocityInit::new(5, 3, 1)));
+
+                let (_holder, item) = *world.read_resource::<(Entity, Entity)>();
+                world
+                    .delete_entity(item)
+                    .expect("Failed to delete item entity.");
+            })
+            .with_assertion(|world| {
+                let (holder, _item) = *world.read_resource::<(Entity, Entity)>();
+                assert!(!world.read_storage::<HeldItem>().contains(holder));
+            })
+            .run()
+    }
+
+    fn create_holder_and_item(world: &mut World, throw: Option<VelocityInit>) {
+        let asset_id = AssetQueries::id_generate_any(world);
+        let sequence_id_mappings = vec![
+            SequenceNameString::Name(ItemSequenceName::Idle),
+            SequenceNameString::Name(ItemSequenceName::Held),
+            SequenceNameString::Name(ItemSequenceName::Thrown),
+        ]
+        .into_iter()
+        .collect::<SequenceIdMappings<ItemSequenceName>>();
+        world
+            .write_resource::<AssetSequenceIdMappings<ItemSequenceName>>()
+            .insert(asset_id, sequence_id_mappings);
+
+        let holder = world
+            .create_entity()
+            .with(HoldPoint::new(20, 35, 0, throw))
+            .with(Mirrored(true))
+            .with(Team::Number(TeamCounter(1)))
+            .build();
+        let item = world
+            .create_entity()
+            .with(asset_id)
+            .with(ParentEntity::new(holder))
+            .with(SequenceId::new(1))
+            .with(Velocity::<FixedPoint>::default())
+            .build();
+        world
+            .write_storage::<HeldItem>()
+            .insert(holder, HeldItem::new(item))
+            .expect("Failed to insert `HeldItem` component.");
+
+        world.insert((holder, item));
+    }
+}
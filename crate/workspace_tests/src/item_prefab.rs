@@ -0,0 +1 @@
+mod item_entity_augmenter;
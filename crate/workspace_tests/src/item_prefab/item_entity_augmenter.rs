@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        core::TransformBundle,
+        ecs::{Builder, World, WorldExt},
+        renderer::{types::DefaultBackend, RenderEmptyBundle},
+        shred::SystemData,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use item_model::play::HoldableItem;
+    use map_model::play::MapUnboundedDelete;
+
+    use item_prefab::{ItemComponentStorages, ItemEntityAugmenter};
+
+    #[test]
+    fn augments_entity_with_item_components() -> Result<(), Error> {
+        let assertion = |world: &mut World| {
+            let entity = world.create_entity().build();
+            {
+                let mut item_component_storages = ItemComponentStorages::fetch(&world);
+                ItemEntityAugmenter::augment(entity, &mut item_component_storages);
+            }
+
+            assert!(world.read_storage::<MapUnboundedDelete>().contains(entity));
+            assert!(world.read_storage::<HoldableItem>().contains(entity));
+        };
+
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_effect(|world| {
+                <ItemComponentStorages as SystemData>::setup(world);
+            })
+            .with_assertion(assertion)
+            .run_winit_loop()
+    }
+}
@@ -1,3 +1,5 @@
+mod fixed_point;
+
 #[cfg(test)]
 mod tests {
     use amethyst::core::math;
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use kinematic_model::config::FixedPoint;
+    use serde_yaml;
+
+    #[test]
+    fn from_int_is_exact() {
+        assert_eq!(3 << FixedPoint::FRAC_BITS, FixedPoint::from(3).to_bits());
+        assert_eq!(
+            -(3 << FixedPoint::FRAC_BITS),
+            FixedPoint::from(-3).to_bits()
+        );
+        assert_eq!(FixedPoint::from(3), FixedPoint::from(3u32));
+    }
+
+    #[test]
+    fn from_f64_rounds_to_nearest() {
+        assert_eq!(FixedPoint::from_bits(32768), FixedPoint::from(0.5));
+        assert_eq!(FixedPoint::from_bits(216_269), FixedPoint::from(3.3));
+        assert_eq!(FixedPoint::from_bits(-216_269), FixedPoint::from(-3.3));
+        assert_eq!(FixedPoint::from(3.3), FixedPoint::from(3.3f32));
+    }
+
+    #[test]
+    fn from_ratio_rounds_towards_zero() {
+        assert_eq!(FixedPoint::from_bits(45875), FixedPoint::from_ratio(7, 10));
+        assert_eq!(
+            FixedPoint::from_bits(-45875),
+            FixedPoint::from_ratio(-7, 10)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = FixedPoint::from(7.5);
+        let b = FixedPoint::from(2);
+
+        assert_eq!(FixedPoint::from(9.5), a + b);
+        assert_eq!(FixedPoint::from(5.5), a - b);
+        assert_eq!(FixedPoint::from(15), a * b);
+        assert_eq!(FixedPoint::from(3.75), a / b);
+        assert_eq!(FixedPoint::from(-7.5), -a);
+        assert_eq!(FixedPoint::from(7.5), (-a).abs());
+        assert_eq!(FixedPoint::from(-1), (-a).signum());
+        assert_eq!(FixedPoint::ZERO, FixedPoint::ZERO.signum());
+    }
+
+    #[test]
+    fn assign_arithmetic() {
+        let mut value = FixedPoint::from(7.5);
+
+        value += FixedPoint::from(2);
+        assert_eq!(FixedPoint::from(9.5), value);
+        value -= FixedPoint::from(1);
+        assert_eq!(FixedPoint::from(8.5), value);
+        value *= FixedPoint::from(2);
+        assert_eq!(FixedPoint::from(17), value);
+        value /= FixedPoint::from(4);
+        assert_eq!(FixedPoint::from(4.25), value);
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(FixedPoint::from(4), FixedPoint::from(16).sqrt());
+        assert_eq!(FixedPoint::from(0.5), FixedPoint::from(0.25).sqrt());
+        assert_eq!(92681, FixedPoint::from(2).sqrt().to_bits());
+        assert_eq!(FixedPoint::ZERO, FixedPoint::from(-4).sqrt());
+    }
+
+    #[test]
+    fn sin_and_cos() {
+        assert_eq!(FixedPoint::ZERO, FixedPoint::ZERO.sin());
+        assert_eq!(FixedPoint::ONE, FixedPoint::ZERO.cos());
+        assert_eq!(31420, FixedPoint::from(0.5).sin().to_bits());
+
+        (-2000..2000).for_each(|n| {
+            let radians = f64::from(n) / 100.;
+            let value = FixedPoint::from(radians);
+
+            assert!((f64::from(value.sin()) - radians.sin()).abs() < 0.001);
+            assert!((f64::from(value.cos()) - radians.cos()).abs() < 0.001);
+        });
+    }
+
+    #[test]
+    fn to_f32() {
+        assert_eq!(-10.25f32, FixedPoint::from(-10.25).to_f32());
+    }
+
+    #[test]
+    fn deserialize_from_number() {
+        assert_eq!(
+            FixedPoint::from(2.5),
+            serde_yaml::from_str::<FixedPoint>("2.5").expect("Failed to deserialize.")
+        );
+        assert_eq!(
+            FixedPoint::from(-3),
+            serde_yaml::from_str::<FixedPoint>("-3").expect("Failed to deserialize.")
+        );
+    }
+
+    #[test]
+    fn serialize_to_number() {
+        assert_eq!(
+            "---\n2.5",
+            serde_yaml::to_string(&FixedPoint::from(2.5)).expect("Failed to serialize.")
+        );
+    }
+
+    #[test]
+    fn div_by_zero_saturates_towards_sign_of_dividend() {
+        assert_eq!(FixedPoint::MAX, FixedPoint::from(3) / FixedPoint::ZERO);
+        assert_eq!(FixedPoint::MIN, FixedPoint::from(-3) / FixedPoint::ZERO);
+        assert_eq!(FixedPoint::ZERO, FixedPoint::ZERO / FixedPoint::ZERO);
+    }
+
+    #[test]
+    fn div_saturates_on_overflow() {
+        assert_eq!(
+            FixedPoint::MAX,
+            FixedPoint::MAX / FixedPoint::from_ratio(1, 2)
+        );
+        assert_eq!(
+            FixedPoint::MIN,
+            FixedPoint::MAX / FixedPoint::from_ratio(-1, 2)
+        );
+    }
+
+    #[test]
+    fn add_saturates_on_overflow() {
+        assert_eq!(FixedPoint::MAX, FixedPoint::MAX + FixedPoint::ONE);
+        assert_eq!(FixedPoint::MIN, FixedPoint::MIN + -FixedPoint::ONE);
+
+        let mut value = FixedPoint::MAX;
+        value += FixedPoint::ONE;
+        assert_eq!(FixedPoint::MAX, value);
+    }
+
+    #[test]
+    fn sub_saturates_on_overflow() {
+        assert_eq!(FixedPoint::MIN, FixedPoint::MIN - FixedPoint::ONE);
+        assert_eq!(FixedPoint::MAX, FixedPoint::MAX - -FixedPoint::ONE);
+
+        let mut value = FixedPoint::MIN;
+        value -= FixedPoint::ONE;
+        assert_eq!(FixedPoint::MIN, value);
+    }
+
+    #[test]
+    fn mul_saturates_on_overflow() {
+        assert_eq!(FixedPoint::MAX, FixedPoint::MAX * FixedPoint::from(2));
+        assert_eq!(FixedPoint::MIN, FixedPoint::MAX * FixedPoint::from(-2));
+        assert_eq!(FixedPoint::MAX, FixedPoint::MIN * FixedPoint::MIN);
+
+        let mut value = FixedPoint::MIN;
+        value *= FixedPoint::from(2);
+        assert_eq!(FixedPoint::MIN, value);
+    }
+
+    #[test]
+    fn neg_and_abs_saturate_on_overflow() {
+        assert_eq!(FixedPoint::MAX, -FixedPoint::MIN);
+        assert_eq!(FixedPoint::MAX, FixedPoint::MIN.abs());
+    }
+
+    #[test]
+    fn sin_does_not_overflow_for_large_values() {
+        assert!(FixedPoint::MAX.sin().abs() <= FixedPoint::ONE);
+    }
+}
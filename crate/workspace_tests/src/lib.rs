@@ -20,6 +20,10 @@ mod application_test_support;
 #[cfg(test)]
 mod application_ui;
 #[cfg(test)]
+mod asset_checker;
+#[cfg(test)]
+mod asset_fs;
+#[cfg(test)]
 mod asset_gfx_gen;
 #[cfg(test)]
 mod asset_loading;
@@ -28,6 +32,12 @@ mod asset_model;
 #[cfg(test)]
 mod asset_play;
 #[cfg(test)]
+mod asset_schema;
+#[cfg(test)]
+mod asset_schema_derive;
+#[cfg(test)]
+mod asset_schema_gen;
+#[cfg(test)]
 mod asset_selection_stdio;
 #[cfg(test)]
 mod asset_selection_ui_play;
@@ -54,8 +64,12 @@ mod character_prefab;
 #[cfg(test)]
 mod character_selection;
 #[cfg(test)]
+mod character_selection_model;
+#[cfg(test)]
 mod character_selection_ui_model;
 #[cfg(test)]
+mod charge_model;
+#[cfg(test)]
 mod charge_play;
 #[cfg(test)]
 mod chase_play;
@@ -78,6 +92,10 @@ mod energy_model;
 #[cfg(test)]
 mod energy_prefab;
 #[cfg(test)]
+mod frame_event_model;
+#[cfg(test)]
+mod frame_event_play;
+#[cfg(test)]
 mod game_input;
 #[cfg(test)]
 mod game_input_model;
@@ -104,12 +122,22 @@ mod input_reaction_model;
 #[cfg(test)]
 mod input_reaction_play;
 #[cfg(test)]
+mod item_loading;
+#[cfg(test)]
+mod item_model;
+#[cfg(test)]
+mod item_play;
+#[cfg(test)]
+mod item_prefab;
+#[cfg(test)]
 mod kinematic_loading;
 #[cfg(test)]
 mod kinematic_model;
 #[cfg(test)]
 mod loading;
 #[cfg(test)]
+mod loading_model;
+#[cfg(test)]
 mod logic_clock;
 #[cfg(test)]
 mod map_loading;
@@ -132,10 +160,16 @@ mod object_model;
 #[cfg(test)]
 mod object_play;
 #[cfg(test)]
+mod object_status_model;
+#[cfg(test)]
 mod object_status_play;
 #[cfg(test)]
 mod parent_play;
 #[cfg(test)]
+mod pause_menu;
+#[cfg(test)]
+mod pause_menu_stdio;
+#[cfg(test)]
 mod sequence_loading;
 #[cfg(test)]
 mod sequence_play;
@@ -170,12 +204,24 @@ mod stdio_command_stdio;
 #[cfg(test)]
 mod stdio_input;
 #[cfg(test)]
+mod survival_loading;
+#[cfg(test)]
+mod survival_model;
+#[cfg(test)]
+mod survival_play;
+#[cfg(test)]
 mod team_model;
 #[cfg(test)]
 mod test_object_model;
 #[cfg(test)]
 mod tracker;
 #[cfg(test)]
+mod training_model;
+#[cfg(test)]
+mod training_play;
+#[cfg(test)]
+mod training_stdio;
+#[cfg(test)]
 mod ui_audio_loading;
 #[cfg(test)]
 mod ui_form_model;
@@ -1,8 +1,12 @@
 mod asset_definition_loading_system;
 mod asset_discovery_system;
 mod asset_id_mapping_system;
+mod asset_load_request_system;
+mod asset_memory_system;
 mod asset_part_loading_coordinator_system;
 mod asset_part_loading_system;
+mod asset_reload_system;
 // mod asset_sequence_component_loading_system; // TODO: refactor first
 mod asset_sprites_definition_loading_system;
 mod asset_texture_loading_system;
+mod asset_unload_system;
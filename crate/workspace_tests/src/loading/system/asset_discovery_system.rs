@@ -8,7 +8,7 @@ mod tests {
         ASSETS_PATH, CHAR_BAT_PATH, CHAR_BAT_SLUG, ENERGY_SQUARE_PATH, ENERGY_SQUARE_SLUG,
         MAP_FADE_PATH, MAP_FADE_SLUG,
     };
-    use loading_model::loaded::LoadStage;
+    use loading_model::loaded::{DeferredAssetTypes, LoadStage};
     use object_type::ObjectType;
 
     use loading::{AssetDiscoverySystem, AssetDiscoverySystemData};
@@ -24,6 +24,7 @@ mod tests {
                     asset_type_mappings,
                     asset_load_stage,
                     asset_id_to_path,
+                    ..
                 } = world.system_data::<AssetDiscoverySystemData<'_>>();
 
                 assert!(asset_index.is_some());
@@ -58,4 +59,38 @@ mod tests {
             })
             .run()
     }
+    #[test]
+    fn inserts_deferred_load_stage_for_deferred_asset_types() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(DeferredAssetTypes::new(
+                [AssetType::Object(ObjectType::Character)]
+                    .iter()
+                    .copied()
+                    .collect(),
+            ))
+            .with_system(AssetDiscoverySystem::new(ASSETS_PATH.clone()), "", &[])
+            .with_assertion(move |world| {
+                let AssetDiscoverySystemData {
+                    asset_id_mappings,
+                    asset_load_stage,
+                    ..
+                } = world.system_data::<AssetDiscoverySystemData<'_>>();
+
+                [
+                    (CHAR_BAT_SLUG.clone(), LoadStage::Deferred),
+                    (ENERGY_SQUARE_SLUG.clone(), LoadStage::New),
+                    (MAP_FADE_SLUG.clone(), LoadStage::New),
+                ]
+                .iter()
+                .for_each(|(asset_slug, load_stage)| {
+                    let asset_id = asset_id_mappings
+                        .id(asset_slug)
+                        .copied()
+                        .expect("Expected `AssetId` to exist.");
+
+                    assert_eq!(Some(*load_stage), asset_load_stage.get(asset_id).copied());
+                })
+            })
+            .run()
+    }
 }
@@ -42,11 +42,13 @@ mod tests {
                 let IdMappingResources {
                     asset_sequence_id_mappings_character,
                     asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_item,
                     ..
                 } = world.system_data::<IdMappingResources<'_>>();
 
                 assert_eq!(0, asset_sequence_id_mappings_character.capacity());
                 assert_eq!(0, asset_sequence_id_mappings_energy.capacity());
+                assert_eq!(0, asset_sequence_id_mappings_item.capacity());
             })
             .with_effect(|world| {
                 {
@@ -64,11 +66,13 @@ mod tests {
                 let IdMappingResources {
                     asset_sequence_id_mappings_character,
                     asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_item,
                     ..
                 } = world.system_data::<IdMappingResources<'_>>();
 
                 assert_eq!(10, asset_sequence_id_mappings_character.capacity());
                 assert_eq!(10, asset_sequence_id_mappings_energy.capacity());
+                assert_eq!(10, asset_sequence_id_mappings_item.capacity());
             })
             .run()
     }
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{ecs::WorldExt, shrev::EventChannel, Error};
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use asset_model::{
+        config::AssetType,
+        loaded::{AssetId, AssetTypeMappings},
+    };
+    use loading_model::{
+        loaded::{DeferredAssetTypes, LoadStage, LoadStatus},
+        play::AssetLoadRequestEvent,
+    };
+    use object_type::ObjectType;
+
+    use loading::{AssetLoadRequestSystem, AssetLoadRequestSystemData};
+
+    #[test]
+    fn request_begins_loading_deferred_asset() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                load_stage: LoadStage::Deferred,
+                load_status: Some(LoadStatus::Complete),
+                deferred: true,
+                event_fn: AssetLoadRequestEvent::Request,
+            },
+            ExpectedParams {
+                load_stage: LoadStage::New,
+                load_status: None,
+            },
+        )
+    }
+
+    #[test]
+    fn request_does_not_restart_loading_asset() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                load_stage: LoadStage::TextureLoading,
+                load_status: Some(LoadStatus::InProgress),
+                deferred: true,
+                event_fn: AssetLoadRequestEvent::Request,
+            },
+            ExpectedParams {
+                load_stage: LoadStage::TextureLoading,
+                load_status: Some(LoadStatus::InProgress),
+            },
+        )
+    }
+
+    #[test]
+    fn cancel_defers_loading_asset() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                load_stage: LoadStage::TextureLoading,
+                load_status: Some(LoadStatus::InProgress),
+                deferred: true,
+                event_fn: AssetLoadRequestEvent::Cancel,
+            },
+            ExpectedParams {
+                load_stage: LoadStage::Deferred,
+                load_status: None,
+            },
+        )
+    }
+
+    #[test]
+    fn cancel_does_not_unload_complete_asset() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                load_stage: LoadStage::Complete,
+                load_status: Some(LoadStatus::Complete),
+                deferred: true,
+                event_fn: AssetLoadRequestEvent::Cancel,
+            },
+            ExpectedParams {
+                load_stage: LoadStage::Complete,
+                load_status: Some(LoadStatus::Complete),
+            },
+        )
+    }
+
+    #[test]
+    fn cancel_does_not_defer_asset_type_that_is_always_loaded() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                load_stage: LoadStage::TextureLoading,
+                load_status: Some(LoadStatus::InProgress),
+                deferred: false,
+                event_fn: AssetLoadRequestEvent::Cancel,
+            },
+            ExpectedParams {
+                load_stage: LoadStage::TextureLoading,
+                load_status: Some(LoadStatus::InProgress),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            load_stage: load_stage_setup,
+            load_status: load_status_setup,
+            deferred,
+            event_fn,
+        }: SetupParams,
+        ExpectedParams {
+            load_stage: load_stage_expected,
+            load_status: load_status_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        let asset_type = AssetType::Object(ObjectType::Character);
+        let deferred_asset_types = if deferred {
+            DeferredAssetTypes::new(Some(asset_type).into_iter().collect())
+        } else {
+            DeferredAssetTypes::default()
+        };
+
+        AmethystApplication::blank()
+            .with_resource(deferred_asset_types)
+            .with_system(AssetLoadRequestSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let asset_id = AssetQueries::id_generate_any(world);
+                {
+                    let AssetLoadRequestSystemData {
+                        mut asset_load_stage,
+                        mut asset_load_status,
+                        ..
+                    } = world.system_data::<AssetLoadRequestSystemData<'_>>();
+
+                    asset_load_stage.insert(asset_id, load_stage_setup);
+                    if let Some(load_status) = load_status_setup {
+                        asset_load_status.insert(asset_id, load_status);
+                    }
+                }
+                world
+                    .write_resource::<AssetTypeMappings>()
+                    .insert(asset_id, asset_type);
+                world
+                    .write_resource::<EventChannel<AssetLoadRequestEvent>>()
+                    .single_write(event_fn(asset_id));
+
+                world.insert(asset_id);
+            })
+            .with_assertion(move |world| {
+                let asset_id = *world.read_resource::<AssetId>();
+
+                let AssetLoadRequestSystemData {
+                    asset_load_stage,
+                    asset_load_status,
+                    ..
+                } = world.system_data::<AssetLoadRequestSystemData<'_>>();
+
+                assert_eq!(
+                    Some(load_stage_expected),
+                    asset_load_stage.get(asset_id).copied()
+                );
+                assert_eq!(
+                    load_status_expected,
+                    asset_load_status.get(asset_id).copied()
+                );
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        load_stage: LoadStage,
+        load_status: Option<LoadStatus>,
+        deferred: bool,
+        event_fn: fn(AssetId) -> AssetLoadRequestEvent,
+    }
+
+    struct ExpectedParams {
+        load_stage: LoadStage,
+        load_status: Option<LoadStatus>,
+    }
+}
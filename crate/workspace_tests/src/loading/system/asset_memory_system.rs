@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use amethyst::{
+        ecs::{World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use asset_model::{
+        config::AssetType,
+        loaded::{AssetId, AssetTypeMappings},
+    };
+    use character_model::config::CharacterSequenceName;
+    use loading_model::{
+        loaded::{AssetLoadStage, DeferredAssetTypes, LoadStage},
+        play::{AssetMemory, AssetMemoryBudget, AssetMemoryUsage},
+    };
+    use object_type::ObjectType;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::{AssetSequenceIdMappings, SequenceIdMappings};
+    use slotmap::SecondaryMap;
+    use tempfile::tempdir;
+
+    use loading::{AssetMemorySystem, AssetUnloadSystem};
+
+    const OBJECT_DEFINITION: &str = "sequences: {}";
+
+    #[test]
+    fn measures_definition_size_of_complete_asset() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetMemorySystem::new(PathBuf::new()), "", &[])
+            .with_effect(|world| setup_asset(world, LoadStage::Complete))
+            .with_assertion(|world| {
+                let asset_id = *world.read_resource::<AssetId>();
+                let asset_memory = world
+                    .read_resource::<AssetMemoryUsage>()
+                    .assets
+                    .get(asset_id)
+                    .copied();
+
+                assert_eq!(
+                    Some(AssetMemory::new(0, 0, OBJECT_DEFINITION.len() as u64)),
+                    asset_memory
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_measure_asset_that_is_not_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetMemorySystem::new(PathBuf::new()), "", &[])
+            .with_effect(|world| setup_asset(world, LoadStage::TextureLoading))
+            .with_assertion(|world| {
+                let asset_id = *world.read_resource::<AssetId>();
+
+                assert!(!world
+                    .read_resource::<AssetMemoryUsage>()
+                    .assets
+                    .contains_key(asset_id));
+            })
+            .run()
+    }
+
+    #[test]
+    fn unloads_measured_asset_when_over_budget() -> Result<(), Error> {
+        let asset_type = AssetType::Object(ObjectType::Character);
+
+        AmethystApplication::blank()
+            .with_resource(DeferredAssetTypes::new(
+                Some(asset_type).into_iter().collect(),
+            ))
+            .with_resource(AssetMemoryBudget::new(Some(0)))
+            .with_system(
+                AssetMemorySystem::new(PathBuf::new()),
+                "asset_memory_system",
+                &[],
+            )
+            .with_system(
+                AssetUnloadSystem::new(0),
+                "asset_unload_system",
+                &["asset_memory_system"],
+            )
+            .with_effect(move |world| {
+                setup_asset(world, LoadStage::Complete);
+
+                let asset_id = *world.read_resource::<AssetId>();
+                world
+                    .write_resource::<AssetTypeMappings>()
+                    .insert(asset_id, asset_type);
+                world
+                    .write_resource::<AssetSequenceIdMappings<CharacterSequenceName>>()
+                    .insert(asset_id, SequenceIdMappings::new());
+            })
+            .with_assertion(|world| {
+                let asset_id = *world.read_resource::<AssetId>();
+
+                assert_eq!(
+                    Some(LoadStage::Deferred),
+                    world
+                        .read_resource::<AssetLoadStage>()
+                        .get(asset_id)
+                        .copied()
+                );
+                assert!(!world
+                    .read_resource::<AssetMemoryUsage>()
+                    .assets
+                    .contains_key(asset_id));
+                assert!(!world
+                    .read_resource::<AssetSequenceIdMappings<CharacterSequenceName>>()
+                    .contains_key(asset_id));
+            })
+            .run()
+    }
+
+    fn setup_asset(world: &mut World, load_stage: LoadStage) {
+        let asset_dir = tempdir().expect("Failed to create temporary directory.");
+        fs::write(asset_dir.path().join("object.yaml"), OBJECT_DEFINITION)
+            .expect("Failed to write `object.yaml`.");
+
+        let asset_id = AssetQueries::id_generate_any(world);
+        world
+            .write_resource::<SecondaryMap<AssetId, PathBuf>>()
+            .insert(asset_id, asset_dir.path().to_path_buf());
+        world
+            .write_resource::<AssetLoadStage>()
+            .insert(asset_id, load_stage);
+
+        world.insert(asset_id);
+        world.insert(asset_dir);
+    }
+}
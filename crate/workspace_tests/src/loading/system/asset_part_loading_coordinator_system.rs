@@ -67,6 +67,20 @@ mod tests {
         )
     }
 
+    #[test]
+    fn does_not_progress_deferred_assets() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                load_stage: LoadStage::Deferred,
+                load_status: Some(LoadStatus::Complete),
+            },
+            ExpectedParams {
+                load_stage: LoadStage::Deferred,
+                load_status: LoadStatus::Complete,
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             load_stage: load_stage_setup,
@@ -0,0 +1,274 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs, path::PathBuf, thread, time::Duration};
+
+    use amethyst::{
+        ecs::{SystemData, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use asset_model::{
+        config::AssetType,
+        loaded::{AssetId, AssetIdMappings, AssetTypeMappings},
+        play::AssetReloadEvent,
+    };
+    use character_model::config::CharacterSequenceName;
+    use loading_model::loaded::{AssetLoadStage, AssetLoadStatus, LoadStage, LoadStatus};
+    use object_type::ObjectType;
+    use pretty_assertions::assert_eq;
+    use sequence_model::{
+        config::SequenceNameString,
+        loaded::{AssetSequenceIdMappings, AssetSequenceIdRemaps, SequenceId, SequenceIdMappings},
+    };
+    use slotmap::SecondaryMap;
+    use tempfile::{tempdir, TempDir};
+
+    use loading::{AssetReloadSystem, AssetReloadSystemData};
+
+    #[test]
+    fn does_not_reload_asset_on_first_check() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetReloadSystem::new(1), "", &[])
+            .with_setup(AssetReloadSystemData::setup)
+            .with_effect(|world| setup_asset(world, AssetType::Object(ObjectType::Character)))
+            .with_assertion(|world| expect_load_stage(world, LoadStage::Complete, None))
+            .run()
+    }
+
+    #[test]
+    fn reloads_object_asset_when_definition_changes() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetReloadSystem::new(1), "", &[])
+            .with_setup(AssetReloadSystemData::setup)
+            .with_effect(|world| setup_asset(world, AssetType::Object(ObjectType::Character)))
+            .with_effect(write_definition)
+            .with_assertion(|world| {
+                expect_load_stage(
+                    world,
+                    LoadStage::AssetDefinitionLoading,
+                    Some(LoadStatus::Queued),
+                )
+            })
+            .run()
+    }
+
+    #[test]
+    fn reloads_object_asset_when_extended_definition_changes() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetReloadSystem::new(1), "", &[])
+            .with_setup(AssetReloadSystemData::setup)
+            .with_effect(setup_asset_extending)
+            .with_effect(write_parent_definition)
+            .with_assertion(|world| {
+                expect_load_stage(
+                    world,
+                    LoadStage::AssetDefinitionLoading,
+                    Some(LoadStatus::Queued),
+                )
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_reload_non_object_asset() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetReloadSystem::new(1), "", &[])
+            .with_setup(AssetReloadSystemData::setup)
+            .with_effect(|world| setup_asset(world, AssetType::Map))
+            .with_effect(write_definition)
+            .with_assertion(|world| expect_load_stage(world, LoadStage::Complete, None))
+            .run()
+    }
+
+    #[test]
+    fn sends_asset_reload_event_when_reloaded_asset_is_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetReloadSystem::new(1), "", &[])
+            .with_setup(AssetReloadSystemData::setup)
+            .with_effect(|world| setup_asset(world, AssetType::Object(ObjectType::Character)))
+            .with_effect(write_definition)
+            .with_effect(|world| {
+                let asset_id = *world.read_resource::<AssetId>();
+                world
+                    .write_resource::<AssetLoadStage>()
+                    .insert(asset_id, LoadStage::Complete);
+                world
+                    .write_resource::<AssetLoadStatus>()
+                    .insert(asset_id, LoadStatus::Complete);
+            })
+            .with_assertion(|world| {
+                let asset_id = *world.read_resource::<AssetId>();
+                let mut reader_id = world.write_resource::<ReaderId<AssetReloadEvent>>();
+                let events = world
+                    .read_resource::<EventChannel<AssetReloadEvent>>()
+                    .read(&mut reader_id)
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                assert_eq!(vec![AssetReloadEvent::Complete { asset_id }], events);
+            })
+            .run()
+    }
+
+    #[test]
+    fn records_sequence_id_remap_when_reloaded_asset_is_complete() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AssetReloadSystem::new(1), "", &[])
+            .with_setup(AssetReloadSystemData::setup)
+            .with_effect(|world| {
+                setup_asset(world, AssetType::Object(ObjectType::Character));
+                sequence_id_mappings_insert(
+                    world,
+                    &[CharacterSequenceName::Stand, CharacterSequenceName::Walk],
+                );
+            })
+            .with_effect(write_definition)
+            .with_effect(|world| {
+                // The reloaded definition declares its sequences in a different order.
+                sequence_id_mappings_insert(
+                    world,
+                    &[CharacterSequenceName::Walk, CharacterSequenceName::Stand],
+                );
+
+                let asset_id = *world.read_resource::<AssetId>();
+                world
+                    .write_resource::<AssetLoadStage>()
+                    .insert(asset_id, LoadStage::Complete);
+                world
+                    .write_resource::<AssetLoadStatus>()
+                    .insert(asset_id, LoadStatus::Complete);
+            })
+            .with_assertion(|world| {
+                let asset_id = *world.read_resource::<AssetId>();
+                let asset_sequence_id_remaps = world.read_resource::<AssetSequenceIdRemaps>();
+
+                let mut sequence_id_remap_expected = HashMap::new();
+                sequence_id_remap_expected.insert(SequenceId(0), SequenceId(1));
+                sequence_id_remap_expected.insert(SequenceId(1), SequenceId(0));
+                assert_eq!(
+                    Some(&sequence_id_remap_expected),
+                    asset_sequence_id_remaps.get(asset_id)
+                );
+            })
+            .run()
+    }
+
+    fn setup_asset(world: &mut World, asset_type: AssetType) {
+        let asset_dir = tempdir().expect("Failed to create temporary directory.");
+        fs::write(asset_dir.path().join("object.yaml"), "sequences: {}")
+            .expect("Failed to write `object.yaml`.");
+
+        let asset_id = AssetQueries::id_generate_any(world);
+        world
+            .write_resource::<SecondaryMap<AssetId, PathBuf>>()
+            .insert(asset_id, asset_dir.path().to_path_buf());
+        world
+            .write_resource::<AssetTypeMappings>()
+            .insert(asset_id, asset_type);
+        world
+            .write_resource::<AssetLoadStage>()
+            .insert(asset_id, LoadStage::Complete);
+
+        let reader_id = world
+            .write_resource::<EventChannel<AssetReloadEvent>>()
+            .register_reader();
+
+        world.insert(reader_id);
+        world.insert(asset_id);
+        world.insert(asset_dir);
+    }
+
+    /// Sets up an object asset that extends another asset in a separate directory.
+    fn setup_asset_extending(world: &mut World) {
+        let parent_dir = tempdir().expect("Failed to create temporary directory.");
+        fs::write(parent_dir.path().join("object.yaml"), "sequences: {}")
+            .expect("Failed to write `object.yaml`.");
+        {
+            let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
+            let asset_id_parent = asset_id_mappings.insert(
+                "test/reload_parent"
+                    .parse()
+                    .expect("Expected asset slug to be valid."),
+            );
+            world
+                .write_resource::<SecondaryMap<AssetId, PathBuf>>()
+                .insert(asset_id_parent, parent_dir.path().to_path_buf());
+        }
+
+        setup_asset(world, AssetType::Object(ObjectType::Character));
+        {
+            let asset_dir = world.read_resource::<TempDir>();
+            fs::write(
+                asset_dir.path().join("object.yaml"),
+                "extends: test/reload_parent\nsequences: {}",
+            )
+            .expect("Failed to write `object.yaml`.");
+        }
+
+        world.insert(ParentDir(parent_dir));
+    }
+
+    fn write_parent_definition(world: &mut World) {
+        // Ensure the modification time differs from the previous write.
+        thread::sleep(Duration::from_millis(10));
+
+        let parent_dir = world.read_resource::<ParentDir>();
+        fs::write(
+            parent_dir.0.path().join("object.yaml"),
+            "sequences: {} # changed",
+        )
+        .expect("Failed to write `object.yaml`.");
+    }
+
+    fn write_definition(world: &mut World) {
+        // Ensure the modification time differs from the previous write.
+        thread::sleep(Duration::from_millis(10));
+
+        let asset_dir = world.read_resource::<TempDir>();
+        fs::write(
+            asset_dir.path().join("object.yaml"),
+            "sequences: {} # changed",
+        )
+        .expect("Failed to write `object.yaml`.");
+    }
+
+    fn sequence_id_mappings_insert(world: &mut World, sequence_names: &[CharacterSequenceName]) {
+        let asset_id = *world.read_resource::<AssetId>();
+        let sequence_id_mappings = sequence_names
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(index, sequence_name)| {
+                (SequenceNameString::Name(sequence_name), SequenceId(index))
+            })
+            .collect::<SequenceIdMappings<CharacterSequenceName>>();
+
+        world
+            .write_resource::<AssetSequenceIdMappings<CharacterSequenceName>>()
+            .insert(asset_id, sequence_id_mappings);
+    }
+
+    fn expect_load_stage(
+        world: &mut World,
+        load_stage_expected: LoadStage,
+        load_status_expected: Option<LoadStatus>,
+    ) {
+        let asset_id = *world.read_resource::<AssetId>();
+        let asset_load_stage = world.read_resource::<AssetLoadStage>();
+        let asset_load_status = world.read_resource::<AssetLoadStatus>();
+
+        assert_eq!(
+            Some(load_stage_expected),
+            asset_load_stage.get(asset_id).copied()
+        );
+        assert_eq!(
+            load_status_expected,
+            asset_load_status.get(asset_id).copied()
+        );
+    }
+
+    /// Directory of the asset that the test asset extends.
+    struct ParentDir(TempDir);
+}
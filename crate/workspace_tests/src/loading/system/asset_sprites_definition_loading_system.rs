@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, path::PathBuf};
+    use std::{collections::HashMap, fs, path::PathBuf};
 
     use amethyst::{
         assets::{Processor, ProgressCounter},
@@ -19,6 +19,7 @@ mod tests {
     use object_type::ObjectType;
     use slotmap::SecondaryMap;
     use sprite_model::config::SpritesDefinition;
+    use tempfile::tempdir;
 
     use loading::{
         AssetLoadingResources, AssetPartLoader, AssetSpritesDefinitionLoader,
@@ -32,6 +33,7 @@ mod tests {
                 asset_slug: CHAR_BAT_SLUG.clone(),
                 asset_path: CHAR_BAT_PATH.clone(),
                 asset_type: AssetType::Object(ObjectType::Character),
+                asset_parent: None,
             },
             ExpectedParams {
                 is_complete_pre_load: false,
@@ -39,6 +41,7 @@ mod tests {
                     let SpritesDefinitionLoadingResources {
                         sprites_definition_assets,
                         asset_sprites_definition_handles,
+                        ..
                     } = sprites_definition_loading_resources;
 
                     let sprites_definition_handle = asset_sprites_definition_handles.get(asset_id);
@@ -63,6 +66,7 @@ mod tests {
                 asset_slug: MAP_FADE_SLUG.clone(),
                 asset_path: MAP_FADE_PATH.clone(),
                 asset_type: AssetType::Map,
+                asset_parent: None,
             },
             ExpectedParams {
                 is_complete_pre_load: false,
@@ -70,6 +74,7 @@ mod tests {
                     let SpritesDefinitionLoadingResources {
                         sprites_definition_assets,
                         asset_sprites_definition_handles,
+                        ..
                     } = sprites_definition_loading_resources;
 
                     let sprites_definition_handle = asset_sprites_definition_handles.get(asset_id);
@@ -94,6 +99,7 @@ mod tests {
                 asset_slug: MAP_EMPTY_SLUG.clone(),
                 asset_path: MAP_EMPTY_PATH.clone(),
                 asset_type: AssetType::Map,
+                asset_parent: None,
             },
             ExpectedParams {
                 is_complete_pre_load: true,
@@ -111,11 +117,50 @@ mod tests {
         )
     }
 
+    #[test]
+    fn loads_parent_sprites_definition_for_objects_without_sprites() -> Result<(), Error> {
+        let asset_dir = tempdir()?;
+        fs::write(
+            asset_dir.path().join("object.yaml"),
+            format!("extends: {}\nsequences: {{}}", *CHAR_BAT_SLUG),
+        )?;
+
+        run_test(
+            SetupParams {
+                asset_slug: "test/bat_remix"
+                    .parse()
+                    .expect("Expected asset slug to be valid."),
+                asset_path: asset_dir.path().to_path_buf(),
+                asset_type: AssetType::Object(ObjectType::Character),
+                asset_parent: Some((CHAR_BAT_SLUG.clone(), CHAR_BAT_PATH.clone())),
+            },
+            ExpectedParams {
+                is_complete_pre_load: false,
+                fn_assertion: |sprites_definition_loading_resources, asset_id| {
+                    let SpritesDefinitionLoadingResources {
+                        sprites_definition_assets,
+                        asset_sprites_definition_handles,
+                        asset_sprites_dirs,
+                        ..
+                    } = sprites_definition_loading_resources;
+
+                    let sprites_definition = asset_sprites_definition_handles
+                        .get(asset_id)
+                        .and_then(|handle| sprites_definition_assets.get(handle));
+
+                    assert!(sprites_definition.is_some());
+                    assert_eq!(Some(&*CHAR_BAT_PATH), asset_sprites_dirs.get(asset_id));
+                },
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             asset_slug,
             asset_path,
             asset_type,
+            asset_parent,
         }: SetupParams,
         ExpectedParams {
             is_complete_pre_load,
@@ -130,6 +175,12 @@ mod tests {
                     let (mut asset_id_to_path, mut asset_id_mappings, mut asset_type_mappings) =
                         world.system_data::<TestSystemData>();
 
+                    if let Some((asset_slug_parent, asset_path_parent)) = asset_parent {
+                        let asset_id_parent = asset_id_mappings.insert(asset_slug_parent);
+                        asset_id_to_path.insert(asset_id_parent, asset_path_parent);
+                        asset_type_mappings.insert(asset_id_parent, asset_type);
+                    }
+
                     let asset_id = asset_id_mappings.insert(asset_slug);
                     asset_id_to_path.insert(asset_id, asset_path);
                     asset_type_mappings.insert(asset_id, asset_type);
@@ -207,6 +258,7 @@ mod tests {
         asset_slug: AssetSlug,
         asset_path: PathBuf,
         asset_type: AssetType,
+        asset_parent: Option<(AssetSlug, PathBuf)>,
     }
 
     struct ExpectedParams {
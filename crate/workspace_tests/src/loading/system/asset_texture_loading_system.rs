@@ -41,6 +41,7 @@ mod tests {
                     let SpritesDefinitionLoadingResources {
                         sprites_definition_assets,
                         asset_sprites_definition_handles,
+                        ..
                     } = definition_loading_resources;
 
                     let sprites_definition_handle =
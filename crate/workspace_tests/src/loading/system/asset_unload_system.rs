@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AssetQueries;
+    use asset_model::{
+        config::AssetType,
+        loaded::{AssetId, AssetItemIds, AssetTypeMappings, ItemId, ItemIds},
+        play::AssetWorld,
+    };
+    use character_model::config::CharacterSequenceName;
+    use derive_new::new;
+    use loading_model::{
+        loaded::{AssetLoadStage, DeferredAssetTypes, LoadStage},
+        play::{AssetMemory, AssetMemoryBudget, AssetMemoryUsage, AssetUsage},
+    };
+    use map_model::{
+        config::{MapBounds, SpawnPoints},
+        loaded::{AssetMapBounds, AssetSpawnPoints},
+    };
+    use object_type::ObjectType;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::{AssetSequenceIdMappings, SequenceIdMappings};
+
+    use loading::AssetUnloadSystem;
+
+    #[test]
+    fn unloads_least_recently_used_asset_when_over_budget() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                budget: Some(150),
+                deferred: true,
+            },
+            ExpectedParams { lru_unloaded: true },
+        )
+    }
+
+    #[test]
+    fn does_not_unload_assets_within_budget() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                budget: Some(200),
+                deferred: true,
+            },
+            ExpectedParams {
+                lru_unloaded: false,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_unload_assets_without_budget() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                budget: None,
+                deferred: true,
+            },
+            ExpectedParams {
+                lru_unloaded: false,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_unload_asset_type_that_is_always_loaded() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                budget: Some(150),
+                deferred: false,
+            },
+            ExpectedParams {
+                lru_unloaded: false,
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams { budget, deferred }: SetupParams,
+        ExpectedParams { lru_unloaded }: ExpectedParams,
+    ) -> Result<(), Error> {
+        let asset_type = AssetType::Object(ObjectType::Character);
+        let deferred_asset_types = if deferred {
+            DeferredAssetTypes::new(Some(asset_type).into_iter().collect())
+        } else {
+            DeferredAssetTypes::default()
+        };
+
+        AmethystApplication::blank()
+            .with_resource(deferred_asset_types)
+            .with_resource(AssetMemoryBudget::new(budget))
+            .with_system(AssetUnloadSystem::new(0), "", &[])
+            .with_effect(move |world| {
+                let asset_lru = setup_asset(world, asset_type, 1);
+                let asset_mru = setup_asset(world, asset_type, 5);
+                world.write_resource::<AssetUsage>().tick = 10;
+
+                world.insert(TestAssets::new(asset_lru, asset_mru));
+            })
+            .with_assertion(move |world| {
+                let TestAssets {
+                    asset_lru,
+                    asset_mru,
+                } = *world.read_resource::<TestAssets>();
+
+                expect_asset_unloaded(world, asset_lru, lru_unloaded);
+                expect_asset_unloaded(world, asset_mru, false);
+            })
+            .run()
+    }
+
+    /// Sets up a loaded asset that uses 100 bytes and was last used at the given tick.
+    fn setup_asset(world: &mut World, asset_type: AssetType, last_used: u64) -> TestAsset {
+        let asset_id = AssetQueries::id_generate_any(world);
+        let item_entity = world.write_resource::<AssetWorld>().create_entity().build();
+
+        world
+            .write_resource::<AssetTypeMappings>()
+            .insert(asset_id, asset_type);
+        world
+            .write_resource::<AssetLoadStage>()
+            .insert(asset_id, LoadStage::Complete);
+        world
+            .write_resource::<AssetMemoryUsage>()
+            .assets
+            .insert(asset_id, AssetMemory::new(100, 0, 0));
+        world
+            .write_resource::<AssetUsage>()
+            .last_used
+            .insert(asset_id, last_used);
+        world
+            .write_resource::<AssetSequenceIdMappings<CharacterSequenceName>>()
+            .insert(asset_id, SequenceIdMappings::new());
+        world
+            .write_resource::<AssetMapBounds>()
+            .insert(asset_id, MapBounds::new(0, 0, 0, 800, 600, 200));
+        world
+            .write_resource::<AssetSpawnPoints>()
+            .insert(asset_id, SpawnPoints::default());
+        world
+            .write_resource::<AssetItemIds>()
+            .insert(asset_id, ItemIds::new(vec![ItemId::new(item_entity)]));
+
+        TestAsset::new(asset_id, item_entity)
+    }
+
+    fn expect_asset_unloaded(world: &mut World, test_asset: TestAsset, unloaded: bool) {
+        let TestAsset {
+            asset_id,
+            item_entity,
+        } = test_asset;
+        let loaded = !unloaded;
+
+        let load_stage_expected = if unloaded {
+            LoadStage::Deferred
+        } else {
+            LoadStage::Complete
+        };
+        assert_eq!(
+            Some(load_stage_expected),
+            world
+                .read_resource::<AssetLoadStage>()
+                .get(asset_id)
+                .copied()
+        );
+        assert_eq!(
+            loaded,
+            world
+                .read_resource::<AssetMemoryUsage>()
+                .assets
+                .contains_key(asset_id)
+        );
+        assert_eq!(
+            loaded,
+            world
+                .read_resource::<AssetSequenceIdMappings<CharacterSequenceName>>()
+                .contains_key(asset_id)
+        );
+        assert_eq!(
+            loaded,
+            world
+                .read_resource::<AssetMapBounds>()
+                .contains_key(asset_id)
+        );
+        assert_eq!(
+            loaded,
+            world
+                .read_resource::<AssetSpawnPoints>()
+                .contains_key(asset_id)
+        );
+        assert_eq!(
+            loaded,
+            world.read_resource::<AssetItemIds>().contains_key(asset_id)
+        );
+        assert_eq!(
+            loaded,
+            world.read_resource::<AssetWorld>().is_alive(item_entity)
+        );
+    }
+
+    #[derive(Clone, Copy, Debug, new)]
+    struct TestAsset {
+        asset_id: AssetId,
+        item_entity: Entity,
+    }
+
+    #[derive(Clone, Copy, Debug, new)]
+    struct TestAssets {
+        asset_lru: TestAsset,
+        asset_mru: TestAsset,
+    }
+
+    struct SetupParams {
+        budget: Option<u64>,
+        deferred: bool,
+    }
+
+    struct ExpectedParams {
+        lru_unloaded: bool,
+    }
+}
@@ -0,0 +1,2 @@
+mod loaded;
+mod play;
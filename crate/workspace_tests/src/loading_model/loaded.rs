@@ -0,0 +1 @@
+mod load_stage;
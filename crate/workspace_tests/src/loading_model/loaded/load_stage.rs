@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use loading_model::loaded::LoadStage;
+
+    #[test]
+    fn deferred_has_no_next_or_prev_stage() {
+        assert_eq!(None, LoadStage::Deferred.next());
+        assert_eq!(None, LoadStage::Deferred.prev());
+    }
+
+    #[test]
+    fn stages_are_ordered_by_loading_progress() {
+        let mut load_stage = LoadStage::New;
+        while let Some(next_load_stage) = load_stage.next() {
+            assert!(load_stage < next_load_stage);
+            assert_eq!(Some(load_stage), next_load_stage.prev());
+
+            load_stage = next_load_stage;
+        }
+
+        assert_eq!(LoadStage::Complete, load_stage);
+        assert!(LoadStage::Deferred < LoadStage::New);
+    }
+}
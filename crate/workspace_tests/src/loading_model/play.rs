@@ -0,0 +1,3 @@
+mod asset_memory_usage;
+mod asset_usage;
+mod loading_progress;
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use asset_model::{
+        config::AssetSlug,
+        loaded::{AssetId, AssetIdMappings},
+    };
+    use loading_model::play::{AssetMemory, AssetMemoryUsage, AssetUsage};
+
+    #[test]
+    fn namespaces_sums_memory_per_namespace() {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let mut asset_memory_usage = AssetMemoryUsage::default();
+        [
+            ("default/will", AssetMemory::new(100, 10, 1)),
+            ("default/bat", AssetMemory::new(200, 20, 2)),
+            ("mod/tux", AssetMemory::new(400, 40, 4)),
+        ]
+        .iter()
+        .for_each(|(slug, asset_memory)| {
+            let asset_id = asset_id_mappings.insert(asset_slug(slug));
+            asset_memory_usage.assets.insert(asset_id, *asset_memory);
+        });
+
+        let mut namespaces_expected = BTreeMap::new();
+        namespaces_expected.insert(String::from("default"), AssetMemory::new(300, 30, 3));
+        namespaces_expected.insert(String::from("mod"), AssetMemory::new(400, 40, 4));
+        assert_eq!(
+            namespaces_expected,
+            asset_memory_usage.namespaces(&asset_id_mappings)
+        );
+        assert_eq!(777, asset_memory_usage.total().total());
+    }
+
+    #[test]
+    fn lru_unloads_returns_nothing_within_budget() {
+        let (asset_memory_usage, asset_ids) = asset_memory_usage(&[100, 100, 100]);
+        let mut asset_usage = AssetUsage::default();
+        asset_usage.update(asset_ids.iter().copied());
+
+        assert!(asset_memory_usage
+            .lru_unloads(300, &asset_usage, |_| true)
+            .is_empty());
+    }
+
+    #[test]
+    fn lru_unloads_least_recently_used_assets_until_within_budget() {
+        let (asset_memory_usage, asset_ids) = asset_memory_usage(&[100, 100, 100, 100]);
+        let mut asset_usage = AssetUsage::default();
+        asset_usage.update(vec![asset_ids[2]]);
+        asset_usage.update(vec![asset_ids[0]]);
+        asset_usage.update(vec![asset_ids[1]]);
+        asset_usage.update(None);
+
+        assert_eq!(
+            vec![asset_ids[3], asset_ids[2]],
+            asset_memory_usage.lru_unloads(200, &asset_usage, |_| true)
+        );
+    }
+
+    #[test]
+    fn lru_unloads_skips_assets_in_use_and_not_unloadable() {
+        let (asset_memory_usage, asset_ids) = asset_memory_usage(&[100, 100, 100, 100]);
+        let mut asset_usage = AssetUsage::default();
+        asset_usage.update(vec![asset_ids[1]]);
+        asset_usage.update(vec![asset_ids[0]]);
+
+        let unloadable = asset_ids[3];
+        assert_eq!(
+            vec![asset_ids[3]],
+            asset_memory_usage.lru_unloads(0, &asset_usage, |asset_id| asset_id == unloadable)
+        );
+    }
+
+    fn asset_memory_usage(texture_bytes: &[u64]) -> (AssetMemoryUsage, Vec<AssetId>) {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let mut asset_memory_usage = AssetMemoryUsage::default();
+        let asset_ids = texture_bytes
+            .iter()
+            .enumerate()
+            .map(|(index, texture_bytes)| {
+                let asset_id =
+                    asset_id_mappings.insert(asset_slug(&format!("test/asset_{}", index)));
+                asset_memory_usage
+                    .assets
+                    .insert(asset_id, AssetMemory::new(*texture_bytes, 0, 0));
+                asset_id
+            })
+            .collect::<Vec<AssetId>>();
+
+        (asset_memory_usage, asset_ids)
+    }
+
+    fn asset_slug(slug: &str) -> AssetSlug {
+        slug.parse().expect("Expected asset slug to be valid.")
+    }
+}
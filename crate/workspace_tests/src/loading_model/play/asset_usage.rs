@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use loading_model::play::AssetUsage;
+
+    #[test]
+    fn update_records_assets_in_use_and_last_used_tick() {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let asset_id_0 = asset_id_mappings.insert(asset_slug("test/zero"));
+        let asset_id_1 = asset_id_mappings.insert(asset_slug("test/one"));
+        let mut asset_usage = AssetUsage::default();
+
+        asset_usage.update(vec![asset_id_0, asset_id_1, asset_id_0]);
+        asset_usage.update(vec![asset_id_1]);
+
+        assert_eq!(2, asset_usage.tick);
+        assert!(!asset_usage.in_use.contains(&asset_id_0));
+        assert!(asset_usage.in_use.contains(&asset_id_1));
+        assert_eq!(1, asset_usage.ticks_unused(asset_id_0));
+        assert_eq!(0, asset_usage.ticks_unused(asset_id_1));
+    }
+
+    #[test]
+    fn ticks_unused_counts_from_zero_for_unused_assets() {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let asset_id = asset_id_mappings.insert(asset_slug("test/zero"));
+        let mut asset_usage = AssetUsage::default();
+
+        asset_usage.update(None);
+        asset_usage.update(None);
+
+        assert_eq!(2, asset_usage.ticks_unused(asset_id));
+    }
+
+    fn asset_slug(slug: &str) -> AssetSlug {
+        slug.parse().expect("Expected asset slug to be valid.")
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use loading_model::{
+        loaded::LoadStage,
+        play::{LoadingProgress, StageProgress},
+    };
+
+    #[test]
+    fn update_stages_counts_assets_past_each_stage() {
+        let mut loading_progress = LoadingProgress::default();
+
+        loading_progress.update_stages(vec![
+            LoadStage::New,
+            LoadStage::IdMapping,
+            LoadStage::Complete,
+        ]);
+
+        assert_eq!(
+            Some(&StageProgress::new(2, 3)),
+            loading_progress
+                .stages
+                .get(&LoadStage::AssetDefinitionLoading)
+        );
+        assert_eq!(
+            Some(&StageProgress::new(1, 3)),
+            loading_progress.stages.get(&LoadStage::IdMapping)
+        );
+        assert_eq!(StageProgress::new(1, 3), loading_progress.assets());
+    }
+
+    #[test]
+    fn update_stages_does_not_count_deferred_assets() {
+        let mut loading_progress = LoadingProgress::default();
+
+        loading_progress.update_stages(vec![LoadStage::Deferred, LoadStage::Complete]);
+
+        assert_eq!(StageProgress::new(1, 1), loading_progress.assets());
+        assert!((loading_progress.fraction() - 1.).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fraction_is_stages_done_over_stages_total() {
+        let mut loading_progress = LoadingProgress::default();
+
+        // 5 loading stages for each of 2 assets, of which 1 + 5 are done.
+        loading_progress.update_stages(vec![LoadStage::IdMapping, LoadStage::Complete]);
+
+        assert!((loading_progress.fraction() - 0.6).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fraction_is_one_when_there_are_no_assets() {
+        let loading_progress = LoadingProgress::default();
+
+        assert!((loading_progress.fraction() - 1.).abs() < f32::EPSILON);
+    }
+}
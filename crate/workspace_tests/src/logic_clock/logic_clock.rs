@@ -36,6 +36,17 @@ mod tests {
         assert!(!logic_clock.is_ongoing());
     }
 
+    #[test]
+    fn remaining_is_ticks_until_limit() {
+        let mut logic_clock = LogicClock::new(3);
+        assert_eq!(3, logic_clock.remaining());
+
+        logic_clock.value = 2;
+        assert_eq!(1, logic_clock.remaining());
+        logic_clock.value = 3;
+        assert_eq!(0, logic_clock.remaining());
+    }
+
     #[test]
     fn tick_increments_value_by_one() {
         let mut logic_clock = LogicClock::new(3);
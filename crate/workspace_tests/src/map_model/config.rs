@@ -1 +1,2 @@
 mod map_definition;
+mod spawn_points;
@@ -7,7 +7,10 @@ mod test {
     use serde_yaml;
     use sprite_model::config::{SpriteFrame, SpriteItem, SpriteRef, SpriteSequence};
 
-    use map_model::config::{MapBounds, MapDefinition, MapHeader};
+    use map_model::config::{
+        MapBounds, MapDefinition, MapHeader, Platform, Platforms, SpawnPoint, SpawnPoints,
+    };
+    use team_model::play::TeamCounter;
 
     const MAP_NO_SPRITE_SEQUENCES: &str = r#"---
 header:
@@ -33,13 +36,88 @@ layers:
     frames: [{ wait: 1, sprite: { sheet: 0, index: 0 } }]
 "#;
 
+    const MAP_WITH_SPAWN_POINTS: &str = r#"---
+header:
+  name: "Map with spawn points"
+  bounds: { x: 1, y: 2, z: 3, width: 800, height: 600, depth: 200 }
+  spawn_points:
+    - position: { x: 200, y: 0, z: 100 }
+    - position: { x: 600, y: 0, z: 100 }
+      mirrored: true
+    - position: { x: 400, y: 0, z: 50 }
+      team: 1
+"#;
+
+    const MAP_WITH_PLATFORMS: &str = r#"---
+header:
+  name: "Map with platforms"
+  bounds: { x: 1, y: 2, z: 3, width: 800, height: 600, depth: 200 }
+  platforms:
+    - { x: 100, z: 3, width: 200, depth: 200, height: 50 }
+    - { x: 300, z: 3, width: 100, depth: 200, height: 50, height_right: 0 }
+    - { x: 500, z: 50, width: 100, depth: 100, height: 120, one_way: true }
+"#;
+
     #[test]
     fn deserialize_minimal_definition() {
         let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_NO_SPRITE_SEQUENCES)
             .expect("Failed to deserialize map definition.");
 
         let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
-        let header = MapHeader::new("Blank Map".to_string(), bounds);
+        let header = MapHeader::new(
+            "Blank Map".to_string(),
+            bounds,
+            SpawnPoints::default(),
+            Platforms::default(),
+        );
+        let expected = MapDefinition::new(header, BackgroundDefinition::default());
+
+        assert_eq!(expected, map_definition);
+    }
+
+    #[test]
+    fn deserialize_with_spawn_points() {
+        let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_WITH_SPAWN_POINTS)
+            .expect("Failed to deserialize map definition.");
+
+        let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
+        let spawn_points = SpawnPoints::new(vec![
+            SpawnPoint::new(PositionInit::new(200, 0, 100), false, None),
+            SpawnPoint::new(PositionInit::new(600, 0, 100), true, None),
+            SpawnPoint::new(
+                PositionInit::new(400, 0, 50),
+                false,
+                Some(TeamCounter::new(1)),
+            ),
+        ]);
+        let header = MapHeader::new(
+            "Map with spawn points".to_string(),
+            bounds,
+            spawn_points,
+            Platforms::default(),
+        );
+        let expected = MapDefinition::new(header, BackgroundDefinition::default());
+
+        assert_eq!(expected, map_definition);
+    }
+
+    #[test]
+    fn deserialize_with_platforms() {
+        let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_WITH_PLATFORMS)
+            .expect("Failed to deserialize map definition.");
+
+        let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
+        let platforms = Platforms::new(vec![
+            Platform::new(100, 3, 200, 200, 50, None, false),
+            Platform::new(300, 3, 100, 200, 50, Some(0), false),
+            Platform::new(500, 50, 100, 100, 120, None, true),
+        ]);
+        let header = MapHeader::new(
+            "Map with platforms".to_string(),
+            bounds,
+            SpawnPoints::default(),
+            platforms,
+        );
         let expected = MapDefinition::new(header, BackgroundDefinition::default());
 
         assert_eq!(expected, map_definition);
@@ -51,7 +129,12 @@ layers:
             .expect("Failed to deserialize map definition.");
 
         let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
-        let header = MapHeader::new("Map with sprite sequence".to_string(), bounds);
+        let header = MapHeader::new(
+            "Map with sprite sequence".to_string(),
+            bounds,
+            SpawnPoints::default(),
+            Platforms::default(),
+        );
         let layer_0 = SpriteItem::new(
             PositionInit::new(1, 4, 0),
             SpriteSequence::new(
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use kinematic_model::config::PositionInit;
+    use team_model::play::TeamCounter;
+
+    use map_model::config::{SpawnPoint, SpawnPoints};
+
+    #[test]
+    fn player_skips_team_spawn_points() {
+        let spawn_points = spawn_points();
+
+        assert_eq!(Some(&spawn_point(0, None)), spawn_points.player(0));
+        assert_eq!(Some(&spawn_point(2, None)), spawn_points.player(1));
+        assert_eq!(None, spawn_points.player(2));
+    }
+
+    #[test]
+    fn team_returns_spawn_points_for_that_team() {
+        let spawn_points = spawn_points();
+
+        assert_eq!(
+            Some(&spawn_point(1, Some(TeamCounter::new(1)))),
+            spawn_points.team(TeamCounter::new(1), 0)
+        );
+        assert_eq!(
+            Some(&spawn_point(4, Some(TeamCounter::new(1)))),
+            spawn_points.team(TeamCounter::new(1), 1)
+        );
+        assert_eq!(
+            Some(&spawn_point(3, Some(TeamCounter::new(2)))),
+            spawn_points.team(TeamCounter::new(2), 0)
+        );
+        assert_eq!(None, spawn_points.team(TeamCounter::new(2), 1));
+        assert_eq!(None, spawn_points.team(TeamCounter::new(3), 0));
+    }
+
+    fn spawn_points() -> SpawnPoints {
+        SpawnPoints::new(vec![
+            spawn_point(0, None),
+            spawn_point(1, Some(TeamCounter::new(1))),
+            spawn_point(2, None),
+            spawn_point(3, Some(TeamCounter::new(2))),
+            spawn_point(4, Some(TeamCounter::new(1))),
+        ])
+    }
+
+    fn spawn_point(x: i32, team: Option<TeamCounter>) -> SpawnPoint {
+        SpawnPoint::new(PositionInit::new(x, 0, 0), false, team)
+    }
+}
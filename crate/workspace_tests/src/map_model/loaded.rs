@@ -1 +1,2 @@
 mod margins;
+mod surfaces;
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use kinematic_model::config::FixedPoint;
     use map_model::{config::MapBounds, loaded::Margins};
 
     #[test]
@@ -7,12 +8,12 @@ mod tests {
         let map_bounds = MapBounds::new(1, 2, 3, 10, 20, 30);
         assert_eq!(
             Margins {
-                left: 1.,
-                right: 11.,
-                bottom: 35.,
-                top: 55.,
-                back: 3.,
-                front: 33.,
+                left: FixedPoint::from(1),
+                right: FixedPoint::from(11),
+                bottom: FixedPoint::from(35),
+                top: FixedPoint::from(55),
+                back: FixedPoint::from(3),
+                front: FixedPoint::from(33),
             },
             map_bounds.into()
         );
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use kinematic_model::config::{FixedPoint, Position};
+    use map_model::{
+        config::{MapBounds, Platform, Platforms},
+        loaded::{Margins, Surface, Surfaces},
+    };
+
+    #[test]
+    fn from_platforms_offsets_height_by_map_bottom() {
+        let margins = Margins::from(MapBounds::new(0, 0, 0, 800, 400, 200));
+        let platforms = Platforms::new(vec![Platform::new(100, 50, 200, 100, 40, Some(80), true)]);
+
+        assert_eq!(
+            Surfaces::new(vec![Surface::new(
+                FixedPoint::from(100),
+                FixedPoint::from(300),
+                FixedPoint::from(50),
+                FixedPoint::from(150),
+                FixedPoint::from(240),
+                FixedPoint::from(280),
+                true,
+            )]),
+            Surfaces::from_platforms(&margins, &platforms)
+        );
+    }
+
+    #[test]
+    fn from_platforms_offsets_position_by_map_origin() {
+        let margins = Margins::from(MapBounds::new(50, 10, 30, 800, 400, 200));
+        let platforms = Platforms::new(vec![Platform::new(100, 50, 200, 100, 40, None, false)]);
+
+        assert_eq!(
+            Surfaces::new(vec![Surface::new(
+                FixedPoint::from(150),
+                FixedPoint::from(350),
+                FixedPoint::from(80),
+                FixedPoint::from(180),
+                FixedPoint::from(280),
+                FixedPoint::from(280),
+                false,
+            )]),
+            Surfaces::from_platforms(&margins, &platforms)
+        );
+    }
+
+    #[test]
+    fn y_at_interpolates_ramps() {
+        let ramp = surface(0, 40, false);
+
+        assert_eq!(FixedPoint::ZERO, ramp.y_at(FixedPoint::from(-10)));
+        assert_eq!(FixedPoint::ZERO, ramp.y_at(FixedPoint::from(100)));
+        assert_eq!(FixedPoint::from(10), ramp.y_at(FixedPoint::from(150)));
+        assert_eq!(FixedPoint::from(40), ramp.y_at(FixedPoint::from(300)));
+        assert_eq!(FixedPoint::from(40), ramp.y_at(FixedPoint::from(310)));
+    }
+
+    #[test]
+    fn supports_object_falling_onto_surface() {
+        let surfaces = Surfaces::new(vec![surface(50, 50, false)]);
+
+        assert_eq!(
+            Some(FixedPoint::from(50)),
+            surfaces.supporting_y(position(150, 55, 100), position(150, 45, 100))
+        );
+    }
+
+    #[test]
+    fn does_not_support_object_from_below() {
+        let surfaces = Surfaces::new(vec![surface(50, 50, true)]);
+
+        assert_eq!(
+            None,
+            surfaces.supporting_y(position(150, 40, 100), position(150, 45, 100))
+        );
+    }
+
+    #[test]
+    fn does_not_support_object_beside_surface() {
+        let surfaces = Surfaces::new(vec![surface(50, 50, false)]);
+
+        assert_eq!(
+            None,
+            surfaces.supporting_y(position(350, 55, 100), position(350, 45, 100))
+        );
+        assert_eq!(
+            None,
+            surfaces.supporting_y(position(150, 55, 10), position(150, 45, 10))
+        );
+    }
+
+    #[test]
+    fn supporting_y_is_highest_supporting_surface() {
+        let surfaces = Surfaces::new(vec![surface(20, 20, false), surface(50, 50, false)]);
+
+        assert_eq!(
+            Some(FixedPoint::from(50)),
+            surfaces.supporting_y(position(150, 60, 100), position(150, 40, 100))
+        );
+    }
+
+    #[test]
+    fn stood_on_returns_surface_while_over_it() {
+        let ramp = surface(40, 0, false);
+        let surfaces = Surfaces::new(vec![ramp]);
+
+        assert_eq!(
+            Some(&ramp),
+            surfaces.stood_on(position(100, 40, 100), position(110, 40, 100))
+        );
+        assert_eq!(
+            None,
+            surfaces.stood_on(position(100, 45, 100), position(110, 45, 100))
+        );
+        assert_eq!(
+            None,
+            surfaces.stood_on(position(300, 0, 100), position(310, 0, 100))
+        );
+    }
+
+    #[test]
+    fn solid_surface_blocks_object_walking_into_side() {
+        let surfaces = Surfaces::new(vec![surface(50, 50, false)]);
+
+        assert!(surfaces.blocks(position(95, 0, 100), position(105, 0, 100)));
+        assert!(!surfaces.blocks(position(95, 60, 100), position(105, 60, 100)));
+    }
+
+    #[test]
+    fn one_way_surface_does_not_block_object() {
+        let surfaces = Surfaces::new(vec![surface(50, 50, true)]);
+
+        assert!(!surfaces.blocks(position(95, 0, 100), position(105, 0, 100)));
+    }
+
+    fn surface(y_left: i32, y_right: i32, one_way: bool) -> Surface {
+        Surface::new(
+            FixedPoint::from(100),
+            FixedPoint::from(300),
+            FixedPoint::from(50),
+            FixedPoint::from(150),
+            FixedPoint::from(y_left),
+            FixedPoint::from(y_right),
+            one_way,
+        )
+    }
+
+    fn position(x: i32, y: i32, z: i32) -> Position<FixedPoint> {
+        Position::new(
+            FixedPoint::from(x),
+            FixedPoint::from(y),
+            FixedPoint::from(z),
+        )
+    }
+}
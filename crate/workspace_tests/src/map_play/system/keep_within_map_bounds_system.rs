@@ -10,14 +10,15 @@ mod tests {
     use amethyst_test::AmethystApplication;
     use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
     use enumflags2::BitFlags;
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position};
     use map_loading::MapLoadingBundle;
     use map_model::{
-        config::MapBounds,
-        loaded::{AssetMargins, Margins},
+        config::{MapBounds, Platform, Platforms},
+        loaded::{AssetMargins, AssetSurfaces, Margins, Surfaces},
         play::{BoundaryFace, MapBoundaryEvent, MapBoundaryEventData, MapBounded},
     };
     use map_selection_model::MapSelection;
+    use tracker::Last;
 
     use map_play::KeepWithinMapBoundsSystem;
 
@@ -25,11 +26,20 @@ mod tests {
     fn does_not_change_position_when_no_map_boundary_event() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 300., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: None,
             },
             ExpectedParams {
-                position: Position::new(100., 300., 100.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -38,7 +48,12 @@ mod tests {
     fn does_not_change_position_on_enter_event() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 300., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces =
                         BoundaryFace::Left | BoundaryFace::Bottom | BoundaryFace::Back;
@@ -49,7 +64,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(100., 300., 100.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -58,7 +77,12 @@ mod tests {
     fn sets_x_to_left_margin_on_exit_event_left() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(-10., 300., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(-10),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BitFlags::from(BoundaryFace::Left);
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -68,7 +92,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(0., 300., 100.),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -77,7 +105,12 @@ mod tests {
     fn sets_x_to_right_margin_on_exit_event_right() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(810., 300., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(810),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BitFlags::from(BoundaryFace::Right);
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -87,7 +120,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(800., 300., 100.),
+                position: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(300),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -96,7 +133,12 @@ mod tests {
     fn sets_y_to_bottom_margin_on_exit_event_bottom() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 190., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(190),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BitFlags::from(BoundaryFace::Bottom);
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -106,7 +148,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(100., 200., 100.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(200),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -115,7 +161,12 @@ mod tests {
     fn sets_y_to_top_margin_on_exit_event_top() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 810., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(810),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BitFlags::from(BoundaryFace::Top);
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -125,7 +176,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(100., 800., 100.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(800),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -134,7 +189,12 @@ mod tests {
     fn sets_z_to_back_margin_on_exit_event_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 300., -10.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(-10),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BitFlags::from(BoundaryFace::Back);
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -144,7 +204,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(100., 300., 0.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::ZERO,
+                ),
             },
         )
     }
@@ -153,7 +217,12 @@ mod tests {
     fn sets_z_to_front_margin_on_exit_event_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 300., 210.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(210),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BitFlags::from(BoundaryFace::Front);
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -163,7 +232,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(100., 300., 200.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(300),
+                    FixedPoint::from(200),
+                ),
             },
         )
     }
@@ -172,7 +245,12 @@ mod tests {
     fn aligns_with_left_and_bottom_margins_on_exit_event_left_bottom() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(-10., 190., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(-10),
+                    FixedPoint::from(190),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BoundaryFace::Left | BoundaryFace::Bottom;
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -182,7 +260,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(0., 200., 100.),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -191,7 +273,12 @@ mod tests {
     fn aligns_with_right_and_top_margins_on_exit_event_right_top() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(810., 810., 100.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(810),
+                    FixedPoint::from(810),
+                    FixedPoint::from(100),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BoundaryFace::Right | BoundaryFace::Top;
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -201,7 +288,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(800., 800., 100.),
+                position: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(800),
+                    FixedPoint::from(100),
+                ),
             },
         )
     }
@@ -210,7 +301,12 @@ mod tests {
     fn aligns_with_bottom_and_back_margins_on_exit_event_bottom_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(100., 190., -10.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(190),
+                    FixedPoint::from(-10),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces = BoundaryFace::Bottom | BoundaryFace::Back;
                     MapBoundaryEvent::Exit(MapBoundaryEventData {
@@ -220,7 +316,11 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(100., 200., 0.),
+                position: Position::new(
+                    FixedPoint::from(100),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
         )
     }
@@ -229,7 +329,12 @@ mod tests {
     fn aligns_with_right_top_front_margins_on_exit_event_right_top_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position: Position::new(810., 810., 210.),
+                position_last: None,
+                position: Position::new(
+                    FixedPoint::from(810),
+                    FixedPoint::from(810),
+                    FixedPoint::from(210),
+                ),
                 map_boundary_event_fn: Some(|entity| {
                     let boundary_faces =
                         BoundaryFace::Right | BoundaryFace::Top | BoundaryFace::Front;
@@ -240,13 +345,110 @@ mod tests {
                 }),
             },
             ExpectedParams {
-                position: Position::new(800., 800., 200.),
+                position: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(800),
+                    FixedPoint::from(200),
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn places_entity_on_platform_when_landing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position_last: Some(position(450, 260, 50)),
+                position: position(450, 240, 50),
+                map_boundary_event_fn: None,
+            },
+            ExpectedParams {
+                position: position(450, 250, 50),
+            },
+        )
+    }
+
+    #[test]
+    fn places_entity_on_ramp_when_walking_uphill() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position_last: Some(position(700, 225, 100)),
+                position: position(690, 225, 100),
+                map_boundary_event_fn: None,
+            },
+            ExpectedParams {
+                position: Position::new(
+                    FixedPoint::from(690),
+                    FixedPoint::from(227.5),
+                    FixedPoint::from(100),
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn keeps_entity_on_ramp_when_walking_downhill() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position_last: Some(position(700, 225, 100)),
+                position: position(710, 225, 100),
+                map_boundary_event_fn: None,
+            },
+            ExpectedParams {
+                position: Position::new(
+                    FixedPoint::from(710),
+                    FixedPoint::from(222.5),
+                    FixedPoint::from(100),
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_change_position_when_jumping_from_ramp() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position_last: Some(position(700, 225, 100)),
+                position: position(710, 235, 100),
+                map_boundary_event_fn: None,
+            },
+            ExpectedParams {
+                position: position(710, 235, 100),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_change_position_when_walking_off_platform() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position_last: Some(position(450, 250, 100)),
+                position: position(450, 250, 105),
+                map_boundary_event_fn: None,
+            },
+            ExpectedParams {
+                position: position(450, 250, 105),
+            },
+        )
+    }
+
+    #[test]
+    fn blocks_entity_walking_into_solid_platform_side() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position_last: Some(position(395, 200, 50)),
+                position: position(405, 200, 50),
+                map_boundary_event_fn: None,
+            },
+            ExpectedParams {
+                position: position(395, 200, 50),
             },
         )
     }
 
     fn run_test(
         SetupParams {
+            position_last,
             position,
             map_boundary_event_fn,
         }: SetupParams,
@@ -264,11 +466,11 @@ mod tests {
             .with_setup(setup_system_data)
             .with_setup(setup_map_selection)
             .with_effect(move |world| {
-                let entity = world
-                    .create_entity()
-                    .with(position)
-                    .with(MapBounded)
-                    .build();
+                let mut entity_builder = world.create_entity().with(position).with(MapBounded);
+                if let Some(position_last) = position_last {
+                    entity_builder = entity_builder.with(Last(position_last));
+                }
+                let entity = entity_builder.build();
 
                 if let Some(map_boundary_event_fn) = map_boundary_event_fn {
                     let map_boundary_event = map_boundary_event_fn(entity);
@@ -282,11 +484,11 @@ mod tests {
             })
             .with_assertion(move |world| {
                 let entity = *world.read_resource::<Entity>();
-                let positions = world.read_storage::<Position<f32>>();
+                let positions = world.read_storage::<Position<FixedPoint>>();
                 let position_actual = positions
                     .get(entity)
                     .copied()
-                    .expect("Expected entity to have `Position<f32>` component.");
+                    .expect("Expected entity to have `Position<FixedPoint>` component.");
 
                 assert_eq!(position_expected, position_actual);
             })
@@ -302,14 +504,21 @@ mod tests {
         let map_selection = {
             let map_bounds = MapBounds::new(0, 0, 0, 800, 600, 200);
             let map_margins = Margins::from(map_bounds);
+            let platforms = Platforms::new(vec![
+                Platform::new(400, 0, 200, 100, 50, None, false),
+                Platform::new(600, 0, 200, 200, 50, Some(0), false),
+            ]);
+            let surfaces = Surfaces::from_platforms(&map_margins, &platforms);
 
             let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
             let mut asset_margins = world.write_resource::<AssetMargins>();
+            let mut asset_surfaces = world.write_resource::<AssetSurfaces>();
             let slug =
                 AssetSlug::from_str("test/empty_map").expect("Expected asset slug to be valid.");
 
             let asset_id = asset_id_mappings.insert(slug);
             asset_margins.insert(asset_id, map_margins);
+            asset_surfaces.insert(asset_id, surfaces);
 
             MapSelection::Id(asset_id)
         };
@@ -317,12 +526,21 @@ mod tests {
         world.insert(map_selection);
     }
 
+    fn position(x: i32, y: i32, z: i32) -> Position<FixedPoint> {
+        Position::new(
+            FixedPoint::from(x),
+            FixedPoint::from(y),
+            FixedPoint::from(z),
+        )
+    }
+
     struct SetupParams {
-        position: Position<f32>,
+        position_last: Option<Position<FixedPoint>>,
+        position: Position<FixedPoint>,
         map_boundary_event_fn: Option<fn(Entity) -> MapBoundaryEvent>,
     }
 
     struct ExpectedParams {
-        position: Position<f32>,
+        position: Position<FixedPoint>,
     }
 }
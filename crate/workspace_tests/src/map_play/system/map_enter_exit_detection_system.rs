@@ -10,7 +10,7 @@ mod tests {
     use amethyst_test::AmethystApplication;
     use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
     use enumflags2::BitFlags;
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position};
     use map_loading::MapLoadingBundle;
     use map_model::{
         config::MapBounds,
@@ -26,8 +26,12 @@ mod tests {
     fn does_not_send_event_when_remaining_in_map() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(0., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(200), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: None,
@@ -39,8 +43,16 @@ mod tests {
     fn does_not_send_event_when_remaining_out_of_map() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(-1., 200., 0.),
-                position: Position::new(-1., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: None,
@@ -52,8 +64,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_left() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(-1., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -71,8 +91,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_right() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(800., 200., 0.),
-                position: Position::new(801., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(801),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -90,8 +118,12 @@ mod tests {
     fn sends_exit_event_when_exiting_map_bottom() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(0., 199., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(199), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -109,8 +141,12 @@ mod tests {
     fn sends_exit_event_when_exiting_map_top() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 800., 0.),
-                position: Position::new(0., 801., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(800),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(801), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -128,8 +164,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(0., 200., -1.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(-1),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -147,8 +191,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 200.),
-                position: Position::new(0., 200., 201.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(200),
+                ),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(201),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -166,8 +218,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_left_bottom() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(-1., 199., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(199),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -185,8 +245,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_right_top() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(800., 800., 0.),
-                position: Position::new(801., 801., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(800),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(801),
+                    FixedPoint::from(801),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -204,8 +272,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_bottom_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(0., 199., -1.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(199),
+                    FixedPoint::from(-1),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -223,8 +299,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_top_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 800., 200.),
-                position: Position::new(0., 801., 201.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(800),
+                    FixedPoint::from(200),
+                ),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(801),
+                    FixedPoint::from(201),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -242,8 +326,16 @@ mod tests {
     fn sends_exit_event_when_exiting_map_left_bottom_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 0.),
-                position: Position::new(-1., 199., -1.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(199),
+                    FixedPoint::from(-1),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -262,8 +354,12 @@ mod tests {
     fn sends_enter_event_when_entering_map_left() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(-1., 200., 0.),
-                position: Position::new(0., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(200), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -281,8 +377,16 @@ mod tests {
     fn sends_enter_event_when_entering_map_right() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(801., 200., 0.),
-                position: Position::new(800., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(801),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -300,8 +404,12 @@ mod tests {
     fn sends_enter_event_when_entering_map_bottom() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 199., 0.),
-                position: Position::new(0., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(199),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(200), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -319,8 +427,12 @@ mod tests {
     fn sends_enter_event_when_entering_map_top() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 801., 0.),
-                position: Position::new(0., 800., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(801),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(800), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -338,8 +450,12 @@ mod tests {
     fn sends_enter_event_when_entering_map_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., -1.),
-                position: Position::new(0., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(-1),
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(200), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -357,8 +473,16 @@ mod tests {
     fn sends_enter_event_when_entering_map_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 200., 201.),
-                position: Position::new(0., 200., 200.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(201),
+                ),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(200),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -376,8 +500,12 @@ mod tests {
     fn sends_enter_event_when_entering_map_left_top() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(-1., 801., 0.),
-                position: Position::new(0., 800., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(801),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(800), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -395,8 +523,16 @@ mod tests {
     fn sends_enter_event_when_entering_map_right_bottom() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(801., 199., 0.),
-                position: Position::new(800., 200., 0.),
+                position_last: Position::new(
+                    FixedPoint::from(801),
+                    FixedPoint::from(199),
+                    FixedPoint::ZERO,
+                ),
+                position: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -414,8 +550,16 @@ mod tests {
     fn sends_enter_event_when_entering_map_bottom_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 199., 201.),
-                position: Position::new(0., 200., 200.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(199),
+                    FixedPoint::from(201),
+                ),
+                position: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::from(200),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -433,8 +577,12 @@ mod tests {
     fn sends_enter_event_when_entering_map_top_back() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(0., 801., -1.),
-                position: Position::new(0., 800., 0.),
+                position_last: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(801),
+                    FixedPoint::from(-1),
+                ),
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(800), FixedPoint::ZERO),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -452,8 +600,16 @@ mod tests {
     fn sends_enter_event_when_entering_map_right_top_front() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_last: Position::new(801., 801., 201.),
-                position: Position::new(800., 800., 200.),
+                position_last: Position::new(
+                    FixedPoint::from(801),
+                    FixedPoint::from(801),
+                    FixedPoint::from(201),
+                ),
+                position: Position::new(
+                    FixedPoint::from(800),
+                    FixedPoint::from(800),
+                    FixedPoint::from(200),
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -550,8 +706,8 @@ mod tests {
     }
 
     struct SetupParams {
-        position_last: Position<f32>,
-        position: Position<f32>,
+        position_last: Position<FixedPoint>,
+        position: Position<FixedPoint>,
     }
 
     struct ExpectedParams {
@@ -11,7 +11,7 @@ mod tests {
     use application_test_support::AssetQueries;
     use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
     use enumflags2::BitFlags;
-    use kinematic_model::config::{Position, Velocity};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use map_loading::MapLoadingBundle;
     use map_model::{
         config::MapBounds,
@@ -28,7 +28,11 @@ mod tests {
     fn does_not_send_event_when_spawned_in_map_bounds() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_spawn: Position::new(0., 200., 0.),
+                position_spawn: Position::new(
+                    FixedPoint::ZERO,
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: None,
@@ -40,7 +44,11 @@ mod tests {
     fn sends_exit_event_when_spawned_out_of_map_bounds() -> Result<(), Error> {
         run_test(
             SetupParams {
-                position_spawn: Position::new(-1., 200., 0.),
+                position_spawn: Position::new(
+                    FixedPoint::from(-1),
+                    FixedPoint::from(200),
+                    FixedPoint::ZERO,
+                ),
             },
             ExpectedParams {
                 map_boundary_event_fn: Some(|entity| {
@@ -146,7 +154,7 @@ mod tests {
     }
 
     struct SetupParams {
-        position_spawn: Position<f32>,
+        position_spawn: Position<FixedPoint>,
     }
 
     struct ExpectedParams {
@@ -9,7 +9,7 @@ mod tests {
     };
     use derivative::Derivative;
     use kinematic_model::config::{
-        ObjectAcceleration, ObjectAccelerationKind, ObjectAccelerationValue,
+        FixedPoint, ObjectAcceleration, ObjectAccelerationKind, ObjectAccelerationValue,
         ObjectAccelerationValueExpr, ObjectAccelerationValueMultiplier, Position, Velocity,
     };
     use sequence_model::config::{
@@ -282,12 +282,12 @@ frames:
                 kind: ObjectAccelerationKind::Once,
                 x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                     multiplier: ObjectAccelerationValueMultiplier::One,
-                    value: 5.,
+                    value: FixedPoint::from(5),
                 }),
-                y: ObjectAccelerationValue::Const(2.5),
+                y: ObjectAccelerationValue::Const(FixedPoint::from(2.5)),
                 z: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                     multiplier: ObjectAccelerationValueMultiplier::ZAxis,
-                    value: 3.,
+                    value: FixedPoint::from(3),
                 }),
             }),
             ..Default::default()
@@ -301,12 +301,12 @@ frames:
                 kind: ObjectAccelerationKind::Continuous,
                 x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                     multiplier: ObjectAccelerationValueMultiplier::One,
-                    value: -5.,
+                    value: FixedPoint::from(-5),
                 }),
-                y: ObjectAccelerationValue::Const(-2.5),
+                y: ObjectAccelerationValue::Const(FixedPoint::from(-2.5)),
                 z: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                     multiplier: ObjectAccelerationValueMultiplier::ZAxis,
-                    value: -3.,
+                    value: FixedPoint::from(-3),
                 }),
             }),
         };
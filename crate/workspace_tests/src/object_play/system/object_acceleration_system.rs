@@ -10,10 +10,14 @@ mod tests {
     use amethyst_test::AmethystApplication;
     use game_input_model::play::ControllerInput;
     use kinematic_model::config::{
-        ObjectAcceleration, ObjectAccelerationKind, ObjectAccelerationValue,
+        FixedPoint, ObjectAcceleration, ObjectAccelerationKind, ObjectAccelerationValue,
         ObjectAccelerationValueExpr, ObjectAccelerationValueMultiplier, Velocity,
     };
     use mirrored_model::play::Mirrored;
+    use object_status_model::{
+        config::StatusEffectKind,
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffects},
+    };
     use sequence_model::{loaded::SequenceId, play::SequenceUpdateEvent};
 
     use object_play::ObjectAccelerationSystem;
@@ -22,22 +26,68 @@ mod tests {
     fn increases_velocity_for_continuous_acceleration() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Continuous,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
+        };
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
+
+        run_test(
+            SetupParams {
+                controller_input: None,
+                mirrored: None,
+                status_effects: None,
+                object_acceleration,
+                velocity,
+                sequence_update_event_fn: None,
+            },
+            ExpectedParams {
+                velocity: Velocity::new(
+                    FixedPoint::from(11),
+                    FixedPoint::from(22),
+                    FixedPoint::from(33),
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn scales_x_and_z_acceleration_by_speed_status_effects() -> Result<(), Error> {
+        let object_acceleration = ObjectAcceleration {
+            kind: ObjectAccelerationKind::Continuous,
+            x: ObjectAccelerationValue::Const(FixedPoint::from(4)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(4)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(4)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
+        let status_effects = StatusEffects::new(vec![StatusEffectEntry::new(
+            StatusEffectKind::SpeedDown,
+            StatusEffectClock::new(10),
+        )]);
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: None,
+                status_effects: Some(status_effects),
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(11., 22., 33.),
+                velocity: Velocity::new(
+                    FixedPoint::from(13),
+                    FixedPoint::from(24),
+                    FixedPoint::from(33),
+                ),
             },
         )
     }
@@ -46,22 +96,31 @@ mod tests {
     fn increases_velocity_for_once_acceleration_on_sequence_begin_event() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Once,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: None,
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: Some(sequence_begin_event),
             },
             ExpectedParams {
-                velocity: Velocity::new(11., 22., 33.),
+                velocity: Velocity::new(
+                    FixedPoint::from(11),
+                    FixedPoint::from(22),
+                    FixedPoint::from(33),
+                ),
             },
         )
     }
@@ -70,22 +129,31 @@ mod tests {
     fn increases_velocity_for_once_acceleration_on_frame_begin_event() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Once,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: None,
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: Some(frame_begin_event),
             },
             ExpectedParams {
-                velocity: Velocity::new(11., 22., 33.),
+                velocity: Velocity::new(
+                    FixedPoint::from(11),
+                    FixedPoint::from(22),
+                    FixedPoint::from(33),
+                ),
             },
         )
     }
@@ -95,16 +163,21 @@ mod tests {
     {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Once,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: None,
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: Some(sequence_end_event),
@@ -117,16 +190,21 @@ mod tests {
     fn does_not_increase_velocity_for_once_acceleration_when_no_event() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Once,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: None,
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
@@ -139,22 +217,31 @@ mod tests {
     fn does_not_negate_velocity_when_not_mirrored() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Continuous,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: Some(Mirrored::new(false)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(11., 22., 33.),
+                velocity: Velocity::new(
+                    FixedPoint::from(11),
+                    FixedPoint::from(22),
+                    FixedPoint::from(33),
+                ),
             },
         )
     }
@@ -163,22 +250,31 @@ mod tests {
     fn negates_x_velocity_when_mirrored() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Continuous,
-            x: ObjectAccelerationValue::Const(1.),
-            y: ObjectAccelerationValue::Const(2.),
-            z: ObjectAccelerationValue::Const(3.),
+            x: ObjectAccelerationValue::Const(FixedPoint::from(1)),
+            y: ObjectAccelerationValue::Const(FixedPoint::from(2)),
+            z: ObjectAccelerationValue::Const(FixedPoint::from(3)),
         };
-        let velocity = Velocity::new(10., 20., 30.);
+        let velocity = Velocity::new(
+            FixedPoint::from(10),
+            FixedPoint::from(20),
+            FixedPoint::from(30),
+        );
 
         run_test(
             SetupParams {
                 controller_input: None,
                 mirrored: Some(Mirrored::new(true)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(9., 22., 33.),
+                velocity: Velocity::new(
+                    FixedPoint::from(9),
+                    FixedPoint::from(22),
+                    FixedPoint::from(33),
+                ),
             },
         )
     }
@@ -190,12 +286,12 @@ mod tests {
             kind: ObjectAccelerationKind::Continuous,
             x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::XAxis,
-                value: 0.,
+                value: FixedPoint::ZERO,
             }),
-            y: ObjectAccelerationValue::Const(0.),
-            z: ObjectAccelerationValue::Const(0.),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            z: ObjectAccelerationValue::Const(FixedPoint::ZERO),
         };
-        let velocity = Velocity::new(10., 0., 0.);
+        let velocity = Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO);
         let controller_input = ControllerInput {
             x_axis_value: 0.,
             ..Default::default()
@@ -205,12 +301,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(false)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(10., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO),
             },
         )
     }
@@ -221,12 +318,12 @@ mod tests {
             kind: ObjectAccelerationKind::Continuous,
             x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::XAxis,
-                value: 0.5,
+                value: FixedPoint::from(0.5),
             }),
-            y: ObjectAccelerationValue::Const(0.),
-            z: ObjectAccelerationValue::Const(0.),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            z: ObjectAccelerationValue::Const(FixedPoint::ZERO),
         };
-        let velocity = Velocity::new(10., 0., 0.);
+        let velocity = Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO);
         let controller_input = ControllerInput {
             x_axis_value: 2.,
             ..Default::default()
@@ -236,12 +333,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(false)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(11., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(11), FixedPoint::ZERO, FixedPoint::ZERO),
             },
         )
     }
@@ -253,12 +351,12 @@ mod tests {
             kind: ObjectAccelerationKind::Continuous,
             x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::XAxis,
-                value: 0.5,
+                value: FixedPoint::from(0.5),
             }),
-            y: ObjectAccelerationValue::Const(0.),
-            z: ObjectAccelerationValue::Const(0.),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            z: ObjectAccelerationValue::Const(FixedPoint::ZERO),
         };
-        let velocity = Velocity::new(10., 0., 0.);
+        let velocity = Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO);
         let controller_input = ControllerInput {
             x_axis_value: 2.,
             ..Default::default()
@@ -268,12 +366,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(true)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(9., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(9), FixedPoint::ZERO, FixedPoint::ZERO),
             },
         )
     }
@@ -285,12 +384,12 @@ mod tests {
             kind: ObjectAccelerationKind::Continuous,
             x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::XAxis,
-                value: -0.5,
+                value: FixedPoint::from(-0.5),
             }),
-            y: ObjectAccelerationValue::Const(0.),
-            z: ObjectAccelerationValue::Const(0.),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            z: ObjectAccelerationValue::Const(FixedPoint::ZERO),
         };
-        let velocity = Velocity::new(10., 0., 0.);
+        let velocity = Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO);
         let controller_input = ControllerInput {
             x_axis_value: 2.,
             ..Default::default()
@@ -300,12 +399,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(true)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(11., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(11), FixedPoint::ZERO, FixedPoint::ZERO),
             },
         )
     }
@@ -317,12 +417,12 @@ mod tests {
             kind: ObjectAccelerationKind::Once,
             x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::XAxis,
-                value: 0.5,
+                value: FixedPoint::from(0.5),
             }),
-            y: ObjectAccelerationValue::Const(0.),
-            z: ObjectAccelerationValue::Const(0.),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            z: ObjectAccelerationValue::Const(FixedPoint::ZERO),
         };
-        let velocity = Velocity::new(10., 0., 0.);
+        let velocity = Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO);
         let controller_input = ControllerInput {
             x_axis_value: 2.,
             ..Default::default()
@@ -332,12 +432,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(true)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: Some(frame_begin_event),
             },
             ExpectedParams {
-                velocity: Velocity::new(9., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(9), FixedPoint::ZERO, FixedPoint::ZERO),
             },
         )
     }
@@ -349,12 +450,12 @@ mod tests {
             kind: ObjectAccelerationKind::Once,
             x: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::XAxis,
-                value: 0.5,
+                value: FixedPoint::from(0.5),
             }),
-            y: ObjectAccelerationValue::Const(0.),
-            z: ObjectAccelerationValue::Const(0.),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            z: ObjectAccelerationValue::Const(FixedPoint::ZERO),
         };
-        let velocity = Velocity::new(10., 0., 0.);
+        let velocity = Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO);
         let controller_input = ControllerInput {
             x_axis_value: 2.,
             ..Default::default()
@@ -364,12 +465,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(true)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(10., 0., 0.),
+                velocity: Velocity::new(FixedPoint::from(10), FixedPoint::ZERO, FixedPoint::ZERO),
             },
         )
     }
@@ -379,14 +481,14 @@ mod tests {
     ) -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Continuous,
-            x: ObjectAccelerationValue::Const(0.),
-            y: ObjectAccelerationValue::Const(0.),
+            x: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
             z: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::ZAxis,
-                value: 0.,
+                value: FixedPoint::ZERO,
             }),
         };
-        let velocity = Velocity::new(0., 0., 10.);
+        let velocity = Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(10));
         let controller_input = ControllerInput {
             z_axis_value: 0.,
             ..Default::default()
@@ -396,12 +498,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(false)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(0., 0., 10.),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(10)),
             },
         )
     }
@@ -410,14 +513,14 @@ mod tests {
     fn increases_velocity_for_z_expr_value_when_z_controller_input_valued() -> Result<(), Error> {
         let object_acceleration = ObjectAcceleration {
             kind: ObjectAccelerationKind::Continuous,
-            x: ObjectAccelerationValue::Const(0.),
-            y: ObjectAccelerationValue::Const(0.),
+            x: ObjectAccelerationValue::Const(FixedPoint::ZERO),
+            y: ObjectAccelerationValue::Const(FixedPoint::ZERO),
             z: ObjectAccelerationValue::Expr(ObjectAccelerationValueExpr {
                 multiplier: ObjectAccelerationValueMultiplier::ZAxis,
-                value: 0.5,
+                value: FixedPoint::from(0.5),
             }),
         };
-        let velocity = Velocity::new(0., 0., 10.);
+        let velocity = Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(10));
         let controller_input = ControllerInput {
             z_axis_value: 2.,
             ..Default::default()
@@ -427,12 +530,13 @@ mod tests {
             SetupParams {
                 controller_input: Some(controller_input),
                 mirrored: Some(Mirrored::new(false)),
+                status_effects: None,
                 object_acceleration,
                 velocity,
                 sequence_update_event_fn: None,
             },
             ExpectedParams {
-                velocity: Velocity::new(0., 0., 11.),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(11)),
             },
         )
     }
@@ -441,6 +545,7 @@ mod tests {
         SetupParams {
             controller_input,
             mirrored,
+            status_effects,
             object_acceleration,
             velocity: velocity_setup,
             sequence_update_event_fn,
@@ -468,6 +573,9 @@ mod tests {
                     if let Some(mirrored) = mirrored {
                         entity_builder = entity_builder.with(mirrored);
                     }
+                    if let Some(status_effects) = status_effects {
+                        entity_builder = entity_builder.with(status_effects);
+                    }
 
                     entity_builder.build()
                 };
@@ -483,11 +591,11 @@ mod tests {
             })
             .with_assertion(move |world| {
                 let entity = *world.read_resource::<Entity>();
-                let velocities = world.read_storage::<Velocity<f32>>();
+                let velocities = world.read_storage::<Velocity<FixedPoint>>();
                 let velocity_actual = velocities
                     .get(entity)
                     .copied()
-                    .expect("Expected entity to have `Velocity<f32>` component.");
+                    .expect("Expected entity to have `Velocity<FixedPoint>` component.");
 
                 assert_eq!(velocity_expected, velocity_actual);
             })
@@ -518,12 +626,13 @@ mod tests {
     struct SetupParams {
         controller_input: Option<ControllerInput>,
         mirrored: Option<Mirrored>,
+        status_effects: Option<StatusEffects>,
         object_acceleration: ObjectAcceleration,
-        velocity: Velocity<f32>,
+        velocity: Velocity<FixedPoint>,
         sequence_update_event_fn: Option<fn(Entity) -> SequenceUpdateEvent>,
     }
 
     struct ExpectedParams {
-        velocity: Velocity<f32>,
+        velocity: Velocity<FixedPoint>,
     }
 }
@@ -7,7 +7,7 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use kinematic_model::config::Velocity;
+    use kinematic_model::config::{FixedPoint, Velocity};
     use object_model::{config::Mass, play::Grounding};
 
     use object_play::ObjectGravitySystem;
@@ -17,11 +17,11 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::Airborne,
-                mass: Mass::new(2.),
-                velocity: Velocity::new(0., 10., 0.),
+                mass: Mass::new(FixedPoint::from(2)),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::from(10), FixedPoint::ZERO),
             },
             ExpectedParams {
-                velocity: Velocity::new(0., 8., 0.),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::from(8), FixedPoint::ZERO),
             },
         )
     }
@@ -31,11 +31,11 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                mass: Mass::new(2.),
-                velocity: Velocity::new(0., 10., 0.),
+                mass: Mass::new(FixedPoint::from(2)),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::from(10), FixedPoint::ZERO),
             },
             ExpectedParams {
-                velocity: Velocity::new(0., 10., 0.),
+                velocity: Velocity::new(FixedPoint::ZERO, FixedPoint::from(10), FixedPoint::ZERO),
             },
         )
     }
@@ -68,11 +68,11 @@ mod tests {
             })
             .with_assertion(move |world| {
                 let entity = *world.read_resource::<Entity>();
-                let velocities = world.read_storage::<Velocity<f32>>();
+                let velocities = world.read_storage::<Velocity<FixedPoint>>();
                 let velocity_actual = velocities
                     .get(entity)
                     .copied()
-                    .expect("Expected entity to have `Velocity<f32>` component.");
+                    .expect("Expected entity to have `Velocity<FixedPoint>` component.");
 
                 assert_eq!(velocity_expected, velocity_actual);
             })
@@ -82,10 +82,10 @@ mod tests {
     struct SetupParams {
         grounding: Grounding,
         mass: Mass,
-        velocity: Velocity<f32>,
+        velocity: Velocity<FixedPoint>,
     }
 
     struct ExpectedParams {
-        velocity: Velocity<f32>,
+        velocity: Velocity<FixedPoint>,
     }
 }
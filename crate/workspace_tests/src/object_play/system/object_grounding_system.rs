@@ -8,14 +8,15 @@ mod tests {
     };
     use amethyst_test::AmethystApplication;
     use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
-    use kinematic_model::config::Position;
+    use kinematic_model::config::{FixedPoint, Position};
     use map_loading::MapLoadingBundle;
     use map_model::{
-        config::MapBounds,
-        loaded::{AssetMargins, Margins},
+        config::{MapBounds, Platform, Platforms},
+        loaded::{AssetMargins, AssetSurfaces, Margins, Surfaces},
     };
     use map_selection_model::MapSelection;
     use object_model::play::Grounding;
+    use tracker::Last;
 
     use object_play::ObjectGroundingSystem;
 
@@ -24,7 +25,8 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::Airborne,
-                position: Position::new(0., 200., 0.),
+                position_last: None,
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(200), FixedPoint::ZERO),
             },
             ExpectedParams {
                 grounding: Grounding::OnGround,
@@ -37,7 +39,8 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                position: Position::new(0., 190., 0.),
+                position_last: None,
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(190), FixedPoint::ZERO),
             },
             ExpectedParams {
                 grounding: Grounding::Underground,
@@ -50,7 +53,64 @@ mod tests {
         run_test(
             SetupParams {
                 grounding: Grounding::OnGround,
-                position: Position::new(0., 210., 0.),
+                position_last: None,
+                position: Position::new(FixedPoint::ZERO, FixedPoint::from(210), FixedPoint::ZERO),
+            },
+            ExpectedParams {
+                grounding: Grounding::Airborne,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_on_ground_when_on_platform() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::Airborne,
+                position_last: Some(position(150, 260, 100)),
+                position: position(150, 250, 100),
+            },
+            ExpectedParams {
+                grounding: Grounding::OnGround,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_underground_when_falling_through_platform() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::Airborne,
+                position_last: Some(position(150, 260, 100)),
+                position: position(150, 240, 100),
+            },
+            ExpectedParams {
+                grounding: Grounding::Underground,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_airborne_when_walking_off_platform() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::OnGround,
+                position_last: Some(position(300, 250, 100)),
+                position: position(305, 250, 100),
+            },
+            ExpectedParams {
+                grounding: Grounding::Airborne,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_airborne_when_jumping_up_through_one_way_platform() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::Airborne,
+                position_last: Some(position(550, 240, 100)),
+                position: position(550, 245, 100),
             },
             ExpectedParams {
                 grounding: Grounding::Airborne,
@@ -61,6 +121,7 @@ mod tests {
     fn run_test(
         SetupParams {
             grounding,
+            position_last,
             position,
         }: SetupParams,
         ExpectedParams {
@@ -72,7 +133,11 @@ mod tests {
             .with_setup(setup_system_data)
             .with_setup(setup_map_selection)
             .with_effect(move |world| {
-                let entity = world.create_entity().with(grounding).with(position).build();
+                let mut entity_builder = world.create_entity().with(grounding).with(position);
+                if let Some(position_last) = position_last {
+                    entity_builder = entity_builder.with(Last(position_last));
+                }
+                let entity = entity_builder.build();
 
                 world.insert(entity);
             })
@@ -103,14 +168,21 @@ mod tests {
         let map_selection = {
             let map_bounds = MapBounds::new(0, 0, 0, 800, 600, 200);
             let map_margins = Margins::from(map_bounds);
+            let platforms = Platforms::new(vec![
+                Platform::new(100, 0, 200, 200, 50, None, false),
+                Platform::new(500, 0, 100, 200, 50, None, true),
+            ]);
+            let surfaces = Surfaces::from_platforms(&map_margins, &platforms);
 
             let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
             let mut asset_margins = world.write_resource::<AssetMargins>();
+            let mut asset_surfaces = world.write_resource::<AssetSurfaces>();
             let slug =
                 AssetSlug::from_str("test/empty_map").expect("Expected asset slug to be valid.");
 
             let asset_id = asset_id_mappings.insert(slug);
             asset_margins.insert(asset_id, map_margins);
+            asset_surfaces.insert(asset_id, surfaces);
 
             MapSelection::Id(asset_id)
         };
@@ -118,9 +190,18 @@ mod tests {
         world.insert(map_selection);
     }
 
+    fn position(x: i32, y: i32, z: i32) -> Position<FixedPoint> {
+        Position::new(
+            FixedPoint::from(x),
+            FixedPoint::from(y),
+            FixedPoint::from(z),
+        )
+    }
+
     struct SetupParams {
         grounding: Grounding,
-        position: Position<f32>,
+        position_last: Option<Position<FixedPoint>>,
+        position: Position<FixedPoint>,
     }
 
     struct ExpectedParams {
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use serde_yaml;
+
+    use object_status_model::config::{StatusEffect, StatusEffectKind, StatusEffectStacking};
+
+    const STATUS_EFFECT_YAML: &str = "kind: poison\nduration: 60\nstacking: stack\n";
+    const STATUS_EFFECT_DEFAULT_STACKING_YAML: &str = "kind: speed_up\nduration: 30\n";
+
+    #[test]
+    fn deserialize_status_effect() {
+        let status_effect = serde_yaml::from_str::<StatusEffect>(STATUS_EFFECT_YAML)
+            .expect("Failed to deserialize `StatusEffect`.");
+
+        let expected = StatusEffect {
+            kind: StatusEffectKind::Poison,
+            duration: 60,
+            stacking: StatusEffectStacking::Stack,
+        };
+        assert_eq!(expected, status_effect);
+    }
+
+    #[test]
+    fn stacking_defaults_to_refresh() {
+        let status_effect =
+            serde_yaml::from_str::<StatusEffect>(STATUS_EFFECT_DEFAULT_STACKING_YAML)
+                .expect("Failed to deserialize `StatusEffect`.");
+
+        assert_eq!(
+            StatusEffect::new(StatusEffectKind::SpeedUp, 30),
+            status_effect
+        );
+        assert_eq!(StatusEffectStacking::Refresh, status_effect.stacking);
+    }
+}
@@ -0,0 +1 @@
+mod status_effects;
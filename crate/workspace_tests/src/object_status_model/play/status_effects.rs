@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use object_status_model::{
+        config::{StatusEffect, StatusEffectKind, StatusEffectStacking, STATUS_EFFECT_STACKS_MAX},
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffects},
+    };
+
+    #[test]
+    fn apply_adds_entry_when_kind_is_not_present() {
+        let mut status_effects = StatusEffects::default();
+
+        status_effects.apply(StatusEffect::new(StatusEffectKind::Burn, 10));
+
+        assert_eq!(
+            StatusEffects::new(vec![StatusEffectEntry::new(
+                StatusEffectKind::Burn,
+                StatusEffectClock::new(10)
+            )]),
+            status_effects
+        );
+    }
+
+    #[test]
+    fn apply_refresh_resets_duration() {
+        let status_effects = applied_to_existing(StatusEffectStacking::Refresh);
+
+        assert_eq!(
+            Some(&entry(StatusEffectClock::new(20), 1)),
+            status_effects.get(StatusEffectKind::Poison)
+        );
+    }
+
+    #[test]
+    fn apply_extend_adds_to_remaining_duration() {
+        let status_effects = applied_to_existing(StatusEffectStacking::Extend);
+
+        assert_eq!(
+            Some(&entry(StatusEffectClock::new_with_value(30, 6), 1)),
+            status_effects.get(StatusEffectKind::Poison)
+        );
+    }
+
+    #[test]
+    fn apply_stack_adds_stack_and_resets_duration() {
+        let status_effects = applied_to_existing(StatusEffectStacking::Stack);
+
+        assert_eq!(
+            Some(&entry(StatusEffectClock::new(20), 2)),
+            status_effects.get(StatusEffectKind::Poison)
+        );
+    }
+
+    #[test]
+    fn apply_stack_does_not_exceed_maximum_stacks() {
+        let mut status_effects = StatusEffects::default();
+        let status_effect = StatusEffect {
+            kind: StatusEffectKind::Poison,
+            duration: 20,
+            stacking: StatusEffectStacking::Stack,
+        };
+
+        (0..STATUS_EFFECT_STACKS_MAX + 2).for_each(|_| status_effects.apply(status_effect));
+
+        assert_eq!(
+            STATUS_EFFECT_STACKS_MAX,
+            status_effects.stacks(StatusEffectKind::Poison)
+        );
+    }
+
+    #[test]
+    fn apply_ignore_keeps_existing_effect() {
+        let status_effects = applied_to_existing(StatusEffectStacking::Ignore);
+
+        assert_eq!(
+            Some(&entry(StatusEffectClock::new_with_value(10, 6), 1)),
+            status_effects.get(StatusEffectKind::Poison)
+        );
+    }
+
+    #[test]
+    fn speed_and_damage_percent_scale_with_stacks() {
+        let status_effects = StatusEffects::new(vec![
+            StatusEffectEntry {
+                kind: StatusEffectKind::SpeedUp,
+                clock: StatusEffectClock::new(10),
+                stacks: 2,
+            },
+            StatusEffectEntry::new(StatusEffectKind::DamageDown, StatusEffectClock::new(10)),
+        ]);
+
+        assert_eq!(150, status_effects.speed_percent());
+        assert_eq!(75, status_effects.damage_percent());
+        assert_eq!(100, StatusEffects::default().speed_percent());
+    }
+
+    fn applied_to_existing(stacking: StatusEffectStacking) -> StatusEffects {
+        let mut status_effects =
+            StatusEffects::new(vec![entry(StatusEffectClock::new_with_value(10, 6), 1)]);
+
+        status_effects.apply(StatusEffect {
+            kind: StatusEffectKind::Poison,
+            duration: 20,
+            stacking,
+        });
+
+        status_effects
+    }
+
+    fn entry(clock: StatusEffectClock, stacks: u32) -> StatusEffectEntry {
+        StatusEffectEntry {
+            kind: StatusEffectKind::Poison,
+            clock,
+            stacks,
+        }
+    }
+}
@@ -1 +1,4 @@
+mod status_effect_apply_system;
+mod status_effect_hit_system;
+mod status_effect_tick_system;
 mod stun_points_reduction_system;
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use object_status_model::{
+        config::{StatusEffect, StatusEffectKind},
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffectEvent, StatusEffects},
+    };
+    use pretty_assertions::assert_eq;
+    use sequence_model::play::FrameFreezeClock;
+
+    use object_status_play::StatusEffectApplySystem;
+
+    #[test]
+    fn inserts_status_effects_for_entity() -> Result<(), Error> {
+        run_test(
+            StatusEffectKind::Poison,
+            |status_effects, frame_freeze_clock| {
+                assert_eq!(
+                    Some(&StatusEffects::new(vec![StatusEffectEntry::new(
+                        StatusEffectKind::Poison,
+                        StatusEffectClock::new(5)
+                    )])),
+                    status_effects
+                );
+                assert_eq!(None, frame_freeze_clock);
+            },
+        )
+    }
+
+    #[test]
+    fn inserts_frame_freeze_clock_for_freeze() -> Result<(), Error> {
+        run_test(
+            StatusEffectKind::Freeze,
+            |status_effects, frame_freeze_clock| {
+                assert!(status_effects
+                    .map(|status_effects| status_effects.contains(StatusEffectKind::Freeze))
+                    .unwrap_or(false));
+                assert_eq!(Some(&FrameFreezeClock::new(5)), frame_freeze_clock);
+            },
+        )
+    }
+
+    #[test]
+    fn keeps_existing_frame_freeze_clock_that_lasts_longer() -> Result<(), Error> {
+        run_test_with_freeze(
+            StatusEffectKind::Freeze,
+            Some(FrameFreezeClock::new(10)),
+            |_status_effects, frame_freeze_clock| {
+                assert_eq!(Some(&FrameFreezeClock::new(10)), frame_freeze_clock);
+            },
+        )
+    }
+
+    fn run_test(
+        kind: StatusEffectKind,
+        assertion_fn: fn(Option<&StatusEffects>, Option<&FrameFreezeClock>),
+    ) -> Result<(), Error> {
+        run_test_with_freeze(kind, None, assertion_fn)
+    }
+
+    fn run_test_with_freeze(
+        kind: StatusEffectKind,
+        frame_freeze_clock: Option<FrameFreezeClock>,
+        assertion_fn: fn(Option<&StatusEffects>, Option<&FrameFreezeClock>),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                StatusEffectApplySystem::new(),
+                any::type_name::<StatusEffectApplySystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let entity = world.create_entity().build();
+                if let Some(frame_freeze_clock) = frame_freeze_clock {
+                    world
+                        .write_storage::<FrameFreezeClock>()
+                        .insert(entity, frame_freeze_clock)
+                        .expect("Failed to insert `FrameFreezeClock` component.");
+                }
+
+                world
+                    .write_resource::<EventChannel<StatusEffectEvent>>()
+                    .single_write(StatusEffectEvent::new(entity, StatusEffect::new(kind, 5)));
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let status_effectses = world.read_storage::<StatusEffects>();
+                let frame_freeze_clocks = world.read_storage::<FrameFreezeClock>();
+
+                assertion_fn(
+                    status_effectses.get(entity),
+                    frame_freeze_clocks.get(entity),
+                );
+            })
+            .run()
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use object_status_model::{
+        config::{StatusEffect, StatusEffectKind},
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffectEvent, StatusEffects},
+    };
+    use pretty_assertions::assert_eq;
+    use shape_model::Volume;
+
+    use object_status_play::StatusEffectHitSystem;
+
+    #[test]
+    fn sends_status_effect_event_for_hit_with_status_effect() -> Result<(), Error> {
+        run_test(None, |entity_to| {
+            vec![StatusEffectEvent::new(entity_to, status_effect())]
+        })
+    }
+
+    #[test]
+    fn does_not_send_status_effect_event_for_invulnerable_object() -> Result<(), Error> {
+        run_test(
+            Some(StatusEffects::new(vec![StatusEffectEntry::new(
+                StatusEffectKind::Invulnerable,
+                StatusEffectClock::new(10),
+            )])),
+            |_| vec![],
+        )
+    }
+
+    fn run_test(
+        status_effects: Option<StatusEffects>,
+        status_effect_events_expected_fn: fn(Entity) -> Vec<StatusEffectEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                StatusEffectHitSystem::new(),
+                any::type_name::<StatusEffectHitSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let status_effect_event_rid = world
+                    .write_resource::<EventChannel<StatusEffectEvent>>()
+                    .register_reader(); // kcov-ignore
+                world.insert(status_effect_event_rid);
+
+                let entity_from = world.create_entity().build();
+                let mut entity_to_builder = world.create_entity();
+                if let Some(status_effects) = status_effects.clone() {
+                    entity_to_builder = entity_to_builder.with(status_effects);
+                }
+                let entity_to = entity_to_builder.build();
+
+                world
+                    .write_resource::<EventChannel<HitEvent>>()
+                    .single_write(HitEvent::new(entity_from, entity_to, interaction(), body()));
+
+                world.insert(entity_to);
+            })
+            .with_assertion(move |world| {
+                let entity_to = *world.read_resource::<Entity>();
+                let status_effect_events_expected = status_effect_events_expected_fn(entity_to);
+
+                assert_events(world, status_effect_events_expected);
+            })
+            .run()
+    }
+
+    fn assert_events(world: &mut World, status_effect_events_expected: Vec<StatusEffectEvent>) {
+        let status_effect_ec = world.read_resource::<EventChannel<StatusEffectEvent>>();
+        let mut status_effect_event_rid = world.write_resource::<ReaderId<StatusEffectEvent>>();
+        let status_effect_events = status_effect_ec
+            .read(&mut status_effect_event_rid)
+            .copied()
+            .collect::<Vec<StatusEffectEvent>>();
+
+        assert_eq!(status_effect_events_expected, status_effect_events);
+    }
+
+    fn status_effect() -> StatusEffect {
+        StatusEffect::new(StatusEffectKind::Burn, 30)
+    }
+
+    fn interaction() -> Interaction {
+        Interaction::new(
+            InteractionKind::Hit(Hit {
+                status_effect: Some(status_effect()),
+                ..Default::default()
+            }),
+            vec![],
+            true,
+        )
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+}
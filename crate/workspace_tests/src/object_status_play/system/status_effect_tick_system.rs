@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use object_model::play::HealthPoints;
+    use object_status_model::{
+        config::StatusEffectKind,
+        play::{StatusEffectClock, StatusEffectEntry, StatusEffects},
+    };
+    use pretty_assertions::assert_eq;
+
+    use object_status_play::{StatusEffectTickSystem, POISON_HP_DAMAGE, POISON_INTERVAL};
+
+    #[test]
+    fn reduces_health_points_on_poison_interval() -> Result<(), Error> {
+        run_test(
+            HealthPoints::new(50),
+            StatusEffectEntry {
+                kind: StatusEffectKind::Poison,
+                clock: StatusEffectClock::new_with_value(100, POISON_INTERVAL - 1),
+                stacks: 2,
+            },
+            HealthPoints::new(50 - POISON_HP_DAMAGE * 2),
+            Some(StatusEffectClock::new_with_value(100, POISON_INTERVAL)),
+        )
+    }
+
+    #[test]
+    fn does_not_reduce_health_points_below_one() -> Result<(), Error> {
+        run_test(
+            HealthPoints::new(1),
+            StatusEffectEntry::new(
+                StatusEffectKind::Poison,
+                StatusEffectClock::new_with_value(100, POISON_INTERVAL - 1),
+            ),
+            HealthPoints::new(1),
+            Some(StatusEffectClock::new_with_value(100, POISON_INTERVAL)),
+        )
+    }
+
+    #[test]
+    fn removes_effect_when_duration_ends() -> Result<(), Error> {
+        run_test(
+            HealthPoints::new(50),
+            StatusEffectEntry::new(
+                StatusEffectKind::SpeedUp,
+                StatusEffectClock::new_with_value(10, 9),
+            ),
+            HealthPoints::new(50),
+            None,
+        )
+    }
+
+    fn run_test(
+        health_points: HealthPoints,
+        status_effect_entry: StatusEffectEntry,
+        health_points_expected: HealthPoints,
+        clock_expected: Option<StatusEffectClock>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                StatusEffectTickSystem::new(),
+                any::type_name::<StatusEffectTickSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let entity = world
+                    .create_entity()
+                    .with(health_points)
+                    .with(StatusEffects::new(vec![status_effect_entry]))
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let health_pointses = world.read_storage::<HealthPoints>();
+                let status_effectses = world.read_storage::<StatusEffects>();
+
+                let clock = status_effectses
+                    .get(entity)
+                    .and_then(|status_effects| status_effects.get(status_effect_entry.kind))
+                    .map(|entry| entry.clock);
+                assert_eq!(Some(&health_points_expected), health_pointses.get(entity));
+                assert_eq!(clock_expected, clock);
+            })
+            .run()
+    }
+}
@@ -0,0 +1 @@
+mod pause_menu_trans;
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod test {
+    use amethyst::{
+        ecs::{World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        GameData, State, Trans,
+    };
+    use application_event::AppEvent;
+    use debug_util_amethyst::assert_eq_trans;
+    use game_play_model::GamePlayEvent;
+    use pause_menu_model::PauseMenuIndex;
+    use pretty_assertions::assert_eq;
+
+    use pause_menu::PauseMenuTrans;
+
+    #[test]
+    fn trans_returns_pop_for_resume() {
+        run_test(PauseMenuIndex::Resume, Trans::Pop, vec![]);
+    }
+
+    #[test]
+    fn trans_returns_pop_and_sends_restart_for_restart() {
+        run_test(
+            PauseMenuIndex::Restart,
+            Trans::Pop,
+            vec![GamePlayEvent::Restart],
+        );
+    }
+
+    #[test]
+    fn trans_returns_push_for_control_settings() {
+        run_test(
+            PauseMenuIndex::ControlSettings,
+            Trans::Push(Box::new(MockState)),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn trans_returns_pop_and_sends_return_for_return_to_menu() {
+        run_test(
+            PauseMenuIndex::ReturnToMenu,
+            Trans::Pop,
+            vec![GamePlayEvent::Return],
+        );
+    }
+
+    fn run_test(
+        pause_menu_index: PauseMenuIndex,
+        trans_expected: Trans<GameData<'static, 'static>, AppEvent>,
+        game_play_events_expected: Vec<GamePlayEvent>,
+    ) {
+        let mut world = World::new();
+        let mut reader_id = {
+            let mut game_play_ec = EventChannel::<GamePlayEvent>::new();
+            let reader_id = game_play_ec.register_reader();
+            world.insert(game_play_ec);
+            reader_id
+        };
+
+        let trans = PauseMenuTrans::trans(&world, pause_menu_index);
+
+        assert_eq_trans(&trans_expected, &trans);
+        assert_eq!(
+            game_play_events_expected,
+            game_play_events(&world, &mut reader_id)
+        );
+    }
+
+    fn game_play_events(
+        world: &World,
+        reader_id: &mut ReaderId<GamePlayEvent>,
+    ) -> Vec<GamePlayEvent> {
+        world
+            .read_resource::<EventChannel<GamePlayEvent>>()
+            .read(reader_id)
+            .copied()
+            .collect::<Vec<GamePlayEvent>>()
+    }
+
+    #[derive(Debug)]
+    struct MockState;
+    impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for MockState {}
+}
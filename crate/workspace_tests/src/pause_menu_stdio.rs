@@ -0,0 +1,2 @@
+mod pause_menu_event_stdin_mapper;
+mod pause_menu_stdio_bundle;
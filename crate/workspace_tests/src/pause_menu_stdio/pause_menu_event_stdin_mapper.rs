@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use menu_model::MenuEvent;
+    use pause_menu_model::{PauseMenuEventArgs, PauseMenuIndex};
+    use stdio_spi::StdinMapper;
+
+    use pause_menu_stdio::PauseMenuEventStdinMapper;
+
+    #[test]
+    fn maps_select_event() {
+        let args = PauseMenuEventArgs::Select {
+            index: PauseMenuIndex::Resume,
+        };
+
+        let result = PauseMenuEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(MenuEvent::Select(PauseMenuIndex::Resume), result.unwrap())
+    }
+
+    #[test]
+    fn maps_close_event() {
+        let args = PauseMenuEventArgs::Close;
+
+        let result = PauseMenuEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(MenuEvent::Close, result.unwrap())
+    }
+}
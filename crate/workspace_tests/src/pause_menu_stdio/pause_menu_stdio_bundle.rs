@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod test {
+    use amethyst::{ecs::WorldExt, shrev::EventChannel, Error};
+    use amethyst_test::AmethystApplication;
+    use stdio_spi::VariantAndTokens;
+
+    use pause_menu_stdio::PauseMenuStdioBundle;
+
+    #[test]
+    fn bundle_should_add_mapper_system_to_dispatcher() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(PauseMenuStdioBundle::new())
+            // kcov-ignore-start
+            .with_effect(|world| {
+                world.read_resource::<EventChannel<VariantAndTokens>>();
+            })
+            // kcov-ignore-end
+            .run()
+    }
+}
@@ -1,5 +1,6 @@
 mod frame_component_update_system;
 mod sequence_component_update_system;
 mod sequence_end_transition_system;
+mod sequence_reload_system;
 mod sequence_status_update_system;
 mod sequence_update_system;
@@ -43,6 +43,20 @@ mod tests {
         )
     }
 
+    #[test]
+    fn updates_frame_component_on_sequence_reload_event() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                // Third frame in the sequence.
+                frame_index_clock: FrameIndexClock::new_with_value(5, 2),
+                frame_wait_clock: FrameWaitClock::new_with_value(5, 0),
+                attach_frame_component_data_handle: true,
+                sequence_update_events_fn: sequence_reload_events,
+            },
+            Wait::new(2),
+        )
+    }
+
     #[test]
     fn does_not_panic_when_entity_does_not_have_frame_component_data_handle() -> Result<(), Error> {
         run_test(
@@ -142,6 +156,15 @@ mod tests {
         }]
     }
 
+    fn sequence_reload_events(world: &mut World) -> Vec<SequenceUpdateEvent> {
+        let entity = *world.read_resource::<Entity>();
+        vec![SequenceUpdateEvent::SequenceReload {
+            entity,
+            sequence_id: SequenceId(1),
+            frame_index: 2,
+        }]
+    }
+
     fn frame_begin_events(world: &mut World) -> Vec<SequenceUpdateEvent> {
         let entity = *world.read_resource::<Entity>();
         let frame_index = {
@@ -23,6 +23,11 @@ mod tests {
         run_test(sequence_begin_events, true, SEQUENCE_ID_CURRENT)
     }
 
+    #[test]
+    fn updates_sequence_component_on_sequence_reload_event() -> Result<(), Error> {
+        run_test(sequence_reload_events, true, SEQUENCE_ID_CURRENT)
+    }
+
     #[test]
     fn does_not_update_sequence_component_on_frame_begin_event() -> Result<(), Error> {
         run_test(frame_begin_events, true, SEQUENCE_ID_PREV)
@@ -123,6 +128,15 @@ mod tests {
         }]
     }
 
+    fn sequence_reload_events(world: &mut World) -> Vec<SequenceUpdateEvent> {
+        let entity = *world.read_resource::<Entity>();
+        vec![SequenceUpdateEvent::SequenceReload {
+            entity,
+            sequence_id: SEQUENCE_ID_CURRENT,
+            frame_index: 0,
+        }]
+    }
+
     fn frame_begin_events(world: &mut World) -> Vec<SequenceUpdateEvent> {
         let entity = *world.read_resource::<Entity>();
         vec![SequenceUpdateEvent::FrameBegin {
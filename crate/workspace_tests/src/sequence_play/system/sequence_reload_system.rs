@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::AssetStorage,
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use application_test_support::{AssetQueries, AutexousiousApplication, SequenceQueries};
+    use asset_model::{
+        loaded::{AssetItemIds, ItemId},
+        play::{AssetReloadEvent, AssetWorld},
+    };
+    use assets_test::CHAR_BAT_SLUG;
+    use sequence_model::{
+        loaded::{AssetSequenceIdRemaps, SequenceId, WaitSequence},
+        play::{FrameIndexClock, FrameWaitClock, SequenceUpdateEvent},
+    };
+
+    use sequence_play::SequenceReloadSystem;
+
+    const SEQUENCE_ID_CURRENT: SequenceId = SequenceId(1);
+    const SEQUENCE_ID_REMAPPED: SequenceId = SequenceId(2);
+    const SEQUENCE_ID_INVALID: SequenceId = SequenceId(1000);
+    const FRAME_INDEX_OUT_OF_RANGE: usize = 1000;
+
+    #[test]
+    fn sends_sequence_reload_event_with_frame_index_clamped() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_system(SequenceReloadSystem::new(), "", &[])
+            .with_effect(|world| initial_values(world, SEQUENCE_ID_CURRENT))
+            .with_effect(send_reload_event)
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let frame_count = wait_sequence_len(world, SEQUENCE_ID_CURRENT);
+                let frame_index = frame_count - 1;
+
+                let frame_index_clock = *world
+                    .read_storage::<FrameIndexClock>()
+                    .get(entity)
+                    .expect("Expected entity to have `FrameIndexClock` component.");
+                assert_eq!(frame_count, (*frame_index_clock).limit);
+                assert_eq!(frame_index, (*frame_index_clock).value);
+
+                assert_eq!(
+                    vec![SequenceUpdateEvent::SequenceReload {
+                        entity,
+                        sequence_id: SEQUENCE_ID_CURRENT,
+                        frame_index,
+                    }],
+                    read_events(world, entity)
+                );
+                assert_eq!(
+                    Some(SEQUENCE_ID_CURRENT),
+                    world.read_storage::<SequenceId>().get(entity).copied()
+                );
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn remaps_sequence_id_when_sequence_id_changes() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_system(SequenceReloadSystem::new(), "", &[])
+            .with_effect(|world| initial_values(world, SEQUENCE_ID_CURRENT))
+            .with_effect(|world| {
+                let asset_id = AssetQueries::id(world, &*CHAR_BAT_SLUG);
+                let sequence_id_remap = vec![(SEQUENCE_ID_CURRENT, SEQUENCE_ID_REMAPPED)]
+                    .into_iter()
+                    .collect();
+                world
+                    .write_resource::<AssetSequenceIdRemaps>()
+                    .insert(asset_id, sequence_id_remap);
+
+                send_reload_event(world);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let frame_index = wait_sequence_len(world, SEQUENCE_ID_REMAPPED) - 1;
+
+                assert_eq!(
+                    vec![SequenceUpdateEvent::SequenceReload {
+                        entity,
+                        sequence_id: SEQUENCE_ID_REMAPPED,
+                        frame_index,
+                    }],
+                    read_events(world, entity)
+                );
+                assert_eq!(
+                    Some(SEQUENCE_ID_REMAPPED),
+                    world.read_storage::<SequenceId>().get(entity).copied()
+                );
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn resets_sequence_id_when_remapped_sequence_no_longer_exists() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_system(SequenceReloadSystem::new(), "", &[])
+            .with_effect(|world| initial_values(world, SEQUENCE_ID_CURRENT))
+            .with_effect(|world| {
+                let asset_id = AssetQueries::id(world, &*CHAR_BAT_SLUG);
+                world
+                    .write_resource::<AssetSequenceIdRemaps>()
+                    .insert(asset_id, Default::default());
+
+                send_reload_event(world);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let sequence_id_init = {
+                    let item_id = item_id(world);
+                    let asset_world = world.read_resource::<AssetWorld>();
+                    let sequence_ids = asset_world.read_storage::<SequenceId>();
+                    sequence_ids.get(item_id.0).copied()
+                };
+
+                assert_eq!(
+                    Vec::<SequenceUpdateEvent>::new(),
+                    read_events(world, entity)
+                );
+                assert_eq!(
+                    sequence_id_init,
+                    world.read_storage::<SequenceId>().get(entity).copied()
+                );
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn resets_sequence_id_when_sequence_no_longer_exists() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_system(SequenceReloadSystem::new(), "", &[])
+            .with_effect(|world| initial_values(world, SEQUENCE_ID_INVALID))
+            .with_effect(send_reload_event)
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let sequence_id_init = {
+                    let item_id = item_id(world);
+                    let asset_world = world.read_resource::<AssetWorld>();
+                    let sequence_ids = asset_world.read_storage::<SequenceId>();
+                    sequence_ids.get(item_id.0).copied()
+                };
+
+                assert_eq!(
+                    Vec::<SequenceUpdateEvent>::new(),
+                    read_events(world, entity)
+                );
+                assert_eq!(
+                    sequence_id_init,
+                    world.read_storage::<SequenceId>().get(entity).copied()
+                );
+            })
+            .run_winit_loop()
+    }
+
+    fn initial_values(world: &mut World, sequence_id: SequenceId) {
+        let item_id = item_id(world);
+        let entity = world
+            .create_entity()
+            .with(item_id)
+            .with(sequence_id)
+            .with(FrameIndexClock::new_with_value(1, FRAME_INDEX_OUT_OF_RANGE))
+            .with(FrameWaitClock::new(1))
+            .build();
+        world.insert(entity);
+
+        let reader_id = world
+            .write_resource::<EventChannel<SequenceUpdateEvent>>()
+            .register_reader();
+        world.insert(reader_id);
+    }
+
+    fn send_reload_event(world: &mut World) {
+        let asset_id = AssetQueries::id(world, &*CHAR_BAT_SLUG);
+        world
+            .write_resource::<EventChannel<AssetReloadEvent>>()
+            .single_write(AssetReloadEvent::Complete { asset_id });
+    }
+
+    fn item_id(world: &World) -> ItemId {
+        let asset_id = AssetQueries::id(world, &*CHAR_BAT_SLUG);
+        let asset_item_ids = world.read_resource::<AssetItemIds>();
+        asset_item_ids
+            .get(asset_id)
+            .and_then(|item_ids| item_ids.first().copied())
+            .expect("Expected `ItemId` to exist.")
+    }
+
+    fn wait_sequence_len(world: &World, sequence_id: SequenceId) -> usize {
+        let wait_sequence_handle =
+            SequenceQueries::wait_sequence_handle(world, &*CHAR_BAT_SLUG, sequence_id);
+        let wait_sequence_assets = world.read_resource::<AssetStorage<WaitSequence>>();
+        wait_sequence_assets
+            .get(&wait_sequence_handle)
+            .expect("Expected `WaitSequence` to be loaded.")
+            .len()
+    }
+
+    fn read_events(world: &World, entity: Entity) -> Vec<SequenceUpdateEvent> {
+        let mut reader_id = world.write_resource::<ReaderId<SequenceUpdateEvent>>();
+        world
+            .read_resource::<EventChannel<SequenceUpdateEvent>>()
+            .read(&mut reader_id)
+            .filter(|ev| ev.entity() == entity)
+            .copied()
+            .collect::<Vec<_>>()
+    }
+}
@@ -111,11 +111,7 @@ mod tests {
         // Filter events for the entity we care about.
         let events_actual = ec
             .read(&mut reader_id)
-            .filter(|ev| match ev {
-                SequenceUpdateEvent::SequenceBegin { entity, .. }
-                | SequenceUpdateEvent::FrameBegin { entity, .. }
-                | SequenceUpdateEvent::SequenceEnd { entity, .. } => target_entity == *entity,
-            })
+            .filter(|ev| target_entity == ev.entity())
             .collect::<Vec<_>>();
 
         assert_eq!(events_expected, events_actual)
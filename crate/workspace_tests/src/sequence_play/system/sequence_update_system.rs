@@ -12,7 +12,8 @@ mod tests {
         config::Wait,
         loaded::{WaitSequence, WaitSequenceHandle},
         play::{
-            FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequenceStatus, SequenceUpdateEvent,
+            FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequencePause, SequenceStatus,
+            SequenceUpdateEvent,
         },
     };
 
@@ -289,6 +290,40 @@ mod tests {
             .run_winit_loop()
     }
 
+    #[test]
+    fn does_not_tick_frame_clocks_when_sequence_paused() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_effect(setup_system_data)
+            .with_effect(|world| {
+                initial_values(
+                    world,
+                    FrameIndexClock::new_with_value(5, 0),
+                    FrameWaitClock::new_with_value(2, 1),
+                    Some(FrameFreezeClock::new_with_value(2, 0)),
+                    SequenceStatus::Ongoing,
+                )
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<SequencePause>()
+                    .insert(entity, SequencePause)
+                    .expect("Failed to insert `SequencePause` component.");
+            })
+            .with_system_single(SequenceUpdateSystem::new(), "", &[])
+            .with_assertion(|world| {
+                expect_values(
+                    world,
+                    FrameIndexClock::new_with_value(5, 0),
+                    FrameWaitClock::new_with_value(2, 1),
+                    Some(FrameFreezeClock::new_with_value(2, 0)),
+                    SequenceStatus::Ongoing,
+                )
+            })
+            .with_assertion(|world| expect_events(world, vec![]))
+            .run_winit_loop()
+    }
+
     fn setup_system_data(world: &mut World) {
         SequenceUpdateSystemData::setup(world);
         let reader_id = {
@@ -401,11 +436,7 @@ mod tests {
         // Filter events for the entity we care about.
         let actual_events = ec
             .read(&mut reader_id)
-            .filter(|ev| match ev {
-                SequenceUpdateEvent::SequenceBegin { entity, .. }
-                | SequenceUpdateEvent::FrameBegin { entity, .. }
-                | SequenceUpdateEvent::SequenceEnd { entity, .. } => target_entity == *entity,
-            })
+            .filter(|ev| target_entity == ev.entity())
             .collect::<Vec<_>>();
 
         assert_eq!(expect_events, actual_events)
@@ -3,11 +3,13 @@ mod test {
     use std::str::FromStr;
 
     use asset_model::config::AssetSlug;
-    use kinematic_model::config::{Position, Velocity};
+    use charge_model::config::ChargePoints;
+    use game_input_model::config::ControlAction;
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use serde::{Deserialize, Serialize};
     use serde_yaml;
 
-    use spawn_model::config::{Spawn, Spawns};
+    use spawn_model::config::{Spawn, SpawnCondition, SpawnJitter, SpawnSpread, Spawns};
 
     const SPAWNS_YAML: &str = r#"
 spawns:
@@ -18,6 +20,18 @@ spawns:
     sequence: "sequence_name_string"
 "#;
 
+    const SPAWNS_FAN_YAML: &str = r#"
+spawns:
+  - object: "default/fireball"
+    count: 3
+    spread: { angle: 0.25, position: { z: 10 } }
+    jitter: { velocity: { y: 2 } }
+    condition: { charge: 50 }
+    limit: 6
+  - object: "default/fireball"
+    condition: { hold: "special" }
+"#;
+
     #[derive(Debug, Deserialize, Serialize)]
     struct Config {
         spawns: Spawns,
@@ -49,4 +63,38 @@ spawns:
             spawns
         );
     }
+
+    #[test]
+    fn deserialize_spawns_with_copies_and_conditions() {
+        let config = serde_yaml::from_str::<Config>(SPAWNS_FAN_YAML)
+            .expect("Failed to deserialize `Spawns`.");
+        let spawns = config.spawns;
+
+        let asset_slug = AssetSlug::from_str("default/fireball")
+            .expect("Expected `default/fireball` to be a valid asset slug.");
+        let mut spawn_fan = Spawn::new(
+            asset_slug.clone(),
+            Position::<i32>::from((0, 0, 0)),
+            Velocity::<i32>::from((0, 0, 0)),
+            None,
+        );
+        spawn_fan.count = 3;
+        spawn_fan.spread =
+            SpawnSpread::new(FixedPoint::from(0.25), Position::<i32>::from((0, 0, 10)));
+        spawn_fan.jitter = SpawnJitter::new(
+            Position::<i32>::from((0, 0, 0)),
+            Velocity::<i32>::from((0, 2, 0)),
+        );
+        spawn_fan.condition = Some(SpawnCondition::Charge(ChargePoints(50)));
+        spawn_fan.limit = Some(6);
+        let mut spawn_hold = Spawn::new(
+            asset_slug,
+            Position::<i32>::from((0, 0, 0)),
+            Velocity::<i32>::from((0, 0, 0)),
+            None,
+        );
+        spawn_hold.condition = Some(SpawnCondition::Hold(ControlAction::Special));
+
+        assert_eq!(Spawns::new(vec![spawn_fan, spawn_hold]), spawns);
+    }
 }
@@ -9,7 +9,7 @@ mod tests {
     };
     use application_test_support::{AssetQueries, AutexousiousApplication};
     use assets_test::ENERGY_SQUARE_SLUG;
-    use kinematic_model::config::{Position, Velocity};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use mirrored_model::play::Mirrored;
     use sequence_model::loaded::SequenceId;
     use spawn_model::{
@@ -27,8 +27,16 @@ mod tests {
             |world| {
                 assert_spawn_values(
                     world,
-                    Position::<f32>::new(11., 22., 33.),
-                    Velocity::<f32>::new(44., 55., 66.),
+                    Position::<FixedPoint>::new(
+                        FixedPoint::from(11),
+                        FixedPoint::from(22),
+                        FixedPoint::from(33),
+                    ),
+                    Velocity::<FixedPoint>::new(
+                        FixedPoint::from(44),
+                        FixedPoint::from(55),
+                        FixedPoint::from(66),
+                    ),
                     Mirrored(false),
                     None,
                     SequenceId::new(0),
@@ -44,8 +52,16 @@ mod tests {
             |world| {
                 assert_spawn_values(
                     world,
-                    Position::<f32>::new(-9., 22., 33.),
-                    Velocity::<f32>::new(-36., 55., 66.),
+                    Position::<FixedPoint>::new(
+                        FixedPoint::from(-9),
+                        FixedPoint::from(22),
+                        FixedPoint::from(33),
+                    ),
+                    Velocity::<FixedPoint>::new(
+                        FixedPoint::from(-36),
+                        FixedPoint::from(55),
+                        FixedPoint::from(66),
+                    ),
                     Mirrored(true),
                     None,
                     SequenceId::new(0),
@@ -61,8 +77,16 @@ mod tests {
             |world| {
                 assert_spawn_values(
                     world,
-                    Position::<f32>::new(11., 22., 33.),
-                    Velocity::<f32>::new(44., 55., 66.),
+                    Position::<FixedPoint>::new(
+                        FixedPoint::from(11),
+                        FixedPoint::from(22),
+                        FixedPoint::from(33),
+                    ),
+                    Velocity::<FixedPoint>::new(
+                        FixedPoint::from(44),
+                        FixedPoint::from(55),
+                        FixedPoint::from(66),
+                    ),
                     Mirrored(false),
                     None,
                     SequenceId::new(2),
@@ -85,8 +109,16 @@ mod tests {
             |world| {
                 assert_spawn_values(
                     world,
-                    Position::<f32>::new(11., 22., 33.),
-                    Velocity::<f32>::new(44., 55., 66.),
+                    Position::<FixedPoint>::new(
+                        FixedPoint::from(11),
+                        FixedPoint::from(22),
+                        FixedPoint::from(33),
+                    ),
+                    Velocity::<FixedPoint>::new(
+                        FixedPoint::from(44),
+                        FixedPoint::from(55),
+                        FixedPoint::from(66),
+                    ),
                     Mirrored(false),
                     Some(Team::Independent(IndependentCounter::new(123))),
                     SequenceId::new(0),
@@ -113,8 +145,16 @@ mod tests {
         team: Option<Team>,
         sequence_id: Option<SequenceId>,
     ) {
-        let position_parent = Position::<f32>::new(1., 2., 3.);
-        let velocity_parent = Velocity::<f32>::new(4., 5., 6.);
+        let position_parent = Position::<FixedPoint>::new(
+            FixedPoint::from(1),
+            FixedPoint::from(2),
+            FixedPoint::from(3),
+        );
+        let velocity_parent = Velocity::<FixedPoint>::new(
+            FixedPoint::from(4),
+            FixedPoint::from(5),
+            FixedPoint::from(6),
+        );
         let entity_parent = {
             let mut entity_builder = world
                 .create_entity()
@@ -134,8 +174,16 @@ mod tests {
         let sequence_id = sequence_id.unwrap_or_else(|| SequenceId::new(0));
         let spawn = Spawn::new(
             asset_id,
-            Position::<f32>::new(10., 20., 30.),
-            Velocity::<f32>::new(40., 50., 60.),
+            Position::<FixedPoint>::new(
+                FixedPoint::from(10),
+                FixedPoint::from(20),
+                FixedPoint::from(30),
+            ),
+            Velocity::<FixedPoint>::new(
+                FixedPoint::from(40),
+                FixedPoint::from(50),
+                FixedPoint::from(60),
+            ),
             sequence_id,
         );
 
@@ -152,15 +200,15 @@ mod tests {
 
     fn assert_spawn_values(
         world: &mut World,
-        position: Position<f32>,
-        velocity: Velocity<f32>,
+        position: Position<FixedPoint>,
+        velocity: Velocity<FixedPoint>,
         mirrored: Mirrored,
         team: Option<Team>,
         sequence_id: SequenceId,
     ) {
         let entity_spawned = *world.read_resource::<Entity>();
-        let positions = world.read_storage::<Position<f32>>();
-        let velocities = world.read_storage::<Velocity<f32>>();
+        let positions = world.read_storage::<Position<FixedPoint>>();
+        let velocities = world.read_storage::<Velocity<FixedPoint>>();
         let mirroreds = world.read_storage::<Mirrored>();
         let sequence_ids = world.read_storage::<SequenceId>();
         let teams = world.read_storage::<Team>();
@@ -168,10 +216,10 @@ mod tests {
 
         let position_actual = positions
             .get(entity_spawned)
-            .expect("Expected entity to have `Position<f32>` component.");
+            .expect("Expected entity to have `Position<FixedPoint>` component.");
         let velocity_actual = velocities
             .get(entity_spawned)
-            .expect("Expected entity to have `Velocity<f32>` component.");
+            .expect("Expected entity to have `Velocity<FixedPoint>` component.");
         let mirrored_actual = mirroreds
             .get(entity_spawned)
             .expect("Expected entity to have `Mirrored` component.");
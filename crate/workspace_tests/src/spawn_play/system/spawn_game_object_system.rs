@@ -4,6 +4,7 @@ mod tests {
 
     use amethyst::{
         assets::{AssetStorage, Loader},
+        core::math::Vector3,
         ecs::{Builder, Entity, Read, ReadExpect, World, WorldExt},
         shrev::{EventChannel, ReaderId},
         Error,
@@ -11,14 +12,17 @@ mod tests {
     use application_test_support::{AssetQueries, AutexousiousApplication};
     use asset_model::loaded::AssetId;
     use assets_test::{CHAR_BAT_SLUG, ENERGY_SQUARE_SLUG};
-    use kinematic_model::config::{Position, Velocity};
+    use charge_model::{config::ChargePoints, play::ChargeTrackerClock};
+    use game_input_model::{config::ControlAction, play::ControllerInput};
+    use kinematic_model::config::{FixedPoint, Position, Velocity};
     use sequence_model::{loaded::SequenceId, play::SequenceUpdateEvent};
     use spawn_model::{
+        config::{SpawnCondition, SpawnJitter, SpawnSpread},
         loaded::{Spawn, Spawns},
         play::SpawnEvent,
     };
 
-    use spawn_play::SpawnGameObjectSystem;
+    use spawn_play::{SpawnGameObjectRectifySystem, SpawnGameObjectSystem};
 
     #[test]
     fn spawns_entity_for_sequence_begin_events() -> Result<(), Error> {
@@ -58,6 +62,198 @@ mod tests {
         run_test(None, 0)
     }
 
+    #[test]
+    fn spawns_copies_spread_around_spawn_position_and_velocity() -> Result<(), Error> {
+        AutexousiousApplication::config_base()
+            .with_system(
+                SpawnGameObjectSystem::new(),
+                any::type_name::<SpawnGameObjectSystem>(),
+                &[],
+            )
+            .with_effect(setup_spawn_ec_reader)
+            .with_effect(|world| {
+                let mut spawn = spawn_energy(world);
+                spawn.velocity =
+                    Velocity::new(FixedPoint::from(4), FixedPoint::ZERO, FixedPoint::ZERO);
+                spawn.count = 3;
+                spawn.spread = SpawnSpread::new(
+                    FixedPoint::PI / FixedPoint::from(2),
+                    Position::<i32>::new(0, 0, 10),
+                );
+
+                let entity = create_parent(world, vec![spawn]);
+                send_sequence_begin(world, entity);
+            })
+            .with_assertion(|world| {
+                let spawns = spawn_events(world)
+                    .into_iter()
+                    .map(|ev| ev.spawn)
+                    .collect::<Vec<Spawn>>();
+
+                let positions = spawns
+                    .iter()
+                    .map(|spawn| spawn.position)
+                    .collect::<Vec<Position<FixedPoint>>>();
+                assert_eq!(
+                    vec![
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(-10)),
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+                        Position::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::from(10)),
+                    ],
+                    positions
+                );
+
+                let velocities_expected = vec![
+                    Vector3::new(0., 0., -4.),
+                    Vector3::new(4., 0., 0.),
+                    Vector3::new(0., 0., 4.),
+                ];
+                assert_eq!(velocities_expected.len(), spawns.len());
+                spawns.iter().zip(velocities_expected.into_iter()).for_each(
+                    |(spawn, velocity_expected)| {
+                        let velocity = spawn.velocity.to_f32();
+                        assert!(
+                            (velocity - velocity_expected).amax() < 0.01,
+                            "Expected velocity: {:?}, actual: {:?}",
+                            velocity_expected,
+                            velocity
+                        );
+                    },
+                );
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn applies_jitter_within_bounds() -> Result<(), Error> {
+        AutexousiousApplication::config_base()
+            .with_system(
+                SpawnGameObjectSystem::new(),
+                any::type_name::<SpawnGameObjectSystem>(),
+                &[],
+            )
+            .with_effect(setup_spawn_ec_reader)
+            .with_effect(|world| {
+                let mut spawn = spawn_energy(world);
+                spawn.count = 5;
+                spawn.jitter =
+                    SpawnJitter::new(Position::<i32>::new(0, 0, 0), Velocity::<i32>::new(0, 2, 0));
+
+                let entity = create_parent(world, vec![spawn]);
+                send_sequence_begin(world, entity);
+            })
+            .with_assertion(|world| {
+                let velocities = spawn_events(world)
+                    .into_iter()
+                    .map(|ev| ev.spawn.velocity)
+                    .collect::<Vec<Velocity<FixedPoint>>>();
+
+                assert_eq!(5, velocities.len());
+                velocities.iter().for_each(|velocity| {
+                    assert_eq!(FixedPoint::ZERO, velocity.x);
+                    assert!(velocity.y.abs() <= FixedPoint::from(2));
+                    assert_eq!(FixedPoint::ZERO, velocity.z);
+                });
+                assert!(velocities
+                    .iter()
+                    .any(|velocity| velocity.y != velocities[0].y));
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn limits_objects_in_play_per_parent() -> Result<(), Error> {
+        AutexousiousApplication::config_base()
+            .with_system(
+                SpawnGameObjectSystem::new(),
+                any::type_name::<SpawnGameObjectSystem>(),
+                &[],
+            )
+            .with_system(
+                SpawnGameObjectRectifySystem::new(),
+                any::type_name::<SpawnGameObjectRectifySystem>(),
+                &[any::type_name::<SpawnGameObjectSystem>()],
+            )
+            .with_effect(|world| {
+                let mut spawn = spawn_energy(world);
+                spawn.count = 3;
+                spawn.limit = Some(2);
+
+                let entity = create_parent(world, vec![spawn]);
+
+                // Both events are processed in the same tick.
+                send_sequence_begin(world, entity);
+                send_sequence_begin(world, entity);
+            })
+            .with_assertion(|world| assert_object_count(world, 2))
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                send_sequence_begin(world, entity);
+            })
+            .with_assertion(|world| assert_object_count(world, 2))
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn spawns_when_parent_has_enough_charge() -> Result<(), Error> {
+        run_condition_test(
+            SpawnCondition::Charge(ChargePoints(5)),
+            |world, entity| {
+                world
+                    .write_storage::<ChargeTrackerClock>()
+                    .insert(entity, ChargeTrackerClock::new_with_value(10, 5))
+                    .expect("Failed to insert `ChargeTrackerClock` component.");
+            },
+            1,
+        )
+    }
+
+    #[test]
+    fn does_not_spawn_when_parent_has_insufficient_charge() -> Result<(), Error> {
+        run_condition_test(
+            SpawnCondition::Charge(ChargePoints(5)),
+            |world, entity| {
+                world
+                    .write_storage::<ChargeTrackerClock>()
+                    .insert(entity, ChargeTrackerClock::new_with_value(10, 4))
+                    .expect("Failed to insert `ChargeTrackerClock` component.");
+            },
+            0,
+        )
+    }
+
+    #[test]
+    fn spawns_when_parent_holds_control_action() -> Result<(), Error> {
+        run_condition_test(
+            SpawnCondition::Hold(ControlAction::Special),
+            |world, entity| {
+                let controller_input = ControllerInput {
+                    special: true,
+                    ..Default::default()
+                };
+                world
+                    .write_storage::<ControllerInput>()
+                    .insert(entity, controller_input)
+                    .expect("Failed to insert `ControllerInput` component.");
+            },
+            1,
+        )
+    }
+
+    #[test]
+    fn does_not_spawn_when_parent_does_not_hold_control_action() -> Result<(), Error> {
+        run_condition_test(
+            SpawnCondition::Hold(ControlAction::Special),
+            |world, entity| {
+                world
+                    .write_storage::<ControllerInput>()
+                    .insert(entity, ControllerInput::default())
+                    .expect("Failed to insert `ControllerInput` component.");
+            },
+            0,
+        )
+    }
+
     fn run_test(
         sequence_update_event_fn: Option<fn(Entity) -> SequenceUpdateEvent>,
         spawn_count_expected: usize,
@@ -81,6 +277,29 @@ mod tests {
             .run_winit_loop()
     }
 
+    fn run_condition_test(
+        condition: SpawnCondition,
+        parent_setup_fn: fn(&mut World, Entity),
+        spawn_count_expected: usize,
+    ) -> Result<(), Error> {
+        AutexousiousApplication::config_base()
+            .with_system(
+                SpawnGameObjectSystem::new(),
+                any::type_name::<SpawnGameObjectSystem>(),
+                &[],
+            )
+            .with_effect(move |world| {
+                let mut spawn = spawn_energy(world);
+                spawn.condition = Some(condition);
+
+                let entity = create_parent(world, vec![spawn]);
+                parent_setup_fn(world, entity);
+                send_sequence_begin(world, entity);
+            })
+            .with_assertion(move |world| assert_object_count(world, spawn_count_expected))
+            .run_winit_loop()
+    }
+
     fn setup_spawn_ec_reader(world: &mut World) {
         let spawn_event_rid = world
             .write_resource::<EventChannel<SpawnEvent>>()
@@ -93,33 +312,45 @@ mod tests {
         world: &mut World,
         sequence_update_event_fn: Option<fn(Entity) -> SequenceUpdateEvent>,
     ) {
+        let spawns = vec![spawn_character(world), spawn_energy(world)];
+        let entity = create_parent(world, spawns);
+
+        if let Some(sequence_update_event_fn) = sequence_update_event_fn {
+            let mut sequence_update_ec =
+                world.write_resource::<EventChannel<SequenceUpdateEvent>>();
+            let sequence_update_event = sequence_update_event_fn(entity);
+            sequence_update_ec.single_write(sequence_update_event);
+        }
+    }
+
+    fn create_parent(world: &mut World, spawns: Vec<Spawn>) -> Entity {
         let spawns_handle = {
             let (loader, spawns_assets) =
                 world.system_data::<(ReadExpect<'_, Loader>, Read<'_, AssetStorage<Spawns>>)>();
-            loader.load_from_data(
-                Spawns::new(vec![spawn_character(world), spawn_energy(world)]),
-                (),
-                &spawns_assets,
-            )
+            loader.load_from_data(Spawns::new(spawns), (), &spawns_assets)
         };
 
         let entity = world.create_entity().with(spawns_handle).build();
         world.insert(entity);
 
-        if let Some(sequence_update_event_fn) = sequence_update_event_fn {
-            let mut sequence_update_ec =
-                world.write_resource::<EventChannel<SequenceUpdateEvent>>();
-            let sequence_update_event = sequence_update_event_fn(entity);
-            sequence_update_ec.single_write(sequence_update_event);
-        }
+        entity
+    }
+
+    fn send_sequence_begin(world: &mut World, entity: Entity) {
+        world
+            .write_resource::<EventChannel<SequenceUpdateEvent>>()
+            .single_write(SequenceUpdateEvent::SequenceBegin {
+                entity,
+                sequence_id: SequenceId::new(0),
+            });
     }
 
     fn spawn_character(world: &World) -> Spawn {
         let asset_id = AssetQueries::id(world, &*CHAR_BAT_SLUG);
         Spawn::new(
             asset_id,
-            Position::<f32>::new(0., 0., 0.),
-            Velocity::<f32>::new(0., 0., 0.),
+            Position::<FixedPoint>::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+            Velocity::<FixedPoint>::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
             SequenceId::new(0),
         )
     }
@@ -128,8 +359,8 @@ mod tests {
         let asset_id = AssetQueries::id(world, &*ENERGY_SQUARE_SLUG);
         Spawn::new(
             asset_id,
-            Position::<f32>::new(0., 0., 0.),
-            Velocity::<f32>::new(0., 0., 0.),
+            Position::<FixedPoint>::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
+            Velocity::<FixedPoint>::new(FixedPoint::ZERO, FixedPoint::ZERO, FixedPoint::ZERO),
             SequenceId::new(0),
         )
     }
@@ -139,6 +370,13 @@ mod tests {
         assert_eq!(count, asset_ids.count());
     }
 
+    fn spawn_events(world: &mut World) -> Vec<SpawnEvent> {
+        let mut spawn_event_rid = world.write_resource::<ReaderId<SpawnEvent>>();
+        let spawn_ec = world.read_resource::<EventChannel<SpawnEvent>>();
+
+        spawn_ec.read(&mut spawn_event_rid).cloned().collect()
+    }
+
     fn assert_events(world: &mut World, event_count: usize) {
         let mut spawn_event_rid = &mut world.write_resource::<ReaderId<SpawnEvent>>();
 
@@ -1,5 +1,9 @@
+mod atlas_image;
+mod atlas_packer;
+mod sprite_atlas_system;
 mod sprite_loading_bundle;
 mod sprite_sheet_mapper;
+mod sprite_variant_loader;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sprite_model::config::PaletteSwap;
+
+    use sprite_loading::AtlasImage;
+
+    #[test]
+    fn swap_palette_replaces_matching_colours_and_keeps_alpha() {
+        let mut image = AtlasImage::new(
+            3,
+            1,
+            vec![
+                255, 0, 0, 255, // red
+                0, 255, 0, 128, // translucent green
+                0, 0, 255, 255, // blue
+            ],
+        );
+
+        image.swap_palette(&[
+            PaletteSwap::new([255, 0, 0], [10, 20, 30]),
+            PaletteSwap::new([0, 255, 0], [40, 50, 60]),
+        ]);
+
+        assert_eq!(
+            vec![
+                10, 20, 30, 255, // swapped
+                40, 50, 60, 128, // swapped, alpha kept
+                0, 0, 255, 255, // unchanged
+            ],
+            image.pixels
+        );
+    }
+
+    #[test]
+    fn swap_palette_uses_first_matching_swap() {
+        let mut image = AtlasImage::new(1, 1, vec![255, 0, 0, 255]);
+
+        image.swap_palette(&[
+            PaletteSwap::new([255, 0, 0], [0, 255, 0]),
+            PaletteSwap::new([0, 255, 0], [0, 0, 255]),
+        ]);
+
+        assert_eq!(vec![0, 255, 0, 255], image.pixels);
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use sprite_loading::{AtlasImage, AtlasLayout, AtlasPackError, AtlasPacker, AtlasPlacement};
+
+    #[test]
+    fn layout_places_tallest_images_first_on_shelves() {
+        let atlas_packer = AtlasPacker::new(16, 0);
+
+        assert_eq!(
+            Ok(AtlasLayout::new(
+                vec![(16, 4)],
+                vec![
+                    AtlasPlacement::new(0, 12, 0),
+                    AtlasPlacement::new(0, 0, 0),
+                    AtlasPlacement::new(0, 8, 0),
+                ]
+            )),
+            atlas_packer.layout(&[(4, 2), (8, 4), (4, 4)])
+        );
+    }
+
+    #[test]
+    fn layout_starts_new_shelf_with_padding_when_shelf_is_full() {
+        let atlas_packer = AtlasPacker::new(10, 1);
+
+        assert_eq!(
+            Ok(AtlasLayout::new(
+                vec![(9, 9)],
+                vec![
+                    AtlasPlacement::new(0, 0, 0),
+                    AtlasPlacement::new(0, 5, 0),
+                    AtlasPlacement::new(0, 0, 5),
+                ]
+            )),
+            atlas_packer.layout(&[(4, 4), (4, 4), (4, 4)])
+        );
+    }
+
+    #[test]
+    fn layout_starts_new_atlas_when_atlas_is_full() {
+        let atlas_packer = AtlasPacker::new(4, 1);
+
+        assert_eq!(
+            Ok(AtlasLayout::new(
+                vec![(4, 4), (4, 4)],
+                vec![AtlasPlacement::new(0, 0, 0), AtlasPlacement::new(1, 0, 0)]
+            )),
+            atlas_packer.layout(&[(4, 4), (4, 4)])
+        );
+    }
+
+    #[test]
+    fn layout_is_deterministic_for_images_of_equal_size() {
+        let atlas_packer = AtlasPacker::new(8, 0);
+        let image_sizes = [(2, 2), (2, 2), (2, 2), (2, 2), (2, 2)];
+
+        let atlas_layout = atlas_packer.layout(&image_sizes);
+
+        assert_eq!(atlas_layout, atlas_packer.layout(&image_sizes));
+        assert_eq!(
+            Ok(vec![
+                AtlasPlacement::new(0, 0, 0),
+                AtlasPlacement::new(0, 2, 0),
+                AtlasPlacement::new(0, 4, 0),
+                AtlasPlacement::new(0, 6, 0),
+                AtlasPlacement::new(0, 0, 2),
+            ]),
+            atlas_layout.map(|atlas_layout| atlas_layout.placements)
+        );
+    }
+
+    #[test]
+    fn layout_returns_error_when_image_is_larger_than_atlas() {
+        let atlas_packer = AtlasPacker::new(8, 0);
+
+        assert_eq!(
+            Err(AtlasPackError::ImageTooLarge {
+                index: 1,
+                width: 4,
+                height: 9,
+                atlas_size: 8,
+            }),
+            atlas_packer.layout(&[(2, 2), (4, 9)])
+        );
+    }
+
+    #[test]
+    fn pack_copies_image_pixels_into_atlas() {
+        let atlas_packer = AtlasPacker::new(4, 1);
+        let red = [255, 0, 0, 255];
+        let green = [0, 255, 0, 255];
+        let images = [
+            AtlasImage::new(1, 1, red.to_vec()),
+            AtlasImage::new(2, 1, [green, green].concat()),
+        ];
+
+        let (atlases, atlas_layout) = atlas_packer
+            .pack(&images)
+            .expect("Expected images to be packed.");
+
+        assert_eq!(
+            vec![AtlasPlacement::new(0, 3, 0), AtlasPlacement::new(0, 0, 0)],
+            atlas_layout.placements
+        );
+        let transparent = [0, 0, 0, 0];
+        assert_eq!(
+            vec![AtlasImage::new(
+                4,
+                1,
+                [green, green, transparent, red].concat()
+            )],
+            atlases
+        );
+    }
+}
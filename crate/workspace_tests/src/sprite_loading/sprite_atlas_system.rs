@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests {
+    use std::{any, path::PathBuf};
+
+    use amethyst::{
+        assets::{AssetStorage, Handle, Loader, Processor, ProgressCounter},
+        core::TransformBundle,
+        ecs::{World, WorldExt},
+        renderer::{
+            sprite::SpriteSheetHandle, types::DefaultBackend, RenderEmptyBundle, SpriteSheet,
+            Texture,
+        },
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application::{AppFile, Format};
+    use application_test_support::AssetQueries;
+    use asset_model::loaded::AssetId;
+    use assets_test::CHAR_BAT_PATH;
+    use pretty_assertions::assert_eq;
+    use slotmap::SecondaryMap;
+    use sprite_model::{
+        config::SpritesDefinition, loaded::AssetSpritesDirs, play::SpriteAtlasRequestEvent,
+    };
+
+    use sprite_loading::{AtlasPacker, SpriteAtlasSystem, SpriteLoader};
+
+    #[test]
+    fn packs_requested_asset_sprite_sheets_into_atlas() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sprites_dir_extended: false,
+                atlas_requested: true,
+            },
+            ExpectedParams { packed: true },
+        )
+    }
+
+    #[test]
+    fn packs_sprite_sheets_from_extended_asset_sprites_dir() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sprites_dir_extended: true,
+                atlas_requested: true,
+            },
+            ExpectedParams { packed: true },
+        )
+    }
+
+    #[test]
+    fn does_not_pack_sprite_sheets_when_atlas_not_requested() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sprites_dir_extended: false,
+                atlas_requested: false,
+            },
+            ExpectedParams { packed: false },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            sprites_dir_extended,
+            atlas_requested,
+        }: SetupParams,
+        ExpectedParams { packed }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_system(Processor::<SpritesDefinition>::new(), "", &[])
+            .with_system(
+                SpriteAtlasSystem::new(AtlasPacker::default()),
+                any::type_name::<SpriteAtlasSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| setup_asset(world, sprites_dir_extended))
+            // Wait for the sprite sheets to be processed.
+            .with_effect(|_| {})
+            .with_effect(move |world| {
+                if atlas_requested {
+                    let asset_id = *world.read_resource::<AssetId>();
+                    world
+                        .write_resource::<EventChannel<SpriteAtlasRequestEvent>>()
+                        .single_write(SpriteAtlasRequestEvent::new(vec![asset_id, asset_id]));
+                }
+            })
+            .with_assertion(move |world| {
+                let asset_id = *world.read_resource::<AssetId>();
+                let asset_sprite_sheet_handles =
+                    world.read_resource::<SecondaryMap<AssetId, Vec<SpriteSheetHandle>>>();
+                let sprite_sheet_assets = world.read_resource::<AssetStorage<SpriteSheet>>();
+
+                let sprite_sheets = asset_sprite_sheet_handles
+                    .get(asset_id)
+                    .expect("Expected sprite sheet handles to exist.")
+                    .iter()
+                    .map(|sprite_sheet_handle| {
+                        sprite_sheet_assets
+                            .get(sprite_sheet_handle)
+                            .expect("Expected sprite sheet to be loaded.")
+                    })
+                    .collect::<Vec<&SpriteSheet>>();
+
+                // `bat_grey.png` and `bat_brown.png` are only drawn from the same texture when
+                // they are packed into one atlas.
+                assert_eq!(2, sprite_sheets.len());
+                assert_eq!(packed, sprite_sheets[0].texture == sprite_sheets[1].texture);
+                assert_eq!(6, sprite_sheets[0].sprites.len());
+                assert_eq!(6, sprite_sheets[1].sprites.len());
+            })
+            .run_winit_loop()
+    }
+
+    fn setup_asset(world: &mut World, sprites_dir_extended: bool) {
+        let sprites_definition =
+            AppFile::load_in::<SpritesDefinition, _>(&*CHAR_BAT_PATH, "sprites.yaml", Format::Yaml)
+                .expect("Failed to load sprites_definition.");
+
+        let (sprite_sheet_handles, sprites_definition_handle) = {
+            let loader = world.read_resource::<Loader>();
+            let texture_assets = world.read_resource::<AssetStorage<Texture>>();
+            let sprite_sheet_assets = world.read_resource::<AssetStorage<SpriteSheet>>();
+            let sprites_definition_assets =
+                world.read_resource::<AssetStorage<SpritesDefinition>>();
+
+            let sprite_sheet_handles = SpriteLoader::load(
+                &mut ProgressCounter::default(),
+                &loader,
+                &texture_assets,
+                &sprite_sheet_assets,
+                &sprites_definition,
+                &CHAR_BAT_PATH,
+            )
+            .expect("Failed to load sprites.");
+            let sprites_definition_handle =
+                loader.load_from_data(sprites_definition, (), &sprites_definition_assets);
+
+            (sprite_sheet_handles, sprites_definition_handle)
+        };
+
+        let asset_id = AssetQueries::id_generate_any(world);
+        if sprites_dir_extended {
+            // The asset's own directory has no sprites, so the images must be read from the
+            // extended asset's directory.
+            world
+                .write_resource::<SecondaryMap<AssetId, PathBuf>>()
+                .insert(asset_id, PathBuf::from("non_existent_dir"));
+            world
+                .write_resource::<AssetSpritesDirs>()
+                .insert(asset_id, CHAR_BAT_PATH.clone());
+        } else {
+            world
+                .write_resource::<SecondaryMap<AssetId, PathBuf>>()
+                .insert(asset_id, CHAR_BAT_PATH.clone());
+        }
+        world
+            .write_resource::<SecondaryMap<AssetId, Handle<SpritesDefinition>>>()
+            .insert(asset_id, sprites_definition_handle);
+        world
+            .write_resource::<SecondaryMap<AssetId, Vec<SpriteSheetHandle>>>()
+            .insert(asset_id, sprite_sheet_handles);
+
+        world.insert(asset_id);
+    }
+
+    struct SetupParams {
+        sprites_dir_extended: bool,
+        atlas_requested: bool,
+    }
+
+    struct ExpectedParams {
+        packed: bool,
+    }
+}
@@ -4,7 +4,10 @@ mod test {
     use amethyst_test::AmethystApplication;
     use sprite_model::{
         config::SpritesDefinition,
-        loaded::{ScaleSequence, SpriteRenderSequence, TintSequence},
+        loaded::{
+            InterpolationSequence, ScaleSequence, SpriteOffsetSequence, SpriteRenderSequence,
+            TintSequence,
+        },
     };
 
     use sprite_loading::SpriteLoadingBundle;
@@ -19,6 +22,8 @@ mod test {
                 world.read_resource::<AssetStorage<SpriteRenderSequence>>();
                 world.read_resource::<AssetStorage<TintSequence>>();
                 world.read_resource::<AssetStorage<ScaleSequence>>();
+                world.read_resource::<AssetStorage<SpriteOffsetSequence>>();
+                world.read_resource::<AssetStorage<InterpolationSequence>>();
             })
             .run()
     }
@@ -15,7 +15,7 @@ mod test {
     use amethyst_test::AmethystApplication;
     use sprite_model::config::{SpriteOffset, SpriteSheetDefinition};
 
-    use sprite_loading::SpriteSheetMapper;
+    use sprite_loading::{AtlasPlacement, SpriteSheetMapper};
 
     #[test]
     fn map_multiple_sprite_sheet_definitions() -> Result<(), Error> {
@@ -193,6 +193,39 @@ mod test {
             .run_winit_loop()
     }
 
+    #[test]
+    fn map_atlas_region_offsets_texture_coordinates_by_placement() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_assertion(|world| {
+                let texture_handle = test_texture_handle(world);
+
+                let sprite_sheet = SpriteSheet {
+                    texture: texture_handle.clone(),
+                    sprites: vec![(
+                        (19., 29.),
+                        [-9.5, -14.5],
+                        [10.5 / 100., 28.5 / 100., 48.5 / 200., 20.5 / 200.],
+                    )
+                        .into()],
+                }; // kcov-ignore
+
+                // kcov-ignore-start
+                assert_eq!(
+                    // kcov-ignore-end
+                    sprite_sheet,
+                    SpriteSheetMapper::map_atlas_region(
+                        texture_handle,
+                        &no_offsets_definition(),
+                        (100, 200),
+                        AtlasPlacement::new(0, 10, 20),
+                    )
+                );
+            })
+            .run_winit_loop()
+    }
+
     fn simple_definition() -> SpriteSheetDefinition {
         SpriteSheetDefinition::new(
             String::from("bat_brown.png"),
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::{AssetStorage, Loader, ProgressCounter},
+        core::TransformBundle,
+        ecs::WorldExt,
+        renderer::{types::DefaultBackend, RenderEmptyBundle, SpriteSheet, Texture},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application::{AppFile, Format};
+    use assets_test::CHAR_BAT_PATH;
+    use pretty_assertions::assert_eq;
+    use sprite_model::config::{PaletteSwap, SpriteVariant, SpritesDefinition};
+    use tempfile::tempdir;
+
+    use sprite_loading::{SpriteLoader, SpriteVariantLoader};
+
+    #[test]
+    fn loads_sprite_sheets_for_changed_sheets_and_reuses_unchanged_sheets() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_assertion(|world| {
+                let sprites_definition = AppFile::load_in::<SpritesDefinition, _>(
+                    &*CHAR_BAT_PATH,
+                    "sprites.yaml",
+                    Format::Yaml,
+                )
+                .expect("Failed to load sprites_definition.");
+                let sprite_variants = vec![
+                    SpriteVariant::default(),
+                    SpriteVariant::new(vec![String::from("bat_brown.png")], vec![]),
+                    SpriteVariant::new(vec![], vec![PaletteSwap::new([0, 0, 0], [255, 0, 0])]),
+                ];
+
+                let loader = world.read_resource::<Loader>();
+                let texture_assets = world.read_resource::<AssetStorage<Texture>>();
+                let sprite_sheet_assets = world.read_resource::<AssetStorage<SpriteSheet>>();
+                let mut progress_counter = ProgressCounter::default();
+
+                let sprite_sheet_handles = SpriteLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &CHAR_BAT_PATH,
+                )
+                .expect("Failed to load sprites.");
+                let sprite_variant_sheet_handles = SpriteVariantLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &sprite_sheet_handles,
+                    &sprite_variants,
+                    &CHAR_BAT_PATH,
+                    &CHAR_BAT_PATH,
+                );
+
+                assert_eq!(3, sprite_variant_sheet_handles.len());
+                sprite_variant_sheet_handles
+                    .iter()
+                    .for_each(|variant_sheet_handles| {
+                        assert_eq!(sprite_sheet_handles.len(), variant_sheet_handles.len())
+                    });
+
+                // Unchanged variant.
+                assert_eq!(sprite_sheet_handles, sprite_variant_sheet_handles[0]);
+                // Only the first sheet is replaced.
+                assert_ne!(sprite_sheet_handles[0], sprite_variant_sheet_handles[1][0]);
+                assert_eq!(
+                    sprite_sheet_handles[1..],
+                    sprite_variant_sheet_handles[1][1..]
+                );
+                // Palette swaps apply to every sheet.
+                sprite_sheet_handles
+                    .iter()
+                    .zip(sprite_variant_sheet_handles[2].iter())
+                    .for_each(|(sprite_sheet_handle, variant_sheet_handle)| {
+                        assert_ne!(sprite_sheet_handle, variant_sheet_handle)
+                    });
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn loads_inherited_sheets_relative_to_sprites_dir() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_assertion(|world| {
+                let sprites_definition = AppFile::load_in::<SpritesDefinition, _>(
+                    &*CHAR_BAT_PATH,
+                    "sprites.yaml",
+                    Format::Yaml,
+                )
+                .expect("Failed to load sprites_definition.");
+                let sprite_variants = vec![SpriteVariant::new(
+                    vec![],
+                    vec![PaletteSwap::new([0, 0, 0], [255, 0, 0])],
+                )];
+                // Asset that extends the bat, and has no sprite images of its own.
+                let asset_dir = tempdir().expect("Failed to create temporary directory.");
+
+                let loader = world.read_resource::<Loader>();
+                let texture_assets = world.read_resource::<AssetStorage<Texture>>();
+                let sprite_sheet_assets = world.read_resource::<AssetStorage<SpriteSheet>>();
+                let mut progress_counter = ProgressCounter::default();
+
+                let sprite_sheet_handles = SpriteLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &CHAR_BAT_PATH,
+                )
+                .expect("Failed to load sprites.");
+                let sprite_variant_sheet_handles = SpriteVariantLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &sprite_sheet_handles,
+                    &sprite_variants,
+                    &CHAR_BAT_PATH,
+                    asset_dir.path(),
+                );
+
+                // Every sheet is palette swapped instead of falling back to the object's sheet.
+                sprite_sheet_handles
+                    .iter()
+                    .zip(sprite_variant_sheet_handles[0].iter())
+                    .for_each(|(sprite_sheet_handle, variant_sheet_handle)| {
+                        assert_ne!(sprite_sheet_handle, variant_sheet_handle)
+                    });
+            })
+            .run_winit_loop()
+    }
+
+    #[test]
+    fn uses_object_sprite_sheets_when_variant_image_fails_to_load() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_assertion(|world| {
+                let sprites_definition = AppFile::load_in::<SpritesDefinition, _>(
+                    &*CHAR_BAT_PATH,
+                    "sprites.yaml",
+                    Format::Yaml,
+                )
+                .expect("Failed to load sprites_definition.");
+                let sprite_variants = vec![SpriteVariant::new(
+                    vec![String::from("non_existent.png")],
+                    vec![PaletteSwap::new([0, 0, 0], [255, 0, 0])],
+                )];
+
+                let loader = world.read_resource::<Loader>();
+                let texture_assets = world.read_resource::<AssetStorage<Texture>>();
+                let sprite_sheet_assets = world.read_resource::<AssetStorage<SpriteSheet>>();
+                let mut progress_counter = ProgressCounter::default();
+
+                let sprite_sheet_handles = SpriteLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &CHAR_BAT_PATH,
+                )
+                .expect("Failed to load sprites.");
+                let sprite_variant_sheet_handles = SpriteVariantLoader::load(
+                    &mut progress_counter,
+                    &loader,
+                    &texture_assets,
+                    &sprite_sheet_assets,
+                    &sprites_definition,
+                    &sprite_sheet_handles,
+                    &sprite_variants,
+                    &CHAR_BAT_PATH,
+                    &CHAR_BAT_PATH,
+                );
+
+                // The first sheet falls back to the object's sprite sheet.
+                assert_eq!(sprite_sheet_handles[0], sprite_variant_sheet_handles[0][0]);
+                // The remaining sheets still have their palette swapped.
+                sprite_sheet_handles[1..]
+                    .iter()
+                    .zip(sprite_variant_sheet_handles[0][1..].iter())
+                    .for_each(|(sprite_sheet_handle, variant_sheet_handle)| {
+                        assert_ne!(sprite_sheet_handle, variant_sheet_handle)
+                    });
+            })
+            .run_winit_loop()
+    }
+}
@@ -1,3 +1,4 @@
+mod interpolation;
 mod sprite_frame;
 mod sprite_sheet_definition;
 
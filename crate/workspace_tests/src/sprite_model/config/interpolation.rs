@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use sprite_model::config::Interpolation;
+
+    #[test]
+    fn step_does_not_blend() {
+        assert_relative_eq!(0., Interpolation::Step.blend_factor(0.5));
+        assert_relative_eq!(0., Interpolation::Step.blend_factor(1.));
+    }
+
+    #[test]
+    fn linear_blends_proportionally() {
+        assert_relative_eq!(0.25, Interpolation::Linear.blend_factor(0.25));
+        assert_relative_eq!(0.75, Interpolation::Linear.blend_factor(0.75));
+    }
+
+    #[test]
+    fn ease_curves_meet_linear_at_ends() {
+        [
+            Interpolation::EaseIn,
+            Interpolation::EaseOut,
+            Interpolation::EaseInOut,
+        ]
+        .iter()
+        .for_each(|interpolation| {
+            assert_relative_eq!(0., interpolation.blend_factor(0.));
+            assert_relative_eq!(1., interpolation.blend_factor(1.));
+        });
+    }
+
+    #[test]
+    fn ease_in_starts_slow_and_ease_out_starts_fast() {
+        assert_relative_eq!(0.0625, Interpolation::EaseIn.blend_factor(0.25));
+        assert_relative_eq!(0.4375, Interpolation::EaseOut.blend_factor(0.25));
+        assert_relative_eq!(0.125, Interpolation::EaseInOut.blend_factor(0.25));
+        assert_relative_eq!(0.5, Interpolation::EaseInOut.blend_factor(0.5));
+        assert_relative_eq!(0.875, Interpolation::EaseInOut.blend_factor(0.75));
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        assert_relative_eq!(0., Interpolation::Linear.blend_factor(-1.));
+        assert_relative_eq!(1., Interpolation::Linear.blend_factor(2.));
+    }
+}
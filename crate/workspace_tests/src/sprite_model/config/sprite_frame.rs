@@ -3,7 +3,7 @@ mod test {
     use serde_yaml;
 
     use sequence_model::config::Wait;
-    use sprite_model::config::{Scale, SpriteFrame, SpriteRef, Tint};
+    use sprite_model::config::{Interpolation, Scale, SpriteFrame, SpriteOffset, SpriteRef, Tint};
 
     const SPRITE_FRAME_DEFAULTS_YAML: &str = "{}";
     const SPRITE_FRAME_FULL_YAML: &str = r#"---
@@ -11,6 +11,8 @@ wait: 3
 sprite: { sheet: 1, index: 2 }
 tint: { r: 0.1, g: 0.2, b: 0.3, a: 0.4 }
 scale: 2.0
+offset: { x: -3 }
+interpolation: "ease_in_out"
 "#;
 
     #[test]
@@ -24,6 +26,8 @@ scale: 2.0
                 sprite: SpriteRef::new(0, 0),
                 tint: Tint::new(1., 1., 1., 1.),
                 scale: Scale(None),
+                offset: SpriteOffset::new(0, 0),
+                interpolation: Interpolation::Step,
             },
             sprite_frame
         );
@@ -39,6 +43,8 @@ scale: 2.0
             sprite: SpriteRef::new(1, 2),
             tint: Tint::new(0.1, 0.2, 0.3, 0.4),
             scale: Scale::new(2.),
+            offset: SpriteOffset::new(-3, 0),
+            interpolation: Interpolation::EaseInOut,
         };
         assert_eq!(sprite_frame_expected, sprite_frame);
     }
@@ -1 +1,3 @@
+mod sprite_interpolation_system;
 mod sprite_scale_update_system;
+mod sprite_sheet_variant_system;
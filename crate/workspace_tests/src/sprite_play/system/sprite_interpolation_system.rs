@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        assets::{AssetStorage, Loader},
+        ecs::{Builder, Entity, Read, ReadExpect, World, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use approx::assert_relative_eq;
+    use pretty_assertions::assert_eq;
+    use sequence_model::play::{FrameIndexClock, FrameWaitClock};
+    use sprite_loading::SpriteLoadingBundle;
+    use sprite_model::{
+        config::{Interpolation, Scale, SpriteOffset},
+        loaded::{ScaleSequence, SpriteOffsetSequence},
+        play::SpriteOffsetTranslation,
+    };
+
+    use sprite_play::SpriteInterpolationSystem;
+
+    #[test]
+    fn blend_tint_lerps_each_channel() {
+        let tint = SpriteInterpolationSystem::blend_tint(
+            Tint(Srgba::new(1., 0., 0.5, 1.)),
+            Tint(Srgba::new(0., 1., 0.5, 0.)),
+            0.25,
+        );
+
+        assert_relative_eq!(0.75, tint.0.red);
+        assert_relative_eq!(0.25, tint.0.green);
+        assert_relative_eq!(0.5, tint.0.blue);
+        assert_relative_eq!(0.75, tint.0.alpha);
+    }
+
+    #[test]
+    fn blend_scale_returns_current_scale_when_either_scale_is_not_set() {
+        assert_eq!(
+            Scale::new(1.5),
+            SpriteInterpolationSystem::blend_scale(Scale::new(1.), Scale::new(2.), 0.5)
+        );
+        assert_eq!(
+            Scale::new(1.),
+            SpriteInterpolationSystem::blend_scale(Scale::new(1.), Scale(None), 0.5)
+        );
+        assert_eq!(
+            Scale(None),
+            SpriteInterpolationSystem::blend_scale(Scale(None), Scale::new(2.), 0.5)
+        );
+    }
+
+    #[test]
+    fn blend_offset_negates_x_translation() {
+        assert_eq!(
+            SpriteOffsetTranslation::new(-5., 3.),
+            SpriteInterpolationSystem::blend_offset(
+                SpriteOffset::new(0, 2),
+                SpriteOffset::new(10, 4),
+                0.5
+            )
+        );
+    }
+
+    #[test]
+    fn blend_factor_is_proportion_of_frame_wait_elapsed() {
+        let frame_wait_clock = FrameWaitClock::new_with_value(4, 1);
+
+        assert_relative_eq!(
+            0.25,
+            SpriteInterpolationSystem::blend_factor(frame_wait_clock, Interpolation::Linear)
+        );
+        assert_relative_eq!(
+            0.,
+            SpriteInterpolationSystem::blend_factor(frame_wait_clock, Interpolation::Step)
+        );
+        assert_relative_eq!(
+            0.,
+            SpriteInterpolationSystem::blend_factor(FrameWaitClock::new(0), Interpolation::Linear)
+        );
+    }
+
+    #[test]
+    fn blends_values_towards_next_frame() -> Result<(), Error> {
+        run_test(Interpolation::Linear, 0, |world, entity| {
+            let scales = world.read_storage::<Scale>();
+            let sprite_offset_translations = world.read_storage::<SpriteOffsetTranslation>();
+
+            assert_eq!(Some(&Scale::new(2.)), scales.get(entity));
+            assert_eq!(
+                Some(&SpriteOffsetTranslation::new(-2., 0.)),
+                sprite_offset_translations.get(entity)
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_blend_step_frames() -> Result<(), Error> {
+        run_test(Interpolation::Step, 0, |world, entity| {
+            let scales = world.read_storage::<Scale>();
+            let sprite_offset_translations = world.read_storage::<SpriteOffsetTranslation>();
+
+            assert!(scales.get(entity).is_none());
+            assert_eq!(
+                Some(&SpriteOffsetTranslation::new(0., 0.)),
+                sprite_offset_translations.get(entity)
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_blend_last_frame() -> Result<(), Error> {
+        run_test(Interpolation::Linear, 1, |world, entity| {
+            let scales = world.read_storage::<Scale>();
+            let sprite_offset_translations = world.read_storage::<SpriteOffsetTranslation>();
+
+            assert_eq!(Some(&Scale::new(3.)), scales.get(entity));
+            assert_eq!(
+                Some(&SpriteOffsetTranslation::new(-4., 0.)),
+                sprite_offset_translations.get(entity)
+            );
+        })
+    }
+
+    fn run_test(
+        interpolation: Interpolation,
+        frame_index: usize,
+        assertion_fn: fn(&mut World, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(SpriteLoadingBundle)
+            .with_system(
+                SpriteInterpolationSystem::new(),
+                any::type_name::<SpriteInterpolationSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let entity = create_entity(world, interpolation, frame_index);
+                world.insert(entity);
+            })
+            // Wait for the sequence assets to be processed.
+            .with_effect(|_| {})
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                assertion_fn(world, entity);
+            })
+            .run()
+    }
+
+    fn create_entity(
+        world: &mut World,
+        interpolation: Interpolation,
+        frame_index: usize,
+    ) -> Entity {
+        let (scale_sequence_handle, sprite_offset_sequence_handle) = {
+            let (loader, scale_sequence_assets, sprite_offset_sequence_assets) = world
+                .system_data::<(
+                    ReadExpect<'_, Loader>,
+                    Read<'_, AssetStorage<ScaleSequence>>,
+                    Read<'_, AssetStorage<SpriteOffsetSequence>>,
+                )>();
+
+            let scale_sequence = ScaleSequence::new(vec![Scale::new(1.), Scale::new(3.)]);
+            let sprite_offset_sequence =
+                SpriteOffsetSequence::new(vec![SpriteOffset::new(0, 0), SpriteOffset::new(4, 0)]);
+
+            (
+                loader.load_from_data(scale_sequence, (), &scale_sequence_assets),
+                loader.load_from_data(sprite_offset_sequence, (), &sprite_offset_sequence_assets),
+            )
+        };
+
+        world
+            .create_entity()
+            .with(FrameIndexClock::new_with_value(2, frame_index))
+            .with(FrameWaitClock::new_with_value(4, 2))
+            .with(interpolation)
+            .with(scale_sequence_handle)
+            .with(sprite_offset_sequence_handle)
+            .build()
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        assets::{AssetStorage, Loader},
+        core::TransformBundle,
+        ecs::{Builder, Entity, World, WorldExt},
+        renderer::{
+            loaders::load_from_srgba,
+            palette::Srgba,
+            sprite::SpriteSheetHandle,
+            types::{DefaultBackend, TextureData},
+            RenderEmptyBundle, SpriteRender, SpriteSheet, Texture,
+        },
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use pretty_assertions::assert_eq;
+    use sprite_model::loaded::SpriteSheetVariant;
+
+    use sprite_play::SpriteSheetVariantSystem;
+
+    #[test]
+    fn swaps_object_sprite_sheet_for_variant_sprite_sheet() -> Result<(), Error> {
+        run_test(|sprite_sheet_handles| sprite_sheet_handles[1].clone(), 3)
+    }
+
+    #[test]
+    fn does_not_swap_sprite_sheet_that_is_not_an_object_sprite_sheet() -> Result<(), Error> {
+        run_test(|sprite_sheet_handles| sprite_sheet_handles[2].clone(), 2)
+    }
+
+    fn run_test(
+        sprite_sheet_handle_fn: fn(&[SpriteSheetHandle]) -> SpriteSheetHandle,
+        sprite_sheet_index_expected: usize,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
+            .with_system(
+                SpriteSheetVariantSystem::new(),
+                any::type_name::<SpriteSheetVariantSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let sprite_sheet_handles = (0..4)
+                    .map(|_| sprite_sheet_handle(world))
+                    .collect::<Vec<SpriteSheetHandle>>();
+                let sprite_sheet_variant = SpriteSheetVariant::new(
+                    vec![
+                        sprite_sheet_handles[0].clone(),
+                        sprite_sheet_handles[1].clone(),
+                    ],
+                    vec![
+                        sprite_sheet_handles[2].clone(),
+                        sprite_sheet_handles[3].clone(),
+                    ],
+                );
+                let sprite_render = SpriteRender {
+                    sprite_sheet: sprite_sheet_handle_fn(&sprite_sheet_handles),
+                    sprite_number: 0,
+                };
+
+                let entity = world
+                    .create_entity()
+                    .with(sprite_sheet_variant)
+                    .with(sprite_render)
+                    .build();
+
+                world.insert(entity);
+                world.insert(sprite_sheet_handles);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let sprite_sheet_handles = world.read_resource::<Vec<SpriteSheetHandle>>();
+                let sprite_renders = world.read_storage::<SpriteRender>();
+
+                let sprite_render = sprite_renders
+                    .get(entity)
+                    .expect("Expected `SpriteRender` to exist.");
+                assert_eq!(
+                    sprite_sheet_handles[sprite_sheet_index_expected],
+                    sprite_render.sprite_sheet
+                );
+            })
+            .run_winit_loop()
+    }
+
+    fn sprite_sheet_handle(world: &mut World) -> SpriteSheetHandle {
+        let loader = world.read_resource::<Loader>();
+        let texture_assets = world.read_resource::<AssetStorage<Texture>>();
+        let sprite_sheet_assets = world.read_resource::<AssetStorage<SpriteSheet>>();
+
+        let texture_builder = load_from_srgba(Srgba::new(0., 0., 0., 1.));
+        let texture_data = TextureData::from(texture_builder);
+        let texture = loader.load_from_data(texture_data, (), &texture_assets);
+        let sprite_sheet = SpriteSheet {
+            texture,
+            sprites: vec![],
+        };
+        loader.load_from_data(sprite_sheet, (), &sprite_sheet_assets)
+    }
+}
@@ -0,0 +1 @@
+mod survival_loading_bundle;
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use amethyst::{assets::AssetStorage, ecs::WorldExt, Error};
+    use amethyst_test::AmethystApplication;
+    use survival_model::{config::SurvivalWaves, SurvivalLoadingStatus};
+
+    use survival_loading::SurvivalLoadingBundle;
+
+    #[test]
+    fn bundle_build_adds_survival_resources() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(SurvivalLoadingBundle::new(PathBuf::default()))
+            .with_assertion(|world| {
+                // Panics if the Systems weren't added
+                world.read_resource::<AssetStorage<SurvivalWaves>>();
+
+                world.read_resource::<SurvivalLoadingStatus>();
+                world.read_resource::<SurvivalWaves>();
+            })
+            .run()
+    }
+}
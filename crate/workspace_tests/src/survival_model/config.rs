@@ -0,0 +1 @@
+mod survival_waves;
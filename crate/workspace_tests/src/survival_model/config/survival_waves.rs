@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use asset_model::config::AssetSlug;
+    use serde_yaml;
+
+    use survival_model::config::{AiProfile, SurvivalWaves, Wave, WaveEnemy};
+
+    const SURVIVAL_WAVES_YAML: &str = r#"---
+waves:
+  - enemies:
+      - object: "test/char_0"
+  - enemies:
+      - object: "test/char_0"
+        count: 2
+        ai: "aggressive"
+        spawn_point: 1
+        delay: 30
+"#;
+
+    #[test]
+    fn deserialize_survival_waves() {
+        let survival_waves = serde_yaml::from_str::<SurvivalWaves>(SURVIVAL_WAVES_YAML)
+            .expect("Failed to deserialize survival waves.");
+
+        let asset_slug =
+            AssetSlug::from_str("test/char_0").expect("Expected asset slug to be valid.");
+        let expected = SurvivalWaves::new(vec![
+            Wave::new(vec![WaveEnemy::new(
+                asset_slug.clone(),
+                1,
+                AiProfile::Chase,
+                None,
+                0,
+            )]),
+            Wave::new(vec![WaveEnemy::new(
+                asset_slug,
+                2,
+                AiProfile::Aggressive,
+                Some(1),
+                30,
+            )]),
+        ]);
+
+        assert_eq!(expected, survival_waves);
+    }
+}
@@ -0,0 +1,2 @@
+mod enemy_ai_input_system;
+mod survival_wave_spawn_system;
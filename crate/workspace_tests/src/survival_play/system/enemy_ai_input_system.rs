@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{Axis, ControlAction},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+    };
+    use kinematic_model::config::{FixedPoint, Position};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use pretty_assertions::assert_eq;
+    use survival_model::{
+        config::AiProfile,
+        play::{AiControlled, SurvivalStatus, SurvivalTeam},
+    };
+    use team_model::play::Team;
+
+    use survival_play::EnemyAiInputSystem;
+
+    #[test]
+    fn moves_towards_nearest_player_when_out_of_range() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            AiProfile::Chase,
+            Position::new(
+                FixedPoint::from(500),
+                FixedPoint::ZERO,
+                FixedPoint::from(100),
+            ),
+            Mirrored(false),
+            |enemy| {
+                vec![ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id: AiControlled::CONTROLLER_ID,
+                    entity: enemy,
+                    axis: Axis::X,
+                    value: FixedPoint::from(-1),
+                })]
+            },
+        )
+    }
+
+    #[test]
+    fn attacks_when_in_range_and_facing_player() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            AiProfile::Chase,
+            Position::new(
+                FixedPoint::from(130),
+                FixedPoint::ZERO,
+                FixedPoint::from(100),
+            ),
+            Mirrored(true),
+            |enemy| {
+                vec![ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: AiControlled::CONTROLLER_ID,
+                        entity: enemy,
+                        control_action: ControlAction::Attack,
+                    },
+                )]
+            },
+        )
+    }
+
+    #[test]
+    fn turns_towards_player_when_in_range_and_facing_away() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            AiProfile::Chase,
+            Position::new(
+                FixedPoint::from(130),
+                FixedPoint::ZERO,
+                FixedPoint::from(100),
+            ),
+            Mirrored(false),
+            |enemy| {
+                vec![ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id: AiControlled::CONTROLLER_ID,
+                    entity: enemy,
+                    axis: Axis::X,
+                    value: FixedPoint::from(-1),
+                })]
+            },
+        )
+    }
+
+    #[test]
+    fn sends_no_input_when_idle() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            AiProfile::Idle,
+            Position::new(
+                FixedPoint::from(500),
+                FixedPoint::ZERO,
+                FixedPoint::from(100),
+            ),
+            Mirrored(false),
+            |_| vec![],
+        )
+    }
+
+    #[test]
+    fn sends_no_input_when_survival_is_inactive() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Inactive,
+            AiProfile::Chase,
+            Position::new(
+                FixedPoint::from(500),
+                FixedPoint::ZERO,
+                FixedPoint::from(100),
+            ),
+            Mirrored(false),
+            |_| vec![],
+        )
+    }
+
+    fn run_test(
+        survival_status: SurvivalStatus,
+        ai_profile: AiProfile,
+        enemy_position: Position<FixedPoint>,
+        enemy_mirrored: Mirrored,
+        expected_events_fn: fn(Entity) -> Vec<ControlInputEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(EnemyAiInputSystem::new(), "", &[])
+            .with_effect(move |world| {
+                world.insert(survival_status);
+
+                // Player on the left, and a further player that is down.
+                world
+                    .create_entity()
+                    .with(Team::Number(SurvivalTeam::PLAYERS))
+                    .with(HealthPoints::default())
+                    .with(Position::<FixedPoint>::new(
+                        FixedPoint::from(100),
+                        FixedPoint::ZERO,
+                        FixedPoint::from(100),
+                    ))
+                    .build();
+                world
+                    .create_entity()
+                    .with(Team::Number(SurvivalTeam::PLAYERS))
+                    .with(HealthPoints(0))
+                    .with(Position::<FixedPoint>::new(
+                        FixedPoint::from(900),
+                        FixedPoint::ZERO,
+                        FixedPoint::from(100),
+                    ))
+                    .build();
+
+                let enemy = world
+                    .create_entity()
+                    .with(Team::Number(SurvivalTeam::ENEMIES))
+                    .with(AiControlled::new(ai_profile))
+                    .with(ControllerInput::default())
+                    .with(HealthPoints::default())
+                    .with(enemy_position)
+                    .with(enemy_mirrored)
+                    .build();
+                world.insert(enemy);
+
+                let reader_id = world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .register_reader();
+                world.insert(reader_id);
+            })
+            .with_assertion(move |world| {
+                let enemy = *world.read_resource::<Entity>();
+                assert_eq!(expected_events_fn(enemy), read_events(world));
+            })
+            .run()
+    }
+
+    fn read_events(world: &World) -> Vec<ControlInputEvent> {
+        let mut reader_id = world.write_resource::<ReaderId<ControlInputEvent>>();
+        world
+            .read_resource::<EventChannel<ControlInputEvent>>()
+            .read(&mut reader_id)
+            .copied()
+            .collect::<Vec<_>>()
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use amethyst::{
+        ecs::{Builder, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::config::AssetSlug;
+    use game_play_model::GamePlayStatus;
+    use object_model::play::HealthPoints;
+    use pretty_assertions::assert_eq;
+    use survival_model::{
+        config::{AiProfile, SurvivalWaves, Wave, WaveEnemy},
+        play::{SurvivalProgress, SurvivalStatus},
+    };
+
+    use survival_play::SurvivalWaveSpawnSystem;
+
+    #[test]
+    fn does_nothing_when_survival_is_inactive() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Inactive,
+            0,
+            |_| SurvivalProgress::default(),
+            |_| SurvivalProgress::default(),
+        )
+    }
+
+    #[test]
+    fn waits_for_enemy_delay_before_spawning() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            10,
+            |_| SurvivalProgress::default(),
+            |_| SurvivalProgress {
+                wave_tick: 1,
+                groups_spawned: vec![false],
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn survives_wave_when_all_enemies_are_spawned_and_down() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            0,
+            |world| {
+                let enemy = world.create_entity().with(HealthPoints(0)).build();
+                SurvivalProgress {
+                    wave_tick: 5,
+                    groups_spawned: vec![true],
+                    enemies: vec![enemy],
+                    ..Default::default()
+                }
+            },
+            |_| SurvivalProgress {
+                waves_survived: 1,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_survive_wave_while_enemies_are_alive() -> Result<(), Error> {
+        run_test(
+            SurvivalStatus::Active,
+            0,
+            |world| {
+                let enemy = world.create_entity().with(HealthPoints(10)).build();
+                SurvivalProgress {
+                    wave_tick: 5,
+                    groups_spawned: vec![true],
+                    enemies: vec![enemy],
+                    ..Default::default()
+                }
+            },
+            |world| {
+                let enemies = world.read_resource::<SurvivalProgress>().enemies.clone();
+                SurvivalProgress {
+                    wave_tick: 6,
+                    groups_spawned: vec![true],
+                    enemies,
+                    ..Default::default()
+                }
+            },
+        )
+    }
+
+    fn run_test(
+        survival_status: SurvivalStatus,
+        delay: usize,
+        setup_fn: fn(&mut World) -> SurvivalProgress,
+        expected_fn: fn(&World) -> SurvivalProgress,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(SurvivalWaveSpawnSystem::new(), "", &[])
+            .with_effect(move |world| {
+                // The enemy is not loaded, so it is never spawned.
+                let asset_slug =
+                    AssetSlug::from_str("test/enemy").expect("Expected asset slug to be valid.");
+                let wave_enemy = WaveEnemy::new(asset_slug, 1, AiProfile::Chase, None, delay);
+                world.insert(SurvivalWaves::new(vec![Wave::new(vec![wave_enemy])]));
+                world.insert(survival_status);
+                world.insert(GamePlayStatus::Playing);
+
+                let survival_progress = setup_fn(world);
+                world.insert(survival_progress);
+            })
+            .with_assertion(move |world| {
+                let expected = expected_fn(world);
+                assert_eq!(expected, *world.read_resource::<SurvivalProgress>());
+            })
+            .run()
+    }
+}
@@ -0,0 +1,2 @@
+mod hit_advantage_tracker;
+mod input_recording;
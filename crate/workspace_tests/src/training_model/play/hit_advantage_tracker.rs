@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use training_model::play::HitAdvantageTracker;
+
+    #[test]
+    fn hit_advantage_is_none_until_both_are_actionable() {
+        let hit_advantage_tracker = HitAdvantageTracker::new(5, Some(3), None);
+
+        assert_eq!(None, hit_advantage_tracker.hit_advantage());
+    }
+
+    #[test]
+    fn hit_advantage_is_positive_when_player_recovers_first() {
+        let hit_advantage_tracker = HitAdvantageTracker::new(10, Some(4), Some(10));
+
+        assert_eq!(Some(6), hit_advantage_tracker.hit_advantage());
+    }
+
+    #[test]
+    fn hit_advantage_is_negative_when_dummy_recovers_first() {
+        let hit_advantage_tracker = HitAdvantageTracker::new(10, Some(10), Some(7));
+
+        assert_eq!(Some(-3), hit_advantage_tracker.hit_advantage());
+    }
+}
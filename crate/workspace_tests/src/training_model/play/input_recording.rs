@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::ecs::{Builder, World, WorldExt};
+    use game_input_model::{
+        config::ControlAction,
+        play::{ControlActionEventData, ControlInputEvent},
+    };
+    use pretty_assertions::assert_eq;
+
+    use training_model::play::InputRecording;
+
+    #[test]
+    fn start_clears_previous_recording() {
+        let mut world = World::new();
+        let entity = world.create_entity().build();
+        let ev = ControlInputEvent::ControlActionPress(ControlActionEventData {
+            controller_id: 0,
+            entity,
+            control_action: ControlAction::Attack,
+        });
+        let mut input_recording = InputRecording {
+            recording: false,
+            length: 10,
+            events: vec![(3, ev)],
+            replay_tick: 4,
+        };
+
+        input_recording.start();
+
+        assert_eq!(
+            InputRecording {
+                recording: true,
+                length: 0,
+                events: Vec::new(),
+                replay_tick: 0,
+            },
+            input_recording
+        );
+    }
+
+    #[test]
+    fn stop_keeps_recorded_events() {
+        let mut input_recording = InputRecording::default();
+        input_recording.start();
+        input_recording.length = 5;
+
+        input_recording.stop();
+
+        assert!(!input_recording.recording);
+        assert_eq!(5, input_recording.length);
+    }
+}
@@ -0,0 +1,6 @@
+mod frame_data_update_system;
+mod training_dummy_input_system;
+mod training_entities_update_system;
+mod training_event_system;
+mod training_key_input_system;
+mod training_refill_system;
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use pretty_assertions::assert_eq;
+    use sequence_model::{loaded::SequenceId, play::FrameIndexClock};
+    use training_model::play::{FrameData, MoveFrames, TrainingEntities, TrainingStatus};
+
+    use training_play::FrameDataUpdateSystem;
+
+    #[test]
+    fn counts_startup_ticks_without_hit_interaction() -> Result<(), Error> {
+        run_test(
+            FrameData::new(
+                Some(SequenceId(1)),
+                0,
+                MoveFrames::new(2, 0, 0),
+                None,
+                None,
+                None,
+            ),
+            FrameData::new(
+                Some(SequenceId(1)),
+                3,
+                MoveFrames::new(3, 0, 0),
+                None,
+                None,
+                None,
+            ),
+        )
+    }
+
+    #[test]
+    fn keeps_last_move_frames_when_sequence_changes() -> Result<(), Error> {
+        run_test(
+            FrameData::new(
+                Some(SequenceId(0)),
+                5,
+                MoveFrames::new(4, 2, 6),
+                None,
+                None,
+                None,
+            ),
+            FrameData::new(
+                Some(SequenceId(1)),
+                3,
+                MoveFrames::new(1, 0, 0),
+                Some(MoveFrames::new(4, 2, 6)),
+                None,
+                None,
+            ),
+        )
+    }
+
+    fn run_test(frame_data_setup: FrameData, frame_data_expected: FrameData) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FrameDataUpdateSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let player = world
+                    .create_entity()
+                    .with(SequenceId(1))
+                    .with(FrameIndexClock::new_with_value(10, 3))
+                    .build();
+
+                world.insert(TrainingStatus::Active);
+                world.insert(TrainingEntities::new(Some(player), None, None));
+                world.insert(frame_data_setup);
+            })
+            .with_assertion(move |world| {
+                assert_eq!(frame_data_expected, *world.read_resource::<FrameData>());
+            })
+            .run()
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use game_input_model::{
+        config::ControlAction,
+        play::{ControlActionEventData, ControlInputEvent, ControllerInput},
+    };
+    use pretty_assertions::assert_eq;
+    use shape_model::Volume;
+    use training_model::{
+        config::DummyBehaviour,
+        play::{InputRecording, TrainingEntities, TrainingSettings, TrainingStatus},
+    };
+
+    use training_play::TrainingDummyInputSystem;
+
+    #[test]
+    fn presses_defend_for_block_all() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingDummyInputSystem::new(), "", &[])
+            .with_effect(|world| {
+                setup_training(world, DummyBehaviour::BlockAll);
+            })
+            .with_assertion(|world| {
+                let (_player, dummy) = *world.read_resource::<(Entity, Entity)>();
+                let expected = vec![ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: 1,
+                        entity: dummy,
+                        control_action: ControlAction::Defend,
+                    },
+                )];
+                assert_eq!(expected, read_events(world));
+            })
+            .run()
+    }
+
+    #[test]
+    fn presses_defend_for_defend() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingDummyInputSystem::new(), "", &[])
+            .with_effect(|world| {
+                setup_training(world, DummyBehaviour::Defend);
+            })
+            .with_assertion(|world| {
+                let (_player, dummy) = *world.read_resource::<(Entity, Entity)>();
+                let expected = vec![ControlInputEvent::ControlActionPress(defend_event_data(
+                    dummy,
+                ))];
+                assert_eq!(expected, read_events(world));
+            })
+            .run()
+    }
+
+    #[test]
+    fn keeps_holding_defend_for_block_all_when_not_hit() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingDummyInputSystem::new(), "", &[])
+            .with_effect(|world| {
+                setup_training(world, DummyBehaviour::BlockAll);
+                hold_defend(world);
+            })
+            .with_assertion(|world| {
+                assert_eq!(Vec::<ControlInputEvent>::new(), read_events(world));
+            })
+            .run()
+    }
+
+    #[test]
+    fn presses_defend_again_for_block_all_when_hit() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingDummyInputSystem::new(), "", &[])
+            .with_effect(|world| {
+                setup_training(world, DummyBehaviour::BlockAll);
+                hold_defend(world);
+
+                let (player, dummy) = *world.read_resource::<(Entity, Entity)>();
+                world
+                    .write_resource::<EventChannel<HitEvent>>()
+                    .single_write(HitEvent::new(player, dummy, interaction(), body()));
+            })
+            .with_assertion(|world| {
+                let (_player, dummy) = *world.read_resource::<(Entity, Entity)>();
+                let expected = vec![
+                    ControlInputEvent::ControlActionRelease(defend_event_data(dummy)),
+                    ControlInputEvent::ControlActionPress(defend_event_data(dummy)),
+                ];
+                assert_eq!(expected, read_events(world));
+            })
+            .run()
+    }
+
+    #[test]
+    fn replays_recorded_player_input_on_dummy() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingDummyInputSystem::new(), "", &[])
+            .with_effect(|world| {
+                setup_training(world, DummyBehaviour::RecordReplay);
+
+                let (player, _dummy) = *world.read_resource::<(Entity, Entity)>();
+                let ev = ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    controller_id: 0,
+                    entity: player,
+                    control_action: ControlAction::Attack,
+                });
+                world.insert(InputRecording {
+                    recording: false,
+                    length: 2,
+                    events: vec![(0, ev)],
+                    replay_tick: 0,
+                });
+            })
+            .with_assertion(|world| {
+                let (_player, dummy) = *world.read_resource::<(Entity, Entity)>();
+                let expected = vec![ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: 1,
+                        entity: dummy,
+                        control_action: ControlAction::Attack,
+                    },
+                )];
+                assert_eq!(expected, read_events(world));
+                assert_eq!(1, world.read_resource::<InputRecording>().replay_tick);
+            })
+            .run()
+    }
+
+    fn setup_training(world: &mut World, dummy_behaviour: DummyBehaviour) {
+        let player = world.create_entity().build();
+        let dummy = world
+            .create_entity()
+            .with(ControllerInput::default())
+            .build();
+
+        world.insert(TrainingStatus::Active);
+        world.insert(TrainingSettings::new(false, false, false, dummy_behaviour));
+        world.insert(TrainingEntities::new(Some(player), Some(dummy), Some(1)));
+        world.insert((player, dummy));
+
+        let reader_id = world
+            .write_resource::<EventChannel<ControlInputEvent>>()
+            .register_reader();
+        world.insert(reader_id);
+    }
+
+    fn hold_defend(world: &mut World) {
+        let (_player, dummy) = *world.read_resource::<(Entity, Entity)>();
+        let controller_input = ControllerInput {
+            defend: true,
+            ..Default::default()
+        };
+        world
+            .write_storage::<ControllerInput>()
+            .insert(dummy, controller_input)
+            .expect("Failed to insert `ControllerInput` component.");
+    }
+
+    fn defend_event_data(dummy: Entity) -> ControlActionEventData {
+        ControlActionEventData {
+            controller_id: 1,
+            entity: dummy,
+            control_action: ControlAction::Defend,
+        }
+    }
+
+    fn read_events(world: &World) -> Vec<ControlInputEvent> {
+        let mut reader_id = world.write_resource::<ReaderId<ControlInputEvent>>();
+        world
+            .read_resource::<EventChannel<ControlInputEvent>>()
+            .read(&mut reader_id)
+            .copied()
+            .collect::<Vec<_>>()
+    }
+
+    fn interaction() -> Interaction {
+        Interaction::new(InteractionKind::Hit(Hit::default()), vec![], true)
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::play::InputControlled;
+    use game_model::play::GameEntities;
+    use object_type::ObjectType;
+    use pretty_assertions::assert_eq;
+    use training_model::play::{TrainingEntities, TrainingStatus};
+
+    use training_play::TrainingEntitiesUpdateSystem;
+
+    #[test]
+    fn assigns_player_and_dummy_by_controller_id() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingEntitiesUpdateSystem::new(), "", &[])
+            .with_effect(|world| {
+                // Dummy is created first to check that the controller ID is used.
+                let dummy = world.create_entity().with(InputControlled::new(1)).build();
+                let player = world.create_entity().with(InputControlled::new(0)).build();
+
+                let mut objects = HashMap::new();
+                objects.insert(ObjectType::Character, vec![dummy, player]);
+                world.insert(GameEntities::new(objects, Vec::new()));
+                world.insert(TrainingStatus::Active);
+                world.insert((player, dummy));
+            })
+            .with_assertion(|world| {
+                let (player, dummy) = *world.read_resource::<(Entity, Entity)>();
+                assert_eq!(
+                    TrainingEntities::new(Some(player), Some(dummy), Some(1)),
+                    *world.read_resource::<TrainingEntities>()
+                );
+
+                let input_controlleds = world.read_storage::<InputControlled>();
+                assert!(input_controlleds.contains(player));
+                assert!(!input_controlleds.contains(dummy));
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_nothing_when_not_training() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingEntitiesUpdateSystem::new(), "", &[])
+            .with_effect(|world| {
+                let player = world.create_entity().with(InputControlled::new(0)).build();
+
+                let mut objects = HashMap::new();
+                objects.insert(ObjectType::Character, vec![player]);
+                world.insert(GameEntities::new(objects, Vec::new()));
+                world.insert(TrainingStatus::Inactive);
+            })
+            .with_assertion(|world| {
+                assert_eq!(
+                    TrainingEntities::default(),
+                    *world.read_resource::<TrainingEntities>()
+                );
+            })
+            .run()
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{ecs::WorldExt, shrev::EventChannel, Error};
+    use amethyst_test::AmethystApplication;
+    use pretty_assertions::assert_eq;
+    use training_model::{
+        config::DummyBehaviour,
+        play::{InputRecording, TrainingSettings},
+        TrainingEvent,
+    };
+
+    use training_play::TrainingEventSystem;
+
+    #[test]
+    fn toggles_infinite_settings() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingEventSystem::new(), "", &[])
+            .with_effect(|world| {
+                let mut training_ec = world.write_resource::<EventChannel<TrainingEvent>>();
+                training_ec.single_write(TrainingEvent::ToggleInfiniteHp);
+                training_ec.single_write(TrainingEvent::ToggleInfiniteCharge);
+            })
+            .with_assertion(|world| {
+                assert_eq!(
+                    TrainingSettings::new(true, false, true, DummyBehaviour::Stand),
+                    *world.read_resource::<TrainingSettings>()
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn sets_dummy_behaviour() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingEventSystem::new(), "", &[])
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<TrainingEvent>>()
+                    .single_write(TrainingEvent::DummyBehaviour(DummyBehaviour::Jump));
+            })
+            .with_assertion(|world| {
+                assert_eq!(
+                    DummyBehaviour::Jump,
+                    world.read_resource::<TrainingSettings>().dummy_behaviour
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn starts_recording() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingEventSystem::new(), "", &[])
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<TrainingEvent>>()
+                    .single_write(TrainingEvent::RecordStart);
+            })
+            .with_assertion(|world| {
+                assert!(world.read_resource::<InputRecording>().recording);
+            })
+            .run()
+    }
+}
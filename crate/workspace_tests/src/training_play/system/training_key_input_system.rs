@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{World, WorldExt},
+        input::{InputEvent, VirtualKeyCode},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::config::ControlBindings;
+    use pretty_assertions::assert_eq;
+    use training_model::{
+        config::DummyBehaviour,
+        play::{InputRecording, TrainingSettings, TrainingStatus},
+        TrainingEvent,
+    };
+
+    use training_play::{TrainingKeyInputSystem, DUMMY_BEHAVIOUR_NEXT_KEY, RECORD_TOGGLE_KEY};
+
+    #[test]
+    fn switches_dummy_to_next_behaviour() -> Result<(), Error> {
+        run_test(
+            TrainingStatus::Active,
+            DummyBehaviour::Defend,
+            false,
+            vec![DUMMY_BEHAVIOUR_NEXT_KEY, DUMMY_BEHAVIOUR_NEXT_KEY],
+            vec![
+                TrainingEvent::DummyBehaviour(DummyBehaviour::Jump),
+                TrainingEvent::DummyBehaviour(DummyBehaviour::BlockAll),
+            ],
+        )
+    }
+
+    #[test]
+    fn wraps_dummy_behaviour_to_first() -> Result<(), Error> {
+        run_test(
+            TrainingStatus::Active,
+            DummyBehaviour::RecordReplay,
+            false,
+            vec![DUMMY_BEHAVIOUR_NEXT_KEY],
+            vec![TrainingEvent::DummyBehaviour(DummyBehaviour::Stand)],
+        )
+    }
+
+    #[test]
+    fn toggles_recording() -> Result<(), Error> {
+        run_test(
+            TrainingStatus::Active,
+            DummyBehaviour::Stand,
+            true,
+            vec![RECORD_TOGGLE_KEY, RECORD_TOGGLE_KEY],
+            vec![TrainingEvent::RecordStop, TrainingEvent::RecordStart],
+        )
+    }
+
+    #[test]
+    fn ignores_other_keys() -> Result<(), Error> {
+        run_test(
+            TrainingStatus::Active,
+            DummyBehaviour::Stand,
+            false,
+            vec![VirtualKeyCode::A],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn does_nothing_when_training_is_inactive() -> Result<(), Error> {
+        run_test(
+            TrainingStatus::Inactive,
+            DummyBehaviour::Stand,
+            false,
+            vec![DUMMY_BEHAVIOUR_NEXT_KEY, RECORD_TOGGLE_KEY],
+            vec![],
+        )
+    }
+
+    fn run_test(
+        training_status: TrainingStatus,
+        dummy_behaviour: DummyBehaviour,
+        recording: bool,
+        key_codes: Vec<VirtualKeyCode>,
+        training_events_expected: Vec<TrainingEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingKeyInputSystem::new(), "", &[])
+            .with_effect(move |world| {
+                world.insert(training_status);
+                world.insert(TrainingSettings::new(false, false, false, dummy_behaviour));
+                let mut input_recording = InputRecording::default();
+                if recording {
+                    input_recording.start();
+                }
+                world.insert(input_recording);
+
+                let reader_id = world
+                    .write_resource::<EventChannel<TrainingEvent>>()
+                    .register_reader();
+                world.insert(reader_id);
+
+                let mut input_ec =
+                    world.write_resource::<EventChannel<InputEvent<ControlBindings>>>();
+                key_codes.iter().copied().for_each(|key_code| {
+                    input_ec.single_write(InputEvent::KeyPressed {
+                        key_code,
+                        scancode: 0,
+                    });
+                });
+            })
+            .with_assertion(move |world| {
+                assert_eq!(training_events_expected, read_events(world));
+            })
+            .run()
+    }
+
+    fn read_events(world: &World) -> Vec<TrainingEvent> {
+        let mut reader_id = world.write_resource::<ReaderId<TrainingEvent>>();
+        world
+            .read_resource::<EventChannel<TrainingEvent>>()
+            .read(&mut reader_id)
+            .copied()
+            .collect::<Vec<_>>()
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use charge_model::play::ChargeTrackerClock;
+    use object_model::play::{HealthPoints, SkillPoints};
+    use pretty_assertions::assert_eq;
+    use training_model::{
+        config::DummyBehaviour,
+        play::{TrainingEntities, TrainingSettings, TrainingStatus},
+    };
+
+    use training_play::TrainingRefillSystem;
+
+    #[test]
+    fn refills_enabled_points_when_training() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingRefillSystem::new(), "", &[])
+            .with_effect(|world| {
+                world.insert(TrainingStatus::Active);
+                world.insert(TrainingSettings::new(
+                    true,
+                    false,
+                    true,
+                    DummyBehaviour::Stand,
+                ));
+                create_character(world);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                assert_eq!(
+                    Some(&HealthPoints::default()),
+                    world.read_storage::<HealthPoints>().get(entity)
+                );
+                assert_eq!(
+                    Some(&SkillPoints(10)),
+                    world.read_storage::<SkillPoints>().get(entity)
+                );
+                assert_eq!(
+                    Some(&ChargeTrackerClock::new_with_value(50, 50)),
+                    world.read_storage::<ChargeTrackerClock>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_refill_when_not_training() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(TrainingRefillSystem::new(), "", &[])
+            .with_effect(|world| {
+                world.insert(TrainingStatus::Inactive);
+                world.insert(TrainingSettings::new(
+                    true,
+                    true,
+                    true,
+                    DummyBehaviour::Stand,
+                ));
+                create_character(world);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                assert_eq!(
+                    Some(&HealthPoints(10)),
+                    world.read_storage::<HealthPoints>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    fn create_character(world: &mut World) {
+        let entity = world
+            .create_entity()
+            .with(HealthPoints(10))
+            .with(SkillPoints(10))
+            .with(ChargeTrackerClock::new_with_value(50, 5))
+            .build();
+        world.insert(TrainingEntities::new(Some(entity), None, None));
+        world.insert(entity);
+    }
+}
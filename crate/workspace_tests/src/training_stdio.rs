@@ -0,0 +1,2 @@
+mod training_event_stdin_mapper;
+mod training_stdio_bundle;
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use stdio_spi::StdinMapper;
+    use training_model::{config::DummyBehaviour, TrainingEvent, TrainingEventArgs};
+
+    use training_stdio::TrainingEventStdinMapper;
+
+    #[test]
+    fn maps_infinite_hp_event() {
+        let args = TrainingEventArgs::InfiniteHp;
+
+        let result = TrainingEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(TrainingEvent::ToggleInfiniteHp, result.unwrap())
+    }
+
+    #[test]
+    fn maps_dummy_behaviour_event() {
+        let args = TrainingEventArgs::DummyBehaviour {
+            behaviour: DummyBehaviour::BlockAll,
+        };
+
+        let result = TrainingEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            TrainingEvent::DummyBehaviour(DummyBehaviour::BlockAll),
+            result.unwrap()
+        )
+    }
+
+    #[test]
+    fn maps_record_start_event() {
+        let args = TrainingEventArgs::RecordStart;
+
+        let result = TrainingEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(TrainingEvent::RecordStart, result.unwrap())
+    }
+}
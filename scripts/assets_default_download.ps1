@@ -25,9 +25,10 @@ Invoke-WebRequest "https://gitlab.com/azriel91/will_assets_test/-/archive/$asset
 # Unzip
 Add-Type -AssemblyName System.IO.Compression.FileSystem
 Expand-Archive -Path $assets_zip -DestinationPath $app_assets_dir -Force
+# Merge into the "default" directory, as it contains assets from this repository.
 $assets_default_dir = "$app_assets_dir\default"
-if (Test-Path $assets_default_dir) {
-  Remove-Item -Recurse -Force $assets_default_dir
-}
+$assets_extracted_dir = "$app_assets_dir\will_assets_test-$assets_ref"
+New-Item -ItemType Directory -Force -Path $assets_default_dir
+Copy-Item -Path "$assets_extracted_dir\*" -Destination $assets_default_dir -Recurse -Force
 Start-Sleep -s 0.5 # Allow directory handle to be freed
-Rename-Item "$app_assets_dir\will_assets_test-$assets_ref" $assets_default_dir -Force
+Remove-Item -Recurse -Force $assets_extracted_dir
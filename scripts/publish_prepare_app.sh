@@ -41,7 +41,10 @@ fi
 
 wget "https://gitlab.com/azriel91/will_assets_test/-/archive/${assets_ref}/will_assets_test-${assets_ref}.zip"
 unzip -uoq "will_assets_test-${assets_ref}.zip" -d "${app_assets_dir}"
-mv "${app_assets_dir}/will_assets_test-${assets_ref}" "${app_assets_dir}/default"
+# Merge into the "default" directory, as it contains assets from this repository.
+mkdir -p "${app_assets_dir}/default"
+cp -r "${app_assets_dir}/will_assets_test-${assets_ref}/." "${app_assets_dir}/default"
+rm -rf "${app_assets_dir}/will_assets_test-${assets_ref}"
 
 # Ensure the source files exist before transferring
 for f in "${app_publish_artifacts[@]}"; do